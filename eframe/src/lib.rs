@@ -10,6 +10,22 @@
 //! call from [`crate::run_native`] your `main.rs`, and/or call `eframe::start_web` from your `lib.rs`.
 //!
 //! `eframe` is implemented using [`egui_web`](https://docs.rs/egui_web) and [`egui_glium`](https://docs.rs/egui_glium).
+//!
+//! ## Android
+//! [`crate::run_native`] also works on Android.
+//! Build your app as a library with [`cargo apk`](https://crates.io/crates/cargo-apk),
+//! and mark your main function with `#[cfg_attr(target_os = "android", ndk_glue::main)]`
+//! (add `ndk-glue = "0.3"` to your dependencies).
+//! Text input is not supported on Android yet, since `winit` doesn't pass on what is typed on the soft keyboard.
+//!
+//! ## iOS
+//! [`crate::run_native`] also works on iOS and iPadOS, using OpenGL ES.
+//! Build your app as a static library and call your main function from an Xcode project,
+//! e.g. with [`cargo-mobile`](https://github.com/BrainiumLLC/cargo-mobile).
+//! Touches work like the mouse, and the app stops painting while it is in the background.
+//! The soft keyboard is shown while egui wants text input, and text composed with an input method
+//! (e.g. for Chinese or Japanese) is shown in the `TextEdit` until it is committed.
+//! Panels keep their contents out of the way of the notch and the home indicator (the safe area).
 
 // Forbid warnings in release builds:
 #![cfg_attr(not(debug_assertions), deny(warnings))]
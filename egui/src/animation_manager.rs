@@ -5,6 +5,7 @@ use crate::{emath::remap_clamp, Id, InputState};
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: AHashMap<Id, BoolAnim>,
+    values: AHashMap<Id, ValueAnim>,
 }
 
 #[derive(Clone, Debug)]
@@ -14,6 +15,14 @@ struct BoolAnim {
     toggle_time: f64,
 }
 
+#[derive(Clone, Debug)]
+struct ValueAnim {
+    from_value: f32,
+    to_value: f32,
+    /// when did `to_value` last change?
+    toggle_time: f64,
+}
+
 impl AnimationManager {
     /// See `Context::animate_bool` for documentation
     pub fn animate_bool(
@@ -58,4 +67,55 @@ impl AnimationManager {
             }
         }
     }
+
+    /// See `Context::animate_value` for documentation
+    pub fn animate_value(
+        &mut self,
+        input: &InputState,
+        animation_time: f32,
+        id: Id,
+        value: f32,
+    ) -> f32 {
+        match self.values.get_mut(&id) {
+            None => {
+                self.values.insert(
+                    id,
+                    ValueAnim {
+                        from_value: value,
+                        to_value: value,
+                        toggle_time: -f64::INFINITY, // long time ago
+                    },
+                );
+                value
+            }
+            Some(anim) => {
+                // Like for bools, extrapolate forwards so we don't return the old value on the frame it changes:
+                let time_since_toggle = (input.time - anim.toggle_time) as f32 + input.predicted_dt;
+                let current_value = if animation_time <= 0.0 {
+                    anim.to_value
+                } else {
+                    remap_clamp(
+                        time_since_toggle,
+                        0.0..=animation_time,
+                        anim.from_value..=anim.to_value,
+                    )
+                };
+                if anim.to_value != value {
+                    // Start the new animation from wherever the old one is:
+                    anim.from_value = current_value;
+                    anim.to_value = value;
+                    anim.toggle_time = input.time;
+                    if animation_time <= 0.0 {
+                        return value;
+                    }
+                    return remap_clamp(
+                        input.predicted_dt,
+                        0.0..=animation_time,
+                        current_value..=value,
+                    );
+                }
+                current_value
+            }
+        }
+    }
 }
@@ -47,11 +47,14 @@ pub struct Area {
     movable: bool,
     interactable: bool,
     enabled: bool,
+    keep_enabled: bool,
     order: Order,
     default_pos: Option<Pos2>,
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
     drag_bounds: Option<Rect>,
+    constrain: bool,
+    always_on_top: bool,
 }
 
 impl Area {
@@ -61,11 +64,14 @@ impl Area {
             movable: true,
             interactable: true,
             enabled: true,
+            keep_enabled: false,
             order: Order::Middle,
             default_pos: None,
             new_pos: None,
             anchor: None,
             drag_bounds: None,
+            constrain: true,
+            always_on_top: false,
         }
     }
 
@@ -87,6 +93,14 @@ impl Area {
         self
     }
 
+    /// Keep this area enabled, and on top, while the rest of egui is disabled
+    /// with [`crate::Context::set_enabled`], e.g. for showing the progress of what the app is busy with.
+    /// Default: `false`.
+    pub fn keep_enabled(mut self, keep_enabled: bool) -> Self {
+        self.keep_enabled = keep_enabled;
+        self
+    }
+
     /// moveable by dragging the area?
     pub fn movable(mut self, movable: bool) -> Self {
         self.movable = movable;
@@ -116,6 +130,15 @@ impl Area {
         self
     }
 
+    /// Stay in front of the other areas with the same [`Order`] (that are not also always on top),
+    /// even when they are clicked. Default: `false`.
+    ///
+    /// See also [`Context::move_to_top`].
+    pub fn always_on_top(mut self, always_on_top: bool) -> Self {
+        self.always_on_top = always_on_top;
+        self
+    }
+
     pub fn default_pos(mut self, default_pos: impl Into<Pos2>) -> Self {
         self.default_pos = Some(default_pos.into());
         self
@@ -156,6 +179,15 @@ impl Area {
         self
     }
 
+    /// Keep the area within [`Self::drag_bounds`], or else within [`Context::available_rect`]
+    /// (unless it is too large to fit).
+    /// If `false`, it can be moved partially or completely off the screen.
+    /// Default: `true`.
+    pub fn constrain(mut self, constrain: bool) -> Self {
+        self.constrain = constrain;
+        self
+    }
+
     pub(crate) fn get_pivot(&self) -> Align2 {
         if let Some((pivot, _)) = self.anchor {
             pivot
@@ -171,6 +203,7 @@ pub(crate) struct Prepared {
     movable: bool,
     enabled: bool,
     drag_bounds: Option<Rect>,
+    constrain: bool,
 }
 
 impl Area {
@@ -181,13 +214,27 @@ impl Area {
             order,
             interactable,
             enabled,
+            keep_enabled,
             default_pos,
             new_pos,
             anchor,
             drag_bounds,
+            constrain,
+            always_on_top,
         } = self;
 
         let layer_id = LayerId::new(order, id);
+        ctx.memory()
+            .areas
+            .set_always_on_top(layer_id, always_on_top);
+
+        if keep_enabled {
+            ctx.frame_state().keep_enabled_layers.push(layer_id);
+            if !ctx.is_enabled() {
+                // Stay above the tint that covers everything else:
+                ctx.memory().areas.move_to_top(layer_id);
+            }
+        }
 
         let state = ctx.memory().areas.get(id).cloned();
         let is_new = state.is_none();
@@ -216,6 +263,7 @@ impl Area {
             movable,
             enabled,
             drag_bounds,
+            constrain,
         }
     }
 
@@ -249,7 +297,10 @@ impl Area {
             // let area_rect =
             //     Rect::from_center_size(area_rect.center(), visibility_factor * area_rect.size());
 
-            let frame = frame.multiply_with_opacity(visibility_factor);
+            let mut frame = frame.multiply_with_opacity(visibility_factor);
+            if ctx.degrade() {
+                frame.shadow = Default::default();
+            }
             painter.add(frame.paint(area_rect));
         }
     }
@@ -268,6 +319,10 @@ impl Prepared {
         self.drag_bounds
     }
 
+    pub(crate) fn constrain(&self) -> bool {
+        self.constrain
+    }
+
     pub(crate) fn content_ui(&self, ctx: &CtxRef) -> Ui {
         let max_rect = Rect::from_min_size(self.state.pos, Vec2::INFINITY);
         let shadow_radius = ctx.style().visuals.window_shadow.extrusion; // hacky
@@ -307,6 +362,7 @@ impl Prepared {
             movable,
             enabled,
             drag_bounds,
+            constrain,
         } = self;
 
         state.size = content_ui.min_rect().size();
@@ -332,10 +388,12 @@ impl Prepared {
             state.pos += ctx.input().pointer.delta();
         }
 
-        if let Some(bounds) = drag_bounds {
-            state.pos = ctx.constrain_window_rect_to_area(state.rect(), bounds).min;
-        } else {
-            state.pos = ctx.constrain_window_rect(state.rect()).min;
+        if constrain {
+            if let Some(bounds) = drag_bounds {
+                state.pos = ctx.constrain_window_rect_to_area(state.rect(), bounds).min;
+            } else {
+                state.pos = ctx.constrain_window_rect(state.rect()).min;
+            }
         }
 
         if (move_response.dragged() || move_response.clicked())
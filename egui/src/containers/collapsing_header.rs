@@ -42,15 +42,30 @@ impl State {
         }
     }
 
+    /// Is it open, or opening? Unlike [`Self::is_open`], this doesn't look in memory.
+    pub fn is_expanded(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_open(&mut self, open: bool) {
+        self.open = open;
+    }
+
     pub fn toggle(&mut self, ui: &Ui) {
         self.open = !self.open;
         ui.ctx().request_repaint();
     }
 
-    /// 0 for closed, 1 for open, with tweening
+    /// 0 for closed, 1 for open, with tweening (unless [`Style::animate_containers`] is off)
     pub fn openness(&self, ctx: &Context, id: Id) -> f32 {
         if ctx.memory().everything_is_visible() {
             1.0
+        } else if !ctx.style().animate_containers {
+            if self.open {
+                1.0
+            } else {
+                0.0
+            }
         } else {
             ctx.animate_bool(id, self.open)
         }
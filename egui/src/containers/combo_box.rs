@@ -25,6 +25,7 @@ pub struct ComboBox {
     label: Option<Label>,
     selected_text: String,
     width: Option<f32>,
+    searchable: bool,
 }
 
 impl ComboBox {
@@ -36,6 +37,7 @@ impl ComboBox {
             label: Some(label),
             selected_text: Default::default(),
             width: None,
+            searchable: false,
         }
     }
 
@@ -46,6 +48,7 @@ impl ComboBox {
             label: Default::default(),
             selected_text: Default::default(),
             width: None,
+            searchable: false,
         }
     }
 
@@ -62,13 +65,41 @@ impl ComboBox {
         self
     }
 
+    /// Add a text field to the top of the menu, for filtering the items as you type.
+    /// The arrow keys move between the matching items, and enter selects one.
+    /// This makes it possible to pick from hundreds of items, e.g. fonts or countries.
+    ///
+    /// Only has an effect on [`Self::show_index`], as egui needs to know the text of each item.
+    /// Default: `false`.
+    ///
+    /// ```
+    /// # let mut ui = &mut egui::Ui::__test();
+    /// let countries = ["Argentina", "Brazil", "Chile", "Denmark", "Egypt"];
+    /// let mut selected = 0;
+    /// egui::ComboBox::from_label("Country")
+    ///     .searchable(true)
+    ///     .show_index(ui, &mut selected, countries.len(), |i| countries[i].to_owned());
+    /// ```
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     pub fn show_ui(self, ui: &mut Ui, menu_contents: impl FnOnce(&mut Ui)) -> Response {
+        self.show_popup(ui, |ui| {
+            ScrollArea::from_max_height(ui.spacing().combo_height).show(ui, menu_contents)
+        })
+    }
+
+    /// Show the combo box, with the given ui code for everything in the popup.
+    fn show_popup(self, ui: &mut Ui, popup_contents: impl FnOnce(&mut Ui)) -> Response {
         let Self {
             id_source,
             label,
             selected_text,
             width,
+            searchable: _,
         } = self;
 
         let button_id = ui.make_persistent_id(id_source);
@@ -77,7 +108,7 @@ impl ComboBox {
             if let Some(width) = width {
                 ui.spacing_mut().slider_width = width; // yes, this is ugly. Will remove later.
             }
-            let mut response = combo_box(ui, button_id, selected_text, menu_contents);
+            let mut response = combo_box(ui, button_id, selected_text, popup_contents);
             if let Some(label) = label {
                 response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, label.text()));
                 response |= ui.add(label);
@@ -91,6 +122,9 @@ impl ComboBox {
 
     /// Show a list of items with the given selected index.
     ///
+    /// While the popup is open, the arrow keys move the highlight between the items,
+    /// enter selects the highlighted item, and escape closes the popup.
+    /// Typing the start of an item jumps to it, unless the combo box is [`Self::searchable`].
     ///
     /// ```
     /// # #[derive(Debug, PartialEq)]
@@ -113,26 +147,312 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> String,
     ) -> Response {
-        let slf = self.selected_text(get(*selected));
+        let mut selected_index = Some(*selected);
+        let response =
+            self.selected_text(get(*selected))
+                .show_list(ui, &mut selected_index, len, get);
+        if let Some(index) = selected_index {
+            *selected = index;
+        }
+        response
+    }
+
+    /// Pick one of the `values`, showing each value as text.
+    ///
+    /// This works like [`Self::show_index`], including keyboard navigation and [`Self::searchable`],
+    /// but without the index bookkeeping.
+    ///
+    /// ```
+    /// # let mut ui = &mut egui::Ui::__test();
+    /// let mut font_size = 14;
+    /// egui::ComboBox::from_label("Font size")
+    ///     .show_values(ui, &mut font_size, (8..=32).step_by(2));
+    /// ```
+    pub fn show_values<Value: PartialEq + ToString>(
+        self,
+        ui: &mut Ui,
+        current_value: &mut Value,
+        values: impl IntoIterator<Item = Value>,
+    ) -> Response {
+        let values: Vec<Value> = values.into_iter().collect();
+        let texts: Vec<String> = values.iter().map(ToString::to_string).collect();
+        let mut selected_index = values.iter().position(|value| value == current_value);
+        let response = self.selected_text(current_value.to_string()).show_list(
+            ui,
+            &mut selected_index,
+            texts.len(),
+            |i| texts[i].clone(),
+        );
+        if response.changed() {
+            if let Some(value) = selected_index.and_then(|i| values.into_iter().nth(i)) {
+                *current_value = value;
+            }
+        }
+        response
+    }
+
+    /// The list of [`Self::show_index`]. `selected` is `None` if none of the items are selected.
+    fn show_list(
+        self,
+        ui: &mut Ui,
+        selected: &mut Option<usize>,
+        len: usize,
+        get: impl Fn(usize) -> String,
+    ) -> Response {
+        let button_id = ui.make_persistent_id(self.id_source);
+        let popup_id = button_id.with("popup");
+        let state_id = button_id.with("list_state");
+        let search_id = button_id.with("search");
+        let searchable = self.searchable;
+
+        let was_open = ui.memory().is_popup_open(popup_id);
+        if !was_open {
+            // Start afresh the next time the popup opens:
+            ui.memory().id_data_temp.remove(&state_id);
+        }
 
         let mut changed = false;
+        let mut keep_open = false;
+
+        let mut response = self.show_popup(ui, |ui| {
+            let state = ui
+                .memory()
+                .id_data_temp
+                .get::<ListState>(&state_id)
+                .cloned();
+            let is_opening = state.is_none();
+            let mut state = state.unwrap_or(ListState {
+                highlighted: selected.unwrap_or(0),
+                ..Default::default()
+            });
+
+            let matches: Vec<usize> = if searchable {
+                let search_response = ui.add(
+                    TextEdit::singleline(&mut state.text)
+                        .id(search_id)
+                        .hint_text("Search…")
+                        .desired_width(f32::INFINITY),
+                );
+                if is_opening {
+                    search_response.request_focus();
+                }
+                if search_response.changed() {
+                    state.highlighted = 0;
+                }
+                // Clicking the search field should not close the popup:
+                keep_open = search_response.clicked();
 
-        let mut response = slf.show_ui(ui, |ui| {
-            for i in 0..len {
-                if ui.selectable_label(i == *selected, get(i)).clicked() {
-                    *selected = i;
-                    changed = true;
+                let query = state.text.to_lowercase();
+                (0..len)
+                    .filter(|&i| get(i).to_lowercase().contains(&query))
+                    .collect()
+            } else {
+                let focus = ui.memory().focus();
+                if focus.is_none() || focus == Some(button_id) {
+                    state.type_ahead(ui.input(), len, &get);
+                }
+                (0..len).collect()
+            };
+
+            let (num_down, num_up) = if is_opening {
+                (0, 0) // The arrow key may have opened the popup
+            } else {
+                let input = ui.input();
+                (
+                    input.num_presses(Key::ArrowDown),
+                    input.num_presses(Key::ArrowUp),
+                )
+            };
+            let highlight_moved = is_opening || num_down + num_up > 0 || state.jumped;
+            state.highlighted = (state.highlighted + num_down)
+                .saturating_sub(num_up)
+                .min(matches.len().saturating_sub(1));
+
+            ScrollArea::from_max_height(ui.spacing().combo_height).show(ui, |ui| {
+                if matches.is_empty() {
+                    ui.label(Label::new("No matches").weak());
                 }
+                for (n, &i) in matches.iter().enumerate() {
+                    let where_to_put_highlight = ui.painter().add(Shape::Noop);
+                    let item_response = ui.selectable_label(Some(i) == *selected, get(i));
+                    if n == state.highlighted {
+                        let visuals = &ui.visuals().widgets.hovered;
+                        ui.painter().set(
+                            where_to_put_highlight,
+                            Shape::Rect {
+                                rect: item_response.rect,
+                                corner_radius: visuals.corner_radius,
+                                fill: visuals.bg_fill,
+                                stroke: visuals.bg_stroke,
+                            },
+                        );
+                        if highlight_moved {
+                            item_response.scroll_to_me(Align::Center);
+                        }
+                    }
+                    if item_response.clicked() {
+                        *selected = Some(i);
+                        changed = true;
+                    }
+                }
+            });
+
+            state.highlighted_item = matches.get(state.highlighted).copied();
+            state.jumped = false;
+            ui.memory().id_data_temp.insert(state_id, state);
+        });
+
+        if keep_open {
+            ui.memory().open_popup(popup_id);
+        }
+        // Enter may also have clicked the button and closed the popup, so we handle it out here:
+        if was_open && ui.input().key_pressed(Key::Enter) {
+            let state = ui
+                .memory()
+                .id_data_temp
+                .get::<ListState>(&state_id)
+                .cloned();
+            if let Some(i) = state.and_then(|state| state.highlighted_item) {
+                *selected = Some(i);
+                changed = true;
+            }
+            ui.memory().close_popup();
+        }
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Show a list of items with a checkbox each, for selecting any number of them.
+    ///
+    /// `selected` has one `bool` per item. The popup stays open while you toggle items.
+    /// Unless you set [`Self::selected_text`], the button shows the names of the selected items,
+    /// or how many are selected if the names don't fit.
+    ///
+    /// ```
+    /// # let mut ui = &mut egui::Ui::__test();
+    /// let tags = ["bug", "feature", "docs", "performance"];
+    /// let mut selected = [true, false, true, false];
+    /// egui::ComboBox::from_label("Tags")
+    ///     .show_multi(ui, &mut selected, |i| tags[i].to_owned());
+    /// ```
+    pub fn show_multi(
+        self,
+        ui: &mut Ui,
+        selected: &mut [bool],
+        get: impl Fn(usize) -> String,
+    ) -> Response {
+        let popup_id = ui.make_persistent_id(self.id_source).with("popup");
+
+        let slf = if self.selected_text.is_empty() {
+            let width = self.width.unwrap_or_else(|| ui.spacing().slider_width);
+            let max_text_width = width - ui.spacing().item_spacing.x - ui.spacing().icon_width;
+            let summary = multi_summary(ui, selected, &get, max_text_width);
+            self.selected_text(summary)
+        } else {
+            self
+        };
+
+        let mut changed = false;
+
+        let mut response = slf.show_ui(ui, |ui| {
+            for (i, checked) in selected.iter_mut().enumerate() {
+                changed |= ui.checkbox(checked, get(i)).clicked();
             }
         });
 
         if changed {
+            // Toggling an item should not close the popup:
+            ui.memory().open_popup(popup_id);
             response.mark_changed();
         }
         response
     }
 }
 
+/// "None", the names of the selected items, or how many are selected if the names are too wide.
+fn multi_summary(
+    ui: &Ui,
+    selected: &[bool],
+    get: impl Fn(usize) -> String,
+    max_width: f32,
+) -> String {
+    let names: Vec<String> = (0..selected.len())
+        .filter(|&i| selected[i])
+        .map(get)
+        .collect();
+    match names.len() {
+        0 => "None".to_owned(),
+        1 => names[0].clone(),
+        num_selected => {
+            let joined = names.join(", ");
+            let width = ui
+                .fonts()
+                .layout_no_wrap(TextStyle::Button, joined.clone())
+                .size
+                .x;
+            if width <= max_width {
+                joined
+            } else {
+                format!("{} selected", num_selected)
+            }
+        }
+    }
+}
+
+/// How long to wait between key presses before a new type-ahead search starts.
+const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
+/// The keyboard state of [`ComboBox::show_index`], while the popup is open.
+#[derive(Clone, Default)]
+struct ListState {
+    /// The search text, or what has been typed so far for type-ahead.
+    text: String,
+    /// Index into the matching items.
+    highlighted: usize,
+    /// The item that enter selects.
+    highlighted_item: Option<usize>,
+    /// When we last got type-ahead input.
+    last_type_time: f64,
+    /// Type-ahead moved the highlight this frame.
+    jumped: bool,
+}
+
+impl ListState {
+    /// Jump to the first item that starts with what was typed.
+    /// Typing the same letter over and over cycles through the items starting with it.
+    fn type_ahead(&mut self, input: &InputState, len: usize, get: impl Fn(usize) -> String) {
+        for event in &input.events {
+            if let Event::Text(text) = event {
+                if input.time - self.last_type_time > TYPE_AHEAD_TIMEOUT {
+                    self.text.clear();
+                }
+                self.last_type_time = input.time;
+                self.text.push_str(&text.to_lowercase());
+
+                let starts_with =
+                    |i: usize, prefix: &str| get(i).to_lowercase().starts_with(prefix);
+                let first = self.text.chars().next();
+                let found = match first {
+                    Some(first) if self.text.chars().all(|c| c == first) => {
+                        // Pressing the same letter again moves on to the next item starting with it:
+                        let first = first.to_string();
+                        (self.highlighted + 1..self.highlighted + 1 + len)
+                            .map(|i| i % len)
+                            .find(|&i| starts_with(i, &first))
+                    }
+                    _ => (0..len).find(|&i| starts_with(i, &self.text)),
+                };
+                if let Some(i) = found {
+                    self.highlighted = i;
+                    self.jumped = true;
+                }
+            }
+        }
+    }
+}
+
 /// A drop-down selection menu with a descriptive label.
 ///
 /// Deprecated! Use [`ComboBox`] instead!
@@ -159,7 +479,9 @@ pub fn combo_box_with_label(
     let button_id = ui.make_persistent_id(label.text());
 
     ui.horizontal(|ui| {
-        let mut response = combo_box(ui, button_id, selected, menu_contents);
+        let mut response = combo_box(ui, button_id, selected, |ui| {
+            ScrollArea::from_max_height(ui.spacing().combo_height).show(ui, menu_contents)
+        });
         response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, label.text()));
         response |= ui.add(label);
         response
@@ -172,7 +494,7 @@ fn combo_box(
     ui: &mut Ui,
     button_id: Id,
     selected: impl ToString,
-    menu_contents: impl FnOnce(&mut Ui),
+    popup_contents: impl FnOnce(&mut Ui),
 ) -> Response {
     let popup_id = button_id.with("popup");
 
@@ -197,11 +519,11 @@ fn combo_box(
 
         let icon_rect = Align2::RIGHT_CENTER.align_size_within_rect(icon_size, rect);
         let visuals = if is_popup_open {
-            &ui.visuals().widgets.open
+            ui.visuals().widgets.open
         } else {
             ui.style().interact(&response)
         };
-        paint_icon(ui.painter(), icon_rect.expand(visuals.expansion), visuals);
+        paint_icon(ui.painter(), icon_rect.expand(visuals.expansion), &visuals);
 
         let text_rect = Align2::LEFT_CENTER.align_size_within_rect(galley.size, rect);
         ui.painter()
@@ -210,10 +532,13 @@ fn combo_box(
 
     if button_response.clicked() {
         ui.memory().toggle_popup(popup_id);
+    } else if button_response.has_focus()
+        && !is_popup_open
+        && ui.input().key_pressed(Key::ArrowDown)
+    {
+        ui.memory().open_popup(popup_id);
     }
-    crate::popup::popup_below_widget(ui, popup_id, &button_response, |ui| {
-        ScrollArea::from_max_height(ui.spacing().combo_height).show(ui, menu_contents)
-    });
+    crate::popup::popup_below_widget(ui, popup_id, &button_response, popup_contents);
 
     button_response
 }
@@ -242,7 +567,7 @@ fn button_frame(
 
     let response = ui.interact(outer_rect, id, sense);
     let visuals = if is_popup_open {
-        &ui.visuals().widgets.open
+        ui.visuals().widgets.open
     } else {
         ui.style().interact(&response)
     };
@@ -272,3 +597,90 @@ fn paint_icon(painter: &Painter, rect: Rect, visuals: &WidgetVisuals) {
         visuals.fg_stroke,
     ));
 }
+
+#[test]
+fn test_searchable_combo_box() {
+    use crate::test_util::{key, pointer_button, run_ui_frame};
+    let items = ["Denmark", "Finland", "France", "Sweden"];
+    let mut selected = 0;
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>, selected: &mut usize| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            ComboBox::from_id_source("countries")
+                .searchable(true)
+                .show_index(ui, selected, items.len(), |i| items[i].to_owned())
+        })
+    };
+
+    let pos = run_frame(vec![], &mut selected).rect.center();
+    run_frame(
+        vec![Event::PointerMoved(pos), pointer_button(pos, true)],
+        &mut selected,
+    );
+    run_frame(vec![pointer_button(pos, false)], &mut selected); // Opens the popup
+    run_frame(vec![], &mut selected); // Focuses the search field
+    run_frame(vec![Event::Text("an".into())], &mut selected); // Finland, France
+    run_frame(vec![key(Key::ArrowDown)], &mut selected);
+    let response = run_frame(vec![key(Key::Enter)], &mut selected);
+    assert_eq!(items[selected], "France");
+    assert!(response.changed());
+}
+
+#[test]
+fn test_multi_select_combo_box() {
+    use crate::test_util::{click, run_ui_frame};
+    let items = ["bug", "feature", "docs"];
+    let mut selected = [false; 3];
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>, selected: &mut [bool]| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            ComboBox::from_id_source("tags").show_multi(ui, selected, |i| items[i].to_owned())
+        })
+    };
+
+    let button_rect = run_frame(vec![], &mut selected).rect;
+    run_frame(click(button_rect.center()), &mut selected); // Opens the popup
+    run_frame(vec![], &mut selected);
+
+    // The first checkbox, just below the button:
+    let style = Style::default();
+    let first_item = button_rect.left_bottom()
+        + style.spacing.window_padding
+        + 0.5 * style.spacing.interact_size;
+    let response = run_frame(click(first_item), &mut selected);
+    assert_eq!(selected, [true, false, false]);
+    assert!(response.changed());
+
+    // The popup is still open, so we can pick another item:
+    let second_item = first_item
+        + vec2(
+            0.0,
+            style.spacing.interact_size.y + style.spacing.item_spacing.y,
+        );
+    run_frame(click(second_item), &mut selected);
+    assert_eq!(selected, [true, true, false]);
+}
+
+#[test]
+fn test_combo_box_keyboard() {
+    use crate::test_util::{key, run_ui_frame};
+    let items = ["Denmark", "Finland", "France", "Sweden"];
+    let mut selected = 0;
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>, selected: &mut usize| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            ComboBox::from_id_source("countries")
+                .show_index(ui, selected, items.len(), |i| items[i].to_owned())
+        })
+    };
+
+    run_frame(vec![key(Key::Tab)], &mut selected); // Focus the button
+    run_frame(vec![key(Key::ArrowDown)], &mut selected); // Opens the popup
+    run_frame(vec![Event::Text("f".into())], &mut selected); // Finland
+    run_frame(vec![Event::Text("f".into())], &mut selected); // France
+    run_frame(vec![key(Key::ArrowDown)], &mut selected); // Sweden
+    run_frame(vec![key(Key::ArrowUp)], &mut selected); // France
+    let response = run_frame(vec![key(Key::Enter)], &mut selected);
+    assert_eq!(items[selected], "France");
+    assert!(response.changed());
+}
@@ -0,0 +1,274 @@
+//! A keyboard-driven overlay for finding and running commands, see [`CommandPalette`].
+
+use crate::{util::fuzzy::fuzzy_match, widgets::label::paint_char_highlights, *};
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct State {
+    open: bool,
+    query: String,
+    /// Index into the filtered and sorted list of commands.
+    selected: usize,
+}
+
+/// An overlay that lets the user search for a command by typing (part of) its name.
+///
+/// The palette is opened (and closed) with a keyboard shortcut, <kbd>Ctrl</kbd>+<kbd>P</kbd> by default
+/// (<kbd>⌘</kbd>+<kbd>P</kbd> on Mac), or with [`Self::set_open`].
+/// The commands are fuzzy-filtered as the user types, with the best match at the top
+/// and the matched characters highlighted.
+/// The arrow keys select a command, <kbd>Enter</kbd> (or a click) runs it,
+/// and <kbd>Escape</kbd> dismisses the palette.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// let commands = ["File: Open", "File: Save", "View: Toggle dark mode"];
+/// if let Some(index) = egui::CommandPalette::new("command_palette").show(ctx, &commands) {
+///     match index {
+///         0 => { /* open a file */ }
+///         1 => { /* save the file */ }
+///         _ => { /* toggle dark mode */ }
+///     }
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct CommandPalette {
+    id: Id,
+    shortcut: (Modifiers, Key),
+    hint_text: String,
+    width: f32,
+    max_results: usize,
+}
+
+impl CommandPalette {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            shortcut: (
+                Modifiers {
+                    command: true,
+                    ..Default::default()
+                },
+                Key::P,
+            ),
+            hint_text: "Type a command…".to_owned(),
+            width: 400.0,
+            max_results: 12,
+        }
+    }
+
+    /// The keyboard shortcut that opens and closes the palette. Default: `command + P`.
+    ///
+    /// Only [`Modifiers::command`], [`Modifiers::shift`] and [`Modifiers::alt`] are looked at.
+    /// For instance, to open the palette with <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>P</kbd>:
+    ///
+    /// ```
+    /// let modifiers = egui::Modifiers { command: true, shift: true, ..Default::default() };
+    /// let palette = egui::CommandPalette::new("command_palette").shortcut(modifiers, egui::Key::P);
+    /// ```
+    pub fn shortcut(mut self, modifiers: Modifiers, key: Key) -> Self {
+        self.shortcut = (modifiers, key);
+        self
+    }
+
+    /// Shown in the search field before the user has typed anything.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn hint_text(mut self, hint_text: impl ToString) -> Self {
+        self.hint_text = hint_text.to_string();
+        self
+    }
+
+    /// Width of the palette in points. Default: 400.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Show at most this many matching commands at once. Default: 12.
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Open or close the palette with the given id source, e.g. from a menu button.
+    pub fn set_open(ctx: &Context, id_source: impl std::hash::Hash, open: bool) {
+        let mut memory = ctx.memory();
+        let state = memory
+            .id_data_temp
+            .get_mut_or_default::<State>(Id::new(id_source));
+        if state.open != open {
+            *state = State {
+                open,
+                ..Default::default()
+            };
+        }
+    }
+
+    /// Is the palette with the given id source currently open?
+    pub fn is_open(ctx: &Context, id_source: impl std::hash::Hash) -> bool {
+        ctx.memory()
+            .id_data_temp
+            .get::<State>(&Id::new(id_source))
+            .map_or(false, |state| state.open)
+    }
+
+    /// Check for the shortcut, and show the palette if it is open.
+    ///
+    /// Returns the index into `commands` of the command the user chose, if any.
+    /// The palette closes itself when a command is chosen.
+    pub fn show(self, ctx: &CtxRef, commands: &[impl AsRef<str>]) -> Option<usize> {
+        let Self {
+            id,
+            shortcut,
+            hint_text,
+            width,
+            max_results,
+        } = self;
+
+        let mut state = ctx
+            .memory()
+            .id_data_temp
+            .get_or_default::<State>(id)
+            .clone();
+
+        if shortcut_pressed(ctx.input(), shortcut) {
+            state = State {
+                open: !state.open,
+                ..Default::default()
+            };
+        }
+
+        let mut chosen = None;
+
+        if state.open {
+            let area_response = Area::new(id)
+                .order(Order::Foreground)
+                .anchor(Align2::CENTER_TOP, vec2(0.0, 64.0))
+                .show(ctx, |ui| {
+                    Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.set_width(width);
+                        chosen = palette_ui(ui, id, &mut state, &hint_text, max_results, commands);
+                    });
+                });
+
+            let input = ctx.input();
+            let clicked_elsewhere = input.pointer.any_pressed()
+                && input
+                    .pointer
+                    .interact_pos()
+                    .map_or(false, |pos| !area_response.rect.contains(pos));
+            if chosen.is_some() || clicked_elsewhere || input.key_pressed(Key::Escape) {
+                state = Default::default();
+            }
+        }
+
+        ctx.memory().id_data_temp.insert(id, state);
+        chosen
+    }
+}
+
+pub(crate) fn shortcut_pressed(input: &InputState, (modifiers, key): (Modifiers, Key)) -> bool {
+    input.modifiers.command == modifiers.command
+        && input.modifiers.shift == modifiers.shift
+        && input.modifiers.alt == modifiers.alt
+        && input.key_pressed(key)
+}
+
+fn palette_ui(
+    ui: &mut Ui,
+    id: Id,
+    state: &mut State,
+    hint_text: &str,
+    max_results: usize,
+    commands: &[impl AsRef<str>],
+) -> Option<usize> {
+    let response = ui.add(
+        TextEdit::singleline(&mut state.query)
+            .id(id.with("query"))
+            .hint_text(hint_text)
+            .desired_width(f32::INFINITY),
+    );
+    response.request_focus();
+    if response.changed() {
+        state.selected = 0;
+    }
+
+    let mut matches: Vec<(usize, Vec<usize>, i32)> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| {
+            fuzzy_match(&state.query, command.as_ref()).map(|m| (index, m.char_indices, m.score))
+        })
+        .collect();
+    matches.sort_by_key(|(_, _, score)| -score); // Stable sort, so equal scores keep their order
+    matches.truncate(max_results);
+
+    if matches.is_empty() {
+        ui.separator();
+        ui.add(Label::new("No matching commands").weak());
+        return None;
+    }
+
+    let mut chosen = None;
+    {
+        let input = ui.input();
+        let num_down = input.num_presses(Key::ArrowDown) as isize;
+        let num_up = input.num_presses(Key::ArrowUp) as isize;
+        let selected = state.selected as isize + num_down - num_up;
+        state.selected = selected.rem_euclid(matches.len() as isize) as usize;
+        if input.key_pressed(Key::Enter) {
+            chosen = Some(matches[state.selected].0);
+        }
+    }
+
+    ui.separator();
+    for (rank, (index, char_indices, _score)) in matches.iter().enumerate() {
+        let selected = rank == state.selected;
+        let response = command_ui(ui, commands[*index].as_ref(), char_indices, selected);
+        if response.clicked() {
+            chosen = Some(*index);
+        } else if response.hovered() && ui.input().pointer.is_moving() {
+            state.selected = rank;
+        }
+    }
+
+    chosen
+}
+
+fn command_ui(ui: &mut Ui, command: &str, char_indices: &[usize], selected: bool) -> Response {
+    let padding = ui.spacing().button_padding;
+    let galley = ui
+        .fonts()
+        .layout_no_wrap(TextStyle::Button, command.to_owned());
+    let desired_size = vec2(
+        ui.available_width(),
+        (galley.size.y + 2.0 * padding.y).at_least(ui.spacing().interact_size.y),
+    );
+    let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+    response.widget_info(|| WidgetInfo::selected(WidgetType::SelectableLabel, selected, command));
+
+    if ui.clip_rect().intersects(rect) {
+        let visuals = ui.style().interact_selectable(&response, selected);
+        if selected || response.hovered() {
+            ui.painter()
+                .rect_filled(rect, visuals.corner_radius, visuals.bg_fill);
+        }
+
+        let text_pos = pos2(
+            rect.left() + padding.x,
+            rect.center().y - 0.5 * galley.size.y,
+        );
+        let highlight = ui.visuals().selection.bg_fill;
+        paint_char_highlights(ui.painter(), text_pos, &galley, char_indices, highlight);
+
+        let text_color = ui
+            .visuals()
+            .override_text_color
+            .unwrap_or_else(|| visuals.text_color());
+        ui.painter().galley(text_pos, galley, text_color);
+    }
+
+    response
+}
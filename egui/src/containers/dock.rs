@@ -0,0 +1,817 @@
+//! Tabs that can be rearranged into groups, splits and floating windows, see [`DockArea`].
+
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use crate::*;
+
+/// Where to put a tab, relative to the group of another tab.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockPosition {
+    /// In the same group, as another tab.
+    Center,
+    /// In a new group to the left.
+    Left,
+    /// In a new group to the right.
+    Right,
+    /// In a new group above.
+    Top,
+    /// In a new group below.
+    Bottom,
+}
+
+impl DockPosition {
+    /// Where a tab dropped at `pos` goes: the edges of `rect` split it, the middle adds to it.
+    fn at(rect: Rect, pos: Pos2) -> Self {
+        let rel = (pos - rect.min) / rect.size();
+        let from_edge = [
+            (rel.x, DockPosition::Left),
+            (1.0 - rel.x, DockPosition::Right),
+            (rel.y, DockPosition::Top),
+            (1.0 - rel.y, DockPosition::Bottom),
+        ];
+        let (distance, position) = from_edge.iter().copied().fold(
+            (f32::INFINITY, DockPosition::Center),
+            |closest, edge| {
+                if edge.0 < closest.0 {
+                    edge
+                } else {
+                    closest
+                }
+            },
+        );
+        if distance < 0.25 {
+            position
+        } else {
+            DockPosition::Center
+        }
+    }
+
+    /// The part of `rect` a tab put here will take up.
+    fn preview(self, rect: Rect) -> Rect {
+        let center = rect.center();
+        match self {
+            DockPosition::Center => rect,
+            DockPosition::Left => Rect::from_min_max(rect.min, pos2(center.x, rect.max.y)),
+            DockPosition::Right => Rect::from_min_max(pos2(center.x, rect.min.y), rect.max),
+            DockPosition::Top => Rect::from_min_max(rect.min, pos2(rect.max.x, center.y)),
+            DockPosition::Bottom => Rect::from_min_max(pos2(rect.min.x, center.y), rect.max),
+        }
+    }
+}
+
+/// A part of a [`DockTree`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockNode {
+    /// A group of tabs, of which the `active` one is shown.
+    Tabs { tabs: Vec<String>, active: usize },
+
+    /// Two nodes next to each other (if `horizontal`) or above each other.
+    /// `fraction` is how much of the space the `first` one gets.
+    Split {
+        horizontal: bool,
+        fraction: f32,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+}
+
+impl DockNode {
+    fn group(tab: String) -> Self {
+        Self::Tabs {
+            tabs: vec![tab],
+            active: 0,
+        }
+    }
+
+    fn contains(&self, tab: &str) -> bool {
+        match self {
+            Self::Tabs { tabs, .. } => tabs.iter().any(|t| t == tab),
+            Self::Split { first, second, .. } => first.contains(tab) || second.contains(tab),
+        }
+    }
+
+    /// The group containing `tab`.
+    fn group_mut(&mut self, tab: &str) -> Option<&mut Self> {
+        match self {
+            Self::Tabs { .. } => {
+                if self.contains(tab) {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+            Self::Split { first, second, .. } => {
+                if first.contains(tab) {
+                    first.group_mut(tab)
+                } else {
+                    second.group_mut(tab)
+                }
+            }
+        }
+    }
+
+    fn first_group_mut(&mut self) -> &mut Self {
+        match self {
+            Self::Tabs { .. } => self,
+            Self::Split { first, .. } => first.first_group_mut(),
+        }
+    }
+
+    fn tabs<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Self::Tabs { tabs, .. } => out.extend(tabs.iter().map(String::as_str)),
+            Self::Split { first, second, .. } => {
+                first.tabs(out);
+                second.tabs(out);
+            }
+        }
+    }
+
+    /// This node without `tab`, or `None` if nothing is left.
+    /// Splits of which one side becomes empty are replaced by the other side.
+    fn without(self, tab: &str) -> Option<Self> {
+        match self {
+            Self::Tabs { mut tabs, active } => {
+                let index = tabs.iter().position(|t| t == tab);
+                let mut active = active;
+                if let Some(index) = index {
+                    tabs.remove(index);
+                    if index < active {
+                        active -= 1;
+                    }
+                }
+                if tabs.is_empty() {
+                    None
+                } else {
+                    let active = active.min(tabs.len() - 1);
+                    Some(Self::Tabs { tabs, active })
+                }
+            }
+            Self::Split {
+                horizontal,
+                fraction,
+                first,
+                second,
+            } => match (first.without(tab), second.without(tab)) {
+                (Some(first), Some(second)) => Some(Self::Split {
+                    horizontal,
+                    fraction,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                }),
+                (first, second) => first.or(second),
+            },
+        }
+    }
+
+    /// Put `tab` next to the group of `target`, which must be in this node.
+    fn insert(&mut self, tab: String, target: &str, position: DockPosition) {
+        let group = match self.group_mut(target) {
+            Some(group) => group,
+            None => return,
+        };
+        if let DockNode::Tabs { tabs, active } = group {
+            if position == DockPosition::Center {
+                tabs.push(tab);
+                *active = tabs.len() - 1;
+                return;
+            }
+        }
+
+        let old = std::mem::replace(group, DockNode::group(String::new()));
+        let new = DockNode::group(tab);
+        let (first, second) = match position {
+            DockPosition::Left | DockPosition::Top => (new, old),
+            _ => (old, new),
+        };
+        *group = DockNode::Split {
+            horizontal: matches!(position, DockPosition::Left | DockPosition::Right),
+            fraction: 0.5,
+            first: Box::new(first),
+            second: Box::new(second),
+        };
+    }
+}
+
+/// A tab that has been dragged out of a [`DockArea`] into its own window.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+struct FloatingTab {
+    tab: String,
+    /// Top left corner of the window.
+    pos: Pos2,
+}
+
+/// The layout of a [`DockArea`]: which tabs there are, how they are grouped and split,
+/// and which have been dragged out into windows of their own.
+///
+/// Tabs are known by their title, which must be unique.
+/// Store the tree in your app (it is serializable with the `persistence` feature)
+/// and change it with [`Self::add_tab`], [`Self::add_tab_at`], [`Self::remove_tab`] etc.
+///
+/// ```
+/// let mut tree = egui::DockTree::default();
+/// tree.add_tab("Files");
+/// tree.add_tab_at("Editor", "Files", egui::DockPosition::Right);
+/// tree.add_tab_at("Console", "Editor", egui::DockPosition::Bottom);
+/// tree.add_tab("Output"); // next to "Console", which was added last
+/// assert_eq!(tree.tabs(), vec!["Files", "Editor", "Console", "Output"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct DockTree {
+    root: Option<DockNode>,
+    floating: Vec<FloatingTab>,
+    focused: Option<String>,
+}
+
+impl DockTree {
+    /// The docked tabs, if any.
+    pub fn root(&self) -> Option<&DockNode> {
+        self.root.as_ref()
+    }
+
+    /// All tabs, docked ones first.
+    pub fn tabs(&self) -> Vec<&str> {
+        let mut tabs = vec![];
+        if let Some(root) = &self.root {
+            root.tabs(&mut tabs);
+        }
+        tabs.extend(self.floating.iter().map(|floating| floating.tab.as_str()));
+        tabs
+    }
+
+    pub fn contains(&self, tab: &str) -> bool {
+        self.is_docked(tab) || self.is_floating(tab)
+    }
+
+    pub fn is_docked(&self, tab: &str) -> bool {
+        self.root.as_ref().map_or(false, |root| root.contains(tab))
+    }
+
+    /// Is the tab in a window of its own?
+    pub fn is_floating(&self, tab: &str) -> bool {
+        self.floating.iter().any(|floating| floating.tab == tab)
+    }
+
+    /// The tab that was added, clicked or focused last.
+    pub fn focused_tab(&self) -> Option<&str> {
+        self.focused.as_deref()
+    }
+
+    /// Add a tab to the group of the focused tab (or the first group), and focus it.
+    /// If the tab is already there it is just focused.
+    pub fn add_tab(&mut self, tab: impl ToString) {
+        let tab = tab.to_string();
+        if self.focus_tab(&tab) {
+            return;
+        }
+
+        let target = self
+            .focused
+            .clone()
+            .filter(|focused| self.is_docked(focused));
+        match (&mut self.root, target) {
+            (Some(root), Some(target)) => root.insert(tab.clone(), &target, DockPosition::Center),
+            (Some(root), None) => {
+                if let DockNode::Tabs { tabs, active } = root.first_group_mut() {
+                    tabs.push(tab.clone());
+                    *active = tabs.len() - 1;
+                }
+            }
+            (None, _) => self.root = Some(DockNode::group(tab.clone())),
+        }
+        self.focused = Some(tab);
+    }
+
+    /// Add (or move) a tab next to the group of the docked tab `target`, and focus it.
+    ///
+    /// Returns `false` if `target` isn't docked.
+    pub fn add_tab_at(&mut self, tab: impl ToString, target: &str, position: DockPosition) -> bool {
+        let tab = tab.to_string();
+        if tab == target || !self.is_docked(target) {
+            return false;
+        }
+        self.remove_tab(&tab);
+        if let Some(root) = &mut self.root {
+            root.insert(tab.clone(), target, position);
+        }
+        self.focused = Some(tab);
+        true
+    }
+
+    /// Move a tab (or add it) to a window of its own, with its top left corner at `pos`.
+    pub fn float_tab(&mut self, tab: impl ToString, pos: Pos2) {
+        let tab = tab.to_string();
+        self.remove_tab(&tab);
+        self.floating.push(FloatingTab {
+            tab: tab.clone(),
+            pos,
+        });
+        self.focused = Some(tab);
+    }
+
+    /// Returns `false` if there was no such tab.
+    pub fn remove_tab(&mut self, tab: &str) -> bool {
+        if !self.contains(tab) {
+            return false;
+        }
+        self.root = self.root.take().and_then(|root| root.without(tab));
+        self.floating.retain(|floating| floating.tab != tab);
+        if self.focused.as_deref() == Some(tab) {
+            self.focused = None;
+        }
+        true
+    }
+
+    /// Show this tab in its group, and make it the focused one.
+    ///
+    /// Returns `false` if there was no such tab.
+    pub fn focus_tab(&mut self, tab: &str) -> bool {
+        if !self.contains(tab) {
+            return false;
+        }
+        if let Some(DockNode::Tabs { tabs, active }) =
+            self.root.as_mut().and_then(|root| root.group_mut(tab))
+        {
+            *active = tabs.iter().position(|t| t == tab).unwrap_or_default();
+        }
+        self.focused = Some(tab.to_owned());
+        true
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What happened in a [`DockArea`] this frame.
+pub struct DockResponse {
+    /// The whole dock area.
+    pub response: Response,
+
+    /// Tabs the user closed this frame. They have already been removed from the [`DockTree`].
+    pub closed: Vec<String>,
+}
+
+/// Shows the tabs of a [`DockTree`]: groups of tabs, split next to or above each other.
+///
+/// The user can drag the tabs around: into another group, to the edge of a group to split it,
+/// or out of the dock area into a window of its own. Windows can be dragged back in the same way.
+/// The lines between the groups can be dragged to resize them.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut tree = egui::DockTree::default();
+/// # tree.add_tab("Editor");
+/// egui::DockArea::new("my_dock").show(ui, &mut tree, |ui, tab| {
+///     ui.label(format!("Contents of {}", tab));
+/// });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct DockArea {
+    id_source: Id,
+    show_close_buttons: bool,
+    allow_floating: bool,
+}
+
+impl DockArea {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            show_close_buttons: true,
+            allow_floating: true,
+        }
+    }
+
+    /// Show a close button on each tab (and floating window). Default: `true`.
+    pub fn show_close_buttons(mut self, show_close_buttons: bool) -> Self {
+        self.show_close_buttons = show_close_buttons;
+        self
+    }
+
+    /// Tabs dropped outside the dock area become windows. Default: `true`.
+    pub fn allow_floating(mut self, allow_floating: bool) -> Self {
+        self.allow_floating = allow_floating;
+        self
+    }
+
+    /// Fill the available space with the docked tabs, and show the floating ones in windows.
+    /// `add_tab(ui, title)` shows the contents of a tab.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        tree: &mut DockTree,
+        mut add_tab: impl FnMut(&mut Ui, &str),
+    ) -> DockResponse {
+        let Self {
+            id_source,
+            show_close_buttons,
+            allow_floating,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let rect = ui.available_rect_before_wrap();
+        let mut state = ShowState {
+            id,
+            show_close_buttons,
+            groups: vec![],
+            focused: None,
+            closed: vec![],
+            dragging: ui.memory().id_data_temp.get::<DraggedTab>(&id).cloned(),
+            dragged: None,
+        };
+
+        match &mut tree.root {
+            Some(root) => node_ui(ui, root, rect, id.with("root"), &mut state, &mut add_tab),
+            None => state.groups.push((rect, vec![])),
+        }
+
+        let ctx = ui.ctx().clone();
+        for floating in &mut tree.floating {
+            let window_id = id.with("floating").with(&floating.tab);
+            let mut open = true;
+            let tab = floating.tab.as_str();
+            let mut window = Window::new(tab)
+                .id(window_id)
+                .current_pos(floating.pos)
+                .default_size(rect.size() * 0.5);
+            if show_close_buttons {
+                window = window.open(&mut open);
+            }
+            let response = window.show(&ctx, |ui| add_tab(ui, tab));
+            if let Some(area) = ctx.memory().areas.get(window_id) {
+                floating.pos = area.pos;
+            }
+            if let Some(response) = response {
+                if response.clicked() || response.dragged() {
+                    state.focused = Some(floating.tab.clone());
+                }
+                state.check_drag(&ctx, &floating.tab, &response);
+            }
+            if !open {
+                state.closed.push(floating.tab.clone());
+            }
+        }
+
+        match state.dragged.take() {
+            Some((tab, released)) => {
+                if !released {
+                    ctx.memory()
+                        .id_data_temp
+                        .insert(id, DraggedTab(tab.clone()));
+                }
+                drag_and_drop(&ctx, tree, &state, tab, released, allow_floating);
+            }
+            None => ctx.memory().id_data_temp.remove(&id),
+        }
+
+        if let Some(focused) = state.focused {
+            tree.focus_tab(&focused);
+        }
+        for tab in &state.closed {
+            tree.remove_tab(tab);
+        }
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+        DockResponse {
+            response,
+            closed: state.closed,
+        }
+    }
+}
+
+struct ShowState {
+    id: Id,
+    show_close_buttons: bool,
+    /// Where each group is, and its tabs.
+    groups: Vec<(Rect, Vec<String>)>,
+    focused: Option<String>,
+    closed: Vec<String>,
+    /// The tab that was being dragged last frame.
+    dragging: Option<DraggedTab>,
+    /// The tab being dragged, and if it was released this frame.
+    dragged: Option<(String, bool)>,
+}
+
+impl ShowState {
+    /// A tab is only moved once it is dragged further than a click,
+    /// and dropped when released after that.
+    fn check_drag(&mut self, ctx: &Context, tab: &str, response: &Response) {
+        if response.dragged() && !ctx.input().pointer.could_be_click() {
+            self.dragged = Some((tab.to_owned(), false));
+        } else if response.drag_released()
+            && self
+                .dragging
+                .as_ref()
+                .map_or(false, |dragging| dragging.0 == tab)
+        {
+            self.dragged = Some((tab.to_owned(), true));
+        }
+    }
+}
+
+/// Remembers which tab is being dragged across frames.
+#[derive(Clone)]
+struct DraggedTab(String);
+
+fn node_ui(
+    ui: &mut Ui,
+    node: &mut DockNode,
+    rect: Rect,
+    id: Id,
+    state: &mut ShowState,
+    add_tab: &mut dyn FnMut(&mut Ui, &str),
+) {
+    match node {
+        DockNode::Tabs { tabs, active } => tabs_ui(ui, tabs, active, rect, state, add_tab),
+        DockNode::Split {
+            horizontal,
+            fraction,
+            first,
+            second,
+        } => {
+            let horizontal = *horizontal;
+            let spacing = ui.spacing().item_spacing;
+            let (first_rect, second_rect, line) = if horizontal {
+                let x = lerp(rect.x_range(), *fraction);
+                (
+                    Rect::from_min_max(rect.min, pos2(x - 0.5 * spacing.x, rect.max.y)),
+                    Rect::from_min_max(pos2(x + 0.5 * spacing.x, rect.min.y), rect.max),
+                    [pos2(x, rect.min.y), pos2(x, rect.max.y)],
+                )
+            } else {
+                let y = lerp(rect.y_range(), *fraction);
+                (
+                    Rect::from_min_max(rect.min, pos2(rect.max.x, y - 0.5 * spacing.y)),
+                    Rect::from_min_max(pos2(rect.min.x, y + 0.5 * spacing.y), rect.max),
+                    [pos2(rect.min.x, y), pos2(rect.max.x, y)],
+                )
+            };
+
+            let grab_radius = ui.style().interaction.resize_grab_radius_side;
+            let line_rect = Rect::from_two_pos(line[0], line[1]).expand(grab_radius);
+            let response = ui.interact(line_rect, id.with("split"), Sense::drag());
+            if response.hovered() || response.dragged() {
+                ui.output().cursor_icon = if horizontal {
+                    CursorIcon::ResizeHorizontal
+                } else {
+                    CursorIcon::ResizeVertical
+                };
+            }
+            if response.dragged() {
+                if let Some(pointer) = ui.input().pointer.interact_pos() {
+                    let t = if horizontal {
+                        remap(pointer.x, rect.x_range(), 0.0..=1.0)
+                    } else {
+                        remap(pointer.y, rect.y_range(), 0.0..=1.0)
+                    };
+                    *fraction = t.clamp(0.1, 0.9);
+                }
+            }
+            let stroke = if response.hovered() || response.dragged() {
+                ui.visuals().widgets.hovered.bg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            ui.painter().line_segment(line, stroke);
+
+            node_ui(ui, first, first_rect, id.with(0), state, add_tab);
+            node_ui(ui, second, second_rect, id.with(1), state, add_tab);
+        }
+    }
+}
+
+fn tabs_ui(
+    ui: &mut Ui,
+    tabs: &[String],
+    active: &mut usize,
+    rect: Rect,
+    state: &mut ShowState,
+    add_tab: &mut dyn FnMut(&mut Ui, &str),
+) {
+    state.groups.push((rect, tabs.to_vec()));
+    if tabs.is_empty() {
+        return;
+    }
+
+    let bar_height = ui.spacing().interact_size.y;
+    let bar_rect = Rect::from_min_max(rect.min, pos2(rect.max.x, rect.min.y + bar_height));
+    let body_rect = Rect::from_min_max(pos2(rect.min.x, bar_rect.max.y), rect.max);
+
+    ui.painter()
+        .rect_filled(bar_rect, 0.0, ui.visuals().faint_bg_color);
+    let mut bar_ui = ui.child_ui(bar_rect, Layout::left_to_right());
+    bar_ui.set_clip_rect(bar_rect.intersect(ui.clip_rect()));
+    let mut x = bar_rect.min.x;
+    for (index, tab) in tabs.iter().enumerate() {
+        let selected = index == *active;
+        let closable = state.show_close_buttons;
+        let width = tabs::tab_width(&bar_ui, tab, closable);
+        let rect = Rect::from_min_size(pos2(x, bar_rect.min.y), vec2(width, bar_rect.height()));
+        x = rect.max.x;
+        let tab_id = state.id.with("tab").with(tab);
+        let (response, close) = tabs::tab_ui(&bar_ui, rect, tab_id, tab, selected, closable);
+        if response.clicked() || response.dragged() {
+            state.focused = Some(tab.clone());
+        }
+        state.check_drag(ui.ctx(), tab, &response);
+        if close {
+            state.closed.push(tab.clone());
+        }
+    }
+    *active = (*active).min(tabs.len() - 1);
+
+    if ui.input().pointer.any_pressed() && ui.rect_contains_pointer(body_rect) {
+        state.focused = Some(tabs[*active].clone());
+    }
+
+    // The id depends on the tab, so its state stays with it when it is moved:
+    let tab = &tabs[*active];
+    let mut body_ui = Ui::new(
+        ui.ctx().clone(),
+        ui.layer_id(),
+        state.id.with("body").with(tab),
+        body_rect.shrink(ui.spacing().window_padding.x),
+        body_rect.intersect(ui.clip_rect()),
+    );
+    body_ui.set_style(ui.style().clone());
+    body_ui.set_enabled(ui.enabled());
+    add_tab(&mut body_ui, tab);
+}
+
+/// Show where the dragged `tab` would go, and put it there if it was `released`.
+fn drag_and_drop(
+    ctx: &CtxRef,
+    tree: &mut DockTree,
+    state: &ShowState,
+    tab: String,
+    released: bool,
+    allow_floating: bool,
+) {
+    let pointer = match ctx.input().pointer.hover_pos() {
+        Some(pointer) => pointer,
+        None => return,
+    };
+    ctx.request_repaint();
+
+    let floating = tree.is_floating(&tab);
+    if !floating {
+        show_tooltip_text(ctx, state.id.with("dragged_tab"), &tab);
+    }
+
+    let group = state.groups.iter().find(|(rect, _)| rect.contains(pointer));
+    let (rect, tabs) = match group {
+        Some(group) => group,
+        None => {
+            if released && allow_floating && !floating {
+                tree.float_tab(tab, pointer);
+            }
+            return;
+        }
+    };
+
+    let position = DockPosition::at(*rect, pointer);
+    let target = tabs.iter().find(|t| **t != tab);
+    let is_own_group = tabs.contains(&tab);
+    let target = match target {
+        Some(_) if is_own_group && position == DockPosition::Center => return,
+        Some(target) => Some(target),
+        None if tree.root.is_none() => None, // the whole dock area is empty
+        None => return,                      // the only tab of its own group
+    };
+
+    let preview = if target.is_some() {
+        position.preview(*rect)
+    } else {
+        *rect
+    };
+    let layer_id = LayerId::new(Order::Foreground, state.id.with("drop_preview"));
+    let fill = ctx.style().visuals.selection.bg_fill.linear_multiply(0.5);
+    ctx.layer_painter(layer_id).rect_filled(preview, 2.0, fill);
+
+    if released {
+        match target {
+            Some(target) => {
+                tree.add_tab_at(tab, target, position);
+            }
+            None => {
+                tree.remove_tab(&tab);
+                tree.add_tab(tab);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dock_tree() {
+    let mut tree = DockTree::default();
+    tree.add_tab("A");
+    tree.add_tab("B");
+    assert_eq!(
+        tree.root(),
+        Some(&DockNode::Tabs {
+            tabs: vec!["A".to_owned(), "B".to_owned()],
+            active: 1,
+        })
+    );
+
+    assert!(tree.add_tab_at("C", "A", DockPosition::Left));
+    assert!(!tree.add_tab_at("D", "missing", DockPosition::Left));
+    match tree.root() {
+        Some(DockNode::Split {
+            horizontal: true,
+            first,
+            ..
+        }) => assert!(first.contains("C")),
+        root => panic!("expected a split, got {:?}", root),
+    }
+    assert_eq!(tree.focused_tab(), Some("C"));
+
+    // Moving the only tab of a group away removes the group and its split:
+    assert!(tree.add_tab_at("C", "B", DockPosition::Center));
+    assert_eq!(
+        tree.root(),
+        Some(&DockNode::Tabs {
+            tabs: vec!["A".to_owned(), "B".to_owned(), "C".to_owned()],
+            active: 2,
+        })
+    );
+
+    assert!(tree.focus_tab("A"));
+    tree.float_tab("B", pos2(10.0, 10.0));
+    assert!(tree.is_floating("B"));
+    assert_eq!(tree.tabs(), vec!["A", "C", "B"]);
+    assert_eq!(
+        tree.root(),
+        Some(&DockNode::Tabs {
+            tabs: vec!["A".to_owned(), "C".to_owned()],
+            active: 0,
+        })
+    );
+
+    assert!(tree.remove_tab("A"));
+    assert!(tree.remove_tab("C"));
+    assert!(!tree.remove_tab("C"));
+    assert_eq!(tree.root(), None);
+    assert_eq!(tree.tabs(), vec!["B"]);
+}
+
+#[test]
+fn test_dock_position() {
+    let rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+    assert_eq!(
+        DockPosition::at(rect, pos2(50.0, 50.0)),
+        DockPosition::Center
+    );
+    assert_eq!(DockPosition::at(rect, pos2(5.0, 50.0)), DockPosition::Left);
+    assert_eq!(
+        DockPosition::at(rect, pos2(95.0, 40.0)),
+        DockPosition::Right
+    );
+    assert_eq!(
+        DockPosition::at(rect, pos2(40.0, 95.0)),
+        DockPosition::Bottom
+    );
+    assert_eq!(
+        DockPosition::Top.preview(rect),
+        Rect::from_min_size(Pos2::ZERO, vec2(100.0, 50.0))
+    );
+}
+
+#[test]
+fn test_dock_area_drag_tab() {
+    use crate::test_util::{pointer_button, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut tree = DockTree::default();
+    tree.add_tab("A");
+    tree.add_tab_at("B", "A", DockPosition::Right);
+
+    let mut run_frame = |events: Vec<Event>, tree: &mut DockTree| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            DockArea::new("dock").show(ui, tree, |ui, tab| {
+                ui.label(tab);
+            })
+        })
+        .response
+        .rect
+    };
+
+    let rect = run_frame(vec![], &mut tree);
+    let tab_b = pos2(rect.center().x + 10.0, rect.min.y + 5.0);
+    let group_a = pos2(rect.min.x + 0.25 * rect.width(), rect.center().y);
+    run_frame(
+        vec![Event::PointerMoved(tab_b), pointer_button(tab_b, true)],
+        &mut tree,
+    );
+    run_frame(vec![Event::PointerMoved(group_a)], &mut tree);
+    run_frame(vec![pointer_button(group_a, false)], &mut tree);
+    assert_eq!(
+        tree.root(),
+        Some(&DockNode::Tabs {
+            tabs: vec!["A".to_owned(), "B".to_owned()],
+            active: 1,
+        })
+    );
+}
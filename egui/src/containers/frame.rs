@@ -103,6 +103,34 @@ impl Frame {
         self
     }
 
+    /// Tweak the look of this frame, starting from its current fill, stroke and corner radius.
+    ///
+    /// Only [`style::WidgetVisuals::bg_fill`], [`style::WidgetVisuals::bg_stroke`]
+    /// and [`style::WidgetVisuals::corner_radius`] are used by a frame.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// egui::Frame::group(ui.style())
+    ///     .style(|s| s.bg_fill = egui::Color32::from_rgb(60, 20, 20))
+    ///     .show(ui, |ui| {
+    ///         ui.label("Danger zone");
+    ///     });
+    /// ```
+    pub fn style(mut self, style: impl FnOnce(&mut style::WidgetVisuals)) -> Self {
+        let mut visuals = style::WidgetVisuals {
+            bg_fill: self.fill,
+            bg_stroke: self.stroke,
+            corner_radius: self.corner_radius,
+            fg_stroke: Stroke::none(),
+            expansion: 0.0,
+        };
+        style(&mut visuals);
+        self.fill = visuals.bg_fill;
+        self.stroke = visuals.bg_stroke;
+        self.corner_radius = visuals.corner_radius;
+        self
+    }
+
     pub fn multiply_with_opacity(mut self, opacity: f32) -> Self {
         self.fill = self.fill.linear_multiply(opacity);
         self.stroke.color = self.stroke.color.linear_multiply(opacity);
@@ -180,11 +208,14 @@ impl Prepared {
         let outer_rect = self.outer_rect();
 
         let Prepared {
-            frame,
+            mut frame,
             where_to_put_background,
             ..
         } = self;
 
+        if ui.ctx().degrade() {
+            frame.shadow = Default::default();
+        }
         let shape = frame.paint(outer_rect);
         ui.painter().set(where_to_put_background, shape);
         ui.allocate_rect(outer_rect, Sense::hover())
@@ -0,0 +1,231 @@
+//! A loupe that shows a zoomed-in view of what is under the mouse, see [`Magnifier`].
+
+use std::f32::consts::SQRT_2;
+
+use super::command_palette::shortcut_pressed;
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct State {
+    open: bool,
+}
+
+/// A round loupe next to the mouse cursor, showing what is under it zoomed in.
+///
+/// Useful for pixel-accurate work, like lining up things in a design tool.
+/// The loupe is opened (and closed) with a keyboard shortcut,
+/// <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>M</kbd> by default, or with [`Self::set_open`].
+///
+/// The magnifier shows the pixels that were painted on screen (see [`Context::read_screen_pixels`]),
+/// so it only works with integrations that can read those back.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// if let Some(color) = egui::Magnifier::new("magnifier").show(ctx) {
+///     // `color` is the color of the pixel under the mouse.
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct Magnifier {
+    id: Id,
+    shortcut: (Modifiers, Key),
+    zoom: f32,
+    radius: f32,
+}
+
+impl Magnifier {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            shortcut: (
+                Modifiers {
+                    command: true,
+                    shift: true,
+                    ..Default::default()
+                },
+                Key::M,
+            ),
+            zoom: 8.0,
+            radius: 80.0,
+        }
+    }
+
+    /// The keyboard shortcut that opens and closes the magnifier. Default: `command + shift + M`.
+    ///
+    /// Only [`Modifiers::command`], [`Modifiers::shift`] and [`Modifiers::alt`] are looked at.
+    pub fn shortcut(mut self, modifiers: Modifiers, key: Key) -> Self {
+        self.shortcut = (modifiers, key);
+        self
+    }
+
+    /// How many times bigger things are shown. Default: 8.
+    pub fn zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom.max(1.0);
+        self
+    }
+
+    /// The radius of the loupe, in points. Default: 80.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Open or close the magnifier with the given id source, e.g. from a menu button.
+    pub fn set_open(ctx: &Context, id_source: impl std::hash::Hash, open: bool) {
+        ctx.memory()
+            .id_data_temp
+            .insert(Id::new(id_source), State { open });
+    }
+
+    /// Is the magnifier with the given id source currently open?
+    pub fn is_open(ctx: &Context, id_source: impl std::hash::Hash) -> bool {
+        ctx.memory()
+            .id_data_temp
+            .get::<State>(&Id::new(id_source))
+            .map_or(false, |state| state.open)
+    }
+
+    /// Check for the shortcut, and show the magnifier if it is open.
+    ///
+    /// Returns the color of the pixel under the mouse, once it is known.
+    pub fn show(self, ctx: &CtxRef) -> Option<Color32> {
+        let Self {
+            id,
+            shortcut,
+            zoom,
+            radius,
+        } = self;
+
+        let mut state = ctx
+            .memory()
+            .id_data_temp
+            .get::<State>(&id)
+            .copied()
+            .unwrap_or_default();
+        if shortcut_pressed(ctx.input(), shortcut) {
+            state.open = !state.open;
+        }
+        ctx.memory().id_data_temp.insert(id, state);
+        if !state.open {
+            return None;
+        }
+
+        let pointer = ctx.input().pointer.hover_pos()?;
+        let screen_rect = ctx.input().screen_rect();
+        let half_size = radius / zoom;
+        let sample_rect = Rect::from_center_size(pointer, Vec2::splat(2.0 * half_size));
+        // Ask for a bit more, so the pixels are there right away when the mouse moves a little:
+        let _ = ctx.read_screen_pixels(sample_rect.expand(half_size));
+        let pixels = ctx.read_screen_pixels(sample_rect);
+
+        // Next to the mouse, where the loupe doesn't cover what it shows:
+        let offset = (radius + SQRT_2 * half_size + 8.0) / SQRT_2;
+        let mut center = pointer + Vec2::splat(offset);
+        if center.x + radius > screen_rect.right() {
+            center.x = pointer.x - offset;
+        }
+        if center.y + radius > screen_rect.bottom() {
+            center.y = pointer.y - offset;
+        }
+
+        let style = ctx.style();
+        let visuals = &style.visuals;
+        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, id));
+        painter.circle_filled(center, radius, visuals.extreme_bg_color);
+
+        let mut hovered = None;
+        if let Some(pixels) = pixels {
+            // Where the pixels are, in points:
+            let rect = sample_rect.intersect(screen_rect);
+            let pixel_size = rect.width() / pixels.width() as f32;
+            let mut mesh = epaint::Mesh::default();
+            for y in 0..pixels.height() {
+                for x in 0..pixels.width() {
+                    let min = rect.min + pixel_size * vec2(x as f32, y as f32);
+                    let pixel = Rect::from_min_size(
+                        center + zoom * (min - pointer),
+                        Vec2::splat(zoom * pixel_size),
+                    );
+                    if pixel.center().distance(center) < radius {
+                        mesh.add_colored_rect(pixel, pixels[(x, y)]);
+                    }
+                    if Rect::from_min_size(min, Vec2::splat(pixel_size)).contains(pointer) {
+                        hovered = Some((pixel, pixels[(x, y)]));
+                    }
+                }
+            }
+            painter.add(Shape::mesh(mesh));
+        }
+        if let Some((pixel, color)) = hovered {
+            painter.rect_stroke(pixel, 0.0, (1.0, contrast_color(color)));
+        }
+        painter.circle_stroke(center, radius, visuals.window_stroke());
+
+        let hovered_color = hovered.map(|(_, color)| color);
+        if let Some(color) = hovered_color {
+            let [r, g, b, _] = color.to_array();
+            let background = painter.add(Shape::Noop);
+            let text_rect = painter.text(
+                center + vec2(0.0, radius + 4.0),
+                Align2::CENTER_TOP,
+                format!("#{:02X}{:02X}{:02X}", r, g, b),
+                TextStyle::Monospace,
+                visuals.text_color(),
+            );
+            painter.set(
+                background,
+                Shape::rect_filled(text_rect.expand(2.0), 2.0, visuals.extreme_bg_color),
+            );
+        }
+
+        hovered_color
+    }
+}
+
+/// Black or white, whichever stands out more on `color`.
+fn contrast_color(color: Color32) -> Color32 {
+    if Rgba::from(color).intensity() > 0.2 {
+        Color32::BLACK
+    } else {
+        Color32::WHITE
+    }
+}
+
+#[test]
+fn test_magnifier() {
+    use crate::test_util::{key_with_modifiers, run_frame_with_input};
+    let mut ctx = CtxRef::default();
+    let pointer = pos2(100.0, 100.0);
+    let modifiers = Modifiers {
+        command: true,
+        shift: true,
+        ..Default::default()
+    };
+    let run_frame = |ctx: &mut CtxRef, shortcut: bool| {
+        let mut input = RawInput {
+            events: vec![Event::PointerMoved(pointer)],
+            ..Default::default()
+        };
+        if shortcut {
+            input.modifiers = modifiers;
+            input.events.push(key_with_modifiers(Key::M, modifiers));
+        }
+        run_frame_with_input(ctx, input, |ctx| Magnifier::new("magnifier").show(ctx)).0
+    };
+
+    run_frame(&mut ctx, true);
+    assert!(Magnifier::is_open(&ctx, "magnifier"));
+
+    // The integration reads back the screen, which is red under the pointer:
+    let screen_rect = Rect::from_center_size(pointer, Vec2::splat(100.0));
+    let mut image = ColorImage::new([100, 100], Color32::BLACK);
+    image[(50, 50)] = Color32::RED;
+    ctx.set_screen_pixels(screen_rect, image);
+    assert_eq!(run_frame(&mut ctx, false), Some(Color32::RED));
+
+    run_frame(&mut ctx, true);
+    assert!(!Magnifier::is_open(&ctx, "magnifier"));
+}
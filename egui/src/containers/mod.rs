@@ -5,23 +5,37 @@
 pub(crate) mod area;
 pub(crate) mod collapsing_header;
 mod combo_box;
+pub(crate) mod command_palette;
+pub(crate) mod dock;
 pub(crate) mod frame;
+pub(crate) mod magnifier;
 pub(crate) mod panel;
 pub mod popup;
 pub(crate) mod resize;
 pub(crate) mod scroll_area;
+pub(crate) mod splitter;
+pub(crate) mod tabs;
+pub(crate) mod tour;
 pub(crate) mod window;
+pub(crate) mod wizard;
 
 pub use {
     area::Area,
     collapsing_header::*,
     combo_box::*,
+    command_palette::CommandPalette,
+    dock::{DockArea, DockNode, DockPosition, DockResponse, DockTree},
     frame::Frame,
+    magnifier::Magnifier,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
     resize::Resize,
     scroll_area::ScrollArea,
+    splitter::{SplitSize, Splitter},
+    tabs::{Tabs, TabsResponse},
+    tour::{Tour, TourStep},
     window::Window,
+    wizard::{Wizard, WizardResponse},
 };
 
 #[allow(deprecated)]
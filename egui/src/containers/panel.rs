@@ -10,7 +10,7 @@
 
 use std::ops::RangeInclusive;
 
-use crate::*;
+use crate::{input_state::PointerEvent, *};
 
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
@@ -73,6 +73,7 @@ pub struct SidePanel {
     id: Id,
     frame: Option<Frame>,
     resizable: bool,
+    respect_safe_area: bool,
     default_width: f32,
     width_range: RangeInclusive<f32>,
 }
@@ -95,19 +96,34 @@ impl SidePanel {
             id: Id::new(id_source),
             frame: None,
             resizable: true,
+            respect_safe_area: true,
             default_width: 200.0,
             width_range: 96.0..=f32::INFINITY,
         }
     }
 
+    /// Keep the contents inside the [`InputState::safe_area_rect`],
+    /// so that they are not hidden by e.g. the notch of a phone.
+    /// The background of the panel still reaches the edges of the screen.
+    /// Default: `true`.
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
+
     /// Switch resizable on/off.
     /// Default is `true`.
+    ///
+    /// The user resizes the panel by dragging its inner edge, within [`Self::width_range`].
+    /// Double-clicking the edge resets the panel to [`Self::default_width`].
+    /// The width is remembered in [`Memory`].
     pub fn resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
         self
     }
 
-    /// The initial wrapping width of the `SidePanel`.
+    /// The initial wrapping width of the `SidePanel`,
+    /// and the width it goes back to when the user double-clicks its resize edge.
     pub fn default_width(mut self, default_width: f32) -> Self {
         self.default_width = default_width;
         self
@@ -141,12 +157,52 @@ impl SidePanel {
         self,
         ctx: &CtxRef,
         add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.show_expanded(ctx, 1.0, add_contents)
+    }
+
+    /// Show the panel if `is_expanded`, sliding it in from the side of the screen
+    /// (and back out) when that changes.
+    ///
+    /// Returns `None` while the panel is hidden.
+    /// See [`Style::animate_containers`].
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// # let show_inspector = true;
+    /// egui::SidePanel::right("inspector").show_animated(ctx, show_inspector, |ui| {
+    ///    ui.label("Inspector");
+    /// });
+    /// ```
+    pub fn show_animated<R>(
+        self,
+        ctx: &CtxRef,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = how_expanded(ctx, self.id, is_expanded);
+        if how_expanded <= 0.0 {
+            None
+        } else {
+            Some(self.show_expanded(ctx, how_expanded, add_contents))
+        }
+    }
+
+    /// `how_expanded` goes from `0.0` (hidden behind the side of the screen) to `1.0` (all shown).
+    fn show_expanded<R>(
+        self,
+        ctx: &CtxRef,
+        how_expanded: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let Self {
             side,
             id,
             frame,
             resizable,
+            respect_safe_area,
             default_width,
             width_range,
         } = self;
@@ -164,9 +220,12 @@ impl SidePanel {
             side.set_rect_width(&mut panel_rect, width);
         }
 
+        let is_animating = how_expanded < 1.0;
+        let can_resize = resizable && !is_animating;
+
         let mut resize_hover = false;
         let mut is_resizing = false;
-        if resizable {
+        if can_resize {
             let resize_id = id.with("__resize");
             if let Some(pointer) = ctx.input().pointer.latest_pos() {
                 let we_are_on_top = ctx
@@ -188,7 +247,13 @@ impl SidePanel {
                 is_resizing = ctx.memory().interaction.drag_id == Some(resize_id);
                 if is_resizing {
                     let width = (pointer.x - side.side_x(panel_rect)).abs();
-                    let width = clamp_to_range(width, width_range).at_most(available_rect.width());
+                    let width =
+                        clamp_to_range(width, width_range.clone()).at_most(available_rect.width());
+                    side.set_rect_width(&mut panel_rect, width);
+                }
+                if mouse_over_resize_line && double_clicked(ctx) {
+                    let width =
+                        clamp_to_range(default_width, width_range).at_most(available_rect.width());
                     side.set_rect_width(&mut panel_rect, width);
                 }
 
@@ -202,31 +267,39 @@ impl SidePanel {
             }
         }
 
-        let clip_rect = ctx.input().screen_rect();
+        let mut clip_rect = ctx.input().screen_rect();
+        if is_animating {
+            // Slide the panel in from the side, without covering any outer panels:
+            let hidden_width = (1.0 - how_expanded) * panel_rect.width();
+            let offset = match side {
+                Side::Left => -hidden_width,
+                Side::Right => hidden_width,
+            };
+            panel_rect = panel_rect.translate(vec2(offset, 0.0));
+            clip_rect = clip_rect.intersect(available_rect);
+        }
         let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, panel_rect, clip_rect);
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect_finite().height()); // Make sure the frame fills the full height
-            add_contents(ui)
+            if resizable {
+                // Keep the width the user picked, even if the contents are narrower
+                ui.set_min_width(ui.max_rect_finite().width());
+            }
+            show_in_safe_area(ui, respect_safe_area, add_contents)
         });
 
         let rect = inner_response.response.rect;
-        ctx.memory().id_data.insert(id, PanelState { rect });
+        if !is_animating {
+            ctx.memory().id_data.insert(id, PanelState { rect });
+        }
 
-        if resize_hover || is_resizing {
-            let stroke = if is_resizing {
-                ctx.style().visuals.widgets.active.bg_stroke
-            } else {
-                ctx.style().visuals.widgets.hovered.bg_stroke
-            };
-            // draw on top of ALL panels so that the resize line won't be covered by subsequent panels
-            let resize_layer = LayerId::new(Order::PanelResizeLine, Id::new("panel_resize"));
+        if can_resize {
             let resize_x = side.opposite().side_x(rect);
             let top = pos2(resize_x, rect.top());
             let bottom = pos2(resize_x, rect.bottom());
-            ctx.layer_painter(resize_layer)
-                .line_segment([top, bottom], stroke);
+            paint_resize_line(ctx, [top, bottom], resize_hover, is_resizing);
         }
 
         match side {
@@ -297,6 +370,7 @@ pub struct TopBottomPanel {
     id: Id,
     frame: Option<Frame>,
     resizable: bool,
+    respect_safe_area: bool,
     default_height: Option<f32>,
     height_range: RangeInclusive<f32>,
 }
@@ -319,19 +393,34 @@ impl TopBottomPanel {
             id: Id::new(id_source),
             frame: None,
             resizable: false,
+            respect_safe_area: true,
             default_height: None,
             height_range: 20.0..=f32::INFINITY,
         }
     }
 
+    /// Keep the contents inside the [`InputState::safe_area_rect`],
+    /// so that they are not hidden by e.g. the notch of a phone.
+    /// The background of the panel still reaches the edges of the screen.
+    /// Default: `true`.
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
+
     /// Switch resizable on/off.
     /// Default is `false`.
+    ///
+    /// The user resizes the panel by dragging its inner edge, within [`Self::height_range`].
+    /// Double-clicking the edge resets the panel to [`Self::default_height`].
+    /// The height is remembered in [`Memory`].
     pub fn resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
         self
     }
 
-    /// The initial height of the `SidePanel`.
+    /// The initial height of the `TopBottomPanel`,
+    /// and the height it goes back to when the user double-clicks its resize edge.
     /// Defaults to [`style::Spacing::interact_size`].y.
     pub fn default_height(mut self, default_height: f32) -> Self {
         self.default_height = Some(default_height);
@@ -366,12 +455,42 @@ impl TopBottomPanel {
         self,
         ctx: &CtxRef,
         add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.show_expanded(ctx, 1.0, add_contents)
+    }
+
+    /// Show the panel if `is_expanded`, sliding it in from the top or bottom of the screen
+    /// (and back out) when that changes.
+    ///
+    /// Returns `None` while the panel is hidden.
+    /// See [`Style::animate_containers`].
+    pub fn show_animated<R>(
+        self,
+        ctx: &CtxRef,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = how_expanded(ctx, self.id, is_expanded);
+        if how_expanded <= 0.0 {
+            None
+        } else {
+            Some(self.show_expanded(ctx, how_expanded, add_contents))
+        }
+    }
+
+    /// `how_expanded` goes from `0.0` (hidden behind the edge of the screen) to `1.0` (all shown).
+    fn show_expanded<R>(
+        self,
+        ctx: &CtxRef,
+        how_expanded: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let Self {
             side,
             id,
             frame,
             resizable,
+            respect_safe_area,
             default_height,
             height_range,
         } = self;
@@ -391,9 +510,12 @@ impl TopBottomPanel {
             side.set_rect_height(&mut panel_rect, height);
         }
 
+        let is_animating = how_expanded < 1.0;
+        let can_resize = resizable && !is_animating;
+
         let mut resize_hover = false;
         let mut is_resizing = false;
-        if resizable {
+        if can_resize {
             let resize_id = id.with("__resize");
             if let Some(pointer) = ctx.input().pointer.latest_pos() {
                 let we_are_on_top = ctx
@@ -415,6 +537,13 @@ impl TopBottomPanel {
                 is_resizing = ctx.memory().interaction.drag_id == Some(resize_id);
                 if is_resizing {
                     let height = (pointer.y - side.side_y(panel_rect)).abs();
+                    let height = clamp_to_range(height, height_range.clone())
+                        .at_most(available_rect.height());
+                    side.set_rect_height(&mut panel_rect, height);
+                }
+                if mouse_over_resize_line && double_clicked(ctx) {
+                    let height =
+                        default_height.unwrap_or_else(|| ctx.style().spacing.interact_size.y);
                     let height =
                         clamp_to_range(height, height_range).at_most(available_rect.height());
                     side.set_rect_height(&mut panel_rect, height);
@@ -430,31 +559,39 @@ impl TopBottomPanel {
             }
         }
 
-        let clip_rect = ctx.input().screen_rect();
+        let mut clip_rect = ctx.input().screen_rect();
+        if is_animating {
+            // Slide the panel in from the edge, without covering any outer panels:
+            let hidden_height = (1.0 - how_expanded) * panel_rect.height();
+            let offset = match side {
+                TopBottomSide::Top => -hidden_height,
+                TopBottomSide::Bottom => hidden_height,
+            };
+            panel_rect = panel_rect.translate(vec2(0.0, offset));
+            clip_rect = clip_rect.intersect(available_rect);
+        }
         let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, panel_rect, clip_rect);
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect_finite().width()); // Make the frame fill full width
-            add_contents(ui)
+            if resizable {
+                // Keep the height the user picked, even if the contents are shorter
+                ui.set_min_height(ui.max_rect_finite().height());
+            }
+            show_in_safe_area(ui, respect_safe_area, add_contents)
         });
 
         let rect = inner_response.response.rect;
-        ctx.memory().id_data.insert(id, PanelState { rect });
+        if !is_animating {
+            ctx.memory().id_data.insert(id, PanelState { rect });
+        }
 
-        if resize_hover || is_resizing {
-            let stroke = if is_resizing {
-                ctx.style().visuals.widgets.active.bg_stroke
-            } else {
-                ctx.style().visuals.widgets.hovered.bg_stroke
-            };
-            // draw on top of ALL panels so that the resize line won't be covered by subsequent panels
-            let resize_layer = LayerId::new(Order::PanelResizeLine, Id::new("panel_resize"));
+        if can_resize {
             let resize_y = side.opposite().side_y(rect);
             let left = pos2(rect.left(), resize_y);
             let right = pos2(rect.right(), resize_y);
-            ctx.layer_painter(resize_layer)
-                .line_segment([left, right], stroke);
+            paint_resize_line(ctx, [left, right], resize_hover, is_resizing);
         }
 
         match side {
@@ -502,9 +639,18 @@ impl TopPanel {
 /// });
 /// ```
 #[must_use = "You should call .show()"]
-#[derive(Default)]
 pub struct CentralPanel {
     frame: Option<Frame>,
+    respect_safe_area: bool,
+}
+
+impl Default for CentralPanel {
+    fn default() -> Self {
+        Self {
+            frame: None,
+            respect_safe_area: true,
+        }
+    }
 }
 
 impl CentralPanel {
@@ -513,6 +659,15 @@ impl CentralPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Keep the contents inside the [`InputState::safe_area_rect`],
+    /// so that they are not hidden by e.g. the notch of a phone.
+    /// The background of the panel still reaches the edges of the screen.
+    /// Default: `true`.
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
 }
 
 impl CentralPanel {
@@ -521,7 +676,10 @@ impl CentralPanel {
         ctx: &CtxRef,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        let Self { frame } = self;
+        let Self {
+            frame,
+            respect_safe_area,
+        } = self;
 
         let panel_rect = ctx.available_rect();
 
@@ -534,7 +692,7 @@ impl CentralPanel {
         let frame = frame.unwrap_or_else(|| Frame::central_panel(&ctx.style()));
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.expand_to_include_rect(ui.max_rect()); // Expand frame to include it all
-            add_contents(ui)
+            show_in_safe_area(ui, respect_safe_area, add_contents)
         });
 
         // Only inform ctx about what we actually used, so we can shrink the native window to fit.
@@ -545,9 +703,189 @@ impl CentralPanel {
     }
 }
 
+/// Add the contents of a panel inside the safe area of the screen,
+/// while the frame of the panel still goes all the way to the edges.
+fn show_in_safe_area<R>(
+    ui: &mut Ui,
+    respect_safe_area: bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    let max_rect = ui.max_rect();
+    let safe_rect = ui.input().safe_area_rect();
+    let mut rect = Rect::from_min_max(
+        max_rect.min.max(safe_rect.min),
+        max_rect.max.min(safe_rect.max),
+    );
+    rect.max = rect.max.max(rect.min);
+    if !respect_safe_area || rect == max_rect {
+        add_contents(ui)
+    } else {
+        ui.allocate_ui_at_rect(rect, add_contents).inner
+    }
+}
+
+/// How much of an animated panel to show, from `0.0` (hidden) to `1.0` (all shown).
+fn how_expanded(ctx: &CtxRef, id: Id, is_expanded: bool) -> f32 {
+    let style = ctx.style();
+    if style.animate_containers && !style.reduce_motion {
+        ctx.animate_bool(id.with("__animation"), is_expanded)
+    } else if is_expanded {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Paint the line that resizes a panel when it is hovered or dragged,
+/// and a grip in the middle of it so the user can see that the panel is resizable.
+fn paint_resize_line(ctx: &CtxRef, line: [Pos2; 2], resize_hover: bool, is_resizing: bool) {
+    let visuals = &ctx.style().visuals;
+    let stroke = if is_resizing {
+        visuals.widgets.active.bg_stroke
+    } else if resize_hover {
+        visuals.widgets.hovered.bg_stroke
+    } else {
+        visuals.widgets.noninteractive.bg_stroke
+    };
+
+    // draw on top of ALL panels so that the resize line won't be covered by subsequent panels
+    let resize_layer = LayerId::new(Order::PanelResizeLine, Id::new("panel_resize"));
+    let painter = ctx.layer_painter(resize_layer);
+    if resize_hover || is_resizing {
+        painter.line_segment(line, stroke);
+    }
+
+    let [a, b] = line;
+    let center = a + 0.5 * (b - a);
+    let direction = (b - a).normalized();
+    let grip_length = 24.0_f32.min((b - a).length());
+    let grip = [
+        center - 0.5 * grip_length * direction,
+        center + 0.5 * grip_length * direction,
+    ];
+    painter.line_segment(grip, Stroke::new(3.0, stroke.color));
+}
+
+/// Was the primary mouse button double-clicked this frame?
+fn double_clicked(ctx: &CtxRef) -> bool {
+    ctx.input().pointer.pointer_events.iter().any(|event| {
+        matches!(event, PointerEvent::Released(Some(click))
+            if click.button == PointerButton::Primary && click.is_double())
+    })
+}
+
 fn clamp_to_range(x: f32, range: RangeInclusive<f32>) -> f32 {
     x.clamp(
         range.start().min(*range.end()),
         range.start().max(*range.end()),
     )
 }
+
+#[test]
+fn test_panels_respect_safe_area() {
+    use crate::test_util::run_frame_with_input;
+    let mut ctx = CtxRef::default();
+    let input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 800.0))),
+        safe_area_insets: SafeAreaInsets {
+            top: 40.0,
+            bottom: 20.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    run_frame_with_input(&mut ctx, input, |ctx| {
+        let top = TopBottomPanel::top("top").show(ctx, |ui| ui.label("top").rect);
+        assert_eq!(top.response.rect.top(), 0.0, "The frame goes to the edge");
+        assert!(top.inner.top() >= 40.0, "The contents avoid the notch");
+
+        let central = CentralPanel::default().show(ctx, |ui| ui.max_rect());
+        assert!(central.inner.bottom() <= 780.0);
+
+        let central = CentralPanel::default()
+            .respect_safe_area(false)
+            .show(ctx, |ui| ui.max_rect());
+        assert!(central.inner.bottom() > 780.0);
+    });
+}
+
+#[test]
+fn test_resize_side_panel() {
+    use crate::test_util::{pointer_button as button, run_frame_with_input};
+    let mut ctx = CtxRef::default();
+    let mut width = |events: Vec<Event>, time: f64| {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        let (rect, _) = run_frame_with_input(&mut ctx, input, |ctx| {
+            SidePanel::left("left")
+                .default_width(200.0)
+                .width_range(100.0..=300.0)
+                .show(ctx, |_ui| {})
+                .response
+                .rect
+        });
+        rect.width()
+    };
+
+    assert_eq!(width(vec![], 0.0), 200.0);
+    let edge = pos2(200.0, 300.0);
+    width(vec![Event::PointerMoved(edge), button(edge, true)], 1.0);
+    assert_eq!(
+        width(vec![Event::PointerMoved(pos2(260.0, 300.0))], 2.0),
+        260.0
+    );
+    let far = pos2(500.0, 300.0);
+    assert_eq!(
+        width(vec![Event::PointerMoved(far)], 3.0),
+        300.0,
+        "max width"
+    );
+    width(vec![button(far, false)], 4.0);
+    assert_eq!(width(vec![], 5.0), 300.0, "the width is remembered");
+
+    let edge = pos2(300.0, 300.0);
+    width(vec![Event::PointerMoved(edge), button(edge, true)], 6.0);
+    width(vec![button(edge, false)], 6.1);
+    width(vec![button(edge, true)], 6.2);
+    assert_eq!(
+        width(vec![button(edge, false)], 6.3),
+        200.0,
+        "double-click resets"
+    );
+}
+
+#[test]
+fn test_side_panel_show_animated() {
+    use crate::test_util::run_frame_with_input;
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |is_expanded: bool, time: f64| {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+            time: Some(time),
+            ..Default::default()
+        };
+        let (result, _) = run_frame_with_input(&mut ctx, input, |ctx| {
+            let shown = SidePanel::left("left")
+                .default_width(200.0)
+                .show_animated(ctx, is_expanded, |_ui| {})
+                .is_some();
+            (shown, ctx.available_rect().left())
+        });
+        result
+    };
+
+    assert_eq!(run_frame(false, 0.0), (false, 0.0));
+    let (shown, central_left) = run_frame(true, 1.0);
+    assert!(shown && 0.0 < central_left && central_left < 200.0);
+    let (_, sliding_left) = run_frame(true, 1.04);
+    assert!(central_left < sliding_left && sliding_left < 200.0);
+    assert_eq!(run_frame(true, 2.0), (true, 200.0));
+
+    let (shown, central_left) = run_frame(false, 3.0);
+    assert!(shown && 0.0 < central_left && central_left < 200.0);
+    assert_eq!(run_frame(false, 4.0), (false, 0.0));
+}
@@ -9,11 +9,21 @@ pub(crate) struct State {
 
     show_scroll: bool,
 
+    /// Like `show_scroll`, for the horizontal scroll bar.
+    show_horizontal_scroll: bool,
+
     /// Momentum, used for kinetic scrolling
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub vel: Vec2,
     /// Mouse offset relative to the top of the handle when started moving the handle.
     scroll_start_offset_from_top: Option<f32>,
+    /// Mouse offset relative to the left of the horizontal handle when started moving it.
+    scroll_start_offset_from_left: Option<f32>,
+
+    /// When did we last scroll? Used to fade out an auto-hiding scroll bar.
+    /// `None` if not since the state was created or loaded.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    last_scroll_time: Option<f64>,
 }
 
 impl Default for State {
@@ -21,19 +31,28 @@ impl Default for State {
         Self {
             offset: Vec2::ZERO,
             show_scroll: false,
+            show_horizontal_scroll: false,
             vel: Vec2::ZERO,
             scroll_start_offset_from_top: None,
+            scroll_start_offset_from_left: None,
+            last_scroll_time: None,
         }
     }
 }
 
 // TODO: rename VScroll
-/// Add vertical scrolling to a contained [`Ui`].
+/// Add vertical scrolling to a contained [`Ui`],
+/// and horizontal scrolling too with [`Self::horizontal_scroll`].
 #[derive(Clone, Debug)]
 #[must_use = "You should call .show()"]
 pub struct ScrollArea {
     max_height: f32,
+    min_height: f32,
+    max_width: f32,
+    min_width: f32,
     always_show_scroll: bool,
+    auto_hide_scroll: Option<f32>,
+    horizontal_scroll: bool,
     id_source: Option<Id>,
     offset: Option<Vec2>,
     scrolling_enabled: bool,
@@ -49,13 +68,40 @@ impl ScrollArea {
     pub fn from_max_height(max_height: f32) -> Self {
         Self {
             max_height,
+            min_height: 0.0,
+            max_width: f32::INFINITY,
+            min_width: 0.0,
             always_show_scroll: false,
+            auto_hide_scroll: None,
+            horizontal_scroll: false,
             id_source: None,
             offset: None,
             scrolling_enabled: true,
         }
     }
 
+    /// The scroll area will never be shorter than this, even if the contents is shorter or empty.
+    ///
+    /// Use this to avoid the layout jumping around when contents arrive. Default: `0.0`.
+    pub fn min_height(mut self, min_height: f32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// The scroll area will never be wider than this, including the scroll bar.
+    /// Default: `f32::INFINITY`, i.e. fill the available width.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// The scroll area will never be narrower than this, including the scroll bar,
+    /// even if the contents is narrower or empty. Default: `0.0`.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
     /// If `false` (default), the scroll bar will be hidden when not needed/
     /// If `true`, the scroll bar will always be displayed even if not needed.
     pub fn always_show_scroll(mut self, always_show_scroll: bool) -> Self {
@@ -63,6 +109,28 @@ impl ScrollArea {
         self
     }
 
+    /// Paint the scroll bar on top of the contents instead of next to it,
+    /// and fade it out after `hide_delay` seconds without any scrolling.
+    ///
+    /// The scroll bar fades back in when the user scrolls, drags the contents,
+    /// or hovers the area where the scroll bar is.
+    ///
+    /// This means the scroll bar never takes any space away from the contents.
+    pub fn auto_hide_scroll(mut self, hide_delay: f32) -> Self {
+        self.auto_hide_scroll = Some(hide_delay);
+        self
+    }
+
+    /// Also scroll horizontally, with a scroll bar at the bottom,
+    /// when the contents is wider than the scroll area. Default: `false`.
+    ///
+    /// The contents then gets an infinite width to lay itself out in,
+    /// so give it a width with e.g. [`Ui::set_width`] if it should fill the area.
+    pub fn horizontal_scroll(mut self, horizontal_scroll: bool) -> Self {
+        self.horizontal_scroll = horizontal_scroll;
+        self
+    }
+
     /// A source for the unique `Id`, e.g. `.id_source("second_scroll_area")` or `.id_source(loop_index)`.
     pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
         self.id_source = Some(Id::new(id_source));
@@ -94,7 +162,13 @@ struct Prepared {
     id: Id,
     state: State,
     current_scroll_bar_width: f32,
+    /// The height of the horizontal scroll bar, like `current_scroll_bar_width`.
+    current_scroll_bar_height: f32,
     always_show_scroll: bool,
+    auto_hide_scroll: Option<f32>,
+    horizontal_scroll: bool,
+    min_size: Vec2,
+    max_width: f32,
     inner_rect: Rect,
     content_ui: Ui,
     /// Relative coordinates: the offset and size of the view of the inner UI.
@@ -107,7 +181,12 @@ impl ScrollArea {
     fn begin(self, ui: &mut Ui) -> Prepared {
         let Self {
             max_height,
+            min_height,
+            max_width,
+            min_width,
             always_show_scroll,
+            auto_hide_scroll,
+            horizontal_scroll,
             id_source,
             offset,
             scrolling_enabled,
@@ -129,26 +208,48 @@ impl ScrollArea {
 
         let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui);
 
-        let current_scroll_bar_width = if always_show_scroll {
+        let current_scroll_bar_width = if auto_hide_scroll.is_some() {
+            0.0 // the scroll bar is painted on top of the contents
+        } else if always_show_scroll {
             max_scroll_bar_width
         } else {
             max_scroll_bar_width * ui.ctx().animate_bool(id, state.show_scroll)
         };
+        let current_scroll_bar_height = if !horizontal_scroll || auto_hide_scroll.is_some() {
+            0.0
+        } else if always_show_scroll {
+            max_scroll_bar_width
+        } else {
+            max_scroll_bar_width
+                * ui.ctx()
+                    .animate_bool(id.with("horizontal"), state.show_horizontal_scroll)
+        };
 
         let available_outer = ui.available_rect_before_wrap();
 
         let outer_size = vec2(
-            available_outer.width(),
-            available_outer.height().at_most(max_height),
+            available_outer
+                .width()
+                .at_most(max_width)
+                .at_least(min_width),
+            available_outer
+                .height()
+                .at_most(max_height)
+                .at_least(min_height),
         );
 
-        let inner_size = outer_size - vec2(current_scroll_bar_width, 0.0);
+        let inner_size = outer_size - vec2(current_scroll_bar_width, current_scroll_bar_height);
         let inner_rect = Rect::from_min_size(available_outer.min, inner_size);
 
+        let content_max_width = if horizontal_scroll {
+            f32::INFINITY
+        } else {
+            inner_size.x
+        };
         let mut content_ui = ui.child_ui(
             Rect::from_min_size(
                 inner_rect.min - state.offset,
-                vec2(inner_size.x, f32::INFINITY),
+                vec2(content_max_width, f32::INFINITY),
             ),
             *ui.layout(),
         );
@@ -163,7 +264,12 @@ impl ScrollArea {
             id,
             state,
             current_scroll_bar_width,
+            current_scroll_bar_height,
             always_show_scroll,
+            auto_hide_scroll,
+            horizontal_scroll,
+            min_size: vec2(min_width, min_height),
+            max_width,
             inner_rect,
             content_ui,
             viewport,
@@ -174,6 +280,7 @@ impl ScrollArea {
     /// Show the `ScrollArea`, and add the contents to the viewport.
     ///
     /// If the inner area can be very long, consider using [`Self::show_rows`] instead.
+    /// For large grids, see [`Self::show_cells`].
     pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
         self.show_viewport(ui, |ui, _viewport| add_contents(ui))
     }
@@ -223,6 +330,90 @@ impl ScrollArea {
         })
     }
 
+    /// Efficiently show only the visible cells of a large grid of same-sized cells,
+    /// e.g. a spreadsheet or a tile map.
+    ///
+    /// Both rows and columns outside of the viewport are skipped.
+    /// This turns on [`Self::horizontal_scroll`], so the user can scroll to all the columns.
+    /// `add_contents` is given the ranges of visible rows and columns,
+    /// and a [`Ui`] whose top left corner is at the first visible cell.
+    ///
+    /// Like with [`Self::show_rows`], the auto-ids of the [`Ui`] are skipped ahead one per hidden row.
+    /// If you add one row [`Ui`] per row, skip ahead `col_range.start` auto-ids in it
+    /// to get consistent ids for the cells too:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let cell_size = egui::vec2(60.0, ui.spacing().interact_size.y);
+    /// let (num_rows, num_cols) = (10_000, 1_000);
+    /// egui::ScrollArea::auto_sized().show_cells(
+    ///     ui,
+    ///     cell_size,
+    ///     num_rows,
+    ///     num_cols,
+    ///     |ui, row_range, col_range| {
+    ///         for row in row_range {
+    ///             ui.horizontal(|ui| {
+    ///                 ui.skip_ahead_auto_ids(col_range.start); // Make sure we get consistent IDs.
+    ///                 for col in col_range.clone() {
+    ///                     ui.add_sized(cell_size, egui::Label::new(format!("{}:{}", row, col)));
+    ///                 }
+    ///             });
+    ///         }
+    ///     },
+    /// );
+    /// ```
+    pub fn show_cells<R>(
+        self,
+        ui: &mut Ui,
+        cell_size_sans_spacing: Vec2,
+        num_rows: usize,
+        num_cols: usize,
+        add_contents: impl FnOnce(&mut Ui, std::ops::Range<usize>, std::ops::Range<usize>) -> R,
+    ) -> R {
+        let spacing = ui.spacing().item_spacing;
+        let cell_size_with_spacing = cell_size_sans_spacing + spacing;
+        let scroll_area = self.horizontal_scroll(true);
+        scroll_area.show_viewport(ui, |ui, viewport| {
+            ui.set_width((cell_size_with_spacing.x * num_cols as f32 - spacing.x).at_least(0.0));
+            ui.set_height((cell_size_with_spacing.y * num_rows as f32 - spacing.y).at_least(0.0));
+
+            let visible_range = |min: f32, max: f32, cell_size: f32, count: usize| {
+                let first = (min / cell_size).floor().at_least(0.0) as usize;
+                let last = (max / cell_size).ceil() as usize + 1;
+                first.at_most(count)..last.at_most(count)
+            };
+            let row_range = visible_range(
+                viewport.min.y,
+                viewport.max.y,
+                cell_size_with_spacing.y,
+                num_rows,
+            );
+            let col_range = visible_range(
+                viewport.min.x,
+                viewport.max.x,
+                cell_size_with_spacing.x,
+                num_cols,
+            );
+
+            let min = ui.max_rect().min
+                + vec2(
+                    col_range.start as f32 * cell_size_with_spacing.x,
+                    row_range.start as f32 * cell_size_with_spacing.y,
+                );
+            let max = ui.max_rect().min
+                + vec2(
+                    col_range.end as f32 * cell_size_with_spacing.x,
+                    row_range.end as f32 * cell_size_with_spacing.y,
+                );
+            let mut viewport_ui = ui.child_ui(Rect::from_min_max(min, max), *ui.layout());
+
+            viewport_ui.skip_ahead_auto_ids(row_range.start); // Make sure we get consistent IDs.
+
+            add_contents(&mut viewport_ui, row_range, col_range)
+        })
+    }
+
     /// This can be used to only paint the visible part of the contents.
     ///
     /// `add_contents` is past the viewport, which is the relative view of the content.
@@ -242,13 +433,19 @@ impl Prepared {
             mut state,
             inner_rect,
             always_show_scroll,
+            auto_hide_scroll,
+            horizontal_scroll,
+            min_size,
+            max_width,
             mut current_scroll_bar_width,
+            mut current_scroll_bar_height,
             content_ui,
             viewport: _,
             scrolling_enabled,
         } = self;
 
         let content_size = content_ui.min_size();
+        let offset_at_frame_start = state.offset;
 
         // We take the scroll target so only this ScrollArea will use it.
         let scroll_target = content_ui.ctx().frame_state().scroll_target.take();
@@ -269,11 +466,18 @@ impl Prepared {
 
         let inner_rect = {
             let width = if inner_rect.width().is_finite() {
-                inner_rect.width().max(content_size.x) // Expand width to fit content
+                if horizontal_scroll {
+                    inner_rect.width() // Scroll to see the rest of the content
+                } else {
+                    inner_rect.width().max(content_size.x) // Expand width to fit content
+                }
             } else {
                 // ScrollArea is in an infinitely wide parent
                 content_size.x
             };
+            let width = width
+                .at_most(max_width - current_scroll_bar_width)
+                .at_least(min_size.x - current_scroll_bar_width);
 
             let mut inner_rect =
                 Rect::from_min_size(inner_rect.min, vec2(width, inner_rect.height()));
@@ -283,31 +487,44 @@ impl Prepared {
                 - current_scroll_bar_width
                 - ui.spacing().item_spacing.x;
             inner_rect.max.x = inner_rect.max.x.at_most(max_x);
-            // TODO: when we support it, we should maybe auto-enable
-            // horizontal scrolling if this limit is reached
+            // TODO: maybe auto-enable horizontal scrolling if this limit is reached
 
             inner_rect
         };
 
         let outer_rect = Rect::from_min_size(
             inner_rect.min,
-            inner_rect.size() + vec2(current_scroll_bar_width, 0.0),
+            inner_rect.size() + vec2(current_scroll_bar_width, current_scroll_bar_height),
         );
 
         let content_is_too_small = content_size.y > inner_rect.height();
+        let content_is_too_wide = horizontal_scroll && content_size.x > inner_rect.width();
 
-        if content_is_too_small {
+        if content_is_too_small || content_is_too_wide {
             // Drag contents to scroll (for touch screens mostly):
             let sense = if self.scrolling_enabled {
                 Sense::drag()
             } else {
                 Sense::hover()
             };
-            let content_response = ui.interact(inner_rect, id.with("area"), sense);
+            let mut content_drag_rect = inner_rect;
+            if auto_hide_scroll.is_some() {
+                // Leave room for grabbing the scroll bars painted on top of the contents:
+                content_drag_rect.max.x -= ui.spacing().scroll_bar_width;
+                if horizontal_scroll {
+                    content_drag_rect.max.y -= ui.spacing().scroll_bar_width;
+                }
+            }
+            let content_response = ui.interact(content_drag_rect, id.with("area"), sense);
 
             let input = ui.input();
             if content_response.dragged() {
-                state.offset.y -= input.pointer.delta().y;
+                if content_is_too_small {
+                    state.offset.y -= input.pointer.delta().y;
+                }
+                if content_is_too_wide {
+                    state.offset.x -= input.pointer.delta().x;
+                }
                 state.vel = input.pointer.velocity();
             } else {
                 let stop_speed = 20.0; // Pixels per second.
@@ -321,13 +538,19 @@ impl Prepared {
                     state.vel -= friction * state.vel.normalized();
                     // Offset has an inverted coordinate system compared to
                     // the velocity, so we subtract it instead of adding it
-                    state.offset.y -= state.vel.y * dt;
+                    if content_is_too_small {
+                        state.offset.y -= state.vel.y * dt;
+                    }
+                    if content_is_too_wide {
+                        state.offset.x -= state.vel.x * dt;
+                    }
                     ui.ctx().request_repaint();
                 }
             }
         }
 
         let max_offset = content_size.y - inner_rect.height();
+        let max_offset_x = content_size.x - inner_rect.width();
         if scrolling_enabled && ui.rect_contains_pointer(outer_rect) {
             let mut frame_state = ui.ctx().frame_state();
             let scroll_delta = frame_state.scroll_delta;
@@ -338,25 +561,97 @@ impl Prepared {
             if scrolling_up || scrolling_down {
                 state.offset.y -= scroll_delta.y;
                 // Clear scroll delta so no parent scroll will use it.
-                frame_state.scroll_delta = Vec2::ZERO;
+                frame_state.scroll_delta.y = 0.0;
+            }
+
+            if horizontal_scroll {
+                let scrolling_left = state.offset.x > 0.0 && scroll_delta.x > 0.0;
+                let scrolling_right = state.offset.x < max_offset_x && scroll_delta.x < 0.0;
+
+                if scrolling_left || scrolling_right {
+                    state.offset.x -= scroll_delta.x;
+                    frame_state.scroll_delta.x = 0.0;
+                }
             }
         }
 
         let show_scroll_this_frame = content_is_too_small || always_show_scroll;
+        let show_horizontal_scroll_this_frame =
+            content_is_too_wide || (horizontal_scroll && always_show_scroll);
 
         let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui);
 
-        if show_scroll_this_frame && current_scroll_bar_width <= 0.0 {
-            // Avoid frame delay; start showing scroll bar right away:
-            current_scroll_bar_width = max_scroll_bar_width * ui.ctx().animate_bool(id, true);
+        if state.offset != offset_at_frame_start {
+            state.last_scroll_time = Some(ui.input().time);
+        }
+
+        // How opaque the scroll bar is. Only ever below one for auto-hiding scroll bars.
+        let mut scroll_bar_opacity = 1.0;
+
+        if let Some(hide_delay) = auto_hide_scroll {
+            let overlay_rect = Rect::from_min_max(
+                pos2(
+                    inner_rect.right() - ui.spacing().scroll_bar_width,
+                    inner_rect.top(),
+                ),
+                inner_rect.right_bottom(),
+            );
+            let horizontal_overlay_rect = Rect::from_min_max(
+                pos2(
+                    inner_rect.left(),
+                    inner_rect.bottom() - ui.spacing().scroll_bar_width,
+                ),
+                inner_rect.right_bottom(),
+            );
+            let time = ui.input().time;
+            let recently_scrolled = state.last_scroll_time.map_or(false, |last_scroll_time| {
+                time - last_scroll_time < hide_delay as f64
+            });
+            let is_dragging_handle = state.scroll_start_offset_from_top.is_some()
+                || state.scroll_start_offset_from_left.is_some();
+            let show = (show_scroll_this_frame && ui.rect_contains_pointer(overlay_rect))
+                || (show_horizontal_scroll_this_frame
+                    && ui.rect_contains_pointer(horizontal_overlay_rect))
+                || ((show_scroll_this_frame || show_horizontal_scroll_this_frame)
+                    && (recently_scrolled || is_dragging_handle));
+            if recently_scrolled {
+                ui.ctx().request_repaint(); // so we notice when it is time to fade out
+            }
+            scroll_bar_opacity = ui.ctx().animate_bool(id, show);
+        } else {
+            if show_scroll_this_frame && current_scroll_bar_width <= 0.0 {
+                // Avoid frame delay; start showing scroll bar right away:
+                current_scroll_bar_width = max_scroll_bar_width * ui.ctx().animate_bool(id, true);
+            }
+            if show_horizontal_scroll_this_frame && current_scroll_bar_height <= 0.0 {
+                current_scroll_bar_height =
+                    max_scroll_bar_width * ui.ctx().animate_bool(id.with("horizontal"), true);
+            }
         }
 
-        if current_scroll_bar_width > 0.0 {
-            let animation_t = current_scroll_bar_width / max_scroll_bar_width;
-            // margin between contents and scroll bar
-            let margin = animation_t * ui.spacing().item_spacing.x;
-            let left = inner_rect.right() + margin;
-            let right = outer_rect.right();
+        let paint_scroll_bar = if auto_hide_scroll.is_some() {
+            scroll_bar_opacity > 0.0 && (show_scroll_this_frame || !horizontal_scroll)
+        } else {
+            current_scroll_bar_width > 0.0
+        };
+        let paint_horizontal_scroll_bar = if auto_hide_scroll.is_some() {
+            show_horizontal_scroll_this_frame && scroll_bar_opacity > 0.0
+        } else {
+            current_scroll_bar_height > 0.0
+        };
+
+        if paint_scroll_bar {
+            let (left, right) = if auto_hide_scroll.is_some() {
+                (
+                    inner_rect.right() - ui.spacing().scroll_bar_width,
+                    inner_rect.right(),
+                )
+            } else {
+                let animation_t = current_scroll_bar_width / max_scroll_bar_width;
+                // margin between contents and scroll bar
+                let margin = animation_t * ui.spacing().item_spacing.x;
+                (inner_rect.right() + margin, outer_rect.right())
+            };
             let top = inner_rect.top();
             let bottom = inner_rect.bottom();
 
@@ -425,35 +720,142 @@ impl Prepared {
             let visuals = if scrolling_enabled {
                 ui.style().interact(&response)
             } else {
-                &ui.style().visuals.widgets.inactive
+                ui.style().visuals.widgets.inactive
+            };
+
+            ui.painter().add(epaint::Shape::rect_filled(
+                outer_scroll_rect,
+                visuals.corner_radius,
+                ui.visuals()
+                    .extreme_bg_color
+                    .linear_multiply(scroll_bar_opacity),
+            ));
+
+            ui.painter().add(epaint::Shape::rect_filled(
+                handle_rect,
+                visuals.corner_radius,
+                visuals.bg_fill.linear_multiply(scroll_bar_opacity),
+            ));
+        }
+
+        if paint_horizontal_scroll_bar {
+            let (top, bottom) = if auto_hide_scroll.is_some() {
+                (
+                    inner_rect.bottom() - ui.spacing().scroll_bar_width,
+                    inner_rect.bottom(),
+                )
+            } else {
+                let animation_t = current_scroll_bar_height / max_scroll_bar_width;
+                // margin between contents and scroll bar
+                let margin = animation_t * ui.spacing().item_spacing.x;
+                (inner_rect.bottom() + margin, outer_rect.bottom())
+            };
+            let left = inner_rect.left();
+            let right = inner_rect.right();
+
+            let outer_scroll_rect = Rect::from_min_max(pos2(left, top), pos2(right, bottom));
+
+            let from_content =
+                |content_x| remap_clamp(content_x, 0.0..=content_size.x, left..=right);
+
+            let handle_rect = Rect::from_min_max(
+                pos2(from_content(state.offset.x), top),
+                pos2(from_content(state.offset.x + inner_rect.width()), bottom),
+            );
+
+            let interact_id = id.with("horizontal");
+            let sense = if scrolling_enabled {
+                Sense::click_and_drag()
+            } else {
+                Sense::hover()
+            };
+            let response = ui.interact(outer_scroll_rect, interact_id, sense);
+
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                let scroll_start_offset_from_left =
+                    state.scroll_start_offset_from_left.get_or_insert_with(|| {
+                        if handle_rect.contains(pointer_pos) {
+                            pointer_pos.x - handle_rect.left()
+                        } else {
+                            let handle_left_pos_at_right = right - handle_rect.width();
+                            // Center the handle on the mouse:
+                            let new_handle_left_pos = (pointer_pos.x - handle_rect.width() / 2.0)
+                                .clamp(left, handle_left_pos_at_right);
+                            pointer_pos.x - new_handle_left_pos
+                        }
+                    });
+
+                let new_handle_left = pointer_pos.x - *scroll_start_offset_from_left;
+                state.offset.x = remap(new_handle_left, left..=right, 0.0..=content_size.x);
+            } else {
+                state.scroll_start_offset_from_left = None;
+            }
+
+            let unbounded_offset_x = state.offset.x;
+            state.offset.x = state.offset.x.min(max_offset_x).max(0.0);
+
+            if state.offset.x != unbounded_offset_x {
+                state.vel = Vec2::ZERO;
+            }
+
+            // Avoid frame-delay by calculating a new handle rect:
+            let mut handle_rect = Rect::from_min_max(
+                pos2(from_content(state.offset.x), top),
+                pos2(from_content(state.offset.x + inner_rect.width()), bottom),
+            );
+            let min_handle_width = ui.spacing().scroll_bar_width;
+            if handle_rect.size().x < min_handle_width {
+                handle_rect = Rect::from_center_size(
+                    handle_rect.center(),
+                    vec2(min_handle_width, handle_rect.size().y),
+                );
+            }
+
+            let visuals = if scrolling_enabled {
+                ui.style().interact(&response)
+            } else {
+                ui.style().visuals.widgets.inactive
             };
 
             ui.painter().add(epaint::Shape::rect_filled(
                 outer_scroll_rect,
                 visuals.corner_radius,
-                ui.visuals().extreme_bg_color,
+                ui.visuals()
+                    .extreme_bg_color
+                    .linear_multiply(scroll_bar_opacity),
             ));
 
             ui.painter().add(epaint::Shape::rect_filled(
                 handle_rect,
                 visuals.corner_radius,
-                visuals.bg_fill,
+                visuals.bg_fill.linear_multiply(scroll_bar_opacity),
             ));
         }
 
+        let horizontal_scroll_bar_height = outer_rect.height() - inner_rect.height();
         let size = vec2(
             outer_rect.size().x,
-            outer_rect.size().y.min(content_size.y), // shrink if content is so small that we don't need scroll bars
+            // shrink if content is so small that we don't need scroll bars:
+            outer_rect
+                .size()
+                .y
+                .min(content_size.y.at_least(min_size.y) + horizontal_scroll_bar_height),
         );
         ui.advance_cursor_after_rect(Rect::from_min_size(outer_rect.min, size));
 
-        if show_scroll_this_frame != state.show_scroll {
+        if show_scroll_this_frame != state.show_scroll
+            || show_horizontal_scroll_this_frame != state.show_horizontal_scroll
+        {
             ui.ctx().request_repaint();
         }
 
         state.offset.y = state.offset.y.min(content_size.y - inner_rect.height());
         state.offset.y = state.offset.y.max(0.0);
+        if horizontal_scroll {
+            state.offset.x = state.offset.x.min(max_offset_x).max(0.0);
+        }
         state.show_scroll = show_scroll_this_frame;
+        state.show_horizontal_scroll = show_horizontal_scroll_this_frame;
 
         ui.memory().id_data.insert(id, state);
     }
@@ -462,3 +864,42 @@ impl Prepared {
 fn max_scroll_bar_width_with_margin(ui: &Ui) -> f32 {
     ui.spacing().item_spacing.x + ui.spacing().scroll_bar_width
 }
+
+#[test]
+fn test_show_cells_scrolls_horizontally() {
+    use crate::test_util::run_frame_with_input;
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |scroll_delta: Vec2| {
+        let input = RawInput {
+            scroll_delta,
+            events: vec![Event::PointerMoved(pos2(100.0, 100.0))],
+            ..Default::default()
+        };
+        let (ranges, _) = run_frame_with_input(&mut ctx, input, |ctx| {
+            CentralPanel::default()
+                .show(ctx, |ui| {
+                    ScrollArea::from_max_height(200.0)
+                        .max_width(400.0)
+                        .show_cells(ui, vec2(50.0, 20.0), 100, 100, |_ui, rows, cols| {
+                            (rows, cols)
+                        })
+                })
+                .inner
+        });
+        ranges
+    };
+
+    let (rows, cols) = run_frame(Vec2::ZERO);
+    assert_eq!((rows.start, cols.start), (0, 0));
+    assert!(cols.end < 100, "columns out of view are skipped");
+
+    run_frame(vec2(-1000.0, 0.0));
+    let (rows, cols) = run_frame(Vec2::ZERO);
+    assert_eq!(rows.start, 0, "only scrolled sideways");
+    assert!(cols.start > 0 && cols.end > 20, "{:?}", cols);
+
+    // Can't scroll past the last column:
+    run_frame(vec2(-100_000.0, 0.0));
+    let (_, cols) = run_frame(Vec2::ZERO);
+    assert_eq!(cols.end, 100);
+}
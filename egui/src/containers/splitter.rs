@@ -0,0 +1,316 @@
+//! Divide some space into regions that the user can resize by dragging the dividers between them.
+
+use crate::*;
+
+/// How big a region of a [`Splitter`] is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum SplitSize {
+    /// A share of the space that the [`Self::Points`] regions leave over,
+    /// relative to the other proportional regions:
+    /// two regions of `Proportional(1.0)` get half each.
+    ///
+    /// The region grows and shrinks with the [`Splitter`].
+    Proportional(f32),
+
+    /// A size in points, kept when the [`Splitter`] is resized.
+    Points(f32),
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    /// The regions as the user sized them.
+    /// [`SplitSize::Proportional`] ones always add up to `1.0`.
+    sizes: Vec<SplitSize>,
+}
+
+/// Divides the available space into regions, side by side or above each other,
+/// with dividers between them that the user can drag to resize them.
+///
+/// The sizes are remembered in [`Memory`].
+/// Double-click a divider to go back to the sizes you gave.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::SplitSize;
+/// egui::Splitter::horizontal("editor_splitter")
+///     .region(SplitSize::Points(150.0))
+///     .region(SplitSize::Proportional(1.0))
+///     .show(ui, |ui, region| {
+///         if region == 0 {
+///             ui.label("Files");
+///         } else {
+///             ui.label("Editor");
+///         }
+///     });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Splitter {
+    id_source: Id,
+    horizontal: bool,
+    regions: Vec<SplitSize>,
+    min_size: f32,
+}
+
+impl Splitter {
+    /// Regions side by side, from left to right.
+    pub fn horizontal(id_source: impl std::hash::Hash) -> Self {
+        Self::new(true, id_source)
+    }
+
+    /// Regions above each other, from top to bottom.
+    pub fn vertical(id_source: impl std::hash::Hash) -> Self {
+        Self::new(false, id_source)
+    }
+
+    fn new(horizontal: bool, id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            horizontal,
+            regions: vec![],
+            min_size: 32.0,
+        }
+    }
+
+    /// Add a region, after the ones already added.
+    ///
+    /// Without any regions, the splitter has two regions that start out equally big.
+    pub fn region(mut self, size: SplitSize) -> Self {
+        self.regions.push(size);
+        self
+    }
+
+    /// The user can't drag a region smaller than this. Default: `32.0`.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Fills the available space. `add_contents` is called with the index of each region.
+    ///
+    /// The response is [`Response::changed`] when the user resized the regions.
+    pub fn show(self, ui: &mut Ui, mut add_contents: impl FnMut(&mut Ui, usize)) -> Response {
+        let Self {
+            id_source,
+            horizontal,
+            mut regions,
+            min_size,
+        } = self;
+        if regions.is_empty() {
+            regions = vec![SplitSize::Proportional(1.0); 2];
+        }
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = ui
+            .memory()
+            .id_data
+            .get::<State>(&id)
+            .cloned()
+            .unwrap_or_default();
+        if !same_kinds(&state.sizes, &regions) {
+            state.sizes = regions.clone();
+        }
+
+        let rect = ui.available_rect_before_wrap_finite();
+        let gap = if horizontal {
+            ui.spacing().item_spacing.x
+        } else {
+            ui.spacing().item_spacing.y
+        };
+        let (start, length) = if horizontal {
+            (rect.left(), rect.width())
+        } else {
+            (rect.top(), rect.height())
+        };
+        let mut lengths = region_lengths(
+            &state.sizes,
+            length - gap * (regions.len() - 1) as f32,
+            min_size,
+        );
+
+        let mut changed = false;
+        for divider in 0..regions.len() - 1 {
+            let region_start =
+                start + lengths[..divider].iter().sum::<f32>() + gap * divider as f32;
+            let position = region_start + lengths[divider] + 0.5 * gap;
+            let line = if horizontal {
+                [pos2(position, rect.top()), pos2(position, rect.bottom())]
+            } else {
+                [pos2(rect.left(), position), pos2(rect.right(), position)]
+            };
+
+            let grab_radius = ui.style().interaction.resize_grab_radius_side;
+            let line_rect = Rect::from_two_pos(line[0], line[1]).expand(grab_radius);
+            let response = ui.interact(line_rect, id.with(divider), Sense::click_and_drag());
+            if response.hovered() || response.dragged() {
+                ui.output().cursor_icon = if horizontal {
+                    CursorIcon::ResizeHorizontal
+                } else {
+                    CursorIcon::ResizeVertical
+                };
+            }
+            if response.double_clicked() {
+                state.sizes = regions.clone();
+                lengths = region_lengths(
+                    &state.sizes,
+                    length - gap * (regions.len() - 1) as f32,
+                    min_size,
+                );
+                changed = true;
+            } else if response.dragged() {
+                if let Some(pointer) = ui.input().pointer.interact_pos() {
+                    let pointer = if horizontal { pointer.x } else { pointer.y };
+                    changed |= drag_divider(
+                        &mut lengths,
+                        divider,
+                        pointer - 0.5 * gap - region_start,
+                        min_size,
+                    );
+                }
+            }
+
+            let stroke = if response.hovered() || response.dragged() {
+                ui.visuals().widgets.hovered.bg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            ui.painter().line_segment(line, stroke);
+        }
+        if changed {
+            set_lengths(&mut state.sizes, &lengths);
+        }
+
+        let mut region_start = start;
+        for (index, &region_length) in lengths.iter().enumerate() {
+            let region_rect = if horizontal {
+                Rect::from_min_max(
+                    pos2(region_start, rect.top()),
+                    pos2(region_start + region_length, rect.bottom()),
+                )
+            } else {
+                Rect::from_min_max(
+                    pos2(rect.left(), region_start),
+                    pos2(rect.right(), region_start + region_length),
+                )
+            };
+            let mut region_ui = ui.child_ui(region_rect, *ui.layout());
+            region_ui.set_clip_rect(region_rect.intersect(ui.clip_rect()));
+            add_contents(&mut region_ui, index);
+            region_start += region_length + gap;
+        }
+
+        ui.memory().id_data.insert(id, state);
+
+        let mut response = ui.allocate_rect(rect, Sense::hover());
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Are the sizes the same kinds of [`SplitSize`], in the same order?
+fn same_kinds(a: &[SplitSize], b: &[SplitSize]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(a, b)| std::mem::discriminant(a) == std::mem::discriminant(b))
+}
+
+/// How long each region is, when there is `length` for all of them (not counting the dividers).
+fn region_lengths(sizes: &[SplitSize], length: f32, min_size: f32) -> Vec<f32> {
+    let mut fixed_length = 0.0;
+    let mut total_weight = 0.0;
+    let mut num_proportional = 0;
+    for size in sizes {
+        match *size {
+            SplitSize::Points(points) => fixed_length += points.max(min_size),
+            SplitSize::Proportional(weight) => {
+                total_weight += weight.max(0.0);
+                num_proportional += 1;
+            }
+        }
+    }
+    let left_over = (length - fixed_length).max(0.0);
+    sizes
+        .iter()
+        .map(|size| match *size {
+            SplitSize::Points(points) => points.max(min_size),
+            SplitSize::Proportional(weight) if total_weight > 0.0 => {
+                left_over * weight.max(0.0) / total_weight
+            }
+            SplitSize::Proportional(_) => left_over / num_proportional as f32,
+        })
+        .collect()
+}
+
+/// Move the divider after region `divider` so that region gets `new_length`,
+/// taking from or giving to the region after it. Returns `true` if anything changed.
+fn drag_divider(lengths: &mut [f32], divider: usize, new_length: f32, min_size: f32) -> bool {
+    let both = lengths[divider] + lengths[divider + 1];
+    if both < 2.0 * min_size {
+        return false; // No room to move
+    }
+    let new_length = new_length.clamp(min_size, both - min_size);
+    if new_length == lengths[divider] {
+        return false;
+    }
+    lengths[divider] = new_length;
+    lengths[divider + 1] = both - new_length;
+    true
+}
+
+/// Update the sizes to the lengths the regions were dragged to.
+fn set_lengths(sizes: &mut [SplitSize], lengths: &[f32]) {
+    let proportional_length: f32 = sizes
+        .iter()
+        .zip(lengths)
+        .filter(|(size, _)| matches!(size, SplitSize::Proportional(_)))
+        .map(|(_, length)| length)
+        .sum();
+    for (size, &length) in sizes.iter_mut().zip(lengths) {
+        *size = match size {
+            SplitSize::Points(_) => SplitSize::Points(length),
+            SplitSize::Proportional(_) if proportional_length > 0.0 => {
+                SplitSize::Proportional(length / proportional_length)
+            }
+            SplitSize::Proportional(weight) => SplitSize::Proportional(*weight),
+        };
+    }
+}
+
+#[test]
+fn test_splitter_lengths() {
+    use SplitSize::*;
+    let sizes = [Points(100.0), Proportional(1.0), Proportional(3.0)];
+    assert_eq!(
+        region_lengths(&sizes, 500.0, 32.0),
+        vec![100.0, 100.0, 300.0]
+    );
+    assert_eq!(
+        region_lengths(&sizes, 100.0, 32.0),
+        vec![100.0, 0.0, 0.0],
+        "fixed sizes are kept"
+    );
+    assert_eq!(region_lengths(&[Points(10.0)], 500.0, 32.0), vec![32.0]);
+
+    let mut lengths = region_lengths(&sizes, 500.0, 32.0);
+    assert!(drag_divider(&mut lengths, 1, 150.0, 32.0));
+    assert_eq!(lengths, vec![100.0, 150.0, 250.0]);
+    assert!(drag_divider(&mut lengths, 0, 1000.0, 32.0));
+    assert_eq!(lengths, vec![218.0, 32.0, 250.0], "clamped to the min size");
+    assert!(!drag_divider(&mut lengths, 0, 1000.0, 32.0));
+
+    let mut sizes = sizes;
+    set_lengths(&mut sizes, &lengths);
+    assert_eq!(
+        sizes,
+        [
+            Points(218.0),
+            Proportional(32.0 / 282.0),
+            Proportional(250.0 / 282.0)
+        ]
+    );
+}
@@ -0,0 +1,446 @@
+//! A row of tabs above the contents of the selected one, see [`Tabs`].
+
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    selected: usize,
+    /// How far the tab bar is scrolled to the left, when the tabs don't fit.
+    scroll: f32,
+    /// The selection changed, so scroll it into view.
+    scroll_to_selected: bool,
+}
+
+/// What happened in a [`Tabs`] this frame.
+pub struct TabsResponse {
+    /// The tab bar and the contents.
+    pub response: Response,
+
+    /// The index of the tab that was shown.
+    pub selected: usize,
+
+    /// The user clicked the close button of this tab.
+    pub closed: Option<usize>,
+
+    /// The user dragged a tab from the first index to the second.
+    pub moved: Option<(usize, usize)>,
+}
+
+impl TabsResponse {
+    /// Close or move the tab in your own list of tabs, like the user did with the titles.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let mut documents = vec!["README.md".to_owned(), "main.rs".to_owned()];
+    /// let response = egui::Tabs::new("documents")
+    ///     .tabs(&documents)
+    ///     .closable(true)
+    ///     .reorderable(true)
+    ///     .show(ui, |ui, index| {
+    ///         ui.label(format!("Contents of {}", documents[index]));
+    ///     });
+    /// response.apply(&mut documents);
+    /// ```
+    pub fn apply<T>(&self, tabs: &mut Vec<T>) {
+        if let Some(closed) = self.closed {
+            if closed < tabs.len() {
+                tabs.remove(closed);
+            }
+        } else if let Some((from, to)) = self.moved {
+            if from < tabs.len() && to < tabs.len() {
+                let tab = tabs.remove(from);
+                tabs.insert(to, tab);
+            }
+        }
+    }
+}
+
+/// A row of tab titles, with the contents of the selected tab below it.
+///
+/// Click a title to select its tab. Optionally the tabs have close buttons ([`Self::closable`])
+/// and can be dragged to change their order ([`Self::reorderable`]).
+/// The tabs are yours, so you need to close and move them yourself, e.g. with [`TabsResponse::apply`].
+/// If the titles don't fit, the tab bar can be scrolled.
+/// The titles should be unique, as egui tells the tabs apart by their titles.
+///
+/// The selected tab is stored by egui, unless you use [`Self::selected`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// egui::Tabs::new("settings_tabs")
+///     .tab("General")
+///     .tab("Advanced")
+///     .show(ui, |ui, index| {
+///         if index == 0 {
+///             ui.label("General settings");
+///         } else {
+///             ui.label("Advanced settings");
+///         }
+///     });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Tabs<'a> {
+    id_source: Id,
+    titles: Vec<String>,
+    selected: Option<&'a mut usize>,
+    closable: bool,
+    reorderable: bool,
+}
+
+impl<'a> Tabs<'a> {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            titles: Default::default(),
+            selected: None,
+            closable: false,
+            reorderable: false,
+        }
+    }
+
+    /// Add a tab with this title to the end.
+    pub fn tab(mut self, title: impl ToString) -> Self {
+        self.titles.push(title.to_string());
+        self
+    }
+
+    /// Add tabs with these titles to the end.
+    pub fn tabs(mut self, titles: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.titles
+            .extend(titles.into_iter().map(|title| title.to_string()));
+        self
+    }
+
+    /// Keep the index of the selected tab here instead of in egui's memory,
+    /// e.g. to select a tab from code.
+    pub fn selected(mut self, selected: &'a mut usize) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// Show a close button on each tab. Default: `false`.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Let the user drag the tabs to change their order. Default: `false`.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Show the tab bar, and the contents of the selected tab with `add_tab(ui, index)`.
+    pub fn show(self, ui: &mut Ui, add_tab: impl FnOnce(&mut Ui, usize)) -> TabsResponse {
+        let Self {
+            id_source,
+            titles,
+            selected,
+            closable,
+            reorderable,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = *ui.memory().id_data.get_or_default::<State>(id);
+        let stored_selected = state.selected;
+        let shown = selected
+            .as_deref()
+            .copied()
+            .unwrap_or(stored_selected)
+            .min(titles.len().saturating_sub(1));
+        let mut new_selected = shown;
+        let mut closed = None;
+        let mut moved = None;
+
+        let response = ui
+            .vertical(|ui| {
+                if !titles.is_empty() {
+                    let bar = TabBar {
+                        id,
+                        titles: &titles,
+                        selected: shown,
+                        closable,
+                        reorderable,
+                    };
+                    let scroll_to_selected = state.scroll_to_selected || shown != stored_selected;
+                    let (clicked, close, move_) = bar.ui(ui, &mut state, scroll_to_selected);
+                    if let Some(clicked) = clicked {
+                        new_selected = clicked;
+                    }
+                    closed = close;
+                    moved = move_;
+                    add_tab(ui, shown);
+                }
+            })
+            .response;
+
+        if let Some(closed) = closed {
+            if closed < new_selected || (closed == new_selected && closed + 1 == titles.len()) {
+                new_selected = new_selected.saturating_sub(1);
+            }
+            moved = None;
+        } else if let Some((from, to)) = moved {
+            if new_selected == from {
+                new_selected = to;
+            } else if new_selected == to {
+                new_selected = from;
+            }
+        }
+
+        if new_selected != shown || closed.is_some() || moved.is_some() {
+            ui.ctx().request_repaint();
+        }
+        state.scroll_to_selected = new_selected != shown;
+        state.selected = new_selected;
+        if let Some(selected) = selected {
+            *selected = new_selected;
+        }
+        ui.memory().id_data.insert(id, state);
+
+        TabsResponse {
+            response,
+            selected: shown,
+            closed,
+            moved,
+        }
+    }
+}
+
+struct TabBar<'t> {
+    id: Id,
+    titles: &'t [String],
+    selected: usize,
+    closable: bool,
+    reorderable: bool,
+}
+
+impl<'t> TabBar<'t> {
+    /// Returns which tab was clicked, closed, and moved.
+    fn ui(
+        self,
+        ui: &mut Ui,
+        state: &mut State,
+        scroll_to_selected: bool,
+    ) -> (Option<usize>, Option<usize>, Option<(usize, usize)>) {
+        let Self {
+            id,
+            titles,
+            selected,
+            closable,
+            reorderable,
+        } = self;
+
+        let widths: Vec<f32> = titles
+            .iter()
+            .map(|title| tab_width(ui, title, closable))
+            .collect();
+        let total_width: f32 = widths.iter().sum();
+
+        let height = ui.spacing().interact_size.y;
+        let (bar_rect, bar_response) =
+            ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+        ui.painter().line_segment(
+            [bar_rect.left_bottom(), bar_rect.right_bottom()],
+            ui.visuals().widgets.noninteractive.bg_stroke,
+        );
+
+        // Scroll buttons, if the tabs don't fit:
+        let mut tabs_rect = bar_rect;
+        if total_width > bar_rect.width() {
+            tabs_rect.max.x -= 2.0 * height;
+            let button_size = Vec2::splat(height);
+            let left_rect = Rect::from_min_size(tabs_rect.right_top(), button_size);
+            let right_rect = Rect::from_min_size(left_rect.right_top(), button_size);
+            let step = 0.5 * tabs_rect.width();
+            if scroll_button(ui, left_rect, id.with("scroll_left"), "⏴").clicked() {
+                state.scroll -= step;
+            }
+            if scroll_button(ui, right_rect, id.with("scroll_right"), "⏵").clicked() {
+                state.scroll += step;
+            }
+            if bar_response.hovered() {
+                let delta = ui.input().scroll_delta;
+                state.scroll -= delta.x + delta.y;
+            }
+        }
+
+        let left: f32 = widths[..selected].iter().sum();
+        if scroll_to_selected {
+            let right = left + widths[selected];
+            if left < state.scroll {
+                state.scroll = left;
+            } else if right > state.scroll + tabs_rect.width() {
+                state.scroll = right - tabs_rect.width();
+            }
+        }
+        state.scroll = state
+            .scroll
+            .clamp(0.0, (total_width - tabs_rect.width()).at_least(0.0));
+
+        let mut bar_ui = ui.child_ui(tabs_rect, Layout::left_to_right());
+        bar_ui.set_clip_rect(tabs_rect.intersect(ui.clip_rect()));
+
+        let mut clicked = None;
+        let mut closed = None;
+        let mut moved = None;
+        let mut x = tabs_rect.min.x - state.scroll;
+        let mut rects = Vec::with_capacity(titles.len());
+        let mut dragged = None;
+        for (index, (title, width)) in titles.iter().zip(&widths).enumerate() {
+            let rect = Rect::from_min_size(pos2(x, tabs_rect.min.y), vec2(*width, height));
+            x += width;
+            rects.push(rect);
+
+            let tab_id = id.with("tab").with(title);
+            let (response, close) =
+                tab_ui(&bar_ui, rect, tab_id, title, index == selected, closable);
+            if response.clicked() || (response.dragged() && reorderable) {
+                clicked = Some(index);
+            }
+            if response.dragged() && reorderable && !ui.input().pointer.could_be_click() {
+                dragged = Some(index);
+            }
+            if close {
+                closed = Some(index);
+            }
+        }
+
+        // Swap places with a neighbor once the pointer is past its middle:
+        if let (Some(index), Some(pointer)) = (dragged, ui.input().pointer.interact_pos()) {
+            if index > 0 && pointer.x < rects[index - 1].center().x {
+                moved = Some((index, index - 1));
+            } else if index + 1 < titles.len() && pointer.x > rects[index + 1].center().x {
+                moved = Some((index, index + 1));
+            }
+        }
+
+        (clicked, closed, moved)
+    }
+}
+
+fn scroll_button(ui: &mut Ui, rect: Rect, id: Id, text: &str) -> Response {
+    let response = ui.interact(rect, id, Sense::click());
+    let visuals = ui.style().interact(&response);
+    ui.painter().text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        text,
+        TextStyle::Button,
+        visuals.text_color(),
+    );
+    response
+}
+
+/// The size of the close button on a tab.
+fn close_button_size(ui: &Ui, closable: bool) -> f32 {
+    if closable {
+        ui.spacing().icon_width
+    } else {
+        0.0
+    }
+}
+
+/// The width of a tab with this title, see [`tab_ui`].
+pub(crate) fn tab_width(ui: &Ui, title: &str, closable: bool) -> f32 {
+    let galley = ui
+        .fonts()
+        .layout_no_wrap(TextStyle::Button, title.to_owned());
+    galley.size.x + close_button_size(ui, closable) + 2.0 * ui.spacing().button_padding.x
+}
+
+/// A tab title in `rect`, senses clicks and drags.
+/// Returns its response, and if its close button was clicked.
+pub(crate) fn tab_ui(
+    ui: &Ui,
+    rect: Rect,
+    id: Id,
+    title: &str,
+    selected: bool,
+    closable: bool,
+) -> (Response, bool) {
+    let padding = ui.spacing().button_padding;
+    let close_size = close_button_size(ui, closable);
+    let close_rect = Rect::from_center_size(
+        pos2(rect.max.x - padding.x - 0.5 * close_size, rect.center().y),
+        Vec2::splat(close_size),
+    );
+
+    // The close button is not part of the tab, so pressing it doesn't select or drag the tab:
+    let mut tab_rect = rect;
+    if closable {
+        tab_rect.max.x = close_rect.min.x;
+    }
+    let response = ui.interact(tab_rect, id, Sense::click_and_drag());
+    let visuals = ui.style().interact_selectable(&response, selected);
+    if selected || response.hovered() {
+        ui.painter()
+            .rect(rect, 2.0, visuals.bg_fill, visuals.bg_stroke);
+    }
+    let galley = ui
+        .fonts()
+        .layout_no_wrap(TextStyle::Button, title.to_owned());
+    let text_pos = pos2(
+        rect.min.x + padding.x,
+        rect.center().y - 0.5 * galley.size.y,
+    );
+    ui.painter().galley(text_pos, galley, visuals.text_color());
+
+    if !closable {
+        return (response, false);
+    }
+    let close_response = ui.interact(close_rect, id.with("close"), Sense::click());
+    let visuals = ui.style().interact(&close_response);
+    let cross_rect = close_rect
+        .shrink(0.2 * close_size)
+        .expand(visuals.expansion);
+    let stroke = visuals.fg_stroke;
+    ui.painter()
+        .line_segment([cross_rect.left_top(), cross_rect.right_bottom()], stroke);
+    ui.painter()
+        .line_segment([cross_rect.right_top(), cross_rect.left_bottom()], stroke);
+    (response, close_response.clicked())
+}
+
+#[test]
+fn test_tabs() {
+    use crate::test_util::{click, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut titles = vec!["A".to_owned(), "B".to_owned(), "C".to_owned()];
+    let mut run_frame = |events: Vec<Event>, titles: &mut Vec<String>| {
+        let mut shown = None;
+        let response = run_ui_frame(&mut ctx, events, |ui| {
+            Tabs::new("tabs")
+                .tabs(titles.iter())
+                .closable(true)
+                .reorderable(true)
+                .show(ui, |_ui, index| shown = Some(index))
+        });
+        response.apply(titles);
+        (response, shown)
+    };
+
+    let (response, shown) = run_frame(vec![], &mut titles);
+    assert_eq!(shown, Some(0));
+    let bar = response.response.rect;
+    let style = Style::default();
+    let tab_height = style.spacing.interact_size.y;
+    let width_a = tab_width(&Ui::__test(), "A", true);
+    let b_center = bar.min + vec2(width_a + 4.0, 0.5 * tab_height);
+    run_frame(click(b_center), &mut titles);
+    assert_eq!(run_frame(vec![], &mut titles).1, Some(1), "clicked B");
+
+    // Close "A", the selected tab "B" moves to its place:
+    let a_close = bar.min
+        + vec2(
+            width_a - style.spacing.button_padding.x - 0.5 * style.spacing.icon_width,
+            0.5 * tab_height,
+        );
+    let (response, _) = run_frame(click(a_close), &mut titles);
+    assert_eq!(response.closed, Some(0));
+    assert_eq!(titles, vec!["B".to_owned(), "C".to_owned()]);
+    assert_eq!(run_frame(vec![], &mut titles).1, Some(0), "still B");
+}
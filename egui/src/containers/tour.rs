@@ -0,0 +1,293 @@
+//! A guided tour through your UI, see [`Tour`].
+
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct State {
+    /// The step being shown, or `None` if the tour isn't running.
+    step: Option<usize>,
+}
+
+/// One step of a [`Tour`]: an explanation, optionally pointing at a widget.
+#[derive(Clone, Debug)]
+pub struct TourStep {
+    title: String,
+    text: String,
+    target: Option<Id>,
+}
+
+impl TourStep {
+    pub fn new(title: impl ToString, text: impl ToString) -> Self {
+        Self {
+            title: title.to_string(),
+            text: text.to_string(),
+            target: None,
+        }
+    }
+
+    /// Highlight the widget with this [`Id`], e.g. [`Response::id`],
+    /// and show the explanation next to it.
+    ///
+    /// The widget is found with [`Context::widget_rect`], so it must be interactive,
+    /// unless [`crate::memory::Options::register_all_widget_rects`] is on.
+    /// If the widget isn't shown, the explanation is shown in the middle of the screen instead.
+    pub fn target(mut self, id: Id) -> Self {
+        self.target = Some(id);
+        self
+    }
+}
+
+/// A guided tour: it dims the screen except for one widget at a time,
+/// and explains each widget in a bubble next to it, with buttons to go to the next or previous step.
+///
+/// Build the tour every frame and call [`Self::show`] after the rest of your UI,
+/// so the widgets it points at have been laid out.
+/// The tour does nothing until you [start](Self::start) it,
+/// and ends when the user finishes or skips it (with the close button or <kbd>Escape</kbd>).
+///
+/// The UI outside the highlighted widget stays interactive, so the user can try things out as they go.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// let mut save_button_id = None;
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     if ui.button("Take the tour").clicked() {
+///         egui::Tour::start(ctx, "onboarding");
+///     }
+///     save_button_id = Some(ui.button("Save").id);
+/// });
+///
+/// let mut save_step = egui::TourStep::new("Saving", "Click here to save your work.");
+/// if let Some(id) = save_button_id {
+///     save_step = save_step.target(id);
+/// }
+/// egui::Tour::new("onboarding")
+///     .step(egui::TourStep::new("Welcome!", "Let's have a look around."))
+///     .step(save_step)
+///     .show(ctx);
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct Tour {
+    id: Id,
+    steps: Vec<TourStep>,
+    width: f32,
+}
+
+impl Tour {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            steps: Default::default(),
+            width: 280.0,
+        }
+    }
+
+    /// Add a step to the end of the tour.
+    pub fn step(mut self, step: TourStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Width of the explanation bubble in points. Default: 280.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Start the tour with the given id source from its first step, e.g. from a "Help" menu.
+    pub fn start(ctx: &Context, id_source: impl std::hash::Hash) {
+        Self::set_step(ctx, id_source, Some(0));
+    }
+
+    /// End the tour with the given id source.
+    pub fn stop(ctx: &Context, id_source: impl std::hash::Hash) {
+        Self::set_step(ctx, id_source, None);
+    }
+
+    /// Is the tour with the given id source running?
+    pub fn is_running(ctx: &Context, id_source: impl std::hash::Hash) -> bool {
+        Self::current_step(ctx, id_source).is_some()
+    }
+
+    /// The index of the step being shown, or `None` if the tour isn't running.
+    pub fn current_step(ctx: &Context, id_source: impl std::hash::Hash) -> Option<usize> {
+        ctx.memory()
+            .id_data_temp
+            .get::<State>(&Id::new(id_source))
+            .and_then(|state| state.step)
+    }
+
+    /// Jump to the step with the given index, or end the tour with `None`.
+    pub fn set_step(ctx: &Context, id_source: impl std::hash::Hash, step: Option<usize>) {
+        ctx.memory()
+            .id_data_temp
+            .insert(Id::new(id_source), State { step });
+    }
+
+    /// Show the current step, if the tour is running.
+    ///
+    /// Returns the index of the step shown, e.g. so you can open the panel the step is about.
+    pub fn show(self, ctx: &CtxRef) -> Option<usize> {
+        let Self { id, steps, width } = self;
+
+        let mut state = *ctx.memory().id_data_temp.get_or_default::<State>(id);
+        let index = match state.step {
+            Some(index) if index < steps.len() => index,
+            _ => {
+                ctx.memory().id_data_temp.insert(id, State { step: None });
+                return None;
+            }
+        };
+        let step = &steps[index];
+
+        let screen_rect = ctx.input().screen_rect();
+        let target_rect = step
+            .target
+            .and_then(|target| ctx.widget_rect(target))
+            .map(|widget_rect| widget_rect.rect)
+            .filter(|rect| screen_rect.intersects(*rect))
+            .map(|rect| rect.expand(4.0));
+
+        paint_dim(ctx, id, screen_rect, target_rect);
+
+        let area = Area::new(id.with("bubble")).order(Order::Foreground);
+        let bubble_size = ctx
+            .memory()
+            .areas
+            .get(area.layer().id)
+            .map_or(vec2(width, 100.0), |area| area.size);
+        area.fixed_pos(bubble_pos(screen_rect, target_rect, bubble_size))
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_width(width);
+                    ui.horizontal(|ui| {
+                        ui.heading(&step.title);
+                        ui.with_layout(Layout::right_to_left(), |ui| {
+                            if ui.small_button("✖").on_hover_text("End the tour").clicked() {
+                                state.step = None;
+                            }
+                        });
+                    });
+                    ui.label(&step.text);
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{} / {}", index + 1, steps.len()));
+                        ui.with_layout(Layout::right_to_left(), |ui| {
+                            let is_last = index + 1 == steps.len();
+                            if ui.button(if is_last { "Done" } else { "Next" }).clicked() {
+                                state.step = Some(index + 1).filter(|_| !is_last);
+                            }
+                            if index > 0 && ui.button("Back").clicked() {
+                                state.step = Some(index - 1);
+                            }
+                        });
+                    });
+                });
+            });
+
+        if ctx.input().key_pressed(Key::Escape) {
+            state.step = None;
+        }
+        if state.step != Some(index) {
+            ctx.request_repaint();
+        }
+        ctx.memory().id_data_temp.insert(id, state);
+        Some(index)
+    }
+}
+
+/// Dim the screen, except for a hole around the target.
+fn paint_dim(ctx: &CtxRef, id: Id, screen_rect: Rect, target_rect: Option<Rect>) {
+    // In the middle, to cover the windows but not the bubble:
+    let painter = ctx.layer_painter(LayerId::new(Order::Middle, id.with("dim")));
+    let dim_color = Color32::from_black_alpha(160);
+
+    if let Some(hole) = target_rect {
+        let hole = hole.intersect(screen_rect);
+        let dim_rects = [
+            Rect::from_x_y_ranges(screen_rect.x_range(), screen_rect.top()..=hole.top()),
+            Rect::from_x_y_ranges(screen_rect.x_range(), hole.bottom()..=screen_rect.bottom()),
+            Rect::from_x_y_ranges(screen_rect.left()..=hole.left(), hole.y_range()),
+            Rect::from_x_y_ranges(hole.right()..=screen_rect.right(), hole.y_range()),
+        ];
+        for rect in &dim_rects {
+            painter.rect_filled(*rect, 0.0, dim_color);
+        }
+        let stroke = Stroke::new(2.0, ctx.style().visuals.selection.bg_fill);
+        painter.rect_stroke(hole, 4.0, stroke);
+    } else {
+        painter.rect_filled(screen_rect, 0.0, dim_color);
+    }
+}
+
+/// Below the target if there is room, else above it, else in the middle of the screen.
+fn bubble_pos(screen_rect: Rect, target_rect: Option<Rect>, bubble_size: Vec2) -> Pos2 {
+    let gap = 8.0;
+    let centered = screen_rect.center() - 0.5 * bubble_size;
+    let target_rect = match target_rect {
+        Some(target_rect) => target_rect,
+        None => return centered,
+    };
+
+    let y = if target_rect.bottom() + gap + bubble_size.y <= screen_rect.bottom() {
+        target_rect.bottom() + gap
+    } else if target_rect.top() - gap - bubble_size.y >= screen_rect.top() {
+        target_rect.top() - gap - bubble_size.y
+    } else {
+        centered.y
+    };
+    let x = (target_rect.center().x - 0.5 * bubble_size.x)
+        .at_most(screen_rect.right() - bubble_size.x)
+        .at_least(screen_rect.left());
+    pos2(x, y)
+}
+
+#[test]
+fn test_tour() {
+    use crate::test_util::key;
+    let mut ctx = CtxRef::default();
+    let run_frame = |ctx: &mut CtxRef, events: Vec<Event>| {
+        crate::test_util::run_frame(ctx, events, |ctx| {
+            let mut target_id = Id::new("unused");
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add_space(100.0);
+                target_id = ui.button("Save").id;
+            });
+            let shown = Tour::new("tour")
+                .step(TourStep::new("Welcome", "Hello"))
+                .step(TourStep::new("Save", "Saves").target(target_id))
+                .show(ctx);
+            let target_rect = ctx
+                .widget_rect(target_id)
+                .map(|widget_rect| widget_rect.rect);
+            let bubble_layer = Area::new(Id::new("tour").with("bubble")).layer();
+            let bubble_rect = ctx
+                .memory()
+                .areas
+                .get(bubble_layer.id)
+                .map(|area| area.rect());
+            (shown, target_rect, bubble_rect)
+        })
+    };
+
+    assert_eq!(run_frame(&mut ctx, vec![]).0, None);
+    Tour::start(&ctx, "tour");
+    assert_eq!(run_frame(&mut ctx, vec![]).0, Some(0));
+
+    Tour::set_step(&ctx, "tour", Some(1));
+    let (shown, target_rect, bubble_rect) = run_frame(&mut ctx, vec![]);
+    assert_eq!(shown, Some(1));
+    // The bubble is placed right below the target:
+    let (target_rect, bubble_rect) = (target_rect.unwrap(), bubble_rect.unwrap());
+    assert!(bubble_rect.top() > target_rect.bottom());
+    assert!(bubble_rect.top() < target_rect.bottom() + 20.0);
+
+    run_frame(&mut ctx, vec![key(Key::Escape)]);
+    assert!(!Tour::is_running(&ctx, "tour"));
+    assert_eq!(run_frame(&mut ctx, vec![]).0, None);
+}
@@ -12,6 +12,7 @@ use super::*;
 /// * default, minimum, maximum and/or fixed size
 /// * if the window has a scroll area (off by default)
 /// * if the window can be collapsed (minimized) to just the title bar (yes, by default)
+/// * if there should be a minimize button (none by default)
 /// * if there should be a close button (none by default)
 ///
 /// ```
@@ -30,6 +31,8 @@ pub struct Window<'open> {
     resize: Resize,
     scroll: Option<ScrollArea>,
     collapsible: bool,
+    collapsed: Option<bool>,
+    minimize_button: bool,
     with_title_bar: bool,
 }
 
@@ -53,6 +56,8 @@ impl<'open> Window<'open> {
                 .default_size([340.0, 420.0]), // Default inner size of a window
             scroll: None,
             collapsible: true,
+            collapsed: None,
+            minimize_button: false,
             with_title_bar: true,
         }
     }
@@ -79,6 +84,13 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Keep this window enabled, and on top, while the rest of egui is disabled
+    /// with [`crate::Context::set_enabled`], e.g. for a progress window.
+    pub fn keep_enabled(mut self, keep_enabled: bool) -> Self {
+        self.area = self.area.keep_enabled(keep_enabled);
+        self
+    }
+
     /// Usage: `Window::new(...).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     /// Not sure this is a good interface for this.
     pub fn mutate(mut self, mutate: impl Fn(&mut Self)) -> Self {
@@ -191,6 +203,22 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Collapse the window to just its title bar (`true`), or expand it (`false`),
+    /// overriding what the user did. Use [`Self::set_collapsed`] to do it just once.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = Some(collapsed);
+        self
+    }
+
+    /// Show a button on the title bar that collapses the window to just its title bar,
+    /// and restores it again. Default: `false`.
+    ///
+    /// Works even if the window isn't [`Self::collapsible`] by clicking on its title.
+    pub fn minimize_button(mut self, minimize_button: bool) -> Self {
+        self.minimize_button = minimize_button;
+        self
+    }
+
     /// Show title bar on top of the window?
     /// If `false`, the window will not be collapsible nor have a close-button.
     pub fn title_bar(mut self, title_bar: bool) -> Self {
@@ -228,6 +256,48 @@ impl<'open> Window<'open> {
         self.area = self.area.drag_bounds(bounds);
         self
     }
+
+    /// Stay in front of the other windows (that are not also always on top),
+    /// even when they are clicked. Default: `false`.
+    ///
+    /// See also [`Context::move_to_top`] and [`Context::move_to_bottom`].
+    pub fn always_on_top(mut self, always_on_top: bool) -> Self {
+        self.area = self.area.always_on_top(always_on_top);
+        self
+    }
+
+    /// Keep the window within [`Self::drag_bounds`], or else within [`Context::available_rect`],
+    /// when it is moved or resized (unless it is too large to fit).
+    /// If `false`, it can be moved partially or completely off the screen.
+    /// Default: `true`.
+    ///
+    /// See also [`crate::style::Interaction::window_snap_distance`].
+    pub fn constrain(mut self, constrain: bool) -> Self {
+        self.area = self.area.constrain(constrain);
+        self
+    }
+}
+
+impl Window<'_> {
+    /// Is the window with this id collapsed to just its title bar?
+    ///
+    /// The id of a window is `Id::new(title)`, unless set with [`Self::id`].
+    pub fn is_collapsed(ctx: &Context, id: Id) -> bool {
+        !collapsing_header::State::is_open(ctx, id.with("collapsing")).unwrap_or(true)
+    }
+
+    /// Collapse the window with this id to just its title bar, or expand it, like the user can.
+    ///
+    /// The id of a window is `Id::new(title)`, unless set with [`Self::id`].
+    /// Like other window state, this is remembered with the `persistence` feature.
+    pub fn set_collapsed(ctx: &Context, id: Id, collapsed: bool) {
+        let collapsing_id = id.with("collapsing");
+        let mut state =
+            collapsing_header::State::from_memory_with_default_open(ctx, collapsing_id, true);
+        state.set_open(!collapsed);
+        ctx.memory().id_data.insert(collapsing_id, state);
+        ctx.request_repaint();
+    }
 }
 
 impl<'open> Window<'open> {
@@ -249,22 +319,36 @@ impl<'open> Window<'open> {
             resize,
             scroll,
             collapsible,
+            collapsed,
+            minimize_button,
             with_title_bar,
         } = self;
 
         let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
 
         let is_open = !matches!(open, Some(false)) || ctx.memory().everything_is_visible();
-        area.show_open_close_animation(ctx, &frame, is_open);
-
+        if ctx.style().window_animation == style::WindowAnimation::None {
+            // Otherwise closing windows are faded out by the `Context`.
+            area.show_open_close_animation(ctx, &frame, is_open);
+        }
         if !is_open {
             return None;
         }
 
         let area_id = area.id;
         let area_layer_id = area.layer();
+        if ctx.memory().options.log_ui_events {
+            ctx.memory()
+                .event_log
+                .window_shown(area_id, title_label.text());
+        }
         let resize_id = area_id.with("resize");
         let collapsing_id = area_id.with("collapsing");
+        if let Some(collapsed) = collapsed {
+            if Self::is_collapsed(ctx, area_id) != collapsed {
+                Self::set_collapsed(ctx, area_id, collapsed);
+            }
+        }
 
         let is_collapsed = with_title_bar
             && !collapsing_header::State::is_open(ctx, collapsing_id).unwrap_or_default();
@@ -297,6 +381,7 @@ impl<'open> Window<'open> {
                 };
                 let margins = 2.0 * frame.margin + vec2(0.0, title_bar_height);
                 let bounds = area.drag_bounds();
+                let constrain = area.constrain();
 
                 interact(
                     window_interaction,
@@ -306,6 +391,7 @@ impl<'open> Window<'open> {
                     area.state_mut(),
                     resize_id,
                     bounds,
+                    constrain,
                 )
             })
         } else {
@@ -332,6 +418,7 @@ impl<'open> Window<'open> {
                     &mut frame.content_ui,
                     title_label,
                     show_close_button,
+                    minimize_button,
                     collapsing_id,
                     &mut collapsing,
                     collapsible,
@@ -369,6 +456,7 @@ impl<'open> Window<'open> {
                     outer_rect,
                     &content_response,
                     open,
+                    minimize_button,
                     &mut collapsing,
                     collapsible,
                 );
@@ -487,6 +575,7 @@ impl WindowInteraction {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn interact(
     window_interaction: WindowInteraction,
     ctx: &Context,
@@ -495,15 +584,29 @@ fn interact(
     area_state: &mut area::State,
     resize_id: Id,
     drag_bounds: Option<Rect>,
+    constrain: bool,
 ) -> Option<WindowInteraction> {
     let new_rect = move_and_resize_window(ctx, &window_interaction)?;
-    let new_rect = ctx.round_rect_to_pixels(new_rect);
+    let bounds = drag_bounds.unwrap_or_else(|| ctx.available_rect());
+    let new_rect = snap_window_rect(ctx, &window_interaction, new_rect, bounds);
+    let mut new_rect = ctx.round_rect_to_pixels(new_rect);
 
-    let new_rect = if let Some(bounds) = drag_bounds {
-        ctx.constrain_window_rect_to_area(new_rect, bounds)
-    } else {
-        ctx.constrain_window_rect(new_rect)
-    };
+    if constrain {
+        // Stop the edges we drag at the bounds, instead of pushing the whole window away:
+        if window_interaction.left {
+            new_rect.min.x = new_rect.min.x.at_least(bounds.left());
+        }
+        if window_interaction.right {
+            new_rect.max.x = new_rect.max.x.at_most(bounds.right());
+        }
+        if window_interaction.top {
+            new_rect.min.y = new_rect.min.y.at_least(bounds.top());
+        }
+        if window_interaction.bottom {
+            new_rect.max.y = new_rect.max.y.at_most(bounds.bottom());
+        }
+        new_rect = ctx.constrain_window_rect_to_area(new_rect, bounds);
+    }
 
     // TODO: add this to a Window state instead as a command "move here next frame"
     area_state.pos = new_rect.min;
@@ -520,6 +623,89 @@ fn interact(
     Some(window_interaction)
 }
 
+/// Snap the edges of a window that is being moved or resized to the edges of `bounds`
+/// and of the other windows, see [`crate::style::Interaction::window_snap_distance`].
+fn snap_window_rect(
+    ctx: &Context,
+    window_interaction: &WindowInteraction,
+    rect: Rect,
+    bounds: Rect,
+) -> Rect {
+    let distance = ctx.style().interaction.window_snap_distance;
+    if distance <= 0.0 {
+        return rect;
+    }
+
+    let others: Vec<Rect> = {
+        let memory = ctx.memory();
+        memory
+            .areas
+            .visible_last_frame_layers()
+            .iter()
+            .filter(|layer_id| {
+                layer_id.order == Order::Middle && **layer_id != window_interaction.area_layer_id
+            })
+            .filter_map(|layer_id| memory.areas.get(layer_id.id))
+            .map(|state| state.rect())
+            .collect()
+    };
+    snap_rect(rect, bounds, &others, distance, window_interaction)
+}
+
+fn snap_rect(
+    mut rect: Rect,
+    bounds: Rect,
+    others: &[Rect],
+    distance: f32,
+    window_interaction: &WindowInteraction,
+) -> Rect {
+    let mut xs = vec![bounds.left(), bounds.right()];
+    let mut ys = vec![bounds.top(), bounds.bottom()];
+    for other in others {
+        // Only snap to windows that are next to us (or nearly so):
+        if other.top() <= rect.bottom() + distance && rect.top() <= other.bottom() + distance {
+            xs.push(other.left());
+            xs.push(other.right());
+        }
+        if other.left() <= rect.right() + distance && rect.left() <= other.right() + distance {
+            ys.push(other.top());
+            ys.push(other.bottom());
+        }
+    }
+
+    if window_interaction.is_resize() {
+        if window_interaction.left {
+            rect.min.x += snap_delta(&[rect.left()], &xs, distance).unwrap_or_default();
+        } else if window_interaction.right {
+            rect.max.x += snap_delta(&[rect.right()], &xs, distance).unwrap_or_default();
+        }
+        if window_interaction.top {
+            rect.min.y += snap_delta(&[rect.top()], &ys, distance).unwrap_or_default();
+        } else if window_interaction.bottom {
+            rect.max.y += snap_delta(&[rect.bottom()], &ys, distance).unwrap_or_default();
+        }
+    } else {
+        let dx = snap_delta(&[rect.left(), rect.right()], &xs, distance);
+        let dy = snap_delta(&[rect.top(), rect.bottom()], &ys, distance);
+        rect = rect.translate(vec2(dx.unwrap_or_default(), dy.unwrap_or_default()));
+    }
+    rect
+}
+
+/// The smallest move that puts one of the `edges` on one of the `targets`, if it is within `distance`.
+fn snap_delta(edges: &[f32], targets: &[f32], distance: f32) -> Option<f32> {
+    let mut best: Option<f32> = None;
+    for &edge in edges {
+        for &target in targets {
+            let delta = target - edge;
+            if delta.abs() <= distance && best.map_or(true, |best| delta.abs() < best.abs()) {
+                best = Some(delta);
+            }
+        }
+    }
+    best
+}
+
 fn move_and_resize_window(ctx: &Context, window_interaction: &WindowInteraction) -> Option<Rect> {
     window_interaction.set_cursor(ctx);
     let pointer_pos = ctx.input().pointer.interact_pos()?;
@@ -742,6 +928,7 @@ fn show_title_bar(
     ui: &mut Ui,
     title_label: Label,
     show_close_button: bool,
+    minimize_button: bool,
     collapsing_id: Id,
     collapsing: &mut collapsing_header::State,
     collapsible: bool,
@@ -771,9 +958,12 @@ fn show_title_bar(
 
         let title_galley = title_label.layout(ui);
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title is centered):
-            2.0 * (pad + button_size.x + item_spacing.x) + title_galley.size.x
+        let num_right_buttons = show_close_button as usize + minimize_button as usize;
+        let num_buttons = num_right_buttons.max(collapsible as usize);
+        let minimum_width = if num_buttons > 0 {
+            // Make room for the buttons on both sides (since title is centered):
+            2.0 * (pad + num_buttons as f32 * (button_size.x + item_spacing.x))
+                + title_galley.size.x
         } else {
             pad + title_galley.size.x + pad
         };
@@ -802,6 +992,7 @@ impl TitleBar {
         outer_rect: Rect,
         content_response: &Option<Response>,
         open: Option<&mut bool>,
+        minimize_button: bool,
         collapsing: &mut collapsing_header::State,
         collapsible: bool,
     ) {
@@ -810,11 +1001,19 @@ impl TitleBar {
             self.rect.max.x = self.rect.max.x.max(content_response.rect.max.x);
         }
 
+        // Add the buttons now that we know our full width:
+        let mut num_right_buttons = 0;
         if let Some(open) = open {
-            // Add close button now that we know our full width:
-            if self.close_button_ui(ui).clicked() {
+            if close_button(ui, self.button_rect(ui, num_right_buttons)).clicked() {
                 *open = false;
             }
+            num_right_buttons += 1;
+        }
+        if minimize_button {
+            let rect = self.button_rect(ui, num_right_buttons);
+            if minimize_button_ui(ui, rect, collapsing.is_expanded()).clicked() {
+                collapsing.toggle(ui);
+            }
         }
 
         // Always have inactive style for the window.
@@ -850,19 +1049,44 @@ impl TitleBar {
         }
     }
 
-    fn close_button_ui(&self, ui: &mut Ui) -> Response {
+    /// Where to put a button on the right of the title bar, counting from the right.
+    fn button_rect(&self, ui: &Ui, index: usize) -> Rect {
         let button_size = Vec2::splat(ui.spacing().icon_width);
         let pad = (self.rect.height() - button_size.y) / 2.0; // calculated so that the icon is on the diagonal (if window padding is symmetrical)
-        let button_rect = Rect::from_min_size(
+        let right =
+            self.rect.right() - pad - index as f32 * (button_size.x + ui.spacing().item_spacing.x);
+        Rect::from_min_size(
             pos2(
-                self.rect.right() - pad - button_size.x,
+                right - button_size.x,
                 self.rect.center().y - 0.5 * button_size.y,
             ),
             button_size,
-        );
+        )
+    }
+}
+
+/// A line to minimize, or a square to restore if the window is minimized.
+fn minimize_button_ui(ui: &mut Ui, rect: Rect, is_open: bool) -> Response {
+    let id = ui.auto_id_with("window_minimize_button");
+    let response = ui.interact(rect, id, Sense::click());
+    ui.expand_to_include_rect(response.rect);
+    response.widget_info(|| {
+        WidgetInfo::labeled(
+            WidgetType::Button,
+            if is_open { "Minimize" } else { "Restore" },
+        )
+    });
 
-        close_button(ui, button_rect)
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    let stroke = visuals.fg_stroke;
+    if is_open {
+        ui.painter()
+            .line_segment([rect.left_bottom(), rect.right_bottom()], stroke);
+    } else {
+        ui.painter().rect_stroke(rect, 0.0, stroke);
     }
+    response
 }
 
 fn close_button(ui: &mut Ui, rect: Rect) -> Response {
@@ -879,3 +1103,75 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[test]
+fn test_window_collapsed() {
+    use crate::test_util::run_frame;
+    let mut ctx = CtxRef::default();
+    let run_frames = |ctx: &mut CtxRef, collapsed: Option<bool>| {
+        let mut shown = false;
+        for _ in 0..10 {
+            shown = false;
+            run_frame(ctx, vec![], |ctx| {
+                let mut window = Window::new("Window").minimize_button(true);
+                if let Some(collapsed) = collapsed {
+                    window = window.collapsed(collapsed);
+                }
+                window.show(ctx, |_ui| shown = true);
+            });
+        }
+        shown
+    };
+    let id = Id::new("Window");
+
+    assert!(run_frames(&mut ctx, None));
+    assert!(!Window::is_collapsed(&ctx, id));
+
+    Window::set_collapsed(&ctx, id, true);
+    assert!(!run_frames(&mut ctx, None));
+    assert!(Window::is_collapsed(&ctx, id));
+
+    // Overridden from code:
+    assert!(run_frames(&mut ctx, Some(false)));
+    assert!(!Window::is_collapsed(&ctx, id));
+}
+
+#[test]
+fn test_snap_rect() {
+    let bounds = Rect::from_min_size(Pos2::ZERO, vec2(1000.0, 800.0));
+    let other = Rect::from_min_size(pos2(500.0, 100.0), vec2(200.0, 200.0));
+    let interaction = |left: bool, bottom: bool| WindowInteraction {
+        area_layer_id: LayerId::background(),
+        start_rect: Rect::NOTHING,
+        left,
+        right: false,
+        top: false,
+        bottom,
+    };
+    let snap = |rect: Rect, interaction: WindowInteraction| {
+        snap_rect(rect, bounds, &[other], 10.0, &interaction)
+    };
+
+    // Moving next to the other window and close to the top of the screen:
+    let rect = Rect::from_min_size(pos2(295.0, 6.0), vec2(200.0, 100.0));
+    let snapped = snap(rect, interaction(false, false));
+    assert_eq!(snapped.min, pos2(300.0, 0.0));
+    assert_eq!(snapped.size(), rect.size());
+
+    // Too far away to snap:
+    let rect = Rect::from_min_size(pos2(250.0, 400.0), vec2(200.0, 100.0));
+    assert_eq!(snap(rect, interaction(false, false)), rect);
+
+    // Resizing only snaps the edges being dragged:
+    let rect = Rect::from_min_max(pos2(4.0, 400.0), pos2(200.0, 795.0));
+    let snapped = snap(rect, interaction(false, true));
+    assert_eq!(
+        snapped,
+        Rect::from_min_max(pos2(4.0, 400.0), pos2(200.0, 800.0))
+    );
+    let snapped = snap(rect, interaction(true, false));
+    assert_eq!(
+        snapped,
+        Rect::from_min_max(pos2(0.0, 400.0), pos2(200.0, 795.0))
+    );
+}
@@ -0,0 +1,246 @@
+//! A sequence of pages with back and next buttons, see [`Wizard`].
+
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    page: usize,
+}
+
+/// What happened in a [`Wizard`] this frame.
+pub struct WizardResponse {
+    /// The whole wizard.
+    pub response: Response,
+
+    /// The index of the page that was shown.
+    pub page: usize,
+
+    /// The user clicked "Finish" on the last page. The wizard starts over from the first page.
+    pub finished: bool,
+}
+
+/// A multi-step dialog: one page at a time, with "Back" and "Next" buttons,
+/// and "Finish" on the last page.
+///
+/// You show the contents of the current page in the closure given to [`Self::show`],
+/// and return `true` if the page is complete. Until then "Next" (or "Finish") is disabled.
+///
+/// Above the page the titles of all pages are shown, so the user can see how far along they are.
+/// Clicking the title of an earlier page goes back to it.
+///
+/// The current page is stored by egui, unless you use [`Self::page_index`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut name = String::new();
+/// # let mut email = String::new();
+/// let response = egui::Wizard::new("sign_up")
+///     .page("Name")
+///     .page("Email")
+///     .page("Done")
+///     .show(ui, |ui, page| match page {
+///         0 => {
+///             ui.text_edit_singleline(&mut name);
+///             !name.is_empty()
+///         }
+///         1 => {
+///             ui.text_edit_singleline(&mut email);
+///             email.contains('@')
+///         }
+///         _ => {
+///             ui.label(format!("Welcome, {}!", name));
+///             true
+///         }
+///     });
+/// if response.finished {
+///     // sign up
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Wizard<'a> {
+    id_source: Id,
+    pages: Vec<String>,
+    page_index: Option<&'a mut usize>,
+    show_steps: bool,
+    finish_text: String,
+}
+
+impl<'a> Wizard<'a> {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            pages: Default::default(),
+            page_index: None,
+            show_steps: true,
+            finish_text: "Finish".to_owned(),
+        }
+    }
+
+    /// Add a page with this title to the end.
+    pub fn page(mut self, title: impl ToString) -> Self {
+        self.pages.push(title.to_string());
+        self
+    }
+
+    /// Keep the index of the current page here instead of in egui's memory,
+    /// e.g. to jump to a page from code.
+    pub fn page_index(mut self, page_index: &'a mut usize) -> Self {
+        self.page_index = Some(page_index);
+        self
+    }
+
+    /// Show the titles of the pages at the top. Default: `true`.
+    pub fn show_steps(mut self, show_steps: bool) -> Self {
+        self.show_steps = show_steps;
+        self
+    }
+
+    /// Text of the button on the last page. Default: "Finish".
+    pub fn finish_text(mut self, finish_text: impl ToString) -> Self {
+        self.finish_text = finish_text.to_string();
+        self
+    }
+
+    /// Show the current page with `add_page(ui, page_index)`, which returns `true` if the page is complete.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        add_page: impl FnOnce(&mut Ui, usize) -> bool,
+    ) -> WizardResponse {
+        let Self {
+            id_source,
+            pages,
+            page_index,
+            show_steps,
+            finish_text,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let stored_page = ui.memory().id_data.get_or_default::<State>(id).page;
+        let mut page = page_index.as_deref().copied().unwrap_or(stored_page);
+        page = page.min(pages.len().saturating_sub(1));
+        let shown_page = page;
+        let mut finished = false;
+
+        let response = ui
+            .vertical(|ui| {
+                if show_steps {
+                    ui.horizontal_wrapped(|ui| {
+                        for (i, title) in pages.iter().enumerate() {
+                            if i > 0 {
+                                ui.add(Label::new("›").weak());
+                            }
+                            let text = if i < shown_page {
+                                format!("✔ {}", title)
+                            } else {
+                                format!("{}. {}", i + 1, title)
+                            };
+                            ui.scope(|ui| {
+                                ui.set_enabled(i <= shown_page);
+                                let label = SelectableLabel::new(i == shown_page, text);
+                                if ui.add(label).clicked() {
+                                    page = i;
+                                }
+                            });
+                        }
+                    });
+                    ui.separator();
+                }
+
+                let complete = add_page(ui, shown_page);
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let back = Button::new("Back").enabled(shown_page > 0);
+                    if ui.add(back).clicked() {
+                        page = shown_page - 1;
+                    }
+                    ui.with_layout(Layout::right_to_left(), |ui| {
+                        let is_last = shown_page + 1 >= pages.len();
+                        let next_text = if is_last {
+                            finish_text.as_str()
+                        } else {
+                            "Next"
+                        };
+                        let next = Button::new(next_text).enabled(complete);
+                        if ui.add(next).clicked() {
+                            if is_last {
+                                finished = true;
+                                page = 0;
+                            } else {
+                                page = shown_page + 1;
+                            }
+                        }
+                    });
+                });
+            })
+            .response;
+
+        if page != shown_page {
+            ui.ctx().request_repaint();
+        }
+        match page_index {
+            Some(page_index) => *page_index = page,
+            None => {
+                ui.memory().id_data.insert(id, State { page });
+            }
+        }
+
+        WizardResponse {
+            response,
+            page: shown_page,
+            finished,
+        }
+    }
+}
+
+#[test]
+fn test_wizard() {
+    use crate::test_util::{click, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut name = String::new();
+    let mut run_frame = |events: Vec<Event>, name: &mut String| {
+        let response = run_ui_frame(&mut ctx, events, |ui| {
+            Wizard::new("wizard")
+                .page("Name")
+                .page("Done")
+                .show(ui, |ui, page| match page {
+                    0 => !name.is_empty(),
+                    _ => {
+                        ui.label("Done!");
+                        true
+                    }
+                })
+        });
+        (response.page, response.finished, response.response.rect)
+    };
+    let next_pos = |rect: Rect| rect.right_bottom() + vec2(-10.0, -8.0);
+    let back_pos = |rect: Rect| rect.left_bottom() + vec2(10.0, -8.0);
+
+    let (page, _, rect) = run_frame(vec![], &mut name);
+    assert_eq!(page, 0);
+
+    // "Next" is disabled until the page is complete:
+    run_frame(click(next_pos(rect)), &mut name);
+    let (page, _, rect) = run_frame(vec![], &mut name);
+    assert_eq!(page, 0);
+
+    name.push_str("Ferris");
+    run_frame(click(next_pos(rect)), &mut name);
+    let (page, _, rect) = run_frame(vec![], &mut name);
+    assert_eq!(page, 1);
+
+    run_frame(click(back_pos(rect)), &mut name);
+    let (page, _, rect) = run_frame(vec![], &mut name);
+    assert_eq!(page, 0);
+
+    run_frame(click(next_pos(rect)), &mut name);
+    let (_, _, rect) = run_frame(vec![], &mut name);
+    let (page, finished, _) = run_frame(click(next_pos(rect)), &mut name);
+    assert_eq!((page, finished), (1, true));
+    assert_eq!(run_frame(vec![], &mut name).0, 0);
+}
@@ -10,7 +10,7 @@ use crate::{
     data::output::Output,
     frame_state::FrameState,
     input_state::*,
-    layers::GraphicLayers,
+    layers::{GraphicLayers, LayerTransitions},
     mutex::{Mutex, MutexGuard},
     *,
 };
@@ -178,8 +178,16 @@ impl CtxRef {
         enabled: bool,
         hovered: bool,
     ) -> Response {
+        let enabled = enabled && self.is_layer_enabled(layer_id);
         let hovered = hovered && enabled; // can't even hover disabled widgets
 
+        let is_interactive = enabled && sense.interactive() && layer_id.allow_interaction();
+        if is_interactive || self.memory().options.register_all_widget_rects {
+            self.frame_state()
+                .widget_rects
+                .insert(id, WidgetRect { layer_id, rect });
+        }
+
         let mut response = Response {
             ctx: self.clone(),
             layer_id,
@@ -195,6 +203,7 @@ impl CtxRef {
             is_pointer_button_down_on: false,
             interact_pointer_pos: None,
             changed: false, // must be set by the widget itself
+            has_error: false,
         };
 
         if !enabled || !sense.focusable || !layer_id.allow_interaction() {
@@ -305,6 +314,34 @@ impl CtxRef {
 
 // ----------------------------------------------------------------------------
 
+/// Where a widget is shown, see [`Context::widget_rect`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WidgetRect {
+    /// The layer the widget is painted in, e.g. that of its [`Window`].
+    pub layer_id: LayerId,
+
+    /// The rect of the widget, in screen coordinates.
+    pub rect: Rect,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Decides what happens to an opened url, see [`Context::set_link_handler`].
+type LinkHandler = Arc<dyn Fn(&output::OpenUrl) -> bool + Send + Sync>;
+
+/// Looks up the color of a texel, see [`Context::set_texel_reader`].
+type TexelReader = Arc<dyn Fn(TextureId, [usize; 2]) -> Option<Color32> + Send + Sync>;
+
+/// The pixels read back from the screen, see [`Context::read_screen_pixels`].
+#[derive(Default)]
+struct ScreenPixels {
+    /// What was asked for this frame, in points.
+    requested: Option<Rect>,
+
+    /// What the integration read back after painting the last frame, and where it is, in points.
+    latest: Option<(Rect, ColorImage)>,
+}
+
 /// This is the first thing you need when working with egui. Create using [`CtxRef`].
 ///
 /// Contains the [`InputState`], [`Memory`], [`Output`], and more.
@@ -335,10 +372,15 @@ pub struct Context {
 
     // The output of a frame:
     graphics: Arc<Mutex<GraphicLayers>>,
+    layer_transitions: Arc<Mutex<LayerTransitions>>,
     output: Arc<Mutex<Output>>,
 
     paint_stats: Arc<Mutex<PaintStats>>,
 
+    link_handler: Arc<Mutex<Option<LinkHandler>>>,
+    texel_reader: Arc<Mutex<Option<TexelReader>>>,
+    screen_pixels: Arc<Mutex<ScreenPixels>>,
+
     /// While positive, keep requesting repaints. Decrement at the end of each frame.
     repaint_requests: AtomicU32,
 }
@@ -352,8 +394,12 @@ impl Clone for Context {
             input: self.input.clone(),
             frame_state: self.frame_state.clone(),
             graphics: self.graphics.clone(),
+            layer_transitions: self.layer_transitions.clone(),
             output: self.output.clone(),
             paint_stats: self.paint_stats.clone(),
+            link_handler: self.link_handler.clone(),
+            texel_reader: self.texel_reader.clone(),
+            screen_pixels: self.screen_pixels.clone(),
             repaint_requests: self.repaint_requests.load(SeqCst).into(),
         }
     }
@@ -401,6 +447,158 @@ impl Context {
         self.repaint_requests.store(times_to_repaint, SeqCst);
     }
 
+    /// Call this if the UI needs to be repainted in `seconds` seconds, e.g. for the next frame of a slow animation.
+    ///
+    /// If there are several such requests in a frame, the soonest one wins,
+    /// and [`Self::request_repaint`] wins over them all.
+    /// See [`Output::repaint_after`] for how the backend handles this.
+    pub fn request_repaint_after(&self, seconds: f32) {
+        let mut output = self.output();
+        let seconds = seconds.max(0.0);
+        output.repaint_after = Some(match output.repaint_after {
+            Some(repaint_after) => repaint_after.min(seconds),
+            None => seconds,
+        });
+    }
+
+    /// Open a url, e.g. because a [`Hyperlink`] was clicked.
+    ///
+    /// It goes to the [link handler](Self::set_link_handler) if there is one.
+    /// If not, or if the handler doesn't take care of it,
+    /// it is put in [`Output::open_url`] for the integration to open in a web browser.
+    pub fn open_url(&self, open_url: output::OpenUrl) {
+        let link_handler = self.link_handler.lock().clone();
+        if let Some(link_handler) = link_handler {
+            if link_handler(&open_url) {
+                return;
+            }
+        }
+        self.output().open_url = Some(open_url);
+    }
+
+    /// Route the urls opened by hyperlinks through your own code,
+    /// e.g. to navigate within your app for internal links instead of opening a web browser.
+    ///
+    /// The handler is called with each url given to [`Self::open_url`], e.g. when a [`Hyperlink`] is clicked.
+    /// Return `true` if you took care of it, or `false` to have it opened in a web browser as usual.
+    ///
+    /// ```
+    /// # let ctx = egui::CtxRef::default();
+    /// let (sender, receiver) = std::sync::mpsc::channel();
+    /// let sender = std::sync::Mutex::new(sender);
+    /// ctx.set_link_handler(move |open_url| {
+    ///     if let Some(page) = open_url.url.strip_prefix("app://") {
+    ///         sender.lock().unwrap().send(page.to_owned()).ok();
+    ///         true
+    ///     } else {
+    ///         false
+    ///     }
+    /// });
+    ///
+    /// // Later, each frame:
+    /// for page in receiver.try_iter() {
+    ///     // go to `page`
+    /// }
+    /// ```
+    pub fn set_link_handler(
+        &self,
+        handler: impl Fn(&output::OpenUrl) -> bool + Send + Sync + 'static,
+    ) {
+        *self.link_handler.lock() = Some(Arc::new(handler));
+    }
+
+    /// Go back to opening all urls in a web browser, see [`Self::set_link_handler`].
+    pub fn clear_link_handler(&self) {
+        *self.link_handler.lock() = None;
+    }
+
+    /// The color of the texel at `[x, y]` in a texture, or `None` if it isn't known.
+    ///
+    /// egui knows the texels of its own font texture ([`TextureId::Egui`]).
+    /// For user textures, this asks the [texel reader](Self::set_texel_reader).
+    /// Used by e.g. [`ImageViewer`] to show the color under the cursor.
+    pub fn read_texel(&self, texture_id: TextureId, [x, y]: [usize; 2]) -> Option<Color32> {
+        if texture_id == TextureId::Egui {
+            let texture = self.fonts.as_ref()?.texture();
+            return (x < texture.width && y < texture.height)
+                .then(|| Color32::from_white_alpha(texture[(x, y)]));
+        }
+        let texel_reader = self.texel_reader.lock().clone();
+        texel_reader.and_then(|texel_reader| texel_reader(texture_id, [x, y]))
+    }
+
+    /// Tell egui how to look up the colors of texels in your textures, see [`Self::read_texel`].
+    ///
+    /// egui only has the ids of user textures, so the integration or the app,
+    /// which has the texels, must install this to read them back.
+    /// `egui_glium` and `egui_web` install one for the user textures they were given the pixels of.
+    /// Return `None` for textures or texels you don't know.
+    ///
+    /// ```
+    /// # let ctx = egui::CtxRef::default();
+    /// # let (my_texture_id, width) = (egui::TextureId::User(0), 16);
+    /// let pixels = vec![egui::Color32::RED; width * width];
+    /// ctx.set_texel_reader(move |texture_id, [x, y]| {
+    ///     if texture_id == my_texture_id {
+    ///         pixels.get(y * width + x).copied()
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    /// ```
+    pub fn set_texel_reader(
+        &self,
+        reader: impl Fn(TextureId, [usize; 2]) -> Option<Color32> + Send + Sync + 'static,
+    ) {
+        *self.texel_reader.lock() = Some(Arc::new(reader));
+    }
+
+    /// The pixels of what egui painted within `rect` (in points), or `None` if they aren't known yet.
+    ///
+    /// The pixels are read back from the screen after painting, so this asks the integration
+    /// for them (see [`Output::read_pixels`]) and returns them on a later frame.
+    /// Keep calling this every frame for as long as you want the pixels;
+    /// you then get the ones painted the frame before.
+    /// Use it for e.g. an eyedropper or a magnifier.
+    ///
+    /// The image is in physical pixels, so it is [`Self::pixels_per_point`] times as big as `rect`.
+    /// Returns `None` forever if the integration doesn't support reading back pixels.
+    pub fn read_screen_pixels(&self, rect: Rect) -> Option<ColorImage> {
+        let rect = rect.intersect(self.input.screen_rect());
+        if !rect.is_positive() {
+            return None;
+        }
+        let mut screen_pixels = self.screen_pixels.lock();
+        screen_pixels.requested = Some(match screen_pixels.requested {
+            Some(requested) => requested.union(rect),
+            None => rect,
+        });
+        match &screen_pixels.latest {
+            Some((latest_rect, image)) if latest_rect.contains_rect(rect) => {
+                let pixels_per_point = image.width() as f32 / latest_rect.width();
+                let min = (rect.min - latest_rect.min) * pixels_per_point;
+                let size = rect.size() * pixels_per_point;
+                Some(image.region(
+                    [min.x.round() as usize, min.y.round() as usize],
+                    [size.x.round() as usize, size.y.round() as usize],
+                ))
+            }
+            _ => {
+                self.request_repaint();
+                None
+            }
+        }
+    }
+
+    /// For integrations: the pixels of the screen within `rect` (in points),
+    /// read back after painting because of [`Output::read_pixels`].
+    ///
+    /// `rect` may differ from what was asked for, e.g. rounded to whole pixels,
+    /// as long as the pixels fill it exactly.
+    pub fn set_screen_pixels(&self, rect: Rect, image: ColorImage) {
+        self.screen_pixels.lock().latest = Some((rect, image));
+    }
+
     #[inline(always)]
     pub fn input(&self) -> &InputState {
         &self.input
@@ -472,6 +670,101 @@ impl Context {
         std::sync::Arc::make_mut(&mut self.memory().options.style).visuals = visuals;
     }
 
+    /// Switch to one of the built-in themes, replacing the current [`Style`].
+    ///
+    /// The choice is remembered by [`Memory`], so with the `persistence` feature
+    /// the theme is restored the next time the app starts.
+    ///
+    /// See also [`crate::style::Theme::switcher_ui`].
+    ///
+    /// Example:
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// ctx.set_theme(egui::Theme::Touch);
+    /// assert_eq!(ctx.theme(), Some(egui::Theme::Touch));
+    /// ```
+    pub fn set_theme(&self, theme: crate::style::Theme) {
+        let mut memory = self.memory();
+        memory.options.style = Arc::new(theme.style());
+        memory.options.theme = Some(theme);
+        memory.options.applied_theme = Some(theme);
+    }
+
+    /// The theme last set with [`Self::set_theme`],
+    /// or `None` if the style has since been changed in some other way.
+    pub fn theme(&self) -> Option<crate::style::Theme> {
+        let memory = self.memory();
+        let theme = memory.options.theme?;
+        if memory.options.applied_theme == Some(theme) && *memory.options.style == theme.style() {
+            Some(theme)
+        } else {
+            None
+        }
+    }
+
+    /// Disable all of egui, e.g. while your app is busy with something in the background.
+    ///
+    /// Nothing responds to clicks or keys, and everything is tinted to look grayed out,
+    /// except for the windows and areas you [`Window::keep_enabled`], which are shown on top.
+    /// Any open popup is closed.
+    ///
+    /// This stays in effect until you enable egui again.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// # let is_saving = true;
+    /// ctx.set_enabled(!is_saving);
+    /// if is_saving {
+    ///     egui::Window::new("Saving…").keep_enabled(true).show(ctx, |ui| {
+    ///         ui.label("Please wait");
+    ///     });
+    /// }
+    /// ```
+    pub fn set_enabled(&self, enabled: bool) {
+        let mut memory = self.memory();
+        if !enabled && !memory.disabled {
+            memory.close_popup();
+        }
+        memory.disabled = !enabled;
+    }
+
+    /// The events logged since the last call, if [`crate::memory::Options::log_ui_events`] is on.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// ctx.memory().options.log_ui_events = true;
+    /// # ctx.begin_frame(Default::default());
+    /// // …
+    /// for event in ctx.take_ui_events() {
+    ///     if let egui::UiEvent::Clicked { info, .. } = event {
+    ///         println!("Clicked {}", info.description());
+    ///     }
+    /// }
+    /// ```
+    pub fn take_ui_events(&self) -> Vec<UiEvent> {
+        std::mem::take(&mut self.memory().event_log.events)
+    }
+
+    /// Log an event, if [`crate::memory::Options::log_ui_events`] is on.
+    pub(crate) fn log_ui_event(&self, make_event: impl FnOnce() -> UiEvent) {
+        let mut memory = self.memory();
+        if memory.options.log_ui_events {
+            memory.event_log.events.push(make_event());
+        }
+    }
+
+    /// `false` while egui is disabled with [`Self::set_enabled`].
+    pub fn is_enabled(&self) -> bool {
+        !self.memory().disabled
+    }
+
+    /// Can widgets on this layer be interacted with, given [`Self::set_enabled`]?
+    fn is_layer_enabled(&self, layer_id: LayerId) -> bool {
+        self.is_enabled() || self.frame_state().keep_enabled_layers.contains(&layer_id)
+    }
+
     /// The number of physical pixels for each logical point.
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
@@ -555,6 +848,18 @@ impl Context {
     fn begin_frame_mut(&mut self, new_raw_input: RawInput) {
         self.memory().begin_frame(&self.input, &new_raw_input);
 
+        {
+            // Apply a theme restored from persisted memory:
+            let mut memory = self.memory();
+            let options = &mut memory.options;
+            if options.theme != options.applied_theme {
+                if let Some(theme) = options.theme {
+                    options.style = Arc::new(theme.style());
+                }
+                options.applied_theme = options.theme;
+            }
+        }
+
         let mut input = std::mem::take(&mut self.input);
         if let Some(new_pixels_per_point) = self.memory().new_pixels_per_point.take() {
             input.pixels_per_point = new_pixels_per_point;
@@ -604,24 +909,188 @@ impl Context {
             self.request_repaint();
         }
 
+        self.animate_layer_transitions();
+        self.paint_disabled_tint();
+        self.paint_widget_highlights();
+
         self.memory()
             .end_frame(&self.input, &self.frame_state().used_ids);
 
         self.fonts().end_frame();
 
         let mut output: Output = std::mem::take(&mut self.output());
+        if self.memory().options.screen_reader {
+            output.speech = output
+                .events
+                .iter()
+                .map(crate::output::OutputEvent::description)
+                .filter(|description| !description.is_empty())
+                .collect();
+        }
         if self.repaint_requests.load(SeqCst) > 0 {
             self.repaint_requests.fetch_sub(1, SeqCst);
             output.needs_repaint = true;
         }
+        if output.needs_repaint {
+            output.repaint_after = None;
+        } else if output.repaint_after.is_some() {
+            // For backends that don't know about `repaint_after`:
+            output.needs_repaint = true;
+        }
+
+        {
+            let mut screen_pixels = self.screen_pixels.lock();
+            output.read_pixels = screen_pixels.requested.take();
+            if output.read_pixels.is_none() {
+                screen_pixels.latest = None;
+            }
+        }
 
         let shapes = self.drain_paint_lists();
         (output, shapes)
     }
 
+    /// Fade windows and popups in and out as they open and close.
+    fn animate_layer_transitions(&self) {
+        let style = self.style();
+        let animation = style.effective_window_animation();
+        if animation == style::WindowAnimation::None || style.animation_time <= 0.0 {
+            *self.layer_transitions.lock() = Default::default();
+            return;
+        }
+
+        let in_progress = self.layer_transitions.lock().end_frame(
+            &self.memory().areas,
+            &mut self.graphics(),
+            self.input.time,
+            style.animation_time,
+            animation == style::WindowAnimation::FadeAndSlide,
+        );
+        if in_progress {
+            self.request_repaint();
+        }
+    }
+
+    /// Gray out everything but the layers that are kept enabled, see [`Self::set_enabled`].
+    fn paint_disabled_tint(&self) {
+        let disabled = !self.is_enabled();
+        let tint = self.animate_bool(Self::disabled_tint_layer().id, disabled);
+        if tint > 0.0 {
+            let color = self
+                .style()
+                .visuals
+                .window_fill()
+                .linear_multiply(0.6 * tint);
+            let screen_rect = self.input.screen_rect();
+            self.graphics()
+                .list(Self::disabled_tint_layer())
+                .lock()
+                .add(screen_rect, Shape::rect_filled(screen_rect, 0.0, color));
+        }
+    }
+
+    /// Where the widget with the given [`Id`] (see [`Response::id`]) is shown:
+    /// this frame if it has been shown yet, or else the previous frame.
+    ///
+    /// This lets you find widgets created elsewhere, e.g. to anchor a popup to them,
+    /// or to click "the widget with this id" in a test.
+    ///
+    /// Only interactive widgets (buttons, sliders, text edits, …) are found,
+    /// unless [`crate::memory::Options::register_all_widget_rects`] is on.
+    pub fn widget_rect(&self, id: Id) -> Option<WidgetRect> {
+        let frame_state = self.frame_state();
+        frame_state
+            .widget_rects
+            .get(&id)
+            .or_else(|| frame_state.prev_widget_rects.get(&id))
+            .copied()
+    }
+
+    /// Draw an animated ring around the widget with the given [`Id`] for a second,
+    /// to draw the user's attention to it, e.g. for a "show me where this setting is" search result.
+    ///
+    /// The widget is found with [`Self::widget_rect`], so it must be interactive,
+    /// unless [`crate::memory::Options::register_all_widget_rects`] is on.
+    /// Nothing is drawn while the widget isn't shown.
+    pub fn highlight_widget(&self, id: Id) {
+        let time = self.input.time;
+        let mut memory = self.memory();
+        memory
+            .highlighted_widgets
+            .retain(|(highlighted_id, _)| *highlighted_id != id);
+        memory.highlighted_widgets.push((id, time));
+    }
+
+    fn paint_widget_highlights(&self) {
+        const DURATION: f64 = 1.0;
+
+        let time = self.input.time;
+        let highlights = {
+            let mut memory = self.memory();
+            memory
+                .highlighted_widgets
+                .retain(|(_, start_time)| time - start_time < DURATION);
+            memory.highlighted_widgets.clone()
+        };
+        if highlights.is_empty() {
+            return;
+        }
+        self.request_repaint();
+
+        let style = self.style();
+        let reduce_motion = style.reduce_motion;
+        let color = style.visuals.selection.bg_fill;
+        let corner_radius = style.visuals.widgets.inactive.corner_radius;
+        let screen_rect = self.input.screen_rect();
+        let layer_id = LayerId::new(Order::Foreground, Id::new("__widget_highlights"));
+
+        for (id, start_time) in highlights {
+            let rect = match self.widget_rect(id) {
+                Some(widget_rect) => widget_rect.rect,
+                None => continue,
+            };
+            let t = ((time - start_time) / DURATION) as f32;
+            let (expansion, opacity) = if reduce_motion {
+                (3.0, (std::f32::consts::PI * t).sin())
+            } else {
+                // Two rings, closing in on the widget:
+                let pulse = (2.0 * t).fract();
+                (3.0 + 12.0 * (1.0 - pulse), pulse.sqrt() * (1.0 - t * t))
+            };
+            let stroke = Stroke::new(2.0, color.linear_multiply(opacity));
+            let shape =
+                Shape::rect_stroke(rect.expand(expansion), corner_radius + expansion, stroke);
+            self.graphics()
+                .list(layer_id)
+                .lock()
+                .add(screen_rect, shape);
+        }
+    }
+
+    fn disabled_tint_layer() -> LayerId {
+        LayerId::new(Order::Middle, Id::new("__disabled_tint"))
+    }
+
     fn drain_paint_lists(&self) -> Vec<ClippedShape> {
         let memory = self.memory();
-        self.graphics().drain(memory.areas.order()).collect()
+        let mut order = memory.areas.order().to_vec();
+
+        // The tint goes just below the layers that are kept enabled, which are on top:
+        let keep_enabled_layers = &self.frame_state().keep_enabled_layers;
+        let tint_index = order
+            .iter()
+            .position(|layer_id| {
+                layer_id.order == Order::Middle && keep_enabled_layers.contains(layer_id)
+            })
+            .unwrap_or_else(|| {
+                order
+                    .iter()
+                    .rposition(|layer_id| layer_id.order <= Order::Middle)
+                    .map_or(0, |index| index + 1)
+            });
+        order.insert(tint_index, Self::disabled_tint_layer());
+
+        self.graphics().drain(&order).collect()
     }
 
     /// Tessellate the given shapes into triangle meshes.
@@ -629,6 +1098,9 @@ impl Context {
         let mut tessellation_options = self.memory().options.tessellation_options;
         tessellation_options.pixels_per_point = self.pixels_per_point();
         tessellation_options.aa_size = 1.0 / self.pixels_per_point();
+        if self.degrade() {
+            tessellation_options.anti_alias = false;
+        }
         let paint_stats = PaintStats::from_shapes(&shapes); // TODO: internal allocations
         let clipped_meshes = tessellator::tessellate_shapes(
             shapes,
@@ -720,6 +1192,34 @@ impl Context {
         self.memory().layer_id_at(pos, resize_grab_radius_side)
     }
 
+    /// Bring an [`Area`] or [`Window`] in front of all others with the same [`Order`].
+    ///
+    /// The layer of a window with id `id` is `LayerId::new(Order::Middle, id)`.
+    pub fn move_to_top(&self, layer_id: LayerId) {
+        self.memory().areas.move_to_top(layer_id);
+        self.request_repaint();
+    }
+
+    /// Send an [`Area`] or [`Window`] behind all others with the same [`Order`].
+    pub fn move_to_bottom(&self, layer_id: LayerId) {
+        self.memory().areas.move_to_bottom(layer_id);
+        self.request_repaint();
+    }
+
+    /// Is this [`Area`] or [`Window`] kept above the others with the same [`Order`],
+    /// with [`Area::always_on_top`]?
+    pub fn is_always_on_top(&self, layer_id: LayerId) -> bool {
+        self.memory().areas.is_always_on_top(layer_id)
+    }
+
+    /// All the layers, from back to front.
+    ///
+    /// Layers are first sorted by their [`Order`], then by [`Area::always_on_top`],
+    /// then by which was clicked (or moved to the top) last.
+    pub fn layer_order(&self) -> Vec<LayerId> {
+        self.memory().areas.order().to_vec()
+    }
+
     pub(crate) fn rect_contains_pointer(&self, layer_id: LayerId, rect: Rect) -> bool {
         if let Some(pointer_pos) = self.input.pointer.interact_pos() {
             rect.contains(pointer_pos) && self.layer_id_at(pointer_pos) == Some(layer_id)
@@ -753,11 +1253,18 @@ impl Context {
     ///
     /// The function will call [`Self::request_repaint()`] when appropriate.
     pub fn animate_bool(&self, id: Id, value: bool) -> f32 {
-        let animation_time = self.style().animation_time;
+        self.animate_bool_with_time(id, value, self.style().animation_time)
+    }
+
+    /// Like [`Self::animate_bool`] but allows you to control the animation time.
+    pub fn animate_bool_with_time(&self, id: Id, value: bool, animation_time: f32) -> f32 {
         let animated_value =
             self.animation_manager
                 .lock()
                 .animate_bool(&self.input, animation_time, id, value);
+        if self.degrade() {
+            return if value { 1.0 } else { 0.0 };
+        }
         let animation_in_progress = 0.0 < animated_value && animated_value < 1.0;
         if animation_in_progress {
             self.request_repaint();
@@ -765,6 +1272,47 @@ impl Context {
         animated_value
     }
 
+    /// Returns a value that moves smoothly to `value` whenever `value` changes, e.g. for a needle on a gauge.
+    ///
+    /// The first time called it will return `value`.
+    /// When `value` changes, the returned value goes from where it was to the new `value`
+    /// over [`Style::animation_time`] seconds.
+    ///
+    /// The function will call [`Self::request_repaint()`] when appropriate.
+    pub fn animate_value(&self, id: Id, value: f32) -> f32 {
+        self.animate_value_with_time(id, value, self.style().animation_time)
+    }
+
+    /// Like [`Self::animate_value`] but allows you to control the animation time.
+    pub fn animate_value_with_time(&self, id: Id, value: f32, animation_time: f32) -> f32 {
+        let animated_value =
+            self.animation_manager
+                .lock()
+                .animate_value(&self.input, animation_time, id, value);
+        if self.degrade() {
+            return value;
+        }
+        let animation_in_progress = animated_value != value;
+        if animation_in_progress {
+            self.request_repaint();
+        }
+        animated_value
+    }
+
+    /// Is the app struggling to keep up with [`crate::memory::Options::frame_budget`]?
+    ///
+    /// If so, skip expensive effects to keep the interaction responsive.
+    /// egui itself then paints no shadows, skips animations and turns off anti-aliasing.
+    pub fn degrade(&self) -> bool {
+        self.memory().frame_budget.degrade()
+    }
+
+    /// The average time the recent frames took, in seconds,
+    /// if the integration sets [`crate::RawInput::previous_frame_time`].
+    pub fn average_frame_time(&self) -> Option<f32> {
+        self.memory().frame_budget.average_frame_time()
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         *self.animation_manager.lock() = Default::default();
@@ -797,6 +1345,33 @@ impl Context {
                 tessellation_options.ui(ui);
                 ui.vertical_centered(|ui| reset_button(ui, &mut tessellation_options));
                 self.memory().options.tessellation_options = tessellation_options;
+
+                let mut frame_budget = self.memory().options.frame_budget;
+                ui.horizontal(|ui| {
+                    let mut enabled = frame_budget.is_some();
+                    ui.checkbox(&mut enabled, "Frame budget")
+                        .on_hover_text("Skip expensive effects when frames take longer than this");
+                    let mut ms = 1e3 * frame_budget.unwrap_or(1.0 / 60.0);
+                    ui.scope(|ui| {
+                        ui.set_enabled(enabled);
+                        ui.add(
+                            crate::DragValue::new(&mut ms)
+                                .clamp_range(1.0..=1000.0)
+                                .suffix(" ms"),
+                        );
+                    });
+                    frame_budget = enabled.then(|| ms / 1e3);
+                });
+                self.memory().options.frame_budget = frame_budget;
+                if let Some(average) = self.average_frame_time() {
+                    ui.label(format!("Average frame time: {:.1} ms", 1e3 * average));
+                }
+                if self.degrade() {
+                    ui.colored_label(
+                        ui.visuals().error_fg_color,
+                        "Degraded: the frames take too long",
+                    );
+                }
             });
     }
 
@@ -828,11 +1403,20 @@ impl Context {
         .on_hover_text("Is egui currently listening for text input?");
         ui.add_space(16.0);
 
+        let galley_cache_stats = self.fonts().galley_cache_stats();
         ui.label(format!(
-            "There are {} text galleys in the layout cache",
-            self.fonts().num_galleys_in_cache()
+            "There are {} text galleys in the layout cache ({:.2} MB)",
+            galley_cache_stats.num_galleys,
+            galley_cache_stats.num_bytes as f64 / 1e6
         ))
-        .on_hover_text("This is approximately the number of text strings on screen");
+        .on_hover_text("Text laid out recently, kept so it doesn't need to be laid out again");
+        ui.label(format!(
+            "Last frame: {} cache hits, {} misses ({:.0}% hit rate)",
+            galley_cache_stats.hits,
+            galley_cache_stats.misses,
+            100.0 * galley_cache_stats.hit_rate()
+        ))
+        .on_hover_text("A miss means a text had to be laid out");
         ui.add_space(16.0);
 
         CollapsingHeader::new("📥 Input")
@@ -946,8 +1530,164 @@ impl Context {
 
 impl Context {
     pub fn style_ui(&self, ui: &mut Ui) {
+        crate::style::Theme::switcher_ui(ui);
         let mut style: Style = (*self.style()).clone();
         style.ui(ui);
         self.set_style(style);
     }
 }
+
+#[test]
+fn test_set_enabled() {
+    use crate::test_util::click;
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>, enabled: bool| {
+        crate::test_util::run_frame(&mut ctx, events, |ctx| {
+            ctx.set_enabled(enabled);
+            let panel_button = CentralPanel::default()
+                .show(ctx, |ui| ui.button("Panel"))
+                .inner;
+            let mut window_button = None;
+            Window::new("Progress")
+                .keep_enabled(true)
+                .default_pos(pos2(200.0, 200.0))
+                .show(ctx, |ui| window_button = Some(ui.button("Cancel")));
+            (panel_button, window_button.unwrap())
+        })
+    };
+
+    let (panel_button, window_button) = run_frame(vec![], true);
+    let (panel_pos, window_pos) = (panel_button.rect.center(), window_button.rect.center());
+    assert!(run_frame(click(panel_pos), true).0.clicked());
+
+    assert!(!run_frame(click(panel_pos), false).0.clicked());
+    assert!(run_frame(click(window_pos), false).1.clicked());
+}
+
+#[test]
+fn test_speech_output() {
+    use crate::test_util::{key, run_frame_with_input};
+    let mut ctx = CtxRef::default();
+    let mut run_frames = |mut events: Vec<Event>, screen_reader: bool| {
+        let mut speech = vec![];
+        // The events go into the first frame, the second one shows what they led to:
+        for _ in 0..2 {
+            ctx.memory().options.screen_reader = screen_reader;
+            let input = RawInput {
+                events: std::mem::take(&mut events),
+                ..Default::default()
+            };
+            let ((), output) = run_frame_with_input(&mut ctx, input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let _ = ui.button("Open");
+                    let _ = ui.button("Save");
+                });
+            });
+            speech.extend(output.speech);
+        }
+        speech
+    };
+    let tab = || vec![key(Key::Tab)];
+
+    run_frames(vec![], false);
+    assert!(run_frames(tab(), false).is_empty(), "screen reader is off");
+    assert_eq!(run_frames(tab(), true), vec!["Save: button, focused"]);
+}
+
+#[test]
+fn test_highlight_widget() {
+    use crate::test_util::run_frame_with_shapes;
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |time: f64| {
+        let input = RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let ((), output, shapes) = run_frame_with_shapes(&mut ctx, input, |ctx| {
+            let mut button_id = Id::new("unused");
+            CentralPanel::default().show(ctx, |ui| button_id = ui.button("Save").id);
+            if time == 1.0 {
+                ctx.highlight_widget(button_id);
+            }
+        });
+        (output.needs_repaint, shapes.len())
+    };
+
+    run_frame(0.0);
+    let (_, num_shapes) = run_frame(0.5);
+    let (needs_repaint, num_highlighted_shapes) = run_frame(1.0);
+    assert!(needs_repaint);
+    assert_eq!(num_highlighted_shapes, num_shapes + 1);
+    assert_eq!(run_frame(1.5).1, num_shapes + 1);
+
+    // Gone after a second:
+    assert_eq!(run_frame(2.5).1, num_shapes);
+    assert!(!run_frame(3.0).0);
+}
+
+#[test]
+fn test_widget_rect() {
+    use crate::test_util::click;
+    let mut ctx = CtxRef::default();
+    let run_frame = |ctx: &mut CtxRef, events: Vec<Event>| {
+        let (ids, clicked) = crate::test_util::run_frame(ctx, events, |ctx| {
+            let mut ids = (Id::new("unused"), Id::new("unused"));
+            let mut clicked = false;
+            Window::new("Window").show(ctx, |ui| {
+                ids.0 = ui.label("Label").id;
+                let button = ui.button("Click me");
+                ids.1 = button.id;
+                clicked = button.clicked();
+            });
+            (ids, clicked)
+        });
+        (ids, ctx.widget_rect(ids.0), ctx.widget_rect(ids.1), clicked)
+    };
+
+    let (_, label_rect, button_rect, _) = run_frame(&mut ctx, vec![]);
+    assert!(
+        label_rect.is_none(),
+        "labels are only registered on request"
+    );
+    let button_rect = button_rect.unwrap();
+    assert_eq!(button_rect.layer_id.order, Order::Middle);
+
+    // Click "the widget with this id":
+    let pos = button_rect.rect.center();
+    let (_, _, _, clicked) = run_frame(&mut ctx, click(pos));
+    assert!(clicked);
+
+    ctx.memory().options.register_all_widget_rects = true;
+    let ((label_id, _), label_rect, _, _) = run_frame(&mut ctx, vec![]);
+    assert!(label_rect.unwrap().rect.height() > 0.0);
+    // Still known the frame after it was last shown:
+    assert!(ctx.widget_rect(label_id).is_some());
+}
+
+#[test]
+fn test_read_screen_pixels() {
+    use crate::test_util::run_frame_with_input;
+    let mut ctx = CtxRef::default();
+    let rect = Rect::from_min_size(pos2(10.0, 20.0), vec2(2.0, 1.0));
+    let run_frame = |ctx: &mut CtxRef| {
+        let input = RawInput {
+            pixels_per_point: Some(2.0),
+            ..Default::default()
+        };
+        run_frame_with_input(ctx, input, |ctx| ctx.read_screen_pixels(rect))
+    };
+
+    let (pixels, output) = run_frame(&mut ctx);
+    assert!(pixels.is_none(), "nothing read back yet");
+    assert!(output.needs_repaint);
+    assert_eq!(output.read_pixels, Some(rect));
+
+    // The integration reads back a bit more than asked for:
+    let mut image = ColorImage::new([8, 4], Color32::BLACK);
+    image[(4, 2)] = Color32::RED;
+    ctx.set_screen_pixels(Rect::from_min_size(pos2(8.0, 19.0), vec2(4.0, 2.0)), image);
+    let pixels = run_frame(&mut ctx).0.unwrap();
+    assert_eq!(pixels.size, [4, 2]);
+    assert_eq!(pixels[(0, 0)], Color32::RED);
+    assert_eq!(pixels[(1, 1)], Color32::BLACK);
+}
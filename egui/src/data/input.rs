@@ -34,6 +34,21 @@ pub struct RawInput {
     /// `None` will be treated as "same as last frame", with the default being a very big area.
     pub screen_rect: Option<Rect>,
 
+    /// The parts of the edges of the [`Self::screen_rect`] that are hidden or hard to reach,
+    /// e.g. by the notch or rounded corners of a phone, or by the home indicator.
+    ///
+    /// Panels keep their contents out of these by default, see [`crate::CentralPanel::respect_safe_area`].
+    pub safe_area_insets: SafeAreaInsets,
+
+    /// Identifies the monitors and how they are arranged, e.g. a hash of their positions and sizes.
+    ///
+    /// egui remembers where the windows were on each monitor setup, and puts them back there
+    /// when that setup returns, e.g. when docking a laptop again. On a setup it hasn't seen before,
+    /// windows that are now off the screen go back to their default positions.
+    ///
+    /// `None` will be treated as "same as last frame".
+    pub monitor_setup: Option<u64>,
+
     /// Also known as device pixel ratio, > 1 for high resolution screens.
     /// If text looks blurry you probably forgot to set this.
     /// Set this the first frame, whenever it changes, or just on every frame.
@@ -48,15 +63,37 @@ pub struct RawInput {
     /// Can safely be left at its default value.
     pub predicted_dt: f32,
 
+    /// How long the previous frame took to run and paint, in seconds.
+    ///
+    /// Used to notice when the app can't keep up with [`crate::memory::Options::frame_budget`],
+    /// see [`crate::Context::degrade`]. Leave it `None` if you don't measure it.
+    pub previous_frame_time: Option<f32>,
+
     /// Which modifier keys are down at the start of the frame?
     pub modifiers: Modifiers,
 
+    /// Is text from another application being dragged over the window?
+    ///
+    /// Set this while a drag-and-drop with text is hovering the window,
+    /// and send [`Event::DroppedText`] when it is dropped.
+    pub hovered_text: bool,
+
     /// In-order events received this frame.
     ///
     /// There is currently no way to know if egui handles a particular event,
     /// but you can check if egui is using the keyboard with [`crate::Context::wants_keyboard_input`]
     /// and/or the pointer (mouse/touch) with [`crate::Context::is_using_pointer`].
     pub events: Vec<Event>,
+
+    /// When each of the [`Self::events`] happened, on the same clock as [`Self::time`].
+    ///
+    /// This is optional, but lets egui estimate the velocity of the pointer accurately
+    /// when several events arrive in the same frame, e.g. for kinetic scrolling.
+    /// Events without a time (`None`, or past the end of this `Vec`) are treated as
+    /// happening at the start of the frame.
+    ///
+    /// The easiest way to fill this in is with [`Self::push_event_at`].
+    pub event_times: Vec<Option<f64>>,
 }
 
 impl Default for RawInput {
@@ -67,11 +104,16 @@ impl Default for RawInput {
             zoom_delta: 1.0,
             screen_size: Default::default(),
             screen_rect: None,
+            safe_area_insets: Default::default(),
+            monitor_setup: None,
             pixels_per_point: None,
             time: None,
             predicted_dt: 1.0 / 60.0,
+            previous_frame_time: None,
             modifiers: Modifiers::default(),
+            hovered_text: false,
             events: vec![],
+            event_times: vec![],
         }
     }
 }
@@ -87,13 +129,56 @@ impl RawInput {
             zoom_delta: zoom,
             screen_size: self.screen_size,
             screen_rect: self.screen_rect.take(),
+            safe_area_insets: self.safe_area_insets,
+            monitor_setup: self.monitor_setup.take(),
             pixels_per_point: self.pixels_per_point.take(),
             time: self.time.take(),
             predicted_dt: self.predicted_dt,
+            previous_frame_time: self.previous_frame_time.take(),
             modifiers: self.modifiers,
+            hovered_text: self.hovered_text,
             events: std::mem::take(&mut self.events),
+            event_times: std::mem::take(&mut self.event_times),
         }
     }
+
+    /// Add an event that happened at the given `time`, on the same clock as [`Self::time`].
+    pub fn push_event_at(&mut self, event: Event, time: f64) {
+        self.event_times.resize(self.events.len(), None);
+        self.events.push(event);
+        self.event_times.push(Some(time));
+    }
+
+    /// When did the event with this index in [`Self::events`] happen, if known?
+    pub fn event_time(&self, index: usize) -> Option<f64> {
+        self.event_times.get(index).copied().flatten()
+    }
+}
+
+/// How far in from each edge of the screen content has to be to not be hidden,
+/// e.g. by a display cutout. In points.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SafeAreaInsets {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl SafeAreaInsets {
+    /// The part of `screen_rect` that is safe to put content in.
+    pub fn shrink(&self, screen_rect: Rect) -> Rect {
+        let mut rect = Rect::from_min_max(
+            pos2(screen_rect.left() + self.left, screen_rect.top() + self.top),
+            pos2(
+                screen_rect.right() - self.right,
+                screen_rect.bottom() - self.bottom,
+            ),
+        );
+        // Don't turn inside out:
+        rect.max = rect.max.max(rect.min);
+        rect
+    }
 }
 
 /// An input event generated by the integration.
@@ -137,6 +222,10 @@ pub enum Event {
     /// IME composition ended with this final result.
     CompositionEnd(String),
 
+    /// Text from another application was dropped onto the window
+    /// (at the latest [`Event::PointerMoved`] position).
+    DroppedText(String),
+
     Touch {
         /// Hashed device identifier (if available; may be zero).
         /// Can be used to separate touches from different devices.
@@ -290,16 +379,23 @@ impl RawInput {
             zoom_delta,
             screen_size: _,
             screen_rect,
+            safe_area_insets,
+            monitor_setup,
             pixels_per_point,
             time,
             predicted_dt,
+            previous_frame_time,
             modifiers,
+            hovered_text,
             events,
+            event_times,
         } = self;
 
         ui.label(format!("scroll_delta: {:?} points", scroll_delta));
         ui.label(format!("zoom_delta: {:.3?} x", zoom_delta));
         ui.label(format!("screen_rect: {:?} points", screen_rect));
+        ui.label(format!("safe_area_insets: {:?} points", safe_area_insets));
+        ui.label(format!("monitor_setup: {:?}", monitor_setup));
         ui.label(format!("pixels_per_point: {:?}", pixels_per_point))
             .on_hover_text(
                 "Also called HDPI factor.\nNumber of physical pixels per each logical pixel.",
@@ -310,9 +406,19 @@ impl RawInput {
             ui.label("time: None");
         }
         ui.label(format!("predicted_dt: {:.1} ms", 1e3 * predicted_dt));
+        if let Some(previous_frame_time) = previous_frame_time {
+            ui.label(format!(
+                "previous_frame_time: {:.1} ms",
+                1e3 * previous_frame_time
+            ));
+        } else {
+            ui.label("previous_frame_time: None");
+        }
         ui.label(format!("modifiers: {:#?}", modifiers));
+        ui.label(format!("hovered_text: {}", hovered_text));
         ui.label(format!("events: {:?}", events))
             .on_hover_text("key presses etc");
+        ui.label(format!("event_times: {:?}", event_times));
     }
 }
 
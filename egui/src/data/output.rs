@@ -23,11 +23,39 @@ pub struct Output {
     /// Call `Context::request_repaint()` instead and it will do so for you.
     pub needs_repaint: bool,
 
+    /// If set, egui only needs a repaint this many seconds from now, see [`crate::Context::request_repaint_after`].
+    ///
+    /// [`Self::needs_repaint`] is also `true` then, so a backend that doesn't support
+    /// waiting still does the right thing, only repainting sooner than needed.
+    /// A backend that does support it should wait until the time has passed or new input arrives.
+    pub repaint_after: Option<f32>,
+
     /// Events that may be useful to e.g. a screen reader.
     pub events: Vec<OutputEvent>,
 
+    /// The [`Self::events`] described in words, e.g. "Save: button, focused",
+    /// for reading out loud with a text-to-speech engine.
+    ///
+    /// Only filled in when [`crate::memory::Options::screen_reader`] is on.
+    /// This is a stop-gap for platforms without a proper screen reader integration:
+    /// just send each string to the text-to-speech engine of the platform.
+    pub speech: Vec<String>,
+
     /// Position of text edit cursor (used for IME).
     pub text_cursor_pos: Option<crate::Pos2>,
+
+    /// The rectangle of the text edit cursor, spanning the height of the text row.
+    ///
+    /// Backends should show the IME candidate window next to this,
+    /// e.g. just below [`crate::Rect::left_bottom`] so it doesn't cover the text being composed.
+    pub text_cursor_rect: Option<crate::Rect>,
+
+    /// egui wants the pixels within this rectangle (in points) once this frame has been painted,
+    /// e.g. for an eyedropper (see [`crate::Context::read_screen_pixels`]).
+    ///
+    /// Backends that can read back what they painted should do so after painting,
+    /// and hand the pixels to [`crate::Context::set_screen_pixels`] before the next frame.
+    pub read_pixels: Option<crate::Rect>,
 }
 
 impl Output {
@@ -38,20 +66,15 @@ impl Output {
     }
 
     /// This can be used by a text-to-speech system to describe the events (if any).
+    ///
+    /// Only describes the widget of the last event.
+    /// See [`OutputEvent::description`] for also saying what happened to it.
     pub fn events_description(&self) -> String {
         // only describe last event:
-        if let Some(event) = self.events.iter().rev().next() {
-            match event {
-                OutputEvent::Clicked(widget_info)
-                | OutputEvent::DoubleClicked(widget_info)
-                | OutputEvent::FocusGained(widget_info)
-                | OutputEvent::TextSelectionChanged(widget_info)
-                | OutputEvent::ValueChanged(widget_info) => {
-                    return widget_info.description();
-                }
-            }
-        }
-        Default::default()
+        self.events
+            .last()
+            .map(|event| event.widget_info().description())
+            .unwrap_or_default()
     }
 }
 
@@ -222,6 +245,37 @@ pub enum OutputEvent {
     ValueChanged(WidgetInfo),
 }
 
+impl OutputEvent {
+    pub fn widget_info(&self) -> &WidgetInfo {
+        match self {
+            OutputEvent::Clicked(widget_info)
+            | OutputEvent::DoubleClicked(widget_info)
+            | OutputEvent::FocusGained(widget_info)
+            | OutputEvent::TextSelectionChanged(widget_info)
+            | OutputEvent::ValueChanged(widget_info) => widget_info,
+        }
+    }
+
+    /// This can be used by a text-to-speech system to describe the event,
+    /// e.g. "Save: button, focused".
+    pub fn description(&self) -> String {
+        let description = self.widget_info().description();
+        let what_happened = match self {
+            OutputEvent::Clicked(_) => "clicked",
+            OutputEvent::DoubleClicked(_) => "double-clicked",
+            OutputEvent::FocusGained(_) => "focused",
+            OutputEvent::TextSelectionChanged(_) | OutputEvent::ValueChanged(_) => {
+                return description;
+            }
+        };
+        if description.is_empty() {
+            what_happened.to_owned()
+        } else {
+            format!("{}, {}", description, what_happened)
+        }
+    }
+}
+
 impl std::fmt::Debug for OutputEvent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -400,6 +454,7 @@ impl WidgetInfo {
             WidgetType::ColorButton => "color button",
             WidgetType::ImageButton => "image button",
             WidgetType::CollapsingHeader => "collapsing header",
+            WidgetType::ProgressIndicator => "progress indicator",
             WidgetType::Label | WidgetType::Other => "",
         };
 
@@ -412,6 +467,9 @@ impl WidgetInfo {
             } else {
                 description += if *selected { "selected" } else { "" };
             };
+        } else if *typ == WidgetType::Checkbox {
+            // An indeterminate checkbox:
+            description = format!("mixed {}", description);
         }
 
         if let Some(label) = label {
@@ -0,0 +1,497 @@
+//! A table of rows that the user can sort, filter, resize and reorder, see [`DataGrid`].
+
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::cmp::Ordering;
+
+use crate::*;
+
+const MIN_COLUMN_WIDTH: f32 = 16.0;
+
+/// The configuration the user made, stored in [`Memory`] under the id of the [`DataGrid`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    /// The width of each column, in the order they were added with [`DataGrid::column`].
+    widths: Vec<f32>,
+
+    /// The indices of the columns, in the order they are shown.
+    order: Vec<usize>,
+
+    /// The column to sort by, and if the order is ascending.
+    sort: Option<(usize, bool)>,
+
+    /// The filter text of each column.
+    filters: Vec<String>,
+}
+
+impl State {
+    /// Start over with whatever doesn't fit the columns, e.g. after a column was added.
+    fn fit<T>(&mut self, columns: &[DataColumn<'_, T>]) {
+        let num_columns = columns.len();
+        if self.widths.len() != num_columns {
+            self.widths = columns.iter().map(|column| column.width).collect();
+        }
+        let mut order = self.order.clone();
+        order.sort_unstable();
+        if order != (0..num_columns).collect::<Vec<_>>() {
+            self.order = (0..num_columns).collect();
+        }
+        if let Some((column, _)) = self.sort {
+            if columns
+                .get(column)
+                .map_or(true, |column| column.compare.is_none())
+            {
+                self.sort = None;
+            }
+        }
+        self.filters.resize(num_columns, String::new());
+    }
+}
+
+type CellFn<'a, T> = Box<dyn 'a + Fn(&mut Ui, &T)>;
+type CompareFn<'a, T> = Box<dyn 'a + Fn(&T, &T) -> Ordering>;
+type FilterTextFn<'a, T> = Box<dyn 'a + Fn(&T) -> String>;
+
+/// A column of a [`DataGrid`].
+pub struct DataColumn<'a, T> {
+    title: String,
+    width: f32,
+    cell: CellFn<'a, T>,
+    compare: Option<CompareFn<'a, T>>,
+    filter_text: Option<FilterTextFn<'a, T>>,
+}
+
+impl<'a, T> DataColumn<'a, T> {
+    /// `add_cell` shows the cell of this column for a row.
+    pub fn new(title: impl ToString, add_cell: impl 'a + Fn(&mut Ui, &T)) -> Self {
+        Self {
+            title: title.to_string(),
+            width: 100.0,
+            cell: Box::new(add_cell),
+            compare: None,
+            filter_text: None,
+        }
+    }
+
+    /// The width of the column until the user resizes it. Default: 100.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width.at_least(MIN_COLUMN_WIDTH);
+        self
+    }
+
+    /// Let the user sort the rows by this column by clicking its header.
+    pub fn sortable(mut self, compare: impl 'a + Fn(&T, &T) -> Ordering) -> Self {
+        self.compare = Some(Box::new(compare));
+        self
+    }
+
+    /// Show a filter field for this column under the header.
+    /// Only rows whose text contains the filter text (ignoring case) are shown.
+    pub fn filterable(mut self, text: impl 'a + Fn(&T) -> String) -> Self {
+        self.filter_text = Some(Box::new(text));
+        self
+    }
+}
+
+/// A table showing one row per item, with a header the user can click to sort the rows,
+/// and filter fields under the header.
+///
+/// The user can resize the columns by dragging the gaps between the headers,
+/// and reorder them by dragging the headers.
+/// The widths, order, sorting and filters are stored in [`Memory`] under the id of the grid,
+/// so they survive restarts with the `persistence` feature.
+///
+/// Only the rows that are scrolled into view are shown, so the grid can have many rows.
+/// A grid wider than the available space scrolls sideways, header and all.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+/// # let people: Vec<Person> = vec![];
+///
+/// egui::DataGrid::new("people")
+///     .column(
+///         egui::DataColumn::new("Name", |ui, person: &Person| {
+///             ui.label(&person.name);
+///         })
+///         .sortable(|a, b| a.name.cmp(&b.name))
+///         .filterable(|person| person.name.clone()),
+///     )
+///     .column(
+///         egui::DataColumn::new("Age", |ui, person: &Person| {
+///             ui.label(person.age.to_string());
+///         })
+///         .width(50.0)
+///         .sortable(|a, b| a.age.cmp(&b.age)),
+///     )
+///     .show(ui, &people);
+/// ```
+#[must_use = "You should call .show()"]
+pub struct DataGrid<'a, T> {
+    id_source: Id,
+    columns: Vec<DataColumn<'a, T>>,
+    striped: bool,
+    max_height: f32,
+}
+
+impl<'a, T> DataGrid<'a, T> {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            columns: Default::default(),
+            striped: true,
+            max_height: 400.0,
+        }
+    }
+
+    /// Add a column to the right.
+    pub fn column(mut self, column: DataColumn<'a, T>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Add a subtle background color to every other row. Default: `true`.
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// The rows scroll when they are taller than this. Default: 400.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Show the grid with one row per item of `rows`.
+    ///
+    /// Returns the indices into `rows` of the rows that pass the filters, in the order they are shown.
+    pub fn show(self, ui: &mut Ui, rows: &[T]) -> InnerResponse<Vec<usize>> {
+        let Self {
+            id_source,
+            columns,
+            striped,
+            max_height,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = ui.memory().id_data.get_or_default::<State>(id).clone();
+        state.fit(&columns);
+
+        let row_height = ui.spacing().interact_size.y;
+        let spacing = ui.spacing().item_spacing;
+
+        let total_width = state
+            .order
+            .iter()
+            .map(|&column| state.widths[column] + spacing.x)
+            .sum::<f32>()
+            - spacing.x;
+        let total_width = total_width.at_least(0.0);
+
+        let InnerResponse { inner, response } = ui.vertical(|ui| {
+            ScrollArea::auto_sized()
+                .horizontal_scroll(true)
+                // Leave room for the scroll bar of the rows:
+                .max_width(total_width + spacing.x + ui.spacing().scroll_bar_width)
+                .id_source(id.with("columns"))
+                .show(ui, |ui| {
+                    let (header_rect, _) =
+                        ui.allocate_exact_size(vec2(total_width, row_height), Sense::hover());
+                    let column_rects = column_rects(&state, header_rect, spacing.x);
+                    header_ui(ui, id, &columns, &mut state, &column_rects);
+
+                    if columns.iter().any(|column| column.filter_text.is_some()) {
+                        let (filter_rect, _) =
+                            ui.allocate_exact_size(vec2(total_width, row_height), Sense::hover());
+                        for &(column, rect) in &column_rects {
+                            if columns[column].filter_text.is_some() {
+                                let rect =
+                                    Rect::from_x_y_ranges(rect.x_range(), filter_rect.y_range());
+                                ui.put(
+                                    rect,
+                                    TextEdit::singleline(&mut state.filters[column])
+                                        .id(id.with("filter").with(column))
+                                        .hint_text("Filter")
+                                        .desired_width(rect.width()),
+                                );
+                            }
+                        }
+                    }
+
+                    let visible_rows = visible_rows(&state, &columns, rows);
+                    ScrollArea::from_max_height(max_height)
+                        .id_source(id.with("rows"))
+                        .show_rows(ui, row_height, visible_rows.len(), |ui, row_range| {
+                            for row_nr in row_range {
+                                let (row_rect, _) = ui.allocate_exact_size(
+                                    vec2(total_width, row_height),
+                                    Sense::hover(),
+                                );
+                                if striped && row_nr % 2 == 1 {
+                                    let stripe = row_rect.expand2(vec2(0.0, 0.5 * spacing.y));
+                                    ui.painter().rect_filled(
+                                        stripe,
+                                        0.0,
+                                        ui.visuals().faint_bg_color,
+                                    );
+                                }
+                                for &(column, rect) in &column_rects {
+                                    let rect =
+                                        Rect::from_x_y_ranges(rect.x_range(), row_rect.y_range());
+                                    let mut cell_ui = ui.child_ui(rect, Layout::left_to_right());
+                                    cell_ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+                                    (columns[column].cell)(
+                                        &mut cell_ui,
+                                        &rows[visible_rows[row_nr]],
+                                    );
+                                }
+                            }
+                        });
+                    visible_rows
+                })
+        });
+
+        ui.memory().id_data.insert(id, state);
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// The index and rect of each column, in the order they are shown.
+fn column_rects(state: &State, header_rect: Rect, spacing: f32) -> Vec<(usize, Rect)> {
+    let mut left = header_rect.left();
+    state
+        .order
+        .iter()
+        .map(|&column| {
+            let width = state.widths[column];
+            let rect = Rect::from_x_y_ranges(left..=left + width, header_rect.y_range());
+            left += width + spacing;
+            (column, rect)
+        })
+        .collect()
+}
+
+/// Click to sort, drag to reorder, drag the gaps to resize.
+fn header_ui<T>(
+    ui: &mut Ui,
+    id: Id,
+    columns: &[DataColumn<'_, T>],
+    state: &mut State,
+    column_rects: &[(usize, Rect)],
+) {
+    let spacing = ui.spacing().item_spacing.x;
+    let mut moved = None;
+
+    for (position, &(column, rect)) in column_rects.iter().enumerate() {
+        let response = ui.interact(
+            rect,
+            id.with("header").with(column),
+            Sense::click_and_drag(),
+        );
+        if response.clicked() && columns[column].compare.is_some() {
+            state.sort = match state.sort {
+                Some((sorted, true)) if sorted == column => Some((column, false)),
+                Some((sorted, false)) if sorted == column => None,
+                _ => Some((column, true)),
+            };
+        }
+
+        // Dropping next to itself doesn't move the column:
+        let drop_position = ui
+            .input()
+            .pointer
+            .interact_pos()
+            .map(|pointer| drop_position(column_rects, pointer.x))
+            .filter(|&drop| drop != position && drop != position + 1);
+        if response.dragged() {
+            ui.output().cursor_icon = CursorIcon::Grabbing;
+            if let Some(drop_position) = drop_position {
+                let x = match column_rects.get(drop_position) {
+                    Some((_, rect)) => rect.left() - 0.5 * spacing,
+                    None => column_rects[drop_position - 1].1.right() + 0.5 * spacing,
+                };
+                let stroke = ui.visuals().selection.stroke;
+                ui.painter()
+                    .line_segment([pos2(x, rect.top()), pos2(x, rect.bottom())], stroke);
+            }
+        }
+        if response.drag_released() {
+            if let Some(drop_position) = drop_position {
+                moved = Some((position, drop_position));
+            }
+        }
+
+        let visuals = ui.style().interact(&response);
+        ui.painter()
+            .rect_filled(rect, visuals.corner_radius, visuals.bg_fill);
+        let arrow = match state.sort {
+            Some((sorted, true)) if sorted == column => " ⏶",
+            Some((sorted, false)) if sorted == column => " ⏷",
+            _ => "",
+        };
+        let text_pos = rect.left_center() + vec2(ui.spacing().button_padding.x, 0.0);
+        ui.painter_at(rect).text(
+            text_pos,
+            Align2::LEFT_CENTER,
+            format!("{}{}", columns[column].title, arrow),
+            TextStyle::Button,
+            visuals.text_color(),
+        );
+
+        let resize_rect = Rect::from_x_y_ranges(
+            rect.right()..=rect.right() + spacing.at_least(4.0),
+            rect.y_range(),
+        );
+        let resize_response =
+            ui.interact(resize_rect, id.with("resize").with(column), Sense::drag());
+        if resize_response.hovered() || resize_response.dragged() {
+            ui.output().cursor_icon = CursorIcon::ResizeHorizontal;
+        }
+        if resize_response.dragged() {
+            let width = &mut state.widths[column];
+            *width = (*width + resize_response.drag_delta().x).at_least(MIN_COLUMN_WIDTH);
+        }
+        let stroke = ui.style().interact(&resize_response).bg_stroke;
+        let x = resize_rect.center().x;
+        ui.painter()
+            .line_segment([pos2(x, rect.top()), pos2(x, rect.bottom())], stroke);
+    }
+
+    if let Some((from, to)) = moved {
+        move_column(&mut state.order, from, to);
+    }
+}
+
+/// Where a column dropped at `x` goes: before the column at the returned position,
+/// or last if it is the number of columns.
+fn drop_position(column_rects: &[(usize, Rect)], x: f32) -> usize {
+    column_rects
+        .iter()
+        .filter(|(_, rect)| rect.center().x < x)
+        .count()
+}
+
+/// Move the column at position `from` to before the one at position `to`,
+/// or last if `to` is the number of columns.
+fn move_column(order: &mut Vec<usize>, from: usize, to: usize) {
+    let column = order.remove(from);
+    // Removing the column moved the ones after it one step to the left:
+    let to = if to > from { to - 1 } else { to };
+    order.insert(to, column);
+}
+
+/// The indices of the rows that pass the filters, sorted.
+fn visible_rows<T>(state: &State, columns: &[DataColumn<'_, T>], rows: &[T]) -> Vec<usize> {
+    let filters: Vec<(&FilterTextFn<'_, T>, String)> = columns
+        .iter()
+        .zip(&state.filters)
+        .filter(|(_, filter)| !filter.is_empty())
+        .filter_map(|(column, filter)| {
+            let filter_text = column.filter_text.as_ref()?;
+            Some((filter_text, filter.to_lowercase()))
+        })
+        .collect();
+    let mut visible_rows: Vec<usize> = (0..rows.len())
+        .filter(|&row| {
+            filters.iter().all(|(filter_text, filter)| {
+                filter_text(&rows[row])
+                    .to_lowercase()
+                    .contains(filter.as_str())
+            })
+        })
+        .collect();
+
+    if let Some((column, ascending)) = state.sort {
+        if let Some(compare) = &columns[column].compare {
+            visible_rows.sort_by(|&a, &b| {
+                let ordering = compare(&rows[a], &rows[b]);
+                if ascending {
+                    ordering
+                } else {
+                    ordering.reverse()
+                }
+            });
+        }
+    }
+    visible_rows
+}
+
+#[test]
+fn test_data_grid() {
+    type Row = (&'static str, u32);
+    let rows: Vec<Row> = vec![("Bob", 30), ("alice", 25), ("Carol", 35), ("Dave", 25)];
+    let columns = || {
+        vec![
+            DataColumn::new("Name", |ui: &mut Ui, row: &Row| {
+                ui.label(row.0);
+            })
+            .filterable(|row| row.0.to_owned()),
+            DataColumn::new("Age", |ui: &mut Ui, row: &Row| {
+                ui.label(row.1.to_string());
+            })
+            .sortable(|a, b| a.1.cmp(&b.1)),
+        ]
+    };
+
+    let mut state = State::default();
+    state.fit(&columns());
+    assert_eq!(visible_rows(&state, &columns(), &rows), vec![0, 1, 2, 3]);
+    state.sort = Some((1, false));
+    assert_eq!(visible_rows(&state, &columns(), &rows), vec![2, 0, 1, 3]);
+    state.filters[0] = "A".to_owned();
+    assert_eq!(visible_rows(&state, &columns(), &rows), vec![2, 1, 3]);
+
+    // Sorting by a column without a comparator is forgotten:
+    state.sort = Some((0, true));
+    state.fit(&columns());
+    assert_eq!(state.sort, None);
+
+    // Clicking the header sorts, and the sorting is kept in memory:
+    use crate::test_util::{click, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>| {
+        let response = run_ui_frame(&mut ctx, events, |ui| {
+            let mut grid = DataGrid::new("grid");
+            for column in columns() {
+                grid = grid.column(column);
+            }
+            grid.show(ui, &rows)
+        });
+        (response.inner, response.response.rect)
+    };
+    let (visible_rows, rect) = run_frame(vec![]);
+    assert_eq!(visible_rows, vec![0, 1, 2, 3]);
+    let age_header = rect.left_top() + vec2(150.0, 5.0);
+    run_frame(click(age_header));
+    assert_eq!(run_frame(vec![]).0, vec![1, 3, 0, 2]);
+
+    // Drag the name header to the right of the age header:
+    use crate::test_util::pointer_button;
+    let name_header = rect.left_top() + vec2(50.0, 5.0);
+    let right_of_age = rect.left_top() + vec2(200.0, 5.0);
+    run_frame(vec![
+        Event::PointerMoved(name_header),
+        pointer_button(name_header, true),
+    ]);
+    run_frame(vec![Event::PointerMoved(right_of_age)]);
+    run_frame(vec![pointer_button(right_of_age, false)]);
+    // Now the age header is first:
+    run_frame(click(name_header));
+    assert_eq!(run_frame(vec![]).0, vec![2, 0, 1, 3]);
+}
+
+#[test]
+fn test_move_column() {
+    let mut order = vec![0, 1, 2];
+    move_column(&mut order, 0, 2);
+    assert_eq!(order, vec![1, 0, 2], "to the right, before the last one");
+    move_column(&mut order, 0, 3);
+    assert_eq!(order, vec![0, 2, 1], "to the end");
+    move_column(&mut order, 2, 0);
+    assert_eq!(order, vec![1, 0, 2], "to the left");
+}
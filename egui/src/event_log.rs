@@ -0,0 +1,107 @@
+//! An opt-in log of what the user does, see [`crate::Context::take_ui_events`].
+
+use std::collections::HashMap;
+
+use crate::{Id, WidgetInfo};
+
+/// Something the user did, logged if [`crate::memory::Options::log_ui_events`] is on.
+///
+/// Use it for analytics, for tutorials ("highlight the button the user never clicks"),
+/// or for recording macros.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiEvent {
+    /// A widget was clicked.
+    Clicked { id: Id, info: WidgetInfo },
+
+    /// A [`crate::Window`] was shown, after not being shown the frame before.
+    WindowOpened { id: Id, title: String },
+
+    /// A [`crate::Window`] that was shown the frame before wasn't shown this frame.
+    WindowClosed { id: Id, title: String },
+
+    /// A [`crate::TextEdit`] lost keyboard focus after its text was changed, i.e. the edit was committed.
+    TextCommitted { id: Id, text: String },
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EventLog {
+    pub events: Vec<UiEvent>,
+
+    /// The windows shown last frame, with their titles.
+    open_windows: HashMap<Id, String>,
+
+    /// The windows shown this frame, with their titles.
+    shown_windows: HashMap<Id, String>,
+}
+
+impl EventLog {
+    pub fn window_shown(&mut self, id: Id, title: &str) {
+        if !self.open_windows.contains_key(&id) && !self.shown_windows.contains_key(&id) {
+            self.events.push(UiEvent::WindowOpened {
+                id,
+                title: title.to_owned(),
+            });
+        }
+        self.shown_windows.insert(id, title.to_owned());
+    }
+
+    pub fn end_frame(&mut self) {
+        let shown_windows = std::mem::take(&mut self.shown_windows);
+        let mut closed: Vec<_> = self
+            .open_windows
+            .drain()
+            .filter(|(id, _)| !shown_windows.contains_key(id))
+            .collect();
+        closed.sort_by(|a, b| a.1.cmp(&b.1)); // deterministic order
+        self.events.extend(
+            closed
+                .into_iter()
+                .map(|(id, title)| UiEvent::WindowClosed { id, title }),
+        );
+        self.open_windows = shown_windows;
+    }
+}
+
+#[test]
+fn test_ui_event_log() {
+    use crate::test_util::{click, key};
+    use crate::*;
+
+    let mut ctx = CtxRef::default();
+    ctx.memory().options.log_ui_events = true;
+    let mut text = String::new();
+    let mut run_frame = |events: Vec<Event>, show_window: bool| {
+        let rects = crate::test_util::run_frame(&mut ctx, events, |ctx| {
+            let mut rects = (Rect::NOTHING, Rect::NOTHING);
+            if show_window {
+                Window::new("Settings").show(ctx, |ui| {
+                    rects.0 = ui.button("Apply").rect;
+                    rects.1 = ui.text_edit_singleline(&mut text).rect;
+                });
+            }
+            rects
+        });
+        (rects, ctx.take_ui_events())
+    };
+    let is_window_opened = |event: &UiEvent| matches!(event, UiEvent::WindowOpened { title, .. } if title == "Settings");
+
+    let ((button_rect, text_edit_rect), events) = run_frame(vec![], true);
+    assert!(events.len() == 1 && is_window_opened(&events[0]));
+
+    let (_, events) = run_frame(click(button_rect.center()), true);
+    assert!(
+        matches!(&events[..], [UiEvent::Clicked { info, .. }] if info.label.as_deref() == Some("Apply"))
+    );
+
+    run_frame(click(text_edit_rect.center()), true);
+    run_frame(vec![Event::Text("hi".to_owned())], true);
+    let (_, events) = run_frame(vec![key(Key::Enter)], true);
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, UiEvent::TextCommitted { text, .. } if text == "hi")));
+
+    let (_, events) = run_frame(vec![], false);
+    assert!(matches!(&events[..], [UiEvent::WindowClosed { title, .. }] if title == "Settings"));
+    let (_, events) = run_frame(vec![], true);
+    assert!(events.len() == 1 && is_window_opened(&events[0]));
+}
@@ -0,0 +1,103 @@
+//! Tracks how long frames take against [`crate::memory::Options::frame_budget`],
+//! see [`crate::Context::degrade`].
+
+use crate::util::History;
+
+/// How many seconds of frame times to average over.
+const WINDOW: f64 = 1.0;
+
+/// Stop degrading once frames are this much faster than the budget,
+/// so we don't flicker between degraded and not.
+const RECOVER_FACTOR: f32 = 0.8;
+
+/// Don't decide anything on fewer frames than this.
+const MIN_FRAMES: usize = 5;
+
+#[derive(Clone, Debug)]
+pub(crate) struct FrameBudget {
+    frame_times: History<f32>,
+    degrade: bool,
+}
+
+impl Default for FrameBudget {
+    fn default() -> Self {
+        Self {
+            frame_times: History::new(1000, WINDOW),
+            degrade: false,
+        }
+    }
+}
+
+impl FrameBudget {
+    /// `previous_frame_time` is [`crate::RawInput::previous_frame_time`].
+    pub fn begin_frame(
+        &mut self,
+        time: f64,
+        previous_frame_time: Option<f32>,
+        budget: Option<f32>,
+    ) {
+        if let Some(frame_time) = previous_frame_time {
+            self.frame_times.add(time, frame_time);
+        } else {
+            self.frame_times.flush(time);
+        }
+
+        let budget = match budget {
+            Some(budget) if budget > 0.0 => budget,
+            _ => {
+                self.degrade = false;
+                return;
+            }
+        };
+        if self.frame_times.len() < MIN_FRAMES {
+            return;
+        }
+        if let Some(average) = self.frame_times.average() {
+            if average > budget {
+                self.degrade = true;
+            } else if average < RECOVER_FACTOR * budget {
+                self.degrade = false;
+            }
+        }
+    }
+
+    pub fn degrade(&self) -> bool {
+        self.degrade
+    }
+
+    /// The average time of the recent frames, in seconds.
+    pub fn average_frame_time(&self) -> Option<f32> {
+        self.frame_times.average()
+    }
+}
+
+#[test]
+fn test_frame_budget() {
+    let mut budget = FrameBudget::default();
+    let target = Some(1.0 / 60.0);
+    let mut time = 0.0;
+    let mut run = |budget: &mut FrameBudget, frame_time: f32, frames: usize| {
+        for _ in 0..frames {
+            time += frame_time as f64;
+            budget.begin_frame(time, Some(frame_time), target);
+        }
+    };
+
+    run(&mut budget, 0.010, 60);
+    assert!(!budget.degrade());
+
+    run(&mut budget, 0.030, 60);
+    assert!(budget.degrade(), "struggling");
+
+    // Just within the budget isn't enough to recover:
+    run(&mut budget, 0.015, 120);
+    assert!(budget.degrade());
+
+    run(&mut budget, 0.010, 120);
+    assert!(!budget.degrade(), "recovered");
+
+    // Without a budget there is nothing to degrade for:
+    run(&mut budget, 0.030, 60);
+    budget.begin_frame(100.0, Some(0.030), None);
+    assert!(!budget.degrade());
+}
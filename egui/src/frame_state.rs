@@ -9,6 +9,12 @@ pub(crate) struct FrameState {
     /// Used to debug `Id` clashes of widgets.
     pub(crate) used_ids: ahash::AHashMap<Id, Rect>,
 
+    /// Where the widgets were shown this frame, see [`Context::widget_rect`].
+    pub(crate) widget_rects: ahash::AHashMap<Id, WidgetRect>,
+
+    /// The `widget_rects` of the previous frame.
+    pub(crate) prev_widget_rects: ahash::AHashMap<Id, WidgetRect>,
+
     /// Starts off as the screen_rect, shrinks as panels are added.
     /// The `CentralPanel` does not change this.
     /// This is the area available to Window's.
@@ -29,18 +35,24 @@ pub(crate) struct FrameState {
     /// Cleared by the first `ScrollArea` that makes use of it.
     pub(crate) scroll_delta: Vec2,
     pub(crate) scroll_target: Option<(f32, Align)>,
+
+    /// Layers that stay enabled while the [`Context`] is disabled, see [`Area::keep_enabled`].
+    pub(crate) keep_enabled_layers: Vec<LayerId>,
 }
 
 impl Default for FrameState {
     fn default() -> Self {
         Self {
             used_ids: Default::default(),
+            widget_rects: Default::default(),
+            prev_widget_rects: Default::default(),
             available_rect: Rect::NAN,
             unused_rect: Rect::NAN,
             used_by_panels: Rect::NAN,
             tooltip_rect: None,
             scroll_delta: Vec2::ZERO,
             scroll_target: None,
+            keep_enabled_layers: Default::default(),
         }
     }
 }
@@ -49,21 +61,27 @@ impl FrameState {
     pub(crate) fn begin_frame(&mut self, input: &InputState) {
         let Self {
             used_ids,
+            widget_rects,
+            prev_widget_rects,
             available_rect,
             unused_rect,
             used_by_panels,
             tooltip_rect,
             scroll_delta,
             scroll_target,
+            keep_enabled_layers,
         } = self;
 
         used_ids.clear();
+        std::mem::swap(widget_rects, prev_widget_rects);
+        widget_rects.clear();
         *available_rect = input.screen_rect();
         *unused_rect = input.screen_rect();
         *used_by_panels = Rect::NOTHING;
         *tooltip_rect = None;
         *scroll_delta = input.scroll_delta;
         *scroll_target = None;
+        keep_enabled_layers.clear();
     }
 
     /// How much space is still available after panels has been added.
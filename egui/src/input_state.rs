@@ -51,6 +51,10 @@ pub struct InputState {
     /// Should be set to the expected time between frames when painting at vsync speeds.
     pub predicted_dt: f32,
 
+    /// How many frames have begun, counting this one.
+    /// Useful for telling whether something was last used during this frame or an earlier one.
+    pub frame_nr: u64,
+
     /// Which modifier keys are down at the start of the frame?
     pub modifiers: Modifiers,
 
@@ -73,6 +77,7 @@ impl Default for InputState {
             time: 0.0,
             unstable_dt: 1.0 / 6.0,
             predicted_dt: 1.0 / 6.0,
+            frame_nr: 0,
             modifiers: Default::default(),
             keys_down: Default::default(),
             events: Default::default(),
@@ -120,6 +125,7 @@ impl InputState {
             time,
             unstable_dt,
             predicted_dt: new.predicted_dt,
+            frame_nr: self.frame_nr + 1,
             modifiers: new.modifiers,
             keys_down,
             events: new.events.clone(), // TODO: remove clone() and use raw.events
@@ -132,6 +138,12 @@ impl InputState {
         self.screen_rect
     }
 
+    /// The part of the [`Self::screen_rect`] that is not hidden by e.g. the notch of a phone.
+    /// See [`RawInput::safe_area_insets`].
+    pub fn safe_area_rect(&self) -> Rect {
+        self.raw.safe_area_insets.shrink(self.screen_rect)
+    }
+
     /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
     /// * `zoom = 1`: no change
     /// * `zoom < 1`: pinch together
@@ -355,6 +367,10 @@ pub struct PointerState {
     /// `None` if no mouse button is down.
     press_origin: Option<Pos2>,
 
+    /// When did the current click/drag start?
+    /// `None` if no mouse button is down.
+    press_start_time: Option<f64>,
+
     /// If the pointer button is down, will it register as a click when released?
     /// Set to true on pointer button down, set to false when pointer button moves too much.
     could_be_click: bool,
@@ -377,6 +393,7 @@ impl Default for PointerState {
             pos_history: History::new(1000, 0.1),
             down: Default::default(),
             press_origin: None,
+            press_start_time: None,
             could_be_click: false,
             last_click_time: std::f64::NEG_INFINITY,
             pointer_events: vec![],
@@ -392,7 +409,21 @@ impl PointerState {
         let old_pos = self.latest_pos;
         self.interact_pos = self.latest_pos;
 
-        for event in &new.events {
+        // Events with a timestamp are added to the position history as they come,
+        // so that we get the velocity right even if there are many of them each frame.
+        // The times must not go backwards, nor past the start of this frame.
+        let mut last_event_time = self
+            .pos_history
+            .iter()
+            .last()
+            .map_or(f64::NEG_INFINITY, |(last_time, _)| last_time);
+        let mut added_to_history = false;
+
+        for (event_index, event) in new.events.iter().enumerate() {
+            let event_time = new
+                .event_time(event_index)
+                .map(|event_time| event_time.min(time).max(last_event_time));
+
             match event {
                 Event::PointerMoved(pos) => {
                     let pos = *pos;
@@ -400,6 +431,12 @@ impl PointerState {
                     self.latest_pos = Some(pos);
                     self.interact_pos = Some(pos);
 
+                    if let Some(event_time) = event_time {
+                        self.pos_history.add(event_time, pos);
+                        last_event_time = event_time;
+                        added_to_history = true;
+                    }
+
                     if let Some(press_origin) = &mut self.press_origin {
                         self.could_be_click &= press_origin.distance(pos) < MAX_CLICK_DIST;
                     } else {
@@ -428,18 +465,27 @@ impl PointerState {
                         self.pos_history.clear();
                     }
 
+                    if let Some(event_time) = event_time {
+                        self.pos_history.add(event_time, pos);
+                        last_event_time = event_time;
+                        added_to_history = true;
+                    }
+
                     if pressed {
                         self.press_origin = Some(pos);
+                        self.press_start_time = Some(event_time.unwrap_or(time));
                         self.could_be_click = true;
                         self.pointer_events.push(PointerEvent::Pressed(pos));
                     } else {
                         let clicked = self.could_be_click;
 
                         let click = if clicked {
-                            let double_click = (time - self.last_click_time) < MAX_CLICK_DELAY;
+                            let click_time = event_time.unwrap_or(time);
+                            let double_click =
+                                (click_time - self.last_click_time) < MAX_CLICK_DELAY;
                             let count = if double_click { 2 } else { 1 };
 
-                            self.last_click_time = time;
+                            self.last_click_time = click_time;
 
                             Some(Click {
                                 pos,
@@ -454,6 +500,7 @@ impl PointerState {
                         self.pointer_events.push(PointerEvent::Released(click));
 
                         self.press_origin = None;
+                        self.press_start_time = None;
                         self.could_be_click = false;
                     }
 
@@ -473,7 +520,9 @@ impl PointerState {
             Vec2::ZERO
         };
 
-        if let Some(pos) = self.latest_pos {
+        if added_to_history {
+            // We already know where the pointer was, and when.
+        } else if let Some(pos) = self.latest_pos {
             self.pos_history.add(time, pos);
         } else {
             // we do not clear the `pos_history` here, because it is exactly when a finger has
@@ -515,6 +564,20 @@ impl PointerState {
         self.press_origin
     }
 
+    /// When did the current click/drag start, on the clock of [`InputState::time`]?
+    /// `None` if no mouse button is down.
+    #[inline(always)]
+    pub fn press_start_time(&self) -> Option<f64> {
+        self.press_start_time
+    }
+
+    /// If a pointer button is down, will it register as a click when released?
+    /// `false` once the pointer has moved too far from the [`Self::press_origin`].
+    #[inline(always)]
+    pub fn could_be_click(&self) -> bool {
+        self.could_be_click
+    }
+
     /// Latest reported pointer position.
     /// When tapping a touch screen, this will be `None`.
     #[inline(always)]
@@ -617,6 +680,7 @@ impl InputState {
             time,
             unstable_dt,
             predicted_dt,
+            frame_nr,
             modifiers,
             keys_down,
             events,
@@ -649,6 +713,7 @@ impl InputState {
             1e3 * unstable_dt
         ));
         ui.label(format!("predicted_dt: {:.1} ms", 1e3 * predicted_dt));
+        ui.label(format!("frame_nr: {}", frame_nr));
         ui.label(format!("modifiers: {:#?}", modifiers));
         ui.label(format!("keys_down: {:?}", keys_down));
         ui.label(format!("events: {:?}", events))
@@ -666,6 +731,7 @@ impl PointerState {
             pos_history: _,
             down,
             press_origin,
+            press_start_time,
             could_be_click,
             last_click_time,
             pointer_events,
@@ -680,8 +746,25 @@ impl PointerState {
         ));
         ui.label(format!("down: {:#?}", down));
         ui.label(format!("press_origin: {:?}", press_origin));
+        ui.label(format!("press_start_time: {:?}", press_start_time));
         ui.label(format!("could_be_click: {:#?}", could_be_click));
         ui.label(format!("last_click_time: {:#?}", last_click_time));
         ui.label(format!("pointer_events: {:?}", pointer_events));
     }
 }
+
+#[test]
+fn test_velocity_from_event_times() {
+    // A frame with many pointer moves, 100 points per second:
+    let mut raw = RawInput {
+        time: Some(1.0),
+        ..Default::default()
+    };
+    for i in 0..10 {
+        let t = 0.9 + 0.01 * i as f64;
+        raw.push_event_at(Event::PointerMoved(pos2(i as f32, 0.0)), t);
+    }
+    let input = InputState::default().begin_frame(raw);
+    let velocity = input.pointer.velocity();
+    assert!((velocity.x - 100.0).abs() < 1.0, "velocity: {:?}", velocity);
+}
@@ -0,0 +1,270 @@
+//! Generic editing UIs for settings structs and the like, see [`Inspect`].
+//!
+//! With the `derive` feature you can `#[derive(egui::Inspect)]` for your own structs and enums:
+//!
+//! ``` ignore
+//! #[derive(egui::Inspect)]
+//! struct Settings {
+//!     #[inspect(range = 0.0..=1.0)]
+//!     volume: f32,
+//!     #[inspect(label = "Player name")]
+//!     name: String,
+//!     fullscreen: bool,
+//!     quality: Quality,
+//!     #[inspect(skip)]
+//!     cache: Vec<u8>,
+//! }
+//!
+//! #[derive(egui::Inspect)]
+//! enum Quality {
+//!     Low,
+//!     Medium,
+//!     High,
+//! }
+//!
+//! // In your ui code:
+//! settings.inspect(ui);
+//! ```
+//!
+//! * Structs are shown as a collapsing header with one row per field.
+//! * Enums without fields are shown as a combo box.
+//! * A `Vec<T>` is shown as a list that can be reordered, added to and removed from.
+//!
+//! The field attributes are:
+//! * `#[inspect(skip)]`: don't show this field.
+//! * `#[inspect(label = "…")]`: show this instead of the field name.
+//! * `#[inspect(range = min..=max)]`: edit a number with a [`Slider`] over this range.
+//! * `#[inspect(speed = …)]`: the drag speed when editing a number with a [`DragValue`].
+
+use std::ops::RangeInclusive;
+
+use crate::*;
+use emath::Numeric as _;
+
+/// Options for [`Inspect::inspect_with`].
+///
+/// When deriving [`Inspect`], these are set per field with `#[inspect(…)]` attributes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InspectOptions {
+    /// Edit numbers with a [`Slider`] over this range, instead of with a [`DragValue`].
+    pub range: Option<RangeInclusive<f64>>,
+
+    /// How much a number changes when dragged one point (logical pixel).
+    /// `None` means a default for the type.
+    pub speed: Option<f64>,
+}
+
+impl InspectOptions {
+    pub fn range<Num: emath::Numeric>(mut self, range: RangeInclusive<Num>) -> Self {
+        self.range = Some(range.start().to_f64()..=range.end().to_f64());
+        self
+    }
+
+    pub fn speed(mut self, speed: impl Into<f64>) -> Self {
+        self.speed = Some(speed.into());
+        self
+    }
+}
+
+/// Something that can show a UI for editing itself.
+///
+/// This is implemented for numbers, `bool`, `String`, [`Vec2`], [`Color32`] and `Vec<T>`,
+/// and can be derived with the `derive` feature (see [the module docs](crate::inspect)).
+///
+/// ```
+/// use egui::Inspect;
+/// # let ui = &mut egui::Ui::__test();
+/// let mut volume = 0.5_f32;
+/// let options = egui::inspect::InspectOptions::default().range(0.0..=1.0);
+/// if volume.inspect_with(ui, &options).changed() {
+///     /* … */
+/// }
+/// ```
+pub trait Inspect {
+    /// Show a UI for editing `self`.
+    ///
+    /// The returned [`Response`] is [`Response::changed`] if `self` was changed.
+    fn inspect_with(&mut self, ui: &mut Ui, options: &InspectOptions) -> Response;
+
+    /// Show a UI for editing `self`, with default [`InspectOptions`].
+    fn inspect(&mut self, ui: &mut Ui) -> Response {
+        self.inspect_with(ui, &Default::default())
+    }
+}
+
+macro_rules! impl_inspect_numeric {
+    ($($t:ident)*) => {
+        $(
+            impl Inspect for $t {
+                fn inspect_with(&mut self, ui: &mut Ui, options: &InspectOptions) -> Response {
+                    if let Some(range) = &options.range {
+                        let range = $t::from_f64(*range.start())..=$t::from_f64(*range.end());
+                        ui.add(Slider::new(self, range))
+                    } else {
+                        let mut drag_value = DragValue::new(self);
+                        if let Some(speed) = options.speed {
+                            drag_value = drag_value.speed(speed);
+                        }
+                        ui.add(drag_value)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_inspect_numeric!(f32 f64 i8 u8 i16 u16 i32 u32 i64 u64 isize usize);
+
+impl Inspect for bool {
+    fn inspect_with(&mut self, ui: &mut Ui, _options: &InspectOptions) -> Response {
+        ui.checkbox(self, "")
+    }
+}
+
+impl Inspect for String {
+    fn inspect_with(&mut self, ui: &mut Ui, _options: &InspectOptions) -> Response {
+        ui.text_edit_singleline(self)
+    }
+}
+
+impl Inspect for Vec2 {
+    fn inspect_with(&mut self, ui: &mut Ui, options: &InspectOptions) -> Response {
+        let mut drag_vec2 = DragVec2::new(self);
+        if let Some(range) = &options.range {
+            drag_vec2 = drag_vec2.clamp_range(*range.start() as f32..=*range.end() as f32);
+        }
+        if let Some(speed) = options.speed {
+            drag_vec2 = drag_vec2.speed(speed as f32);
+        }
+        ui.add(drag_vec2)
+    }
+}
+
+impl Inspect for Color32 {
+    fn inspect_with(&mut self, ui: &mut Ui, _options: &InspectOptions) -> Response {
+        ui.color_edit_button_srgba(self)
+    }
+}
+
+/// A list where each item can be moved up or down or removed, and new items added at the end.
+///
+/// The `options` are passed on to each item.
+impl<T: Inspect + Default> Inspect for Vec<T> {
+    fn inspect_with(&mut self, ui: &mut Ui, options: &InspectOptions) -> Response {
+        enum Action {
+            MoveUp(usize),
+            MoveDown(usize),
+            Remove(usize),
+        }
+
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = ui.vertical(|ui| {
+            let mut changed = false;
+            let mut action = None;
+            let len = self.len();
+
+            for (i, item) in self.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(Button::new("⏶").small().enabled(i > 0))
+                        .on_hover_text("Move up")
+                        .clicked()
+                    {
+                        action = Some(Action::MoveUp(i));
+                    }
+                    if ui
+                        .add(Button::new("⏷").small().enabled(i + 1 < len))
+                        .on_hover_text("Move down")
+                        .clicked()
+                    {
+                        action = Some(Action::MoveDown(i));
+                    }
+                    if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                        action = Some(Action::Remove(i));
+                    }
+                    changed |= item.inspect_with(ui, options).changed();
+                });
+            }
+
+            changed |= action.is_some();
+            match action {
+                Some(Action::MoveUp(i)) => self.swap(i - 1, i),
+                Some(Action::MoveDown(i)) => self.swap(i, i + 1),
+                Some(Action::Remove(i)) => {
+                    self.remove(i);
+                }
+                None => {}
+            }
+
+            if ui.small_button("➕ Add").clicked() {
+                self.push(T::default());
+                changed = true;
+            }
+
+            changed
+        });
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Building blocks used by `#[derive(Inspect)]`, also useful when implementing `Inspect` by hand.
+
+/// Show the fields of a struct under a collapsing header with the given name.
+///
+/// `add_fields` should call [`field_ui`] for each field, and return `true` if any field changed.
+pub fn struct_ui(ui: &mut Ui, name: &str, add_fields: impl FnOnce(&mut Ui) -> bool) -> Response {
+    let id = ui.next_auto_id();
+    let collapsing = CollapsingHeader::new(name)
+        .id_source(id)
+        .default_open(true)
+        .show(ui, |ui| {
+            Grid::new(id.with("fields"))
+                .striped(true)
+                .show(ui, add_fields)
+                .inner
+        });
+
+    let mut response = collapsing.header_response;
+    if collapsing.body_returned == Some(true) {
+        response.mark_changed();
+    }
+    response
+}
+
+/// One labeled row in a [`struct_ui`]. Returns `true` if the value changed.
+pub fn field_ui(
+    ui: &mut Ui,
+    label: &str,
+    value: &mut impl Inspect,
+    options: &InspectOptions,
+) -> bool {
+    ui.label(label);
+    let changed = value.inspect_with(ui, options).changed();
+    ui.end_row();
+    changed
+}
+
+/// A combo box for picking one of the variants of an enum.
+///
+/// `selected` is the index of the current variant in `variant_names`.
+pub fn enum_ui(ui: &mut Ui, variant_names: &[&str], selected: &mut usize) -> Response {
+    let before = *selected;
+    let mut response = ComboBox::from_id_source(ui.next_auto_id())
+        .selected_text(variant_names.get(*selected).copied().unwrap_or_default())
+        .show_ui(ui, |ui| {
+            for (i, name) in variant_names.iter().enumerate() {
+                ui.selectable_value(selected, i, *name);
+            }
+        });
+    if *selected != before {
+        response.mark_changed();
+    }
+    response
+}
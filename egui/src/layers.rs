@@ -111,6 +111,20 @@ impl PaintList {
             .extend(shapes.drain(..).map(|shape| ClippedShape(clip_rect, shape)))
     }
 
+    #[inline(always)]
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// A copy of the shapes added since the list had length `start`.
+    pub(crate) fn shapes_from(&self, start: usize) -> Vec<ClippedShape> {
+        self.0[start..].to_vec()
+    }
+
+    pub(crate) fn extend_clipped(&mut self, shapes: impl IntoIterator<Item = ClippedShape>) {
+        self.0.extend(shapes);
+    }
+
     /// Modify an existing [`Shape`].
     ///
     /// Sometimes you want to paint a frame behind some contents, but don't know how large the frame needs to be
@@ -171,3 +185,101 @@ impl GraphicLayers {
         all_shapes.into_iter()
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Animates windows and popups as they open and close, see [`crate::style::WindowAnimation`].
+///
+/// A closing layer is kept alive for a few frames by repainting the shapes it had
+/// on its last frame, fading them out, even though nobody is showing it anymore.
+#[derive(Clone, Default)]
+pub(crate) struct LayerTransitions {
+    /// When the layers that are still fading in were opened.
+    opening: AHashMap<LayerId, f64>,
+    /// When the layers that are fading out were closed, and what they looked like then.
+    closing: AHashMap<LayerId, (f64, Vec<ClippedShape>)>,
+    /// What each visible layer looks like, in case it closes.
+    latest_shapes: AHashMap<LayerId, Vec<ClippedShape>>,
+}
+
+impl LayerTransitions {
+    /// How far a window slides while fading in or out, in points.
+    const SLIDE_DISTANCE: f32 = 8.0;
+
+    /// Call at the end of each frame, before the shapes are drained from `graphics`.
+    ///
+    /// Returns `true` while some animation is in progress.
+    pub fn end_frame(
+        &mut self,
+        areas: &crate::memory::Areas,
+        graphics: &mut GraphicLayers,
+        time: f64,
+        animation_time: f32,
+        slide: bool,
+    ) -> bool {
+        let is_animated = |layer_id: &LayerId| {
+            layer_id.order == Order::Middle || layer_id.order == Order::Foreground
+        };
+        let visible = areas.visible_current_frame_layers();
+        let mut in_progress = false;
+
+        for layer_id in areas.visible_last_frame_layers() {
+            if !visible.contains(layer_id) {
+                if let Some(shapes) = self.latest_shapes.remove(layer_id) {
+                    self.closing.insert(*layer_id, (time, shapes));
+                }
+            }
+        }
+        self.latest_shapes
+            .retain(|layer_id, _| visible.contains(layer_id));
+
+        for layer_id in visible.iter().filter(|layer_id| is_animated(layer_id)) {
+            let list = graphics.list(*layer_id).clone();
+            let mut list = list.lock();
+            // Reuse last frame's allocation:
+            self.latest_shapes
+                .entry(*layer_id)
+                .or_default()
+                .clone_from(&list.0);
+            self.closing.remove(layer_id);
+
+            if !areas.visible_last_frame(layer_id) {
+                self.opening.insert(*layer_id, time);
+            }
+            if let Some(&open_time) = self.opening.get(layer_id) {
+                let opacity = (time - open_time) as f32 / animation_time;
+                if opacity < 1.0 {
+                    fade(&mut list.0, opacity, slide);
+                    in_progress = true;
+                } else {
+                    self.opening.remove(layer_id);
+                }
+            }
+        }
+
+        self.closing.retain(|layer_id, (close_time, shapes)| {
+            let opacity = 1.0 - (time - *close_time) as f32 / animation_time;
+            if opacity <= 0.0 {
+                return false;
+            }
+            let mut shapes = shapes.clone();
+            fade(&mut shapes, opacity, slide);
+            graphics.list(*layer_id).lock().0.extend(shapes);
+            in_progress = true;
+            true
+        });
+
+        in_progress
+    }
+}
+
+fn fade(shapes: &mut [ClippedShape], opacity: f32, slide: bool) {
+    let delta = vec2(0.0, -LayerTransitions::SLIDE_DISTANCE * (1.0 - opacity));
+    for ClippedShape(clip_rect, shape) in shapes {
+        shape.multiply_with_opacity(opacity);
+        if slide {
+            *clip_rect = clip_rect.translate(delta);
+            shape.translate(delta);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Replaying the shapes of a part of the ui that hasn't changed, see [`crate::Ui::cache_layout`].
+
+use std::sync::Arc;
+
+use epaint::ClippedShape;
+
+use crate::*;
+
+/// What a [`Ui::cache_layout`] painted last time it ran.
+#[derive(Clone)]
+struct LayoutCache {
+    version: Id,
+    /// Where the contents could go, and where they were clipped.
+    available_rect: Rect,
+    clip_rect: Rect,
+    style: Arc<Style>,
+    pixels_per_point: f32,
+    enabled: bool,
+
+    /// What the contents took up.
+    rect: Rect,
+    shapes: Vec<ClippedShape>,
+
+    /// The pointer or the keyboard focus was inside, so things may look different next frame.
+    interacted: bool,
+}
+
+impl LayoutCache {
+    fn is_valid(&self, ui: &Ui, version: Id) -> bool {
+        self.version == version
+            && !self.interacted
+            && self.available_rect == ui.available_rect_before_wrap()
+            && self.clip_rect == ui.clip_rect()
+            && Arc::ptr_eq(&self.style, ui.style())
+            && self.pixels_per_point == ui.ctx().pixels_per_point()
+            && self.enabled == ui.enabled()
+            && !interacts_with(ui.ctx(), self.rect)
+            // Replayed widgets can't take the keyboard focus:
+            && !ui.input().key_pressed(Key::Tab)
+    }
+}
+
+/// Is the pointer over `rect`, or dragging something that started there?
+fn interacts_with(ctx: &Context, rect: Rect) -> bool {
+    let pointer = &ctx.input().pointer;
+    let hovered = pointer.hover_pos().map_or(false, |pos| rect.contains(pos));
+    let dragging = (pointer.any_down() || pointer.any_released())
+        && pointer
+            .press_origin()
+            .map_or(false, |origin| rect.contains(origin));
+    hovered || dragging
+}
+
+/// Does the widget with keyboard focus lie within `rect`?
+fn contains_focus(ctx: &Context, rect: Rect) -> bool {
+    let focus = match ctx.memory().focus() {
+        Some(focus) => focus,
+        None => return false,
+    };
+    ctx.frame_state()
+        .used_ids
+        .get(&focus)
+        .map_or(false, |focus_rect| rect.intersects(*focus_rect))
+}
+
+pub(crate) fn cache_layout(
+    ui: &mut Ui,
+    id: Id,
+    version: Id,
+    add_contents: Box<dyn FnOnce(&mut Ui) + '_>,
+) -> Response {
+    let cache = ui.memory().id_data_temp.get::<LayoutCache>(&id).cloned();
+    let cache = cache.filter(|cache| cache.is_valid(ui, version));
+
+    if let Some(cache) = cache {
+        ui.ctx()
+            .graphics()
+            .list(ui.layer_id())
+            .lock()
+            .extend_clipped(cache.shapes.iter().cloned());
+        let response = ui.allocate_rect(cache.rect, Sense::hover());
+        ui.memory().id_data_temp.insert(id, cache);
+        return response;
+    }
+
+    let available_rect = ui.available_rect_before_wrap();
+    let clip_rect = ui.clip_rect();
+    let list = ui.ctx().graphics().list(ui.layer_id()).clone();
+    let start = list.lock().len();
+
+    let response = ui.scope(add_contents).response;
+
+    let shapes = list.lock().shapes_from(start);
+    let interacted =
+        interacts_with(ui.ctx(), response.rect) || contains_focus(ui.ctx(), response.rect);
+    let cache = LayoutCache {
+        version,
+        available_rect,
+        clip_rect,
+        style: ui.style().clone(),
+        pixels_per_point: ui.ctx().pixels_per_point(),
+        enabled: ui.enabled(),
+        rect: response.rect,
+        shapes,
+        interacted,
+    };
+    ui.memory().id_data_temp.insert(id, cache);
+    response
+}
+
+#[test]
+fn test_cache_layout() {
+    use crate::test_util::{key, run_frame_with_shapes};
+    let mut ctx = CtxRef::default();
+    let mut runs = 0;
+    let run_frame = |ctx: &mut CtxRef, runs: &mut usize, events: Vec<Event>, version: u32| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let (rect, _, shapes) = run_frame_with_shapes(ctx, input, |ctx| {
+            CentralPanel::default()
+                .show(ctx, |ui| {
+                    ui.cache_layout("cached", version, |ui| {
+                        *runs += 1;
+                        ui.label("Hello");
+                        ui.label("World");
+                    })
+                    .rect
+                })
+                .inner
+        });
+        (rect, shapes.len())
+    };
+
+    let (rect, num_shapes) = run_frame(&mut ctx, &mut runs, vec![], 0);
+    assert_eq!(runs, 1);
+    assert_eq!(
+        run_frame(&mut ctx, &mut runs, vec![], 0),
+        (rect, num_shapes),
+        "replayed"
+    );
+    assert_eq!(runs, 1);
+
+    run_frame(&mut ctx, &mut runs, vec![], 1);
+    assert_eq!(runs, 2, "new version");
+
+    // Hovering may change how things look:
+    run_frame(
+        &mut ctx,
+        &mut runs,
+        vec![Event::PointerMoved(rect.center())],
+        1,
+    );
+    assert_eq!(runs, 3);
+    run_frame(
+        &mut ctx,
+        &mut runs,
+        vec![Event::PointerMoved(pos2(1000.0, 1000.0))],
+        1,
+    );
+    assert_eq!(runs, 4, "no longer hovered");
+    run_frame(&mut ctx, &mut runs, vec![], 1);
+    assert_eq!(runs, 4);
+
+    // Tab may move the focus into the contents:
+    run_frame(&mut ctx, &mut runs, vec![key(Key::Tab)], 1);
+    assert_eq!(runs, 5);
+}
+
+#[test]
+fn test_cache_layout_tab_focus() {
+    use crate::test_util::{key, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            let mut button_id = None;
+            ui.cache_layout("cached", 0, |ui| {
+                button_id = Some(ui.button("Focus me").id);
+            });
+            (button_id, ui.memory().focus())
+        })
+    };
+    let (button_id, _) = run_frame(vec![]);
+    assert!(run_frame(vec![]).0.is_none(), "replayed");
+    let (_, focus) = run_frame(vec![key(Key::Tab)]);
+    assert_eq!(focus, button_id);
+}
@@ -324,13 +324,18 @@ pub mod any;
 pub mod containers;
 mod context;
 mod data;
+mod data_grid;
+mod event_log;
+mod frame_budget;
 mod frame_state;
 pub(crate) mod grid;
 mod id;
 mod input_state;
+pub mod inspect;
 mod introspection;
 pub mod layers;
 mod layout;
+mod layout_cache;
 mod memory;
 pub mod menu;
 mod painter;
@@ -338,6 +343,8 @@ pub(crate) mod placer;
 mod response;
 mod sense;
 pub mod style;
+#[cfg(test)]
+mod test_util;
 mod ui;
 pub mod util;
 pub mod widgets;
@@ -355,26 +362,33 @@ pub use emath::{lerp, pos2, remap, remap_clamp, vec2, Align, Align2, NumExt, Pos
 pub use epaint::{
     color, mutex,
     text::{FontDefinitions, FontFamily, TextStyle},
-    ClippedMesh, Color32, Rgba, Shape, Stroke, Texture, TextureId,
+    ClippedMesh, Color32, ColorImage, Rgba, Shape, Stroke, Texture, TextureId,
 };
 
+/// Derive [`Inspect`] with the `derive` feature, see [`inspect`].
+#[cfg(feature = "derive")]
+pub use egui_derive::Inspect;
+
 pub use {
     containers::*,
-    context::{Context, CtxRef},
+    context::{Context, CtxRef, WidgetRect},
     data::{
         input::*,
         output::{self, CursorIcon, Output, WidgetInfo},
     },
+    data_grid::{DataColumn, DataGrid},
+    event_log::UiEvent,
     grid::Grid,
     id::Id,
     input_state::{InputState, MultiTouchInfo, PointerState},
+    inspect::Inspect,
     layers::{LayerId, Order},
     layout::*,
     memory::Memory,
     painter::Painter,
     response::{InnerResponse, Response},
     sense::Sense,
-    style::{Style, Visuals},
+    style::{Style, Theme, Visuals},
     ui::Ui,
     widgets::*,
 };
@@ -520,6 +534,7 @@ pub enum WidgetType {
     ColorButton,
     ImageButton,
     CollapsingHeader,
+    ProgressIndicator,
 
     /// If you cannot fit any of the above slots.
     ///
@@ -71,6 +71,22 @@ pub struct Memory {
 
     #[cfg_attr(feature = "persistence", serde(skip))]
     everything_is_visible: bool,
+
+    /// Set by [`crate::Context::set_enabled`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) disabled: bool,
+
+    /// Only used if [`Options::log_ui_events`] is on.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) event_log: crate::event_log::EventLog,
+
+    /// Widgets highlighted with [`crate::Context::highlight_widget`], and when.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) highlighted_widgets: Vec<(Id, f64)>,
+
+    /// The recent frame times, for [`crate::Context::degrade`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) frame_budget: crate::frame_budget::FrameBudget,
 }
 
 // ----------------------------------------------------------------------------
@@ -84,13 +100,42 @@ pub struct Options {
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) style: std::sync::Arc<Style>,
 
+    /// The theme last chosen with [`crate::Context::set_theme`].
+    ///
+    /// Unlike the style, this is persisted, and re-applied on the first frame after a restore.
+    pub(crate) theme: Option<crate::style::Theme>,
+
+    /// The theme that [`Self::style`] was last set from.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) applied_theme: Option<crate::style::Theme>,
+
     /// Controls the tessellator.
     pub(crate) tessellation_options: epaint::TessellationOptions,
 
     /// This does not at all change the behavior of egui,
     /// but is a signal to any backend that we want the [`crate::Output::events`] read out loud.
+    /// egui also describes them in words in [`crate::Output::speech`].
     /// Screen readers is an experimental feature of egui, and not supported on all platforms.
     pub screen_reader: bool,
+
+    /// Keep a log of what the user does, e.g. which widgets were clicked.
+    /// Take the events with [`crate::Context::take_ui_events`].
+    /// Off by default.
+    pub log_ui_events: bool,
+
+    /// Remember where all widgets are shown, for [`crate::Context::widget_rect`].
+    /// Otherwise only interactive widgets are remembered (e.g. not labels, or disabled buttons).
+    /// Costs a little time for every widget, so it is off by default.
+    pub register_all_widget_rects: bool,
+
+    /// How long a frame may take, in seconds, e.g. `Some(1.0 / 60.0)`.
+    ///
+    /// When recent frames take longer than this on average, [`crate::Context::degrade`]
+    /// turns on and egui skips expensive effects, like shadows, animations and anti-aliasing,
+    /// until the app keeps up again.
+    /// Requires the integration to set [`crate::RawInput::previous_frame_time`].
+    /// `None` (the default) never degrades.
+    pub frame_budget: Option<f32>,
 }
 
 // ----------------------------------------------------------------------------
@@ -126,6 +171,9 @@ pub(crate) struct Interaction {
     /// Any interest in catching clicks this frame?
     /// Cleared to false at start of each frame.
     pub drag_interest: bool,
+
+    /// Text carried by the widget being dragged, that can be dropped into a [`crate::TextEdit`].
+    pub drag_text: Option<String>,
 }
 
 /// Keeps tracks of what widget has keyboard focus
@@ -179,6 +227,7 @@ impl Interaction {
             // pointer button was not down last frame
             self.click_id = None;
             self.drag_id = None;
+            self.drag_text = None;
         }
 
         self.focus.begin_frame(new_input);
@@ -279,6 +328,18 @@ impl Memory {
         if !prev_input.pointer.any_down() {
             self.window_interaction = None;
         }
+
+        if let Some(monitor_setup) = new_input.monitor_setup {
+            let screen_rect = new_input.screen_rect.unwrap_or(prev_input.screen_rect);
+            self.areas.set_monitor_setup(monitor_setup, screen_rect);
+        }
+
+        let time = new_input.time.unwrap_or(prev_input.time);
+        self.frame_budget.begin_frame(
+            time,
+            new_input.previous_frame_time,
+            self.options.frame_budget,
+        );
     }
 
     pub(crate) fn end_frame(
@@ -289,6 +350,15 @@ impl Memory {
         self.areas.end_frame();
         self.interaction.focus.end_frame(used_ids);
         self.drag_value.end_frame(input);
+        if !input.pointer.any_down() {
+            self.data_temp
+                .remove::<crate::widgets::color_picker::DraggedColor>();
+        }
+        if self.options.log_ui_events {
+            self.event_log.end_frame();
+        } else {
+            self.event_log = Default::default();
+        }
     }
 
     pub fn layer_id_at(&self, pos: Pos2, resize_interact_radius_side: f32) -> Option<LayerId> {
@@ -375,6 +445,19 @@ impl Memory {
         self.interaction.drag_id == Some(id)
     }
 
+    /// Let the current drag carry some text, which can be dropped into a [`crate::TextEdit`].
+    ///
+    /// Only call this while something is being dragged. The text is forgotten when the drag ends.
+    /// See also [`crate::Response::on_drag_text`].
+    pub fn set_dragged_text(&mut self, text: impl Into<String>) {
+        self.interaction.drag_text = Some(text.into());
+    }
+
+    /// The text carried by the current drag, if any. See [`Self::set_dragged_text`].
+    pub fn dragged_text(&self) -> Option<&str> {
+        self.interaction.drag_text.as_deref()
+    }
+
     /// Forget window positions, sizes etc.
     /// Can be used to auto-layout windows.
     pub fn reset_areas(&mut self) {
@@ -446,6 +529,18 @@ pub struct Areas {
     /// So if you close three windows and then reopen them all in one frame,
     /// they will all be sent to the top, but keep their previous internal order.
     wants_to_be_on_top: HashSet<LayerId>,
+
+    /// Like `wants_to_be_on_top`, but for going to the bottom.
+    wants_to_be_on_bottom: HashSet<LayerId>,
+
+    /// These stay above the other areas with the same [`crate::Order`].
+    always_on_top: HashSet<LayerId>,
+
+    /// The monitor setup the areas are on, see [`crate::RawInput::monitor_setup`].
+    monitor_setup: Option<u64>,
+
+    /// Where the areas were on the other monitor setups.
+    areas_by_monitor_setup: HashMap<u64, HashMap<Id, area::State>>,
 }
 
 impl Areas {
@@ -457,7 +552,8 @@ impl Areas {
         self.areas.get(&id)
     }
 
-    pub(crate) fn order(&self) -> &[LayerId] {
+    /// All layers, from back to front.
+    pub fn order(&self) -> &[LayerId] {
         &self.order
     }
 
@@ -469,6 +565,29 @@ impl Areas {
         }
     }
 
+    /// Remembers where the areas are on the current monitor setup,
+    /// and moves them to where they were the last time `monitor_setup` was used.
+    /// If it is a new setup, areas that are now off the screen go back to their default positions.
+    pub(crate) fn set_monitor_setup(&mut self, monitor_setup: u64, screen_rect: Rect) {
+        if self.monitor_setup == Some(monitor_setup) {
+            return;
+        }
+        if let Some(previous) = self.monitor_setup {
+            self.areas_by_monitor_setup
+                .insert(previous, self.areas.clone());
+        }
+
+        if let Some(areas) = self.areas_by_monitor_setup.get(&monitor_setup) {
+            self.areas
+                .extend(areas.iter().map(|(id, state)| (*id, *state)));
+        } else {
+            // The monitor an area was on may be gone:
+            self.areas
+                .retain(|_, state| screen_rect.contains(state.rect().center()));
+        }
+        self.monitor_setup = Some(monitor_setup);
+    }
+
     pub fn layer_id_at(&self, pos: Pos2, resize_interact_radius_side: f32) -> Option<LayerId> {
         for layer in self.order.iter().rev() {
             if self.is_visible(layer) {
@@ -487,6 +606,14 @@ impl Areas {
         None
     }
 
+    pub(crate) fn visible_current_frame_layers(&self) -> &HashSet<LayerId> {
+        &self.visible_current_frame
+    }
+
+    pub(crate) fn visible_last_frame_layers(&self) -> &HashSet<LayerId> {
+        &self.visible_last_frame
+    }
+
     pub fn visible_last_frame(&self, layer_id: &LayerId) -> bool {
         self.visible_last_frame.contains(layer_id)
     }
@@ -511,27 +638,62 @@ impl Areas {
             .collect()
     }
 
+    /// Put the layer in front of all others with the same [`crate::Order`] (at the end of the frame).
     pub fn move_to_top(&mut self, layer_id: LayerId) {
         self.visible_current_frame.insert(layer_id);
+        self.wants_to_be_on_bottom.remove(&layer_id);
         self.wants_to_be_on_top.insert(layer_id);
 
-        if !self.order.iter().any(|x| *x == layer_id) {
+        if !self.order.contains(&layer_id) {
             self.order.push(layer_id);
         }
     }
 
+    /// Put the layer behind all others with the same [`crate::Order`] (at the end of the frame).
+    pub fn move_to_bottom(&mut self, layer_id: LayerId) {
+        self.wants_to_be_on_top.remove(&layer_id);
+        self.wants_to_be_on_bottom.insert(layer_id);
+
+        if !self.order.contains(&layer_id) {
+            self.order.insert(0, layer_id);
+        }
+    }
+
+    /// Keep the layer above all others with the same [`crate::Order`] that are not always on top.
+    pub fn set_always_on_top(&mut self, layer_id: LayerId, always_on_top: bool) {
+        if always_on_top {
+            self.always_on_top.insert(layer_id);
+        } else {
+            self.always_on_top.remove(&layer_id);
+        }
+    }
+
+    pub fn is_always_on_top(&self, layer_id: LayerId) -> bool {
+        self.always_on_top.contains(&layer_id)
+    }
+
     pub(crate) fn end_frame(&mut self) {
         let Self {
             visible_last_frame,
             visible_current_frame,
             order,
             wants_to_be_on_top,
+            wants_to_be_on_bottom,
+            always_on_top,
             ..
         } = self;
 
         *visible_last_frame = std::mem::take(visible_current_frame);
-        order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
+        order.sort_by_key(|layer| {
+            (
+                layer.order,
+                always_on_top.contains(layer),
+                !wants_to_be_on_bottom.contains(layer),
+                wants_to_be_on_top.contains(layer),
+            )
+        });
         wants_to_be_on_top.clear();
+        wants_to_be_on_bottom.clear();
     }
 }
 
@@ -543,3 +705,60 @@ fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Memory>();
 }
+
+#[test]
+fn test_areas_by_monitor_setup() {
+    let mut areas = Areas::default();
+    let layer_id = LayerId::new(crate::Order::Middle, Id::new("window"));
+    let big_screen = Rect::from_min_size(Pos2::ZERO, crate::vec2(2000.0, 1000.0));
+    let small_screen = Rect::from_min_size(Pos2::ZERO, crate::vec2(1000.0, 600.0));
+    areas.set_monitor_setup(1, big_screen);
+    areas.set_state(
+        layer_id,
+        area::State {
+            pos: crate::pos2(1500.0, 100.0),
+            size: crate::vec2(200.0, 100.0),
+            interactable: true,
+        },
+    );
+
+    // The window is off the screen of the new setup, so it goes back to its default position:
+    areas.set_monitor_setup(2, small_screen);
+    assert!(areas.get(layer_id.id).is_none());
+
+    // Back where it was:
+    areas.set_monitor_setup(1, big_screen);
+    assert_eq!(
+        areas.get(layer_id.id).unwrap().pos,
+        crate::pos2(1500.0, 100.0)
+    );
+}
+
+#[test]
+fn test_areas_order() {
+    let mut areas = Areas::default();
+    let window = |name: &str| LayerId::new(crate::Order::Middle, Id::new(name));
+    let (a, b, c) = (window("a"), window("b"), window("c"));
+    let tooltip = LayerId::new(crate::Order::Tooltip, Id::new("tooltip"));
+    for &layer_id in &[tooltip, a, b, c] {
+        areas.move_to_top(layer_id);
+        areas.end_frame();
+    }
+    assert_eq!(areas.order(), &[a, b, c, tooltip]);
+
+    areas.move_to_top(a);
+    areas.end_frame();
+    assert_eq!(areas.order(), &[b, c, a, tooltip]);
+
+    areas.move_to_bottom(a);
+    areas.end_frame();
+    assert_eq!(areas.order(), &[a, b, c, tooltip]);
+
+    // Clicking other windows doesn't put them above one that is always on top:
+    areas.set_always_on_top(b, true);
+    assert!(areas.is_always_on_top(b));
+    areas.end_frame();
+    areas.move_to_top(c);
+    areas.end_frame();
+    assert_eq!(areas.order(), &[a, c, b, tooltip]);
+}
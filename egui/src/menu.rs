@@ -6,10 +6,15 @@
 //!     use egui::{menu, Button};
 //!
 //!     menu::bar(ui, |ui| {
-//!         menu::menu(ui, "File", |ui| {
+//!         menu::menu(ui, "&File", |ui| {
 //!             if ui.button("Open").clicked() {
 //!                 // ...
 //!             }
+//!             menu::submenu(ui, "Open &Recent", |ui| {
+//!                 if ui.button("notes.txt").clicked() {
+//!                     // ...
+//!                 }
+//!             });
 //!         });
 //!     });
 //! }
@@ -19,16 +24,39 @@ use crate::{widgets::*, *};
 use epaint::Stroke;
 
 /// What is saved between frames.
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "persistence", serde(default))]
 pub(crate) struct BarState {
     open_menu: Option<Id>,
+    /// The menus of the bar, from left to right.
+    menus: Vec<BarMenu>,
+    /// The items of the open menu and its submenus that can get keyboard focus.
+    open_items: Vec<Id>,
+    /// Give keyboard focus to the first item of the open menu when it is shown.
+    focus_first_item: bool,
+    /// Is the menu bar used with the keyboard? Then the access keys are underlined.
+    keyboard: bool,
+    /// Was alt down last frame?
+    alt_down: bool,
+    /// Has alt been pressed without anything else since?
+    alt_alone: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+struct BarMenu {
+    button_id: Id,
+    menu_id: Id,
+    access_key: Option<char>,
 }
 
 impl BarState {
     fn load(ctx: &Context, bar_id: &Id) -> Self {
-        *ctx.memory().id_data_temp.get_or_default(*bar_id)
+        ctx.memory()
+            .id_data_temp
+            .get_or_default::<Self>(*bar_id)
+            .clone()
     }
 
     fn save(self, ctx: &Context, bar_id: Id) {
@@ -36,9 +64,138 @@ impl BarState {
     }
 }
 
+/// Which submenu of a menu is open. Stored per menu, with the id of its layer.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct SubMenuState {
+    open: Option<Id>,
+    /// Where the open submenu was last frame.
+    open_rect: Option<Rect>,
+}
+
+/// The items of a menu that can get keyboard focus, from top to bottom.
+/// Stored per menu, with the id of its layer `.with("items")`.
+#[derive(Clone, Debug, Default)]
+struct MenuItems(Vec<Id>);
+
+/// Collects the [`MenuItems`] of the open menu and its submenus while showing them.
+#[derive(Clone, Debug, Default)]
+struct OpenMenuItems(Vec<Id>);
+
+/// The widths of the columns of the items of a menu, so that they line up.
+/// Stored per menu, with the id of its layer `.with("columns")`.
+/// The items add themselves to the ones with `.with("next")`, which are used the next frame.
+#[derive(Clone, Copy, Debug, Default)]
+struct MenuColumns {
+    /// Does any item have an icon, a checkmark or a radio button?
+    icons: bool,
+    text_width: f32,
+    shortcut_width: f32,
+}
+
+/// Set when an item was clicked with its access key, which closes the menu.
+#[derive(Clone, Copy, Debug, Default)]
+struct MenuItemActivated;
+
+/// Set while showing a menu of a menu bar that is used with the keyboard.
+#[derive(Clone, Copy, Debug, Default)]
+struct ShowAccessKeys;
+
+/// Set when something in a menu was clicked that shouldn't close the menu, like a submenu button.
+#[derive(Clone, Copy, Debug, Default)]
+struct KeepMenuOpen;
+
+/// Set when a submenu used the left or right arrow key, so the menu bar doesn't also move to another menu.
+#[derive(Clone, Copy, Debug, Default)]
+struct ArrowKeyUsed;
+
+/// Sets the style of a menu and adds its contents.
+///
+/// Moves the keyboard focus between the items with the up and down arrow keys,
+/// and returns the items that can get keyboard focus.
+fn menu_ui<'c>(ui: &mut Ui, add_contents: Box<dyn FnOnce(&mut Ui) + 'c>) -> Vec<Id> {
+    let columns_id = ui.layer_id().id.with("columns");
+    {
+        let mut memory = ui.memory();
+        let columns = memory
+            .id_data_temp
+            .get::<MenuColumns>(&columns_id.with("next"))
+            .copied()
+            .unwrap_or_default();
+        memory.id_data_temp.insert(columns_id, columns);
+        memory
+            .id_data_temp
+            .insert(columns_id.with("next"), MenuColumns::default());
+    }
+
+    Frame::menu(ui.style()).show(ui, |ui| {
+        let mut style = (**ui.style()).clone();
+        style.spacing.button_padding = vec2(2.0, 0.0);
+        // style.visuals.widgets.active.bg_fill = Color32::TRANSPARENT;
+        style.visuals.widgets.active.bg_stroke = Stroke::none();
+        // style.visuals.widgets.hovered.bg_fill = Color32::TRANSPARENT;
+        style.visuals.widgets.hovered.bg_stroke = Stroke::none();
+        style.visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
+        style.visuals.widgets.inactive.bg_stroke = Stroke::none();
+        ui.set_style(style);
+        ui.with_layout(Layout::top_down_justified(Align::LEFT), add_contents);
+    });
+
+    let layer_id = ui.layer_id();
+    let mut items: Vec<(Id, Rect)> = ui
+        .ctx()
+        .frame_state()
+        .widget_rects
+        .iter()
+        .filter(|(_, widget)| widget.layer_id == layer_id)
+        .map(|(id, widget)| (*id, widget.rect))
+        .collect();
+    items.sort_by(|(_, a), (_, b)| {
+        (a.top(), a.left())
+            .partial_cmp(&(b.top(), b.left()))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let items: Vec<Id> = items.into_iter().map(|(id, _)| id).collect();
+
+    let step = if ui.input().key_pressed(Key::ArrowDown) {
+        1
+    } else if ui.input().key_pressed(Key::ArrowUp) {
+        -1
+    } else {
+        0
+    };
+    let focus = ui.memory().focus();
+    let focused_item = focus.and_then(|focus| items.iter().position(|&id| id == focus));
+    if let (Some(index), true) = (focused_item, step != 0) {
+        let next = (index as isize + step).rem_euclid(items.len() as isize) as usize;
+        let mut memory = ui.memory();
+        memory.request_focus(items[next]);
+        // Moving away from a submenu button closes its submenu:
+        let state = memory
+            .id_data_temp
+            .get_mut_or_default::<SubMenuState>(layer_id.id);
+        if let Some(open) = state.open.take() {
+            state.open_rect = None;
+            memory.id_data_temp.remove(&Area::new(open).layer().id);
+        }
+    }
+
+    let mut memory = ui.memory();
+    if let Some(open_items) = memory.data_temp.get_mut::<OpenMenuItems>() {
+        open_items.0.extend(items.iter().copied());
+    }
+    memory
+        .id_data_temp
+        .insert(layer_id.id.with("items"), MenuItems(items.clone()));
+    items
+}
+
 /// The menu bar goes well in a [`TopBottomPanel::top`],
 /// but can also be placed in a `Window`.
 /// In the latter case you may want to wrap it in `Frame`.
+///
+/// The menu bar can be used with the keyboard. Press and release alt to move the keyboard focus to it.
+/// Then use the arrow keys to move between the menus and their items, enter to open a menu or click an item,
+/// and escape to close the menu. Hold alt and press the access key of a menu to open it (see [`menu`]).
 pub fn bar<R>(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
     ui.horizontal(|ui| {
         let mut style = (**ui.style()).clone();
@@ -55,11 +212,104 @@ pub fn bar<R>(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResp
         let height = ui.spacing().interact_size.y;
         ui.set_min_size(vec2(ui.available_width(), height));
 
-        add_contents(ui)
+        // The menus add themselves:
+        let bar_id = ui.id();
+        let mut bar_state = BarState::load(ui.ctx(), &bar_id);
+        bar_state.menus.clear();
+        bar_state.save(ui.ctx(), bar_id);
+
+        let inner = add_contents(ui);
+
+        let mut bar_state = BarState::load(ui.ctx(), &bar_id);
+        bar_keyboard_input(ui, &mut bar_state);
+        bar_state.save(ui.ctx(), bar_id);
+        inner
     })
 }
 
+/// Moves between the menus of a bar with the keyboard.
+fn bar_keyboard_input(ui: &Ui, state: &mut BarState) {
+    let input = ui.input();
+    let focus = ui.memory().focus();
+    let focused_menu =
+        focus.and_then(|focus| state.menus.iter().position(|menu| menu.button_id == focus));
+    let focus_in_open_menu = focus.map_or(false, |focus| state.open_items.contains(&focus));
+    let open_menu = state
+        .menus
+        .iter()
+        .position(|menu| Some(menu.menu_id) == state.open_menu);
+
+    // Left and right move to the neighboring menu:
+    let arrow_key_used = ui.memory().data_temp.get::<ArrowKeyUsed>().is_some();
+    ui.memory().data_temp.remove::<ArrowKeyUsed>();
+    let step = if input.key_pressed(Key::ArrowRight) {
+        1
+    } else if input.key_pressed(Key::ArrowLeft) {
+        -1
+    } else {
+        0
+    };
+    let current = focused_menu.or(if focus_in_open_menu { open_menu } else { None });
+    if let (Some(index), true) = (current, step != 0 && !arrow_key_used) {
+        let next =
+            state.menus[(index as isize + step).rem_euclid(state.menus.len() as isize) as usize];
+        ui.memory().request_focus(next.button_id);
+        if state.open_menu.is_some() {
+            state.open_menu = Some(next.menu_id);
+            state.focus_first_item = true;
+        }
+    }
+
+    // Alt on its own moves the keyboard focus to the menu bar, or back out of it:
+    let anything_else = input.events.iter().any(|event| {
+        matches!(
+            event,
+            Event::Key { pressed: true, .. } | Event::PointerButton { .. } | Event::Text(_)
+        )
+    });
+    let alt = input.modifiers.alt;
+    if alt && !state.alt_down {
+        state.alt_alone = true;
+    }
+    if anything_else {
+        state.alt_alone = false;
+    }
+    if !alt && state.alt_down && state.alt_alone {
+        if focused_menu.is_some() || focus_in_open_menu {
+            if let Some(focus) = focus {
+                ui.memory().surrender_focus(focus);
+            }
+            state.open_menu = None;
+        } else if let Some(first) = state.menus.first() {
+            ui.memory().request_focus(first.button_id);
+        }
+    }
+    state.alt_down = alt;
+
+    // Alt and an access key opens that menu. Without alt when the focus is on the menu bar:
+    if alt || (focused_menu.is_some() && state.open_menu.is_none()) {
+        let pressed = state.menus.iter().find(|menu| {
+            menu.access_key
+                .and_then(key_from_char)
+                .map_or(false, |key| input.key_pressed(key))
+        });
+        if let Some(menu) = pressed {
+            ui.memory().request_focus(menu.button_id);
+            state.open_menu = Some(menu.menu_id);
+            state.focus_first_item = true;
+        }
+    }
+
+    let focus = ui.memory().focus();
+    state.keyboard = focus.map_or(false, |focus| {
+        state.menus.iter().any(|menu| menu.button_id == focus) || state.open_items.contains(&focus)
+    });
+}
+
 /// Construct a top level menu in a menu bar. This would be e.g. "File", "Edit" etc.
+///
+/// Put a `&` before the access key of the menu in the title, e.g. `"&File"`.
+/// Holding alt and pressing the access key opens the menu. Use `"&&"` for a `&`.
 pub fn menu(ui: &mut Ui, title: impl ToString, add_contents: impl FnOnce(&mut Ui)) {
     menu_impl(ui, title, Box::new(add_contents))
 }
@@ -69,10 +319,11 @@ fn menu_impl<'c>(ui: &mut Ui, title: impl ToString, add_contents: Box<dyn FnOnce
     let title = title.to_string();
     let bar_id = ui.id();
     let menu_id = bar_id.with(&title);
+    let (text, access_key) = parse_access_key(&title);
 
     let mut bar_state = BarState::load(ui.ctx(), &bar_id);
 
-    let mut button = Button::new(title);
+    let mut button = Button::new(&text);
 
     if bar_state.open_menu == Some(menu_id) {
         button = button.fill(ui.visuals().widgets.open.bg_fill);
@@ -80,6 +331,26 @@ fn menu_impl<'c>(ui: &mut Ui, title: impl ToString, add_contents: Box<dyn FnOnce
     }
 
     let button_response = ui.add(button);
+    bar_state.menus.push(BarMenu {
+        button_id: button_response.id,
+        menu_id,
+        access_key: access_key.and_then(|index| text[index..].chars().next()),
+    });
+    if let Some(index) = access_key {
+        if bar_state.keyboard || ui.input().modifiers.alt {
+            let text_size = ui
+                .fonts()
+                .layout_no_wrap(TextStyle::Button, text.clone())
+                .size;
+            let text_pos = pos2(
+                button_response.rect.left() + ui.spacing().button_padding.x,
+                button_response.rect.center().y - text_size.y / 2.0,
+            );
+            let color = ui.style().interact(&button_response).text_color();
+            paint_access_key(ui, text_pos, &text, index, color);
+        }
+    }
+
     if button_response.clicked() {
         // Toggle
         if bar_state.open_menu == Some(menu_id) {
@@ -87,36 +358,938 @@ fn menu_impl<'c>(ui: &mut Ui, title: impl ToString, add_contents: Box<dyn FnOnce
         } else {
             bar_state.open_menu = Some(menu_id);
         }
-    } else if button_response.hovered() && bar_state.open_menu.is_some() {
+    } else if button_response.hovered()
+        && bar_state.open_menu.is_some()
+        && ui.input().pointer.delta() != Vec2::ZERO
+    {
         bar_state.open_menu = Some(menu_id);
     }
+    if button_response.has_focus() {
+        let input = ui.input();
+        if input.key_pressed(Key::ArrowDown) {
+            bar_state.open_menu = Some(menu_id);
+        }
+        if bar_state.open_menu == Some(menu_id)
+            && (input.key_pressed(Key::ArrowDown)
+                || input.key_pressed(Key::Enter)
+                || input.key_pressed(Key::Space))
+        {
+            bar_state.focus_first_item = true;
+        }
+    }
 
     if bar_state.open_menu == Some(menu_id) || ui.ctx().memory().everything_is_visible() {
         let area = Area::new(menu_id)
             .order(Order::Foreground)
             .fixed_pos(button_response.rect.left_bottom());
-        let frame = Frame::menu(ui.style());
-
-        area.show(ui.ctx(), |ui| {
-            frame.show(ui, |ui| {
-                let mut style = (**ui.style()).clone();
-                style.spacing.button_padding = vec2(2.0, 0.0);
-                // style.visuals.widgets.active.bg_fill = Color32::TRANSPARENT;
-                style.visuals.widgets.active.bg_stroke = Stroke::none();
-                // style.visuals.widgets.hovered.bg_fill = Color32::TRANSPARENT;
-                style.visuals.widgets.hovered.bg_stroke = Stroke::none();
-                style.visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
-                style.visuals.widgets.inactive.bg_stroke = Stroke::none();
-                ui.set_style(style);
-                ui.with_layout(Layout::top_down_justified(Align::LEFT), add_contents);
-            });
-        });
+        let mut items = vec![];
+        {
+            let mut memory = ui.memory();
+            memory.data_temp.insert(OpenMenuItems::default());
+            if bar_state.keyboard {
+                memory.data_temp.insert(ShowAccessKeys);
+            }
+        }
+        area.show(ui.ctx(), |ui| items = menu_ui(ui, add_contents));
+        let open_items = {
+            let mut memory = ui.memory();
+            let open_items = memory
+                .data_temp
+                .get::<OpenMenuItems>()
+                .map(|open_items| open_items.0.clone())
+                .unwrap_or_default();
+            memory.data_temp.remove::<OpenMenuItems>();
+            memory.data_temp.remove::<ShowAccessKeys>();
+            open_items
+        };
 
-        // TODO: this prevents sub-menus in menus. We should fix that.
-        if ui.input().key_pressed(Key::Escape) || button_response.clicked_elsewhere() {
+        let keep_open = ui.memory().data_temp.get::<KeepMenuOpen>().is_some();
+        ui.memory().data_temp.remove::<KeepMenuOpen>();
+        let activated_with_access_key = ui.memory().data_temp.get::<MenuItemActivated>().is_some();
+        ui.memory().data_temp.remove::<MenuItemActivated>();
+        let focus = ui.memory().focus();
+        // An item was clicked with the keyboard:
+        let activated = activated_with_access_key
+            || focus.map_or(false, |focus| open_items.contains(&focus))
+                && (ui.input().key_pressed(Key::Enter) || ui.input().key_pressed(Key::Space));
+
+        if ui.input().key_pressed(Key::Escape) {
+            bar_state.open_menu = None;
+            // Back to the menu bar:
+            let mut memory = ui.memory();
+            if open_items.iter().any(|&id| memory.had_focus_last_frame(id)) {
+                memory.request_focus(button_response.id);
+            }
+        } else if (button_response.clicked_elsewhere() || activated) && !keep_open {
             bar_state.open_menu = None;
+            if let (Some(focus), true) = (focus, activated) {
+                ui.memory().surrender_focus(focus);
+            }
+        } else if bar_state.focus_first_item && bar_state.open_menu == Some(menu_id) {
+            if let Some(&first) = items.first() {
+                ui.memory().request_focus(first);
+            }
         }
+        if bar_state.open_menu == Some(menu_id) {
+            bar_state.open_items = open_items;
+            bar_state.focus_first_item = false;
+        }
+    }
+    if bar_state.open_menu.is_none() {
+        // Start with all submenus closed next time:
+        ui.memory().id_data_temp.remove_by_type::<SubMenuState>();
+        bar_state.open_items.clear();
+        bar_state.focus_first_item = false;
     }
 
     bar_state.save(ui.ctx(), bar_id);
 }
+
+/// Construct a submenu in a menu, e.g. "Open Recent" in the "File" menu.
+///
+/// The submenu opens to the side when its button is hovered or clicked,
+/// or when the button is hovered and the right arrow key is pressed. The left arrow key closes it again.
+/// While the mouse moves diagonally towards the open submenu over other items, it stays open.
+///
+/// With the keyboard, move to the button and press the right arrow key or enter, or press its access key
+/// (see [`menu`]) while in the menu.
+///
+/// Submenus can be nested as deep as you like.
+pub fn submenu(ui: &mut Ui, title: impl ToString, add_contents: impl FnOnce(&mut Ui)) -> Response {
+    submenu_impl(ui, &title.to_string(), Box::new(add_contents))
+}
+
+fn submenu_impl<'c>(
+    ui: &mut Ui,
+    title: &str,
+    add_contents: Box<dyn FnOnce(&mut Ui) + 'c>,
+) -> Response {
+    let parent_id = ui.layer_id().id;
+    let submenu_id = parent_id.with(title);
+    let area = Area::new(submenu_id).order(Order::Foreground);
+    let (text, access_key) = parse_access_key(title);
+
+    let mut state = *ui
+        .memory()
+        .id_data_temp
+        .get_or_default::<SubMenuState>(parent_id);
+    let was_open = state.open == Some(submenu_id);
+    let response = submenu_button(ui, &text, access_key, was_open);
+
+    let pointer = ui.input().pointer.hover_pos();
+    let moving_toward_open_submenu = match (pointer, state.open_rect) {
+        (Some(pointer), Some(open_rect)) => {
+            is_moving_toward(pointer, ui.input().pointer.delta(), open_rect)
+        }
+        _ => false,
+    };
+
+    let focus_in = |layer: Id| {
+        let mut memory = ui.memory();
+        let focus = memory.focus();
+        focus.map_or(false, |focus| {
+            memory
+                .id_data_temp
+                .get::<MenuItems>(&layer.with("items"))
+                .map_or(false, |items| items.0.contains(&focus))
+        })
+    };
+    let focus_in_parent = focus_in(parent_id);
+    let focus_in_submenu = focus_in(area.layer().id);
+
+    let input = ui.input();
+    let access_key_pressed = focus_in_parent
+        && access_key
+            .and_then(|index| text[index..].chars().next())
+            .and_then(key_from_char)
+            .map_or(false, |key| input.key_pressed(key));
+    let opened_with_keyboard = access_key_pressed
+        || (response.has_focus()
+            && (input.key_pressed(Key::ArrowRight)
+                || input.key_pressed(Key::Enter)
+                || input.key_pressed(Key::Space)));
+
+    if response.clicked() || opened_with_keyboard {
+        ui.memory().data_temp.insert(KeepMenuOpen);
+        state.open = Some(submenu_id);
+        if input.key_pressed(Key::ArrowRight) {
+            ui.memory().data_temp.insert(ArrowKeyUsed);
+        }
+    } else if response.hovered()
+        && (input.pointer.delta() != Vec2::ZERO || input.key_pressed(Key::ArrowRight))
+        && (!moving_toward_open_submenu || input.key_pressed(Key::ArrowRight))
+    {
+        state.open = Some(submenu_id);
+    } else if was_open {
+        // Close when the pointer rests on another item of this menu:
+        let on_other_item = pointer.map_or(false, |pointer| {
+            !response.rect.contains(pointer) && ui.ctx().layer_id_at(pointer) == Some(ui.layer_id())
+        });
+        // The left arrow key closes the innermost submenu:
+        let has_open_child = ui
+            .memory()
+            .id_data_temp
+            .get::<SubMenuState>(&area.layer().id)
+            .map_or(false, |child| child.open.is_some());
+        if on_other_item && !moving_toward_open_submenu && !focus_in_submenu {
+            state.open = None;
+        } else if input.key_pressed(Key::ArrowLeft) && !has_open_child {
+            state.open = None;
+            ui.memory().data_temp.insert(ArrowKeyUsed);
+            if focus_in_submenu {
+                response.request_focus();
+            }
+        }
+    }
+
+    if state.open == Some(submenu_id) || (was_open && state.open.is_none()) {
+        state.open_rect = None;
+    }
+    if state.open == Some(submenu_id) {
+        let gap = ui.spacing().item_spacing.x;
+        let frame_margin = ui.style().spacing.window_padding.y;
+        let mut items = vec![];
+        let area_response = area
+            .fixed_pos(response.rect.right_top() + vec2(gap, -frame_margin))
+            .show(ui.ctx(), |ui| items = menu_ui(ui, add_contents));
+        state.open_rect = Some(area_response.rect);
+        if opened_with_keyboard {
+            if let Some(&first) = items.first() {
+                ui.memory().request_focus(first);
+            }
+        }
+    } else if was_open {
+        // Start with its submenus closed next time:
+        ui.memory().id_data_temp.remove(&area.layer().id);
+    }
+
+    ui.memory().id_data_temp.insert(parent_id, state);
+    response
+}
+
+/// Like a button in a menu, but with an arrow pointing to the submenu.
+fn submenu_button(ui: &mut Ui, text: &str, access_key: Option<usize>, open: bool) -> Response {
+    let text_style = TextStyle::Button;
+    let button_padding = ui.spacing().button_padding;
+    let galley = ui.fonts().layout_no_wrap(text_style, text.to_owned());
+    let arrow = ui.fonts().layout_no_wrap(text_style, "⏵".to_owned());
+    // Line up with the text of the items, see [`MenuItem`]:
+    let indent = if menu_columns(ui).icons {
+        icon_column_width(ui)
+    } else {
+        0.0
+    };
+    let mut desired_size = vec2(
+        indent + galley.size.x + ui.spacing().item_spacing.x + arrow.size.x,
+        galley.size.y.max(arrow.size.y),
+    ) + 2.0 * button_padding;
+    desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+
+    let (rect, response) = ui.allocate_at_least(desired_size, Sense::click());
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, text));
+
+    if ui.clip_rect().intersects(rect) {
+        let visuals = if open {
+            ui.visuals().widgets.open
+        } else {
+            ui.style().interact(&response)
+        };
+        ui.painter().rect(
+            rect.expand(visuals.expansion),
+            visuals.corner_radius,
+            visuals.bg_fill,
+            visuals.bg_stroke,
+        );
+        let text_color = visuals.text_color();
+        let inner = rect.shrink2(button_padding);
+        let text_pos = pos2(
+            inner.left() + indent,
+            inner.center().y - galley.size.y / 2.0,
+        );
+        let arrow_pos = pos2(
+            inner.right() - arrow.size.x,
+            inner.center().y - arrow.size.y / 2.0,
+        );
+        ui.painter().galley(text_pos, galley, text_color);
+        ui.painter().galley(arrow_pos, arrow, text_color);
+
+        if let (Some(index), true) = (access_key, show_access_keys(ui)) {
+            paint_access_key(ui, text_pos, text, index, text_color);
+        }
+    }
+
+    response
+}
+
+/// The columns of the menu `ui` is in, as of last frame.
+fn menu_columns(ui: &Ui) -> MenuColumns {
+    ui.memory()
+        .id_data_temp
+        .get::<MenuColumns>(&ui.layer_id().id.with("columns"))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// The width of the column for the icons, checkmarks and radio buttons of [`MenuItem`]s.
+fn icon_column_width(ui: &Ui) -> f32 {
+    ui.fonts().row_height(TextStyle::Button) + ui.spacing().icon_spacing
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MenuItemMark {
+    None,
+    Check(bool),
+    Radio(bool),
+}
+
+/// An item in a [`menu`] or [`submenu`] that runs a command when clicked.
+///
+/// It can have an icon, a checkmark or a radio button before the text, and the keyboard shortcut
+/// for the command right-aligned after it. The texts and shortcuts of the items in a menu line up in columns.
+///
+/// Put a `&` before the access key in the text, like for [`menu`].
+/// When the menu is used with the keyboard, pressing the access key clicks the item.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut show_grid = true;
+/// use egui::menu::MenuItem;
+/// if ui.add(MenuItem::new("&Save").icon("💾").shortcut("Ctrl+S")).clicked() {
+///     // …
+/// }
+/// if ui.add(MenuItem::new("Show &grid").checked(show_grid)).clicked() {
+///     show_grid = !show_grid;
+/// }
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct MenuItem {
+    text: String,
+    icon: Option<String>,
+    mark: MenuItemMark,
+    shortcut: String,
+    enabled: bool,
+}
+
+impl MenuItem {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(text: impl ToString) -> Self {
+        Self {
+            text: text.to_string(),
+            icon: None,
+            mark: MenuItemMark::None,
+            shortcut: Default::default(),
+            enabled: true,
+        }
+    }
+
+    /// Show an icon (e.g. an emoji) before the text.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn icon(mut self, icon: impl ToString) -> Self {
+        self.icon = Some(icon.to_string());
+        self
+    }
+
+    /// Show a checkmark before the text if `checked`, for an option that is turned on or off by clicking.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.mark = MenuItemMark::Check(checked);
+        self
+    }
+
+    /// Show a radio button before the text, for one of several options of which one is `selected`.
+    pub fn radio(mut self, selected: bool) -> Self {
+        self.mark = MenuItemMark::Radio(selected);
+        self
+    }
+
+    /// Show the keyboard shortcut for the command after the text, e.g. `"Ctrl+S"`.
+    ///
+    /// This is only a hint: checking for the shortcut is up to you.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn shortcut(mut self, shortcut: impl ToString) -> Self {
+        self.shortcut = shortcut.to_string();
+        self
+    }
+
+    /// If `false`, the item is grayed out and can't be clicked.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl Widget for MenuItem {
+    fn ui(self, ui: &mut Ui) -> Response {
+        if self.enabled || !ui.enabled() {
+            self.enabled_ui(ui)
+        } else {
+            ui.scope(|ui| {
+                ui.set_enabled(false);
+                self.enabled_ui(ui)
+            })
+            .inner
+        }
+    }
+}
+
+impl MenuItem {
+    fn enabled_ui(self, ui: &mut Ui) -> Response {
+        let MenuItem {
+            text,
+            icon,
+            mark,
+            shortcut,
+            enabled: _,
+        } = self;
+
+        let text_style = TextStyle::Button;
+        let (text, access_key) = parse_access_key(&text);
+        let galley = ui.fonts().layout_no_wrap(text_style, text.clone());
+        let shortcut = ui.fonts().layout_no_wrap(text_style, shortcut);
+        let has_icon = icon.is_some() || mark != MenuItemMark::None;
+
+        // Line up with the other items of the menu:
+        let columns_id = ui.layer_id().id.with("columns");
+        let columns = menu_columns(ui);
+        {
+            let mut memory = ui.memory();
+            let next = memory
+                .id_data_temp
+                .get_mut_or_default::<MenuColumns>(columns_id.with("next"));
+            next.icons |= has_icon;
+            next.text_width = next.text_width.max(galley.size.x);
+            next.shortcut_width = next.shortcut_width.max(shortcut.size.x);
+        }
+        let icon_width = if has_icon || columns.icons {
+            icon_column_width(ui)
+        } else {
+            0.0
+        };
+        let text_width = galley.size.x.max(columns.text_width);
+        let shortcut_width = shortcut.size.x.max(columns.shortcut_width);
+        let shortcut_gap = if shortcut_width > 0.0 {
+            4.0 * ui.spacing().item_spacing.x
+        } else {
+            0.0
+        };
+
+        let button_padding = ui.spacing().button_padding;
+        let mut desired_size = vec2(
+            icon_width + text_width + shortcut_gap + shortcut_width,
+            galley.size.y,
+        ) + 2.0 * button_padding;
+        desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+        let (rect, mut response) = ui.allocate_at_least(desired_size, Sense::click());
+        response.widget_info(|| match mark {
+            MenuItemMark::None => WidgetInfo::labeled(WidgetType::Button, &text),
+            MenuItemMark::Check(checked) => {
+                WidgetInfo::selected(WidgetType::Checkbox, checked, &text)
+            }
+            MenuItemMark::Radio(selected) => {
+                WidgetInfo::selected(WidgetType::RadioButton, selected, &text)
+            }
+        });
+
+        // The access key clicks the item when the menu is used with the keyboard:
+        if let Some(key) = access_key
+            .and_then(|index| text[index..].chars().next())
+            .and_then(key_from_char)
+        {
+            let mut memory = ui.memory();
+            let focus = memory.focus();
+            let focus_in_menu = focus.map_or(false, |focus| {
+                memory
+                    .id_data_temp
+                    .get::<MenuItems>(&ui.layer_id().id.with("items"))
+                    .map_or(false, |items| items.0.contains(&focus))
+            });
+            if focus_in_menu && ui.input().key_pressed(key) {
+                response.clicked[PointerButton::Primary as usize] = true;
+                memory.data_temp.insert(MenuItemActivated);
+            }
+        }
+
+        if ui.clip_rect().intersects(rect) {
+            let visuals = ui.style().interact(&response);
+            ui.painter().rect(
+                rect.expand(visuals.expansion),
+                visuals.corner_radius,
+                visuals.bg_fill,
+                visuals.bg_stroke,
+            );
+            let text_color = visuals.text_color();
+            let inner = rect.shrink2(button_padding);
+            let icon_rect = Rect::from_min_size(
+                pos2(inner.left(), inner.center().y - galley.size.y / 2.0),
+                Vec2::splat(galley.size.y),
+            );
+            match mark {
+                MenuItemMark::Check(true) => {
+                    let check = icon_rect.shrink(icon_rect.width() / 4.0);
+                    ui.painter().add(Shape::line(
+                        vec![
+                            pos2(check.left(), check.center().y),
+                            pos2(check.center().x, check.bottom()),
+                            pos2(check.right(), check.top()),
+                        ],
+                        visuals.fg_stroke,
+                    ));
+                }
+                MenuItemMark::Radio(true) => {
+                    ui.painter().circle_filled(
+                        icon_rect.center(),
+                        icon_rect.width() / 6.0,
+                        visuals.fg_stroke.color,
+                    );
+                }
+                _ => {
+                    if let Some(icon) = icon {
+                        let icon = ui.fonts().layout_no_wrap(text_style, icon);
+                        let icon_pos = icon_rect.center() - icon.size / 2.0;
+                        ui.painter().galley(icon_pos, icon, text_color);
+                    }
+                }
+            }
+
+            let text_pos = pos2(
+                inner.left() + icon_width,
+                inner.center().y - galley.size.y / 2.0,
+            );
+            ui.painter().galley(text_pos, galley, text_color);
+            if let (Some(index), true) = (access_key, show_access_keys(ui)) {
+                paint_access_key(ui, text_pos, &text, index, text_color);
+            }
+            let shortcut_pos = pos2(
+                inner.right() - shortcut.size.x,
+                inner.center().y - shortcut.size.y / 2.0,
+            );
+            ui.painter()
+                .galley(shortcut_pos, shortcut, ui.visuals().weak_text_color());
+        }
+
+        response
+    }
+}
+
+/// Should the access keys in menus be underlined?
+fn show_access_keys(ui: &Ui) -> bool {
+    ui.input().modifiers.alt || ui.memory().data_temp.get::<ShowAccessKeys>().is_some()
+}
+
+/// Splits a title like `"&File"` into the text to show (`"File"`)
+/// and the byte index of its access key in it (the `F`), if any. `"&&"` is shown as `"&"`.
+fn parse_access_key(title: &str) -> (String, Option<usize>) {
+    let mut text = String::with_capacity(title.len());
+    let mut access_key = None;
+    let mut chars = title.chars();
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('&') | None => text.push('&'),
+            Some(c) => {
+                if access_key.is_none() {
+                    access_key = Some(text.len());
+                }
+                text.push(c);
+            }
+        }
+    }
+    (text, access_key)
+}
+
+/// The key to press for an access key, if it is a letter or a digit.
+fn key_from_char(c: char) -> Option<Key> {
+    use Key::{
+        Num0, Num1, Num2, Num3, Num4, Num5, Num6, Num7, Num8, Num9, A, B, C, D, E, F, G, H, I, J,
+        K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    };
+    const LETTERS: [Key; 26] = [
+        A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    ];
+    const DIGITS: [Key; 10] = [Num0, Num1, Num2, Num3, Num4, Num5, Num6, Num7, Num8, Num9];
+    let c = c.to_ascii_uppercase();
+    if c.is_ascii_uppercase() {
+        Some(LETTERS[(c as u8 - b'A') as usize])
+    } else if c.is_ascii_digit() {
+        Some(DIGITS[(c as u8 - b'0') as usize])
+    } else {
+        None
+    }
+}
+
+/// Underlines the access key at byte index `index` of `text`, which is shown at `text_pos`.
+fn paint_access_key(ui: &Ui, text_pos: Pos2, text: &str, index: usize, color: Color32) {
+    let text_style = TextStyle::Button;
+    let key_len = text[index..].chars().next().map_or(0, char::len_utf8);
+    let fonts = ui.fonts();
+    let left = text_pos.x
+        + fonts
+            .layout_no_wrap(text_style, text[..index].to_owned())
+            .size
+            .x;
+    let width = fonts
+        .layout_no_wrap(text_style, text[index..index + key_len].to_owned())
+        .size
+        .x;
+    let y = text_pos.y + fonts.row_height(text_style) - 1.0;
+    ui.painter().line_segment(
+        [pos2(left, y), pos2(left + width, y)],
+        Stroke::new(1.0, color),
+    );
+}
+
+/// Which context menu is open, and where. Only one can be open at a time.
+#[derive(Clone, Copy, Debug)]
+struct ContextMenuState {
+    /// The widget the context menu belongs to.
+    response_id: Id,
+    pos: Pos2,
+    /// Opened by a long-press that hasn't been released yet, so the release is not a click.
+    long_press: bool,
+}
+
+/// How long to press without moving to open a context menu, e.g. on a touch screen, in seconds.
+const LONG_PRESS_DURATION: f64 = 0.5;
+
+/// See [`Response::context_menu`].
+pub(crate) fn context_menu<'c>(response: &Response, add_contents: Box<dyn FnOnce(&mut Ui) + 'c>) {
+    let ctx = &response.ctx;
+    let input = ctx.input();
+    let pointer = &input.pointer;
+
+    let held_still = response.is_pointer_button_down_on() && pointer.could_be_click();
+    let long_pressed = held_still
+        && pointer
+            .press_start_time()
+            .map_or(false, |start| input.time - start >= LONG_PRESS_DURATION);
+    let mut state = ctx.memory().data_temp.get::<ContextMenuState>().copied();
+    let already_open = state.map_or(false, |state| state.response_id == response.id);
+    if held_still && !already_open && !long_pressed {
+        ctx.request_repaint(); // to open it when the time is up
+    }
+
+    let mut just_opened = false;
+    if (response.secondary_clicked() || long_pressed) && !already_open {
+        if let Some(pos) = pointer.interact_pos() {
+            state = Some(ContextMenuState {
+                response_id: response.id,
+                pos,
+                long_press: long_pressed,
+            });
+            just_opened = true;
+            let mut memory = ctx.memory();
+            if long_pressed {
+                // Releasing shouldn't click the widget:
+                memory.interaction.click_id = None;
+                memory.interaction.drag_id = None;
+            }
+            // Start with all submenus closed:
+            memory.id_data_temp.remove_by_type::<SubMenuState>();
+        }
+    }
+    let mut state = match state {
+        Some(state) if state.response_id == response.id => state,
+        _ => return,
+    };
+
+    let area = Area::new(response.id.with("context_menu"))
+        .order(Order::Foreground)
+        .fixed_pos(state.pos);
+    ctx.memory().data_temp.insert(OpenMenuItems::default());
+    area.show(ctx, |ui| {
+        menu_ui(ui, add_contents);
+    });
+
+    let mut memory = ctx.memory();
+    let open_items = memory
+        .data_temp
+        .get::<OpenMenuItems>()
+        .map(|open_items| open_items.0.clone())
+        .unwrap_or_default();
+    memory.data_temp.remove::<OpenMenuItems>();
+    let keep_open = memory.data_temp.get::<KeepMenuOpen>().is_some();
+    memory.data_temp.remove::<KeepMenuOpen>();
+    let activated_with_access_key = memory.data_temp.get::<MenuItemActivated>().is_some();
+    memory.data_temp.remove::<MenuItemActivated>();
+    let focus = memory.focus();
+    let activated = activated_with_access_key
+        || focus.map_or(false, |focus| open_items.contains(&focus))
+            && (input.key_pressed(Key::Enter) || input.key_pressed(Key::Space));
+
+    // Clicking an item or anywhere else closes the menu, except for the release of the long-press:
+    let clicked = pointer.any_click() && !state.long_press && !just_opened;
+    if pointer.any_released() {
+        state.long_press = false;
+    }
+    if input.key_pressed(Key::Escape) || ((clicked || activated) && !keep_open) {
+        memory.data_temp.remove::<ContextMenuState>();
+        if let (Some(focus), true) = (focus, activated) {
+            memory.surrender_focus(focus);
+        }
+    } else {
+        memory.data_temp.insert(state);
+    }
+}
+
+/// Is the pointer, which moved by `delta` this frame, heading towards `rect`?
+fn is_moving_toward(pointer: Pos2, delta: Vec2, rect: Rect) -> bool {
+    if delta == Vec2::ZERO {
+        return false;
+    }
+    let previous = pointer - delta;
+    // The triangle from where the pointer was to the near side of the rectangle:
+    let (a, b) = if previous.x <= rect.left() {
+        (rect.left_top(), rect.left_bottom())
+    } else {
+        (rect.right_top(), rect.right_bottom())
+    };
+    let side = |p: Pos2, q: Pos2, r: Pos2| (q - p).x * (r - p).y - (q - p).y * (r - p).x;
+    let (d1, d2, d3) = (
+        side(previous, a, pointer),
+        side(a, b, pointer),
+        side(b, previous, pointer),
+    );
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
+#[test]
+fn test_submenus() {
+    use crate::test_util::{click, run_ui_frame};
+    let rect = Rect::from_min_max(pos2(100.0, 0.0), pos2(200.0, 100.0));
+    assert!(is_moving_toward(pos2(52.0, 51.0), vec2(2.0, 1.0), rect));
+    assert!(!is_moving_toward(pos2(50.0, 52.0), vec2(0.0, 2.0), rect));
+    assert!(!is_moving_toward(pos2(50.0, 50.0), Vec2::ZERO, rect));
+
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>| {
+        let rects = std::cell::RefCell::new(vec![]);
+        run_ui_frame(&mut ctx, events, |ui| {
+            bar(ui, |ui| {
+                rects.borrow_mut().push(ui.button("Edit").rect);
+                menu(ui, "File", |ui| {
+                    rects.borrow_mut().push(ui.button("Open").rect);
+                    let recent = submenu(ui, "Recent", |ui| {
+                        rects.borrow_mut().push(ui.button("notes.txt").rect);
+                    });
+                    rects.borrow_mut().insert(2, recent.rect);
+                });
+            });
+        });
+        rects.into_inner()
+    };
+
+    // Open the file menu, and hover the submenu button:
+    let rects = run_frame(vec![]);
+    let file = rects[0].right_center() + vec2(20.0, 0.0);
+    run_frame(click(file));
+    let rects = run_frame(vec![]);
+    assert_eq!(rects.len(), 3);
+    run_frame(vec![Event::PointerMoved(rects[2].center())]);
+    assert_eq!(run_frame(vec![]).len(), 4, "the submenu is open");
+
+    // Clicking the submenu button keeps everything open:
+    let rects = run_frame(click(rects[2].center()));
+    run_frame(vec![]);
+    assert_eq!(run_frame(vec![]).len(), 4);
+
+    // Resting on the other item closes the submenu:
+    run_frame(vec![Event::PointerMoved(rects[1].center())]);
+    run_frame(vec![Event::PointerMoved(rects[1].center())]);
+    assert_eq!(run_frame(vec![]).len(), 3);
+}
+
+#[test]
+fn test_menu_keyboard() {
+    use crate::test_util::{key_with_modifiers, run_frame_with_input};
+    assert_eq!(parse_access_key("&File"), ("File".to_owned(), Some(0)));
+    assert_eq!(
+        parse_access_key("Save &as"),
+        ("Save as".to_owned(), Some(5))
+    );
+    assert_eq!(
+        parse_access_key("Copy && paste"),
+        ("Copy & paste".to_owned(), None)
+    );
+    assert_eq!(key_from_char('e'), Some(Key::E));
+
+    let mut ctx = CtxRef::default();
+    let undone = std::cell::Cell::new(false);
+    let mut run_frame = |alt: bool, keys: Vec<Key>| {
+        let modifiers = Modifiers {
+            alt,
+            ..Default::default()
+        };
+        let input = RawInput {
+            events: keys
+                .into_iter()
+                .map(|key| key_with_modifiers(key, modifiers))
+                .collect(),
+            modifiers,
+            ..Default::default()
+        };
+        let ids = std::cell::RefCell::new(std::collections::BTreeMap::new());
+        run_frame_with_input(&mut ctx, input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                bar(ui, |ui| {
+                    menu(ui, "&File", |ui| {
+                        ids.borrow_mut().insert("Open", ui.button("Open").id);
+                        let recent = submenu(ui, "&Recent", |ui| {
+                            ids.borrow_mut().insert("notes", ui.button("notes.txt").id);
+                        });
+                        ids.borrow_mut().insert("Recent", recent.id);
+                    });
+                    menu(ui, "&Edit", |ui| {
+                        let undo = ui.button("Undo");
+                        undone.set(undone.get() || undo.clicked());
+                        ids.borrow_mut().insert("Undo", undo.id);
+                    });
+                });
+            });
+        });
+        let focus = ctx.memory().focus();
+        ids.into_inner()
+            .into_iter()
+            .find(|&(_, id)| Some(id) == focus)
+            .map(|(name, _)| name)
+            .or_else(|| focus.map(|_| "menu bar"))
+    };
+
+    // Tapping alt moves the focus to the menu bar:
+    run_frame(true, vec![]);
+    assert_eq!(run_frame(false, vec![]), Some("menu bar"));
+    assert_eq!(run_frame(false, vec![Key::ArrowDown]), Some("Open"));
+    assert_eq!(run_frame(false, vec![Key::ArrowDown]), Some("Recent"));
+    assert_eq!(run_frame(false, vec![Key::ArrowRight]), Some("notes"));
+    assert_eq!(run_frame(false, vec![Key::ArrowLeft]), Some("Recent"));
+    assert_eq!(run_frame(false, vec![Key::ArrowUp]), Some("Open"));
+
+    // Right moves to the next menu:
+    run_frame(false, vec![Key::ArrowRight]);
+    assert_eq!(run_frame(false, vec![]), Some("Undo"));
+    run_frame(false, vec![Key::Enter]);
+    assert!(undone.get());
+    assert_eq!(run_frame(false, vec![]), None, "the menu is closed");
+
+    // Alt and an access key opens a menu, escape closes it:
+    run_frame(true, vec![Key::F]);
+    assert_eq!(run_frame(false, vec![]), Some("Open"));
+    run_frame(false, vec![Key::R]);
+    assert_eq!(run_frame(false, vec![]), Some("notes"));
+    assert_eq!(run_frame(false, vec![Key::Escape]), Some("menu bar"));
+    assert_eq!(run_frame(false, vec![Key::Escape]), None);
+}
+
+#[test]
+fn test_menu_items() {
+    use crate::test_util::{key_with_modifiers, run_frame_with_input};
+    let mut ctx = CtxRef::default();
+    let saved = std::cell::Cell::new(false);
+    let mut run_frame = |events: Vec<Event>, modifiers: Modifiers| {
+        let input = RawInput {
+            events,
+            modifiers,
+            ..Default::default()
+        };
+        let columns = std::cell::Cell::new(None);
+        run_frame_with_input(&mut ctx, input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                bar(ui, |ui| {
+                    menu(ui, "&File", |ui| {
+                        if ui.add(MenuItem::new("&Save").shortcut("Ctrl+S")).clicked() {
+                            saved.set(true);
+                        }
+                        let _ = ui.add(MenuItem::new("Show grid").checked(true));
+                        columns.set(Some(menu_columns(ui)));
+                    });
+                });
+            });
+        });
+        columns.get()
+    };
+    let key = |key, modifiers| vec![key_with_modifiers(key, modifiers)];
+    let alt = Modifiers {
+        alt: true,
+        ..Default::default()
+    };
+
+    // Open the menu with the keyboard:
+    run_frame(key(Key::F, alt), alt);
+    run_frame(vec![], Default::default());
+    let columns = run_frame(vec![], Default::default()).unwrap();
+    assert!(columns.icons, "the checkmark column is shared");
+    assert!(columns.shortcut_width > 0.0);
+
+    // The access key clicks the item and closes the menu:
+    run_frame(key(Key::S, Default::default()), Default::default());
+    assert!(saved.get());
+    assert!(run_frame(vec![], Default::default()).is_none());
+}
+
+#[test]
+fn test_context_menu() {
+    use crate::test_util::{click, key, pointer_button, run_frame_with_input};
+    let mut ctx = CtxRef::default();
+    let deleted = std::cell::Cell::new(false);
+    let mut run_frame = |events: Vec<Event>, time: f64| {
+        let input = RawInput {
+            events,
+            time: Some(time),
+            ..Default::default()
+        };
+        let item_rect = std::cell::Cell::new(None);
+        let (response, _) = run_frame_with_input(&mut ctx, input, |ctx| {
+            CentralPanel::default()
+                .show(ctx, |ui| {
+                    ui.button("Right-click me").context_menu(|ui| {
+                        let response = ui.button("Delete");
+                        if response.clicked() {
+                            deleted.set(true);
+                        }
+                        item_rect.set(Some(response.rect));
+                    })
+                })
+                .inner
+        });
+        (response, item_rect.get())
+    };
+    let right_click = |pos| {
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Secondary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        vec![Event::PointerMoved(pos), button(true), button(false)]
+    };
+
+    let (response, item) = run_frame(vec![], 0.0);
+    assert!(item.is_none());
+    let pos = response.rect.center();
+
+    // Right-click opens it, and escape closes it:
+    run_frame(right_click(pos), 1.0);
+    let item = run_frame(vec![], 2.0).1.expect("open");
+    run_frame(vec![key(Key::Escape)], 3.0);
+    assert!(run_frame(vec![], 4.0).1.is_none());
+
+    // A long-press opens it without clicking the button:
+    run_frame(
+        vec![Event::PointerMoved(pos), pointer_button(pos, true)],
+        5.0,
+    );
+    assert!(run_frame(vec![], 5.1).1.is_none(), "not long enough yet");
+    run_frame(vec![], 5.6);
+    let (response, item_after_release) = run_frame(vec![pointer_button(pos, false)], 5.7);
+    assert!(!response.clicked());
+    assert!(item_after_release.is_some(), "the release doesn't close it");
+
+    // Clicking an item closes it:
+    assert_eq!(item_after_release, Some(item));
+    run_frame(click(item.center()), 6.0);
+    assert!(deleted.get());
+    assert!(run_frame(vec![], 7.0).1.is_none());
+}
@@ -1,7 +1,7 @@
 use crate::{
     emath::{Align2, Pos2, Rect, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
-    Color32, CtxRef,
+    Color32, ColorImage, CtxRef,
 };
 use epaint::{
     mutex::Mutex,
@@ -98,6 +98,12 @@ impl Painter {
         &self.ctx
     }
 
+    /// The pixels on screen within `rect`, as painted last frame, once the integration has read them back.
+    /// See [`crate::Context::read_screen_pixels`].
+    pub fn read_pixels(&self, rect: Rect) -> Option<ColorImage> {
+        self.ctx.read_screen_pixels(rect.intersect(self.clip_rect))
+    }
+
     /// Available fonts.
     #[inline(always)]
     pub fn fonts(&self) -> &Fonts {
@@ -65,6 +65,9 @@ pub struct Response {
     /// e.g. the slider was dragged, text was entered in a `TextEdit` etc.
     /// Always `false` for something like a `Button`.
     pub(crate) changed: bool,
+
+    /// Does the data have a validation error, e.g. from [`crate::TextEdit::error`]?
+    pub(crate) has_error: bool,
 }
 
 impl std::fmt::Debug for Response {
@@ -84,6 +87,7 @@ impl std::fmt::Debug for Response {
             is_pointer_button_down_on,
             interact_pointer_pos,
             changed,
+            has_error,
         } = self;
         f.debug_struct("Response")
             .field("layer_id", layer_id)
@@ -99,6 +103,7 @@ impl std::fmt::Debug for Response {
             .field("is_pointer_button_down_on", is_pointer_button_down_on)
             .field("interact_pointer_pos", interact_pointer_pos)
             .field("changed", changed)
+            .field("has_error", has_error)
             .finish()
     }
 }
@@ -296,6 +301,20 @@ impl Response {
         self.changed = true;
     }
 
+    /// Does the data shown by this widget have a validation error?
+    ///
+    /// See [`crate::TextEdit::error`].
+    #[inline(always)]
+    pub fn has_error(&self) -> bool {
+        self.has_error
+    }
+
+    /// Report that the data shown by this widget is not valid.
+    #[inline(always)]
+    pub fn mark_error(&mut self) {
+        self.has_error = true;
+    }
+
     /// Show this UI if the widget was hovered (i.e. a tooltip).
     ///
     /// The text will not be visible if the widget is not enabled.
@@ -312,6 +331,27 @@ impl Response {
         self
     }
 
+    /// Show a context menu when the widget is right-clicked, or long-pressed on a touch screen.
+    ///
+    /// The menu opens at the pointer, and closes when an item in it is clicked,
+    /// when clicking elsewhere, or with escape.
+    /// Use [`crate::menu::MenuItem`] and [`crate::menu::submenu`] in it like in any other menu.
+    ///
+    /// The widget must sense clicks, like a button does.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.button("Right-click me").context_menu(|ui| {
+    ///     if ui.button("Delete").clicked() {
+    ///         // …
+    ///     }
+    /// });
+    /// ```
+    pub fn context_menu(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        crate::menu::context_menu(&self, Box::new(add_contents));
+        self
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         if !self.enabled && self.ctx.rect_contains_pointer(self.layer_id, self.rect) {
@@ -389,6 +429,18 @@ impl Response {
         self
     }
 
+    /// While this widget is being dragged, carry this text along,
+    /// so that it can be dropped into a [`crate::TextEdit`].
+    ///
+    /// The widget must sense drags.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn on_drag_text(self, text: impl ToString) -> Self {
+        if self.dragged() {
+            self.ctx.memory().set_dragged_text(text.to_string());
+        }
+        self
+    }
+
     /// Check for more interactions (e.g. sense clicks on a `Response` returned from a label).
     ///
     /// Note that this call will not add any hover-effects to the widget, so when possible
@@ -450,6 +502,12 @@ impl Response {
         if let Some(event) = event {
             self.ctx.output().events.push(event);
         }
+        if self.clicked() {
+            self.ctx.log_ui_event(|| crate::UiEvent::Clicked {
+                id: self.id,
+                info: make_info(),
+            });
+        }
     }
 }
 
@@ -486,6 +544,7 @@ impl Response {
                 || other.is_pointer_button_down_on,
             interact_pointer_pos: self.interact_pointer_pos.or(other.interact_pointer_pos),
             changed: self.changed || other.changed,
+            has_error: self.has_error || other.has_error,
         }
     }
 }
@@ -45,21 +45,178 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// How windows and popups animate as they open and close. Default: [`WindowAnimation::None`].
+    ///
+    /// To fade out a closing window, egui keeps a copy of what every window and popup painted
+    /// on the last frame, which costs some time and memory each frame.
+    /// With [`WindowAnimation::None`], a [`crate::Window`] closed with its close button
+    /// fades out its frame, as it always did.
+    pub window_animation: WindowAnimation,
+
+    /// Avoid animations that move things around, for users who are sensitive to motion.
+    ///
+    /// Windows and popups will still fade in and out, but not slide.
+    /// Panels shown with e.g. [`crate::SidePanel::show_animated`] appear at once.
+    pub reduce_motion: bool,
+
+    /// Slide panels in and out as they are shown and hidden (see [`crate::SidePanel::show_animated`]),
+    /// and expand and collapse [`crate::CollapsingHeader`]s smoothly,
+    /// over [`Self::animation_time`] seconds.
+    ///
+    /// With this off they change at once.
+    pub animate_containers: bool,
+
+    /// Fade widgets between their inactive, hovered and active looks,
+    /// instead of switching instantly.
+    ///
+    /// The colors and the [`WidgetVisuals::expansion`] change over [`Self::widget_animation_time`] seconds.
+    /// With [`Self::reduce_motion`] only the colors fade.
+    pub animate_widgets: bool,
+
+    /// How many seconds widgets take to fade to a new look, if [`Self::animate_widgets`] is on.
+    pub widget_animation_time: f32,
+
     /// Options to help debug why egui behaves strangely.
     pub debug: DebugOptions,
 }
 
+/// How windows and popups animate as they open and close, see [`Style::window_animation`].
+///
+/// The animation takes [`Style::animation_time`] seconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum WindowAnimation {
+    /// Appear and disappear instantly.
+    None,
+    /// Fade in and out.
+    Fade,
+    /// Fade in and out while sliding a few points up and down.
+    FadeAndSlide,
+}
+
+/// A complete, built-in [`Style`] to pick from, see [`crate::Context::set_theme`].
+///
+/// Use [`Theme::switcher_ui`] to let your users pick a theme at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum Theme {
+    /// The default egui look: dark colors and normal spacing.
+    Dark,
+    /// Light colors and normal spacing.
+    Light,
+    /// Dark colors and tight spacing, to fit a lot of widgets on screen.
+    Compact,
+    /// Dark colors, big widgets and generous spacing, for touch screens.
+    Touch,
+}
+
+impl Theme {
+    /// All the built-in themes, in the order they are shown by [`Self::switcher_ui`].
+    pub const ALL: [Theme; 4] = [Theme::Dark, Theme::Light, Theme::Compact, Theme::Touch];
+
+    /// A short, human readable name of the theme.
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Dark => "🌙 Dark",
+            Theme::Light => "☀ Light",
+            Theme::Compact => "▪ Compact",
+            Theme::Touch => "👆 Touch",
+        }
+    }
+
+    /// The complete [`Style`] of this theme.
+    pub fn style(self) -> Style {
+        match self {
+            Theme::Dark => Style::default(),
+            Theme::Light => Style {
+                visuals: Visuals::light(),
+                ..Default::default()
+            },
+            Theme::Compact => Style {
+                spacing: Spacing::compact(),
+                ..Default::default()
+            },
+            Theme::Touch => Style {
+                spacing: Spacing::touch(),
+                interaction: Interaction {
+                    resize_grab_radius_side: 12.0,
+                    resize_grab_radius_corner: 20.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Show a combo-box for picking one of the built-in themes.
+    ///
+    /// The chosen theme is applied to the whole [`crate::Context`] with [`crate::Context::set_theme`],
+    /// and so it is remembered between sessions if the `persistence` feature is enabled.
+    pub fn switcher_ui(ui: &mut crate::Ui) -> Response {
+        let current = ui.ctx().theme();
+        let mut selected = current;
+        let mut response = crate::ComboBox::from_label("Theme")
+            .selected_text(selected.map_or("Custom", Theme::name))
+            .show_ui(ui, |ui| {
+                for theme in Self::ALL.iter().copied() {
+                    ui.selectable_value(&mut selected, Some(theme), theme.name());
+                }
+            });
+        if selected != current {
+            if let Some(theme) = selected {
+                ui.ctx().set_theme(theme);
+                response.mark_changed();
+            }
+        }
+        response
+    }
+}
+
 impl Style {
+    /// The [`Self::window_animation`], taking [`Self::reduce_motion`] into account.
+    pub fn effective_window_animation(&self) -> WindowAnimation {
+        match self.window_animation {
+            WindowAnimation::FadeAndSlide if self.reduce_motion => WindowAnimation::Fade,
+            animation => animation,
+        }
+    }
+
     // TODO: rename style.interact() to maybe... `style.interactive` ?
     /// Use this style for interactive things.
     /// Note that you must already have a response,
     /// i.e. you must allocate space and interact BEFORE painting the widget!
-    pub fn interact(&self, response: &Response) -> &WidgetVisuals {
-        self.visuals.widgets.style(response)
+    ///
+    /// If [`Self::animate_widgets`] is on, this fades between the [`Widgets`] styles.
+    pub fn interact(&self, response: &Response) -> WidgetVisuals {
+        let widgets = &self.visuals.widgets;
+        if !self.animate_widgets || !response.sense.interactive() {
+            return *widgets.style(response);
+        }
+
+        let active = response.is_pointer_button_down_on() || response.has_focus();
+        let hovered = active || response.hovered();
+        let animate = |name: &str, value: bool| {
+            response.ctx.animate_bool_with_time(
+                response.id.with(name),
+                value,
+                self.widget_animation_time,
+            )
+        };
+        let hover_t = animate("__hover_animation", hovered);
+        let active_t = animate("__active_animation", active);
+
+        let mut visuals = widgets
+            .inactive
+            .lerp(&widgets.hovered, hover_t)
+            .lerp(&widgets.active, active_t);
+        if self.reduce_motion {
+            visuals.expansion = widgets.style(response).expansion;
+        }
+        visuals
     }
 
     pub fn interact_selectable(&self, response: &Response, selected: bool) -> WidgetVisuals {
-        let mut visuals = *self.visuals.widgets.style(response);
+        let mut visuals = self.interact(response);
         if selected {
             visuals.bg_fill = self.visuals.selection.bg_fill;
             // visuals.bg_stroke = self.visuals.selection.stroke;
@@ -157,6 +314,10 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// When moving or resizing a window, snap its edges to the edges of the screen
+    /// and of other windows that are closer than this. `0.0` turns snapping off.
+    pub window_snap_distance: f32,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -199,6 +360,9 @@ pub struct Visuals {
     /// The color used for `Hyperlink`,
     pub hyperlink_color: Color32,
 
+    /// The color used for validation errors, e.g. the frame and message of [`crate::TextEdit::error`].
+    pub error_fg_color: Color32,
+
     /// Something just barely different from the background color.
     /// Used for [`crate::Grid::striped`].
     pub faint_bg_color: Color32,
@@ -264,8 +428,24 @@ impl Visuals {
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "persistence", serde(default))]
 pub struct Selection {
+    /// Background of selected text in a widget with keyboard focus, and of selected labels.
     pub bg_fill: Color32,
     pub stroke: Stroke,
+
+    /// Background of selected text in a [`crate::TextEdit`] or selectable [`crate::Label`]
+    /// that doesn't have keyboard focus.
+    pub inactive_bg_fill: Color32,
+
+    /// Color of selected text in a widget with keyboard focus.
+    /// `None` keeps the normal text color.
+    pub text_color: Option<Color32>,
+
+    /// Color of selected text in a widget without keyboard focus.
+    /// `None` keeps the normal text color.
+    pub inactive_text_color: Option<Color32>,
+
+    /// Rounding of the background behind selected text.
+    pub corner_radius: f32,
 }
 
 /// The visuals of widgets for different states of interaction.
@@ -324,10 +504,36 @@ pub struct WidgetVisuals {
     pub expansion: f32,
 }
 
+/// A per-widget tweak of [`WidgetVisuals`], e.g. from [`crate::Button::style`].
+pub(crate) type WidgetStyleFn<'a> = Box<dyn 'a + Fn(&mut WidgetVisuals)>;
+
 impl WidgetVisuals {
     pub fn text_color(&self) -> Color32 {
         self.fg_stroke.color
     }
+
+    /// Blend towards `other`, where `t = 0` gives `self` and `t = 1` gives `other`.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let lerp_stroke = |a: Stroke, b: Stroke| Stroke {
+            width: lerp(a.width..=b.width, t),
+            color: lerp_color(a.color, b.color, t),
+        };
+        Self {
+            bg_fill: lerp_color(self.bg_fill, other.bg_fill, t),
+            bg_stroke: lerp_stroke(self.bg_stroke, other.bg_stroke),
+            corner_radius: lerp(self.corner_radius..=other.corner_radius, t),
+            fg_stroke: lerp_stroke(self.fg_stroke, other.fg_stroke),
+            expansion: lerp(self.expansion..=other.expansion, t),
+        }
+    }
+}
+
+fn lerp_color(a: Color32, b: Color32, t: f32) -> Color32 {
+    if a == b {
+        a
+    } else {
+        (Rgba::from(a) * (1.0 - t) + Rgba::from(b) * t).into()
+    }
 }
 
 /// Options for help debug egui by adding extra visualization
@@ -355,6 +561,11 @@ impl Default for Style {
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            window_animation: WindowAnimation::None,
+            reduce_motion: false,
+            animate_containers: true,
+            animate_widgets: false,
+            widget_animation_time: 0.1,
             debug: Default::default(),
         }
     }
@@ -380,12 +591,53 @@ impl Default for Spacing {
     }
 }
 
+impl Spacing {
+    /// Tight spacing and small widgets, used by [`Theme::Compact`].
+    pub fn compact() -> Self {
+        Self {
+            item_spacing: vec2(4.0, 2.0),
+            window_padding: Vec2::splat(4.0),
+            button_padding: vec2(2.0, 0.0),
+            indent: 14.0,
+            interact_size: vec2(32.0, 14.0),
+            slider_width: 80.0,
+            text_edit_width: 200.0,
+            icon_width: 12.0,
+            icon_spacing: 0.0,
+            tooltip_width: 500.0,
+            combo_height: 160.0,
+            scroll_bar_width: 6.0,
+            indent_ends_with_horizontal_line: false,
+        }
+    }
+
+    /// Big widgets and generous spacing, used by [`Theme::Touch`].
+    pub fn touch() -> Self {
+        Self {
+            item_spacing: vec2(12.0, 8.0),
+            window_padding: Vec2::splat(12.0),
+            button_padding: vec2(12.0, 8.0),
+            indent: 32.0,
+            interact_size: vec2(56.0, 36.0),
+            slider_width: 200.0,
+            text_edit_width: 320.0,
+            icon_width: 20.0,
+            icon_spacing: 0.0,
+            tooltip_width: 600.0,
+            combo_height: 320.0,
+            scroll_bar_width: 14.0,
+            indent_ends_with_horizontal_line: false,
+        }
+    }
+}
+
 impl Default for Interaction {
     fn default() -> Self {
         Self {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            window_snap_distance: 0.0,
         }
     }
 }
@@ -399,6 +651,7 @@ impl Visuals {
             widgets: Widgets::default(),
             selection: Selection::default(),
             hyperlink_color: Color32::from_rgb(90, 170, 255),
+            error_fg_color: Color32::from_rgb(255, 90, 90),
             faint_bg_color: Color32::from_gray(24),
             extreme_bg_color: Color32::from_gray(10),
             code_bg_color: Color32::from_gray(64),
@@ -421,6 +674,7 @@ impl Visuals {
             widgets: Widgets::light(),
             selection: Selection::light(),
             hyperlink_color: Color32::from_rgb(0, 155, 255),
+            error_fg_color: Color32::from_rgb(210, 0, 0),
             faint_bg_color: Color32::from_gray(240),
             extreme_bg_color: Color32::from_gray(250),
             code_bg_color: Color32::from_gray(200),
@@ -442,12 +696,20 @@ impl Selection {
         Self {
             bg_fill: Color32::from_rgb(0, 92, 128),
             stroke: Stroke::new(1.0, Color32::from_rgb(192, 222, 255)),
+            inactive_bg_fill: Color32::from_rgb(52, 64, 72),
+            text_color: None,
+            inactive_text_color: None,
+            corner_radius: 0.0,
         }
     }
     fn light() -> Self {
         Self {
             bg_fill: Color32::from_rgb(144, 209, 255),
             stroke: Stroke::new(1.0, Color32::from_rgb(0, 83, 125)),
+            inactive_bg_fill: Color32::from_rgb(210, 222, 230),
+            text_color: None,
+            inactive_text_color: None,
+            corner_radius: 0.0,
         }
     }
 }
@@ -560,6 +822,11 @@ impl Style {
             interaction,
             visuals,
             animation_time,
+            window_animation,
+            reduce_motion,
+            animate_containers,
+            animate_widgets,
+            widget_animation_time,
             debug,
         } = self;
 
@@ -613,8 +880,38 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Window animation:");
+            ui.horizontal(|ui| {
+                ui.radio_value(window_animation, WindowAnimation::None, "None");
+                ui.radio_value(window_animation, WindowAnimation::Fade, "Fade");
+                ui.radio_value(
+                    window_animation,
+                    WindowAnimation::FadeAndSlide,
+                    "Fade and slide",
+                );
+            });
+            ui.end_row();
+
+            ui.checkbox(animate_containers, "Animate containers")
+                .on_hover_text(
+                    "Slide panels in and out, and expand and collapse collapsing headers",
+                );
+            ui.end_row();
+
+            ui.checkbox(animate_widgets, "Animate widgets")
+                .on_hover_text("Fade widgets in and out of their hovered and active looks");
+            ui.add(
+                Slider::new(widget_animation_time, 0.0..=1.0)
+                    .clamp_to_range(true)
+                    .suffix(" s"),
+            );
+            ui.end_row();
         });
 
+        ui.checkbox(reduce_motion, "Reduce motion")
+            .on_hover_text("Don't slide windows, popups and panels as they open and close, and don't grow animated widgets");
+
         ui.collapsing("📏 Spacing", |ui| spacing.ui(ui));
         ui.collapsing("☝ Interaction", |ui| interaction.ui(ui));
         ui.collapsing("🎨 Visuals", |ui| visuals.ui(ui));
@@ -703,6 +1000,7 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            window_snap_distance,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -712,6 +1010,10 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.add(Slider::new(window_snap_distance, 0.0..=20.0).text("window_snap_distance"))
+            .on_hover_text(
+                "Snap windows to the screen edges and to each other when closer than this",
+            );
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -756,10 +1058,23 @@ impl Widgets {
 
 impl Selection {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
-        let Self { bg_fill, stroke } = self;
+        let Self {
+            bg_fill,
+            stroke,
+            inactive_bg_fill,
+            text_color,
+            inactive_text_color,
+            corner_radius,
+        } = self;
         ui.label("Selectable labels");
         ui_color(ui, bg_fill, "bg_fill");
         stroke_ui(ui, stroke, "stroke");
+
+        ui.label("Selected text");
+        ui_color(ui, inactive_bg_fill, "inactive_bg_fill (unfocused)");
+        optional_color_ui(ui, text_color, "text_color");
+        optional_color_ui(ui, inactive_text_color, "inactive_text_color (unfocused)");
+        ui.add(Slider::new(corner_radius, 0.0..=10.0).text("corner_radius"));
     }
 }
 
@@ -823,6 +1138,7 @@ impl Visuals {
             widgets,
             selection,
             hyperlink_color,
+            error_fg_color,
             faint_bg_color,
             extreme_bg_color,
             code_bg_color,
@@ -874,6 +1190,7 @@ impl Visuals {
         });
 
         ui_color(ui, hyperlink_color, "hyperlink_color");
+        ui_color(ui, error_fg_color, "error_fg_color");
         ui.add(Slider::new(resize_corner_size, 0.0..=20.0).text("resize_corner_size"));
         ui.add(Slider::new(text_cursor_width, 0.0..=4.0).text("text_cursor_width"));
         ui.checkbox(text_cursor_preview, "Preview text cursor on hover");
@@ -941,3 +1258,17 @@ fn ui_color(ui: &mut Ui, srgba: &mut Color32, text: impl Into<Label>) -> Respons
     })
     .response
 }
+
+fn optional_color_ui(ui: &mut Ui, color: &mut Option<Color32>, text: &str) {
+    ui.horizontal(|ui| {
+        let mut enabled = color.is_some();
+        ui.checkbox(&mut enabled, text);
+        match (enabled, color.as_mut()) {
+            (true, Some(color)) => {
+                ui.color_edit_button_srgba(color);
+            }
+            (true, None) => *color = Some(ui.visuals().text_color()),
+            (false, _) => *color = None,
+        }
+    });
+}
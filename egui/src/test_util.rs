@@ -0,0 +1,82 @@
+//! Helpers for the unit tests: running frames and making input events.
+
+use crate::*;
+
+/// Run one frame with this input, calling `add_contents` between [`CtxRef::begin_frame`] and [`CtxRef::end_frame`].
+pub(crate) fn run_frame_with_input<R>(
+    ctx: &mut CtxRef,
+    input: RawInput,
+    add_contents: impl FnOnce(&CtxRef) -> R,
+) -> (R, Output) {
+    let (inner, output, _shapes) = run_frame_with_shapes(ctx, input, add_contents);
+    (inner, output)
+}
+
+/// Like [`run_frame_with_input`], also returning what was painted.
+pub(crate) fn run_frame_with_shapes<R>(
+    ctx: &mut CtxRef,
+    input: RawInput,
+    add_contents: impl FnOnce(&CtxRef) -> R,
+) -> (R, Output, Vec<epaint::ClippedShape>) {
+    ctx.begin_frame(input);
+    let inner = add_contents(ctx);
+    let (output, shapes) = ctx.end_frame();
+    (inner, output, shapes)
+}
+
+/// Run one frame with these events.
+pub(crate) fn run_frame<R>(
+    ctx: &mut CtxRef,
+    events: Vec<Event>,
+    add_contents: impl FnOnce(&CtxRef) -> R,
+) -> R {
+    let input = RawInput {
+        events,
+        ..Default::default()
+    };
+    run_frame_with_input(ctx, input, add_contents).0
+}
+
+/// Run one frame with these events, showing `add_contents` in a [`CentralPanel`].
+pub(crate) fn run_ui_frame<R>(
+    ctx: &mut CtxRef,
+    events: Vec<Event>,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    run_frame(ctx, events, |ctx| {
+        CentralPanel::default().show(ctx, add_contents).inner
+    })
+}
+
+/// The primary mouse button going down (`pressed`) or up at `pos`.
+pub(crate) fn pointer_button(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    }
+}
+
+/// Move the mouse to `pos`, then click the primary button there.
+pub(crate) fn click(pos: Pos2) -> Vec<Event> {
+    vec![
+        Event::PointerMoved(pos),
+        pointer_button(pos, true),
+        pointer_button(pos, false),
+    ]
+}
+
+/// Press `key`, without any modifiers.
+pub(crate) fn key(key: Key) -> Event {
+    key_with_modifiers(key, Default::default())
+}
+
+/// Press `key` while holding `modifiers`.
+pub(crate) fn key_with_modifiers(key: Key, modifiers: Modifiers) -> Event {
+    Event::Key {
+        key,
+        pressed: true,
+        modifiers,
+    }
+}
@@ -1116,6 +1116,58 @@ impl Ui {
         response
     }
 
+    /// One [`Self::radio_value`] for each of the `values`, showing the value as text.
+    ///
+    /// The returned [`Response`] covers all the radio buttons, and is changed if any of them was clicked.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let mut size = "Medium";
+    /// ui.radio_values(&mut size, vec!["Small", "Medium", "Large"]);
+    /// ```
+    pub fn radio_values<Value: PartialEq + ToString>(
+        &mut self,
+        current_value: &mut Value,
+        values: impl IntoIterator<Item = Value>,
+    ) -> Response {
+        self.add_values(current_value, values, Self::radio_value)
+    }
+
+    /// One [`Self::selectable_value`] for each of the `values`, showing the value as text.
+    ///
+    /// The returned [`Response`] covers all the labels, and is changed if any of them was clicked.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let mut day = 3;
+    /// ui.horizontal(|ui| ui.selectable_values(&mut day, 1..=7));
+    /// ```
+    pub fn selectable_values<Value: PartialEq + ToString>(
+        &mut self,
+        current_value: &mut Value,
+        values: impl IntoIterator<Item = Value>,
+    ) -> Response {
+        self.add_values(current_value, values, Self::selectable_value)
+    }
+
+    fn add_values<Value: PartialEq + ToString>(
+        &mut self,
+        current_value: &mut Value,
+        values: impl IntoIterator<Item = Value>,
+        add_value: impl Fn(&mut Self, &mut Value, Value, String) -> Response,
+    ) -> Response {
+        let mut response: Option<Response> = None;
+        for value in values {
+            let text = value.to_string();
+            let value_response = add_value(self, current_value, value, text);
+            response = Some(match response {
+                Some(response) => response | value_response,
+                None => value_response,
+            });
+        }
+        response.unwrap_or_else(|| self.allocate_response(Vec2::ZERO, Sense::hover()))
+    }
+
     /// Shortcut for `add(Separator::default())` (see [`Separator`]).
     #[inline(always)]
     pub fn separator(&mut self) -> Response {
@@ -1202,6 +1254,13 @@ impl Ui {
         response
     }
 
+    /// Shows a button with the given HDR color, and the intensity it is multiplied by.
+    /// If the user clicks the button, a full color picker is shown.
+    /// The given color is in linear RGB space, and the channels may be above 1.
+    pub fn color_edit_button_rgb_hdr(&mut self, rgb: &mut [f32; 3]) -> Response {
+        color_picker::color_edit_button_rgb_hdr(self, rgb)
+    }
+
     /// Shows a button with the given color.
     /// If the user clicks the button, a full color picker is shown.
     /// The given color is in `sRGBA` space with premultiplied alpha
@@ -1267,6 +1326,40 @@ impl Ui {
         crate::Frame::group(self.style()).show(self, add_contents)
     }
 
+    /// Show contents that rarely change, and skip running `add_contents` when they haven't.
+    ///
+    /// If `version` (e.g. a hash of everything the contents depend on) is the same as last frame,
+    /// and the contents were not hovered, dragged or focused, the shapes painted last frame are
+    /// painted again and the same space is allocated, without calling `add_contents`.
+    /// Moving the contents, a change of style or a change of the available space also calls `add_contents` again.
+    ///
+    /// Only use this for contents that are expensive to lay out and don't animate.
+    /// Only shapes painted in the layer of this `Ui` are kept, so popups and tooltips
+    /// will not show while the cached contents are replayed.
+    ///
+    /// Replayed widgets don't interact at all: they can't be clicked until hovered (which
+    /// calls `add_contents` again), and they send no [`WidgetInfo`](crate::WidgetInfo) to screen readers.
+    /// `add_contents` is also called when Tab is pressed, so the keyboard focus can move into the contents.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let items = vec!["apple", "banana"];
+    /// ui.cache_layout("item_list", &items, |ui| {
+    ///     for item in &items {
+    ///         ui.label(*item);
+    ///     }
+    /// });
+    /// ```
+    pub fn cache_layout(
+        &mut self,
+        id_source: impl Hash + std::fmt::Debug,
+        version: impl Hash,
+        add_contents: impl FnOnce(&mut Ui),
+    ) -> Response {
+        let id = self.make_persistent_id(id_source);
+        crate::layout_cache::cache_layout(self, id, Id::new(version), Box::new(add_contents))
+    }
+
     /// Create a scoped child ui.
     ///
     /// You can use this to temporarily change the [`Style`] of a sub-region, for instance:
@@ -0,0 +1,103 @@
+//! Finding the differences between two sequences, e.g. the lines of two texts.
+
+/// One step of a [`diff`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffOp {
+    /// The next element is the same in both sequences.
+    Same,
+    /// The next element of the old sequence was removed.
+    Removed,
+    /// The next element of the new sequence was added.
+    Added,
+}
+
+/// Above this many elements (old times new, after the common start and end are skipped)
+/// [`diff`] doesn't look for a shortest diff, and just removes everything and adds it back.
+pub const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// The steps to turn `old` into `new`, keeping as many elements as possible
+/// (a longest common subsequence).
+///
+/// Takes time and memory proportional to the length of `old` times the length of `new`,
+/// not counting a common start and end. See [`MAX_DIFF_CELLS`].
+///
+/// ```
+/// use egui::util::diff::{diff, DiffOp::*};
+/// assert_eq!(diff(b"abc", b"axc"), vec![Same, Removed, Added, Same]);
+/// ```
+pub fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let (old, new) = (&old[prefix..], &new[prefix..]);
+    let suffix = old
+        .iter()
+        .rev()
+        .zip(new.iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let (old, new) = (&old[..old.len() - suffix], &new[..new.len() - suffix]);
+
+    let mut ops = vec![DiffOp::Same; prefix];
+    if old.len().saturating_mul(new.len()) > MAX_DIFF_CELLS {
+        ops.extend(std::iter::repeat(DiffOp::Removed).take(old.len()));
+        ops.extend(std::iter::repeat(DiffOp::Added).take(new.len()));
+    } else {
+        ops.extend(lcs_ops(old, new));
+    }
+    ops.extend(std::iter::repeat(DiffOp::Same).take(suffix));
+    ops
+}
+
+fn lcs_ops<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    let (n, m) = (old.len(), new.len());
+    // lengths[i * (m + 1) + j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lengths = vec![0_u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i * (m + 1) + j] = if old[i] == new[j] {
+                lengths[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                lengths[(i + 1) * (m + 1) + j].max(lengths[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            ops.push(DiffOp::Same);
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * (m + 1) + j] >= lengths[i * (m + 1) + j + 1] {
+            ops.push(DiffOp::Removed);
+            i += 1;
+        } else {
+            ops.push(DiffOp::Added);
+            j += 1;
+        }
+    }
+    ops.extend(std::iter::repeat(DiffOp::Removed).take(n - i));
+    ops.extend(std::iter::repeat(DiffOp::Added).take(m - j));
+    ops
+}
+
+#[test]
+fn test_diff() {
+    use DiffOp::*;
+    assert_eq!(diff::<u8>(b"", b""), vec![]);
+    assert_eq!(diff(b"abc", b"abc"), vec![Same, Same, Same]);
+    assert_eq!(diff(b"", b"ab"), vec![Added, Added]);
+    assert_eq!(diff(b"ab", b""), vec![Removed, Removed]);
+    assert_eq!(
+        diff(b"kitten", b"sitting"),
+        vec![Removed, Added, Same, Same, Same, Removed, Added, Same, Added]
+    );
+
+    let old = ["fn main() {", "    foo();", "}"];
+    let new = ["fn main() {", "    bar();", "    foo();", "}"];
+    assert_eq!(diff(&old, &new), vec![Same, Added, Same, Same]);
+}
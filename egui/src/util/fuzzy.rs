@@ -0,0 +1,115 @@
+//! Fuzzy matching of a search query against some text, e.g. for filtering a list of commands.
+
+/// The result of a successful [`fuzzy_match`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FuzzyMatch {
+    /// Higher is better. Only meaningful when compared to other matches of the same query.
+    pub score: i32,
+
+    /// The char indices (not byte indices!) into the text of the matched characters, in increasing order.
+    pub char_indices: Vec<usize>,
+}
+
+/// Do all the characters of `query` appear in `text`, in order?
+///
+/// The comparison ignores case and whitespace in the query.
+/// Consecutive matches and matches at the start of words are rewarded,
+/// skipped characters are penalized.
+///
+/// An empty query matches everything with a score of zero.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    const MATCH: i32 = 16;
+    const CONSECUTIVE_BONUS: i32 = 16;
+    const WORD_START_BONUS: i32 = 12;
+    const GAP_PENALTY: i32 = 1;
+    const MAX_GAP_PENALTY: i32 = 8;
+
+    let mut query = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut result = FuzzyMatch::default();
+    let mut prev_char: Option<char> = None;
+    let mut prev_match: Option<usize> = None;
+
+    for (i, c) in text.chars().enumerate() {
+        let q = match query.peek() {
+            Some(&q) => q,
+            None => break,
+        };
+
+        if eq_ignore_case(q, c) {
+            query.next();
+            result.score += MATCH;
+
+            if prev_match.map_or(false, |prev| prev + 1 == i) {
+                result.score += CONSECUTIVE_BONUS;
+            } else {
+                let gap = i - prev_match.map_or(0, |prev| prev + 1);
+                result.score -= (GAP_PENALTY * gap as i32).min(MAX_GAP_PENALTY);
+            }
+
+            if is_word_start(prev_char, c) {
+                result.score += WORD_START_BONUS;
+            }
+
+            result.char_indices.push(i);
+            prev_match = Some(i);
+        }
+
+        prev_char = Some(c);
+    }
+
+    if query.peek().is_none() {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Start of text, after a separator, or a camelCase hump.
+fn is_word_start(prev: Option<char>, c: char) -> bool {
+    match prev {
+        None => true,
+        Some(prev) => {
+            (!prev.is_alphanumeric() && c.is_alphanumeric())
+                || (prev.is_lowercase() && c.is_uppercase())
+        }
+    }
+}
+
+#[test]
+fn test_fuzzy_match() {
+    assert_eq!(fuzzy_match("", "anything").unwrap().score, 0);
+    assert!(fuzzy_match("xyz", "File: Open").is_none());
+    assert!(fuzzy_match("nepo", "File: Open").is_none());
+
+    let m = fuzzy_match("fo", "File: Open").unwrap();
+    assert_eq!(m.char_indices, vec![0, 6]);
+
+    let m = fuzzy_match("OPEN", "File: open").unwrap();
+    assert_eq!(m.char_indices, vec![6, 7, 8, 9]);
+
+    // Whitespace in the query is ignored:
+    let m = fuzzy_match("f o", "File: Open").unwrap();
+    assert_eq!(m.char_indices, vec![0, 6]);
+
+    // Char indices, not byte indices:
+    let m = fuzzy_match("ö", "Zoom: größer").unwrap();
+    assert_eq!(m.char_indices, vec![8]);
+
+    // Consecutive matches beat scattered ones:
+    let consecutive = fuzzy_match("save", "Save file").unwrap();
+    let scattered = fuzzy_match("save", "Search and view everything").unwrap();
+    assert!(consecutive.score > scattered.score);
+
+    // Word starts beat matches in the middle of words:
+    let word_starts = fuzzy_match("tw", "Toggle Wrap").unwrap();
+    let mid_word = fuzzy_match("tw", "Outweigh").unwrap();
+    assert!(word_starts.score > mid_word.score);
+
+    // camelCase humps count as word starts:
+    let m = fuzzy_match("sa", "showAll").unwrap();
+    assert_eq!(m.char_indices, vec![0, 4]);
+}
@@ -1,8 +1,12 @@
 //! Miscellaneous tools used by the rest of egui.
 
 pub(crate) mod cache;
+pub mod diff;
+pub mod fuzzy;
 mod history;
+mod undo_stack;
 pub mod undoer;
 
 pub(crate) use cache::Cache;
 pub use history::History;
+pub use undo_stack::UndoStack;
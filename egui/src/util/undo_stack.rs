@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+
+use crate::{Context, Key};
+
+/// Undo and redo for the state of a whole tool or document.
+///
+/// Call [`Self::update`] once per frame, after your ui code has (maybe) changed the state.
+/// `UndoStack` follows egui's interaction patterns to decide what makes up one undo step:
+///
+/// * While something is being dragged (e.g. a [`crate::Slider`] or a [`crate::DragValue`]),
+///   all the changes are coalesced and committed as one undo point when the pointer is released.
+/// * While a widget has keyboard focus (e.g. a [`crate::TextEdit`]), the changes are committed
+///   when the focus is lost.
+/// * Other changes (e.g. clicking a checkbox) are committed right away.
+///
+/// [`Self::update`] also handles the undo/redo shortcuts:
+/// <kbd>Ctrl</kbd>+<kbd>Z</kbd> to undo, and <kbd>Ctrl</kbd>+<kbd>Y</kbd> or
+/// <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>Z</kbd> to redo
+/// (<kbd>⌘</kbd> instead of <kbd>Ctrl</kbd> on Mac).
+/// These are ignored while a widget has keyboard focus, so that a [`crate::TextEdit`]
+/// can still undo its own edits.
+///
+/// Unlike [`super::undoer::Undoer`], `UndoStack` never creates undo points based on time.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ui = &mut egui::Ui::__test();
+/// # #[derive(Clone, PartialEq)] struct Document { volume: f32 }
+/// # let mut document = Document { volume: 0.5 };
+/// # let mut undo_stack = egui::util::UndoStack::default();
+/// ui.add(egui::Slider::new(&mut document.volume, 0.0..=1.0));
+/// // … more ui code changing `document` …
+/// undo_stack.update(&ctx, &mut document);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct UndoStack<T> {
+    max_undos: usize,
+
+    /// Older states, the most recent at the back.
+    undos: VecDeque<T>,
+
+    /// Undone states, the most recently undone at the back.
+    redos: Vec<T>,
+
+    /// The latest committed state, i.e. what [`Self::undo`] would go back to if there are uncommitted changes.
+    committed: Option<T>,
+}
+
+impl<T> Default for UndoStack<T> {
+    fn default() -> Self {
+        Self {
+            max_undos: 100,
+            undos: Default::default(),
+            redos: Default::default(),
+            committed: None,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for UndoStack<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { undos, redos, .. } = self;
+        f.debug_struct("UndoStack")
+            .field("undo count", &undos.len())
+            .field("redo count", &redos.len())
+            .finish()
+    }
+}
+
+impl<T: Clone + PartialEq> UndoStack<T> {
+    /// Keep at most this many undo points. Default: `100`.
+    pub fn max_undos(mut self, max_undos: usize) -> Self {
+        self.max_undos = max_undos;
+        self
+    }
+
+    /// Is there anything to undo to from `current_state`?
+    pub fn can_undo(&self, current_state: &T) -> bool {
+        !self.undos.is_empty() || self.has_uncommitted_changes(current_state)
+    }
+
+    /// Is there anything to redo from `current_state`?
+    ///
+    /// Redoing is no longer possible once the state has been changed after an undo.
+    pub fn can_redo(&self, current_state: &T) -> bool {
+        !self.redos.is_empty() && !self.has_uncommitted_changes(current_state)
+    }
+
+    /// Forget all undo and redo points, and start over from `current_state`.
+    pub fn clear(&mut self, current_state: &T) {
+        self.undos.clear();
+        self.redos.clear();
+        self.committed = Some(current_state.clone());
+    }
+
+    /// Create an undo point now if `current_state` has changed since the latest one,
+    /// e.g. before an operation you want to be undoable on its own.
+    ///
+    /// This clears the redo points.
+    pub fn commit(&mut self, current_state: &T) {
+        match &self.committed {
+            None => self.committed = Some(current_state.clone()),
+            Some(committed) if committed == current_state => {}
+            Some(_) => {
+                if let Some(previous) = self.committed.replace(current_state.clone()) {
+                    self.undos.push_back(previous);
+                }
+                self.redos.clear();
+                while self.undos.len() > self.max_undos {
+                    self.undos.pop_front();
+                }
+            }
+        }
+    }
+
+    /// Go back to the previous undo point. Returns `false` if there was nothing to undo.
+    ///
+    /// Uncommitted changes (e.g. from a drag that is still ongoing) are undone first.
+    pub fn undo(&mut self, state: &mut T) -> bool {
+        self.commit(state);
+        if let Some(previous) = self.undos.pop_back() {
+            if let Some(committed) = self.committed.replace(previous.clone()) {
+                self.redos.push(committed);
+            }
+            *state = previous;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Go forward to the most recently undone state. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self, state: &mut T) -> bool {
+        if !self.can_redo(state) {
+            return false;
+        }
+        if let Some(next) = self.redos.pop() {
+            if let Some(committed) = self.committed.replace(next.clone()) {
+                self.undos.push_back(committed);
+            }
+            *state = next;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Call this once per frame, after your ui code.
+    ///
+    /// Commits the changes to `state` unless an interaction is still ongoing,
+    /// and undoes or redoes `state` if the user pressed the corresponding shortcut.
+    ///
+    /// Returns `true` if `state` was undone or redone.
+    pub fn update(&mut self, ctx: &Context, state: &mut T) -> bool {
+        let keyboard_focus = ctx.wants_keyboard_input();
+        let interacting = keyboard_focus
+            || ctx.memory().is_anything_being_dragged()
+            || ctx.input().pointer.any_down();
+
+        if !interacting {
+            self.commit(state);
+        }
+
+        if keyboard_focus {
+            return false;
+        }
+
+        let (undo_pressed, redo_pressed) = {
+            let input = ctx.input();
+            let command = input.modifiers.command;
+            let shift = input.modifiers.shift;
+            (
+                command && !shift && input.key_pressed(Key::Z),
+                command && (input.key_pressed(Key::Y) || shift && input.key_pressed(Key::Z)),
+            )
+        };
+
+        if undo_pressed {
+            self.undo(state)
+        } else if redo_pressed {
+            self.redo(state)
+        } else {
+            false
+        }
+    }
+
+    fn has_uncommitted_changes(&self, current_state: &T) -> bool {
+        self.committed
+            .as_ref()
+            .map_or(false, |committed| committed != current_state)
+    }
+}
+
+#[test]
+fn test_undo_stack() {
+    let mut stack = UndoStack::default();
+    let mut state = 0;
+    stack.commit(&state);
+    assert!(!stack.can_undo(&state));
+
+    // Changes that are never committed (e.g. a drag in progress) are still undoable:
+    state = 2;
+    assert!(stack.can_undo(&state));
+    assert!(stack.undo(&mut state));
+    assert_eq!(state, 0);
+    assert!(stack.redo(&mut state));
+    assert_eq!(state, 2);
+    assert!(!stack.redo(&mut state));
+
+    state = 3;
+    stack.commit(&state);
+    state = 4;
+    stack.commit(&state);
+    assert!(stack.undo(&mut state));
+    assert!(stack.undo(&mut state));
+    assert_eq!(state, 2);
+
+    // A new change clears the redo points:
+    state = 5;
+    assert!(!stack.can_redo(&state));
+    stack.commit(&state);
+    assert!(!stack.redo(&mut state));
+    assert!(stack.undo(&mut state));
+    assert_eq!(state, 2);
+    assert!(stack.undo(&mut state));
+    assert_eq!(state, 0);
+    assert!(!stack.undo(&mut state));
+}
+
+#[test]
+fn test_undo_stack_max_undos() {
+    let mut stack = UndoStack::default().max_undos(2);
+    for state in 0..10 {
+        stack.commit(&state);
+    }
+    let mut state = 9;
+    assert!(stack.undo(&mut state));
+    assert!(stack.undo(&mut state));
+    assert_eq!(state, 7);
+    assert!(!stack.undo(&mut state));
+}
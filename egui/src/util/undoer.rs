@@ -47,6 +47,8 @@ impl Default for Settings {
 ///
 /// Rule 1) will make sure an undo point is not created until you _stop_ dragging that slider.
 /// Rule 2) will make sure that you will get some undo points even if you are constantly changing the state.
+///
+/// Undone states can be redone with [`Self::redo`], until a new undo point is created.
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct Undoer<State> {
@@ -57,15 +59,21 @@ pub struct Undoer<State> {
     /// The latest undo point may (often) be the current state.
     undos: VecDeque<State>,
 
+    /// Undone states, the most recently undone at the back.
+    /// Defaults to empty, so undo history saved before there was redo can still be loaded.
+    #[cfg_attr(feature = "persistence", serde(default))]
+    redos: Vec<State>,
+
     #[cfg_attr(feature = "persistence", serde(skip))]
     flux: Option<Flux<State>>,
 }
 
 impl<State> std::fmt::Debug for Undoer<State> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let Self { undos, .. } = self;
+        let Self { undos, redos, .. } = self;
         f.debug_struct("Undoer")
             .field("undo count", &undos.len())
+            .field("redo count", &redos.len())
             .finish()
     }
 }
@@ -91,6 +99,35 @@ where
         }
     }
 
+    /// Do we have an undone state to go back to?
+    ///
+    /// Only possible if the given state is the latest undo point,
+    /// i.e. nothing has changed since the latest call to [`Self::undo`] or [`Self::redo`].
+    pub fn has_redo(&self, current_state: &State) -> bool {
+        !self.redos.is_empty() && self.undos.back() == Some(current_state)
+    }
+
+    /// Like [`Self::has_undo`], but compared to the latest state given to [`Self::feed_state`].
+    pub fn can_undo(&self) -> bool {
+        match self.undos.len() {
+            0 => false,
+            1 => self.flux.is_some(),
+            _ => true,
+        }
+    }
+
+    /// Like [`Self::has_redo`], but compared to the latest state given to [`Self::feed_state`].
+    pub fn can_redo(&self) -> bool {
+        !self.redos.is_empty() && self.flux.is_none()
+    }
+
+    /// Forget all undo and redo points.
+    pub fn clear(&mut self) {
+        self.undos.clear();
+        self.redos.clear();
+        self.flux = None;
+    }
+
     /// Return true if the state is currently changing
     pub fn is_in_flux(&self) -> bool {
         self.flux.is_some()
@@ -103,6 +140,7 @@ where
             if self.undos.back() == Some(current_state) {
                 self.undos.pop_back();
             }
+            self.redos.push(current_state.clone());
 
             // Note: we keep the undo point intact.
             self.undos.back()
@@ -111,12 +149,27 @@ where
         }
     }
 
+    /// Go back to the most recently undone state.
+    pub fn redo(&mut self, current_state: &State) -> Option<&State> {
+        if self.has_redo(current_state) {
+            self.flux = None;
+            let state = self.redos.pop()?;
+            self.undos.push_back(state);
+            self.undos.back()
+        } else {
+            None
+        }
+    }
+
     /// Add an undo point if, and only if, there has been a change since the latest undo point.
     ///
+    /// This clears the redo points.
+    ///
     /// * `time`: current time in seconds.
     pub fn add_undo(&mut self, current_state: &State) {
         if self.undos.back() != Some(current_state) {
             self.undos.push_back(current_state.clone());
+            self.redos.clear();
         }
         while self.undos.len() > self.settings.max_undos {
             self.undos.pop_front();
@@ -170,3 +223,35 @@ where
         }
     }
 }
+
+#[test]
+fn test_undo_redo() {
+    let mut undoer = Undoer::default();
+    undoer.add_undo(&"a");
+    undoer.add_undo(&"ab");
+    undoer.add_undo(&"abc");
+    assert!(!undoer.has_redo(&"abc"));
+
+    assert_eq!(undoer.undo(&"abc"), Some(&"ab"));
+    assert_eq!(undoer.undo(&"ab"), Some(&"a"));
+    assert!(undoer.can_redo());
+    assert_eq!(undoer.redo(&"a"), Some(&"ab"));
+    assert_eq!(undoer.redo(&"ab"), Some(&"abc"));
+    assert_eq!(undoer.redo(&"abc"), None);
+
+    // Undoing while the state is in flux goes back to the latest undo point,
+    // and redoing goes back to the unsaved state:
+    undoer.feed_state(0.0, &"abcd");
+    assert!(undoer.is_in_flux());
+    assert_eq!(undoer.undo(&"abcd"), Some(&"abc"));
+    assert_eq!(undoer.redo(&"abc"), Some(&"abcd"));
+
+    // A new change clears the redo points:
+    assert_eq!(undoer.undo(&"abcd"), Some(&"abc"));
+    undoer.add_undo(&"abcx");
+    assert!(!undoer.has_redo(&"abcx"));
+    assert!(undoer.can_undo());
+
+    undoer.clear();
+    assert!(!undoer.can_undo());
+}
@@ -0,0 +1,262 @@
+use crate::*;
+
+/// One frame of an [`AnimatedImage`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimationFrame {
+    pub texture_id: TextureId,
+
+    /// How many seconds to show this frame for.
+    pub duration: f32,
+}
+
+/// Where an [`AnimatedImage`] is in its animation, and if it is playing.
+///
+/// Keep one yourself and pass it to [`AnimatedImage::playback`] to control the animation from code.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Playback {
+    /// If `false`, the animation is paused.
+    pub playing: bool,
+
+    /// Seconds since the start of the animation.
+    pub time: f32,
+
+    /// Start over when reaching the end. If `false`, the animation stops at its last frame.
+    pub looping: bool,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            playing: true,
+            time: 0.0,
+            looping: true,
+        }
+    }
+}
+
+impl Playback {
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Jump to this many seconds into the animation.
+    pub fn seek(&mut self, time: f32) {
+        self.time = time.at_least(0.0);
+    }
+
+    /// Advance the time by `dt` seconds if playing, wrapping around or stopping at the end.
+    fn advance(&mut self, dt: f32, total_duration: f32) {
+        if self.playing {
+            self.time += dt;
+        }
+        if total_duration <= 0.0 {
+            self.time = 0.0;
+        } else if self.time >= total_duration {
+            if self.looping {
+                self.time %= total_duration;
+            } else {
+                self.time = total_duration;
+                self.playing = false;
+            }
+        }
+    }
+}
+
+/// The index of the frame to show at `time`, and how many seconds until the next frame.
+fn frame_at(frames: &[AnimationFrame], time: f32) -> (usize, f32) {
+    let mut end = 0.0;
+    for (i, frame) in frames.iter().enumerate() {
+        end += frame.duration;
+        if time < end {
+            return (i, end - time);
+        }
+    }
+    (frames.len().saturating_sub(1), f32::INFINITY)
+}
+
+// ----------------------------------------------------------------------------
+
+/// Show an animation, e.g. a GIF, one frame after the other.
+///
+/// egui only repaints when the next frame is due
+/// (using [`Context::request_repaint_after`]), so a slow animation is cheap.
+///
+/// Load the frames with `epi::TextureCache::get_or_load_animation_bytes`,
+/// or upload them yourself.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let frames: Vec<egui::AnimationFrame> = vec![];
+/// ui.add(egui::AnimatedImage::new(&frames, [64.0, 64.0]).show_controls(true));
+///
+/// // Control the playback from code:
+/// # let mut playback = egui::Playback::default();
+/// if ui.button("Restart").clicked() {
+///     playback.seek(0.0);
+///     playback.play();
+/// }
+/// ui.add(egui::AnimatedImage::new(&frames, [64.0, 64.0]).playback(&mut playback));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct AnimatedImage<'a> {
+    frames: &'a [AnimationFrame],
+    size: Vec2,
+    playback: Option<&'a mut Playback>,
+    id_source: Option<Id>,
+    show_controls: bool,
+}
+
+impl<'a> AnimatedImage<'a> {
+    pub fn new(frames: &'a [AnimationFrame], size: impl Into<Vec2>) -> Self {
+        Self {
+            frames,
+            size: size.into(),
+            playback: None,
+            id_source: None,
+            show_controls: false,
+        }
+    }
+
+    /// Use this playback state instead of one stored by egui.
+    ///
+    /// The widget advances it every frame while it is playing.
+    pub fn playback(mut self, playback: &'a mut Playback) -> Self {
+        self.playback = Some(playback);
+        self
+    }
+
+    /// Where to store the playback state if you don't use [`Self::playback`].
+    /// Default: the texture of the first frame.
+    ///
+    /// Set this if you show the same animation more than once and want them to play separately.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Show a play/pause button and a slider for seeking below the image. Default: `false`.
+    pub fn show_controls(mut self, show_controls: bool) -> Self {
+        self.show_controls = show_controls;
+        self
+    }
+}
+
+impl<'a> Widget for AnimatedImage<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let AnimatedImage {
+            frames,
+            size,
+            playback,
+            id_source,
+            show_controls,
+        } = self;
+
+        let id = ui.make_persistent_id(
+            id_source.unwrap_or_else(|| Id::new(frames.first().map(|frame| frame.texture_id))),
+        );
+        let mut stored_playback = None;
+        let playback = match playback {
+            Some(playback) => playback,
+            None => stored_playback
+                .get_or_insert(*ui.memory().id_data_temp.get_or_default::<Playback>(id)),
+        };
+
+        let total_duration: f32 = frames.iter().map(|frame| frame.duration).sum();
+        playback.advance(ui.input().unstable_dt, total_duration);
+
+        let response = ui
+            .vertical(|ui| {
+                let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+                let (index, time_left) = frame_at(frames, playback.time);
+                if let Some(frame) = frames.get(index) {
+                    if ui.clip_rect().intersects(rect) {
+                        Image::new(frame.texture_id, size).paint_at(ui, rect);
+                        if playback.playing && time_left.is_finite() {
+                            ui.ctx().request_repaint_after(time_left);
+                        }
+                    }
+                }
+
+                if show_controls {
+                    ui.horizontal(|ui| {
+                        let (icon, hover_text) = if playback.playing {
+                            ("⏸", "Pause")
+                        } else {
+                            ("▶", "Play")
+                        };
+                        if ui.button(icon).on_hover_text(hover_text).clicked() {
+                            if playback.playing {
+                                playback.pause();
+                            } else {
+                                if !playback.looping && playback.time >= total_duration {
+                                    playback.seek(0.0);
+                                }
+                                playback.play();
+                            }
+                        }
+                        let mut time = playback.time;
+                        let slider = Slider::new(&mut time, 0.0..=total_duration)
+                            .show_value(false)
+                            .text(format!("{:.1} / {:.1} s", playback.time, total_duration));
+                        if ui.add(slider).changed() {
+                            playback.seek(time);
+                        }
+                    });
+                }
+                response
+            })
+            .inner;
+
+        if let Some(playback) = stored_playback {
+            ui.memory().id_data_temp.insert(id, playback);
+        }
+
+        response
+    }
+}
+
+#[test]
+fn test_animated_image_playback() {
+    let frames = [
+        AnimationFrame {
+            texture_id: TextureId::User(0),
+            duration: 0.1,
+        },
+        AnimationFrame {
+            texture_id: TextureId::User(1),
+            duration: 0.2,
+        },
+    ];
+    assert_eq!(frame_at(&frames, 0.0).0, 0);
+    assert_eq!(frame_at(&frames, 0.15).0, 1);
+    assert_eq!(frame_at(&frames, 1.0), (1, f32::INFINITY));
+
+    let mut playback = Playback::default();
+    playback.advance(0.35, 0.3);
+    assert!(playback.playing && (playback.time - 0.05).abs() < 1e-6);
+
+    playback.looping = false;
+    playback.advance(0.35, 0.3);
+    assert!(!playback.playing && playback.time == 0.3);
+
+    // Only repaints when the next frame is due:
+    use crate::test_util::run_frame_with_input;
+    let mut ctx = CtxRef::default();
+    let mut playback = Playback::default();
+    let mut run_frame = || {
+        let (_, output) = run_frame_with_input(&mut ctx, Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(AnimatedImage::new(&frames, [8.0, 8.0]).playback(&mut playback))
+            });
+        });
+        output
+    };
+    run_frame(); // the first frame may want another frame anyway
+    let output = run_frame();
+    assert!(output.needs_repaint);
+    assert!(output.repaint_after.is_some());
+}
@@ -0,0 +1,412 @@
+//! Building blocks for audio tools: [`LevelMeter`] and [`Waveform`].
+
+use crate::*;
+
+/// Decibels relative to full scale, for an amplitude where `1.0` is full scale.
+fn amplitude_to_db(amplitude: f32) -> f32 {
+    20.0 * amplitude.abs().max(1e-9).log10()
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LevelMeterState {
+    /// The peak shown, falling slowly after the actual peak drops.
+    held_peak_db: f32,
+
+    /// The level went over full scale, and the user hasn't clicked the meter since.
+    clipped: bool,
+}
+
+impl Default for LevelMeterState {
+    fn default() -> Self {
+        Self {
+            held_peak_db: f32::NEG_INFINITY,
+            clipped: false,
+        }
+    }
+}
+
+/// A meter showing the level of an audio signal, like on a mixing desk.
+///
+/// The RMS level is shown as a solid bar, and the peak level as a dimmer bar beyond it.
+/// A line marks the highest recent peak, falling back slowly (see [`Self::decay`]).
+/// The bar is green, then yellow from -12 dB, and red from -3 dB.
+///
+/// When the peak reaches full scale (`1.0`), a clip indicator at the end lights up,
+/// and stays lit until the user clicks the meter.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let (peak, rms) = (0.8, 0.3);
+/// ui.add(egui::LevelMeter::new(peak).rms(rms));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct LevelMeter {
+    peak: f32,
+    rms: Option<f32>,
+    id_source: Option<Id>,
+    vertical: bool,
+    length: Option<f32>,
+    min_db: f32,
+    decay: f32,
+}
+
+impl LevelMeter {
+    /// `peak` is the peak amplitude since the last frame, where `1.0` is full scale.
+    pub fn new(peak: f32) -> Self {
+        Self {
+            peak: peak.abs(),
+            rms: None,
+            id_source: None,
+            vertical: false,
+            length: None,
+            min_db: -60.0,
+            decay: 20.0,
+        }
+    }
+
+    /// The RMS amplitude since the last frame, where `1.0` is full scale.
+    pub fn rms(mut self, rms: f32) -> Self {
+        self.rms = Some(rms.abs());
+        self
+    }
+
+    /// Needed if you show more than one [`LevelMeter`] in the same [`Ui`], e.g. for the left and right channels.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Fill from the bottom up instead of from left to right. Default: `false`.
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    /// The length of the meter in points.
+    /// Default: the available width, or [`style::Spacing::slider_width`] if vertical.
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// The level at the start of the meter, in decibels. Default: -60.
+    pub fn min_db(mut self, min_db: f32) -> Self {
+        self.min_db = min_db.min(-1.0);
+        self
+    }
+
+    /// How fast the peak line falls back, in decibels per second. Default: 20.
+    pub fn decay(mut self, db_per_second: f32) -> Self {
+        self.decay = db_per_second.max(0.0);
+        self
+    }
+}
+
+impl Widget for LevelMeter {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let LevelMeter {
+            peak,
+            rms,
+            id_source,
+            vertical,
+            length,
+            min_db,
+            decay,
+        } = self;
+
+        let thickness = ui.spacing().interact_size.y / 2.0;
+        let size = if vertical {
+            vec2(thickness, length.unwrap_or(ui.spacing().slider_width))
+        } else {
+            let length = length.unwrap_or_else(|| ui.available_size_before_wrap_finite().x);
+            vec2(length, thickness)
+        };
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+        response.widget_info(|| WidgetInfo {
+            value: Some(amplitude_to_db(peak) as f64),
+            ..WidgetInfo::labeled(WidgetType::ProgressIndicator, "Level")
+        });
+
+        let id = id_source.map_or(response.id, |id_source| ui.make_persistent_id(id_source));
+        let mut state = *ui
+            .memory()
+            .id_data_temp
+            .get_or_default::<LevelMeterState>(id);
+        let peak_db = amplitude_to_db(peak);
+        let dt = ui.input().unstable_dt.min(0.1);
+        state.held_peak_db = peak_db.max(state.held_peak_db - decay * dt);
+        if peak >= 1.0 {
+            state.clipped = true;
+        }
+        if response.clicked() {
+            state.clipped = false;
+        }
+        if state.held_peak_db > peak_db && state.held_peak_db > min_db {
+            ui.ctx().request_repaint(); // let the peak line fall
+        }
+        ui.memory().id_data_temp.insert(id, state);
+
+        if !ui.clip_rect().intersects(rect) {
+            return response;
+        }
+
+        // The clip indicator is at the end, as a square:
+        let (bar_rect, clip_rect) = if vertical {
+            let clip_rect = Rect::from_min_size(rect.min, vec2(rect.width(), rect.width()));
+            let bar_rect =
+                Rect::from_x_y_ranges(rect.x_range(), clip_rect.bottom() + 1.0..=rect.bottom());
+            (bar_rect, clip_rect)
+        } else {
+            let clip_rect = Rect::from_min_size(
+                pos2(rect.right() - rect.height(), rect.top()),
+                vec2(rect.height(), rect.height()),
+            );
+            let bar_rect =
+                Rect::from_x_y_ranges(rect.left()..=clip_rect.left() - 1.0, rect.y_range());
+            (bar_rect, clip_rect)
+        };
+
+        let fraction = |db: f32| ((db - min_db) / -min_db).clamp(0.0, 1.0);
+        // The part of the bar from `from` to `to`, as fractions of its length:
+        let sub_rect = |from: f32, to: f32| {
+            if vertical {
+                Rect::from_x_y_ranges(
+                    bar_rect.x_range(),
+                    bar_rect.bottom() - to * bar_rect.height()
+                        ..=bar_rect.bottom() - from * bar_rect.height(),
+                )
+            } else {
+                Rect::from_x_y_ranges(
+                    bar_rect.left() + from * bar_rect.width()
+                        ..=bar_rect.left() + to * bar_rect.width(),
+                    bar_rect.y_range(),
+                )
+            }
+        };
+        let zones = [
+            (fraction(min_db), Color32::from_rgb(40, 190, 60)),
+            (fraction(-12.0), Color32::from_rgb(220, 200, 40)),
+            (fraction(-3.0), Color32::from_rgb(230, 50, 40)),
+        ];
+        let zone_color = |at: f32| {
+            zones
+                .iter()
+                .rev()
+                .find(|(start, _)| *start <= at)
+                .map_or(zones[0].1, |(_, color)| *color)
+        };
+
+        let mut mesh = epaint::Mesh::default();
+        mesh.add_colored_rect(bar_rect, ui.visuals().extreme_bg_color);
+        // Fill from `from` to `to` in the colors of the zones:
+        let mut add_bar = |from: f32, to: f32, dim: bool| {
+            let mut boundaries: Vec<f32> = zones
+                .iter()
+                .map(|(start, _)| *start)
+                .filter(|&start| from < start && start < to)
+                .collect();
+            boundaries.insert(0, from);
+            boundaries.push(to);
+            for pair in boundaries.windows(2) {
+                let color = zone_color(pair[0]);
+                let color = if dim {
+                    color.linear_multiply(0.4)
+                } else {
+                    color
+                };
+                mesh.add_colored_rect(sub_rect(pair[0], pair[1]), color);
+            }
+        };
+        let rms_fraction = rms.map_or(0.0, |rms| fraction(amplitude_to_db(rms)));
+        let peak_fraction = fraction(peak_db);
+        add_bar(0.0, rms_fraction, false);
+        if peak_fraction > rms_fraction {
+            add_bar(rms_fraction, peak_fraction, rms.is_some());
+        }
+        let held_fraction = fraction(state.held_peak_db);
+        if held_fraction > 0.0 {
+            let line = sub_rect(held_fraction, held_fraction);
+            let line = if vertical {
+                line.expand2(vec2(0.0, 1.0))
+            } else {
+                line.expand2(vec2(1.0, 0.0))
+            };
+            mesh.add_colored_rect(line.intersect(bar_rect), zone_color(held_fraction));
+        }
+
+        let clip_color = if state.clipped {
+            zones[2].1
+        } else {
+            ui.visuals().extreme_bg_color
+        };
+        mesh.add_colored_rect(clip_rect, clip_color);
+        ui.painter().add(Shape::mesh(mesh));
+
+        response.on_hover_text(format!("Peak: {:.1} dB", state.held_peak_db.max(min_db)))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shows an audio signal as a waveform.
+///
+/// The samples are reduced to the minimum and maximum of each column of physical pixels,
+/// so even a long recording is cheap to paint.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let samples: Vec<f32> = (0..48_000).map(|i| (i as f32 * 0.05).sin()).collect();
+/// ui.add(egui::Waveform::new(&samples).playhead(12_000));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Waveform<'a> {
+    samples: &'a [f32],
+    desired_size: Option<Vec2>,
+    color: Option<Color32>,
+    playhead: Option<usize>,
+}
+
+impl<'a> Waveform<'a> {
+    /// The samples go from `-1.0` to `1.0`.
+    pub fn new(samples: &'a [f32]) -> Self {
+        Self {
+            samples,
+            desired_size: None,
+            color: None,
+            playhead: None,
+        }
+    }
+
+    /// Default: the available width, and a height of 64.
+    pub fn desired_size(mut self, desired_size: impl Into<Vec2>) -> Self {
+        self.desired_size = Some(desired_size.into());
+        self
+    }
+
+    /// Default: the selection color ([`style::Selection::bg_fill`]).
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Show a line at this sample, e.g. where playback is.
+    pub fn playhead(mut self, sample: usize) -> Self {
+        self.playhead = Some(sample);
+        self
+    }
+}
+
+impl<'a> Widget for Waveform<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Waveform {
+            samples,
+            desired_size,
+            color,
+            playhead,
+        } = self;
+
+        let desired_size =
+            desired_size.unwrap_or_else(|| vec2(ui.available_size_before_wrap_finite().x, 64.0));
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        if !ui.clip_rect().intersects(rect) || samples.is_empty() {
+            return response;
+        }
+
+        let color = color.unwrap_or(ui.visuals().selection.bg_fill);
+        let pixels_per_point = ui.ctx().pixels_per_point();
+        let num_columns = (rect.width() * pixels_per_point).round().max(1.0) as usize;
+        let column_width = rect.width() / num_columns as f32;
+        let y = |sample: f32| rect.center().y - 0.5 * rect.height() * sample.clamp(-1.0, 1.0);
+
+        let mut mesh = epaint::Mesh::default();
+        mesh.add_colored_rect(
+            Rect::from_x_y_ranges(
+                rect.x_range(),
+                rect.center().y..=rect.center().y + 1.0 / pixels_per_point,
+            ),
+            ui.visuals().weak_text_color(),
+        );
+        for (column, (min, max)) in min_max_columns(samples, num_columns)
+            .into_iter()
+            .enumerate()
+        {
+            let left = rect.left() + column as f32 * column_width;
+            // At least one pixel high, so silence still shows:
+            let bottom = y(min).max(y(max) + 1.0 / pixels_per_point);
+            mesh.add_colored_rect(
+                Rect::from_x_y_ranges(left..=left + column_width, y(max)..=bottom),
+                color,
+            );
+        }
+        if let Some(playhead) = playhead {
+            let x = rect.left() + rect.width() * playhead as f32 / samples.len() as f32;
+            mesh.add_colored_rect(
+                Rect::from_x_y_ranges(x..=x + 1.0, rect.y_range()),
+                ui.visuals().strong_text_color(),
+            );
+        }
+        ui.painter().add(Shape::mesh(mesh));
+
+        response
+    }
+}
+
+/// The minimum and maximum of the samples in each of `num_columns` equal parts.
+/// With fewer samples than columns, neighboring columns show the same sample.
+fn min_max_columns(samples: &[f32], num_columns: usize) -> Vec<(f32, f32)> {
+    (0..num_columns)
+        .map(|column| {
+            let start = column * samples.len() / num_columns;
+            let end = ((column + 1) * samples.len() / num_columns).max(start + 1);
+            let chunk = &samples[start.min(samples.len() - 1)..end.min(samples.len())];
+            chunk
+                .iter()
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &sample| {
+                    (min.min(sample), max.max(sample))
+                })
+        })
+        .collect()
+}
+
+#[test]
+fn test_audio_widgets() {
+    let samples = [0.0, 0.5, -0.5, 1.0, -1.0, 0.25];
+    assert_eq!(
+        min_max_columns(&samples, 3),
+        vec![(0.0, 0.5), (-0.5, 1.0), (-1.0, 0.25)]
+    );
+    assert_eq!(min_max_columns(&[0.5], 2), vec![(0.5, 0.5), (0.5, 0.5)]);
+    assert_eq!(amplitude_to_db(1.0), 0.0);
+
+    // The peak line falls back slowly, and the clip indicator stays lit until clicked:
+    use crate::test_util::{click, run_frame_with_input};
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |time: f64, peak: f32, events: Vec<Event>| {
+        let input = RawInput {
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        let ((rect, state), output) = run_frame_with_input(&mut ctx, input, |ctx| {
+            let (response, id) = CentralPanel::default()
+                .show(ctx, |ui| {
+                    let response = ui.add(LevelMeter::new(peak).id_source("meter"));
+                    (response, ui.make_persistent_id(Id::new("meter")))
+                })
+                .inner;
+            let state = *ctx
+                .memory()
+                .id_data_temp
+                .get::<LevelMeterState>(&id)
+                .unwrap();
+            (response.rect, state)
+        });
+        (rect, state, output.needs_repaint)
+    };
+    run_frame(0.0, 1.0, vec![]);
+    let (rect, state, needs_repaint) = run_frame(0.05, 0.1, vec![]);
+    assert!(state.clipped && needs_repaint);
+    assert!((state.held_peak_db - -1.0).abs() < 0.01); // 20 dB/s for 0.05 s
+    assert!(!run_frame(0.1, 0.1, click(rect.center())).1.clipped);
+}
@@ -1,6 +1,6 @@
 use crate::*;
 
-/// Clickable button with text.
+/// Clickable button with text, and optionally an image or icon.
 ///
 /// See also [`Ui::button`].
 ///
@@ -9,11 +9,22 @@ use crate::*;
 /// if ui.add(egui::Button::new("Click mew")).clicked() {
 ///     do_stuff();
 /// }
+///
+/// if ui.add(egui::Button::new("Save").icon("💾")).clicked() {
+///     do_stuff();
+/// }
+///
+/// # let texture_id = egui::TextureId::Egui;
+/// if ui.add(egui::Button::image_and_text(texture_id, [16.0, 16.0], "Open")).clicked() {
+///     do_stuff();
+/// }
 /// # fn do_stuff() {}
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Button {
     text: String,
+    image: Option<ButtonImage>,
+    tint_image: bool,
     text_color: Option<Color32>,
     text_style: Option<TextStyle>,
     /// None means default for interact
@@ -24,6 +35,7 @@ pub struct Button {
     frame: Option<bool>,
     wrap: Option<bool>,
     min_size: Vec2,
+    style: Option<style::WidgetStyleFn<'static>>,
 }
 
 impl Button {
@@ -31,6 +43,8 @@ impl Button {
     pub fn new(text: impl ToString) -> Self {
         Self {
             text: text.to_string(),
+            image: None,
+            tint_image: false,
             text_color: None,
             text_style: None,
             fill: None,
@@ -40,9 +54,46 @@ impl Button {
             frame: None,
             wrap: None,
             min_size: Vec2::ZERO,
+            style: None,
         }
     }
 
+    /// A button with an image to the left of the text. Use an empty text for just the image.
+    pub fn image_and_text(
+        texture_id: TextureId,
+        size: impl Into<Vec2>,
+        text: impl ToString,
+    ) -> Self {
+        Self::new(text).image(texture_id, size)
+    }
+
+    /// Show an image to the left of the text.
+    ///
+    /// The button is sized to fit both, and a click on the image is a click on the button.
+    pub fn image(mut self, texture_id: TextureId, size: impl Into<Vec2>) -> Self {
+        self.image = Some(ButtonImage::Texture {
+            texture_id,
+            size: size.into(),
+        });
+        self
+    }
+
+    /// Show an icon glyph, e.g. an emoji like `"💾"`, to the left of the text, in the color of the text.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn icon(mut self, icon: impl ToString) -> Self {
+        self.image = Some(ButtonImage::Icon(icon.to_string()));
+        self
+    }
+
+    /// Tint the [image](Self::image) with the text color,
+    /// so that it changes along with the text when the button is hovered or pressed.
+    /// Useful for white images, e.g. monochrome icons.
+    /// Default: `false`.
+    pub fn tint_image(mut self, tint_image: bool) -> Self {
+        self.tint_image = tint_image;
+        self
+    }
+
     pub fn text_color(mut self, text_color: Color32) -> Self {
         self.text_color = Some(text_color);
         self
@@ -116,6 +167,30 @@ impl Button {
         self
     }
 
+    /// Tweak the look of just this button, e.g. to make a red "Delete" button.
+    ///
+    /// `style` is called with the visuals for the current interaction state
+    /// (inactive, hovered, active…), so any changes apply in all states.
+    /// [`Self::fill`], [`Self::stroke`] and [`Self::text_color`] take precedence over this.
+    ///
+    /// Unlike [`crate::Slider::style`], the closure can't borrow, so `Button` doesn't need a lifetime:
+    /// `move` in what it needs instead.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let delete = egui::Button::new("Delete").style(|s| {
+    ///     s.bg_fill = egui::Color32::from_rgb(160, 30, 30);
+    ///     s.fg_stroke.color = egui::Color32::WHITE;
+    /// });
+    /// if ui.add(delete).clicked() {
+    ///     /* … */
+    /// }
+    /// ```
+    pub fn style(mut self, style: impl Fn(&mut style::WidgetVisuals) + 'static) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
     pub(crate) fn min_size(mut self, min_size: Vec2) -> Self {
         self.min_size = min_size;
         self
@@ -126,6 +201,8 @@ impl Button {
     fn enabled_ui(self, ui: &mut Ui) -> Response {
         let Button {
             text,
+            image,
+            tint_image,
             text_color,
             text_style,
             fill,
@@ -135,6 +212,7 @@ impl Button {
             frame,
             wrap,
             min_size,
+            style,
         } = self;
 
         let frame = frame.unwrap_or_else(|| ui.visuals().button_frame);
@@ -149,15 +227,38 @@ impl Button {
         }
         let total_extra = button_padding + button_padding;
 
+        let image = image.map(|image| match image {
+            ButtonImage::Texture { texture_id, size } => (Some(texture_id), size, None),
+            ButtonImage::Icon(icon) => {
+                let galley = ui.fonts().layout_no_wrap(text_style, icon);
+                (None, galley.size, Some(galley))
+            }
+        });
+        let image_size = image.as_ref().map_or(Vec2::ZERO, |(_, size, _)| *size);
+        let image_spacing = if image.is_some() && !text.is_empty() {
+            ui.spacing().icon_spacing
+        } else {
+            0.0
+        };
+
         let wrap = wrap.unwrap_or_else(|| ui.wrap_text());
         let galley = if wrap {
-            ui.fonts()
-                .layout_multiline(text_style, text, ui.available_width() - total_extra.x)
+            let wrap_width = ui.available_width() - total_extra.x - image_size.x - image_spacing;
+            ui.fonts().layout_multiline(text_style, text, wrap_width)
         } else {
             ui.fonts().layout_no_wrap(text_style, text)
         };
+        let text_size = if galley.text.is_empty() && image.is_some() {
+            Vec2::ZERO
+        } else {
+            galley.size
+        };
+        let content_size = vec2(
+            image_size.x + image_spacing + text_size.x,
+            image_size.y.max(text_size.y),
+        );
 
-        let mut desired_size = galley.size + 2.0 * button_padding;
+        let mut desired_size = content_size + 2.0 * button_padding;
         if !small {
             desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
         }
@@ -167,11 +268,13 @@ impl Button {
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, &galley.text));
 
         if ui.clip_rect().intersects(rect) {
-            let visuals = ui.style().interact(&response);
-            let text_pos = ui
+            let mut visuals = ui.style().interact(&response);
+            if let Some(style) = &style {
+                style(&mut visuals);
+            }
+            let content_rect = ui
                 .layout()
-                .align_size_within_rect(galley.size, rect.shrink2(button_padding))
-                .min;
+                .align_size_within_rect(content_size, rect.shrink2(button_padding));
 
             if frame {
                 let fill = fill.unwrap_or(visuals.bg_fill);
@@ -187,6 +290,31 @@ impl Button {
             let text_color = text_color
                 .or(ui.visuals().override_text_color)
                 .unwrap_or_else(|| visuals.text_color());
+
+            if let Some((texture_id, image_size, icon_galley)) = image {
+                let image_rect =
+                    Align2::LEFT_CENTER.align_size_within_rect(image_size, content_rect);
+                if let Some(icon_galley) = icon_galley {
+                    ui.painter().galley(image_rect.min, icon_galley, text_color);
+                } else if let Some(texture_id) = texture_id {
+                    let tint = if tint_image {
+                        text_color
+                    } else {
+                        Color32::WHITE
+                    };
+                    widgets::Image::new(texture_id, image_size)
+                        .tint(tint)
+                        .paint_at(ui, image_rect);
+                }
+            }
+
+            let text_rect = Rect::from_x_y_ranges(
+                content_rect.left() + image_size.x + image_spacing..=content_rect.right(),
+                content_rect.y_range(),
+            );
+            let text_pos = Align2::LEFT_CENTER
+                .align_size_within_rect(galley.size, text_rect)
+                .min;
             ui.painter().galley(text_pos, galley, text_color);
         }
 
@@ -194,6 +322,12 @@ impl Button {
     }
 }
 
+/// The image of a [`Button`], see [`Button::image`] and [`Button::icon`].
+enum ButtonImage {
+    Texture { texture_id: TextureId, size: Vec2 },
+    Icon(String),
+}
+
 impl Widget for Button {
     fn ui(self, ui: &mut Ui) -> Response {
         let button_enabled = self.sense != Sense::hover();
@@ -224,26 +358,72 @@ impl Widget for Button {
 /// ui.checkbox(&mut my_bool, "Checked");
 /// ui.add(egui::Checkbox::new(&mut my_bool, "Checked"));
 /// ```
+///
+/// A checkbox can also be in a third, indeterminate state, painted as a dash.
+/// See [`Self::indeterminate`] and [`Self::tri_state`].
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 #[derive(Debug)]
 pub struct Checkbox<'a> {
-    checked: &'a mut bool,
+    checked: CheckboxValue<'a>,
+    indeterminate: bool,
     text: String,
     text_color: Option<Color32>,
     text_style: Option<TextStyle>,
 }
 
+#[derive(Debug)]
+enum CheckboxValue<'a> {
+    Bool(&'a mut bool),
+    /// `None` is indeterminate.
+    TriState(&'a mut Option<bool>),
+}
+
 impl<'a> Checkbox<'a> {
     #[allow(clippy::needless_pass_by_value)]
     pub fn new(checked: &'a mut bool, text: impl ToString) -> Self {
         Checkbox {
-            checked,
+            checked: CheckboxValue::Bool(checked),
+            indeterminate: false,
+            text: text.to_string(),
+            text_color: None,
+            text_style: None,
+        }
+    }
+
+    /// A checkbox where `None` is the indeterminate state.
+    ///
+    /// Clicking an indeterminate checkbox checks it.
+    /// After that, clicking toggles it between checked and unchecked,
+    /// so only your code can make it indeterminate again.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn tri_state(checked: &'a mut Option<bool>, text: impl ToString) -> Self {
+        Checkbox {
+            checked: CheckboxValue::TriState(checked),
+            indeterminate: false,
             text: text.to_string(),
             text_color: None,
             text_style: None,
         }
     }
 
+    /// Show the checkbox as neither checked nor unchecked, e.g. for a "select all" checkbox
+    /// when only some of the items are selected. Clicking it checks it.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let mut selected = [true, false, true];
+    /// let mut all = selected.iter().all(|&s| s);
+    /// let some = !all && selected.iter().any(|&s| s);
+    /// let response = ui.add(egui::Checkbox::new(&mut all, "Select all").indeterminate(some));
+    /// if response.changed() {
+    ///     selected = [all; 3];
+    /// }
+    /// ```
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
     pub fn text_color(mut self, text_color: Color32) -> Self {
         self.text_color = Some(text_color);
         self
@@ -259,6 +439,7 @@ impl<'a> Widget for Checkbox<'a> {
     fn ui(self, ui: &mut Ui) -> Response {
         let Checkbox {
             checked,
+            indeterminate,
             text,
             text_color,
             text_style,
@@ -286,11 +467,30 @@ impl<'a> Widget for Checkbox<'a> {
         desired_size.y = desired_size.y.max(icon_width);
         let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
 
-        if response.clicked() {
-            *checked = !*checked;
+        let clicked = response.clicked();
+        if clicked {
             response.mark_changed();
         }
-        response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, *checked, &galley.text));
+        // `None` is indeterminate:
+        let state = match checked {
+            CheckboxValue::Bool(checked) => {
+                if clicked {
+                    // Indeterminate goes to checked:
+                    *checked = indeterminate || !*checked;
+                }
+                Some(*checked).filter(|_| clicked || !indeterminate)
+            }
+            CheckboxValue::TriState(checked) => {
+                if clicked {
+                    *checked = Some(*checked != Some(true));
+                }
+                *checked
+            }
+        };
+        response.widget_info(|| match state {
+            Some(checked) => WidgetInfo::selected(WidgetType::Checkbox, checked, &galley.text),
+            None => WidgetInfo::labeled(WidgetType::Checkbox, &galley.text),
+        });
 
         // let visuals = ui.style().interact_selectable(&response, *checked); // too colorful
         let visuals = ui.style().interact(&response);
@@ -306,16 +506,30 @@ impl<'a> Widget for Checkbox<'a> {
             stroke: visuals.bg_stroke,
         });
 
-        if *checked {
-            // Check mark:
-            ui.painter().add(Shape::line(
-                vec![
-                    pos2(small_icon_rect.left(), small_icon_rect.center().y),
-                    pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
-                    pos2(small_icon_rect.right(), small_icon_rect.top()),
-                ],
-                visuals.fg_stroke,
-            ));
+        match state {
+            Some(true) => {
+                // Check mark:
+                ui.painter().add(Shape::line(
+                    vec![
+                        pos2(small_icon_rect.left(), small_icon_rect.center().y),
+                        pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
+                        pos2(small_icon_rect.right(), small_icon_rect.top()),
+                    ],
+                    visuals.fg_stroke,
+                ));
+            }
+            None => {
+                // Dash:
+                let y = small_icon_rect.center().y;
+                ui.painter().line_segment(
+                    [
+                        pos2(small_icon_rect.left(), y),
+                        pos2(small_icon_rect.right(), y),
+                    ],
+                    visuals.fg_stroke,
+                );
+            }
+            Some(false) => {}
         }
 
         let text_color = text_color
@@ -539,3 +753,54 @@ impl Widget for ImageButton {
         response
     }
 }
+
+#[test]
+fn test_tri_state_checkbox() {
+    use crate::test_util::{click, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>, checked: &mut Option<bool>| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            ui.add(Checkbox::tri_state(checked, "Tri-state"))
+        })
+    };
+
+    let mut checked = None;
+    let pos = run_frame(vec![], &mut checked).rect.left_center() + vec2(8.0, 0.0);
+    for &expected in &[Some(true), Some(false), Some(true)] {
+        assert!(run_frame(click(pos), &mut checked).changed());
+        assert_eq!(checked, expected);
+    }
+}
+
+#[test]
+fn test_button_with_image() {
+    use crate::test_util::{click, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            (
+                ui.add(Button::new("Open")),
+                ui.add(Button::image_and_text(
+                    TextureId::Egui,
+                    [20.0, 30.0],
+                    "Open",
+                )),
+                ui.add(Button::image_and_text(TextureId::Egui, [20.0, 30.0], "")),
+            )
+        })
+    };
+
+    let spacing = style::Spacing::default();
+    let (padding, icon_spacing) = (spacing.button_padding, spacing.icon_spacing);
+    let (text_only, with_image, image_only) = run_frame(vec![]);
+    assert_eq!(
+        with_image.rect.width(),
+        text_only.rect.width() + 20.0 + icon_spacing
+    );
+    assert_eq!(with_image.rect.height(), 30.0 + 2.0 * padding.y);
+    assert_eq!(image_only.rect.size(), vec2(20.0, 30.0) + 2.0 * padding);
+
+    // A click on the image is a click on the button:
+    let pos = with_image.rect.left_center() + vec2(padding.x + 10.0, 0.0);
+    assert!(run_frame(click(pos)).1.clicked());
+}
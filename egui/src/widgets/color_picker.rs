@@ -17,7 +17,7 @@ fn contrast_color(color: impl Into<Rgba>) -> Color32 {
 /// Should always be a multiple of 6 to hit the peak hues in HSV/HSL (every 60°).
 const N: u32 = 6 * 6;
 
-fn background_checkers(painter: &Painter, rect: Rect) {
+pub(crate) fn background_checkers(painter: &Painter, rect: Rect) {
     let rect = rect.shrink(0.5); // Small hack to avoid the checkers from peeking through the sides
     if !rect.is_positive() {
         return;
@@ -69,10 +69,10 @@ fn show_hsva(ui: &mut Ui, color: Hsva, desired_size: Vec2) -> Response {
 
 fn color_button(ui: &mut Ui, color: Color32, open: bool) -> Response {
     let size = ui.spacing().interact_size;
-    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+    let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
     response.widget_info(|| WidgetInfo::new(WidgetType::ColorButton));
     let visuals = if open {
-        &ui.visuals().widgets.open
+        ui.visuals().widgets.open
     } else {
         ui.style().interact(&response)
     };
@@ -319,10 +319,426 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
         ui.label("Value / Saturation");
         ui.end_row();
     });
+
+    ui.separator();
+    color_numbers_ui(ui, hsva, alpha);
+    color_hex_ui(ui, hsva, alpha);
+    swatches_ui(ui, hsva, alpha);
+}
+
+/// How the numbers of a color are shown in the color picker.
+/// Shared by all color pickers, so the user only has to pick it once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ColorInputMode {
+    Rgb,
+    Hsv,
+    Hsl,
+}
+
+/// The unmultiplied sRGB channels (0-1, in gamma space), and the alpha, of a color.
+///
+/// This is what design tools mean with RGB, HSV and HSL,
+/// unlike the perceptually even [`HsvaGamma`] of the sliders.
+fn srgba_from_hsvag(hsva: HsvaGamma) -> [f32; 4] {
+    let [r, g, b, a] = Hsva::from(hsva).to_rgba_unmultiplied();
+    [
+        gamma_from_linear(r),
+        gamma_from_linear(g),
+        gamma_from_linear(b),
+        a.abs(),
+    ]
+}
+
+/// The inverse of [`srgba_from_hsvag`].
+///
+/// Keeps the hue, and the sign of the alpha (additive or not), of `old`
+/// where the new color doesn't have them.
+fn hsvag_from_srgba([r, g, b, a]: [f32; 4], old: HsvaGamma) -> HsvaGamma {
+    let rgba = [
+        linear_from_gamma(r),
+        linear_from_gamma(g),
+        linear_from_gamma(b),
+        a,
+    ];
+    let mut hsva = HsvaGamma::from(Hsva::from_rgba_unmultiplied(rgba));
+    if hsva.s == 0.0 || hsva.v == 0.0 {
+        hsva.h = old.h; // gray has no hue
+    }
+    if old.a < 0.0 {
+        hsva.a = old.a; // additive colors have no alpha
+    }
+    hsva
+}
+
+/// Hue, saturation and value (0-1) from sRGB channels (0-1).
+fn hsv_from_rgb([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let range = max - min;
+    let h = if range == 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / range).rem_euclid(6.0) / 6.0
+    } else if max == g {
+        ((b - r) / range + 2.0) / 6.0
+    } else {
+        ((r - g) / range + 4.0) / 6.0
+    };
+    let s = if max == 0.0 { 0.0 } else { range / max };
+    [h, s, max]
+}
+
+/// sRGB channels (0-1) from hue, saturation and value (0-1).
+fn rgb_from_hsv([h, s, v]: [f32; 3]) -> [f32; 3] {
+    let channel = |n: f32| {
+        let k = (n + h * 6.0).rem_euclid(6.0);
+        v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+    [channel(5.0), channel(3.0), channel(1.0)]
+}
+
+/// Hue, saturation and lightness (0-1) from hue, saturation and value (0-1).
+fn hsl_from_hsv([h, s, v]: [f32; 3]) -> [f32; 3] {
+    let l = v * (1.0 - s / 2.0);
+    let s = if l <= 0.0 || l >= 1.0 {
+        0.0
+    } else {
+        (v - l) / l.min(1.0 - l)
+    };
+    [h, s, l]
+}
+
+/// Hue, saturation and value (0-1) from hue, saturation and lightness (0-1).
+fn hsv_from_hsl([h, s, l]: [f32; 3]) -> [f32; 3] {
+    let v = l + s * l.min(1.0 - l);
+    let s = if v <= 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+    [h, s, v]
+}
+
+/// Exact numbers for the color, as RGB (0-255), HSV or HSL.
+fn color_numbers_ui(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
+    let mut mode = ui
+        .memory()
+        .data_temp
+        .get::<ColorInputMode>()
+        .copied()
+        .unwrap_or(ColorInputMode::Rgb);
+    ui.horizontal(|ui| {
+        ui.selectable_value(&mut mode, ColorInputMode::Rgb, "RGB");
+        ui.selectable_value(&mut mode, ColorInputMode::Hsv, "HSV");
+        ui.selectable_value(&mut mode, ColorInputMode::Hsl, "HSL");
+    });
+    ui.memory().data_temp.insert(mode);
+
+    let [r, g, b, a] = srgba_from_hsvag(*hsva);
+    let show_alpha = alpha != Alpha::Opaque && hsva.a >= 0.0;
+
+    ui.horizontal(|ui| {
+        let mut changed = false;
+        let mut rgba = [r, g, b, a];
+        match mode {
+            ColorInputMode::Rgb => {
+                for (value, prefix) in rgba.iter_mut().zip(&["R ", "G ", "B ", "A "]) {
+                    if *prefix == "A " && !show_alpha {
+                        continue;
+                    }
+                    let mut byte = (*value * 255.0).round();
+                    let response = ui.add(
+                        DragValue::new(&mut byte)
+                            .clamp_range(0.0..=255.0)
+                            .max_decimals(0)
+                            .prefix(prefix),
+                    );
+                    if response.changed() {
+                        *value = byte / 255.0;
+                        changed = true;
+                    }
+                }
+            }
+            ColorInputMode::Hsv | ColorInputMode::Hsl => {
+                let is_hsl = mode == ColorInputMode::Hsl;
+                let mut hsv = hsv_from_rgb([r, g, b]);
+                if hsv[1] == 0.0 || hsv[2] == 0.0 {
+                    hsv[0] = hsva.h; // gray has no hue, so keep the one from before
+                }
+                let mut numbers = if is_hsl { hsl_from_hsv(hsv) } else { hsv };
+
+                let mut degrees = numbers[0] * 360.0;
+                if ui
+                    .add(
+                        DragValue::new(&mut degrees)
+                            .clamp_range(0.0..=360.0)
+                            .wrap(true)
+                            .max_decimals(1)
+                            .prefix("H ")
+                            .suffix("°"),
+                    )
+                    .changed()
+                {
+                    numbers[0] = degrees / 360.0;
+                    changed = true;
+                }
+                changed |= percentage_ui(ui, &mut numbers[1], "S ");
+                changed |= percentage_ui(ui, &mut numbers[2], if is_hsl { "L " } else { "V " });
+                if show_alpha {
+                    changed |= percentage_ui(ui, &mut rgba[3], "A ");
+                }
+
+                if changed {
+                    let hsv = if is_hsl {
+                        hsv_from_hsl(numbers)
+                    } else {
+                        numbers
+                    };
+                    let [r, g, b] = rgb_from_hsv(hsv);
+                    rgba = [r, g, b, rgba[3]];
+                    let hue = hsv[0];
+                    *hsva = hsvag_from_srgba(rgba, HsvaGamma { h: hue, ..*hsva });
+                    changed = false; // already applied, with the hue
+                }
+            }
+        }
+        if changed {
+            *hsva = hsvag_from_srgba(rgba, *hsva);
+        }
+    });
+}
+
+/// Edit a value from 0 to 1 as a percentage. Returns `true` on change.
+fn percentage_ui(ui: &mut Ui, value: &mut f32, prefix: &str) -> bool {
+    let mut percent = *value * 100.0;
+    let response = ui.add(
+        DragValue::new(&mut percent)
+            .clamp_range(0.0..=100.0)
+            .max_decimals(1)
+            .prefix(prefix)
+            .suffix("%"),
+    );
+    if response.changed() {
+        *value = percent / 100.0;
+    }
+    response.changed()
 }
 
+/// Parse a color written as `#RRGGBB` or `#RRGGBBAA` (unmultiplied sRGBA, the `#` is optional),
+/// or the shorthands `#RGB` and `#RGBA`.
+fn parse_hex_color(text: &str) -> Option<[u8; 4]> {
+    let hex = text.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .map(|c| c.to_digit(16).unwrap() as u8 * 0x11)
+            .collect(),
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect(),
+        _ => return None,
+    };
+    Some([
+        digits[0],
+        digits[1],
+        digits[2],
+        *digits.get(3).unwrap_or(&255),
+    ])
+}
+
+pub(crate) fn format_hex_color([r, g, b, a]: [u8; 4], with_alpha: bool) -> String {
+    if with_alpha {
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    } else {
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+/// The color as `#rrggbb` (or `#rrggbbaa`) in a text field, for copying from and pasting into.
+fn color_hex_ui(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
+    let with_alpha = alpha != Alpha::Opaque && hsva.a >= 0.0;
+    let srgba = Hsva::from(*hsva).to_srgba_unmultiplied();
+    let hex = format_hex_color(srgba, with_alpha);
+
+    // While the user is typing we keep their text, even when it isn't a valid color (yet):
+    let id = ui.id().with("hex");
+    let mut text = ui
+        .memory()
+        .id_data_temp
+        .get::<String>(&id)
+        .cloned()
+        .unwrap_or_else(|| hex.clone());
+
+    ui.horizontal(|ui| {
+        ui.label("Hex:");
+        let response = ui.add(
+            TextEdit::singleline(&mut text)
+                .id(id)
+                .desired_width(ui.spacing().interact_size.x * 1.5)
+                .hint_text("#rrggbb"),
+        );
+        if response.changed() {
+            if let Some([r, g, b, a]) = parse_hex_color(&text) {
+                let a = if with_alpha { a } else { srgba[3] };
+                let rgba = [r, g, b, a];
+                let rgba = [
+                    rgba[0] as f32 / 255.0,
+                    rgba[1] as f32 / 255.0,
+                    rgba[2] as f32 / 255.0,
+                    rgba[3] as f32 / 255.0,
+                ];
+                *hsva = hsvag_from_srgba(rgba, *hsva);
+            }
+        }
+        if response.has_focus() {
+            ui.memory().id_data_temp.insert(id, text);
+        } else {
+            ui.memory().id_data_temp.remove(&id);
+        }
+
+        if ui.button("📋").on_hover_text("Click to copy").clicked() {
+            ui.output().copied_text = hex;
+        }
+    });
+}
+
+// ----------------------------------------------------------------------------
+
+/// The colors set with [`set_palette`].
+#[derive(Clone, Debug, Default)]
+struct Palette(Vec<Color32>);
+
+/// The colors picked most recently, newest first.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+struct RecentColors(Vec<Color32>);
+
+/// How many colors [`recent_colors`] remembers.
+const MAX_RECENT_COLORS: usize = 12;
+
+/// Offer these colors as swatches to click in every color picker, e.g. the colors of your app's theme.
+///
+/// You only need to call this once, or whenever the palette changes.
+pub fn set_palette(ctx: &Context, palette: Vec<Color32>) {
+    ctx.memory().data_temp.insert(Palette(palette));
+}
+
+/// The colors the user picked most recently, newest first.
+///
+/// They are shown as swatches to click in every color picker,
+/// and are stored in [`Memory`], so they are remembered with the `persistence` feature.
+/// A color counts as picked when the color picker popup is closed after changing it.
+pub fn recent_colors(ctx: &Context) -> Vec<Color32> {
+    ctx.memory()
+        .data
+        .get::<RecentColors>()
+        .map(|recent| recent.0.clone())
+        .unwrap_or_default()
+}
+
+/// Forget the [`recent_colors`].
+pub fn clear_recent_colors(ctx: &Context) {
+    ctx.memory().data.remove::<RecentColors>();
+}
+
+fn add_recent_color(ctx: &Context, color: Color32) {
+    let mut memory = ctx.memory();
+    let recent = &mut memory.data.get_mut_or_default::<RecentColors>().0;
+    recent.retain(|&recent_color| recent_color != color);
+    recent.insert(0, color);
+    recent.truncate(MAX_RECENT_COLORS);
+}
+
+/// A color being dragged from a color button, until the mouse button is released.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DraggedColor(pub Color32);
+
+/// The color the user is dragging from a color button, if any.
+///
+/// Use this to make your own drop targets for colors:
+/// check it on the frame the mouse button is released (see [`crate::PointerState::any_released`])
+/// while the pointer is over your target.
+/// [`crate::PaletteGrid`] is such a drop target.
+pub fn dragged_color(ctx: &Context) -> Option<Color32> {
+    ctx.memory()
+        .data_temp
+        .get::<DraggedColor>()
+        .map(|dragged| dragged.0)
+}
+
+/// Start or keep dragging `color`, showing it under the mouse cursor.
+fn drag_color(ui: &mut Ui, color: Color32) {
+    ui.memory().data_temp.insert(DraggedColor(color));
+    ui.output().cursor_icon = CursorIcon::Grabbing;
+    if let Some(pointer) = ui.input().pointer.interact_pos() {
+        let layer_id = LayerId::new(Order::Tooltip, Id::new("__dragged_color"));
+        let rect = Rect::from_min_size(pointer, ui.spacing().interact_size);
+        let painter = ui.ctx().layer_painter(layer_id);
+        background_checkers(&painter, rect);
+        painter.rect(rect, 2.0, color, ui.visuals().widgets.active.fg_stroke);
+    }
+}
+
+/// A small square showing a color, for clicking.
+pub(crate) fn swatch(ui: &mut Ui, color: Color32) -> Response {
+    let size = Vec2::splat(ui.spacing().interact_size.y);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+    response.widget_info(|| WidgetInfo::new(WidgetType::ColorButton));
+    let visuals = ui.style().interact(&response);
+    let rect = rect.expand(visuals.expansion);
+    background_checkers(ui.painter(), rect);
+    ui.painter().rect_filled(rect, 0.0, color);
+    ui.painter().rect_stroke(rect, 0.0, visuals.bg_stroke);
+    response
+}
+
+/// The palette and the recent colors, if any, as rows of swatches to click.
+fn swatches_ui(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
+    let palette = ui
+        .memory()
+        .data_temp
+        .get::<Palette>()
+        .map(|palette| palette.0.clone())
+        .unwrap_or_default();
+    let recent = recent_colors(ui.ctx());
+    if palette.is_empty() && recent.is_empty() {
+        return;
+    }
+
+    ui.separator();
+    crate::Grid::new("color_picker_swatches").show(ui, |ui| {
+        for (label, colors) in &[("Palette", palette), ("Recent", recent)] {
+            if colors.is_empty() {
+                continue;
+            }
+            ui.label(*label);
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing.x = ui.spacing().item_spacing.y;
+                for &color in colors {
+                    let srgba = Hsva::from(color).to_srgba_unmultiplied();
+                    let hex = format_hex_color(srgba, srgba[3] < 255);
+                    if swatch(ui, color).on_hover_text(hex).clicked() {
+                        let mut new_hsva = HsvaGamma::from(color);
+                        if alpha == Alpha::Opaque {
+                            new_hsva.a = 1.0;
+                        }
+                        if new_hsva.s == 0.0 || new_hsva.v == 0.0 {
+                            new_hsva.h = hsva.h; // gray has no hue
+                        }
+                        *hsva = new_hsva;
+                    }
+                }
+            });
+            ui.end_row();
+        }
+    });
+}
+
+// ----------------------------------------------------------------------------
+
 /// return true on change
-fn color_picker_hsva_2d(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> bool {
+pub(crate) fn color_picker_hsva_2d(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> bool {
     let mut hsvag = HsvaGamma::from(*hsva);
     color_picker_hsvag_2d(ui, &mut hsvag, alpha);
     let new_hasva = Hsva::from(hsvag);
@@ -337,8 +753,19 @@ fn color_picker_hsva_2d(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> bool {
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
     let pupup_id = ui.auto_id_with("popup");
     let open = ui.memory().is_popup_open(pupup_id);
-    let mut button_response =
-        color_button(ui, (*hsva).into(), open).on_hover_text("Click to edit color");
+
+    // The color was changed in the popup, and the popup has been closed since:
+    let changed_id = pupup_id.with("changed");
+    if !open && ui.memory().id_data_temp.get::<bool>(&changed_id).is_some() {
+        ui.memory().id_data_temp.remove(&changed_id);
+        add_recent_color(ui.ctx(), Color32::from(*hsva));
+    }
+
+    let mut button_response = color_button(ui, (*hsva).into(), open)
+        .on_hover_text("Click to edit color, or drag it somewhere");
+    if button_response.dragged() {
+        drag_color(ui, (*hsva).into());
+    }
 
     if button_response.clicked() {
         ui.memory().toggle_popup(pupup_id);
@@ -353,6 +780,7 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
                 Frame::popup(ui.style()).show(ui, |ui| {
                     if color_picker_hsva_2d(ui, hsva, alpha) {
                         button_response.mark_changed();
+                        ui.memory().id_data_temp.insert(changed_id, true);
                     }
                 });
             });
@@ -367,6 +795,40 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
     button_response
 }
 
+/// Shows a button with an HDR color in linear RGB space, where the channels may be above 1.
+///
+/// The button picks the color itself (at most 1), and the [`DragValue`] next to it
+/// the intensity the color is multiplied by.
+pub fn color_edit_button_rgb_hdr(ui: &mut Ui, rgb: &mut [f32; 3]) -> Response {
+    let hsva = Hsva::from_rgb(*rgb);
+    let mut intensity = hsva.v.at_least(1.0);
+    let mut base = Hsva {
+        v: hsva.v / intensity,
+        ..hsva
+    };
+
+    ui.horizontal(|ui| {
+        let mut response = color_edit_button_hsva(ui, &mut base, Alpha::Opaque);
+        response |= ui
+            .add(
+                DragValue::new(&mut intensity)
+                    .speed(0.01)
+                    .clamp_range(1.0..=f32::INFINITY)
+                    .prefix("×"),
+            )
+            .on_hover_text("Intensity. Above 1 makes this an HDR color.");
+        if response.changed() {
+            *rgb = Hsva {
+                v: base.v * intensity,
+                ..base
+            }
+            .to_rgb();
+        }
+        response
+    })
+    .inner
+}
+
 /// Shows a button with the given color.
 /// If the user clicks the button, a full color picker is shown.
 pub fn color_edit_button_srgba(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> Response {
@@ -394,3 +856,62 @@ pub fn color_edit_button_srgba(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -
 
     response
 }
+
+#[test]
+fn test_color_numbers() {
+    assert_eq!(parse_hex_color("#ff8000"), Some([255, 128, 0, 255]));
+    assert_eq!(parse_hex_color(" FF800080 "), Some([255, 128, 0, 128]));
+    assert_eq!(parse_hex_color("#f80"), Some([255, 136, 0, 255]));
+    assert_eq!(parse_hex_color("#ff80"), Some([255, 255, 136, 0]));
+    assert_eq!(parse_hex_color("#ff800"), None);
+    assert_eq!(parse_hex_color("#gg8000"), None);
+    assert_eq!(format_hex_color([255, 128, 0, 128], false), "#ff8000");
+    assert_eq!(format_hex_color([255, 128, 0, 128], true), "#ff800080");
+
+    let close = |a: [f32; 3], b: [f32; 3]| a.iter().zip(&b).all(|(a, b)| (a - b).abs() < 1e-5);
+    // Orange: hsv(30°, 100%, 100%), hsl(30°, 100%, 50%)
+    let orange = [1.0, 0.5, 0.0];
+    assert!(close(hsv_from_rgb(orange), [30.0 / 360.0, 1.0, 1.0]));
+    assert!(close(
+        hsl_from_hsv(hsv_from_rgb(orange)),
+        [30.0 / 360.0, 1.0, 0.5]
+    ));
+    assert!(close(
+        rgb_from_hsv(hsv_from_hsl([30.0 / 360.0, 1.0, 0.5])),
+        orange
+    ));
+    // Magenta, where the hue wraps around:
+    let pink = [1.0, 0.0, 0.5];
+    assert!(close(rgb_from_hsv(hsv_from_rgb(pink)), pink));
+
+    let old = HsvaGamma::from(Color32::RED);
+    let hsva = hsvag_from_srgba([1.0, 0.5, 0.0, 0.5], old);
+    let srgba = srgba_from_hsvag(hsva);
+    assert!(close([srgba[0], srgba[1], srgba[2]], orange) && (srgba[3] - 0.5).abs() < 1e-5);
+    // Gray keeps the old hue:
+    let gray = hsvag_from_srgba([0.5, 0.5, 0.5, 1.0], hsva);
+    assert_eq!(gray.h, hsva.h);
+}
+
+#[test]
+fn test_recent_colors() {
+    let ctx = CtxRef::default();
+    for i in 0..20 {
+        add_recent_color(&ctx, Color32::from_gray(i));
+    }
+    add_recent_color(&ctx, Color32::from_gray(15));
+    let recent = recent_colors(&ctx);
+    assert_eq!(recent.len(), MAX_RECENT_COLORS);
+    assert_eq!(recent[0], Color32::from_gray(15));
+    assert_eq!(recent[1], Color32::from_gray(19));
+    assert_eq!(
+        recent
+            .iter()
+            .filter(|&&c| c == Color32::from_gray(15))
+            .count(),
+        1
+    );
+
+    clear_recent_colors(&ctx);
+    assert!(recent_colors(&ctx).is_empty());
+}
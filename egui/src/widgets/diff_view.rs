@@ -0,0 +1,438 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::util::diff::{diff, DiffOp};
+use crate::*;
+
+/// Removed lines, and the changed parts of them.
+const REMOVED: Color32 = Color32::from_rgba_premultiplied(50, 0, 0, 50);
+const REMOVED_STRONG: Color32 = Color32::from_rgba_premultiplied(110, 0, 0, 110);
+/// Added lines, and the changed parts of them.
+const ADDED: Color32 = Color32::from_rgba_premultiplied(0, 40, 0, 40);
+const ADDED_STRONG: Color32 = Color32::from_rgba_premultiplied(0, 90, 0, 90);
+
+/// A row of the diff: a line of the old text, the new text, or both.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Row {
+    old: Option<usize>,
+    new: Option<usize>,
+    /// The lines are the same.
+    same: bool,
+}
+
+/// The lines of both texts, and how they line up.
+#[derive(Default)]
+struct Diffed {
+    old: Vec<String>,
+    new: Vec<String>,
+    /// The chars that changed in a line that was changed only a little.
+    old_emphasis: Vec<Vec<Range<usize>>>,
+    new_emphasis: Vec<Vec<Range<usize>>>,
+    /// Changed lines are next to each other.
+    side_by_side: Vec<Row>,
+    /// Removed lines come before the added lines that replace them.
+    unified: Vec<Row>,
+}
+
+impl Diffed {
+    fn new(old: &str, new: &str) -> Self {
+        let old: Vec<String> = old.lines().map(expand_tabs).collect();
+        let new: Vec<String> = new.lines().map(expand_tabs).collect();
+        let mut diffed = Self {
+            old_emphasis: vec![vec![]; old.len()],
+            new_emphasis: vec![vec![]; new.len()],
+            ..Default::default()
+        };
+
+        let (mut i, mut j) = (0, 0);
+        let mut removed = vec![];
+        let mut added = vec![];
+        for op in diff(&old, &new) {
+            match op {
+                DiffOp::Same => {
+                    diffed.add_hunk(&old, &new, &removed, &added);
+                    removed.clear();
+                    added.clear();
+                    let row = Row {
+                        old: Some(i),
+                        new: Some(j),
+                        same: true,
+                    };
+                    diffed.side_by_side.push(row);
+                    diffed.unified.push(row);
+                    i += 1;
+                    j += 1;
+                }
+                DiffOp::Removed => {
+                    removed.push(i);
+                    i += 1;
+                }
+                DiffOp::Added => {
+                    added.push(j);
+                    j += 1;
+                }
+            }
+        }
+        diffed.add_hunk(&old, &new, &removed, &added);
+
+        diffed.old = old;
+        diffed.new = new;
+        diffed
+    }
+
+    /// Some `removed` lines replaced by some `added` lines.
+    fn add_hunk(&mut self, old: &[String], new: &[String], removed: &[usize], added: &[usize]) {
+        for k in 0..removed.len().max(added.len()) {
+            let row = Row {
+                old: removed.get(k).copied(),
+                new: added.get(k).copied(),
+                same: false,
+            };
+            if let (Some(i), Some(j)) = (row.old, row.new) {
+                if let Some((old_emphasis, new_emphasis)) = changed_chars(&old[i], &new[j]) {
+                    self.old_emphasis[i] = old_emphasis;
+                    self.new_emphasis[j] = new_emphasis;
+                }
+            }
+            self.side_by_side.push(row);
+        }
+        for &i in removed {
+            self.unified.push(Row {
+                old: Some(i),
+                new: None,
+                same: false,
+            });
+        }
+        for &j in added {
+            self.unified.push(Row {
+                old: None,
+                new: Some(j),
+                same: false,
+            });
+        }
+    }
+}
+
+fn expand_tabs(line: &str) -> String {
+    line.replace('\t', "    ")
+}
+
+/// Ranges of chars in a line.
+type CharRanges = Vec<Range<usize>>;
+
+/// The ranges of chars that were removed from `old` and added to `new`,
+/// or `None` if the lines are too different for that to be helpful.
+fn changed_chars(old: &str, new: &str) -> Option<(CharRanges, CharRanges)> {
+    let old: Vec<char> = old.chars().collect();
+    let new: Vec<char> = new.chars().collect();
+    let ops = diff(&old, &new);
+    let num_same = ops.iter().filter(|op| **op == DiffOp::Same).count();
+    if 2 * num_same < old.len().max(new.len()) {
+        return None;
+    }
+
+    fn push(ranges: &mut Vec<Range<usize>>, index: usize) {
+        match ranges.last_mut() {
+            Some(range) if range.end == index => range.end += 1,
+            _ => ranges.push(index..index + 1),
+        }
+    }
+
+    let (mut old_ranges, mut new_ranges) = (vec![], vec![]);
+    let (mut i, mut j) = (0, 0);
+    for op in ops {
+        match op {
+            DiffOp::Same => {
+                i += 1;
+                j += 1;
+            }
+            DiffOp::Removed => {
+                push(&mut old_ranges, i);
+                i += 1;
+            }
+            DiffOp::Added => {
+                push(&mut new_ranges, j);
+                j += 1;
+            }
+        }
+    }
+    Some((old_ranges, new_ranges))
+}
+
+#[derive(Clone)]
+struct DiffCache {
+    /// Of the two texts.
+    key: Id,
+    diffed: Arc<Diffed>,
+}
+
+/// Shows the differences between two texts, line by line:
+/// side by side, or unified (the removed lines above the added ones).
+///
+/// Removed and added lines get a red and green background.
+/// Where a line was only changed a little, the changed characters are emphasized.
+/// Both sides scroll together.
+///
+/// The diff is only computed again when the texts change.
+/// It takes time proportional to the number of old times new changed lines,
+/// see [`crate::util::diff::diff`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let old = "width = 100\nheight = 50\n";
+/// let new = "width = 120\nheight = 50\ndepth = 10\n";
+/// egui::DiffView::new(old, new).side_by_side(true).show(ui);
+/// ```
+#[must_use = "You should call .show()"]
+pub struct DiffView<'a> {
+    old: &'a str,
+    new: &'a str,
+    id_source: Option<Id>,
+    side_by_side: bool,
+    line_numbers: bool,
+    max_height: f32,
+}
+
+impl<'a> DiffView<'a> {
+    pub fn new(old: &'a str, new: &'a str) -> Self {
+        Self {
+            old,
+            new,
+            id_source: None,
+            side_by_side: true,
+            line_numbers: true,
+            max_height: 400.0,
+        }
+    }
+
+    /// Needed if you show more than one [`DiffView`] in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Show the old and new text next to each other, or else as one unified diff. Default: `true`.
+    pub fn side_by_side(mut self, side_by_side: bool) -> Self {
+        self.side_by_side = side_by_side;
+        self
+    }
+
+    /// Default: `true`.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// The lines scroll when they are taller than this. Default: 400.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let DiffView {
+            old,
+            new,
+            id_source,
+            side_by_side,
+            line_numbers,
+            max_height,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("diff_view")));
+        let key = Id::new((old, new));
+        let cached = ui.memory().id_data_temp.get::<DiffCache>(&id).cloned();
+        let diffed = match cached {
+            Some(cache) if cache.key == key => cache.diffed,
+            _ => {
+                let diffed = Arc::new(Diffed::new(old, new));
+                let cache = DiffCache {
+                    key,
+                    diffed: diffed.clone(),
+                };
+                ui.memory().id_data_temp.insert(id, cache);
+                diffed
+            }
+        };
+
+        let text_style = TextStyle::Monospace;
+        let char_width = ui.fonts().glyph_width(text_style, '0');
+        let row_height = ui.fonts().row_height(text_style);
+        let row_step = row_height + ui.spacing().item_spacing.y;
+        let number_chars = if line_numbers {
+            diffed.old.len().max(diffed.new.len()).to_string().len()
+        } else {
+            0
+        };
+        let rows = if side_by_side {
+            &diffed.side_by_side
+        } else {
+            &diffed.unified
+        };
+
+        ScrollArea::from_max_height(max_height)
+            .id_source(id.with("scroll"))
+            .show_rows(ui, row_height, rows.len(), |ui, row_range| {
+                let width = ui.available_width();
+                let first_row_top = ui.max_rect().top();
+                let content_top = first_row_top - row_range.start as f32 * row_step;
+                let rect = Rect::from_min_size(
+                    pos2(ui.max_rect().left(), first_row_top),
+                    vec2(width, row_range.len() as f32 * row_step),
+                );
+                let response = ui.allocate_rect(rect, Sense::hover());
+
+                let line = |row: &Row, op: DiffOp| {
+                    let (lines, emphasis, index) = match op {
+                        DiffOp::Added => (&diffed.new, &diffed.new_emphasis, row.new),
+                        _ => (&diffed.old, &diffed.old_emphasis, row.old),
+                    };
+                    index.map(|index| Line {
+                        text: &lines[index],
+                        emphasis: &emphasis[index],
+                        op,
+                    })
+                };
+                let number = |index: Option<usize>| index.map(|index| index + 1);
+
+                let painter = LinePainter {
+                    ui,
+                    char_width,
+                    row_height,
+                    number_chars,
+                };
+                for index in row_range {
+                    let row = &rows[index];
+                    let top = content_top + index as f32 * row_step;
+                    if side_by_side {
+                        let (old_op, new_op) = if row.same {
+                            (DiffOp::Same, DiffOp::Same)
+                        } else {
+                            (DiffOp::Removed, DiffOp::Added)
+                        };
+                        let half = 0.5 * width;
+                        let old_rect =
+                            Rect::from_min_size(pos2(rect.left(), top), vec2(half, row_height));
+                        let new_rect = old_rect.translate(vec2(half, 0.0));
+                        painter.paint(old_rect, &[number(row.old)], line(row, old_op));
+                        painter.paint(new_rect, &[number(row.new)], line(row, new_op));
+                    } else {
+                        let op = match (row.old, row.new) {
+                            (Some(_), Some(_)) => DiffOp::Same,
+                            (Some(_), None) => DiffOp::Removed,
+                            _ => DiffOp::Added,
+                        };
+                        let line_rect =
+                            Rect::from_min_size(pos2(rect.left(), top), vec2(width, row_height));
+                        let numbers = [number(row.old), number(row.new)];
+                        painter.paint(line_rect, &numbers, line(row, op));
+                    }
+                }
+                response
+            })
+    }
+}
+
+struct Line<'l> {
+    text: &'l str,
+    emphasis: &'l [Range<usize>],
+    op: DiffOp,
+}
+
+struct LinePainter<'u> {
+    ui: &'u Ui,
+    char_width: f32,
+    row_height: f32,
+    /// Zero if there are no line numbers.
+    number_chars: usize,
+}
+
+impl<'u> LinePainter<'u> {
+    /// Paint a line (or a gap, if `None`) in `rect`, after columns with the line `numbers`
+    /// (one in a side by side diff, the old and new in a unified diff).
+    fn paint(&self, rect: Rect, numbers: &[Option<usize>], line: Option<Line<'_>>) {
+        let painter = self.ui.painter().sub_region(rect);
+        let visuals = self.ui.visuals();
+        let line = match line {
+            Some(line) => line,
+            None => {
+                painter.rect_filled(rect, 0.0, visuals.faint_bg_color);
+                return;
+            }
+        };
+
+        let (fill, strong_fill, marker) = match line.op {
+            DiffOp::Same => (Color32::TRANSPARENT, Color32::TRANSPARENT, ' '),
+            DiffOp::Removed => (REMOVED, REMOVED_STRONG, '-'),
+            DiffOp::Added => (ADDED, ADDED_STRONG, '+'),
+        };
+
+        let mut gutter = String::new();
+        if self.number_chars > 0 {
+            for number in numbers {
+                match number {
+                    Some(number) => {
+                        gutter += &format!("{:>width$} ", number, width = self.number_chars);
+                    }
+                    None => gutter += &" ".repeat(self.number_chars + 1),
+                }
+            }
+        }
+        gutter.push(marker);
+        gutter.push(' ');
+
+        let text_left = rect.left() + gutter.chars().count() as f32 * self.char_width;
+        if line.op != DiffOp::Same {
+            painter.rect_filled(rect, 0.0, fill);
+            for range in line.emphasis {
+                let emphasis_rect = Rect::from_min_size(
+                    pos2(text_left + range.start as f32 * self.char_width, rect.top()),
+                    vec2(range.len() as f32 * self.char_width, self.row_height),
+                );
+                painter.rect_filled(emphasis_rect, 0.0, strong_fill);
+            }
+        }
+
+        painter.text(
+            rect.left_top(),
+            Align2::LEFT_TOP,
+            gutter,
+            TextStyle::Monospace,
+            visuals.weak_text_color(),
+        );
+        painter.text(
+            pos2(text_left, rect.top()),
+            Align2::LEFT_TOP,
+            line.text,
+            TextStyle::Monospace,
+            visuals.text_color(),
+        );
+    }
+}
+
+#[test]
+fn test_diffed() {
+    let old = "a\nwidth = 100\nb\nc\n";
+    let new = "a\nwidth = 120\nb\nd\ne\n";
+    let diffed = Diffed::new(old, new);
+    let row = |old, new, same| Row { old, new, same };
+    assert_eq!(
+        diffed.side_by_side,
+        vec![
+            row(Some(0), Some(0), true),
+            row(Some(1), Some(1), false),
+            row(Some(2), Some(2), true),
+            row(Some(3), Some(3), false),
+            row(None, Some(4), false),
+        ]
+    );
+    assert_eq!(diffed.unified.len(), 7);
+    assert_eq!(diffed.unified[1], row(Some(1), None, false));
+    assert_eq!(diffed.unified[2], row(None, Some(1), false));
+
+    // Only "0" of "100" and "2" of "120" changed:
+    assert_eq!(diffed.old_emphasis[1], vec![9..10]);
+    assert_eq!(diffed.new_emphasis[1], vec![9..10]);
+    // "c" and "d" have nothing in common:
+    assert!(diffed.old_emphasis[3].is_empty());
+}
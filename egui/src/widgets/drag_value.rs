@@ -39,6 +39,12 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
     (get_set_value)(Some(value));
 }
 
+/// Turns a value into text, given the range of decimals egui would like to show.
+pub(crate) type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
+
+/// Turns text back into a value, or `None` if the text is not valid.
+pub(crate) type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
+
 /// A numeric value that you can change by dragging the number. More compact than a [`Slider`].
 ///
 /// ```
@@ -50,11 +56,19 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 pub struct DragValue<'a> {
     get_set_value: GetSetValue<'a>,
     speed: f64,
+    slow_speed_factor: f64,
+    fast_step_factor: f64,
+    keyboard_step: Option<f64>,
+    page_step: Option<f64>,
     prefix: String,
     suffix: String,
+    units: Vec<(String, f64)>,
     clamp_range: RangeInclusive<f64>,
+    wrap: bool,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    custom_formatter: Option<NumFormatter<'a>>,
+    custom_parser: Option<NumParser<'a>>,
 }
 
 macro_rules! impl_integer_constructor {
@@ -127,11 +141,19 @@ impl<'a> DragValue<'a> {
         Self {
             get_set_value: Box::new(get_set_value),
             speed: 1.0,
+            slow_speed_factor: 0.1,
+            fast_step_factor: 10.0,
+            keyboard_step: None,
+            page_step: None,
             prefix: Default::default(),
             suffix: Default::default(),
+            units: Default::default(),
             clamp_range: f64::NEG_INFINITY..=f64::INFINITY,
+            wrap: false,
             min_decimals: 0,
             max_decimals: None,
+            custom_formatter: None,
+            custom_parser: None,
         }
     }
 
@@ -141,12 +163,63 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// Holding down Shift while dragging multiplies the [`Self::speed`] by this, for fine control.
+    /// The same goes for the keyboard steps.
+    /// Default: `0.1`.
+    pub fn slow_speed_factor(mut self, slow_speed_factor: impl Into<f64>) -> Self {
+        self.slow_speed_factor = slow_speed_factor.into();
+        self
+    }
+
+    /// Holding down Ctrl (Cmd on Mac) while pressing the arrow or page keys multiplies the step by this.
+    /// Default: `10.0`.
+    pub fn fast_step_factor(mut self, fast_step_factor: impl Into<f64>) -> Self {
+        self.fast_step_factor = fast_step_factor.into();
+        self
+    }
+
+    /// How much the value changes for each press of an arrow key while the value has keyboard focus.
+    /// The value is not rounded, so each press moves it by exactly this much.
+    /// Home and end jump to the ends of the [`Self::clamp_range`].
+    /// Default: the same as [`Self::speed`].
+    pub fn keyboard_step(mut self, keyboard_step: impl Into<f64>) -> Self {
+        self.keyboard_step = Some(keyboard_step.into());
+        self
+    }
+
+    /// How much the value changes for each press of page up or page down while the value has keyboard focus.
+    /// Default: ten times the [`Self::keyboard_step`].
+    pub fn page_step(mut self, page_step: impl Into<f64>) -> Self {
+        self.page_step = Some(page_step.into());
+        self
+    }
+
     /// Clamp incoming and outgoing values to this range.
     pub fn clamp_range<Num: emath::Numeric>(mut self, clamp_range: RangeInclusive<Num>) -> Self {
         self.clamp_range = clamp_range.start().to_f64()..=clamp_range.end().to_f64();
         self
     }
 
+    /// Wrap values that go past one end of the [`Self::clamp_range`] around to the other end,
+    /// instead of clamping them. Useful for angles and the like.
+    /// Only has an effect if the clamp range is finite.
+    /// Default: `false`.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut degrees: f32 = 0.0;
+    /// ui.add(
+    ///     egui::DragValue::new(&mut degrees)
+    ///         .clamp_range(0.0..=360.0)
+    ///         .wrap(true)
+    ///         .suffix("°"),
+    /// );
+    /// ```
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
     #[deprecated = "Use clamp_range"]
     pub fn clamp_range_f64(mut self, clamp_range: RangeInclusive<f64>) -> Self {
         self.clamp_range = clamp_range;
@@ -170,6 +243,35 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// Show the value in one of several units, each with how many of the value it takes to make one of that unit.
+    ///
+    /// The value is shown in the biggest unit it has at least one of,
+    /// and decimals and dragging follow that unit.
+    /// You can type a value with any of the units, e.g. "2 cm" or "5mm".
+    /// A number without a unit is taken to be in the unit that is shown.
+    ///
+    /// The units are ignored while you use a [`Self::custom_formatter`] or [`Self::custom_parser`].
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut width_in_pixels: f32 = 100.0;
+    /// ui.add(egui::DragValue::new(&mut width_in_pixels).suffix_units(&[
+    ///     ("px", 1.0),
+    ///     ("mm", 3.78),
+    ///     ("cm", 37.8),
+    /// ]));
+    /// ```
+    pub fn suffix_units(mut self, units: &[(&str, f64)]) -> Self {
+        self.units = units
+            .iter()
+            .filter(|(_, factor)| factor.is_finite() && *factor > 0.0)
+            .map(|(name, factor)| ((*name).to_owned(), *factor))
+            .collect();
+        self.units
+            .sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        self
+    }
+
     // TODO: we should also have a "min precision".
     /// Set a minimum number of decimals to display.
     /// Normally you don't need to pick a precision, as the slider will intelligently pick a precision for you.
@@ -203,6 +305,55 @@ impl<'a> DragValue<'a> {
         self.max_decimals = Some(num_decimals);
         self
     }
+
+    /// Show the value with your own formatting, e.g. as a time or in hexadecimal.
+    ///
+    /// The formatter is given the value and the range of decimals egui would like to show,
+    /// based on the drag speed and [`Self::min_decimals`]/[`Self::max_decimals`].
+    /// The [`Self::prefix`] and [`Self::suffix`] are still added around the formatted text.
+    ///
+    /// You most likely want a matching [`Self::custom_parser`] too,
+    /// so that users can enter the value in the same format when they click to edit it.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut seconds = 150.0;
+    /// ui.add(
+    ///     egui::DragValue::new(&mut seconds)
+    ///         .clamp_range(0.0..=f64::INFINITY)
+    ///         .custom_formatter(|seconds, _| {
+    ///             let seconds = seconds as u64;
+    ///             format!("{:02}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+    ///         })
+    ///         .custom_parser(|text| {
+    ///             let parts: Vec<&str> = text.split(':').collect();
+    ///             if parts.len() != 3 {
+    ///                 return None;
+    ///             }
+    ///             let mut seconds = 0.0;
+    ///             for part in parts {
+    ///                 seconds = 60.0 * seconds + part.trim().parse::<f64>().ok()?;
+    ///             }
+    ///             Some(seconds)
+    ///         }),
+    /// );
+    /// ```
+    pub fn custom_formatter(
+        mut self,
+        formatter: impl 'a + Fn(f64, RangeInclusive<usize>) -> String,
+    ) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Parse the text the user enters with your own parser, e.g. to allow times or hexadecimal.
+    ///
+    /// Return `None` if the text is not a valid value, and the value is left as it is.
+    /// See [`Self::custom_formatter`] for an example.
+    pub fn custom_parser(mut self, parser: impl 'a + Fn(&str) -> Option<f64>) -> Self {
+        self.custom_parser = Some(Box::new(parser));
+        self
+    }
 }
 
 impl<'a> Widget for DragValue<'a> {
@@ -210,29 +361,66 @@ impl<'a> Widget for DragValue<'a> {
         let Self {
             mut get_set_value,
             speed,
+            slow_speed_factor,
+            fast_step_factor,
+            keyboard_step,
+            page_step,
             clamp_range,
+            wrap,
             prefix,
             suffix,
+            units,
             min_decimals,
             max_decimals,
+            custom_formatter,
+            custom_parser,
         } = self;
 
         let is_slow_speed =
             ui.input().modifiers.shift_only() && ui.memory().is_being_dragged(ui.next_auto_id());
 
         let old_value = get(&mut get_set_value);
-        let value = clamp_to_range(old_value, clamp_range.clone());
+        let value = limit_to_range(old_value, clamp_range.clone(), wrap);
         if old_value != value {
             set(&mut get_set_value, value);
         }
         let aim_rad = ui.input().aim_radius() as f64;
 
-        let auto_decimals = (aim_rad / speed.abs()).log10().ceil().clamp(0.0, 15.0) as usize;
-        let auto_decimals = auto_decimals + is_slow_speed as usize;
+        // The decimals, rounding and smart aim are all in this unit:
+        let unit = if custom_formatter.is_none() && custom_parser.is_none() {
+            pick_unit(&units, value)
+        } else {
+            None
+        };
+        let unit_factor = unit.map_or(1.0, |(_, factor)| factor);
+
+        let auto_decimals = (aim_rad * unit_factor / speed.abs())
+            .log10()
+            .ceil()
+            .clamp(0.0, 15.0) as usize;
+        let auto_decimals = if is_slow_speed {
+            // Show the extra precision we get from dragging slowly:
+            let extra_decimals = (-slow_speed_factor.abs().log10()).ceil().clamp(0.0, 15.0);
+            auto_decimals + extra_decimals as usize
+        } else {
+            auto_decimals
+        };
 
         let max_decimals = max_decimals.unwrap_or(auto_decimals + 2);
         let auto_decimals = auto_decimals.clamp(min_decimals, max_decimals);
-        let value_text = if value == 0.0 {
+        let value_text = if let Some(custom_formatter) = &custom_formatter {
+            custom_formatter(value, auto_decimals..=max_decimals)
+        } else if let Some((unit_name, unit_factor)) = unit {
+            let number = if value == 0.0 {
+                "0".to_owned()
+            } else {
+                emath::format_with_decimals_in_range(
+                    value / unit_factor,
+                    auto_decimals..=max_decimals,
+                )
+            };
+            format!("{} {}", number, unit_name)
+        } else if value == 0.0 {
             "0".to_owned()
         } else {
             emath::format_with_decimals_in_range(value, auto_decimals..=max_decimals)
@@ -255,8 +443,13 @@ impl<'a> Widget for DragValue<'a> {
                     .desired_width(button_width)
                     .text_style(TextStyle::Monospace),
             );
-            if let Ok(parsed_value) = value_text.parse() {
-                let parsed_value = clamp_to_range(parsed_value, clamp_range);
+            let parsed_value = match &custom_parser {
+                Some(custom_parser) => custom_parser(&value_text),
+                None if !units.is_empty() => parse_with_units(&value_text, &units, unit_factor),
+                None => value_text.parse().ok(),
+            };
+            if let Some(parsed_value) = parsed_value {
+                let parsed_value = limit_to_range(parsed_value, clamp_range, wrap);
                 set(&mut get_set_value, parsed_value)
             }
             if ui.input().key_pressed(Key::Enter) {
@@ -273,13 +466,22 @@ impl<'a> Widget for DragValue<'a> {
                 .wrap(false)
                 .min_size(ui.spacing().interact_size); // TODO: find some more generic solution to this
 
+            // Show full precision value on-hover. TODO: figure out f64 vs f32
+            let full_precision_text = if let Some(custom_formatter) = &custom_formatter {
+                custom_formatter(value, auto_decimals..=max_decimals.max(15))
+            } else if let Some((unit_name, unit_factor)) = unit {
+                format!("{} {}", (value / unit_factor) as f32, unit_name)
+            } else {
+                (value as f32).to_string()
+            };
+
             let response = ui.add(button);
             let response = response
                 .on_hover_cursor(CursorIcon::ResizeHorizontal)
                 .on_hover_text(format!(
                     "{}{}{}\nDrag to edit or click to enter a value.\nPress 'Shift' while dragging for better control.",
                     prefix,
-                    value as f32, // Show full precision value on-hover. TODO: figure out f64 vs f32
+                    full_precision_text,
                     suffix
                 ));
 
@@ -292,7 +494,11 @@ impl<'a> Widget for DragValue<'a> {
                 let mdelta = response.drag_delta();
                 let delta_points = mdelta.x - mdelta.y; // Increase to the right and up
 
-                let speed = if is_slow_speed { speed / 10.0 } else { speed };
+                let speed = if is_slow_speed {
+                    speed * slow_speed_factor
+                } else {
+                    speed
+                };
 
                 let delta_value = delta_points as f64 * speed;
 
@@ -308,12 +514,12 @@ impl<'a> Widget for DragValue<'a> {
 
                     let aim_delta = aim_rad * speed;
                     let rounded_new_value = emath::smart_aim::best_in_range_f64(
-                        stored_value - aim_delta,
-                        stored_value + aim_delta,
+                        (stored_value - aim_delta) / unit_factor,
+                        (stored_value + aim_delta) / unit_factor,
                     );
                     let rounded_new_value =
-                        emath::round_to_decimals(rounded_new_value, auto_decimals);
-                    let rounded_new_value = clamp_to_range(rounded_new_value, clamp_range);
+                        emath::round_to_decimals(rounded_new_value, auto_decimals) * unit_factor;
+                    let rounded_new_value = limit_to_range(rounded_new_value, clamp_range, wrap);
                     set(&mut get_set_value, rounded_new_value);
 
                     drag_state.last_dragged_id = Some(response.id);
@@ -321,15 +527,39 @@ impl<'a> Widget for DragValue<'a> {
                     ui.memory().drag_value = drag_state;
                 }
             } else if response.has_focus() {
-                let change = ui.input().num_presses(Key::ArrowUp) as f64
-                    + ui.input().num_presses(Key::ArrowRight) as f64
-                    - ui.input().num_presses(Key::ArrowDown) as f64
-                    - ui.input().num_presses(Key::ArrowLeft) as f64;
-
-                if change != 0.0 {
-                    let new_value = value + speed * change;
-                    let new_value = emath::round_to_decimals(new_value, auto_decimals);
-                    let new_value = clamp_to_range(new_value, clamp_range);
+                let keys = KeyboardSteps::new(
+                    ui.input(),
+                    &[Key::ArrowDown, Key::ArrowLeft],
+                    &[Key::ArrowUp, Key::ArrowRight],
+                    slow_speed_factor,
+                    fast_step_factor,
+                );
+                if keys.any() {
+                    let min = clamp_range.start().min(*clamp_range.end());
+                    let max = clamp_range.start().max(*clamp_range.end());
+                    let step = keyboard_step.unwrap_or(speed);
+                    let page_step = page_step.unwrap_or(10.0 * step);
+                    let new_value = match keys.jump_to_max {
+                        Some(true) if max.is_finite() => max,
+                        Some(false) if min.is_finite() => min,
+                        Some(_) => value,
+                        None => {
+                            let new_value =
+                                value + (keys.steps * step + keys.pages * page_step) * keys.factor;
+                            if keyboard_step.is_some() {
+                                new_value
+                            } else {
+                                // Keep the extra precision of small steps:
+                                let extra_decimals =
+                                    (-keys.factor.abs().log10()).ceil().clamp(0.0, 15.0);
+                                emath::round_to_decimals(
+                                    new_value / unit_factor,
+                                    auto_decimals + extra_decimals as usize,
+                                ) * unit_factor
+                            }
+                        }
+                    };
+                    let new_value = limit_to_range(new_value, clamp_range, wrap);
                     set(&mut get_set_value, new_value);
                 }
             }
@@ -344,9 +574,164 @@ impl<'a> Widget for DragValue<'a> {
     }
 }
 
-fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
-    x.clamp(
-        range.start().min(*range.end()),
-        range.start().max(*range.end()),
-    )
+/// The biggest of the units (sorted smallest first) that the value has at least one of.
+fn pick_unit(units: &[(String, f64)], value: f64) -> Option<(&str, f64)> {
+    let (name, factor) = units
+        .iter()
+        .rev()
+        .find(|(_, factor)| value.abs() >= *factor)
+        .or_else(|| units.first())?;
+    Some((name.as_str(), *factor))
+}
+
+/// Parse a number followed by one of the units, e.g. "2.5 cm".
+/// Without a unit, the number is multiplied by `default_factor`.
+fn parse_with_units(text: &str, units: &[(String, f64)], default_factor: f64) -> Option<f64> {
+    let text = text.trim();
+    let lowercase = text.to_lowercase();
+    // The longest matching unit, so that "mm" wins over "m":
+    let unit = units
+        .iter()
+        .filter(|(name, _)| !name.is_empty() && lowercase.ends_with(&name.to_lowercase()))
+        .max_by_key(|(name, _)| name.len());
+    let (number, factor) = match unit {
+        Some((name, factor)) => (
+            &lowercase[..lowercase.len() - name.to_lowercase().len()],
+            *factor,
+        ),
+        None => (lowercase.as_str(), default_factor),
+    };
+    let number: f64 = number.trim().parse().ok()?;
+    Some(number * factor)
+}
+
+/// The keyboard input to a focused [`DragValue`] or [`Slider`].
+pub(crate) struct KeyboardSteps {
+    /// How many arrow key presses increased the value, minus how many decreased it.
+    pub steps: f64,
+    /// Page up presses minus page down presses.
+    pub pages: f64,
+    /// `Some(true)` if end was pressed, `Some(false)` if home was pressed.
+    pub jump_to_max: Option<bool>,
+    /// What to multiply the steps with, given the modifier keys that are down.
+    pub factor: f64,
+}
+
+impl KeyboardSteps {
+    /// Shift uses `slow_factor`, and Ctrl (Cmd on Mac) uses `fast_factor`.
+    pub fn new(
+        input: &InputState,
+        decrease: &[Key],
+        increase: &[Key],
+        slow_factor: f64,
+        fast_factor: f64,
+    ) -> Self {
+        let presses =
+            |keys: &[Key]| -> f64 { keys.iter().map(|&key| input.num_presses(key) as f64).sum() };
+        let jump_to_max = if input.key_pressed(Key::End) {
+            Some(true)
+        } else if input.key_pressed(Key::Home) {
+            Some(false)
+        } else {
+            None
+        };
+        let factor = if input.modifiers.shift {
+            slow_factor
+        } else if input.modifiers.command {
+            fast_factor
+        } else {
+            1.0
+        };
+        Self {
+            steps: presses(increase) - presses(decrease),
+            pages: presses(&[Key::PageUp]) - presses(&[Key::PageDown]),
+            jump_to_max,
+            factor,
+        }
+    }
+
+    pub fn any(&self) -> bool {
+        self.steps != 0.0 || self.pages != 0.0 || self.jump_to_max.is_some()
+    }
+}
+
+/// Clamp to the range, or wrap around to the other end of it if `wrap` is set.
+fn limit_to_range(x: f64, range: RangeInclusive<f64>, wrap: bool) -> f64 {
+    let min = range.start().min(*range.end());
+    let max = range.start().max(*range.end());
+    if wrap && min.is_finite() && max.is_finite() && min < max && (x < min || max < x) {
+        min + (x - min).rem_euclid(max - min)
+    } else {
+        x.clamp(min, max)
+    }
+}
+
+#[test]
+fn test_limit_to_range() {
+    assert_eq!(limit_to_range(400.0, 0.0..=360.0, false), 360.0);
+    assert_eq!(limit_to_range(400.0, 0.0..=360.0, true), 40.0);
+    assert_eq!(limit_to_range(-10.0, 0.0..=360.0, true), 350.0);
+    assert_eq!(limit_to_range(360.0, 0.0..=360.0, true), 360.0);
+    assert_eq!(limit_to_range(-10.0, 360.0..=0.0, true), 350.0);
+    assert_eq!(limit_to_range(-10.0, 0.0..=f64::INFINITY, true), 0.0);
+}
+
+#[test]
+fn test_drag_value_keyboard() {
+    use crate::test_util::{key_with_modifiers, run_frame_with_input};
+    let mut value = 5.0;
+    let mut ctx = CtxRef::default();
+    let mut press = |key, modifiers: Modifiers, value: &mut f64| {
+        let input = RawInput {
+            events: vec![key_with_modifiers(key, modifiers)],
+            modifiers,
+            ..Default::default()
+        };
+        run_frame_with_input(&mut ctx, input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(
+                    DragValue::new(value)
+                        .clamp_range(0.0..=100.0)
+                        .keyboard_step(1.0),
+                )
+            });
+        });
+        *value
+    };
+    let none = Modifiers::default();
+    let shift = Modifiers {
+        shift: true,
+        ..Default::default()
+    };
+    let ctrl = Modifiers {
+        ctrl: true,
+        command: true,
+        ..Default::default()
+    };
+
+    press(Key::Tab, none, &mut value); // Focus the DragValue
+    assert_eq!(press(Key::ArrowUp, none, &mut value), 6.0);
+    assert_eq!(press(Key::ArrowDown, shift, &mut value), 5.9);
+    assert_eq!(press(Key::ArrowRight, ctrl, &mut value), 15.9);
+    assert_eq!(press(Key::PageDown, none, &mut value), 5.9);
+    assert_eq!(press(Key::End, none, &mut value), 100.0);
+    assert_eq!(press(Key::Home, none, &mut value), 0.0);
+}
+
+#[test]
+fn test_units() {
+    let units = vec![
+        ("px".to_owned(), 1.0),
+        ("mm".to_owned(), 3.78),
+        ("cm".to_owned(), 37.8),
+    ];
+    assert_eq!(pick_unit(&units, 100.0), Some(("cm", 37.8)));
+    assert_eq!(pick_unit(&units, -10.0), Some(("mm", 3.78)));
+    assert_eq!(pick_unit(&units, 0.5), Some(("px", 1.0)));
+    assert_eq!(pick_unit(&[], 0.5), None);
+
+    assert_eq!(parse_with_units("2cm", &units, 1.0), Some(75.6));
+    assert_eq!(parse_with_units(" 10 MM ", &units, 1.0), Some(37.8));
+    assert_eq!(parse_with_units("2", &units, 37.8), Some(75.6));
+    assert_eq!(parse_with_units("2 in", &units, 1.0), None);
 }
@@ -0,0 +1,186 @@
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// Edit a [`Vec2`] (e.g. a size or a scale) with two [`DragValue`]s,
+/// and optionally a lock button to keep the aspect ratio.
+///
+/// While the aspect ratio is locked, dragging either component
+/// changes the other one proportionally.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut size = egui::vec2(640.0, 480.0);
+/// # let mut keep_aspect_ratio = true;
+/// ui.add(
+///     egui::DragVec2::new(&mut size)
+///         .clamp_range(1.0..=4096.0)
+///         .lock_aspect_ratio(&mut keep_aspect_ratio),
+/// );
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Debug)]
+pub struct DragVec2<'a> {
+    value: &'a mut Vec2,
+    aspect_locked: Option<&'a mut bool>,
+    speed: f32,
+    clamp_range: RangeInclusive<f32>,
+    prefixes: [String; 2],
+}
+
+impl<'a> DragVec2<'a> {
+    pub fn new(value: &'a mut Vec2) -> Self {
+        Self {
+            value,
+            aspect_locked: None,
+            speed: 1.0,
+            clamp_range: f32::NEG_INFINITY..=f32::INFINITY,
+            prefixes: ["x: ".to_owned(), "y: ".to_owned()],
+        }
+    }
+
+    /// How much the value changes when dragged one point (logical pixel).
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Clamp both components to this range.
+    pub fn clamp_range(mut self, clamp_range: RangeInclusive<f32>) -> Self {
+        self.clamp_range = clamp_range;
+        self
+    }
+
+    /// Show these before the two numbers. Default: `"x: "` and `"y: "`.
+    ///
+    /// For a size you may want `"w: "` and `"h: "` instead.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn prefixes(mut self, x: impl ToString, y: impl ToString) -> Self {
+        self.prefixes = [x.to_string(), y.to_string()];
+        self
+    }
+
+    /// Show a lock button that toggles `locked`.
+    /// While `locked` is `true`, changing one component changes the other proportionally.
+    pub fn lock_aspect_ratio(mut self, locked: &'a mut bool) -> Self {
+        self.aspect_locked = Some(locked);
+        self
+    }
+}
+
+impl<'a> Widget for DragVec2<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            aspect_locked,
+            speed,
+            clamp_range,
+            prefixes,
+        } = self;
+        let [x_prefix, y_prefix] = prefixes;
+
+        ui.horizontal(|ui| {
+            let old_value = *value;
+            let mut response = ui.add(
+                DragValue::new(&mut value.x)
+                    .speed(speed)
+                    .clamp_range(clamp_range.clone())
+                    .prefix(x_prefix),
+            );
+            response |= ui.add(
+                DragValue::new(&mut value.y)
+                    .speed(speed)
+                    .clamp_range(clamp_range.clone())
+                    .prefix(y_prefix),
+            );
+
+            if let Some(locked) = aspect_locked {
+                let lock_response =
+                    ui.add(SelectableLabel::new(*locked, "🔗"))
+                        .on_hover_text(if *locked {
+                            "Unlock the aspect ratio"
+                        } else {
+                            "Lock the aspect ratio"
+                        });
+                if lock_response.clicked() {
+                    *locked = !*locked;
+                }
+
+                if *locked {
+                    *value = keep_aspect_ratio(old_value, *value, &clamp_range);
+                }
+
+                response |= lock_response;
+            }
+
+            response
+        })
+        .inner
+    }
+}
+
+/// Scale the unchanged component of `new_value` by as much as the changed one was scaled,
+/// but no more than keeps both components in `clamp_range`.
+///
+/// Nothing is done for components that were zero, since then there is no ratio to keep.
+fn keep_aspect_ratio(old_value: Vec2, new_value: Vec2, clamp_range: &RangeInclusive<f32>) -> Vec2 {
+    let (min, max) = (*clamp_range.start(), *clamp_range.end());
+    let scale = if new_value.x != old_value.x && old_value.x != 0.0 {
+        new_value.x / old_value.x
+    } else if new_value.y != old_value.y && old_value.y != 0.0 {
+        new_value.y / old_value.y
+    } else {
+        return new_value.clamp(Vec2::splat(min), Vec2::splat(max));
+    };
+
+    // The scales that keep both components in the range:
+    let (mut min_scale, mut max_scale) = (f32::NEG_INFINITY, f32::INFINITY);
+    for &component in [old_value.x, old_value.y].iter() {
+        if component > 0.0 {
+            min_scale = min_scale.max(min / component);
+            max_scale = max_scale.min(max / component);
+        } else if component < 0.0 {
+            min_scale = min_scale.max(max / component);
+            max_scale = max_scale.min(min / component);
+        }
+    }
+    let scale = if min_scale <= max_scale {
+        scale.clamp(min_scale, max_scale)
+    } else {
+        scale // The old value was already out of range
+    };
+    (old_value * scale).clamp(Vec2::splat(min), Vec2::splat(max))
+}
+
+#[test]
+fn test_keep_aspect_ratio() {
+    let any = f32::NEG_INFINITY..=f32::INFINITY;
+    let old = vec2(640.0, 480.0);
+    assert_eq!(keep_aspect_ratio(old, old, &any), old);
+    assert_eq!(
+        keep_aspect_ratio(old, vec2(320.0, 480.0), &any),
+        vec2(320.0, 240.0)
+    );
+    assert_eq!(
+        keep_aspect_ratio(old, vec2(640.0, 960.0), &any),
+        vec2(1280.0, 960.0)
+    );
+
+    // Zero components have no ratio to keep:
+    let old = vec2(0.0, 10.0);
+    assert_eq!(
+        keep_aspect_ratio(old, vec2(5.0, 10.0), &any),
+        vec2(5.0, 10.0)
+    );
+
+    // The ratio is kept when one component hits the end of the range:
+    let old = vec2(640.0, 480.0);
+    assert_eq!(
+        keep_aspect_ratio(old, vec2(640.0, 960.0), &(1.0..=1000.0)),
+        vec2(1000.0, 750.0)
+    );
+    assert_eq!(
+        keep_aspect_ratio(old, vec2(0.5, 480.0), &(1.0..=1000.0)),
+        vec2(4.0 / 3.0, 1.0)
+    );
+}
@@ -0,0 +1,390 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::{
+    f32::consts::{PI, TAU},
+    ops::RangeInclusive,
+};
+
+use crate::*;
+
+/// The arc of a [`Gauge`] goes from this angle (clockwise from straight up, in radians) to its negative.
+const START_ANGLE: f32 = -0.75 * PI;
+
+/// `value`, or the value moving smoothly towards it after it changes.
+fn animated_value(ui: &Ui, id: Id, value: f32, animate: bool) -> f32 {
+    if animate && !ui.style().reduce_motion {
+        ui.ctx().animate_value(id, value)
+    } else {
+        value
+    }
+}
+
+/// A needle over an arc showing a value, like a speedometer or a thermometer.
+///
+/// The ends of the arc are labeled with the ends of the range,
+/// and parts of the range can be colored, e.g. red for "too hot".
+/// When the value changes the needle moves smoothly to it.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let temperature = 72.0;
+/// ui.add(
+///     egui::Gauge::new(temperature, 0.0..=120.0)
+///         .zone(80.0..=100.0, egui::Color32::YELLOW)
+///         .zone(100.0..=120.0, egui::Color32::RED)
+///         .suffix(" °C")
+///         .text("CPU"),
+/// );
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Gauge {
+    value: f32,
+    range: RangeInclusive<f32>,
+    zones: Vec<(RangeInclusive<f32>, Color32)>,
+    diameter: Option<f32>,
+    text: String,
+    suffix: String,
+    animate: bool,
+}
+
+impl Gauge {
+    pub fn new(value: f32, range: RangeInclusive<f32>) -> Self {
+        Self {
+            value,
+            range,
+            zones: vec![],
+            diameter: None,
+            text: Default::default(),
+            suffix: Default::default(),
+            animate: true,
+        }
+    }
+
+    /// Color a part of the arc. Later zones are painted on top of earlier ones.
+    pub fn zone(mut self, range: RangeInclusive<f32>, color: Color32) -> Self {
+        self.zones.push((range, color));
+        self
+    }
+
+    /// Default: four times [`style::Spacing::interact_size`] high.
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Show a text under the value, e.g. what is measured.
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    /// Show a unit after the value, e.g. `" km/h"`.
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Move the needle smoothly when the value changes.
+    /// Default: `true`, unless [`Style::reduce_motion`] is set.
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+}
+
+impl Widget for Gauge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Gauge {
+            value,
+            range,
+            zones,
+            diameter,
+            text,
+            suffix,
+            animate,
+        } = self;
+
+        let diameter = diameter.unwrap_or(4.0 * ui.spacing().interact_size.y);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+        response.widget_info(|| WidgetInfo {
+            label: Some(text.clone()).filter(|text| !text.is_empty()),
+            value: Some(value as f64),
+            ..WidgetInfo::new(WidgetType::ProgressIndicator)
+        });
+
+        if !ui.clip_rect().intersects(rect) {
+            return response;
+        }
+
+        let shown_value = animated_value(ui, response.id, value, animate);
+        let angle_from_value = |value: f32| {
+            let normalized = remap_clamp(value, range.clone(), 0.0..=1.0);
+            lerp(START_ANGLE..=-START_ANGLE, normalized)
+        };
+
+        let visuals = ui.visuals();
+        let painter = ui.painter();
+        let center = rect.center();
+        let radius = diameter / 2.0;
+        let arc_width = (diameter * 0.08).max(3.0);
+        let arc_radius = radius - arc_width / 2.0;
+
+        painter.add(Shape::arc(
+            center,
+            arc_radius,
+            START_ANGLE,
+            -START_ANGLE,
+            Stroke::new(arc_width, visuals.widgets.inactive.bg_fill),
+        ));
+        for (zone, color) in &zones {
+            painter.add(Shape::arc(
+                center,
+                arc_radius,
+                angle_from_value(*zone.start()),
+                angle_from_value(*zone.end()),
+                Stroke::new(arc_width, *color),
+            ));
+        }
+
+        // Label the ends of the arc, below them:
+        for &end in [*range.start(), *range.end()].iter() {
+            let angle = angle_from_value(end);
+            let pos = center + arc_radius * vec2(angle.sin(), -angle.cos());
+            painter.text(
+                pos + vec2(0.0, arc_width / 2.0),
+                Align2::CENTER_TOP,
+                emath::format_with_decimals_in_range(end as f64, 0..=2),
+                TextStyle::Small,
+                visuals.weak_text_color(),
+            );
+        }
+
+        let angle = angle_from_value(shown_value);
+        let dir = vec2(angle.sin(), -angle.cos());
+        let needle_color = visuals.strong_text_color();
+        painter.line_segment(
+            [
+                center - 0.1 * radius * dir,
+                center + (arc_radius - arc_width) * dir,
+            ],
+            Stroke::new((arc_width / 2.0).max(1.5), needle_color),
+        );
+        painter.circle_filled(center, arc_width * 0.7, needle_color);
+
+        // The value is colored like the zone it is in:
+        let (min, max) = (
+            range.start().min(*range.end()),
+            range.start().max(*range.end()),
+        );
+        let value_color = zones
+            .iter()
+            .rev()
+            .find(|(zone, _)| {
+                let (start, end) = (zone.start().min(*zone.end()), zone.start().max(*zone.end()));
+                (start..=end).contains(&value.clamp(min, max))
+            })
+            .map_or(visuals.text_color(), |(_, color)| *color);
+        let value_pos = center + vec2(0.0, 0.4 * radius);
+        painter.text(
+            value_pos,
+            Align2::CENTER_CENTER,
+            format!(
+                "{}{}",
+                emath::format_with_decimals_in_range(value as f64, 0..=2),
+                suffix
+            ),
+            TextStyle::Body,
+            value_color,
+        );
+        if !text.is_empty() {
+            painter.text(
+                value_pos + vec2(0.0, ui.fonts().row_height(TextStyle::Body) / 2.0),
+                Align2::CENTER_TOP,
+                text,
+                TextStyle::Small,
+                visuals.weak_text_color(),
+            );
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A ring that fills up clockwise with the progress, with the percentage in the middle.
+///
+/// When the progress changes the ring fills smoothly to it.
+/// See also [`ProgressBar`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let files_copied = 3.0;
+/// # let files_total = 8.0;
+/// ui.add(egui::RadialProgress::new(files_copied / files_total).text("Copying"));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct RadialProgress {
+    progress: f32,
+    diameter: Option<f32>,
+    text: Option<String>,
+    show_percentage: bool,
+    fill: Option<Color32>,
+    animate: bool,
+}
+
+impl RadialProgress {
+    /// `progress` goes from `0.0` (nothing done) to `1.0` (all done), and is clamped to that range.
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: progress.clamp(0.0, 1.0),
+            diameter: None,
+            text: None,
+            show_percentage: true,
+            fill: None,
+            animate: true,
+        }
+    }
+
+    /// Default: three times [`style::Spacing::interact_size`] high.
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Show a text under the percentage, e.g. "Uploading".
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Show the progress in percent in the middle of the ring, e.g. "42%".
+    /// Default: `true`.
+    pub fn show_percentage(mut self, show_percentage: bool) -> Self {
+        self.show_percentage = show_percentage;
+        self
+    }
+
+    /// The color of the filled part of the ring.
+    /// Default: the selection color ([`style::Selection::bg_fill`]).
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Fill the ring smoothly when the progress changes.
+    /// Default: `true`, unless [`Style::reduce_motion`] is set.
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+}
+
+impl Widget for RadialProgress {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let RadialProgress {
+            progress,
+            diameter,
+            text,
+            show_percentage,
+            fill,
+            animate,
+        } = self;
+
+        let diameter = diameter.unwrap_or(3.0 * ui.spacing().interact_size.y);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+        response.widget_info(|| WidgetInfo {
+            label: text.clone(),
+            value: Some((progress * 100.0).round() as f64),
+            ..WidgetInfo::new(WidgetType::ProgressIndicator)
+        });
+
+        if !ui.clip_rect().intersects(rect) {
+            return response;
+        }
+
+        let shown_progress = animated_value(ui, response.id, progress, animate);
+
+        let visuals = ui.visuals();
+        let painter = ui.painter();
+        let center = rect.center();
+        let ring_width = (diameter * 0.1).max(3.0);
+        let ring_radius = diameter / 2.0 - ring_width / 2.0;
+        painter.circle_stroke(
+            center,
+            ring_radius,
+            Stroke::new(ring_width, visuals.extreme_bg_color),
+        );
+        if shown_progress > 0.0 {
+            painter.add(Shape::arc(
+                center,
+                ring_radius,
+                0.0,
+                TAU * shown_progress,
+                Stroke::new(ring_width, fill.unwrap_or(visuals.selection.bg_fill)),
+            ));
+        }
+
+        let text_height = ui.fonts().row_height(TextStyle::Body);
+        let (percentage_pos, text_pos) = if show_percentage && text.is_some() {
+            (
+                center - vec2(0.0, text_height / 2.0),
+                center + vec2(0.0, text_height / 2.0),
+            )
+        } else {
+            (center, center)
+        };
+        if show_percentage {
+            painter.text(
+                percentage_pos,
+                Align2::CENTER_CENTER,
+                format!("{}%", (shown_progress * 100.0).round() as i32),
+                TextStyle::Body,
+                visuals.text_color(),
+            );
+        }
+        if let Some(text) = text {
+            painter.text(
+                text_pos,
+                Align2::CENTER_CENTER,
+                text,
+                TextStyle::Small,
+                visuals.weak_text_color(),
+            );
+        }
+
+        response
+    }
+}
+
+#[test]
+fn test_animate_value() {
+    use crate::test_util::run_frame_with_input;
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |time: f64, value: f32| {
+        let input = RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let (animated, output) = run_frame_with_input(&mut ctx, input, |ctx| {
+            ctx.animate_value_with_time(Id::new("gauge"), value, 1.0)
+        });
+        (animated, output.needs_repaint)
+    };
+
+    assert_eq!(run_frame(0.0, 0.0).0, 0.0, "no animation at first");
+    run_frame(1.0, 0.0);
+
+    let (animated, needs_repaint) = run_frame(2.0, 10.0);
+    assert!(0.0 < animated && animated < 1.0);
+    assert!(needs_repaint);
+    let (animated, _) = run_frame(2.5, 10.0);
+    assert!(4.0 < animated && animated < 6.0, "{}", animated);
+
+    // Turning back half-way starts from where the animation is:
+    let (animated, _) = run_frame(2.5, 0.0);
+    assert!(4.0 < animated && animated < 6.0, "{}", animated);
+    assert_eq!(run_frame(4.0, 0.0).0, 0.0);
+    run_frame(5.0, 0.0);
+    assert_eq!(run_frame(6.0, 0.0), (0.0, false));
+}
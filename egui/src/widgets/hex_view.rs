@@ -0,0 +1,433 @@
+use std::ops::Range;
+
+use crate::*;
+
+/// Width of the offset column, including the gap after it.
+const OFFSET_CHARS: usize = 10;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct State {
+    /// Where the selection started, if anything is selected.
+    anchor: Option<usize>,
+
+    /// The byte at the end of the selection, where typing goes.
+    cursor: usize,
+
+    /// When editing: the high nibble of the byte at the cursor has been typed.
+    high_nibble_typed: bool,
+}
+
+impl State {
+    fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor) + 1)
+    }
+}
+
+enum HexData<'a> {
+    Shared(&'a [u8]),
+    Mutable(&'a mut [u8]),
+}
+
+impl<'a> HexData<'a> {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Self::Shared(bytes) => bytes,
+            Self::Mutable(bytes) => bytes,
+        }
+    }
+}
+
+/// What happened in a [`HexView`] this frame.
+pub struct HexViewResponse {
+    /// [`Response::changed`] is set if a byte was edited.
+    pub response: Response,
+
+    /// The selected bytes, if any.
+    pub selection: Option<Range<usize>>,
+}
+
+/// Shows binary data as rows of hexadecimal bytes, with the offset of each row to the left
+/// and the bytes as ASCII to the right.
+///
+/// Only the rows that are scrolled into view are laid out, so it handles large data.
+///
+/// Click and drag to select bytes, or use the arrow keys (with shift to select),
+/// and copy them as hex with the usual shortcut.
+/// If the data is [editable](Self::editable), type hex digits to overwrite the bytes at the cursor.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let data = vec![0_u8; 100];
+/// let response = egui::HexView::new(&data)
+///     .highlight(0..4, egui::Color32::from_rgb(60, 60, 120)) // e.g. a magic number
+///     .show(ui);
+/// if let Some(selection) = response.selection {
+///     ui.label(format!("{} bytes selected", selection.len()));
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct HexView<'a> {
+    data: HexData<'a>,
+    id_source: Option<Id>,
+    bytes_per_row: usize,
+    highlights: Vec<(Range<usize>, Color32)>,
+    max_height: f32,
+}
+
+impl<'a> HexView<'a> {
+    /// Show the data, without editing.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self::from_data(HexData::Shared(data))
+    }
+
+    /// Show the data and let the user overwrite bytes by typing hex digits.
+    pub fn editable(data: &'a mut [u8]) -> Self {
+        Self::from_data(HexData::Mutable(data))
+    }
+
+    fn from_data(data: HexData<'a>) -> Self {
+        Self {
+            data,
+            id_source: None,
+            bytes_per_row: 16,
+            highlights: Default::default(),
+            max_height: 400.0,
+        }
+    }
+
+    /// Needed if you show more than one [`HexView`] in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Default: 16.
+    pub fn bytes_per_row(mut self, bytes_per_row: usize) -> Self {
+        self.bytes_per_row = bytes_per_row.at_least(1);
+        self
+    }
+
+    /// Paint the background of these bytes in this color, e.g. to show the fields of a file header.
+    ///
+    /// Later highlights are painted over earlier ones. The selection is painted over all of them.
+    pub fn highlight(mut self, range: Range<usize>, color: impl Into<Color32>) -> Self {
+        self.highlights.push((range, color.into()));
+        self
+    }
+
+    /// The rows scroll when they are taller than this. Default: 400.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> HexViewResponse {
+        let HexView {
+            mut data,
+            id_source,
+            bytes_per_row,
+            highlights,
+            max_height,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("hex_view")));
+        let mut state = *ui.memory().id_data_temp.get_or_default::<State>(id);
+        let len = data.bytes().len();
+        // The data may have shrunk since last frame:
+        state.cursor = state.cursor.min(len.saturating_sub(1));
+        state.anchor = state
+            .anchor
+            .filter(|_| len > 0)
+            .map(|anchor| anchor.min(len - 1));
+
+        let text_style = TextStyle::Monospace;
+        let char_width = ui.fonts().glyph_width(text_style, '0');
+        let row_height = ui.fonts().row_height(text_style);
+        let row_step = row_height + ui.spacing().item_spacing.y;
+        let geometry = Geometry { bytes_per_row };
+        let row_width = geometry.num_chars() as f32 * char_width;
+        let num_rows = (len + bytes_per_row - 1) / bytes_per_row;
+
+        let mut response = ScrollArea::from_max_height(max_height)
+            .id_source(id.with("scroll"))
+            .show_rows(ui, row_height, num_rows, |ui, row_range| {
+                ui.set_min_width(row_width);
+                let first_row_top = ui.max_rect().top();
+                let content_top = first_row_top - row_range.start as f32 * row_step;
+                let rect = Rect::from_min_size(
+                    pos2(ui.max_rect().left(), first_row_top),
+                    vec2(row_width, row_range.len() as f32 * row_step),
+                );
+                let response = ui.interact(rect, id, Sense::click_and_drag());
+
+                let byte_at = |pos: Pos2| {
+                    let row = ((pos.y - content_top) / row_step).floor().max(0.0) as usize;
+                    let col = ((pos.x - rect.left()) / char_width).floor().max(0.0) as usize;
+                    let index = row * bytes_per_row + geometry.byte_at_col(col)?;
+                    (len > 0).then(|| index.min(len - 1))
+                };
+                if let Some(pos) = ui.input().pointer.interact_pos() {
+                    if response.drag_started() || response.clicked() {
+                        if let Some(index) = byte_at(pos) {
+                            if !ui.input().modifiers.shift || state.anchor.is_none() {
+                                state.anchor = Some(index);
+                            }
+                            state.cursor = index;
+                            state.high_nibble_typed = false;
+                        }
+                        response.request_focus();
+                    } else if response.dragged() {
+                        if let Some(index) = byte_at(pos) {
+                            state.cursor = index;
+                        }
+                    }
+                }
+                let response = if let Some(index) = response.hover_pos().and_then(byte_at) {
+                    response.on_hover_text(format!(
+                        "Offset {0} (0x{0:X}): 0x{1:02X} ({1})",
+                        index,
+                        data.bytes()[index]
+                    ))
+                } else {
+                    response
+                };
+
+                let selection = state.selection();
+                let has_focus = response.has_focus();
+                let painter = ui.painter();
+                for row in row_range.clone() {
+                    let top = content_top + row as f32 * row_step;
+                    let row_start = row * bytes_per_row;
+                    let row_bytes = &data.bytes()[row_start..(row_start + bytes_per_row).min(len)];
+
+                    for i in 0..row_bytes.len() {
+                        let index = row_start + i;
+                        let fill = if selection.as_ref().map_or(false, |s| s.contains(&index)) {
+                            Some(ui.visuals().selection.bg_fill)
+                        } else {
+                            highlights
+                                .iter()
+                                .rev()
+                                .find(|(range, _)| range.contains(&index))
+                                .map(|(_, color)| *color)
+                        };
+                        let hex_rect = Rect::from_min_size(
+                            pos2(rect.left() + Geometry::hex_col(i) as f32 * char_width, top),
+                            vec2(2.0 * char_width, row_height),
+                        );
+                        let ascii_rect = Rect::from_min_size(
+                            pos2(rect.left() + geometry.ascii_col(i) as f32 * char_width, top),
+                            vec2(char_width, row_height),
+                        );
+                        if let Some(fill) = fill {
+                            painter.rect_filled(hex_rect, 0.0, fill);
+                            painter.rect_filled(ascii_rect, 0.0, fill);
+                        }
+                        if has_focus && index == state.cursor {
+                            let stroke = ui.visuals().selection.stroke;
+                            painter.rect_stroke(hex_rect.expand(1.0), 0.0, stroke);
+                        }
+                    }
+
+                    let text_color = ui.visuals().text_color();
+                    painter.text(
+                        pos2(rect.left(), top),
+                        Align2::LEFT_TOP,
+                        format!("{:08X}", row_start),
+                        text_style,
+                        ui.visuals().weak_text_color(),
+                    );
+                    painter.text(
+                        pos2(rect.left() + OFFSET_CHARS as f32 * char_width, top),
+                        Align2::LEFT_TOP,
+                        geometry.row_text(row_bytes),
+                        text_style,
+                        text_color,
+                    );
+                }
+                response
+            });
+
+        if response.has_focus() {
+            let events = ui.input().events.clone();
+            for event in &events {
+                match event {
+                    Event::Copy if len > 0 => {
+                        if let Some(selection) = state.selection() {
+                            ui.output().copied_text = format_hex(&data.bytes()[selection]);
+                        }
+                    }
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                    } => {
+                        let cursor = state.cursor;
+                        let new_cursor = match key {
+                            Key::ArrowLeft => cursor.saturating_sub(1),
+                            Key::ArrowRight => cursor + 1,
+                            Key::ArrowUp => cursor.saturating_sub(bytes_per_row),
+                            Key::ArrowDown => cursor + bytes_per_row,
+                            Key::Home => cursor - cursor % bytes_per_row,
+                            Key::End => cursor - cursor % bytes_per_row + bytes_per_row - 1,
+                            _ => continue,
+                        }
+                        .min(len.saturating_sub(1));
+                        if modifiers.shift {
+                            state.anchor.get_or_insert(cursor);
+                        } else {
+                            state.anchor = None;
+                        }
+                        state.cursor = new_cursor;
+                        state.high_nibble_typed = false;
+                    }
+                    Event::Text(text) => {
+                        if let HexData::Mutable(bytes) = &mut data {
+                            for digit in text.chars().filter_map(|c| c.to_digit(16)) {
+                                if let Some(byte) = bytes.get_mut(state.cursor) {
+                                    let digit = digit as u8;
+                                    if state.high_nibble_typed {
+                                        *byte = (*byte & 0xF0) | digit;
+                                        state.cursor = (state.cursor + 1).min(len - 1);
+                                    } else {
+                                        *byte = (digit << 4) | (*byte & 0x0F);
+                                    }
+                                    state.high_nibble_typed = !state.high_nibble_typed;
+                                    state.anchor = None;
+                                    response.mark_changed();
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let selection = state.selection();
+        ui.memory().id_data_temp.insert(id, state);
+        HexViewResponse {
+            response,
+            selection,
+        }
+    }
+}
+
+/// Where the characters of a row go.
+#[derive(Clone, Copy)]
+struct Geometry {
+    bytes_per_row: usize,
+}
+
+impl Geometry {
+    /// The column of the first hex digit of the byte, with an extra gap after every 8 bytes.
+    fn hex_col(i: usize) -> usize {
+        OFFSET_CHARS + 3 * i + i / 8
+    }
+
+    /// The column of the byte in the ASCII part, after a gap.
+    fn ascii_col(&self, i: usize) -> usize {
+        let bytes_per_row = self.bytes_per_row;
+        OFFSET_CHARS + 3 * bytes_per_row + (bytes_per_row - 1) / 8 + 1 + i
+    }
+
+    fn num_chars(&self) -> usize {
+        self.ascii_col(self.bytes_per_row)
+    }
+
+    /// The byte within the row shown at this column, in the hex or the ASCII part.
+    fn byte_at_col(&self, col: usize) -> Option<usize> {
+        if col >= self.ascii_col(0) {
+            Some((col - self.ascii_col(0)).min(self.bytes_per_row - 1))
+        } else if col >= OFFSET_CHARS {
+            (0..self.bytes_per_row)
+                .rev()
+                .find(|&i| Self::hex_col(i) <= col)
+        } else {
+            None
+        }
+    }
+
+    /// The hex and ASCII parts of a row, starting at [`OFFSET_CHARS`].
+    fn row_text(&self, bytes: &[u8]) -> String {
+        let mut text = String::new();
+        for i in 0..self.bytes_per_row {
+            if i > 0 && i % 8 == 0 {
+                text.push(' ');
+            }
+            match bytes.get(i) {
+                Some(byte) => text.push_str(&format!("{:02X} ", byte)),
+                None => text.push_str("   "),
+            }
+        }
+        text.push(' ');
+        text.extend(bytes.iter().map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            }
+        }));
+        text
+    }
+}
+
+/// E.g. "DE AD BE EF".
+fn format_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn test_hex_view() {
+    let geometry = Geometry { bytes_per_row: 16 };
+    let text = geometry.row_text(b"Hello, hex view!");
+    assert_eq!(
+        text,
+        "48 65 6C 6C 6F 2C 20 68  65 78 20 76 69 65 77 21  Hello, hex view!"
+    );
+    for i in 0..16 {
+        let hex_col = Geometry::hex_col(i);
+        assert_eq!(
+            &text[hex_col - OFFSET_CHARS..hex_col - OFFSET_CHARS + 2],
+            format!("{:02X}", b"Hello, hex view!"[i])
+        );
+        assert_eq!(geometry.byte_at_col(hex_col), Some(i));
+        assert_eq!(geometry.byte_at_col(hex_col + 1), Some(i));
+        assert_eq!(geometry.byte_at_col(geometry.ascii_col(i)), Some(i));
+    }
+    assert_eq!(geometry.num_chars() - OFFSET_CHARS, text.len());
+    assert_eq!(geometry.byte_at_col(3), None);
+    assert_eq!(format_hex(&[0xDE, 0xAD]), "DE AD");
+
+    // Typing overwrites the bytes at the cursor:
+    use crate::test_util::{click, key_with_modifiers, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut data = vec![0_u8; 40];
+    let mut run_frame = |events: Vec<Event>, data: &mut [u8]| {
+        let response = run_ui_frame(&mut ctx, events, |ui| HexView::editable(data).show(ui));
+        (response.response.rect, response.selection)
+    };
+    let rect = run_frame(vec![], &mut data).0;
+    let (_, selection) = run_frame(click(rect.left_top() + vec2(120.0, 5.0)), &mut data);
+    let cursor = selection.unwrap().start;
+    run_frame(vec![Event::Text("a1b".to_owned())], &mut data);
+    assert_eq!(&data[cursor..cursor + 2], &[0xA1, 0xB0]);
+
+    // Copying when the data has shrunk below the selection:
+    let shift = Modifiers {
+        shift: true,
+        ..Default::default()
+    };
+    let select = vec![key_with_modifiers(Key::ArrowRight, shift)];
+    let (_, selection) = run_frame(select, &mut data);
+    assert!(selection.unwrap().end > 4);
+    let (_, selection) = run_frame(vec![Event::Copy], &mut data[..4]);
+    assert_eq!(selection, Some(3..4));
+    let (_, selection) = run_frame(vec![Event::Copy], &mut []);
+    assert_eq!(selection, None);
+}
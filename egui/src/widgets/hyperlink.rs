@@ -4,6 +4,9 @@ use crate::*;
 ///
 /// See also [`Ui::hyperlink`] and [`Ui::hyperlink_to`].
 ///
+/// Clicking it opens the url with [`Context::open_url`],
+/// so you can route it through your own code with [`Context::set_link_handler`].
+///
 /// ```
 /// # let ui = &mut egui::Ui::__test();
 /// ui.hyperlink("https://github.com/emilk/egui");
@@ -13,6 +16,7 @@ use crate::*;
 pub struct Hyperlink {
     url: String,
     label: Label,
+    new_tab: Option<bool>,
 }
 
 impl Hyperlink {
@@ -22,6 +26,7 @@ impl Hyperlink {
         Self {
             url: url.clone(),
             label: Label::new(url),
+            new_tab: None,
         }
     }
 
@@ -30,6 +35,7 @@ impl Hyperlink {
         Self {
             url: url.to_string(),
             label: label.into(),
+            new_tab: None,
         }
     }
 
@@ -49,11 +55,25 @@ impl Hyperlink {
     pub fn small(self) -> Self {
         self.text_style(TextStyle::Small)
     }
+
+    /// Always open the url in a new tab (`true`) or in the same tab (`false`) when clicked.
+    ///
+    /// By default it opens in a new tab if a modifier key is held down.
+    /// A middle click always opens it in a new tab.
+    /// Only matters when in a web browser, or to your [link handler](Context::set_link_handler).
+    pub fn new_tab(mut self, new_tab: bool) -> Self {
+        self.new_tab = Some(new_tab);
+        self
+    }
 }
 
 impl Widget for Hyperlink {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Hyperlink { url, label } = self;
+        let Hyperlink {
+            url,
+            label,
+            new_tab,
+        } = self;
         let galley = label.layout(ui);
         let (rect, response) = ui.allocate_exact_size(galley.size, Sense::click());
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Hyperlink, &galley.text));
@@ -62,17 +82,14 @@ impl Widget for Hyperlink {
             ui.ctx().output().cursor_icon = CursorIcon::PointingHand;
         }
         if response.clicked() {
-            let modifiers = ui.ctx().input().modifiers;
-            ui.ctx().output().open_url = Some(crate::output::OpenUrl {
+            let new_tab = new_tab.unwrap_or_else(|| ui.input().modifiers.any());
+            ui.ctx().open_url(crate::output::OpenUrl {
                 url: url.clone(),
-                new_tab: modifiers.any(),
+                new_tab,
             });
         }
         if response.middle_clicked() {
-            ui.ctx().output().open_url = Some(crate::output::OpenUrl {
-                url: url.clone(),
-                new_tab: true,
-            });
+            ui.ctx().open_url(crate::output::OpenUrl::new_tab(&url));
         }
 
         let color = ui.visuals().hyperlink_color;
@@ -95,3 +112,42 @@ impl Widget for Hyperlink {
         response.on_hover_text(url)
     }
 }
+
+#[test]
+fn test_link_handler() {
+    use crate::test_util::{click, run_frame_with_input};
+    use std::sync::{Arc, Mutex};
+
+    let mut ctx = CtxRef::default();
+    let handled = Arc::new(Mutex::new(vec![]));
+    let handled_clone = handled.clone();
+    ctx.set_link_handler(move |open_url| {
+        let is_internal = open_url.url.starts_with("app://");
+        if is_internal {
+            handled_clone.lock().unwrap().push(open_url.url.clone());
+        }
+        is_internal
+    });
+
+    let mut click_link = |url: &str, new_tab: bool| {
+        let mut run_frame = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let (link_rect, output) = run_frame_with_input(&mut ctx, input, |ctx| {
+                CentralPanel::default()
+                    .show(ctx, |ui| ui.add(Hyperlink::new(url).new_tab(new_tab)).rect)
+                    .inner
+            });
+            (output.open_url, link_rect)
+        };
+        let pos = run_frame(vec![]).1.center();
+        run_frame(click(pos)).0
+    };
+
+    assert!(click_link("app://settings", false).is_none());
+    assert_eq!(*handled.lock().unwrap(), vec!["app://settings".to_owned()]);
+    let open_url = click_link("https://github.com/emilk/egui", true).unwrap();
+    assert!(open_url.new_tab);
+}
@@ -0,0 +1,274 @@
+use crate::*;
+
+/// Below this many points per texel, the pixel grid is not shown.
+const PIXEL_GRID_MIN_ZOOM: f32 = 8.0;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ImageViewerState {
+    /// Points per texel.
+    zoom: f32,
+
+    /// The position in the image (in texels) at the center of the view.
+    center: Vec2,
+}
+
+/// The response of [`ImageViewer::show`].
+pub struct ImageViewerResponse {
+    /// The response of the view of the image.
+    pub response: Response,
+
+    /// The texel under the mouse cursor, if any.
+    pub hovered_texel: Option<[usize; 2]>,
+
+    /// The color of [`Self::hovered_texel`], if known (see [`Context::read_texel`]).
+    pub hovered_color: Option<Color32>,
+}
+
+/// Shows an image that the user can zoom and pan, e.g. for inspecting textures.
+///
+/// Scroll (or pinch) to zoom in on the mouse cursor, and drag to pan.
+/// The buttons above the image fit it to the view, or show it at one texel per physical pixel.
+/// When zoomed in far enough, a grid shows the edges of the texels.
+///
+/// Below the image is the position of the texel under the cursor,
+/// and its color if egui can read it (see [`Context::set_texel_reader`]).
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let texture_id = egui::TextureId::Egui;
+/// let response = egui::ImageViewer::new(texture_id, [256.0, 256.0]).show(ui);
+/// if let Some([x, y]) = response.hovered_texel {
+///     // …
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ImageViewer {
+    texture_id: TextureId,
+    image_size: Vec2,
+    id_source: Option<Id>,
+    desired_size: Option<Vec2>,
+    max_zoom: f32,
+    pixel_grid: bool,
+}
+
+impl ImageViewer {
+    /// `image_size` is the size of the texture in texels.
+    pub fn new(texture_id: TextureId, image_size: impl Into<Vec2>) -> Self {
+        Self {
+            texture_id,
+            image_size: image_size.into().max(Vec2::splat(1.0)),
+            id_source: None,
+            desired_size: None,
+            max_zoom: 64.0,
+            pixel_grid: true,
+        }
+    }
+
+    /// Needed if you show more than one [`ImageViewer`] in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// The size of the view of the image. Default: all available space.
+    pub fn desired_size(mut self, desired_size: impl Into<Vec2>) -> Self {
+        self.desired_size = Some(desired_size.into());
+        self
+    }
+
+    /// How far the user can zoom in, in points per texel. Default: 64.
+    pub fn max_zoom(mut self, max_zoom: f32) -> Self {
+        self.max_zoom = max_zoom.max(1.0);
+        self
+    }
+
+    /// Show the edges of the texels when zoomed in far enough. Default: `true`.
+    pub fn pixel_grid(mut self, pixel_grid: bool) -> Self {
+        self.pixel_grid = pixel_grid;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> ImageViewerResponse {
+        let ImageViewer {
+            texture_id,
+            image_size,
+            id_source,
+            desired_size,
+            max_zoom,
+            pixel_grid,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("image_viewer")));
+        let pixels_per_point = ui.ctx().pixels_per_point();
+        let state = ui
+            .memory()
+            .id_data_temp
+            .get::<ImageViewerState>(&id)
+            .copied();
+
+        let (fit, one_to_one) = ui
+            .horizontal(|ui| {
+                let fit = ui.button("Fit").on_hover_text("Fit the image to the view");
+                let one_to_one = ui
+                    .button("1:1")
+                    .on_hover_text("One texel per physical pixel");
+                if let Some(state) = state {
+                    ui.label(format!("{:.0}%", 100.0 * state.zoom * pixels_per_point));
+                }
+                (fit.clicked(), one_to_one.clicked())
+            })
+            .inner;
+
+        let status_height = ui.fonts().row_height(TextStyle::Body) + ui.spacing().item_spacing.y;
+        let desired_size = desired_size
+            .unwrap_or_else(|| ui.available_size_before_wrap_finite() - vec2(0.0, status_height));
+        let (rect, response) =
+            ui.allocate_exact_size(desired_size.max(Vec2::splat(16.0)), Sense::click_and_drag());
+
+        let fit_zoom = (rect.size() / image_size).min_elem().min(max_zoom);
+        let min_zoom = fit_zoom.min(1.0 / pixels_per_point) / 4.0;
+        let mut state = match state {
+            Some(state) if !fit => state,
+            _ => ImageViewerState {
+                zoom: fit_zoom,
+                center: image_size / 2.0,
+            },
+        };
+        if one_to_one {
+            state.zoom = 1.0 / pixels_per_point;
+        }
+
+        if let Some(pointer) = response.hover_pos() {
+            let input = ui.input();
+            let zoom_factor = input.zoom_delta() * (input.scroll_delta.y / 200.0).exp();
+            if zoom_factor != 1.0 {
+                // Keep the point under the cursor in place:
+                let offset = pointer - rect.center();
+                let pointer_in_image = state.center + offset / state.zoom;
+                state.zoom = (state.zoom * zoom_factor).clamp(min_zoom, max_zoom);
+                state.center = pointer_in_image - offset / state.zoom;
+            }
+        }
+        state.center -= response.drag_delta() / state.zoom;
+        state.center = state.center.clamp(Vec2::ZERO, image_size);
+        ui.memory().id_data_temp.insert(id, state);
+
+        let ImageViewerState { zoom, center } = state;
+        let image_rect = Rect::from_min_size(rect.center() - center * zoom, image_size * zoom);
+        let texel_rect = |[x, y]: [usize; 2]| {
+            Rect::from_min_size(
+                image_rect.min + vec2(x as f32, y as f32) * zoom,
+                Vec2::splat(zoom),
+            )
+        };
+        let hovered_texel = response.hover_pos().and_then(|pointer| {
+            let texel = (pointer - image_rect.min) / zoom;
+            let inside = texel.x >= 0.0
+                && texel.y >= 0.0
+                && texel.x < image_size.x
+                && texel.y < image_size.y;
+            inside.then(|| [texel.x as usize, texel.y as usize])
+        });
+        let hovered_color = hovered_texel.and_then(|texel| ui.ctx().read_texel(texture_id, texel));
+
+        if ui.clip_rect().intersects(rect) {
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+
+            let mut mesh = epaint::Mesh::with_texture(texture_id);
+            let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+            mesh.add_rect_with_uv(image_rect, uv, Color32::WHITE);
+            painter.add(Shape::mesh(mesh));
+
+            if pixel_grid && zoom >= PIXEL_GRID_MIN_ZOOM {
+                let stroke = Stroke::new(1.0 / pixels_per_point, Color32::from_black_alpha(80));
+                let visible = rect.intersect(image_rect);
+                let first = ((visible.min - image_rect.min) / zoom).floor();
+                let last = ((visible.max - image_rect.min) / zoom).ceil();
+                for x in first.x as usize..=last.x as usize {
+                    let x = image_rect.left() + x as f32 * zoom;
+                    painter
+                        .line_segment([pos2(x, visible.top()), pos2(x, visible.bottom())], stroke);
+                }
+                for y in first.y as usize..=last.y as usize {
+                    let y = image_rect.top() + y as f32 * zoom;
+                    painter
+                        .line_segment([pos2(visible.left(), y), pos2(visible.right(), y)], stroke);
+                }
+            }
+
+            if let Some(texel) = hovered_texel {
+                if zoom >= PIXEL_GRID_MIN_ZOOM {
+                    painter.rect_stroke(texel_rect(texel), 0.0, ui.visuals().selection.stroke);
+                }
+            }
+        }
+
+        let status = match (hovered_texel, hovered_color) {
+            (Some([x, y]), Some(color)) => {
+                let [r, g, b, a] = color::Hsva::from(color).to_srgba_unmultiplied();
+                format!("x: {}, y: {}   #{:02x}{:02x}{:02x}{:02x}", x, y, r, g, b, a)
+            }
+            (Some([x, y]), None) => format!("x: {}, y: {}", x, y),
+            (None, _) => format!("{} × {}", image_size.x as usize, image_size.y as usize),
+        };
+        ui.horizontal(|ui| {
+            if let Some(color) = hovered_color {
+                let (swatch, _) =
+                    ui.allocate_exact_size(Vec2::splat(status_height / 2.0), Sense::hover());
+                ui.painter().rect_filled(swatch, 0.0, color);
+            }
+            ui.add(Label::new(status).weak());
+        });
+
+        ImageViewerResponse {
+            response,
+            hovered_texel,
+            hovered_color,
+        }
+    }
+}
+
+#[test]
+fn test_image_viewer() {
+    use crate::test_util::run_frame_with_input;
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |events: Vec<Event>, scroll_delta: Vec2| {
+        let input = RawInput {
+            events,
+            scroll_delta,
+            ..Default::default()
+        };
+        let (response, _) = run_frame_with_input(&mut ctx, input, |ctx| {
+            CentralPanel::default()
+                .show(ctx, |ui| {
+                    ImageViewer::new(TextureId::User(0), [8.0, 4.0])
+                        .desired_size([200.0, 100.0])
+                        .show(ui)
+                })
+                .inner
+        });
+        response
+    };
+
+    // The image is fitted to the view, at 25 points per texel:
+    let rect = run_frame(vec![], Vec2::ZERO).response.rect;
+    let pointer = rect.min + vec2(30.0, 80.0);
+    let response = run_frame(vec![Event::PointerMoved(pointer)], Vec2::ZERO);
+    assert_eq!(response.hovered_texel, Some([1, 3]));
+    assert_eq!(response.hovered_color, None);
+
+    // Zooming keeps the texel under the cursor:
+    let response = run_frame(vec![], vec2(0.0, 300.0));
+    assert_eq!(response.hovered_texel, Some([1, 3]));
+
+    // Reading back texels of the user texture:
+    ctx.set_texel_reader(|texture_id, [x, y]| {
+        (texture_id == TextureId::User(0)).then(|| Color32::from_gray((x + y) as u8))
+    });
+    assert_eq!(
+        ctx.read_texel(TextureId::User(0), [1, 3]),
+        Some(Color32::from_gray(4))
+    );
+    assert_eq!(ctx.read_texel(TextureId::User(1), [1, 3]), None);
+}
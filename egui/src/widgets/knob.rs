@@ -0,0 +1,284 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::{f32::consts::PI, ops::RangeInclusive};
+
+use super::slider::{normalized_from_value, value_from_normalized, SliderSpec};
+use crate::*;
+
+/// The knob turns from this angle (clockwise from straight up, in radians) to its negative.
+const START_ANGLE: f32 = -0.75 * PI;
+
+/// How far to drag a [`KnobDrag::Vertical`] knob to go through the whole range, in points.
+const VERTICAL_DRAG_DISTANCE: f32 = 200.0;
+
+/// How the user turns a [`Knob`] by dragging.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KnobDrag {
+    /// Drag up to turn clockwise, and down to turn counter-clockwise.
+    /// Hold shift to turn slowly.
+    Vertical,
+
+    /// Point the knob at the mouse cursor, by dragging around it.
+    Circular,
+}
+
+/// Control a number by turning a knob, like on a synthesizer or a mixing desk.
+///
+/// The arc around the knob shows the value.
+/// Double-click the knob to reset it to [`Self::default_value`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut cutoff: f32 = 1000.0;
+/// ui.add(
+///     egui::Knob::new(&mut cutoff, 20.0..=20_000.0)
+///         .logarithmic(true)
+///         .default_value(1000.0)
+///         .text("Cutoff"),
+/// );
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Knob<'a> {
+    get_set_value: Box<dyn 'a + FnMut(Option<f64>) -> f64>,
+    range: RangeInclusive<f64>,
+    spec: SliderSpec,
+    integral: bool,
+    default_value: Option<f64>,
+    diameter: Option<f32>,
+    drag: KnobDrag,
+    text: String,
+}
+
+impl<'a> Knob<'a> {
+    pub fn new<Num: emath::Numeric>(value: &'a mut Num, range: RangeInclusive<Num>) -> Self {
+        Self {
+            get_set_value: Box::new(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = Num::from_f64(v)
+                }
+                value.to_f64()
+            }),
+            range: range.start().to_f64()..=range.end().to_f64(),
+            spec: SliderSpec {
+                logarithmic: false,
+                smallest_positive: if Num::INTEGRAL { 1.0 } else { 1e-6 },
+                largest_finite: f64::INFINITY,
+            },
+            integral: Num::INTEGRAL,
+            default_value: None,
+            diameter: None,
+            drag: KnobDrag::Vertical,
+            text: Default::default(),
+        }
+    }
+
+    /// Make this a logarithmic knob, see [`Slider::logarithmic`].
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.spec.logarithmic = logarithmic;
+        self
+    }
+
+    /// The value to go back to on double-click. Default: the start of the range.
+    pub fn default_value(mut self, default_value: impl Into<f64>) -> Self {
+        self.default_value = Some(default_value.into());
+        self
+    }
+
+    /// Default: twice [`style::Spacing::interact_size`] high.
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Default: [`KnobDrag::Vertical`].
+    pub fn drag(mut self, drag: KnobDrag) -> Self {
+        self.drag = drag;
+        self
+    }
+
+    /// Show a text next to the knob.
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+}
+
+/// The angle (clockwise from straight up) of the knob at this normalized value.
+fn angle_from_normalized(normalized: f32) -> f32 {
+    lerp(START_ANGLE..=-START_ANGLE, normalized)
+}
+
+/// The normalized value that points the knob in the direction of `dir` (y down),
+/// clamped to the ends if `dir` points into the gap at the bottom.
+fn normalized_from_direction(dir: Vec2) -> f32 {
+    let angle = dir.x.atan2(-dir.y);
+    remap_clamp(angle, START_ANGLE..=-START_ANGLE, 0.0..=1.0)
+}
+
+impl<'a> Widget for Knob<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Knob {
+            mut get_set_value,
+            range,
+            spec,
+            integral,
+            default_value,
+            diameter,
+            drag,
+            text,
+        } = self;
+
+        let diameter = diameter.unwrap_or(2.0 * ui.spacing().interact_size.y);
+        ui.horizontal(|ui| {
+            let (rect, mut response) =
+                ui.allocate_exact_size(Vec2::splat(diameter), Sense::click_and_drag());
+
+            let new_value = if response.double_clicked() {
+                Some(default_value.unwrap_or(*range.start()))
+            } else if response.dragged() {
+                let old_normalized =
+                    normalized_from_value(get_set_value(None), range.clone(), &spec);
+                // The unrounded position while dragging, so integer knobs turn smoothly:
+                let drag_id = response.id.with("drag");
+                let mut normalized = if response.drag_started() {
+                    old_normalized
+                } else {
+                    ui.memory()
+                        .id_data_temp
+                        .get::<f64>(&drag_id)
+                        .copied()
+                        .unwrap_or(old_normalized)
+                };
+                match drag {
+                    KnobDrag::Vertical => {
+                        let speed = if ui.input().modifiers.shift_only() {
+                            0.1
+                        } else {
+                            1.0
+                        };
+                        normalized -=
+                            (speed * response.drag_delta().y / VERTICAL_DRAG_DISTANCE) as f64;
+                    }
+                    KnobDrag::Circular => {
+                        if let Some(pointer) = response.interact_pointer_pos() {
+                            normalized = normalized_from_direction(pointer - rect.center()) as f64;
+                        }
+                    }
+                }
+                let normalized = normalized.clamp(0.0, 1.0);
+                ui.memory().id_data_temp.insert(drag_id, normalized);
+                Some(value_from_normalized(normalized, range.clone(), &spec))
+            } else {
+                None
+            };
+            if let Some(value) = new_value {
+                let value = if integral { value.round() } else { value };
+                let (min, max) = (*range.start(), *range.end());
+                let value = value.clamp(min.min(max), max.max(min));
+                if value != get_set_value(None) {
+                    get_set_value(Some(value));
+                    response.mark_changed();
+                }
+            }
+
+            let value = get_set_value(None);
+            response.widget_info(|| WidgetInfo::slider(value, &text));
+
+            if ui.clip_rect().intersects(rect) {
+                let visuals = ui.style().interact(&response);
+                let normalized = normalized_from_value(value, range.clone(), &spec) as f32;
+                let center = rect.center();
+                let radius = diameter / 2.0;
+                let arc_width = (diameter * 0.1).max(2.0);
+                let arc_radius = radius - arc_width / 2.0;
+
+                ui.painter().add(Shape::arc(
+                    center,
+                    arc_radius,
+                    START_ANGLE,
+                    -START_ANGLE,
+                    Stroke::new(arc_width, ui.visuals().widgets.inactive.bg_fill),
+                ));
+
+                // Fill from zero if the range goes through it, so e.g. a pan knob fills from the middle:
+                let (min, max) = (*range.start(), *range.end());
+                let zero = if min.min(max) < 0.0 && 0.0 < min.max(max) {
+                    normalized_from_value(0.0, range.clone(), &spec) as f32
+                } else {
+                    0.0
+                };
+                if normalized != zero {
+                    ui.painter().add(Shape::arc(
+                        center,
+                        arc_radius,
+                        angle_from_normalized(zero),
+                        angle_from_normalized(normalized),
+                        Stroke::new(arc_width, ui.visuals().selection.bg_fill),
+                    ));
+                }
+
+                let body_radius = radius - 1.5 * arc_width;
+                ui.painter()
+                    .circle(center, body_radius, visuals.bg_fill, visuals.bg_stroke);
+                let angle = angle_from_normalized(normalized);
+                let dir = vec2(angle.sin(), -angle.cos());
+                ui.painter().line_segment(
+                    [
+                        center + 0.3 * body_radius * dir,
+                        center + 0.9 * body_radius * dir,
+                    ],
+                    Stroke::new(arc_width / 2.0, visuals.fg_stroke.color),
+                );
+            }
+
+            if !text.is_empty() {
+                ui.add(Label::new(&text).wrap(false));
+            }
+
+            let value_text =
+                emath::format_with_decimals_in_range(value, if integral { 0..=0 } else { 0..=3 });
+            response.on_hover_text(value_text)
+        })
+        .inner
+    }
+}
+
+#[test]
+fn test_knob() {
+    assert_eq!(normalized_from_direction(vec2(0.0, -1.0)), 0.5);
+    assert_eq!(normalized_from_direction(vec2(-1.0, 1.0)), 0.0);
+    assert_eq!(normalized_from_direction(vec2(1.0, 1.0)), 1.0);
+    assert_eq!(normalized_from_direction(vec2(0.1, 1.0)), 1.0, "the gap");
+
+    use crate::test_util::{pointer_button as button, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut value: i32 = 5;
+    let mut run_frame = |value: &mut i32, events: Vec<Event>| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            ui.add(
+                Knob::new(value, 0..=10)
+                    .default_value(3)
+                    .drag(KnobDrag::Circular),
+            )
+        })
+    };
+
+    let rect = run_frame(&mut value, vec![]).rect;
+
+    // Press above the center, which is halfway around, and drag to the right of it:
+    let pos = rect.center() + vec2(0.0, -4.0);
+    run_frame(
+        &mut value,
+        vec![Event::PointerMoved(pos), button(pos, true)],
+    );
+    let pos = rect.center() + vec2(10.0, 0.0);
+    let response = run_frame(&mut value, vec![Event::PointerMoved(pos)]);
+    assert!(response.changed());
+    assert_eq!(value, 8);
+    run_frame(&mut value, vec![button(pos, false)]);
+
+    // Double-click resets:
+    run_frame(&mut value, vec![button(pos, true), button(pos, false)]);
+    run_frame(&mut value, vec![button(pos, true), button(pos, false)]);
+    assert_eq!(value, 3);
+}
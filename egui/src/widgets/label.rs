@@ -1,5 +1,11 @@
-use crate::*;
-use epaint::Galley;
+use crate::{
+    util::fuzzy::fuzzy_match,
+    widgets::text_edit::{
+        on_read_only_events, paint_cursor_selection, paint_selected_text, select_with_pointer,
+    },
+    *,
+};
+use epaint::{text::cursor::CCursor, Galley};
 use std::sync::Arc;
 
 /// Static text.
@@ -10,6 +16,12 @@ use std::sync::Arc;
 /// ui.add(egui::Label::new("Equivalent"));
 /// ui.add(egui::Label::new("With Options").text_color(egui::Color32::RED));
 /// ```
+///
+/// Small images can be put inline with the text using [`Self::inline_image`].
+///
+/// To show what part of the text matches a search query, use [`Self::highlight_query`].
+///
+/// To let the user select and copy the text, use [`Self::selectable`].
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Label {
     // TODO: not pub
@@ -25,7 +37,10 @@ pub struct Label {
     underline: bool,
     italics: bool,
     raised: bool,
+    inline_images: Vec<TextureId>,
+    highlighted_chars: Vec<usize>,
     sense: Sense,
+    selectable: bool,
 }
 
 impl Label {
@@ -44,7 +59,10 @@ impl Label {
             underline: false,
             italics: false,
             raised: false,
+            inline_images: vec![],
+            highlighted_chars: vec![],
             sense: Sense::focusable_noninteractive(),
+            selectable: false,
         }
     }
 
@@ -145,6 +163,50 @@ impl Label {
         self
     }
 
+    /// Paint an image in place of the next [`epaint::text::INLINE_IMAGE_CHAR`] in the text.
+    ///
+    /// The image is sized to the row height and wraps together with the text.
+    /// Call this once for each [`epaint::text::INLINE_IMAGE_CHAR`], in order.
+    ///
+    /// ``` rust
+    /// # use egui::{epaint::text::INLINE_IMAGE_CHAR, Label};
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let ok_icon = egui::TextureId::User(0);
+    /// ui.add(Label::new(format!("Build {} passed", INLINE_IMAGE_CHAR)).inline_image(ok_icon));
+    /// ```
+    pub fn inline_image(mut self, texture_id: TextureId) -> Self {
+        self.inline_images.push(texture_id);
+        self
+    }
+
+    /// Highlight the characters of the text that [`fuzzy_match`] the given search query,
+    /// by painting a background behind them.
+    ///
+    /// Nothing is highlighted if the query is empty or does not match.
+    ///
+    /// ``` rust
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let query = "open";
+    /// for command in &["File: Open", "File: Save"] {
+    ///     if egui::util::fuzzy::fuzzy_match(query, command).is_some() {
+    ///         ui.add(egui::Label::new(command).highlight_query(query));
+    ///     }
+    /// }
+    /// ```
+    pub fn highlight_query(mut self, query: &str) -> Self {
+        self.highlighted_chars = fuzzy_match(query, &self.text)
+            .map(|m| m.char_indices)
+            .unwrap_or_default();
+        self
+    }
+
+    /// Highlight the characters at these char indices (not byte indices!),
+    /// e.g. from a [`crate::util::fuzzy::FuzzyMatch`] you already have.
+    pub fn highlight_chars(mut self, char_indices: Vec<usize>) -> Self {
+        self.highlighted_chars = char_indices;
+        self
+    }
+
     /// Make the label respond to clicks and/or drags.
     ///
     /// By default, a label is inert and does not respond to click or drags.
@@ -162,6 +224,68 @@ impl Label {
         self.sense = sense;
         self
     }
+
+    /// If `true`, the user can select the text by dragging over it (or double-clicking a word),
+    /// and copy the selection. Default: `false`.
+    ///
+    /// Useful for e.g. error messages, which users often want to paste somewhere.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.add(egui::Label::new("Error: file not found").selectable(true));
+    /// ```
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+}
+
+/// The selection of a [`Label::selectable`].
+#[derive(Clone, Copy, Debug, Default)]
+struct SelectionState {
+    cursorp: Option<CursorPair>,
+}
+
+/// Let the user select text with the pointer and the keyboard, and paint the selection background.
+///
+/// Returns the selection, so the selected text can be painted over the galley.
+fn selection_ui(
+    ui: &mut Ui,
+    response: &Response,
+    pos: Pos2,
+    galley: &Galley,
+) -> Option<CursorPair> {
+    let id = response.id;
+    let mut cursorp = ui
+        .memory()
+        .id_data_temp
+        .get_or_default::<SelectionState>(id)
+        .cursorp
+        .map(|cursorp| {
+            // The text or wrapping may have changed since last frame:
+            CursorPair {
+                primary: galley.from_ccursor(cursorp.primary.ccursor),
+                secondary: galley.from_ccursor(cursorp.secondary.ccursor),
+            }
+        });
+
+    select_with_pointer(ui, response, &galley.text, pos, galley, &mut cursorp);
+
+    if response.hovered() {
+        ui.output().cursor_icon = CursorIcon::Text;
+    }
+
+    if let Some(cursorp) = &mut cursorp {
+        if response.has_focus() {
+            on_read_only_events(ui, true, galley, cursorp);
+        }
+        paint_cursor_selection(ui, pos, galley, cursorp, response.has_focus());
+    }
+
+    ui.memory()
+        .id_data_temp
+        .insert(id, SelectionState { cursorp });
+    cursorp
 }
 
 impl Label {
@@ -263,6 +387,19 @@ impl Label {
             }
         }
 
+        if !self.highlighted_chars.is_empty() {
+            let color = ui.visuals().selection.bg_fill;
+            paint_char_highlights(ui.painter(), pos, &galley, &self.highlighted_chars, color);
+        }
+
+        if !self.inline_images.is_empty() {
+            let image_rects = galley.inline_image_rects();
+            for (&texture_id, rect) in self.inline_images.iter().zip(image_rects) {
+                let rect = rect.translate(pos.to_vec2());
+                Image::new(texture_id, rect.size()).paint_at(ui, rect);
+            }
+        }
+
         ui.painter()
             .galley_with_italics(pos, galley, text_color, italics);
 
@@ -317,7 +454,11 @@ impl Label {
 
 impl Widget for Label {
     fn ui(self, ui: &mut Ui) -> Response {
-        let sense = self.sense;
+        let sense = if self.selectable {
+            self.sense.union(Sense::click_and_drag())
+        } else {
+            self.sense
+        };
 
         if self.should_wrap(ui)
             && ui.layout().main_dir() == Direction::LeftToRight
@@ -369,20 +510,76 @@ impl Widget for Label {
                 response |= ui.allocate_rect(rect, sense);
             }
             response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, &galley.text));
-            let response_color = ui.style().interact(&response).text_color();
-            self.paint_galley_impl(ui, pos, galley, response.has_focus(), response_color);
+            self.paint_response(ui, &response, pos, galley);
             response
         } else {
             let galley = self.layout(ui);
             let (rect, response) = ui.allocate_exact_size(galley.size, sense);
             response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, &galley.text));
-            let response_color = ui.style().interact(&response).text_color();
-            self.paint_galley_impl(ui, rect.min, galley, response.has_focus(), response_color);
+            self.paint_response(ui, &response, rect.min, galley);
             response
         }
     }
 }
 
+impl Label {
+    fn paint_response(&self, ui: &mut Ui, response: &Response, pos: Pos2, galley: Arc<Galley>) {
+        if self.selectable {
+            // Selectable text is not a button, so it shouldn't look like one when hovered.
+            let selection = selection_ui(ui, response, pos, &galley);
+            let text_color = ui.visuals().text_color();
+            self.paint_galley_impl(ui, pos, galley.clone(), false, text_color);
+            if let Some(selection) = &selection {
+                paint_selected_text(ui, pos, &galley, selection, response.has_focus());
+            }
+        } else {
+            let response_color = ui.style().interact(response).text_color();
+            self.paint_galley_impl(ui, pos, galley, response.has_focus(), response_color);
+        }
+    }
+}
+
+/// Paint a background behind the chars at the given char indices of a galley painted at `pos`.
+///
+/// Adjacent chars on the same row share one background rectangle.
+pub(crate) fn paint_char_highlights(
+    painter: &Painter,
+    pos: Pos2,
+    galley: &Galley,
+    char_indices: &[usize],
+    color: Color32,
+) {
+    for rect in char_rects(pos, galley, char_indices) {
+        painter.rect_filled(rect, 2.0, color);
+    }
+}
+
+/// The screen rectangles covered by the given chars of a galley painted at `pos`,
+/// with adjacent chars on the same row merged into one rectangle.
+pub(crate) fn char_rects(pos: Pos2, galley: &Galley, char_indices: &[usize]) -> Vec<Rect> {
+    let mut rects: Vec<Rect> = vec![];
+    for &index in char_indices {
+        let min = galley.pos_from_cursor(&galley.from_ccursor(CCursor {
+            index,
+            prefer_next_row: true,
+        }));
+        let max = galley.pos_from_cursor(&galley.from_ccursor(CCursor::new(index + 1)));
+        if min.top() != max.top() {
+            continue; // a newline
+        }
+        let rect = Rect::from_min_max(min.min, max.max).translate(pos.to_vec2());
+
+        match rects.last_mut() {
+            Some(last) if last.top() == rect.top() && last.right() == rect.left() => {
+                last.max.x = rect.max.x;
+            }
+            _ => rects.push(rect),
+        }
+    }
+
+    rects
+}
+
 impl From<&str> for Label {
     fn from(s: &str) -> Label {
         Label::new(s)
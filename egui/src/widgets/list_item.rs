@@ -0,0 +1,183 @@
+use crate::*;
+
+/// One row in a list, e.g. a file in a file browser or an entry in a settings list.
+///
+/// Shows an optional leading icon, a title, an optional dimmer subtitle below the title,
+/// and optional trailing actions (e.g. small buttons) at the right edge.
+/// The item spans the full available width, and so does its hover and selection highlight.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut selected = false;
+/// let item = egui::ListItem::new("report.pdf")
+///     .icon("🗋")
+///     .subtitle("1.2 MB, modified yesterday")
+///     .selected(selected)
+///     .show(ui, |ui| {
+///         ui.small_button("🗑").on_hover_text("Delete").clicked()
+///     });
+/// if item.response.clicked() {
+///     selected = !selected;
+/// }
+/// if item.inner {
+///     /* delete the file */
+/// }
+///
+/// // Without trailing actions:
+/// ui.add(egui::ListItem::new("Settings").icon("⚙"));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Clone, Debug)]
+pub struct ListItem {
+    icon: Option<String>,
+    title: String,
+    subtitle: Option<String>,
+    selected: bool,
+}
+
+impl ListItem {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(title: impl ToString) -> Self {
+        Self {
+            icon: None,
+            title: title.to_string(),
+            subtitle: None,
+            selected: false,
+        }
+    }
+
+    /// A leading icon, e.g. an emoji like `"🗀"`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn icon(mut self, icon: impl ToString) -> Self {
+        self.icon = Some(icon.to_string());
+        self
+    }
+
+    /// Secondary text, shown smaller and dimmer below the title.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn subtitle(mut self, subtitle: impl ToString) -> Self {
+        self.subtitle = Some(subtitle.to_string());
+        self
+    }
+
+    /// Highlight the item as selected.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Show the item, with trailing actions added by `add_trailing` at the right edge.
+    ///
+    /// `add_trailing` is given a right-to-left [`Ui`],
+    /// so the first thing you add ends up furthest to the right.
+    /// The returned [`Response`] is for the item as a whole;
+    /// clicks on the trailing widgets are not counted as clicks on the item.
+    pub fn show<R>(self, ui: &mut Ui, add_trailing: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let Self {
+            icon,
+            title,
+            subtitle,
+            selected,
+        } = self;
+
+        let padding = ui.spacing().button_padding;
+        let item_spacing = ui.spacing().item_spacing;
+
+        let title_galley = ui.fonts().layout_no_wrap(TextStyle::Button, title);
+        let subtitle_galley =
+            subtitle.map(|subtitle| ui.fonts().layout_no_wrap(TextStyle::Small, subtitle));
+        let icon_galley = icon.map(|icon| {
+            let icon_style = if subtitle_galley.is_some() {
+                TextStyle::Heading
+            } else {
+                TextStyle::Button
+            };
+            ui.fonts().layout_no_wrap(icon_style, icon)
+        });
+
+        let text_height = title_galley.size.y
+            + subtitle_galley
+                .as_ref()
+                .map_or(0.0, |subtitle| subtitle.size.y);
+        let height = (text_height + 2.0 * padding.y).at_least(ui.spacing().interact_size.y);
+        let width = if ui.available_width().is_finite() {
+            ui.available_width()
+        } else {
+            let icon_width = icon_galley
+                .as_ref()
+                .map_or(0.0, |icon| icon.size.x + item_spacing.x);
+            let text_width = subtitle_galley
+                .as_ref()
+                .map_or(title_galley.size.x, |subtitle| {
+                    title_galley.size.x.max(subtitle.size.x)
+                });
+            icon_width + text_width + 2.0 * padding.x
+        };
+
+        // Interact with the item only after the trailing widgets,
+        // so that they get to claim clicks first.
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+        let background = ui.painter().add(Shape::Noop);
+
+        let mut trailing_ui = ui.child_ui(rect.shrink2(padding), Layout::right_to_left());
+        let inner = add_trailing(&mut trailing_ui);
+        let trailing_rect = trailing_ui.min_rect();
+        let text_right = if trailing_rect.width() > 0.0 {
+            trailing_rect.left() - item_spacing.x
+        } else {
+            rect.right() - padding.x
+        };
+
+        let response = ui.interact(rect, response.id, Sense::click());
+        response.widget_info(|| {
+            WidgetInfo::selected(WidgetType::SelectableLabel, selected, &title_galley.text)
+        });
+
+        if ui.clip_rect().intersects(rect) {
+            let visuals = ui.style().interact_selectable(&response, selected);
+
+            if selected || response.hovered() || response.has_focus() {
+                ui.painter().set(
+                    background,
+                    Shape::rect_filled(rect, visuals.corner_radius, visuals.bg_fill),
+                );
+            }
+
+            let mut text_left = rect.left() + padding.x;
+            let painter = ui.painter().sub_region(Rect::from_min_max(
+                rect.min,
+                pos2(text_right.at_least(rect.left()), rect.bottom()),
+            ));
+
+            let text_color = ui
+                .visuals()
+                .override_text_color
+                .unwrap_or_else(|| visuals.text_color());
+
+            if let Some(icon_galley) = icon_galley {
+                let icon_pos = pos2(text_left, rect.center().y - 0.5 * icon_galley.size.y);
+                text_left += icon_galley.size.x + item_spacing.x;
+                painter.galley(icon_pos, icon_galley, text_color);
+            }
+
+            let title_pos = pos2(text_left, rect.center().y - 0.5 * text_height);
+            let subtitle_pos = title_pos + vec2(0.0, title_galley.size.y);
+            painter.galley(title_pos, title_galley, text_color);
+            if let Some(subtitle_galley) = subtitle_galley {
+                painter.galley(
+                    subtitle_pos,
+                    subtitle_galley,
+                    ui.visuals().weak_text_color(),
+                );
+            }
+        }
+
+        InnerResponse::new(inner, response)
+    }
+}
+
+impl Widget for ListItem {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui, |_ui| {}).response
+    }
+}
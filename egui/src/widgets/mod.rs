@@ -6,23 +6,67 @@
 
 use crate::*;
 
+mod animated_image;
+mod audio;
 mod button;
 pub mod color_picker;
+mod diff_view;
 pub(crate) mod drag_value;
+mod drag_vec2;
+mod gauge;
+mod hex_view;
 mod hyperlink;
 mod image;
-mod label;
+mod image_viewer;
+mod knob;
+pub(crate) mod label;
+mod list_item;
+mod palette_grid;
 pub mod plot;
+mod progress_bar;
+mod range_slider;
 mod selected_label;
 mod separator;
 mod slider;
+mod sparkline;
+mod spinner;
+mod stepper;
+mod symbol_picker;
 pub(crate) mod text_edit;
+mod time_picker;
+mod value_tree;
+mod vector_edit;
 
 pub use hyperlink::*;
 pub use label::*;
+pub use list_item::ListItem;
 pub use selected_label::*;
 pub use separator::*;
-pub use {button::*, drag_value::DragValue, image::Image, slider::*, text_edit::*};
+pub use vector_edit::{MatrixEdit, VectorEdit};
+pub use {
+    animated_image::{AnimatedImage, AnimationFrame, Playback},
+    audio::{LevelMeter, Waveform},
+    button::*,
+    diff_view::DiffView,
+    drag_value::DragValue,
+    drag_vec2::DragVec2,
+    gauge::{Gauge, RadialProgress},
+    hex_view::{HexView, HexViewResponse},
+    image::Image,
+    image_viewer::{ImageViewer, ImageViewerResponse},
+    knob::{Knob, KnobDrag},
+    palette_grid::PaletteGrid,
+    progress_bar::{ProgressBar, ProgressHandle},
+    range_slider::RangeSlider,
+    slider::*,
+    sparkline::Sparkline,
+    spinner::Spinner,
+    stepper::Stepper,
+    symbol_picker::SymbolPicker,
+    text_edit::*,
+    time_picker::{DurationPicker, TimePicker},
+    value_tree::{TreeValue, ValueTree},
+};
 
 // ----------------------------------------------------------------------------
 
@@ -0,0 +1,396 @@
+use super::color_picker::{self, Alpha};
+use crate::{color::Hsva, *};
+
+/// Where the swatches of a [`PaletteGrid`] go.
+struct SwatchLayout {
+    origin: Pos2,
+    size: f32,
+    gap: f32,
+    columns: usize,
+}
+
+impl SwatchLayout {
+    fn slot_rect(&self, index: usize) -> Rect {
+        let (row, column) = (index / self.columns, index % self.columns);
+        let step = self.size + self.gap;
+        Rect::from_min_size(
+            self.origin + vec2(column as f32 * step, row as f32 * step),
+            Vec2::splat(self.size),
+        )
+    }
+
+    /// The slot under `pos`, or the nearest one, at most `max_index`.
+    fn slot_at(&self, pos: Pos2, max_index: usize) -> usize {
+        let step = self.size + self.gap;
+        let column = ((pos.x - self.origin.x) / step).floor().max(0.0) as usize;
+        let row = ((pos.y - self.origin.y) / step).floor().max(0.0) as usize;
+        (row * self.columns + column.min(self.columns - 1)).min(max_index)
+    }
+}
+
+/// Where the item at `index` ends up after moving the item at `from` to `to`.
+fn index_after_move(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+/// A palette of colors, shown as swatches in a grid that wraps to the available width.
+///
+/// * Click a swatch to select it (see [`Self::selected`]).
+/// * Drag a swatch to move it.
+/// * Double-click a swatch to edit the color, or remove it.
+/// * Drag the button of a color picker (e.g. [`Ui::color_edit_button_srgba`]) onto the grid to add its color.
+///
+/// The colors are yours, so to remember the palette, store them with the rest of your app state.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut palette = vec![egui::Color32::RED, egui::Color32::GREEN];
+/// # let mut selected = None;
+/// if ui.add(egui::PaletteGrid::new(&mut palette).selected(&mut selected)).changed() {
+///     // The palette was edited.
+/// }
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct PaletteGrid<'a> {
+    colors: &'a mut Vec<Color32>,
+    selected: Option<&'a mut Option<usize>>,
+    id_source: Option<Id>,
+    swatch_size: Option<f32>,
+    alpha: Alpha,
+}
+
+impl<'a> PaletteGrid<'a> {
+    pub fn new(colors: &'a mut Vec<Color32>) -> Self {
+        Self {
+            colors,
+            selected: None,
+            id_source: None,
+            swatch_size: None,
+            alpha: Alpha::OnlyBlend,
+        }
+    }
+
+    /// The index of the selected color, which the user can change by clicking a swatch.
+    pub fn selected(mut self, selected: &'a mut Option<usize>) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// Needed if you show more than one [`PaletteGrid`] in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// The width and height of each swatch.
+    /// Default: one and a half times [`style::Spacing::interact_size`] high.
+    pub fn swatch_size(mut self, swatch_size: f32) -> Self {
+        self.swatch_size = Some(swatch_size);
+        self
+    }
+
+    /// What alpha options to show when editing a color. Default: [`Alpha::OnlyBlend`].
+    pub fn alpha(mut self, alpha: Alpha) -> Self {
+        self.alpha = alpha;
+        self
+    }
+}
+
+impl<'a> Widget for PaletteGrid<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let PaletteGrid {
+            colors,
+            mut selected,
+            id_source,
+            swatch_size,
+            alpha,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("palette_grid")));
+        let size = swatch_size.unwrap_or(1.5 * ui.spacing().interact_size.y);
+        let gap = ui.spacing().item_spacing.y;
+        let width = ui.available_size_before_wrap_finite().x;
+        let columns = (((width + gap) / (size + gap)).floor() as usize).max(1);
+        // At least one row, to drop colors on:
+        let rows = ((colors.len() + columns - 1) / columns).max(1);
+        let step = size + gap;
+        let desired_size = vec2(
+            columns.min(colors.len().max(1)) as f32 * step - gap,
+            rows as f32 * step - gap,
+        );
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::hover());
+        let layout = SwatchLayout {
+            origin: rect.min,
+            size,
+            gap,
+            columns,
+        };
+        let pointer = ui.input().pointer.interact_pos();
+
+        let mut dragged = None;
+        let mut drag_released = None;
+        let mut double_clicked = None;
+        let mut hovered = None;
+        for (index, &color) in colors.iter().enumerate() {
+            let swatch = ui.interact(
+                layout.slot_rect(index),
+                id.with(index),
+                Sense::click_and_drag(),
+            );
+            swatch.widget_info(|| WidgetInfo::new(WidgetType::ColorButton));
+            if swatch.clicked() {
+                if let Some(selected) = selected.as_deref_mut() {
+                    *selected = Some(index);
+                }
+            }
+            if swatch.double_clicked() {
+                double_clicked = Some(index);
+            }
+            if swatch.dragged() {
+                dragged = Some(index);
+            }
+            if swatch.drag_released() {
+                drag_released = Some(index);
+            }
+            if swatch.hovered() {
+                hovered = Some(index);
+            }
+            let srgba = Hsva::from(color).to_srgba_unmultiplied();
+            response |= swatch.on_hover_text(color_picker::format_hex_color(srgba, srgba[3] < 255));
+        }
+
+        // Moving a swatch:
+        let target = |pointer: Option<Pos2>, max_index| {
+            pointer.map(|pointer| layout.slot_at(pointer, max_index))
+        };
+        let mut order: Vec<usize> = (0..colors.len()).collect();
+        if let Some(from) = dragged {
+            ui.output().cursor_icon = CursorIcon::Grabbing;
+            if let Some(to) = target(pointer, colors.len() - 1) {
+                let index = order.remove(from);
+                order.insert(to, index);
+            }
+        }
+        if let Some(from) = drag_released {
+            if let Some(to) = target(pointer, colors.len() - 1) {
+                if from != to {
+                    let color = colors.remove(from);
+                    colors.insert(to, color);
+                    if let Some(Some(selected)) = selected.as_deref_mut() {
+                        *selected = index_after_move(*selected, from, to);
+                    }
+                    response.mark_changed();
+                }
+            }
+        }
+
+        // Dropping a color from a color picker:
+        let mut drop_index = None;
+        if let Some(color) = color_picker::dragged_color(ui.ctx()) {
+            if ui.rect_contains_pointer(rect) {
+                let index = target(pointer, colors.len()).unwrap_or(colors.len());
+                if ui.input().pointer.any_released() {
+                    colors.insert(index, color);
+                    order = (0..colors.len()).collect();
+                    if let Some(selected) = selected.as_deref_mut() {
+                        *selected = Some(index);
+                    }
+                    response.mark_changed();
+                } else {
+                    drop_index = Some(index);
+                }
+            }
+        }
+
+        // Editing a swatch:
+        let edit_id = id.with("edit");
+        if let Some(index) = double_clicked {
+            ui.memory().open_popup(edit_id);
+            ui.memory().id_data_temp.insert(edit_id, index);
+        }
+        let editing = if ui.memory().is_popup_open(edit_id) {
+            ui.memory().id_data_temp.get::<usize>(&edit_id).copied()
+        } else {
+            None
+        };
+        if let Some(index) = editing.filter(|&index| index < colors.len()) {
+            // Remember the hue of grays while editing:
+            let hsva_id = edit_id.with("hsva");
+            let mut hsva = ui
+                .memory()
+                .id_data_temp
+                .get::<Hsva>(&hsva_id)
+                .copied()
+                .filter(|&hsva| Color32::from(hsva) == colors[index])
+                .unwrap_or_else(|| Hsva::from(colors[index]));
+            let mut remove = false;
+            let area_response = Area::new(edit_id)
+                .order(Order::Foreground)
+                .default_pos(layout.slot_rect(index).left_bottom())
+                .show(ui.ctx(), |ui| {
+                    ui.spacing_mut().slider_width = 256.0;
+                    Frame::popup(ui.style()).show(ui, |ui| {
+                        if color_picker::color_picker_hsva_2d(ui, &mut hsva, alpha) {
+                            colors[index] = hsva.into();
+                            response.mark_changed();
+                        }
+                        remove = ui.button("🗑 Remove").clicked();
+                    });
+                });
+            ui.memory().id_data_temp.insert(hsva_id, hsva);
+
+            if remove {
+                colors.remove(index);
+                order = (0..colors.len()).collect();
+                if let Some(selected) = selected.as_deref_mut() {
+                    *selected = match *selected {
+                        Some(selected) if selected == index => None,
+                        Some(selected) if selected > index => Some(selected - 1),
+                        other => other,
+                    };
+                }
+                response.mark_changed();
+                ui.memory().close_popup();
+            } else if double_clicked.is_none()
+                && (ui.input().key_pressed(Key::Escape) || area_response.clicked_elsewhere())
+            {
+                ui.memory().close_popup();
+            }
+        }
+
+        if ui.clip_rect().intersects(rect) {
+            let painter = ui.painter();
+            let visuals = ui.visuals();
+            if colors.is_empty() && drop_index.is_none() {
+                painter.text(
+                    rect.left_center(),
+                    Align2::LEFT_CENTER,
+                    "Drop colors here",
+                    TextStyle::Small,
+                    visuals.weak_text_color(),
+                );
+            }
+            for (slot, &index) in order.iter().enumerate() {
+                let color = colors[index];
+                let slot = match drop_index {
+                    Some(drop_index) if slot >= drop_index => slot + 1,
+                    _ => slot,
+                };
+                let swatch_rect = layout.slot_rect(slot);
+                let stroke = if dragged == Some(index) {
+                    visuals.widgets.active.fg_stroke
+                } else if selected.as_deref() == Some(&Some(index)) {
+                    visuals.selection.stroke
+                } else if hovered == Some(index) {
+                    visuals.widgets.hovered.bg_stroke
+                } else {
+                    visuals.widgets.noninteractive.bg_stroke
+                };
+                color_picker::background_checkers(painter, swatch_rect);
+                painter.rect_filled(swatch_rect, 0.0, color);
+                painter.rect_stroke(swatch_rect, 0.0, stroke);
+            }
+            if let Some(drop_index) = drop_index {
+                painter.rect_stroke(layout.slot_rect(drop_index), 0.0, visuals.selection.stroke);
+            }
+        }
+
+        response
+    }
+}
+
+#[test]
+fn test_palette_grid() {
+    assert_eq!(index_after_move(0, 0, 2), 2);
+    assert_eq!(index_after_move(1, 0, 2), 0);
+    assert_eq!(index_after_move(3, 0, 2), 3);
+    assert_eq!(index_after_move(0, 2, 0), 1);
+
+    use crate::test_util::{click, pointer_button as button, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut colors = vec![Color32::RED, Color32::GREEN, Color32::BLUE];
+    let mut selected = None;
+    let run_frame =
+        |ctx: &mut CtxRef, colors: &mut Vec<Color32>, selected: &mut Option<usize>, events| {
+            run_ui_frame(ctx, events, |ui| {
+                ui.add(
+                    PaletteGrid::new(colors)
+                        .selected(selected)
+                        .swatch_size(20.0),
+                )
+            })
+        };
+
+    let rect = run_frame(&mut ctx, &mut colors, &mut selected, vec![]).rect;
+    let first = rect.min + vec2(10.0, 10.0);
+    let last = rect.right_top() + vec2(-10.0, 10.0);
+
+    // Select the first swatch, and drag it to the end:
+    run_frame(
+        &mut ctx,
+        &mut colors,
+        &mut selected,
+        vec![Event::PointerMoved(first), button(first, true)],
+    );
+    run_frame(
+        &mut ctx,
+        &mut colors,
+        &mut selected,
+        vec![button(first, false)],
+    );
+    assert_eq!(selected, Some(0));
+    run_frame(
+        &mut ctx,
+        &mut colors,
+        &mut selected,
+        vec![button(first, true)],
+    );
+    run_frame(
+        &mut ctx,
+        &mut colors,
+        &mut selected,
+        vec![Event::PointerMoved(last)],
+    );
+    let response = run_frame(
+        &mut ctx,
+        &mut colors,
+        &mut selected,
+        vec![button(last, false)],
+    );
+    assert!(response.changed());
+    assert_eq!(colors, vec![Color32::GREEN, Color32::BLUE, Color32::RED]);
+    assert_eq!(selected, Some(2), "the selection follows the color");
+
+    // Double-click the first swatch, and remove it:
+    run_frame(&mut ctx, &mut colors, &mut selected, click(first));
+    run_frame(&mut ctx, &mut colors, &mut selected, click(first));
+    run_frame(&mut ctx, &mut colors, &mut selected, vec![]);
+    let popup_rect = {
+        let memory = ctx.memory();
+        let popup = memory
+            .areas
+            .visible_layer_ids()
+            .into_iter()
+            .find(|layer_id| layer_id.order == Order::Foreground)
+            .expect("the edit popup is open");
+        memory.areas.get(popup.id).unwrap().rect()
+    };
+    let style = Style::default();
+    let remove_button = popup_rect.left_bottom()
+        + vec2(
+            style.spacing.window_padding.x + 10.0,
+            -style.spacing.window_padding.y - 0.5 * style.spacing.interact_size.y,
+        );
+    let response = run_frame(&mut ctx, &mut colors, &mut selected, click(remove_button));
+    assert!(response.changed());
+    assert_eq!(colors, vec![Color32::BLUE, Color32::RED]);
+    assert_eq!(selected, None, "the removed swatch was selected");
+}
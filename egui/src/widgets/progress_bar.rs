@@ -0,0 +1,353 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use crate::*;
+use std::sync::{Arc, Mutex};
+
+/// Fraction of the bar covered by the moving block of an indeterminate [`ProgressBar`].
+const INDETERMINATE_BLOCK_WIDTH: f32 = 0.3;
+
+/// How many seconds it takes the block of an indeterminate [`ProgressBar`] to cross the bar.
+const INDETERMINATE_PERIOD: f64 = 1.5;
+
+/// How often a [`ProgressBar`] looks for changes of a [`ProgressHandle`] without [`ProgressHandle::on_change`].
+const HANDLE_POLL_INTERVAL: f32 = 0.1;
+
+/// Progress reported by a background thread, for showing with [`ProgressBar::from_handle`].
+///
+/// Clone the handle and move the clone to the thread doing the work,
+/// which calls [`Self::set_progress`] as it goes and [`Self::finish`] when done.
+///
+/// Use [`Self::on_change`] to wake up the ui when something changed.
+/// Without it, a [`ProgressBar`] showing the handle checks for changes ten times a second
+/// until the work is finished, so you don't need to call [`Context::request_repaint`] yourself.
+///
+/// ```
+/// # let repaint_signal = || {};
+/// let handle = egui::ProgressHandle::default().on_change(move || repaint_signal());
+/// std::thread::spawn({
+///     let handle = handle.clone();
+///     move || {
+///         for i in 0..100 {
+///             handle.set_progress(i as f32 / 100.0);
+///             handle.set_text(format!("Processing file {}", i));
+///             // …
+///         }
+///         handle.finish();
+///     }
+/// });
+///
+/// // Later, in your ui code:
+/// # let ui = &mut egui::Ui::__test();
+/// ui.add(egui::ProgressBar::from_handle(&handle).show_percentage());
+/// ```
+#[derive(Clone, Default)]
+pub struct ProgressHandle(Arc<Mutex<ProgressState>>);
+
+type OnChangeFn = Arc<dyn Fn() + Send + Sync>;
+
+#[derive(Default)]
+struct ProgressState {
+    /// `None` for indeterminate.
+    progress: Option<f32>,
+    text: Option<String>,
+    finished: bool,
+    on_change: Option<OnChangeFn>,
+}
+
+impl ProgressHandle {
+    fn state(&self) -> std::sync::MutexGuard<'_, ProgressState> {
+        // A thread that panicked while holding the lock can't have left the state half-written:
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Call `on_change` whenever the progress, the text or [`Self::is_finished`] changes,
+    /// e.g. to wake up the ui with `frame.repaint_signal().request_repaint()` in an `epi` app.
+    ///
+    /// It is called on the thread making the change.
+    pub fn on_change(self, on_change: impl Fn() + Send + Sync + 'static) -> Self {
+        self.state().on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Change the state, and call [`Self::on_change`] if that changed anything.
+    fn update(&self, update: impl FnOnce(&mut ProgressState)) {
+        let mut state = self.state();
+        let before = (state.progress, state.text.clone(), state.finished);
+        update(&mut state);
+        if before != (state.progress, state.text.clone(), state.finished) {
+            if let Some(on_change) = state.on_change.clone() {
+                drop(state); // Let `on_change` look at the handle
+                on_change();
+            }
+        }
+    }
+
+    /// From `0.0` (nothing done) to `1.0` (all done).
+    pub fn set_progress(&self, progress: f32) {
+        self.update(|state| state.progress = Some(progress.clamp(0.0, 1.0)));
+    }
+
+    /// Go back to not knowing how far along the work is.
+    pub fn set_indeterminate(&self) {
+        self.update(|state| state.progress = None);
+    }
+
+    /// Describe what is going on, e.g. "Downloading…".
+    pub fn set_text(&self, text: impl ToString) {
+        self.update(|state| state.text = Some(text.to_string()));
+    }
+
+    /// The work is done. This also sets the progress to `1.0`.
+    pub fn finish(&self) {
+        self.update(|state| {
+            state.progress = Some(1.0);
+            state.finished = true;
+        });
+    }
+
+    /// `None` until the progress is set (or after [`Self::set_indeterminate`]).
+    pub fn progress(&self) -> Option<f32> {
+        self.state().progress
+    }
+
+    pub fn text(&self) -> Option<String> {
+        self.state().text.clone()
+    }
+
+    /// Has [`Self::finish`] been called?
+    pub fn is_finished(&self) -> bool {
+        self.state().finished
+    }
+
+    /// Is the work still going on, i.e. not [finished](Self::finish) yet?
+    pub fn is_active(&self) -> bool {
+        !self.is_finished()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A horizontal bar showing how far along a long-running operation is.
+///
+/// Use [`Self::new`] when you know the progress,
+/// and [`Self::indeterminate`] to show that something is happening when you don't.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let bytes_downloaded = 300.0;
+/// # let bytes_total = 1000.0;
+/// ui.add(egui::ProgressBar::new(bytes_downloaded / bytes_total).show_percentage());
+/// ui.add(egui::ProgressBar::indeterminate().text("Connecting…"));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ProgressBar {
+    /// `None` for indeterminate.
+    progress: Option<f32>,
+    desired_width: Option<f32>,
+    text: Option<String>,
+    show_percentage: bool,
+    fill: Option<Color32>,
+    /// Look for changes of a [`ProgressHandle`] that has no [`ProgressHandle::on_change`].
+    poll_handle: bool,
+}
+
+impl ProgressBar {
+    /// `progress` goes from `0.0` (nothing done) to `1.0` (all done), and is clamped to that range.
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: Some(progress.clamp(0.0, 1.0)),
+            ..Self::indeterminate()
+        }
+    }
+
+    /// A bar with a block sliding across it over and over, for when you don't know how far along you are.
+    ///
+    /// With [`Style::reduce_motion`] the block stays still and the bar pulses instead.
+    pub fn indeterminate() -> Self {
+        Self {
+            progress: None,
+            desired_width: None,
+            text: None,
+            show_percentage: false,
+            fill: None,
+            poll_handle: false,
+        }
+    }
+
+    /// Show the progress and text of a [`ProgressHandle`].
+    ///
+    /// Without [`ProgressHandle::on_change`], egui repaints ten times a second
+    /// to look for changes, for as long as the handle [is active](ProgressHandle::is_active).
+    ///
+    /// You can still override the text with [`Self::text`].
+    pub fn from_handle(handle: &ProgressHandle) -> Self {
+        let state = handle.state();
+        let progress_bar = match state.progress {
+            Some(progress) => Self::new(progress),
+            None => Self::indeterminate(),
+        };
+        Self {
+            text: state.text.clone(),
+            poll_handle: !state.finished && state.on_change.is_none(),
+            ..progress_bar
+        }
+    }
+
+    /// The desired width of the bar. Default: all the available width.
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// Show a text on top of the bar, e.g. "Downloading…".
+    ///
+    /// Together with [`Self::show_percentage`] you get both, e.g. "Downloading… 42%".
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Show the progress in percent on top of the bar, e.g. "42%".
+    /// Has no effect on an indeterminate bar.
+    pub fn show_percentage(mut self) -> Self {
+        self.show_percentage = true;
+        self
+    }
+
+    /// The color of the filled part of the bar.
+    /// Default: the selection color ([`style::Selection::bg_fill`]).
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+}
+
+impl Widget for ProgressBar {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let ProgressBar {
+            progress,
+            desired_width,
+            text,
+            show_percentage,
+            fill,
+            poll_handle,
+        } = self;
+
+        if poll_handle {
+            ui.ctx().request_repaint_after(HANDLE_POLL_INTERVAL);
+        }
+
+        let text = match (text, progress.filter(|_| show_percentage)) {
+            (Some(text), Some(progress)) => {
+                Some(format!("{} {}%", text, (progress * 100.0).round() as i32))
+            }
+            (None, Some(progress)) => Some(format!("{}%", (progress * 100.0).round() as i32)),
+            (text, None) => text,
+        };
+
+        let width = desired_width.unwrap_or_else(|| ui.available_size_before_wrap_finite().x);
+        let height = ui.spacing().interact_size.y;
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+        response.widget_info(|| WidgetInfo {
+            label: text.clone(),
+            value: progress.map(|progress| (progress * 100.0).round() as f64),
+            ..WidgetInfo::new(WidgetType::ProgressIndicator)
+        });
+
+        if !ui.clip_rect().intersects(rect) {
+            return response;
+        }
+
+        let visuals = ui.visuals();
+        let corner_radius = rect.height() / 2.0;
+        let fill = fill.unwrap_or(visuals.selection.bg_fill);
+        ui.painter()
+            .rect_filled(rect, corner_radius, visuals.extreme_bg_color);
+
+        if let Some(progress) = progress {
+            if progress > 0.0 {
+                // Never narrower than the rounded ends:
+                let fill_width = (rect.width() * progress).at_least(rect.height());
+                let fill_rect = Rect::from_min_size(rect.min, vec2(fill_width, rect.height()));
+                ui.painter().rect_filled(fill_rect, corner_radius, fill);
+            }
+        } else {
+            let time = ui.input().time;
+            if ui.style().reduce_motion {
+                let pulse =
+                    0.5 + 0.25 * (std::f64::consts::TAU * time / INDETERMINATE_PERIOD).cos();
+                ui.painter()
+                    .rect_filled(rect, corner_radius, fill.linear_multiply(pulse as f32));
+            } else {
+                let block_width =
+                    (INDETERMINATE_BLOCK_WIDTH * rect.width()).at_least(rect.height());
+                // Slide in from the left, and out to the right, easing in and out:
+                let t = (time / INDETERMINATE_PERIOD).fract() as f32;
+                let t = t * t * (3.0 - 2.0 * t);
+                let left = lerp((rect.left() - block_width)..=rect.right(), t);
+                let block_rect =
+                    Rect::from_min_size(pos2(left, rect.top()), vec2(block_width, height));
+                let mut painter = ui.painter().clone();
+                painter.set_clip_rect(rect.intersect(painter.clip_rect()));
+                painter.rect_filled(block_rect, corner_radius, fill);
+            }
+            ui.ctx().request_repaint();
+        }
+
+        if let Some(text) = text {
+            ui.painter().text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                text,
+                TextStyle::Button,
+                visuals.text_color(),
+            );
+        }
+
+        response
+    }
+}
+
+#[test]
+fn test_progress_handle_repaint() {
+    use crate::test_util::run_frame_with_input;
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |handle: &ProgressHandle| {
+        let (_, output) = run_frame_with_input(&mut ctx, Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| ui.add(ProgressBar::from_handle(handle)));
+        });
+        output
+    };
+
+    // Without `on_change`, look for changes now and then until finished:
+    let handle = ProgressHandle::default();
+    handle.set_progress(0.5); // not indeterminate, which is always animated
+    run_frame(&handle); // the first frame may want another frame anyway
+    assert_eq!(run_frame(&handle).repaint_after, Some(HANDLE_POLL_INTERVAL));
+    handle.finish();
+    run_frame(&handle);
+    assert!(!run_frame(&handle).needs_repaint);
+
+    // With `on_change`, only when something changed:
+    let changes = Arc::new(AtomicUsize::new(0));
+    let handle = ProgressHandle::default().on_change({
+        let changes = changes.clone();
+        move || {
+            changes.fetch_add(1, SeqCst);
+        }
+    });
+    let worker = handle.clone();
+    worker.set_progress(0.5);
+    run_frame(&handle);
+    assert!(!run_frame(&handle).needs_repaint);
+    worker.set_progress(0.5);
+    worker.set_text("Working");
+    worker.set_text("Working");
+    worker.finish();
+    assert_eq!(changes.load(SeqCst), 3);
+    assert!(!handle.is_active());
+}
@@ -0,0 +1,428 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use crate::{widgets::Label, *};
+use std::ops::RangeInclusive;
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+
+fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
+    (get_set_value)(None)
+}
+
+fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
+    (get_set_value)(Some(value));
+}
+
+/// One of the two handles of a [`RangeSlider`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Handle {
+    Low,
+    High,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Control a range of numbers with a horizontal slider with two handles.
+///
+/// The region between the handles is filled in.
+/// The handles can not pass each other, so `low <= high` always holds.
+///
+/// Dragging on the slider moves the closest handle.
+/// Each handle can also be focused (e.g. with tab) and moved with the arrow keys.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut min_price: f32 = 20.0;
+/// # let mut max_price: f32 = 80.0;
+/// ui.add(egui::RangeSlider::new(&mut min_price, &mut max_price, 0.0..=100.0).text("Price"));
+/// ```
+///
+/// The default `RangeSlider` size is set by [`crate::style::Spacing::slider_width`].
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct RangeSlider<'a> {
+    get_set_low: GetSetValue<'a>,
+    get_set_high: GetSetValue<'a>,
+    range: RangeInclusive<f64>,
+    integer: bool,
+    smart_aim: bool,
+    show_value: bool,
+    text: String,
+    text_color: Option<Color32>,
+}
+
+impl<'a> RangeSlider<'a> {
+    /// The values are clamped to `range`.
+    pub fn new<Num: emath::Numeric>(
+        low: &'a mut Num,
+        high: &'a mut Num,
+        range: RangeInclusive<Num>,
+    ) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set(
+            range_f64,
+            move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *low = Num::from_f64(v)
+                }
+                low.to_f64()
+            },
+            move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *high = Num::from_f64(v)
+                }
+                high.to_f64()
+            },
+        );
+
+        if Num::INTEGRAL {
+            slf.integer()
+        } else {
+            slf
+        }
+    }
+
+    pub fn from_get_set(
+        range: RangeInclusive<f64>,
+        get_set_low: impl 'a + FnMut(Option<f64>) -> f64,
+        get_set_high: impl 'a + FnMut(Option<f64>) -> f64,
+    ) -> Self {
+        Self {
+            get_set_low: Box::new(get_set_low),
+            get_set_high: Box::new(get_set_high),
+            range,
+            integer: false,
+            smart_aim: true,
+            show_value: true,
+            text: Default::default(),
+            text_color: None,
+        }
+    }
+
+    /// Control whether or not the slider shows the current values.
+    /// Default: `true`.
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Show a text next to the slider (e.g. explaining what the slider controls).
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    pub fn text_color(mut self, text_color: Color32) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    /// Turn smart aim on/off. Default is ON.
+    /// There is almost no point in turning this off.
+    pub fn smart_aim(mut self, smart_aim: bool) -> Self {
+        self.smart_aim = smart_aim;
+        self
+    }
+
+    /// Only allow whole numbers.
+    /// If you use [`Self::new`] with an integer type this is called for you.
+    pub fn integer(mut self) -> Self {
+        self.integer = true;
+        self
+    }
+
+    fn min_max(&self) -> (f64, f64) {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        (start.min(end), start.max(end))
+    }
+
+    fn get_value(&mut self, handle: Handle) -> f64 {
+        let (min, max) = self.min_max();
+        match handle {
+            Handle::Low => get(&mut self.get_set_low).clamp(min, max),
+            Handle::High => get(&mut self.get_set_high).clamp(min, max),
+        }
+    }
+
+    /// Set the value of one handle, keeping it from passing the other one.
+    fn set_value(&mut self, handle: Handle, mut value: f64) {
+        let (min, max) = self.min_max();
+        if self.integer {
+            value = value.round();
+        }
+        match handle {
+            Handle::Low => {
+                let high = self.get_value(Handle::High);
+                set(&mut self.get_set_low, value.clamp(min, high.max(min)));
+            }
+            Handle::High => {
+                let low = self.get_value(Handle::Low);
+                set(&mut self.get_set_high, value.clamp(low.min(max), max));
+            }
+        }
+    }
+
+    /// For instance, `position` is the mouse position and `position_range` is the physical location of the slider on the screen.
+    fn value_from_position(&self, position: f32, position_range: RangeInclusive<f32>) -> f64 {
+        let normalized = remap_clamp(position, position_range, 0.0..=1.0) as f64;
+        lerp(self.range.clone(), normalized)
+    }
+
+    fn position_from_value(&self, value: f64, position_range: RangeInclusive<f32>) -> f32 {
+        let normalized = if self.range.start() == self.range.end() {
+            0.5 // empty range, show center of slider
+        } else {
+            remap_clamp(value, self.range.clone(), 0.0..=1.0)
+        };
+        lerp(position_range, normalized as f32)
+    }
+
+    /// The value at `position`, using smart aim if enabled.
+    fn aimed_value(&self, ui: &Ui, position: f32, position_range: RangeInclusive<f32>) -> f64 {
+        if self.smart_aim {
+            let aim_radius = ui.input().aim_radius();
+            emath::smart_aim::best_in_range_f64(
+                self.value_from_position(position - aim_radius, position_range.clone()),
+                self.value_from_position(position + aim_radius, position_range),
+            )
+        } else {
+            self.value_from_position(position, position_range)
+        }
+    }
+
+    /// Which handle to move when the user presses the slider at `position`.
+    ///
+    /// If both handles are at the same spot we can't tell until the pointer moves.
+    fn closest_handle(
+        &mut self,
+        position: f32,
+        position_range: RangeInclusive<f32>,
+        delta: f32,
+    ) -> Option<Handle> {
+        let low = self.get_value(Handle::Low);
+        let high = self.get_value(Handle::High);
+        let low_distance = (position - self.position_from_value(low, position_range.clone())).abs();
+        let high_distance =
+            (position - self.position_from_value(high, position_range.clone())).abs();
+
+        if low_distance < high_distance {
+            Some(Handle::Low)
+        } else if high_distance < low_distance {
+            Some(Handle::High)
+        } else {
+            // Pick the handle in the direction the pointer is moving:
+            let value = self.value_from_position(position, position_range.clone());
+            let prev_value = self.value_from_position(position - delta, position_range);
+            if value < prev_value || (value < low && delta == 0.0) {
+                Some(Handle::Low)
+            } else if value > prev_value || (value > high && delta == 0.0) {
+                Some(Handle::High)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// delta(value) / delta(points)
+    fn gradient(&self, position_range: &RangeInclusive<f32>) -> f64 {
+        let width = (position_range.end() - position_range.start())
+            .abs()
+            .at_least(1.0);
+        let (min, max) = self.min_max();
+        (max - min) / width as f64
+    }
+}
+
+impl<'a> RangeSlider<'a> {
+    /// Just the slider, no text
+    fn slider_ui(&mut self, ui: &mut Ui) -> Response {
+        let thickness = ui
+            .fonts()
+            .row_height(TextStyle::Button)
+            .at_least(ui.spacing().interact_size.y);
+        let desired_size = vec2(ui.spacing().slider_width, thickness);
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+        let id = response.id;
+
+        let handle_radius = rect.height() / 2.5;
+        let position_range = (rect.left() + handle_radius)..=(rect.right() - handle_radius);
+        let handle_rect = |slf: &Self, value: f64| {
+            let center = pos2(
+                slf.position_from_value(value, position_range.clone()),
+                rect.center().y,
+            );
+            Rect::from_center_size(center, Vec2::splat(2.0 * handle_radius))
+        };
+
+        // The slider itself is the keyboard focus of the low handle,
+        // and the high handle gets its own (tab-able) widget:
+        let high_value = self.get_value(Handle::High);
+        let high_response = ui.interact(
+            handle_rect(self, high_value),
+            id.with("high_handle"),
+            Sense::click(),
+        );
+
+        let active_handle_id = id.with("active_handle");
+        let mut active_handle = None;
+        if let Some(pointer_pos) = response.interact_pointer_pos() {
+            let delta = ui.input().pointer.delta().x;
+            active_handle = if response.drag_started() {
+                None
+            } else {
+                ui.memory()
+                    .id_data_temp
+                    .get::<Option<Handle>>(&active_handle_id)
+                    .copied()
+                    .flatten()
+            };
+            if active_handle.is_none() {
+                active_handle = self.closest_handle(pointer_pos.x, position_range.clone(), delta);
+                ui.memory()
+                    .id_data_temp
+                    .insert(active_handle_id, active_handle);
+            }
+
+            if let Some(handle) = active_handle {
+                let new_value = self.aimed_value(ui, pointer_pos.x, position_range.clone());
+                self.set_value(handle, new_value);
+            }
+        }
+
+        let focused_handle = if response.has_focus() {
+            Some(Handle::Low)
+        } else if high_response.has_focus() {
+            Some(Handle::High)
+        } else {
+            None
+        };
+
+        if let Some(handle) = focused_handle {
+            let kb_step = ui.input().num_presses(Key::ArrowRight) as f32
+                - ui.input().num_presses(Key::ArrowLeft) as f32;
+
+            if kb_step != 0.0 {
+                let prev_value = self.get_value(handle);
+                let prev_position = self.position_from_value(prev_value, position_range.clone());
+                let new_value =
+                    self.aimed_value(ui, prev_position + kb_step, position_range.clone());
+                self.set_value(handle, new_value);
+            }
+        }
+
+        let low = self.get_value(Handle::Low);
+        let high = self.get_value(Handle::High);
+        response.widget_info(|| WidgetInfo::slider(low, &self.text));
+        high_response.widget_info(|| WidgetInfo::slider(high, &self.text));
+
+        // Paint it:
+        {
+            let rail_radius = ui
+                .painter()
+                .round_to_pixel((rect.height() / 4.0).at_least(2.0));
+            let rail_rect = |left: f32, right: f32| {
+                Rect::from_min_max(
+                    pos2(left, rect.center().y - rail_radius),
+                    pos2(right, rect.center().y + rail_radius),
+                )
+            };
+            let low_position = self.position_from_value(low, position_range.clone());
+            let high_position = self.position_from_value(high, position_range);
+
+            let rail_visuals = ui.visuals().widgets.inactive;
+            ui.painter().rect_filled(
+                rail_rect(rect.left(), rect.right()),
+                rail_visuals.corner_radius,
+                rail_visuals.bg_fill,
+            );
+            ui.painter().rect_filled(
+                rail_rect(low_position, high_position),
+                rail_visuals.corner_radius,
+                ui.visuals().selection.bg_fill,
+            );
+
+            let widgets = &ui.visuals().widgets;
+            let handle_visuals = |handle: Handle, hovered: bool| {
+                if active_handle == Some(handle) || focused_handle == Some(handle) {
+                    widgets.active
+                } else if hovered {
+                    widgets.hovered
+                } else {
+                    widgets.inactive
+                }
+            };
+            for &(handle, position, hovered) in &[
+                (Handle::Low, low_position, response.hovered()),
+                (Handle::High, high_position, high_response.hovered()),
+            ] {
+                let visuals = handle_visuals(handle, hovered);
+                ui.painter().add(Shape::Circle {
+                    center: pos2(position, rect.center().y),
+                    radius: handle_radius + visuals.expansion,
+                    fill: visuals.bg_fill,
+                    stroke: visuals.fg_stroke,
+                });
+            }
+        }
+
+        response.union(high_response)
+    }
+
+    fn value_ui(&mut self, ui: &mut Ui) {
+        let (min, max) = self.min_max();
+        let speed = self.gradient(&(0.0..=ui.spacing().slider_width));
+        let max_decimals = if self.integer { Some(0) } else { None };
+
+        let mut low = self.get_value(Handle::Low);
+        let mut high = self.get_value(Handle::High);
+        ui.add(
+            DragValue::new(&mut low)
+                .speed(speed)
+                .clamp_range(min..=high)
+                .max_decimals_opt(max_decimals),
+        );
+        ui.label("–");
+        ui.add(
+            DragValue::new(&mut high)
+                .speed(speed)
+                .clamp_range(low..=max)
+                .max_decimals_opt(max_decimals),
+        );
+        if low != self.get_value(Handle::Low) {
+            self.set_value(Handle::Low, low);
+        }
+        if high != self.get_value(Handle::High) {
+            self.set_value(Handle::High, high);
+        }
+    }
+
+    fn label_ui(&mut self, ui: &mut Ui) {
+        if !self.text.is_empty() {
+            let text_color = self.text_color.unwrap_or_else(|| ui.visuals().text_color());
+            ui.add(Label::new(&self.text).wrap(false).text_color(text_color));
+        }
+    }
+}
+
+impl<'a> Widget for RangeSlider<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let old_values = (self.get_value(Handle::Low), self.get_value(Handle::High));
+
+        let inner_response = ui.horizontal(|ui| {
+            let slider_response = self.slider_ui(ui);
+            if self.show_value {
+                self.value_ui(ui);
+            }
+            self.label_ui(ui);
+            slider_response
+        });
+
+        let mut response = inner_response.inner | inner_response.response;
+        response.changed =
+            (self.get_value(Handle::Low), self.get_value(Handle::High)) != old_values;
+        response
+    }
+}
@@ -1,6 +1,12 @@
 #![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
 
-use crate::{widgets::Label, *};
+use crate::{
+    widgets::{
+        drag_value::{KeyboardSteps, NumFormatter, NumParser},
+        Label,
+    },
+    *,
+};
 use std::ops::RangeInclusive;
 
 // ----------------------------------------------------------------------------
@@ -20,15 +26,22 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 // ----------------------------------------------------------------------------
 
 #[derive(Clone)]
-struct SliderSpec {
-    logarithmic: bool,
+pub(crate) struct SliderSpec {
+    pub logarithmic: bool,
     /// For logarithmic sliders, the smallest positive value we are interested in.
     /// 1 for integer sliders, maybe 1e-6 for others.
-    smallest_positive: f64,
+    pub smallest_positive: f64,
     /// For logarithmic sliders, the largest positive value we are interested in
     /// before the slider switches to `INFINITY`, if that is the higher end.
     /// Default: INFINITY.
-    largest_finite: f64,
+    pub largest_finite: f64,
+}
+
+/// Specifies the orientation of a [`Slider`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SliderOrientation {
+    Horizontal,
+    Vertical,
 }
 
 /// Control a number by a horizontal slider.
@@ -57,13 +70,25 @@ pub struct Slider<'a> {
     spec: SliderSpec,
     clamp_to_range: bool,
     smart_aim: bool,
+    step: Option<f64>,
+    snap_modifiers: Modifiers,
+    keyboard_step: Option<f64>,
+    page_step: Option<f64>,
+    slow_step_factor: f64,
+    fast_step_factor: f64,
+    ticks: Option<f64>,
+    tick_labels: bool,
     show_value: bool,
+    orientation: SliderOrientation,
     prefix: String,
     suffix: String,
     text: String,
     text_color: Option<Color32>,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    custom_formatter: Option<NumFormatter<'a>>,
+    custom_parser: Option<NumParser<'a>>,
+    style: Option<style::WidgetStyleFn<'a>>,
 }
 
 macro_rules! impl_integer_constructor {
@@ -145,13 +170,25 @@ impl<'a> Slider<'a> {
             },
             clamp_to_range: false,
             smart_aim: true,
+            step: None,
+            snap_modifiers: Default::default(),
+            keyboard_step: None,
+            page_step: None,
+            slow_step_factor: 0.1,
+            fast_step_factor: 10.0,
+            ticks: None,
+            tick_labels: false,
             show_value: true,
+            orientation: SliderOrientation::Horizontal,
             prefix: Default::default(),
             suffix: Default::default(),
             text: Default::default(),
             text_color: None,
             min_decimals: 0,
             max_decimals: None,
+            custom_formatter: None,
+            custom_parser: None,
+            style: None,
         }
     }
 
@@ -162,6 +199,21 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Vertical or horizontal slider? The default is horizontal.
+    pub fn orientation(mut self, orientation: SliderOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Make this a vertical slider, e.g. a fader in an audio mixer.
+    ///
+    /// The largest value is at the top, and the value and text are shown below the slider.
+    /// The length of the slider is [`crate::style::Spacing::slider_width`].
+    pub fn vertical(mut self) -> Self {
+        self.orientation = SliderOrientation::Vertical;
+        self
+    }
+
     /// Show a prefix before the number, e.g. "x: "
     pub fn prefix(mut self, prefix: impl ToString) -> Self {
         self.prefix = prefix.to_string();
@@ -185,10 +237,40 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Tweak the look of just this slider, e.g. to give it an accent color.
+    ///
+    /// `style` is called with the visuals of both the rail and the handle,
+    /// for the current interaction state (inactive, hovered, active…).
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut volume = 0.5;
+    /// ui.add(egui::Slider::new(&mut volume, 0.0..=1.0).style(|s| {
+    ///     s.bg_fill = egui::Color32::from_rgb(40, 110, 200);
+    /// }));
+    /// ```
+    pub fn style(mut self, style: impl Fn(&mut style::WidgetVisuals) + 'a) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
     /// Make this a logarithmic slider.
     /// This is great for when the slider spans a huge range,
     /// e.g. from one to a million.
     /// The default is OFF.
+    ///
+    /// Dragging, the arrow keys and the number of decimals shown all follow the logarithmic scale,
+    /// so each order of magnitude gets the same length of the slider.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut frequency = 440.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut frequency, 20.0..=20_000.0)
+    ///         .logarithmic(true)
+    ///         .suffix(" Hz"),
+    /// );
+    /// ```
     pub fn logarithmic(mut self, logarithmic: bool) -> Self {
         self.spec.logarithmic = logarithmic;
         self
@@ -224,6 +306,84 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Snap to multiples of `step` when dragging the slider or using the arrow keys,
+    /// e.g. to only allow whole degrees.
+    /// The arrow keys then move the value one step at a time.
+    /// A `step` of zero turns snapping off, which is the default.
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = (step != 0.0).then(|| step.abs());
+        self
+    }
+
+    /// Only snap to [`Self::step_by`] while all of these modifier keys are held down.
+    /// This gives the user fine control when dragging normally,
+    /// and coarse control when holding the modifiers.
+    /// Default: no modifiers, i.e. always snap.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut angle = 0.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut angle, 0.0..=360.0)
+    ///         .step_by(15.0)
+    ///         .snap_modifiers(egui::Modifiers {
+    ///             alt: true,
+    ///             ..Default::default()
+    ///         }),
+    /// );
+    /// ```
+    pub fn snap_modifiers(mut self, snap_modifiers: Modifiers) -> Self {
+        self.snap_modifiers = snap_modifiers;
+        self
+    }
+
+    /// How much the value changes for each press of an arrow key while the slider has keyboard focus.
+    /// Home and end jump to the ends of the range.
+    /// Default: one point along the slider, or one [`Self::step_by`] when snapping.
+    pub fn keyboard_step(mut self, keyboard_step: f64) -> Self {
+        self.keyboard_step = Some(keyboard_step.abs());
+        self
+    }
+
+    /// How much the value changes for each press of page up or page down while the slider has keyboard focus.
+    /// Default: ten arrow key presses.
+    pub fn page_step(mut self, page_step: f64) -> Self {
+        self.page_step = Some(page_step.abs());
+        self
+    }
+
+    /// Holding down Shift while pressing the arrow or page keys multiplies the step by this, for fine control.
+    /// Ignored while snapping to [`Self::step_by`].
+    /// Default: `0.1`.
+    pub fn slow_step_factor(mut self, slow_step_factor: f64) -> Self {
+        self.slow_step_factor = slow_step_factor;
+        self
+    }
+
+    /// Holding down Ctrl (Cmd on Mac) while pressing the arrow or page keys multiplies the step by this.
+    /// Default: `10.0`.
+    pub fn fast_step_factor(mut self, fast_step_factor: f64) -> Self {
+        self.fast_step_factor = fast_step_factor;
+        self
+    }
+
+    /// Paint a tick mark along the slider at every multiple of `spacing`.
+    /// A `spacing` of zero turns the ticks off, which is the default.
+    ///
+    /// If the ticks would be too close together to tell apart, none are painted.
+    pub fn ticks(mut self, spacing: f64) -> Self {
+        self.ticks = (spacing != 0.0).then(|| spacing.abs());
+        self
+    }
+
+    /// Show the value of each tick mark next to it (below horizontal sliders).
+    /// Only has an effect together with [`Self::ticks`].
+    /// Default: `false`.
+    pub fn tick_labels(mut self, tick_labels: bool) -> Self {
+        self.tick_labels = tick_labels;
+        self
+    }
+
     #[deprecated = "Use fixed_decimals instead"]
     pub fn precision(self, precision: usize) -> Self {
         self.max_decimals(precision)
@@ -258,6 +418,42 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Show the value with your own formatting, e.g. "1.5 km" or in hexadecimal.
+    /// This is also used for the [`Self::tick_labels`].
+    ///
+    /// The formatter is given the value and the range of decimals egui would like to show.
+    /// See [`DragValue::custom_formatter`] for more, and use [`Self::custom_parser`]
+    /// to let users enter the value in the same format.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut color: u32 = 0x4080c0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut color, 0..=0xffffff)
+    ///         .custom_formatter(|n, _| format!("#{:06X}", n as u32))
+    ///         .custom_parser(|text| {
+    ///             let text = text.trim().trim_start_matches('#');
+    ///             u32::from_str_radix(text, 16).map(|n| n as f64).ok()
+    ///         }),
+    /// );
+    /// ```
+    pub fn custom_formatter(
+        mut self,
+        formatter: impl 'a + Fn(f64, RangeInclusive<usize>) -> String,
+    ) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Parse the text the user enters when clicking the value with your own parser.
+    ///
+    /// Return `None` if the text is not a valid value, and the value is left as it is.
+    /// See [`Self::custom_formatter`] for an example.
+    pub fn custom_parser(mut self, parser: impl 'a + Fn(&str) -> Option<f64>) -> Self {
+        self.custom_parser = Some(Box::new(parser));
+        self
+    }
+
     /// Helper: equivalent to `self.precision(0).smallest_positive(1.0)`.
     /// If you use one of the integer constructors (e.g. `Slider::i32`) this is called for you,
     /// but if you want to have a slider for picking integer values in an `Slider::f64`, use this.
@@ -300,49 +496,232 @@ impl<'a> Slider<'a> {
         self.range.clone()
     }
 
-    /// For instance, `x` is the mouse position and `x_range` is the physical location of the slider on the screen.
-    fn value_from_x(&self, x: f32, x_range: RangeInclusive<f32>) -> f64 {
-        let normalized = remap_clamp(x, x_range, 0.0..=1.0) as f64;
+    /// For instance, `position` is the mouse position and `position_range` is the physical location of the slider on the screen.
+    fn value_from_position(&self, position: f32, position_range: RangeInclusive<f32>) -> f64 {
+        let normalized = remap_clamp(position, position_range, 0.0..=1.0) as f64;
         value_from_normalized(normalized, self.range(), &self.spec)
     }
 
-    fn x_from_value(&self, value: f64, x_range: RangeInclusive<f32>) -> f32 {
+    fn position_from_value(&self, value: f64, position_range: RangeInclusive<f32>) -> f32 {
         let normalized = normalized_from_value(value, self.range(), &self.spec);
-        lerp(x_range, normalized as f32)
+        lerp(position_range, normalized as f32)
     }
-}
 
-fn handle_radius(rect: &Rect) -> f32 {
-    rect.height() / 2.5
-}
+    fn handle_radius(&self, rect: &Rect) -> f32 {
+        match self.orientation {
+            SliderOrientation::Horizontal => rect.height() / 2.5,
+            SliderOrientation::Vertical => rect.width() / 2.5,
+        }
+    }
+
+    /// Where the center of the handle is for the start and the end of the range.
+    /// Vertical sliders go from the bottom to the top.
+    fn position_range(&self, rect: &Rect) -> RangeInclusive<f32> {
+        let handle_radius = self.handle_radius(rect);
+        match self.orientation {
+            SliderOrientation::Horizontal => {
+                (rect.left() + handle_radius)..=(rect.right() - handle_radius)
+            }
+            SliderOrientation::Vertical => {
+                (rect.bottom() - handle_radius)..=(rect.top() + handle_radius)
+            }
+        }
+    }
+
+    fn pointer_position(&self, pointer_pos: Pos2) -> f32 {
+        match self.orientation {
+            SliderOrientation::Horizontal => pointer_pos.x,
+            SliderOrientation::Vertical => pointer_pos.y,
+        }
+    }
+
+    /// The step to snap to, if the snap modifiers are held down.
+    fn snap_step(&self, modifiers: &Modifiers) -> Option<f64> {
+        let required = &self.snap_modifiers;
+        let held = (!required.alt || modifiers.alt)
+            && (!required.ctrl || modifiers.ctrl)
+            && (!required.shift || modifiers.shift)
+            && (!required.mac_cmd || modifiers.mac_cmd)
+            && (!required.command || modifiers.command);
+        self.step.filter(|_| held)
+    }
+
+    /// The closest multiple of `step`, without leaving the slider range.
+    fn snap(&self, value: f64, step: f64) -> f64 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let snapped = (value / step).round() * step;
+        snapped.clamp(start.min(end), start.max(end))
+    }
+
+    /// The next multiple of `step` above or below `value`, without leaving the slider range.
+    fn next_step(&self, value: f64, step: f64, increase: bool) -> f64 {
+        let steps = value / step;
+        // Ignore rounding errors, so we don't get stuck on a step:
+        let steps = if (steps - steps.round()).abs() < 1e-6 {
+            steps.round()
+        } else {
+            steps
+        };
+        let steps = if increase {
+            steps.floor() + 1.0
+        } else {
+            steps.ceil() - 1.0
+        };
+        self.snap(steps * step, step)
+    }
+
+    /// The new value after the arrow, page, home or end keys in `keys`.
+    fn keyboard_value(
+        &mut self,
+        keys: &KeyboardSteps,
+        snap_step: Option<f64>,
+        ui: &Ui,
+        position_range: RangeInclusive<f32>,
+    ) -> f64 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        match keys.jump_to_max {
+            Some(true) => return start.max(end),
+            Some(false) => return start.min(end),
+            None => {}
+        }
+
+        // Page up and down go by value, the arrow keys go towards the start or end of the range:
+        let increasing = start <= end;
+        let page_steps = if increasing { keys.pages } else { -keys.pages };
+        let steps = match self.page_step {
+            Some(_) => keys.steps,
+            None => keys.steps + 10.0 * page_steps,
+        };
+        let page_delta = self
+            .page_step
+            .map_or(0.0, |page_step| page_step * keys.pages);
+
+        let value = self.get_value();
+        if let Some(step) = snap_step {
+            // Whole steps only:
+            let factor = keys.factor.max(1.0);
+            let value = self.snap(value + page_delta * factor, step);
+            let num_steps = (steps * factor).round();
+            if num_steps == 0.0 {
+                return value;
+            }
+            let increase = (num_steps > 0.0) == increasing;
+            let value = self.next_step(value, step, increase);
+            let direction = if increase { 1.0 } else { -1.0 };
+            self.snap(value + (num_steps.abs() - 1.0) * step * direction, step)
+        } else if let Some(keyboard_step) = self.keyboard_step {
+            let direction = if increasing { 1.0 } else { -1.0 };
+            value + (steps * keyboard_step * direction + page_delta) * keys.factor
+        } else {
+            // One point along the slider per step:
+            let towards_end = if position_range.start() <= position_range.end() {
+                1.0
+            } else {
+                -1.0
+            };
+            let position = self.position_from_value(value, position_range.clone())
+                + (steps * keys.factor) as f32 * towards_end;
+            let value = if self.smart_aim {
+                let aim_radius = ui.input().aim_radius();
+                emath::smart_aim::best_in_range_f64(
+                    self.value_from_position(position - aim_radius, position_range.clone()),
+                    self.value_from_position(position + aim_radius, position_range),
+                )
+            } else {
+                self.value_from_position(position, position_range)
+            };
+            value + page_delta * keys.factor
+        }
+    }
+
+    /// The values to paint tick marks at, given the length of the slider in points.
+    fn tick_values(&self, spacing: f64, length: f32) -> Vec<f64> {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (min, max) = (start.min(end), start.max(end));
+        if !min.is_finite() || !max.is_finite() {
+            return vec![];
+        }
+        let first = (min / spacing).ceil();
+        let last = (max / spacing).floor();
+        if last - first > (length / 4.0) as f64 {
+            return vec![]; // Too close together to be of any help
+        }
+        (first as i64..=last as i64)
+            .map(|i| i as f64 * spacing)
+            .collect()
+    }
+
+    fn tick_label(&self, value: f64) -> String {
+        let max_decimals = self.max_decimals.unwrap_or(6).max(self.min_decimals);
+        let value_text = if let Some(custom_formatter) = &self.custom_formatter {
+            custom_formatter(value, self.min_decimals..=max_decimals)
+        } else {
+            emath::format_with_decimals_in_range(value, self.min_decimals..=max_decimals)
+        };
+        format!("{}{}", value_text, self.suffix)
+    }
+
+    /// Room for the tick labels: below horizontal sliders and to the right of vertical ones.
+    fn tick_label_room(&self, ui: &Ui) -> f32 {
+        match self.ticks {
+            Some(spacing) if self.tick_labels => match self.orientation {
+                SliderOrientation::Horizontal => ui.fonts().row_height(TextStyle::Small),
+                SliderOrientation::Vertical => self
+                    .tick_values(spacing, ui.spacing().slider_width)
+                    .into_iter()
+                    .map(|value| {
+                        let text = self.tick_label(value);
+                        ui.fonts().layout_no_wrap(TextStyle::Small, text).size.x
+                    })
+                    .fold(0.0, f32::max),
+            },
+            _ => 0.0,
+        }
+    }
 
-fn x_range(rect: &Rect) -> RangeInclusive<f32> {
-    let handle_radius = handle_radius(rect);
-    (rect.left() + handle_radius)..=(rect.right() - handle_radius)
+    /// The part of the slider rectangle that is left for the rail and handle.
+    fn track_rect(&self, mut rect: Rect, tick_label_room: f32) -> Rect {
+        match self.orientation {
+            SliderOrientation::Horizontal => rect.max.y -= tick_label_room,
+            SliderOrientation::Vertical => rect.max.x -= tick_label_room,
+        }
+        rect
+    }
 }
 
 impl<'a> Slider<'a> {
     /// Just the slider, no text
-    #[allow(clippy::unused_self)]
-    fn allocate_slider_space(&self, ui: &mut Ui, height: f32) -> Response {
-        let desired_size = vec2(ui.spacing().slider_width, height);
+    fn allocate_slider_space(&self, ui: &mut Ui, thickness: f32, tick_label_room: f32) -> Response {
+        let thickness = thickness + tick_label_room;
+        let desired_size = match self.orientation {
+            SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
+            SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
+        };
         ui.allocate_response(desired_size, Sense::click_and_drag())
     }
 
     /// Just the slider, no text
-    fn slider_ui(&mut self, ui: &mut Ui, response: &Response) {
-        let rect = &response.rect;
-        let x_range = x_range(rect);
+    fn slider_ui(&mut self, ui: &mut Ui, response: &Response, tick_label_room: f32) {
+        let track_rect = self.track_rect(response.rect, tick_label_room);
+        let rect = &track_rect;
+        let position_range = self.position_range(rect);
+        let snap_step = self.snap_step(&ui.input().modifiers);
 
         if let Some(pointer_pos) = response.interact_pointer_pos() {
-            let new_value = if self.smart_aim {
+            let position = self.pointer_position(pointer_pos);
+            let new_value = if let Some(step) = snap_step {
+                self.snap(
+                    self.value_from_position(position, position_range.clone()),
+                    step,
+                )
+            } else if self.smart_aim {
                 let aim_radius = ui.input().aim_radius();
                 emath::smart_aim::best_in_range_f64(
-                    self.value_from_x(pointer_pos.x - aim_radius, x_range.clone()),
-                    self.value_from_x(pointer_pos.x + aim_radius, x_range.clone()),
+                    self.value_from_position(position - aim_radius, position_range.clone()),
+                    self.value_from_position(position + aim_radius, position_range.clone()),
                 )
             } else {
-                self.value_from_x(pointer_pos.x, x_range.clone())
+                self.value_from_position(position, position_range.clone())
             };
             self.set_value(new_value);
         }
@@ -351,22 +730,20 @@ impl<'a> Slider<'a> {
         response.widget_info(|| WidgetInfo::slider(value, &self.text));
 
         if response.has_focus() {
-            let kb_step = ui.input().num_presses(Key::ArrowRight) as f32
-                - ui.input().num_presses(Key::ArrowLeft) as f32;
-
-            if kb_step != 0.0 {
-                let prev_value = self.get_value();
-                let prev_x = self.x_from_value(prev_value, x_range.clone());
-                let new_x = prev_x + kb_step;
-                let new_value = if self.smart_aim {
-                    let aim_radius = ui.input().aim_radius();
-                    emath::smart_aim::best_in_range_f64(
-                        self.value_from_x(new_x - aim_radius, x_range.clone()),
-                        self.value_from_x(new_x + aim_radius, x_range.clone()),
-                    )
-                } else {
-                    self.value_from_x(new_x, x_range.clone())
-                };
+            // Towards the start and the end of the range, i.e. down and up for vertical sliders:
+            let (towards_start, towards_end) = match self.orientation {
+                SliderOrientation::Horizontal => (Key::ArrowLeft, Key::ArrowRight),
+                SliderOrientation::Vertical => (Key::ArrowDown, Key::ArrowUp),
+            };
+            let keys = KeyboardSteps::new(
+                ui.input(),
+                &[towards_start],
+                &[towards_end],
+                self.slow_step_factor,
+                self.fast_step_factor,
+            );
+            if keys.any() {
+                let new_value = self.keyboard_value(&keys, snap_step, ui, position_range.clone());
                 self.set_value(new_value);
             }
         }
@@ -375,21 +752,42 @@ impl<'a> Slider<'a> {
         {
             let value = self.get_value();
 
-            let rail_radius = ui
-                .painter()
-                .round_to_pixel((rect.height() / 4.0).at_least(2.0));
-
-            let rail_rect = Rect::from_min_max(
-                pos2(rect.left(), rect.center().y - rail_radius),
-                pos2(rect.right(), rect.center().y + rail_radius),
-            );
-            let marker_center_x = self.x_from_value(value, x_range);
+            let thickness = match self.orientation {
+                SliderOrientation::Horizontal => rect.height(),
+                SliderOrientation::Vertical => rect.width(),
+            };
+            let rail_radius = ui.painter().round_to_pixel((thickness / 4.0).at_least(2.0));
+
+            let (rail_rect, handle_center) = {
+                let position = self.position_from_value(value, position_range);
+                match self.orientation {
+                    SliderOrientation::Horizontal => (
+                        Rect::from_min_max(
+                            pos2(rect.left(), rect.center().y - rail_radius),
+                            pos2(rect.right(), rect.center().y + rail_radius),
+                        ),
+                        pos2(position, rect.center().y),
+                    ),
+                    SliderOrientation::Vertical => (
+                        Rect::from_min_max(
+                            pos2(rect.center().x - rail_radius, rect.top()),
+                            pos2(rect.center().x + rail_radius, rect.bottom()),
+                        ),
+                        pos2(rect.center().x, position),
+                    ),
+                }
+            };
 
-            let visuals = ui.style().interact(response);
+            let mut visuals = ui.style().interact(response);
+            let mut rail_visuals = ui.visuals().widgets.inactive;
+            if let Some(style) = &self.style {
+                style(&mut visuals);
+                style(&mut rail_visuals);
+            }
             ui.painter().add(Shape::Rect {
                 rect: rail_rect,
-                corner_radius: ui.visuals().widgets.inactive.corner_radius,
-                fill: ui.visuals().widgets.inactive.bg_fill,
+                corner_radius: rail_visuals.corner_radius,
+                fill: rail_visuals.bg_fill,
                 // fill: visuals.bg_fill,
                 // fill: ui.visuals().extreme_bg_color,
                 stroke: Default::default(),
@@ -397,15 +795,65 @@ impl<'a> Slider<'a> {
                 // stroke: ui.visuals().widgets.inactive.bg_stroke,
             });
 
+            if let Some(spacing) = self.ticks {
+                self.paint_ticks(ui, rect, rail_radius, spacing, tick_label_room);
+            }
+
             ui.painter().add(Shape::Circle {
-                center: pos2(marker_center_x, rail_rect.center().y),
-                radius: handle_radius(rect) + visuals.expansion,
+                center: handle_center,
+                radius: self.handle_radius(rect) + visuals.expansion,
                 fill: visuals.bg_fill,
                 stroke: visuals.fg_stroke,
             });
         }
     }
 
+    fn paint_ticks(
+        &self,
+        ui: &Ui,
+        rect: &Rect,
+        rail_radius: f32,
+        spacing: f64,
+        tick_label_room: f32,
+    ) {
+        let position_range = self.position_range(rect);
+        let length = (position_range.end() - position_range.start()).abs();
+        let stroke = ui.visuals().widgets.inactive.fg_stroke;
+        let tick_half_length = 2.0 * rail_radius;
+        let label_color = ui.visuals().text_color();
+
+        for value in self.tick_values(spacing, length) {
+            let position = self.position_from_value(value, position_range.clone());
+            let center = match self.orientation {
+                SliderOrientation::Horizontal => pos2(position, rect.center().y),
+                SliderOrientation::Vertical => pos2(rect.center().x, position),
+            };
+            let (tick, label_pos, label_anchor) = match self.orientation {
+                SliderOrientation::Horizontal => (
+                    vec2(0.0, tick_half_length),
+                    pos2(position, rect.bottom()),
+                    Align2::CENTER_TOP,
+                ),
+                SliderOrientation::Vertical => (
+                    vec2(tick_half_length, 0.0),
+                    pos2(rect.right(), position),
+                    Align2::LEFT_CENTER,
+                ),
+            };
+            ui.painter()
+                .line_segment([center - tick, center + tick], stroke);
+            if tick_label_room > 0.0 {
+                ui.painter().text(
+                    label_pos,
+                    label_anchor,
+                    self.tick_label(value),
+                    TextStyle::Small,
+                    label_color,
+                );
+            }
+        }
+    }
+
     fn label_ui(&mut self, ui: &mut Ui) {
         if !self.text.is_empty() {
             let text_color = self.text_color.unwrap_or_else(|| ui.visuals().text_color());
@@ -413,58 +861,86 @@ impl<'a> Slider<'a> {
         }
     }
 
-    fn value_ui(&mut self, ui: &mut Ui, x_range: RangeInclusive<f32>) {
+    fn value_ui(&mut self, ui: &mut Ui, position_range: RangeInclusive<f32>) {
         let mut value = self.get_value();
-        ui.add(
-            DragValue::new(&mut value)
-                .speed(self.current_gradient(&x_range))
-                .clamp_range(self.clamp_range())
-                .min_decimals(self.min_decimals)
-                .max_decimals_opt(self.max_decimals)
-                .suffix(self.suffix.clone())
-                .prefix(self.prefix.clone()),
-        );
+        let mut drag_value = DragValue::new(&mut value)
+            .speed(self.current_gradient(&position_range))
+            .clamp_range(self.clamp_range())
+            .min_decimals(self.min_decimals)
+            .max_decimals_opt(self.max_decimals)
+            .slow_speed_factor(self.slow_step_factor)
+            .fast_step_factor(self.fast_step_factor)
+            .suffix(self.suffix.clone())
+            .prefix(self.prefix.clone());
+        if let Some(keyboard_step) = self.keyboard_step {
+            drag_value = drag_value.keyboard_step(keyboard_step);
+        }
+        if let Some(page_step) = self.page_step {
+            drag_value = drag_value.page_step(page_step);
+        }
+        if let Some(custom_formatter) = &self.custom_formatter {
+            drag_value = drag_value.custom_formatter(custom_formatter);
+        }
+        if let Some(custom_parser) = &self.custom_parser {
+            drag_value = drag_value.custom_parser(move |text| custom_parser(text));
+        }
+        ui.add(drag_value);
         if value != self.get_value() {
             self.set_value(value);
         }
     }
 
     /// delta(value) / delta(points)
-    fn current_gradient(&mut self, x_range: &RangeInclusive<f32>) -> f64 {
+    fn current_gradient(&mut self, position_range: &RangeInclusive<f32>) -> f64 {
         // TODO: handle clamping
         let value = self.get_value();
-        let value_from_x = |x: f32| self.value_from_x(x, x_range.clone());
-        let x_from_value = |value: f64| self.x_from_value(value, x_range.clone());
-        let left_value = value_from_x(x_from_value(value) - 0.5);
-        let right_value = value_from_x(x_from_value(value) + 0.5);
+        let value_from_position =
+            |position: f32| self.value_from_position(position, position_range.clone());
+        let position_from_value =
+            |value: f64| self.position_from_value(value, position_range.clone());
+        // Towards the end of the range, i.e. up for vertical sliders:
+        let direction = if position_range.start() <= position_range.end() {
+            0.5
+        } else {
+            -0.5
+        };
+        let left_value = value_from_position(position_from_value(value) - direction);
+        let right_value = value_from_position(position_from_value(value) + direction);
         right_value - left_value
     }
-}
 
-impl<'a> Widget for Slider<'a> {
-    fn ui(mut self, ui: &mut Ui) -> Response {
+    fn add_contents(&mut self, ui: &mut Ui) -> Response {
         let text_style = TextStyle::Button;
-        let height = ui
+        let thickness = ui
             .fonts()
             .row_height(text_style)
             .at_least(ui.spacing().interact_size.y);
 
-        let old_value = self.get_value();
+        let tick_label_room = self.tick_label_room(ui);
+        let slider_response = self.allocate_slider_space(ui, thickness, tick_label_room);
+        self.slider_ui(ui, &slider_response, tick_label_room);
 
-        let inner_response = ui.horizontal(|ui| {
-            let slider_response = self.allocate_slider_space(ui, height);
-            self.slider_ui(ui, &slider_response);
+        if self.show_value {
+            let track_rect = self.track_rect(slider_response.rect, tick_label_room);
+            let position_range = self.position_range(&track_rect);
+            self.value_ui(ui, position_range);
+        }
 
-            if self.show_value {
-                let x_range = x_range(&slider_response.rect);
-                self.value_ui(ui, x_range);
-            }
+        if !self.text.is_empty() {
+            self.label_ui(ui);
+        }
+        slider_response
+    }
+}
 
-            if !self.text.is_empty() {
-                self.label_ui(ui);
-            }
-            slider_response
-        });
+impl<'a> Widget for Slider<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let old_value = self.get_value();
+
+        let inner_response = match self.orientation {
+            SliderOrientation::Horizontal => ui.horizontal(|ui| self.add_contents(ui)),
+            SliderOrientation::Vertical => ui.vertical(|ui| self.add_contents(ui)),
+        };
 
         let mut response = inner_response.inner | inner_response.response;
         response.changed = self.get_value() != old_value;
@@ -484,7 +960,11 @@ use std::f64::INFINITY;
 /// give a scale that this many orders of magnitude in size.
 const INF_RANGE_MAGNITUDE: f64 = 10.0;
 
-fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
+pub(crate) fn value_from_normalized(
+    normalized: f64,
+    range: RangeInclusive<f64>,
+    spec: &SliderSpec,
+) -> f64 {
     let (min, max) = (*range.start(), *range.end());
 
     if min.is_nan() || max.is_nan() {
@@ -533,7 +1013,11 @@ fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &Sli
     }
 }
 
-fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
+pub(crate) fn normalized_from_value(
+    value: f64,
+    range: RangeInclusive<f64>,
+    spec: &SliderSpec,
+) -> f64 {
     let (min, max) = (*range.start(), *range.end());
 
     if min.is_nan() || max.is_nan() {
@@ -625,3 +1109,54 @@ fn logaritmic_zero_cutoff(min: f64, max: f64) -> f64 {
     crate::egui_assert!(0.0 <= cutoff && cutoff <= 1.0);
     cutoff
 }
+
+#[test]
+fn test_logarithmic_slider() {
+    let spec = SliderSpec {
+        logarithmic: true,
+        smallest_positive: 1e-3,
+        largest_finite: f64::INFINITY,
+    };
+    let round_trip = |value: f64, range: RangeInclusive<f64>| {
+        let normalized = normalized_from_value(value, range.clone(), &spec);
+        value_from_normalized(normalized, range, &spec)
+    };
+
+    // Every order of magnitude gets the same length:
+    let range = 20.0..=20_000.0;
+    assert!((normalized_from_value(200.0, range.clone(), &spec) - 1.0 / 3.0).abs() < 1e-9);
+    assert!((normalized_from_value(2_000.0, range.clone(), &spec) - 2.0 / 3.0).abs() < 1e-9);
+    for &value in &[20.0, 440.0, 1234.5, 20_000.0] {
+        assert!((round_trip(value, range.clone()) - value).abs() < 1e-6 * value);
+    }
+
+    // A range starting at zero uses `smallest_positive` as the lower end of the scale:
+    let range = 0.0..=1000.0;
+    assert_eq!(normalized_from_value(0.0, range.clone(), &spec), 0.0);
+    assert!((normalized_from_value(1.0, range.clone(), &spec) - 0.5).abs() < 1e-9);
+    assert!((round_trip(0.01, range) - 0.01).abs() < 1e-12);
+
+    // Ranges crossing zero:
+    let range = -100.0..=100.0;
+    assert!((normalized_from_value(0.0, range.clone(), &spec) - 0.5).abs() < 1e-9);
+    assert!((round_trip(-10.0, range.clone()) + 10.0).abs() < 1e-9);
+    assert!((round_trip(10.0, range) - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_slider_steps() {
+    let mut value = 0.0;
+    let slider = Slider::new(&mut value, 0.0..=1.0).step_by(0.1);
+    assert!((slider.snap(0.33, 0.1) - 0.3).abs() < 1e-9);
+    assert_eq!(slider.snap(1.2, 0.1), 1.0);
+
+    // Don't get stuck on rounding errors:
+    assert!((slider.next_step(0.1 + 0.2, 0.1, true) - 0.4).abs() < 1e-9);
+    assert!((slider.next_step(0.1 + 0.2, 0.1, false) - 0.2).abs() < 1e-9);
+    assert!((slider.next_step(0.35, 0.1, true) - 0.4).abs() < 1e-9);
+    assert_eq!(slider.next_step(0.0, 0.1, false), 0.0);
+
+    let ticks = slider.tick_values(0.25, 100.0);
+    assert_eq!(ticks, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    assert!(slider.tick_values(0.001, 100.0).is_empty());
+}
@@ -0,0 +1,279 @@
+use std::ops::{Range, RangeInclusive};
+
+use crate::*;
+
+const MIN_COLOR: Color32 = Color32::from_rgb(220, 80, 60);
+const MAX_COLOR: Color32 = Color32::from_rgb(80, 180, 80);
+
+/// A tiny chart of some values, as high as a line of text, without any axes.
+///
+/// Small enough to put in every row of a table: however many values there are,
+/// at most a couple of points per pixel column are painted.
+///
+/// The smallest and largest values are marked, and so is the last (current) one.
+/// Hover the chart to see the value under the mouse.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let requests_per_minute = [3.0, 5.0, 4.0, 9.0, 7.0, 6.0, 8.0];
+/// ui.horizontal(|ui| {
+///     ui.label("Requests:");
+///     ui.add(egui::Sparkline::new(&requests_per_minute));
+/// });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Sparkline<'a> {
+    values: &'a [f32],
+    range: Option<RangeInclusive<f32>>,
+    width: Option<f32>,
+    bars: bool,
+    markers: bool,
+    color: Option<Color32>,
+}
+
+impl<'a> Sparkline<'a> {
+    /// Non-finite values (like `NaN`) are left out.
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            range: None,
+            width: None,
+            bars: false,
+            markers: true,
+            color: None,
+        }
+    }
+
+    /// The values at the bottom and top of the chart.
+    /// Default: the smallest and largest values.
+    ///
+    /// Give all sparklines in a table column the same range to make them comparable.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Default: four times as wide as high.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Show the values as bars instead of as a line. Default: `false`.
+    pub fn bars(mut self, bars: bool) -> Self {
+        self.bars = bars;
+        self
+    }
+
+    /// Mark the smallest, largest and last value. Default: `true`.
+    pub fn markers(mut self, markers: bool) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Default: the text color.
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// The finite values of `range` (indices into `values`) with the smallest and largest value.
+fn min_max_index(values: &[f32], range: Range<usize>) -> Option<(usize, usize)> {
+    let mut min_max: Option<(usize, usize)> = None;
+    for i in range {
+        if values[i].is_finite() {
+            min_max = Some(match min_max {
+                None => (i, i),
+                Some((min, max)) => (
+                    if values[i] < values[min] { i } else { min },
+                    if values[i] > values[max] { i } else { max },
+                ),
+            });
+        }
+    }
+    min_max
+}
+
+/// Split `len` values into `num_buckets` consecutive ranges of about the same size.
+fn bucket(index: usize, len: usize, num_buckets: usize) -> Range<usize> {
+    index * len / num_buckets..(index + 1) * len / num_buckets
+}
+
+/// The points of the line through `values` (at most two per pixel column),
+/// using `x` and `y` to place a value with some index.
+fn line_points(
+    values: &[f32],
+    width: f32,
+    x: impl Fn(usize) -> f32,
+    y: impl Fn(f32) -> f32,
+) -> Vec<Pos2> {
+    let num_buckets = values.len().min(width.ceil().at_least(1.0) as usize);
+    let mut points = Vec::with_capacity(2 * num_buckets);
+    for b in 0..num_buckets {
+        let range = bucket(b, values.len(), num_buckets);
+        if let Some((min, max)) = min_max_index(values, range) {
+            // Keep the order, so the line goes up and down the same way as the values:
+            for &i in [min.min(max), min.max(max)].iter() {
+                points.push(pos2(x(i), y(values[i])));
+                if min == max {
+                    break;
+                }
+            }
+        }
+    }
+    points
+}
+
+impl<'a> Widget for Sparkline<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Sparkline {
+            values,
+            range,
+            width,
+            bars,
+            markers,
+            color,
+        } = self;
+
+        let height = ui.fonts().row_height(ui.style().body_text_style);
+        let width = width.unwrap_or(4.0 * height);
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+
+        let all = min_max_index(values, 0..values.len());
+        let last = values.iter().rposition(|value| value.is_finite());
+        response.widget_info(|| WidgetInfo {
+            value: last.map(|last| values[last] as f64),
+            ..WidgetInfo::new(WidgetType::Other)
+        });
+        let (all, last) = match (all, last) {
+            (Some(all), Some(last)) => (all, last),
+            _ => return response, // Nothing to show
+        };
+        if !ui.clip_rect().intersects(rect) {
+            return response;
+        }
+
+        let (min, max) = match range {
+            Some(range) => (*range.start(), *range.end()),
+            None => (values[all.0], values[all.1]),
+        };
+        // Leave room for the markers:
+        let rect = rect.shrink(2.0);
+        let n = values.len();
+        let y = |value: f32| {
+            if min == max {
+                rect.center().y
+            } else {
+                remap_clamp(value, min..=max, rect.bottom()..=rect.top())
+            }
+        };
+        // Bars fill their part of the width, while a line goes from edge to edge:
+        let x = |index: usize| {
+            if bars {
+                rect.left() + rect.width() * (index as f32 + 0.5) / n as f32
+            } else if n == 1 {
+                rect.center().x
+            } else {
+                rect.left() + rect.width() * index as f32 / (n - 1) as f32
+            }
+        };
+
+        let color = color.unwrap_or_else(|| ui.visuals().text_color());
+        let painter = ui.painter();
+        if bars {
+            let base_y = y(0.0_f32.clamp(min.min(max), min.max(max)));
+            let num_bars = n.min(rect.width().ceil().at_least(1.0) as usize);
+            let gap = if rect.width() / num_bars as f32 >= 3.0 {
+                1.0
+            } else {
+                0.0
+            };
+            for b in 0..num_bars {
+                let range = bucket(b, n, num_bars);
+                let (left, right) = (
+                    rect.left() + rect.width() * range.start as f32 / n as f32,
+                    rect.left() + rect.width() * range.end as f32 / n as f32 - gap,
+                );
+                if let Some((lowest, highest)) = min_max_index(values, range) {
+                    // The value farthest from the base:
+                    let top_y = if (y(values[lowest]) - base_y).abs()
+                        > (y(values[highest]) - base_y).abs()
+                    {
+                        y(values[lowest])
+                    } else {
+                        y(values[highest])
+                    };
+                    let bar = Rect::from_two_pos(
+                        pos2(left, top_y),
+                        pos2(right.at_least(left + 1.0), base_y),
+                    );
+                    painter.rect_filled(bar, 0.0, color.linear_multiply(0.6));
+                }
+            }
+        } else {
+            let points = line_points(values, rect.width(), x, y);
+            painter.add(Shape::line(points, Stroke::new(1.0, color)));
+        }
+
+        if markers {
+            let pos = |index: usize| pos2(x(index), y(values[index]));
+            painter.circle_filled(pos(all.0), 1.5, MIN_COLOR);
+            painter.circle_filled(pos(all.1), 1.5, MAX_COLOR);
+            painter.circle_filled(pos(last), 2.0, color);
+        }
+
+        let hovered = response
+            .hover_pos()
+            .map(|pointer| {
+                let index = if bars {
+                    (n as f32 * (pointer.x - rect.left()) / rect.width()).floor()
+                } else {
+                    ((n - 1) as f32 * (pointer.x - rect.left()) / rect.width()).round()
+                };
+                index.clamp(0.0, (n - 1) as f32) as usize
+            })
+            .filter(|&index| values[index].is_finite());
+        if let Some(index) = hovered {
+            let stroke = ui.visuals().widgets.hovered.fg_stroke;
+            ui.painter()
+                .circle_stroke(pos2(x(index), y(values[index])), 2.5, stroke);
+            let format = |value: f32| emath::format_with_decimals_in_range(value as f64, 0..=3);
+            response.on_hover_ui_at_pointer(|ui| {
+                ui.label(format!("#{}: {}", index, format(values[index])));
+                ui.add(
+                    Label::new(format!(
+                        "min {}, max {}, last {}",
+                        format(values[all.0]),
+                        format(values[all.1]),
+                        format(values[last])
+                    ))
+                    .small()
+                    .weak(),
+                );
+            })
+        } else {
+            response
+        }
+    }
+}
+
+#[test]
+fn test_sparkline_line_points() {
+    let values: Vec<f32> = (0..10_000).map(|i| (i as f32 * 0.01).sin()).collect();
+    let points = line_points(&values, 50.0, |i| i as f32, |value| value);
+    assert!(points.len() <= 100, "at most two points per column");
+    let highest = points.iter().map(|p| p.y).fold(f32::MIN, f32::max);
+    let lowest = points.iter().map(|p| p.y).fold(f32::MAX, f32::min);
+    assert!(lowest < -0.999 && highest > 0.999, "the peaks are kept");
+
+    let values = [1.0, f32::NAN, 3.0];
+    let points = line_points(&values, 50.0, |i| i as f32, |value| value);
+    assert_eq!(
+        points,
+        vec![pos2(0.0, 1.0), pos2(2.0, 3.0)],
+        "NaN is skipped"
+    );
+    assert_eq!(min_max_index(&values, 0..3), Some((0, 2)));
+    assert_eq!(min_max_index(&values, 1..2), None);
+}
@@ -0,0 +1,85 @@
+use crate::*;
+
+/// How many seconds one turn of a [`Spinner`] takes.
+const SPINNER_PERIOD: f64 = 1.0;
+
+/// A small spinning arc, showing that something is loading.
+///
+/// Put it next to a label or button for an inline "loading…" state.
+/// egui keeps repainting while a spinner is visible.
+///
+/// With [`Style::reduce_motion`] the spinner doesn't spin, but pulses instead.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// ui.horizontal(|ui| {
+///     ui.add(egui::Spinner::new());
+///     ui.label("Loading…");
+/// });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Default)]
+pub struct Spinner {
+    size: Option<f32>,
+    color: Option<Color32>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The width and height of the spinner.
+    /// Default: the height of an interactive widget ([`style::Spacing::interact_size`]).
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Default: the foreground color of non-interactive widgets.
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Widget for Spinner {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let size = self.size.unwrap_or_else(|| ui.spacing().interact_size.y);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
+        response.widget_info(|| WidgetInfo::new(WidgetType::ProgressIndicator));
+
+        if ui.clip_rect().intersects(rect) {
+            ui.ctx().request_repaint();
+
+            let mut stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+            stroke.width = (size / 8.0).at_least(1.0);
+            if let Some(color) = self.color {
+                stroke.color = color;
+            }
+            let radius = (rect.height() - stroke.width) / 2.0;
+            let center = rect.center();
+            let turns = ui.input().time / SPINNER_PERIOD;
+
+            if ui.style().reduce_motion {
+                let pulse = 0.6 + 0.4 * (std::f64::consts::TAU * turns).cos();
+                stroke.color = stroke.color.linear_multiply(pulse as f32);
+                ui.painter().circle_stroke(center, radius, stroke);
+            } else {
+                // An arc of three quarters of a turn:
+                let start_angle = std::f64::consts::TAU * turns.fract();
+                let num_points = 24;
+                let points = (0..=num_points)
+                    .map(|i| {
+                        let angle = start_angle
+                            + 0.75 * std::f64::consts::TAU * i as f64 / num_points as f64;
+                        center + radius * Vec2::angled(angle as f32)
+                    })
+                    .collect();
+                ui.painter().add(Shape::line(points, stroke));
+            }
+        }
+
+        response
+    }
+}
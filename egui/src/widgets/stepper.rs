@@ -0,0 +1,260 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// How long to hold a button before it starts repeating, in seconds.
+const REPEAT_DELAY: f64 = 0.4;
+
+/// Time between repeats when they start, in seconds.
+const INITIAL_REPEAT_INTERVAL: f64 = 0.12;
+
+/// With acceleration, the repeats get no closer than this, in seconds.
+const MIN_REPEAT_INTERVAL: f64 = 0.03;
+
+/// With acceleration, each repeat is ten steps after this many repeats.
+const REPEATS_BEFORE_BIG_STEPS: u32 = 20;
+
+/// Keeps track of a +/- button being held down.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HoldRepeat {
+    next_repeat: f64,
+    repeats: u32,
+}
+
+impl HoldRepeat {
+    /// The button was pressed at `time`. It steps once right away.
+    fn pressed(time: f64) -> Self {
+        Self {
+            next_repeat: time + REPEAT_DELAY,
+            repeats: 0,
+        }
+    }
+
+    /// The button is still held at `time`. Returns the number of steps to take this frame.
+    fn update(&mut self, time: f64, acceleration: bool) -> u32 {
+        let mut steps = 0;
+        while time >= self.next_repeat {
+            let interval = if acceleration {
+                (INITIAL_REPEAT_INTERVAL * 0.9_f64.powi(self.repeats as i32))
+                    .max(MIN_REPEAT_INTERVAL)
+            } else {
+                INITIAL_REPEAT_INTERVAL
+            };
+            steps += if acceleration && self.repeats >= REPEATS_BEFORE_BIG_STEPS {
+                10
+            } else {
+                1
+            };
+            self.repeats += 1;
+            self.next_repeat += interval;
+        }
+        steps
+    }
+}
+
+/// A [`DragValue`] between a `-` and a `+` button.
+///
+/// Clicking a button changes the value by one [`Self::step`].
+/// Holding it down repeats, faster and faster (see [`Self::acceleration`]).
+/// The buttons are easier to hit than dragging a [`DragValue`], especially on touch screens.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut quantity: u32 = 1;
+/// ui.add(egui::Stepper::new(&mut quantity).clamp_range(1..=99));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Stepper<'a> {
+    get_set_value: Box<dyn 'a + FnMut(Option<f64>) -> f64>,
+    integral: bool,
+    step: f64,
+    clamp_range: RangeInclusive<f64>,
+    speed: f64,
+    prefix: String,
+    suffix: String,
+    max_decimals: Option<usize>,
+    acceleration: bool,
+}
+
+impl<'a> Stepper<'a> {
+    pub fn new<Num: emath::Numeric>(value: &'a mut Num) -> Self {
+        Self {
+            get_set_value: Box::new(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = Num::from_f64(v)
+                }
+                value.to_f64()
+            }),
+            integral: Num::INTEGRAL,
+            step: 1.0,
+            clamp_range: Num::MIN.to_f64()..=Num::MAX.to_f64(),
+            speed: if Num::INTEGRAL { 0.25 } else { 1.0 },
+            prefix: Default::default(),
+            suffix: Default::default(),
+            max_decimals: if Num::INTEGRAL { Some(0) } else { None },
+            acceleration: true,
+        }
+    }
+
+    /// How much the buttons change the value. Default: `1.0`.
+    pub fn step(mut self, step: impl Into<f64>) -> Self {
+        self.step = step.into().abs();
+        self
+    }
+
+    /// Clamp the value to this range. The buttons are disabled at the ends.
+    pub fn clamp_range<Num: emath::Numeric>(mut self, clamp_range: RangeInclusive<Num>) -> Self {
+        self.clamp_range = clamp_range.start().to_f64()..=clamp_range.end().to_f64();
+        self
+    }
+
+    /// How much the value changes when the number is dragged one point, see [`DragValue::speed`].
+    pub fn speed(mut self, speed: impl Into<f64>) -> Self {
+        self.speed = speed.into();
+        self
+    }
+
+    /// Show a prefix before the number, e.g. "x: "
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Add a suffix to the number, this can be e.g. a unit ("°" or " m")
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show at most this many decimals, see [`DragValue::max_decimals`].
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// When a button is held down, repeat faster and faster,
+    /// and eventually go ten steps at a time. Default: `true`.
+    pub fn acceleration(mut self, acceleration: bool) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+}
+
+impl<'a> Widget for Stepper<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Stepper {
+            mut get_set_value,
+            integral,
+            step,
+            clamp_range,
+            speed,
+            prefix,
+            suffix,
+            max_decimals,
+            acceleration,
+        } = self;
+
+        let old_value = get_set_value(None);
+        let (min, max) = (*clamp_range.start(), *clamp_range.end());
+
+        let (mut response, steps) = ui
+            .horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 2.0;
+                let time = ui.input().time;
+
+                // Returns the number of steps to take this frame:
+                let button = |ui: &mut Ui, text: &str, enabled: bool| {
+                    let response = ui.add(
+                        Button::new(text)
+                            .sense(Sense::click_and_drag())
+                            .enabled(enabled),
+                    );
+                    let mut steps = 0;
+                    if response.is_pointer_button_down_on() && enabled {
+                        let mut memory = ui.memory();
+                        match memory.id_data_temp.get_mut::<HoldRepeat>(&response.id) {
+                            Some(hold) => steps = hold.update(time, acceleration),
+                            None => {
+                                steps = 1;
+                                memory
+                                    .id_data_temp
+                                    .insert(response.id, HoldRepeat::pressed(time));
+                            }
+                        }
+                        drop(memory);
+                        ui.ctx().request_repaint();
+                    } else {
+                        ui.memory().id_data_temp.remove(&response.id);
+                    }
+                    (response, steps)
+                };
+
+                let (mut response, decrements) = button(ui, "−", old_value > min);
+
+                let mut drag_value = DragValue::from_get_set(&mut get_set_value)
+                    .speed(speed)
+                    .clamp_range(min..=max)
+                    .prefix(prefix)
+                    .suffix(suffix);
+                if let Some(max_decimals) = max_decimals {
+                    drag_value = drag_value.max_decimals(max_decimals);
+                }
+                response |= ui.add(drag_value);
+
+                let (increment, increments) = button(ui, "+", old_value < max);
+                response |= increment;
+
+                (response, increments as f64 - decrements as f64)
+            })
+            .inner;
+
+        if steps != 0.0 {
+            let value = get_set_value(None) + steps * step;
+            let value = if integral { value.round() } else { value };
+            get_set_value(Some(value.clamp(min, max)));
+        }
+        if get_set_value(None) != old_value {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+#[test]
+fn test_hold_repeat() {
+    let mut hold = HoldRepeat::pressed(0.0);
+    assert_eq!(hold.update(0.2, true), 0, "no repeats before the delay");
+    assert_eq!(hold.update(REPEAT_DELAY, true), 1);
+    assert_eq!(hold.update(REPEAT_DELAY + 0.05, true), 0);
+
+    // Holding for a long time goes faster than without acceleration:
+    let mut fast = HoldRepeat::pressed(0.0);
+    let mut slow = HoldRepeat::pressed(0.0);
+    assert!(fast.update(5.0, true) > 10 * slow.update(5.0, false));
+}
+
+#[test]
+fn test_stepper() {
+    use crate::test_util::{pointer_button, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut value: i32 = 9;
+    let mut run_frame = |value: &mut i32, events: Vec<Event>| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            ui.add(Stepper::new(value).clamp_range(0..=10))
+        })
+    };
+
+    // The "+" button is at the right end:
+    let rect = run_frame(&mut value, vec![]).rect;
+    let plus = pos2(rect.right() - 4.0, rect.center().y);
+    let press = |pressed| pointer_button(plus, pressed);
+
+    let response = run_frame(&mut value, vec![Event::PointerMoved(plus), press(true)]);
+    assert!(response.changed());
+    assert_eq!(value, 10);
+    run_frame(&mut value, vec![press(false)]);
+    run_frame(&mut value, vec![press(true)]);
+    assert_eq!(value, 10, "clamped to the range");
+}
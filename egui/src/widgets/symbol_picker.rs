@@ -0,0 +1,322 @@
+use std::ops::RangeInclusive;
+
+use super::text_edit::{insert_text_later, LastFocusedTextEdit};
+use crate::*;
+
+/// A group of characters in a [`SymbolPicker`].
+struct Category {
+    name: &'static str,
+    icon: &'static str,
+    ranges: &'static [RangeInclusive<u32>],
+}
+
+const CATEGORIES: &[Category] = &[
+    Category {
+        name: "Smileys and people",
+        icon: "😀",
+        ranges: &[0x1F600..=0x1F64F, 0x1F466..=0x1F487],
+    },
+    Category {
+        name: "Nature and objects",
+        icon: "🌟",
+        ranges: &[0x1F300..=0x1F465, 0x1F488..=0x1F5FF],
+    },
+    Category {
+        name: "Transport and maps",
+        icon: "🚀",
+        ranges: &[0x1F680..=0x1F6FF],
+    },
+    Category {
+        name: "Symbols",
+        icon: "☀",
+        ranges: &[0x2600..=0x27BF],
+    },
+    Category {
+        name: "Arrows",
+        icon: "➡",
+        ranges: &[0x2190..=0x21FF, 0x27F0..=0x27FF, 0x2900..=0x297F],
+    },
+    Category {
+        name: "Math",
+        icon: "∑",
+        ranges: &[
+            0x2200..=0x22FF,
+            0x2A00..=0x2AFF,
+            0x00B1..=0x00B1,
+            0x00D7..=0x00D7,
+        ],
+    },
+    Category {
+        name: "Shapes and boxes",
+        icon: "■",
+        ranges: &[0x2500..=0x25FF, 0x2B00..=0x2BFF],
+    },
+    Category {
+        name: "Letters and numbers",
+        icon: "Ω",
+        ranges: &[0x0370..=0x03FF, 0x2070..=0x209F, 0x2100..=0x215F],
+    },
+    Category {
+        name: "Punctuation and currency",
+        icon: "€",
+        ranges: &[0x00A1..=0x00BF, 0x2010..=0x205E, 0x20A0..=0x20CF],
+    },
+    Category {
+        name: "Icons",
+        icon: "",
+        ranges: &[0xE000..=0xF8FF],
+    },
+];
+
+/// The symbols picked most recently, newest first.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+struct RecentSymbols(Vec<char>);
+
+/// How many symbols the "Recent" tab remembers.
+const MAX_RECENT_SYMBOLS: usize = 32;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SymbolPickerState {
+    /// `None` for the recently used symbols.
+    category: Option<usize>,
+    search: String,
+    /// Where to insert picked symbols.
+    text_edit_id: Option<Id>,
+}
+
+/// Does `c` match what the user searched for in `category`?
+///
+/// The search matches the name of the category, the character itself,
+/// or its code point, e.g. "U+2192" or "2192".
+fn matches_search(c: char, category: &str, search: &str) -> bool {
+    let search = search.trim();
+    if search.is_empty() {
+        return true;
+    }
+    let code = search
+        .strip_prefix("U+")
+        .or_else(|| search.strip_prefix("u+"))
+        .unwrap_or(search);
+    category.to_lowercase().contains(&search.to_lowercase())
+        || search.contains(c)
+        || format!("{:04X}", c as u32).starts_with(&code.to_uppercase())
+}
+
+/// A button that opens a popup for picking emojis and other symbols.
+///
+/// The symbols are in categories, with a tab for the recently used ones
+/// (which are remembered with the `persistence` feature). Only symbols that
+/// the fonts can show are listed, so installing more fonts with [`Context::set_fonts`]
+/// adds more symbols. Search by category name or code point, e.g. "arrow" or "U+2192".
+///
+/// The picked symbol is inserted at the cursor of the [`TextEdit`] that had keyboard focus last,
+/// and is also returned.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut message = String::new();
+/// ui.horizontal(|ui| {
+///     ui.text_edit_singleline(&mut message);
+///     if let Some(symbol) = egui::SymbolPicker::new("message_symbols").show(ui).inner {
+///         // `symbol` was inserted in the message.
+///     }
+/// });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct SymbolPicker {
+    id_source: Id,
+    text_style: TextStyle,
+    columns: usize,
+}
+
+impl SymbolPicker {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            text_style: TextStyle::Heading,
+            columns: 10,
+        }
+    }
+
+    /// The size to show the symbols in. Default: [`TextStyle::Heading`].
+    pub fn text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = text_style;
+        self
+    }
+
+    /// How many symbols per row. Default: 10.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Shows the button, and the popup if open.
+    /// Returns the symbol the user picked this frame, if any.
+    pub fn show(self, ui: &mut Ui) -> InnerResponse<Option<char>> {
+        let SymbolPicker {
+            id_source,
+            text_style,
+            columns,
+        } = self;
+
+        let popup_id = ui.make_persistent_id(id_source);
+        let button_response = ui.button("☺").on_hover_text("Insert a symbol");
+        if button_response.clicked() {
+            ui.memory().toggle_popup(popup_id);
+            // Remember where to insert, before the search field takes the focus:
+            let text_edit_id = ui
+                .memory()
+                .data_temp
+                .get::<LastFocusedTextEdit>()
+                .map(|last| last.0);
+            ui.memory()
+                .id_data_temp
+                .get_mut_or_default::<SymbolPickerState>(popup_id)
+                .text_edit_id = text_edit_id;
+        }
+        if !ui.memory().is_popup_open(popup_id) {
+            return InnerResponse::new(None, button_response);
+        }
+
+        let mut state = ui
+            .memory()
+            .id_data_temp
+            .get_or_default::<SymbolPickerState>(popup_id)
+            .clone();
+        let recent = ui
+            .memory()
+            .data
+            .get::<RecentSymbols>()
+            .map(|recent| recent.0.clone())
+            .unwrap_or_default();
+        if state.category.is_none() && recent.is_empty() {
+            state.category = Some(0);
+        }
+
+        let mut picked = None;
+        let area_response = Area::new(popup_id)
+            .order(Order::Foreground)
+            .default_pos(button_response.rect.left_bottom())
+            .show(ui.ctx(), |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.add(
+                        TextEdit::singleline(&mut state.search)
+                            .hint_text("Search, e.g. arrow or U+2192"),
+                    );
+
+                    ui.horizontal(|ui| {
+                        if !recent.is_empty() {
+                            ui.selectable_value(&mut state.category, None, "🕓")
+                                .on_hover_text("Recently used");
+                        }
+                        for (index, category) in CATEGORIES.iter().enumerate() {
+                            ui.selectable_value(&mut state.category, Some(index), category.icon)
+                                .on_hover_text(category.name);
+                        }
+                    });
+                    ui.separator();
+
+                    // Searching looks in all categories:
+                    let font = &ui.fonts()[text_style];
+                    let symbols: Vec<char> = if !state.search.trim().is_empty() {
+                        CATEGORIES
+                            .iter()
+                            .flat_map(|category| {
+                                let search = &state.search;
+                                category
+                                    .ranges
+                                    .iter()
+                                    .flat_map(|range| range.clone().filter_map(std::char::from_u32))
+                                    .filter(move |&c| matches_search(c, category.name, search))
+                            })
+                            .filter(|c| font.characters().contains(c))
+                            .collect()
+                    } else if let Some(category) = state.category {
+                        CATEGORIES[category]
+                            .ranges
+                            .iter()
+                            .flat_map(|range| range.clone().filter_map(std::char::from_u32))
+                            .filter(|c| font.characters().contains(c))
+                            .collect()
+                    } else {
+                        recent.clone()
+                    };
+
+                    let cell_size = Vec2::splat(ui.fonts().row_height(text_style) * 1.3);
+                    let num_rows = (symbols.len() + columns - 1) / columns;
+                    ui.set_width(columns as f32 * (cell_size.x + ui.spacing().item_spacing.x));
+                    if symbols.is_empty() {
+                        ui.add(Label::new("No symbols found").weak());
+                    }
+                    ScrollArea::from_max_height(8.0 * cell_size.y).show_rows(
+                        ui,
+                        cell_size.y,
+                        num_rows,
+                        |ui, row_range| {
+                            for row in symbols
+                                .chunks(columns)
+                                .skip(row_range.start)
+                                .take(row_range.len())
+                            {
+                                ui.horizontal(|ui| {
+                                    for &c in row {
+                                        let button =
+                                            Button::new(c).text_style(text_style).frame(false);
+                                        if ui
+                                            .add_sized(cell_size, button)
+                                            .on_hover_text(format!("U+{:04X}", c as u32))
+                                            .clicked()
+                                        {
+                                            picked = Some(c);
+                                        }
+                                    }
+                                });
+                            }
+                        },
+                    );
+                });
+            });
+
+        if let Some(c) = picked {
+            let mut memory = ui.memory();
+            let recent = &mut memory.data.get_mut_or_default::<RecentSymbols>().0;
+            recent.retain(|&recent| recent != c);
+            recent.insert(0, c);
+            recent.truncate(MAX_RECENT_SYMBOLS);
+            drop(memory);
+            if let Some(text_edit_id) = state.text_edit_id {
+                insert_text_later(ui.ctx(), text_edit_id, c.to_string());
+            }
+        }
+
+        if !button_response.clicked()
+            && (ui.input().key_pressed(Key::Escape) || area_response.clicked_elsewhere())
+        {
+            ui.memory().close_popup();
+        }
+        ui.memory().id_data_temp.insert(popup_id, state);
+
+        InnerResponse::new(picked, button_response)
+    }
+}
+
+#[test]
+fn test_symbol_picker() {
+    assert!(matches_search('→', "Arrows", "arrow"));
+    assert!(matches_search('→', "Arrows", "U+2192"));
+    assert!(matches_search('→', "Arrows", "→"));
+    assert!(!matches_search('→', "Arrows", "math"));
+
+    // Picked symbols go in at the cursor of the text edit:
+    use crate::test_util::run_ui_frame;
+    let mut ctx = CtxRef::default();
+    let mut text = "ab".to_owned();
+    let run_frame = |ctx: &mut CtxRef, text: &mut String| {
+        run_ui_frame(ctx, vec![], |ui| ui.text_edit_singleline(text)).id
+    };
+    let id = run_frame(&mut ctx, &mut text);
+    insert_text_later(&ctx, id, "→".to_owned());
+    run_frame(&mut ctx, &mut text);
+    assert_eq!(text, "ab→");
+}
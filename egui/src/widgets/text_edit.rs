@@ -1,6 +1,11 @@
-use crate::{output::OutputEvent, util::undoer::Undoer, *};
+use crate::{
+    output::OutputEvent,
+    util::undoer::Undoer,
+    widgets::label::{char_rects, paint_char_highlights},
+    *,
+};
 use epaint::{text::cursor::*, *};
-use std::ops::Range;
+use std::{ops::Range, sync::Arc};
 
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
@@ -14,6 +19,32 @@ pub(crate) struct State {
     // If IME candidate window is shown on this text edit.
     #[cfg_attr(feature = "persistence", serde(skip))]
     has_ime: bool,
+
+    /// The char range of the uncommitted IME composition (pre-edit) text, if any.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    ime_char_range: Option<Range<usize>>,
+
+    /// Has the text changed since we got focus? For [`UiEvent::TextCommitted`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    edited: bool,
+}
+
+/// The [`TextEdit`] that had keyboard focus most recently.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LastFocusedTextEdit(pub Id);
+
+/// Text to insert at the cursor of a [`TextEdit`] the next time it is shown, see [`insert_text_later`].
+#[derive(Clone, Debug)]
+struct PendingInsert(String);
+
+/// Insert `text` at the cursor (replacing the selection) of the [`TextEdit`] with the given id
+/// the next time it is shown, as if the user had typed it. The [`TextEdit`] also gets keyboard focus.
+pub(crate) fn insert_text_later(ctx: &Context, text_edit_id: Id, text: String) {
+    let mut memory = ctx.memory();
+    memory
+        .id_data_temp
+        .insert(text_edit_id.with("pending_insert"), PendingInsert(text));
+    memory.request_focus(text_edit_id);
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -217,8 +248,9 @@ impl TextBuffer for String {
 /// ui.add_sized(ui.available_size(), egui::TextEdit::multiline(&mut my_string));
 /// ```
 ///
+/// For syntax highlighting and the like, see [`Self::layouter`].
+///
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
-#[derive(Debug)]
 pub struct TextEdit<'t, S: TextBuffer = String> {
     text: &'t mut S,
     hint_text: String,
@@ -230,9 +262,51 @@ pub struct TextEdit<'t, S: TextBuffer = String> {
     frame: bool,
     multiline: bool,
     enabled: bool,
+    interactive: bool,
+    error: Option<String>,
     desired_width: Option<f32>,
     desired_height_rows: usize,
     lock_focus: bool,
+    layouter: Option<Layouter<'t>>,
+    char_limit: usize,
+    input_filter: Option<InputFilter<'t>>,
+    highlight_ranges: Vec<Range<usize>>,
+    current_highlight: Option<usize>,
+    scroll_to_highlight: Option<usize>,
+}
+
+/// See [`TextEdit::layouter`].
+pub type Layouter<'t> = &'t mut dyn FnMut(&Ui, &str, f32) -> Arc<Galley>;
+
+/// See [`TextEdit::input_filter`].
+pub type InputFilter<'t> = Box<dyn 't + Fn(char) -> bool>;
+
+impl<'t, S: TextBuffer + std::fmt::Debug> std::fmt::Debug for TextEdit<'t, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextEdit")
+            .field("text", &self.text)
+            .field("hint_text", &self.hint_text)
+            .field("id", &self.id)
+            .field("id_source", &self.id_source)
+            .field("text_style", &self.text_style)
+            .field("text_color", &self.text_color)
+            .field("password", &self.password)
+            .field("frame", &self.frame)
+            .field("multiline", &self.multiline)
+            .field("enabled", &self.enabled)
+            .field("interactive", &self.interactive)
+            .field("error", &self.error)
+            .field("desired_width", &self.desired_width)
+            .field("desired_height_rows", &self.desired_height_rows)
+            .field("lock_focus", &self.lock_focus)
+            .field("layouter", &self.layouter.is_some())
+            .field("char_limit", &self.char_limit)
+            .field("input_filter", &self.input_filter.is_some())
+            .field("highlight_ranges", &self.highlight_ranges)
+            .field("current_highlight", &self.current_highlight)
+            .field("scroll_to_highlight", &self.scroll_to_highlight)
+            .finish()
+    }
 }
 impl<'t, S: TextBuffer> TextEdit<'t, S> {
     pub fn cursor(ui: &Ui, id: Id) -> Option<CursorPair> {
@@ -241,6 +315,34 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             .get::<State>(&id)
             .and_then(|state| state.cursorp)
     }
+
+    /// Is there anything to undo (`command + Z`) in the [`TextEdit`] with the given id?
+    ///
+    /// Consecutive edits are merged into one undo point
+    /// until the text has been left unchanged for a second.
+    pub fn can_undo(ui: &Ui, id: Id) -> bool {
+        ui.memory()
+            .id_data
+            .get::<State>(&id)
+            .map_or(false, |state| state.undoer.can_undo())
+    }
+
+    /// Is there anything to redo (`command + shift + Z` or `command + Y`)
+    /// in the [`TextEdit`] with the given id?
+    pub fn can_redo(ui: &Ui, id: Id) -> bool {
+        ui.memory()
+            .id_data
+            .get::<State>(&id)
+            .map_or(false, |state| state.undoer.can_redo())
+    }
+
+    /// Forget the undo and redo history of the [`TextEdit`] with the given id,
+    /// e.g. after loading a new document into it.
+    pub fn clear_undo_history(ui: &Ui, id: Id) {
+        if let Some(state) = ui.memory().id_data.get_mut::<State>(&id) {
+            state.undoer.clear();
+        }
+    }
 }
 
 impl<'t, S: TextBuffer> TextEdit<'t, S> {
@@ -262,9 +364,17 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             frame: true,
             multiline: false,
             enabled: true,
+            interactive: true,
+            error: None,
             desired_width: None,
             desired_height_rows: 1,
             lock_focus: false,
+            layouter: None,
+            char_limit: usize::MAX,
+            input_filter: None,
+            highlight_ranges: vec![],
+            current_highlight: None,
+            scroll_to_highlight: None,
         }
     }
 
@@ -281,9 +391,17 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             frame: true,
             multiline: true,
             enabled: true,
+            interactive: true,
+            error: None,
             desired_width: None,
             desired_height_rows: 4,
             lock_focus: false,
+            layouter: None,
+            char_limit: usize::MAX,
+            input_filter: None,
+            highlight_ranges: vec![],
+            current_highlight: None,
+            scroll_to_highlight: None,
         }
     }
 
@@ -295,6 +413,80 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         self.text_style(TextStyle::Monospace).lock_focus(true)
     }
 
+    /// Override how the text is laid out, e.g. to add syntax highlighting.
+    ///
+    /// The layouter is given the text and the width to wrap it at,
+    /// and must return a [`Galley`] of that exact text.
+    /// Use [`Galley::with_sections`] to color or underline parts of it.
+    /// The wrap width is `f32::INFINITY` for single-line text edits.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut my_code = String::new();
+    /// use egui::epaint::text::{TextFormat, TextSection};
+    ///
+    /// let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+    ///     let galley = ui
+    ///         .fonts()
+    ///         .layout_multiline(egui::TextStyle::Monospace, text.to_owned(), wrap_width);
+    ///     // Underline every "TODO" in red:
+    ///     let sections = text
+    ///         .match_indices("TODO")
+    ///         .map(|(byte_index, _)| {
+    ///             let start = text[..byte_index].chars().count();
+    ///             TextSection {
+    ///                 char_range: start..start + 4,
+    ///                 format: TextFormat {
+    ///                     underline: (1.0, egui::Color32::RED).into(),
+    ///                     ..Default::default()
+    ///                 },
+    ///             }
+    ///         })
+    ///         .collect();
+    ///     std::sync::Arc::new(galley.with_sections(sections))
+    /// };
+    /// ui.add(egui::TextEdit::multiline(&mut my_code).layouter(&mut layouter));
+    /// ```
+    pub fn layouter(mut self, layouter: Layouter<'t>) -> Self {
+        self.layouter = Some(layouter);
+        self
+    }
+
+    /// Paint a background highlight behind these byte ranges of the text,
+    /// e.g. the matches of a "find in document" search.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut text = String::from("one two one");
+    /// let matches: Vec<_> = text.match_indices("one").map(|(i, m)| i..i + m.len()).collect();
+    /// ui.add(
+    ///     egui::TextEdit::multiline(&mut text)
+    ///         .highlight_ranges(matches)
+    ///         .current_highlight(Some(1)),
+    /// );
+    /// ```
+    pub fn highlight_ranges(mut self, highlight_ranges: Vec<Range<usize>>) -> Self {
+        self.highlight_ranges = highlight_ranges;
+        self
+    }
+
+    /// Paint the highlight with this index into [`Self::highlight_ranges`] stronger than the others,
+    /// e.g. the search match the user is currently at.
+    pub fn current_highlight(mut self, index: Option<usize>) -> Self {
+        self.current_highlight = index;
+        self
+    }
+
+    /// Scroll the enclosing [`ScrollArea`] so that the highlight with this index
+    /// into [`Self::highlight_ranges`] is in view.
+    ///
+    /// Only set this on the frame you want to scroll, e.g. when the user presses "Find next",
+    /// or the user won't be able to scroll away from it.
+    pub fn scroll_to_highlight(mut self, index: usize) -> Self {
+        self.scroll_to_highlight = Some(index);
+        self
+    }
+
     /// Use if you want to set an explicit `Id` for this widget.
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
@@ -307,7 +499,10 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         self
     }
 
-    /// Show a faint hint text when the text field is empty.
+    /// Show a faint hint text, e.g. `"Search…"`, when the text field is empty and not focused.
+    ///
+    /// The hint is laid out like the text would be, so it wraps in a multiline `TextEdit`,
+    /// and the `TextEdit` grows to fit it.
     #[allow(clippy::needless_pass_by_value)]
     pub fn hint_text(mut self, hint_text: impl ToString) -> Self {
         self.hint_text = hint_text.to_string();
@@ -315,6 +510,11 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
     }
 
     /// If true, hide the letters from view and prevent copying from the field.
+    ///
+    /// Each character is shown as [`epaint::text::PASSWORD_REPLACEMENT_CHAR`],
+    /// also to screen readers.
+    /// Copy and cut do nothing, and no undo history is kept,
+    /// so the text is never stored anywhere outside of the string you pass in.
     pub fn password(mut self, password: bool) -> Self {
         self.password = password;
         self
@@ -341,6 +541,40 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         self
     }
 
+    /// Default is `true`. If set to `false` the text is read-only:
+    /// it can still be selected, copied and navigated with the cursor, but not edited.
+    ///
+    /// Unlike [`Self::enabled`], the text is not grayed out.
+    /// Useful for showing generated output that the user may want to copy.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut output = "Generated output".to_owned();
+    /// ui.add(egui::TextEdit::multiline(&mut output).interactive(false));
+    /// ```
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Mark the text as invalid with this message, or as valid with `None`.
+    ///
+    /// An invalid `TextEdit` gets a frame in [`crate::style::Visuals::error_fg_color`],
+    /// shows the message below itself while hovered or focused,
+    /// and returns a [`Response`] with [`Response::has_error`] set.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut email = String::new();
+    /// let error = (!email.contains('@')).then(|| "Not a valid email address");
+    /// ui.add(egui::TextEdit::singleline(&mut email).error(error));
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn error(mut self, error: Option<impl ToString>) -> Self {
+        self.error = error.map(|error| error.to_string());
+        self
+    }
+
     /// Default is `true`. If set to `false` there will be no frame showing that this is editable text!
     pub fn frame(mut self, frame: bool) -> Self {
         self.frame = frame;
@@ -370,11 +604,41 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         self.lock_focus = b;
         self
     }
+
+    /// Don't let the user type or paste more than this many characters in total.
+    ///
+    /// Pasted text is cut short to fit. Text that is already longer is left as is.
+    pub fn char_limit(mut self, char_limit: usize) -> Self {
+        self.char_limit = char_limit;
+        self
+    }
+
+    /// Only let the user type or paste characters for which `input_filter` returns `true`.
+    ///
+    /// Rejected characters are dropped before they reach the text,
+    /// so the cursor ends up right after what was actually inserted.
+    /// Typing only rejected characters leaves the selection alone.
+    /// The newlines and tabs inserted by Enter and Tab are always let through.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut hex = String::new();
+    /// ui.add(
+    ///     egui::TextEdit::singleline(&mut hex)
+    ///         .input_filter(|c| c.is_ascii_hexdigit())
+    ///         .char_limit(6),
+    /// );
+    /// ```
+    pub fn input_filter(mut self, input_filter: impl 't + Fn(char) -> bool) -> Self {
+        self.input_filter = Some(Box::new(input_filter));
+        self
+    }
 }
 
 impl<'t, S: TextBuffer> Widget for TextEdit<'t, S> {
-    fn ui(self, ui: &mut Ui) -> Response {
+    fn ui(mut self, ui: &mut Ui) -> Response {
         let frame = self.frame;
+        let error = self.error.take();
         let where_to_put_background = ui.painter().add(Shape::Noop);
 
         let margin = Vec2::new(4.0, 2.0);
@@ -385,10 +649,13 @@ impl<'t, S: TextBuffer> Widget for TextEdit<'t, S> {
         let frame_rect = response.rect.expand2(margin);
         ui.allocate_rect(frame_rect, Sense::hover());
         let frame_response = ui.interact(frame_rect, id, Sense::click());
-        let response = response | frame_response;
+        let mut response = response | frame_response;
         if response.clicked() {
             ui.memory().request_focus(response.id);
         }
+        if error.is_some() {
+            response.mark_error();
+        }
 
         if frame {
             let visuals = ui.style().interact(&response);
@@ -413,6 +680,31 @@ impl<'t, S: TextBuffer> Widget for TextEdit<'t, S> {
             ui.painter().set(where_to_put_background, shape);
         }
 
+        if let Some(error) = error {
+            let error_color = ui.visuals().error_fg_color;
+            let visuals = ui.style().interact(&response);
+            let stroke_width = visuals
+                .bg_stroke
+                .width
+                .max(ui.visuals().selection.stroke.width);
+            ui.painter().rect_stroke(
+                response.rect.expand(visuals.expansion),
+                visuals.corner_radius,
+                Stroke::new(stroke_width.at_least(1.0), error_color),
+            );
+
+            if response.hovered() || response.has_focus() {
+                crate::containers::show_tooltip_under(
+                    ui.ctx(),
+                    response.id.with("__error"),
+                    &response.rect,
+                    |ui| {
+                        ui.add(Label::new(error).text_color(error_color));
+                    },
+                );
+            }
+        }
+
         response
     }
 }
@@ -436,11 +728,24 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             frame: _,
             multiline,
             enabled,
+            interactive,
+            error: _,
             desired_width,
             desired_height_rows,
             lock_focus,
+            mut layouter,
+            char_limit,
+            input_filter,
+            highlight_ranges,
+            current_highlight,
+            scroll_to_highlight,
         } = self;
 
+        let input_rules = InputRules {
+            char_limit,
+            filter: input_filter.as_deref(),
+        };
+
         let mask_if_password = |text: &str| {
             if password {
                 mask_massword(text)
@@ -456,9 +761,16 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         let line_spacing = ui.fonts().row_height(text_style);
         let available_width = ui.available_width();
 
-        let make_galley = |ui: &Ui, text: &str| {
+        let mut make_galley = |ui: &Ui, text: &str| {
             let text = mask_if_password(text);
-            if multiline {
+            if let Some(layouter) = &mut layouter {
+                let wrap_width = if multiline {
+                    available_width
+                } else {
+                    f32::INFINITY
+                };
+                layouter(ui, &text, wrap_width)
+            } else if multiline {
                 ui.fonts()
                     .layout_multiline(text_style, text, available_width)
             } else {
@@ -474,11 +786,30 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
 
         let mut galley = make_galley(ui, text.as_ref());
 
+        // The hint is laid out just like the text, so it wraps and aligns the same way:
+        let layout_hint_text = |ui: &Ui| {
+            if multiline {
+                ui.fonts()
+                    .layout_multiline(text_style, hint_text.clone(), available_width)
+            } else {
+                ui.fonts().layout_single_line(text_style, hint_text.clone())
+            }
+        };
+        let hint_galley =
+            (text.as_ref().is_empty() && !hint_text.is_empty()).then(|| layout_hint_text(ui));
+        let hint_size = hint_galley
+            .as_ref()
+            .map_or(Vec2::ZERO, |hint_galley| hint_galley.size);
+
         let desired_width = desired_width.unwrap_or_else(|| ui.spacing().text_edit_width);
         let desired_height = (desired_height_rows.at_least(1) as f32) * line_spacing;
         let desired_size = vec2(
-            galley.size.x.max(desired_width.min(available_width)),
-            galley.size.y.max(desired_height),
+            galley
+                .size
+                .x
+                .max(hint_size.x)
+                .max(desired_width.min(available_width)),
+            galley.size.y.max(hint_size.y).max(desired_height),
         );
         let (auto_id, rect) = ui.allocate_space(desired_size);
 
@@ -500,54 +831,73 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
 
         if enabled {
             if let Some(pointer_pos) = ui.input().pointer.interact_pos() {
-                // TODO: triple-click to select whole paragraph
-                // TODO: drag selected text to either move or clone (ctrl on windows, alt on mac)
-
-                let cursor_at_pointer = galley.cursor_from_pos(pointer_pos - response.rect.min);
-
                 if ui.visuals().text_cursor_preview
                     && response.hovered()
                     && ui.input().pointer.is_moving()
                 {
                     // preview:
+                    let cursor_at_pointer = galley.cursor_from_pos(pointer_pos - response.rect.min);
                     paint_cursor_end(ui, response.rect.min, &galley, &cursor_at_pointer);
                 }
+            }
+            select_with_pointer(
+                ui,
+                &response,
+                text.as_ref(),
+                response.rect.min,
+                &galley,
+                &mut state.cursorp,
+            );
+        }
+
+        if response.hovered() && enabled {
+            ui.output().cursor_icon = CursorIcon::Text;
+        }
 
-                if response.double_clicked() {
-                    // Select word:
-                    let center = cursor_at_pointer;
-                    let ccursorp = select_word_at(text.as_ref(), center.ccursor);
-                    state.cursorp = Some(CursorPair {
-                        primary: galley.from_ccursor(ccursorp.primary),
-                        secondary: galley.from_ccursor(ccursorp.secondary),
+        if enabled && interactive && ui.rect_contains_pointer(rect) {
+            // Text being dragged from another widget (see `Response::on_drag_text`) or from another application:
+            let dragged_text = ui.memory().dragged_text().map(ToOwned::to_owned);
+            let pointer_pos = ui.input().pointer.hover_pos();
+            if let Some(pointer_pos) = pointer_pos {
+                if dragged_text.is_some() || ui.input().raw.hovered_text {
+                    let cursor_at_pointer = galley.cursor_from_pos(pointer_pos - rect.min);
+                    paint_cursor_end(ui, rect.min, &galley, &cursor_at_pointer);
+
+                    let dropped_text = if ui.input().pointer.any_released() {
+                        dragged_text
+                    } else {
+                        None
+                    };
+                    let dropped_text = dropped_text.or_else(|| {
+                        ui.input().events.iter().find_map(|event| match event {
+                            Event::DroppedText(dropped_text) => Some(dropped_text.clone()),
+                            _ => None,
+                        })
                     });
-                } else if response.hovered() && ui.input().pointer.any_pressed() {
-                    ui.memory().request_focus(id);
-                    if ui.input().modifiers.shift {
-                        if let Some(cursorp) = &mut state.cursorp {
-                            cursorp.primary = cursor_at_pointer;
-                        } else {
-                            state.cursorp = Some(CursorPair::one(cursor_at_pointer));
+
+                    if let Some(dropped_text) = dropped_text {
+                        let start = cursor_at_pointer.ccursor;
+                        let mut end = start;
+                        if insert_text(&mut end, text, &dropped_text, &input_rules) > 0 {
+                            response.mark_changed();
+                            galley = make_galley(ui, text.as_ref());
+                            // Select what was dropped:
+                            state.cursorp = Some(CursorPair {
+                                primary: galley.from_ccursor(end),
+                                secondary: galley.from_ccursor(start),
+                            });
+                            ui.memory().request_focus(id);
                         }
-                    } else {
-                        state.cursorp = Some(CursorPair::one(cursor_at_pointer));
-                    }
-                } else if ui.input().pointer.any_down() && response.is_pointer_button_down_on() {
-                    if let Some(cursorp) = &mut state.cursorp {
-                        cursorp.primary = cursor_at_pointer;
                     }
                 }
             }
         }
 
-        if response.hovered() && enabled {
-            ui.output().cursor_icon = CursorIcon::Text;
-        }
-
         let mut text_cursor = None;
         let prev_text_cursor = state.cursorp;
         if ui.memory().has_focus(id) && enabled {
             ui.memory().lock_focus(id, lock_focus);
+            ui.memory().data_temp.insert(LastFocusedTextEdit(id));
 
             let mut cursorp = state
                 .cursorp
@@ -566,178 +916,248 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
                 })
                 .unwrap_or_else(|| CursorPair::one(galley.end()));
 
-            // We feed state to the undoer both before and after handling input
-            // so that the undoer creates automatic saves even when there are no events for a while.
-            state.undoer.feed_state(
-                ui.input().time,
-                &(cursorp.as_ccursorp(), text.as_ref().to_owned()),
-            );
+            if interactive {
+                // We feed state to the undoer both before and after handling input
+                // so that the undoer creates automatic saves even when there are no events for a while.
+                // Passwords are not kept in the undo history.
+                if password {
+                    state.undoer.clear();
+                } else {
+                    state.undoer.feed_state(
+                        ui.input().time,
+                        &(cursorp.as_ccursorp(), text.as_ref().to_owned()),
+                    );
+                }
 
-            for event in &ui.input().events {
-                let did_mutate_text = match event {
-                    Event::Copy => {
-                        if cursorp.is_empty() {
-                            copy_if_not_password(ui, text.as_ref().to_owned());
-                        } else {
-                            copy_if_not_password(
-                                ui,
-                                selected_str(text.as_ref(), &cursorp).to_owned(),
-                            );
+                let mut events = ui.input().events.clone();
+                let pending_id = id.with("pending_insert");
+                let pending = ui
+                    .memory()
+                    .id_data_temp
+                    .get::<PendingInsert>(&pending_id)
+                    .cloned();
+                if let Some(PendingInsert(pending)) = pending {
+                    ui.memory().id_data_temp.remove(&pending_id);
+                    events.push(Event::Text(pending));
+                }
+
+                for event in &events {
+                    let did_mutate_text = match event {
+                        Event::Copy => {
+                            if cursorp.is_empty() {
+                                copy_if_not_password(ui, text.as_ref().to_owned());
+                            } else {
+                                copy_if_not_password(
+                                    ui,
+                                    selected_str(text.as_ref(), &cursorp).to_owned(),
+                                );
+                            }
+                            None
                         }
-                        None
-                    }
-                    Event::Cut => {
-                        if cursorp.is_empty() {
-                            copy_if_not_password(ui, text.take());
-                            Some(CCursorPair::default())
-                        } else {
-                            copy_if_not_password(
-                                ui,
-                                selected_str(text.as_ref(), &cursorp).to_owned(),
-                            );
-                            Some(CCursorPair::one(delete_selected(text, &cursorp)))
+                        Event::Cut if password => None, // Don't delete what we can't copy
+                        Event::Cut => {
+                            if cursorp.is_empty() {
+                                copy_if_not_password(ui, text.take());
+                                Some(CCursorPair::default())
+                            } else {
+                                copy_if_not_password(
+                                    ui,
+                                    selected_str(text.as_ref(), &cursorp).to_owned(),
+                                );
+                                Some(CCursorPair::one(delete_selected(text, &cursorp)))
+                            }
                         }
-                    }
-                    Event::Text(text_to_insert) => {
-                        // Newlines are handled by `Key::Enter`.
-                        if !text_to_insert.is_empty()
-                            && text_to_insert != "\n"
-                            && text_to_insert != "\r"
-                        {
-                            let mut ccursor = delete_selected(text, &cursorp);
-                            insert_text(&mut ccursor, text, text_to_insert);
-                            Some(CCursorPair::one(ccursor))
-                        } else {
-                            None
+                        Event::Text(text_to_insert) => {
+                            // Newlines are handled by `Key::Enter`.
+                            if !text_to_insert.is_empty()
+                                && text_to_insert != "\n"
+                                && text_to_insert != "\r"
+                                && !input_rules.rejects_all(text_to_insert)
+                            {
+                                let mut ccursor = delete_selected(text, &cursorp);
+                                let num_inserted =
+                                    insert_text(&mut ccursor, text, text_to_insert, &input_rules);
+                                if num_inserted == 0 && cursorp.is_empty() {
+                                    None // Everything was filtered out
+                                } else {
+                                    Some(CCursorPair::one(ccursor))
+                                }
+                            } else {
+                                None
+                            }
                         }
-                    }
-                    Event::Key {
-                        key: Key::Tab,
-                        pressed: true,
-                        modifiers,
-                    } => {
-                        if multiline && ui.memory().has_lock_focus(id) {
-                            let mut ccursor = delete_selected(text, &cursorp);
-                            if modifiers.shift {
-                                // TODO: support removing indentation over a selection?
-                                decrease_identation(&mut ccursor, text);
+                        Event::Key {
+                            key: Key::Tab,
+                            pressed: true,
+                            modifiers,
+                        } => {
+                            if multiline && ui.memory().has_lock_focus(id) {
+                                let mut ccursor = delete_selected(text, &cursorp);
+                                if modifiers.shift {
+                                    // TODO: support removing indentation over a selection?
+                                    decrease_identation(&mut ccursor, text);
+                                } else {
+                                    insert_text(&mut ccursor, text, "\t", &input_rules);
+                                }
+                                Some(CCursorPair::one(ccursor))
                             } else {
-                                insert_text(&mut ccursor, text, "\t");
+                                None
                             }
-                            Some(CCursorPair::one(ccursor))
-                        } else {
-                            None
                         }
-                    }
-                    Event::Key {
-                        key: Key::Enter,
-                        pressed: true,
-                        ..
-                    } => {
-                        if multiline {
-                            let mut ccursor = delete_selected(text, &cursorp);
-                            insert_text(&mut ccursor, text, "\n");
-                            Some(CCursorPair::one(ccursor))
-                        } else {
-                            ui.memory().surrender_focus(id); // End input with enter
-                            break;
+                        Event::Key {
+                            key: Key::Enter,
+                            pressed: true,
+                            ..
+                        } => {
+                            if multiline {
+                                let mut ccursor = delete_selected(text, &cursorp);
+                                insert_text(&mut ccursor, text, "\n", &input_rules);
+                                Some(CCursorPair::one(ccursor))
+                            } else {
+                                ui.memory().surrender_focus(id); // End input with enter
+                                break;
+                            }
                         }
-                    }
-                    Event::Key {
-                        key: Key::Z,
-                        pressed: true,
-                        modifiers,
-                    } if modifiers.command && !modifiers.shift => {
-                        // TODO: redo
-                        if let Some((undo_ccursorp, undo_txt)) = state
-                            .undoer
-                            .undo(&(cursorp.as_ccursorp(), text.as_ref().to_owned()))
+                        Event::Key {
+                            key: Key::Z,
+                            pressed: true,
+                            modifiers,
+                        } if modifiers.command && !modifiers.shift => {
+                            if let Some((undo_ccursorp, undo_txt)) = state
+                                .undoer
+                                .undo(&(cursorp.as_ccursorp(), text.as_ref().to_owned()))
+                            {
+                                text.replace(undo_txt);
+                                Some(*undo_ccursorp)
+                            } else {
+                                None
+                            }
+                        }
+                        Event::Key {
+                            key,
+                            pressed: true,
+                            modifiers,
+                        } if modifiers.command
+                            && ((*key == Key::Z && modifiers.shift) || *key == Key::Y) =>
                         {
-                            text.replace(undo_txt);
-                            Some(*undo_ccursorp)
-                        } else {
-                            None
+                            if let Some((redo_ccursorp, redo_txt)) = state
+                                .undoer
+                                .redo(&(cursorp.as_ccursorp(), text.as_ref().to_owned()))
+                            {
+                                text.replace(redo_txt);
+                                Some(*redo_ccursorp)
+                            } else {
+                                None
+                            }
                         }
-                    }
 
-                    Event::Key {
-                        key,
-                        pressed: true,
-                        modifiers,
-                    } => on_key_press(&mut cursorp, text, &galley, *key, modifiers),
+                        Event::Key {
+                            key,
+                            pressed: true,
+                            modifiers,
+                        } => on_key_press(&mut cursorp, text, &galley, *key, modifiers),
 
-                    Event::CompositionStart => {
-                        state.has_ime = true;
-                        None
-                    }
-
-                    Event::CompositionUpdate(text_mark) => {
-                        if !text_mark.is_empty()
-                            && text_mark != "\n"
-                            && text_mark != "\r"
-                            && state.has_ime
-                        {
-                            let mut ccursor = delete_selected(text, &cursorp);
-                            let start_cursor = ccursor;
-                            insert_text(&mut ccursor, text, text_mark);
-                            Some(CCursorPair::two(start_cursor, ccursor))
-                        } else {
+                        Event::CompositionStart => {
+                            state.has_ime = true;
+                            state.ime_char_range = None;
                             None
                         }
-                    }
 
-                    Event::CompositionEnd(prediction) => {
-                        if !prediction.is_empty()
-                            && prediction != "\n"
-                            && prediction != "\r"
-                            && state.has_ime
-                        {
-                            state.has_ime = false;
-                            let mut ccursor = delete_selected(text, &cursorp);
-                            insert_text(&mut ccursor, text, prediction);
-                            Some(CCursorPair::one(ccursor))
-                        } else {
-                            None
+                        Event::CompositionUpdate(text_mark) => {
+                            if text_mark != "\n" && text_mark != "\r" && state.has_ime {
+                                // Replace the previous pre-edit text (or the selection) with the new one:
+                                let mut ccursor = delete_ime_or_selected(
+                                    text,
+                                    &cursorp,
+                                    state.ime_char_range.take(),
+                                );
+                                let start_cursor = ccursor;
+                                insert_text(&mut ccursor, text, text_mark, &input_rules);
+                                if ccursor.index > start_cursor.index {
+                                    state.ime_char_range = Some(start_cursor.index..ccursor.index);
+                                }
+                                Some(CCursorPair::one(ccursor))
+                            } else {
+                                None
+                            }
                         }
-                    }
 
-                    _ => None,
-                };
+                        Event::CompositionEnd(prediction) => {
+                            if prediction != "\n" && prediction != "\r" && state.has_ime {
+                                state.has_ime = false;
+                                let mut ccursor = delete_ime_or_selected(
+                                    text,
+                                    &cursorp,
+                                    state.ime_char_range.take(),
+                                );
+                                insert_text(&mut ccursor, text, prediction, &input_rules);
+                                Some(CCursorPair::one(ccursor))
+                            } else {
+                                None
+                            }
+                        }
+
+                        _ => None,
+                    };
 
-                if let Some(new_ccursorp) = did_mutate_text {
-                    response.mark_changed();
+                    if let Some(new_ccursorp) = did_mutate_text {
+                        response.mark_changed();
 
-                    // Layout again to avoid frame delay, and to keep `text` and `galley` in sync.
-                    galley = make_galley(ui, text.as_ref());
+                        // Layout again to avoid frame delay, and to keep `text` and `galley` in sync.
+                        galley = make_galley(ui, text.as_ref());
 
-                    // Set cursorp using new galley:
-                    cursorp = CursorPair {
-                        primary: galley.from_ccursor(new_ccursorp.primary),
-                        secondary: galley.from_ccursor(new_ccursorp.secondary),
-                    };
+                        // Set cursorp using new galley:
+                        cursorp = CursorPair {
+                            primary: galley.from_ccursor(new_ccursorp.primary),
+                            secondary: galley.from_ccursor(new_ccursorp.secondary),
+                        };
+                    }
                 }
+            } else {
+                on_read_only_events(ui, !password, &galley, &mut cursorp);
             }
             state.cursorp = Some(cursorp);
             text_cursor = Some(cursorp);
 
-            state.undoer.feed_state(
-                ui.input().time,
-                &(cursorp.as_ccursorp(), text.as_ref().to_owned()),
-            );
+            if interactive && !password {
+                state.undoer.feed_state(
+                    ui.input().time,
+                    &(cursorp.as_ccursorp(), text.as_ref().to_owned()),
+                );
+            }
         }
 
-        if ui.memory().has_focus(id) {
-            if let Some(cursorp) = state.cursorp {
-                paint_cursor_selection(ui, response.rect.min, &galley, &cursorp);
-                paint_cursor_end(ui, response.rect.min, &galley, &cursorp.primary);
-
-                if enabled {
-                    ui.ctx().output().text_cursor_pos = Some(
-                        galley
-                            .pos_from_cursor(&cursorp.primary)
-                            .translate(response.rect.min.to_vec2())
-                            .left_top(),
-                    );
+        if !highlight_ranges.is_empty() {
+            let byte_offsets: Vec<usize> = text.as_ref().char_indices().map(|(i, _)| i).collect();
+            let color = ui.visuals().selection.bg_fill;
+            for (i, byte_range) in highlight_ranges.iter().enumerate() {
+                let char_range = char_range_from_byte_range(&byte_offsets, byte_range);
+                if char_range.is_empty() {
+                    continue;
+                }
+                let char_indices: Vec<usize> = char_range.clone().collect();
+                let color = if current_highlight == Some(i) {
+                    color
+                } else {
+                    color.linear_multiply(0.4)
+                };
+                paint_char_highlights(
+                    ui.painter(),
+                    response.rect.min,
+                    &galley,
+                    &char_indices,
+                    color,
+                );
+
+                if scroll_to_highlight == Some(i) {
+                    let first = galley
+                        .pos_from_cursor(&galley.from_ccursor(CCursor::new(char_range.start)));
+                    let last =
+                        galley.pos_from_cursor(&galley.from_ccursor(CCursor::new(char_range.end)));
+                    let y_range =
+                        (response.rect.top() + first.top())..=(response.rect.top() + last.bottom());
+                    let scroll_target = lerp(y_range, Align::Center.to_factor());
+                    ui.ctx().frame_state().scroll_target = Some((scroll_target, Align::Center));
                 }
             }
         }
@@ -746,20 +1166,73 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             .or(ui.visuals().override_text_color)
             // .unwrap_or_else(|| ui.style().interact(&response).text_color()); // too bright
             .unwrap_or_else(|| ui.visuals().widgets.inactive.text_color());
-        ui.painter().galley(response.rect.min, galley, text_color);
 
-        if text.as_ref().is_empty() && !hint_text.is_empty() {
-            let galley = if multiline {
-                ui.fonts()
-                    .layout_multiline(text_style, hint_text, available_width)
-            } else {
-                ui.fonts().layout_single_line(text_style, hint_text)
-            };
+        let has_focus = ui.memory().has_focus(id);
+        // Without focus the text may have been changed from the outside since the selection was made:
+        let selection = state.cursorp.map(|cursorp| CursorPair {
+            primary: galley.from_ccursor(cursorp.primary.ccursor),
+            secondary: galley.from_ccursor(cursorp.secondary.ccursor),
+        });
+        if let Some(selection) = &selection {
+            paint_cursor_selection(ui, response.rect.min, &galley, selection, has_focus);
+        }
+
+        if has_focus {
+            if let Some(cursorp) = state.cursorp {
+                paint_cursor_end(ui, response.rect.min, &galley, &cursorp.primary);
+
+                if enabled && interactive {
+                    // Tell the backend where to show the IME candidate window:
+                    let cursor_rect = galley
+                        .pos_from_cursor(&cursorp.primary)
+                        .translate(response.rect.min.to_vec2());
+                    let mut output = ui.ctx().output();
+                    output.text_cursor_pos = Some(cursor_rect.left_top());
+                    output.text_cursor_rect = Some(cursor_rect);
+                }
+            }
+
+            if let Some(ime_char_range) = &state.ime_char_range {
+                // Underline the uncommitted pre-edit text:
+                let char_indices: Vec<usize> = ime_char_range.clone().collect();
+                let stroke = Stroke::new(1.0, text_color);
+                for rect in char_rects(response.rect.min, &galley, &char_indices) {
+                    ui.painter()
+                        .line_segment([rect.left_bottom(), rect.right_bottom()], stroke);
+                }
+            }
+        } else {
+            // Losing focus commits whatever was being composed.
+            state.has_ime = false;
+            state.ime_char_range = None;
+        }
+
+        ui.painter()
+            .galley(response.rect.min, galley.clone(), text_color);
+        if let Some(selection) = &selection {
+            paint_selected_text(ui, response.rect.min, &galley, selection, has_focus);
+        }
+
+        if text.as_ref().is_empty() && !hint_text.is_empty() && !ui.memory().has_focus(id) {
+            let galley = hint_galley.unwrap_or_else(|| layout_hint_text(ui));
             let hint_text_color = ui.visuals().weak_text_color();
             ui.painter()
                 .galley(response.rect.min, galley, hint_text_color);
         }
 
+        if response.changed {
+            state.edited = true;
+        }
+        if response.lost_focus() {
+            if state.edited {
+                ui.ctx().log_ui_event(|| UiEvent::TextCommitted {
+                    id,
+                    text: mask_if_password(text.as_str()),
+                });
+            }
+            state.edited = false;
+        }
+
         ui.memory().id_data.insert(id, state);
 
         let selection_changed = if let (Some(text_cursor), Some(prev_text_cursor)) =
@@ -803,35 +1276,171 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
 
 // ----------------------------------------------------------------------------
 
-fn paint_cursor_selection(ui: &mut Ui, pos: Pos2, galley: &Galley, cursorp: &CursorPair) {
-    let color = ui.visuals().selection.bg_fill;
+/// Select text in a galley painted at `galley_pos` with the pointer:
+/// press to move the cursor (shift-press to extend the selection), drag to select,
+/// and double-click to select a word.
+///
+/// `text` is what `galley` was laid out from (they differ for passwords).
+/// Pressing also gives `response` keyboard focus.
+/// Shared by [`TextEdit`] and selectable [`Label`]s.
+pub(crate) fn select_with_pointer(
+    ui: &Ui,
+    response: &Response,
+    text: &str,
+    galley_pos: Pos2,
+    galley: &Galley,
+    cursorp: &mut Option<CursorPair>,
+) {
+    // TODO: triple-click to select whole paragraph
+    // TODO: drag selected text to either move or clone (ctrl on windows, alt on mac)
+    let pointer_pos = match ui.input().pointer.interact_pos() {
+        Some(pointer_pos) => pointer_pos,
+        None => return,
+    };
+    let cursor_at_pointer = galley.cursor_from_pos(pointer_pos - galley_pos);
+
+    if response.double_clicked() {
+        // Select word:
+        let ccursorp = select_word_at(text, cursor_at_pointer.ccursor);
+        *cursorp = Some(CursorPair {
+            primary: galley.from_ccursor(ccursorp.primary),
+            secondary: galley.from_ccursor(ccursorp.secondary),
+        });
+    } else if response.hovered() && ui.input().pointer.any_pressed() {
+        ui.memory().request_focus(response.id);
+        match cursorp {
+            Some(cursorp) if ui.input().modifiers.shift => cursorp.primary = cursor_at_pointer,
+            _ => *cursorp = Some(CursorPair::one(cursor_at_pointer)),
+        }
+    } else if ui.input().pointer.any_down() && response.is_pointer_button_down_on() {
+        if let Some(cursorp) = cursorp {
+            cursorp.primary = cursor_at_pointer;
+        }
+    }
+}
+
+/// Handle the keyboard events that don't change the text:
+/// copying, moving the cursor and extending the selection, and select all.
+///
+/// Used for text that can be selected but not edited.
+/// Nothing is copied unless `copyable` (e.g. not for passwords).
+pub(crate) fn on_read_only_events(
+    ui: &Ui,
+    copyable: bool,
+    galley: &Galley,
+    cursorp: &mut CursorPair,
+) {
+    for event in &ui.input().events {
+        match event {
+            Event::Copy if copyable && !cursorp.is_empty() => {
+                ui.ctx().output().copied_text = selected_str(&galley.text, cursorp).to_owned();
+            }
+            Event::Key {
+                key: Key::A,
+                pressed: true,
+                modifiers,
+            } if modifiers.command => {
+                *cursorp = CursorPair::two(Cursor::default(), galley.end());
+            }
+            Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            } if matches!(
+                key,
+                Key::ArrowLeft
+                    | Key::ArrowRight
+                    | Key::ArrowUp
+                    | Key::ArrowDown
+                    | Key::Home
+                    | Key::End
+            ) =>
+            {
+                move_single_cursor(&mut cursorp.primary, galley, *key, modifiers);
+                if !modifiers.shift {
+                    cursorp.secondary = cursorp.primary;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Paint the background of the selected text, see [`crate::style::Selection`].
+///
+/// Call this before painting the galley, and [`paint_selected_text`] after.
+pub(crate) fn paint_cursor_selection(
+    ui: &mut Ui,
+    pos: Pos2,
+    galley: &Galley,
+    cursorp: &CursorPair,
+    has_focus: bool,
+) {
+    let selection = ui.visuals().selection;
+    let color = if has_focus {
+        selection.bg_fill
+    } else {
+        selection.inactive_bg_fill
+    };
+    for rect in selection_rects(pos, galley, cursorp) {
+        ui.painter()
+            .rect_filled(rect, selection.corner_radius, color);
+    }
+}
+
+/// Paint the selected text again on top of the galley, in the selected text color (if any).
+pub(crate) fn paint_selected_text(
+    ui: &mut Ui,
+    pos: Pos2,
+    galley: &Arc<Galley>,
+    cursorp: &CursorPair,
+    has_focus: bool,
+) {
+    let selection = ui.visuals().selection;
+    let text_color = if has_focus {
+        selection.text_color
+    } else {
+        selection.inactive_text_color
+    };
+    if let Some(text_color) = text_color {
+        for rect in selection_rects(pos, galley, cursorp) {
+            ui.painter()
+                .sub_region(rect)
+                .galley(pos, galley.clone(), text_color);
+        }
+    }
+}
+
+/// One rectangle per row of the selection.
+fn selection_rects(pos: Pos2, galley: &Galley, cursorp: &CursorPair) -> Vec<Rect> {
     if cursorp.is_empty() {
-        return;
+        return vec![];
     }
     let [min, max] = cursorp.sorted();
     let min = min.rcursor;
     let max = max.rcursor;
 
-    for ri in min.row..=max.row {
-        let row = &galley.rows[ri];
-        let left = if ri == min.row {
-            row.x_offset(min.column)
-        } else {
-            row.min_x()
-        };
-        let right = if ri == max.row {
-            row.x_offset(max.column)
-        } else {
-            let newline_size = if row.ends_with_newline {
-                row.height() / 2.0 // visualize that we select the newline
+    (min.row..=max.row)
+        .map(|ri| {
+            let row = &galley.rows[ri];
+            let left = if ri == min.row {
+                row.x_offset(min.column)
             } else {
-                0.0
+                row.min_x()
             };
-            row.max_x() + newline_size
-        };
-        let rect = Rect::from_min_max(pos + vec2(left, row.y_min), pos + vec2(right, row.y_max));
-        ui.painter().rect_filled(rect, 0.0, color);
-    }
+            let right = if ri == max.row {
+                row.x_offset(max.column)
+            } else {
+                let newline_size = if row.ends_with_newline {
+                    row.height() / 2.0 // visualize that we select the newline
+                } else {
+                    0.0
+                };
+                row.max_x() + newline_size
+            };
+            Rect::from_min_max(pos + vec2(left, row.y_min), pos + vec2(right, row.y_max))
+        })
+        .collect()
 }
 
 fn paint_cursor_end(ui: &mut Ui, pos: Pos2, galley: &Galley, cursor: &Cursor) {
@@ -881,12 +1490,81 @@ fn byte_index_from_char_index(s: &str, char_index: usize) -> usize {
     s.len()
 }
 
-fn insert_text<S: TextBuffer>(ccursor: &mut CCursor, text: &mut S, text_to_insert: &str) {
-    ccursor.index += text.insert_text(text_to_insert, ccursor.index);
+/// The char indices covered by a byte range of a text,
+/// given the byte offsets of all the chars in the text.
+///
+/// Byte indices that are not on a char boundary are rounded up to the next char.
+fn char_range_from_byte_range(byte_offsets: &[usize], byte_range: &Range<usize>) -> Range<usize> {
+    let char_index = |byte_index: usize| {
+        byte_offsets
+            .binary_search(&byte_index)
+            .unwrap_or_else(|next_char| next_char)
+    };
+    let start = char_index(byte_range.start);
+    let end = char_index(byte_range.end).max(start);
+    start..end
+}
+
+/// What the user is allowed to insert, see [`TextEdit::char_limit`] and [`TextEdit::input_filter`].
+struct InputRules<'a> {
+    char_limit: usize,
+    filter: Option<&'a dyn Fn(char) -> bool>,
+}
+
+impl<'a> InputRules<'a> {
+    /// Does the filter let `c` through? Newlines and tabs always pass.
+    fn passes_filter(&self, c: char) -> bool {
+        c == '\n' || c == '\t' || self.filter.map_or(true, |filter| filter(c))
+    }
+
+    /// Would the filter drop all of `text_to_insert`?
+    fn rejects_all(&self, text_to_insert: &str) -> bool {
+        !text_to_insert.chars().any(|c| self.passes_filter(c))
+    }
+
+    /// The part of `text_to_insert` that may be inserted into `current_text`.
+    fn allowed(&self, current_text: &str, text_to_insert: &str) -> String {
+        let room = self.char_limit.saturating_sub(current_text.chars().count());
+        text_to_insert
+            .chars()
+            .filter(|&c| self.passes_filter(c))
+            .take(room)
+            .collect()
+    }
+}
+
+/// Returns the number of inserted characters.
+fn insert_text<S: TextBuffer>(
+    ccursor: &mut CCursor,
+    text: &mut S,
+    text_to_insert: &str,
+    rules: &InputRules<'_>,
+) -> usize {
+    let text_to_insert = rules.allowed(text.as_ref(), text_to_insert);
+    let num_inserted = text.insert_text(&text_to_insert, ccursor.index);
+    ccursor.index += num_inserted;
+    num_inserted
 }
 
 // ----------------------------------------------------------------------------
 
+/// Delete the IME pre-edit text if there is any, else the selection.
+fn delete_ime_or_selected<S: TextBuffer>(
+    text: &mut S,
+    cursorp: &CursorPair,
+    ime_char_range: Option<Range<usize>>,
+) -> CCursor {
+    match ime_char_range {
+        Some(range) => {
+            let num_chars = text.as_ref().chars().count();
+            let min = CCursor::new(range.start.min(num_chars));
+            let max = CCursor::new(range.end.min(num_chars));
+            delete_selected_ccursor_range(text, [min, max])
+        }
+        None => delete_selected(text, cursorp),
+    }
+}
+
 fn delete_selected<S: TextBuffer>(text: &mut S, cursorp: &CursorPair) -> CCursor {
     let [min, max] = cursorp.sorted();
     delete_selected_ccursor_range(text, [min.ccursor, max.ccursor])
@@ -1221,3 +1899,98 @@ fn decrease_identation<S: TextBuffer>(ccursor: &mut CCursor, text: &mut S) {
         }
     }
 }
+
+#[test]
+fn test_char_range_from_byte_range() {
+    let text = "aåb";
+    let byte_offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    assert_eq!(char_range_from_byte_range(&byte_offsets, &(0..1)), 0..1);
+    assert_eq!(char_range_from_byte_range(&byte_offsets, &(1..3)), 1..2);
+    assert_eq!(char_range_from_byte_range(&byte_offsets, &(3..4)), 2..3);
+    assert_eq!(char_range_from_byte_range(&byte_offsets, &(0..100)), 0..3);
+    assert_eq!(char_range_from_byte_range(&byte_offsets, &(100..200)), 3..3);
+}
+
+#[test]
+fn test_input_rules() {
+    let digits = |c: char| c.is_ascii_digit();
+    let rules = InputRules {
+        char_limit: 5,
+        filter: Some(&digits),
+    };
+    assert_eq!(rules.allowed("", "a1b2c3"), "123");
+    assert_eq!(rules.allowed("123", "4567"), "45");
+    assert_eq!(rules.allowed("123456", "7"), "");
+    assert_eq!(rules.allowed("1", "\n\t"), "\n\t", "Enter and Tab pass");
+    assert!(rules.rejects_all("abc"));
+    assert!(!rules.rejects_all("a1"));
+
+    let unlimited = InputRules {
+        char_limit: usize::MAX,
+        filter: None,
+    };
+    assert_eq!(unlimited.allowed("åäö", "Hello\n"), "Hello\n");
+}
+
+#[test]
+fn test_input_filter_keeps_selection() {
+    use crate::test_util::{key, key_with_modifiers, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut run_frame = |text: &mut String, multiline: bool, events: Vec<Event>| {
+        run_ui_frame(&mut ctx, events, |ui| {
+            let id = Id::new("digits");
+            ui.memory().request_focus(id);
+            let edit = if multiline {
+                TextEdit::multiline(text)
+            } else {
+                TextEdit::singleline(text)
+            };
+            ui.add(edit.id(id).input_filter(|c| c.is_ascii_digit()));
+        });
+    };
+    let select_all = key_with_modifiers(
+        Key::A,
+        Modifiers {
+            ctrl: true,
+            command: true,
+            ..Default::default()
+        },
+    );
+
+    let mut text = "123".to_owned();
+    run_frame(&mut text, false, vec![]);
+    run_frame(&mut text, false, vec![select_all, Event::Text("a".into())]);
+    assert_eq!(text, "123", "a rejected char doesn't replace the selection");
+    run_frame(&mut text, false, vec![Event::Text("4".into())]);
+    assert_eq!(text, "4", "the selection was kept");
+
+    run_frame(
+        &mut text,
+        true,
+        vec![key(Key::Enter), Event::Text("5".into())],
+    );
+    assert_eq!(text, "4\n5");
+}
+
+#[test]
+fn test_selection_rects() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    let galley =
+        ctx.fonts()
+            .layout_multiline(TextStyle::Body, "hello\nworld".into(), f32::INFINITY);
+    let select = |start: usize, end: usize| CursorPair {
+        primary: galley.from_ccursor(CCursor::new(end)),
+        secondary: galley.from_ccursor(CCursor::new(start)),
+    };
+
+    assert!(selection_rects(Pos2::ZERO, &galley, &select(3, 3)).is_empty());
+
+    // One rectangle per row, the first one including the newline:
+    let rects = selection_rects(pos2(10.0, 0.0), &galley, &select(2, 8));
+    assert_eq!(rects.len(), 2);
+    assert!(rects[0].left() > 10.0);
+    assert!(rects[0].right() > galley.rows[0].max_x() + 10.0);
+    assert_eq!(rects[1].left(), 10.0);
+    assert!(rects[0].bottom() <= rects[1].top());
+}
@@ -0,0 +1,337 @@
+//! Widgets for editing a time of day, see [`TimePicker`], and a length of time, see [`DurationPicker`].
+
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::{ops::RangeInclusive, time::Duration};
+
+use crate::*;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Add `delta` seconds to a time of day, wrapping around midnight.
+fn add_wrapping(seconds_since_midnight: u32, delta: i64) -> u32 {
+    (seconds_since_midnight as i64 + delta).rem_euclid(SECONDS_PER_DAY) as u32
+}
+
+/// Edit a time of day, given as the number of seconds since midnight,
+/// with one field each for the hours, minutes and seconds.
+///
+/// Drag a field to change it, or click it to type a new value.
+/// Going past the end of a field carries over into the next one,
+/// e.g. dragging the minutes past 59 goes to the next hour, and the time wraps around midnight.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut alarm: u32 = 7 * 3600;
+/// ui.add(egui::TimePicker::new(&mut alarm).show_seconds(false));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct TimePicker<'a> {
+    seconds_since_midnight: &'a mut u32,
+    show_seconds: bool,
+    twelve_hour: bool,
+}
+
+impl<'a> TimePicker<'a> {
+    /// The time is the number of seconds since midnight, in `0..86400`.
+    /// Larger values are wrapped around.
+    pub fn new(seconds_since_midnight: &'a mut u32) -> Self {
+        Self {
+            seconds_since_midnight,
+            show_seconds: true,
+            twelve_hour: false,
+        }
+    }
+
+    /// Show a field for the seconds. Default: `true`.
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Show the hours as 1-12 with an AM/PM button, instead of as 0-23. Default: `false`.
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self
+    }
+}
+
+impl<'a> Widget for TimePicker<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let TimePicker {
+            seconds_since_midnight,
+            show_seconds,
+            twelve_hour,
+        } = self;
+
+        let old_time = *seconds_since_midnight;
+        let mut time = add_wrapping(old_time, 0);
+
+        let mut response = ui
+            .horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 2.0;
+
+                let is_pm = time >= 12 * 3600;
+                let field = |ui: &mut Ui, time: &mut u32, unit: u32, modulo: u32| {
+                    let drag_value = DragValue::from_get_set(|value: Option<f64>| {
+                        let current = (*time / unit % modulo) as f64;
+                        if let Some(value) = value {
+                            let delta = value.round() as i64 - current as i64;
+                            *time = add_wrapping(*time, delta * unit as i64);
+                        }
+                        current
+                    })
+                    .speed(0.1)
+                    .keyboard_step(1.0)
+                    .fixed_decimals(0);
+                    let drag_value = if twelve_hour && unit == 3600 {
+                        drag_value
+                            .custom_formatter(|hours, _| {
+                                format!("{}", (hours as u32 + 11) % 12 + 1)
+                            })
+                            .custom_parser(move |text| {
+                                let hours: f64 = text.trim().parse().ok()?;
+                                let hours = hours % 12.0 + if is_pm { 12.0 } else { 0.0 };
+                                Some(hours)
+                            })
+                    } else {
+                        drag_value.custom_formatter(|value, _| format!("{:02}", value as u32))
+                    };
+                    ui.add(drag_value)
+                };
+
+                let mut response = field(ui, &mut time, 3600, 24);
+                ui.label(":");
+                response |= field(ui, &mut time, 60, 60);
+                if show_seconds {
+                    ui.label(":");
+                    response |= field(ui, &mut time, 1, 60);
+                }
+                if twelve_hour {
+                    let text = if is_pm { "PM" } else { "AM" };
+                    let am_pm = ui.add(Button::new(text).small());
+                    if am_pm.clicked() {
+                        time = add_wrapping(time, 12 * 3600);
+                    }
+                    response |= am_pm;
+                }
+                response
+            })
+            .inner;
+
+        if time != old_time {
+            *seconds_since_midnight = time;
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+type DurationFormatter<'a> = Box<dyn 'a + Fn(Duration) -> String>;
+type DurationParser<'a> = Box<dyn 'a + Fn(&str) -> Option<Duration>>;
+
+/// Edit a length of time, e.g. a timeout or the length of a video clip.
+///
+/// By default it is shown as e.g. "1h 30m 5s". Drag it to change it, or click it to type a new value,
+/// e.g. "90s", "1h 30m", "1:30" (one minute and a half) or "1:30:00".
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut timeout = std::time::Duration::from_secs(30);
+/// ui.add(egui::DurationPicker::new(&mut timeout).speed(1.0));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct DurationPicker<'a> {
+    duration: &'a mut Duration,
+    speed: f64,
+    clamp_range: Option<RangeInclusive<Duration>>,
+    custom_formatter: Option<DurationFormatter<'a>>,
+    custom_parser: Option<DurationParser<'a>>,
+}
+
+impl<'a> DurationPicker<'a> {
+    pub fn new(duration: &'a mut Duration) -> Self {
+        Self {
+            duration,
+            speed: 1.0,
+            clamp_range: None,
+            custom_formatter: None,
+            custom_parser: None,
+        }
+    }
+
+    /// How many seconds the duration changes for each point dragged. Default: 1.
+    pub fn speed(mut self, seconds_per_point: impl Into<f64>) -> Self {
+        self.speed = seconds_per_point.into();
+        self
+    }
+
+    /// Clamp the duration to this range.
+    pub fn clamp_range(mut self, clamp_range: RangeInclusive<Duration>) -> Self {
+        self.clamp_range = Some(clamp_range);
+        self
+    }
+
+    /// Show the duration with your own formatting, e.g. as a number of frames.
+    ///
+    /// You most likely want a matching [`Self::custom_parser`] too.
+    pub fn custom_formatter(mut self, formatter: impl 'a + Fn(Duration) -> String) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Parse the text the user enters with your own parser.
+    ///
+    /// Return `None` if the text is not a valid duration, and the duration is left as it is.
+    pub fn custom_parser(mut self, parser: impl 'a + Fn(&str) -> Option<Duration>) -> Self {
+        self.custom_parser = Some(Box::new(parser));
+        self
+    }
+}
+
+impl<'a> Widget for DurationPicker<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let DurationPicker {
+            duration,
+            speed,
+            clamp_range,
+            custom_formatter,
+            custom_parser,
+        } = self;
+
+        let range = clamp_range.map_or(0.0..=f64::INFINITY, |range| {
+            range.start().as_secs_f64()..=range.end().as_secs_f64()
+        });
+        let mut seconds = duration.as_secs_f64();
+        let response = ui.add(
+            DragValue::new(&mut seconds)
+                .speed(speed)
+                .clamp_range(range)
+                .custom_formatter(|seconds, _| {
+                    let duration = duration_from_secs(seconds);
+                    match &custom_formatter {
+                        Some(custom_formatter) => custom_formatter(duration),
+                        None => format_duration(duration),
+                    }
+                })
+                .custom_parser(|text| {
+                    let duration = match &custom_parser {
+                        Some(custom_parser) => custom_parser(text),
+                        None => parse_duration(text),
+                    };
+                    duration.map(|duration| duration.as_secs_f64())
+                }),
+        );
+        if seconds != duration.as_secs_f64() {
+            *duration = duration_from_secs(seconds);
+        }
+        response
+    }
+}
+
+/// Unlike [`Duration::from_secs_f64`] this doesn't panic for negative or huge numbers.
+fn duration_from_secs(seconds: f64) -> Duration {
+    const MAX_SECONDS: f64 = (1_u64 << 53) as f64;
+    Duration::from_secs_f64(seconds.clamp(0.0, MAX_SECONDS))
+}
+
+/// E.g. "1h 2m 3s", or "1.5s".
+fn format_duration(duration: Duration) -> String {
+    let total_seconds = duration.as_secs();
+    let (hours, minutes) = (total_seconds / 3600, total_seconds / 60 % 60);
+    let seconds = (total_seconds % 60) as f64 + duration.subsec_millis() as f64 / 1000.0;
+
+    let mut parts = vec![];
+    if hours > 0 {
+        parts.push(format!("{}h", hours));
+    }
+    if minutes > 0 {
+        parts.push(format!("{}m", minutes));
+    }
+    if seconds > 0.0 || parts.is_empty() {
+        parts.push(format!("{}s", seconds));
+    }
+    parts.join(" ")
+}
+
+/// Parses "1h 2m 3s", "90s", "1:30" (minutes and seconds), "1:02:03" or just a number of seconds.
+fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let seconds = if text.contains(':') {
+        let parts: Vec<&str> = text.split(':').collect();
+        if parts.len() > 3 {
+            return None;
+        }
+        let mut seconds = 0.0;
+        for part in parts {
+            seconds = 60.0 * seconds + part.trim().parse::<f64>().ok()?;
+        }
+        seconds
+    } else if let Ok(seconds) = text.parse::<f64>() {
+        seconds
+    } else {
+        let mut seconds = 0.0;
+        let mut number = String::new();
+        for c in text.chars() {
+            match c {
+                'h' | 'm' | 's' => {
+                    let value: f64 = number.trim().parse().ok()?;
+                    number.clear();
+                    seconds += value
+                        * match c {
+                            'h' => 3600.0,
+                            'm' => 60.0,
+                            _ => 1.0,
+                        };
+                }
+                _ if c.is_whitespace() => {}
+                _ => number.push(c),
+            }
+        }
+        if !number.is_empty() {
+            return None;
+        }
+        seconds
+    };
+    if seconds.is_finite() && seconds >= 0.0 {
+        Some(duration_from_secs(seconds))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_time_and_duration() {
+    assert_eq!(add_wrapping(23 * 3600 + 59 * 60, 60), 0);
+    assert_eq!(add_wrapping(0, -1), 86399);
+
+    assert_eq!(format_duration(Duration::from_secs(3723)), "1h 2m 3s");
+    assert_eq!(format_duration(Duration::from_millis(1500)), "1.5s");
+    assert_eq!(format_duration(Duration::from_secs(0)), "0s");
+    assert_eq!(format_duration(Duration::from_secs(7200)), "2h");
+
+    assert_eq!(parse_duration("1h 2m 3s"), Some(Duration::from_secs(3723)));
+    assert_eq!(parse_duration("90s"), Some(Duration::from_secs(90)));
+    assert_eq!(parse_duration("1:30"), Some(Duration::from_secs(90)));
+    assert_eq!(parse_duration("1:02:03"), Some(Duration::from_secs(3723)));
+    assert_eq!(parse_duration("2.5"), Some(Duration::from_millis(2500)));
+    assert_eq!(parse_duration("5 minutes"), None);
+    assert_eq!(parse_duration("-3"), None);
+
+    // Going past the end of the minutes carries into the hours:
+    use crate::test_util::{key, run_ui_frame};
+    let mut ctx = CtxRef::default();
+    let mut time = 10 * 3600 + 59 * 60;
+    let mut press = |pressed, time: &mut u32| {
+        run_ui_frame(&mut ctx, vec![key(pressed)], |ui| {
+            ui.add(TimePicker::new(time))
+        });
+        *time
+    };
+    press(Key::Tab, &mut time); // Focus the hours
+    press(Key::Tab, &mut time); // Focus the minutes
+    assert_eq!(press(Key::ArrowUp, &mut time), 11 * 3600);
+    assert_eq!(press(Key::ArrowDown, &mut time), 10 * 3600 + 59 * 60);
+}
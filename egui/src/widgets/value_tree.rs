@@ -0,0 +1,423 @@
+use std::collections::HashSet;
+
+use crate::*;
+
+/// A value shown by a [`ValueTree`]: like a JSON value.
+///
+/// With the `serde_json` feature you can convert a `serde_json::Value` with `TreeValue::from(&value)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TreeValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<TreeValue>),
+    /// Keys and values, in the order they are shown.
+    Object(Vec<(String, TreeValue)>),
+}
+
+impl TreeValue {
+    /// As (compact) JSON.
+    ///
+    /// ```
+    /// use egui::TreeValue;
+    /// let value = TreeValue::Object(vec![
+    ///     ("name".to_owned(), TreeValue::String("egui".to_owned())),
+    ///     ("stars".to_owned(), TreeValue::Number(4000.0)),
+    /// ]);
+    /// assert_eq!(value.to_json(), r#"{"name":"egui","stars":4000}"#);
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json);
+        json
+    }
+
+    fn write_json(&self, json: &mut String) {
+        match self {
+            Self::Null => json.push_str("null"),
+            Self::Bool(value) => json.push_str(&value.to_string()),
+            Self::Number(_) | Self::String(_) => json.push_str(&self.scalar_text()),
+            Self::Array(values) => {
+                json.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        json.push(',');
+                    }
+                    value.write_json(json);
+                }
+                json.push(']');
+            }
+            Self::Object(entries) => {
+                json.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        json.push(',');
+                    }
+                    json.push_str(&json_string(key));
+                    json.push(':');
+                    value.write_json(json);
+                }
+                json.push('}');
+            }
+        }
+    }
+
+    /// How a value that isn't an array or object is shown.
+    fn scalar_text(&self) -> String {
+        match self {
+            Self::Null => "null".to_owned(),
+            Self::Bool(value) => value.to_string(),
+            Self::Number(value) if !value.is_finite() => "null".to_owned(),
+            Self::Number(value) if value.fract() == 0.0 && value.abs() < 1e15 => {
+                format!("{}", *value as i64)
+            }
+            Self::Number(value) => value.to_string(),
+            Self::String(value) => json_string(value),
+            Self::Array(values) => format!("[…] {} items", values.len()),
+            Self::Object(entries) => format!("{{…}} {} keys", entries.len()),
+        }
+    }
+
+    fn children(&self) -> Vec<(Key<'_>, &TreeValue)> {
+        match self {
+            Self::Array(values) => values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| (Key::Index(i), value))
+                .collect(),
+            Self::Object(entries) => entries
+                .iter()
+                .map(|(key, value)| (Key::Name(key), value))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn is_container(&self) -> bool {
+        matches!(self, Self::Array(_) | Self::Object(_))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<&serde_json::Value> for TreeValue {
+    fn from(value: &serde_json::Value) -> Self {
+        use serde_json::Value;
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(value) => Self::Bool(*value),
+            Value::Number(value) => Self::Number(value.as_f64().unwrap_or(f64::NAN)),
+            Value::String(value) => Self::String(value.clone()),
+            Value::Array(values) => Self::Array(values.iter().map(Self::from).collect()),
+            Value::Object(entries) => Self::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// How a value is found in its parent.
+#[derive(Clone, Copy)]
+enum Key<'a> {
+    Index(usize),
+    Name(&'a str),
+}
+
+impl<'a> Key<'a> {
+    /// The path of the child with this key, like `$.users[0].name`.
+    fn path(self, parent: &str) -> String {
+        match self {
+            Key::Index(i) => format!("{}[{}]", parent, i),
+            Key::Name(name) if is_identifier(name) => format!("{}.{}", parent, name),
+            Key::Name(name) => format!("{}[{}]", parent, json_string(name)),
+        }
+    }
+
+    fn text(self) -> String {
+        match self {
+            Key::Index(i) => i.to_string(),
+            Key::Name(name) => name.to_owned(),
+        }
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// `query` must be lowercase.
+fn matches(text: &str, query: &str) -> bool {
+    !query.is_empty() && text.to_lowercase().contains(query)
+}
+
+/// Does the key or value of a descendant of `value` match the (lowercase) `query`?
+fn has_matching_child(value: &TreeValue, query: &str) -> bool {
+    value.children().iter().any(|(key, value)| {
+        matches(&key.text(), query)
+            || (!value.is_container() && matches(&value.scalar_text(), query))
+            || has_matching_child(value, query)
+    })
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    /// Paths of the expanded arrays and objects.
+    expanded: HashSet<String>,
+    query: String,
+}
+
+/// Shows nested arrays and objects (e.g. JSON) as a tree that can be expanded and collapsed.
+///
+/// Values are colored by their type. Right-click a row to copy its path (like `$.users[0].name`)
+/// or its value (as JSON).
+///
+/// Above the tree is a search field. While searching only the rows whose key or value match
+/// are shown (and their parents, which are expanded).
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::TreeValue;
+/// let value = TreeValue::Object(vec![
+///     ("name".to_owned(), TreeValue::String("egui".to_owned())),
+///     ("tags".to_owned(), TreeValue::Array(vec![TreeValue::String("gui".to_owned())])),
+/// ]);
+/// egui::ValueTree::new(&value).show(ui);
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ValueTree<'a> {
+    value: &'a TreeValue,
+    id_source: Option<Id>,
+    search: bool,
+}
+
+impl<'a> ValueTree<'a> {
+    pub fn new(value: &'a TreeValue) -> Self {
+        Self {
+            value,
+            id_source: None,
+            search: true,
+        }
+    }
+
+    /// Needed if you show more than one [`ValueTree`] in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Show a search field above the tree. Default: `true`.
+    pub fn search(mut self, search: bool) -> Self {
+        self.search = search;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            id_source,
+            search,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("value_tree")));
+        let mut state = ui.memory().id_data.get_or_default::<State>(id).clone();
+
+        let response = ui
+            .vertical(|ui| {
+                if search {
+                    ui.horizontal(|ui| {
+                        ui.label("🔍");
+                        ui.add(TextEdit::singleline(&mut state.query).hint_text("Search"));
+                        if !state.query.is_empty() && ui.small_button("✖").clicked() {
+                            state.query.clear();
+                        }
+                    });
+                }
+                let query = if search {
+                    state.query.to_lowercase()
+                } else {
+                    String::new()
+                };
+
+                let mut tree = TreeUi {
+                    id,
+                    query: &query,
+                    expanded: &mut state.expanded,
+                };
+                if value.is_container() {
+                    for (key, child) in value.children() {
+                        tree.node_ui(ui, 0, Some(key), child, &key.path("$"));
+                    }
+                } else {
+                    tree.node_ui(ui, 0, None, value, "$");
+                }
+            })
+            .response;
+
+        ui.memory().id_data.insert(id, state);
+        response
+    }
+}
+
+struct TreeUi<'t> {
+    id: Id,
+    /// Lowercase.
+    query: &'t str,
+    expanded: &'t mut HashSet<String>,
+}
+
+impl<'t> TreeUi<'t> {
+    fn node_ui(
+        &mut self,
+        ui: &mut Ui,
+        depth: usize,
+        key: Option<Key<'_>>,
+        value: &TreeValue,
+        path: &str,
+    ) {
+        let query = self.query;
+        let key_matches = key.map_or(false, |key| matches(&key.text(), query));
+        let value_matches = !value.is_container() && matches(&value.scalar_text(), query);
+        let child_matches = !query.is_empty() && has_matching_child(value, query);
+        if !query.is_empty() && !key_matches && !value_matches && !child_matches {
+            return;
+        }
+        let expanded = child_matches || self.expanded.contains(path);
+
+        let highlight = ui.visuals().selection.bg_fill;
+        let mut toggle = false;
+        let row = ui.horizontal(|ui| {
+            ui.add_space(depth as f32 * ui.spacing().indent);
+            let icon_size = vec2(ui.spacing().icon_width, ui.spacing().interact_size.y);
+            if value.is_container() {
+                let (_, icon_response) = ui.allocate_exact_size(icon_size, Sense::click());
+                let openness = if expanded { 1.0 } else { 0.0 };
+                collapsing_header::paint_icon(ui, openness, &icon_response);
+                toggle |= icon_response.clicked();
+            } else {
+                ui.allocate_exact_size(icon_size, Sense::hover());
+            }
+
+            if let Some(key) = key {
+                let mut label = Label::new(format!("{}:", key.text()));
+                if let Key::Index(_) = key {
+                    label = label.weak();
+                }
+                if key_matches {
+                    label = label.background_color(highlight);
+                }
+                ui.add(label);
+            }
+
+            let mut label = Label::new(truncate(&value.scalar_text()))
+                .monospace()
+                .text_color(value_color(ui.visuals(), value));
+            if value.is_container() {
+                label = label.weak();
+            }
+            if value_matches {
+                label = label.background_color(highlight);
+            }
+            ui.add(label);
+        });
+
+        let row_response = ui.interact(row.response.rect, self.id.with(path), Sense::click());
+        toggle |= value.is_container() && row_response.clicked();
+        row_response.context_menu(|ui| {
+            if ui.button("Copy path").clicked() {
+                ui.output().copied_text = path.to_owned();
+            }
+            if ui.button("Copy value").clicked() {
+                ui.output().copied_text = value.to_json();
+            }
+        });
+
+        if toggle && !self.expanded.remove(path) {
+            self.expanded.insert(path.to_owned());
+        }
+        if expanded {
+            for (key, child) in value.children() {
+                self.node_ui(ui, depth + 1, Some(key), child, &key.path(path));
+            }
+        }
+    }
+}
+
+fn value_color(visuals: &style::Visuals, value: &TreeValue) -> Color32 {
+    let dark = visuals.dark_mode;
+    match value {
+        TreeValue::String(_) if dark => Color32::from_rgb(206, 145, 120),
+        TreeValue::String(_) => Color32::from_rgb(163, 21, 21),
+        TreeValue::Number(_) if dark => Color32::from_rgb(181, 206, 168),
+        TreeValue::Number(_) => Color32::from_rgb(9, 134, 88),
+        TreeValue::Bool(_) | TreeValue::Null if dark => Color32::from_rgb(86, 156, 214),
+        TreeValue::Bool(_) | TreeValue::Null => Color32::from_rgb(0, 0, 255),
+        TreeValue::Array(_) | TreeValue::Object(_) => visuals.weak_text_color(),
+    }
+}
+
+/// Long values would make the tree very wide.
+fn truncate(text: &str) -> String {
+    const MAX_CHARS: usize = 100;
+    if text.chars().count() > MAX_CHARS {
+        let mut truncated: String = text.chars().take(MAX_CHARS).collect();
+        truncated.push('…');
+        truncated
+    } else {
+        text.to_owned()
+    }
+}
+
+#[test]
+fn test_value_tree_paths_and_search() {
+    let value = TreeValue::Object(vec![
+        (
+            "users".to_owned(),
+            TreeValue::Array(vec![TreeValue::Object(vec![
+                ("name".to_owned(), TreeValue::String("Ada \"A\"".to_owned())),
+                ("admin".to_owned(), TreeValue::Bool(true)),
+            ])]),
+        ),
+        ("max size".to_owned(), TreeValue::Number(1.5)),
+        ("none".to_owned(), TreeValue::Null),
+    ]);
+    assert_eq!(
+        value.to_json(),
+        r#"{"users":[{"name":"Ada \"A\"","admin":true}],"max size":1.5,"none":null}"#
+    );
+
+    assert_eq!(Key::Index(0).path("$.users"), "$.users[0]");
+    assert_eq!(Key::Name("name").path("$.users[0]"), "$.users[0].name");
+    assert_eq!(Key::Name("max size").path("$"), r#"$["max size"]"#);
+
+    assert!(has_matching_child(&value, "ada"));
+    assert!(has_matching_child(&value, "admin"));
+    assert!(!has_matching_child(&value, "bob"));
+    assert!(!has_matching_child(&TreeValue::Null, "null"));
+}
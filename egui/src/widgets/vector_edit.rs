@@ -0,0 +1,269 @@
+//! Compact editors for vectors and matrices, see [`VectorEdit`] and [`MatrixEdit`].
+
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// Edit a vector (e.g. `[f32; 3]`, or the components of a `Vec2`/`Vec3`/`Vec4`)
+/// with one [`DragValue`] per component, all on one row.
+///
+/// Vectors with at most four components get the prefixes `x`, `y`, `z` and `w`.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut position = [1.0_f32, 2.0, 3.0];
+/// ui.add(
+///     egui::VectorEdit::new(&mut position)
+///         .speed(0.1)
+///         .reset_to(&[0.0, 0.0, 0.0]),
+/// );
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Debug)]
+pub struct VectorEdit<'a> {
+    values: &'a mut [f32],
+    speed: f32,
+    clamp_range: RangeInclusive<f32>,
+    reset_value: Option<Vec<f32>>,
+}
+
+impl<'a> VectorEdit<'a> {
+    pub fn new(values: &'a mut [f32]) -> Self {
+        Self {
+            values,
+            speed: 0.01,
+            clamp_range: f32::NEG_INFINITY..=f32::INFINITY,
+            reset_value: None,
+        }
+    }
+
+    /// How much a component changes when dragged one point (logical pixel). Default: `0.01`.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Clamp all components to this range.
+    pub fn clamp_range(mut self, clamp_range: RangeInclusive<f32>) -> Self {
+        self.clamp_range = clamp_range;
+        self
+    }
+
+    /// Show a button that resets the vector to this value.
+    ///
+    /// `reset_value` must have as many components as the vector.
+    pub fn reset_to(mut self, reset_value: &[f32]) -> Self {
+        crate::egui_assert!(reset_value.len() == self.values.len());
+        self.reset_value = Some(reset_value.to_vec());
+        self
+    }
+}
+
+impl<'a> Widget for VectorEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            values,
+            speed,
+            clamp_range,
+            reset_value,
+        } = self;
+
+        let prefixes = if values.len() <= 4 {
+            ["x: ", "y: ", "z: ", "w: "].as_ref()
+        } else {
+            [].as_ref()
+        };
+
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = ui.horizontal(|ui| {
+            let mut changed = false;
+            for (i, value) in values.iter_mut().enumerate() {
+                let prefix = prefixes.get(i).copied().unwrap_or_default();
+                changed |= component_ui(ui, value, speed, clamp_range.clone(), prefix).changed();
+            }
+            if let Some(reset_value) = &reset_value {
+                changed |= reset_button_ui(ui, values, reset_value);
+            }
+            changed
+        });
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Edit a matrix (e.g. a 3x3 or 4x4 transform) with one [`DragValue`] per element, laid out in a grid.
+///
+/// The values are given as a flat slice, row by row (row-major).
+/// Use [`Self::column_major`] if your matrix type stores its values column by column.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut transform = [1.0_f32, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+/// ui.add(
+///     egui::MatrixEdit::new(&mut transform, 3)
+///         .column_major(true)
+///         .reset_to_identity(),
+/// );
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Debug)]
+pub struct MatrixEdit<'a> {
+    values: &'a mut [f32],
+    num_columns: usize,
+    column_major: bool,
+    speed: f32,
+    clamp_range: RangeInclusive<f32>,
+    reset_value: Option<Vec<f32>>,
+}
+
+impl<'a> MatrixEdit<'a> {
+    /// `values.len()` must be a multiple of `num_columns`.
+    pub fn new(values: &'a mut [f32], num_columns: usize) -> Self {
+        crate::egui_assert!(
+            num_columns > 0 && values.chunks_exact(num_columns).remainder().is_empty()
+        );
+        Self {
+            values,
+            num_columns,
+            column_major: false,
+            speed: 0.01,
+            clamp_range: f32::NEG_INFINITY..=f32::INFINITY,
+            reset_value: None,
+        }
+    }
+
+    /// If `true`, the values are stored column by column, and `num_columns` is really the number of rows.
+    ///
+    /// The matrix is still shown the right way around.
+    pub fn column_major(mut self, column_major: bool) -> Self {
+        self.column_major = column_major;
+        self
+    }
+
+    /// How much an element changes when dragged one point (logical pixel). Default: `0.01`.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Clamp all elements to this range.
+    pub fn clamp_range(mut self, clamp_range: RangeInclusive<f32>) -> Self {
+        self.clamp_range = clamp_range;
+        self
+    }
+
+    /// Show a button that resets the matrix to these values (in the same order as the matrix).
+    pub fn reset_to(mut self, reset_value: &[f32]) -> Self {
+        crate::egui_assert!(reset_value.len() == self.values.len());
+        self.reset_value = Some(reset_value.to_vec());
+        self
+    }
+
+    /// Show a button that resets the matrix to the identity matrix.
+    pub fn reset_to_identity(mut self) -> Self {
+        let num_columns = self.num_columns;
+        let identity = (0..self.values.len())
+            .map(|i| {
+                if i / num_columns == i % num_columns {
+                    1.0
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        self.reset_value = Some(identity);
+        self
+    }
+}
+
+impl<'a> Widget for MatrixEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            values,
+            num_columns,
+            column_major,
+            speed,
+            clamp_range,
+            reset_value,
+        } = self;
+
+        let stored_rows = values.len() / num_columns;
+        let (num_rows, num_columns) = if column_major {
+            (num_columns, stored_rows)
+        } else {
+            (stored_rows, num_columns)
+        };
+        let index = |row: usize, column: usize| {
+            if column_major {
+                column * num_rows + row
+            } else {
+                row * num_columns + column
+            }
+        };
+
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = ui.horizontal(|ui| {
+            let mut changed = false;
+            Grid::new(ui.next_auto_id())
+                .spacing(ui.spacing().item_spacing * vec2(0.5, 1.0))
+                .show(ui, |ui| {
+                    for row in 0..num_rows {
+                        for column in 0..num_columns {
+                            let value = &mut values[index(row, column)];
+                            changed |=
+                                component_ui(ui, value, speed, clamp_range.clone(), "").changed();
+                        }
+                        ui.end_row();
+                    }
+                });
+            if let Some(reset_value) = &reset_value {
+                changed |= reset_button_ui(ui, values, reset_value);
+            }
+            changed
+        });
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn component_ui(
+    ui: &mut Ui,
+    value: &mut f32,
+    speed: f32,
+    clamp_range: RangeInclusive<f32>,
+    prefix: &str,
+) -> Response {
+    ui.add(
+        DragValue::new(value)
+            .speed(speed)
+            .clamp_range(clamp_range)
+            .prefix(prefix),
+    )
+}
+
+/// Returns `true` if the values were reset.
+fn reset_button_ui(ui: &mut Ui, values: &mut [f32], reset_value: &[f32]) -> bool {
+    let is_reset = values == reset_value;
+    let clicked = ui
+        .add(Button::new("⟲").small().enabled(!is_reset))
+        .on_hover_text("Reset")
+        .clicked();
+    if clicked {
+        values.copy_from_slice(reset_value);
+    }
+    clicked
+}
@@ -0,0 +1,68 @@
+/// Shows a [`egui::LevelMeter`] per channel and a [`egui::Waveform`] of a made-up recording.
+pub struct AudioMeters {
+    samples: Vec<f32>,
+    gain: f32,
+}
+
+impl Default for AudioMeters {
+    fn default() -> Self {
+        // Two seconds of a beating tone at 8 kHz that swells and fades:
+        let sample_rate = 8_000.0;
+        let samples = (0..16_000)
+            .map(|i| {
+                let t = i as f32 / sample_rate;
+                use std::f32::consts::{PI, TAU};
+                let envelope = (t * PI / 2.0).sin();
+                envelope * 0.5 * ((t * 220.0 * TAU).sin() + (t * 223.0 * TAU).sin())
+            })
+            .collect();
+        Self { samples, gain: 1.0 }
+    }
+}
+
+impl super::Demo for AudioMeters {
+    fn name(&self) -> &'static str {
+        "🔊 Audio Meters"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .default_width(320.0)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for AudioMeters {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.add(egui::Slider::new(&mut self.gain, 0.0..=2.0).text("Gain"));
+
+        // Play the recording in a loop, and meter the part played since the last frame:
+        let len = self.samples.len();
+        let time = ui.input().time;
+        let position = (time * 8_000.0) as usize % len;
+        let window = &self.samples[position.saturating_sub(400)..position];
+        let (left_peak, right_peak, rms) = {
+            let peak = window.iter().fold(0.0_f32, |peak, s| peak.max(s.abs())) * self.gain;
+            let power = window.iter().map(|s| s * s).sum::<f32>() / window.len().max(1) as f32;
+            (peak, peak * 0.8, power.sqrt() * self.gain)
+        };
+        ui.add(egui::LevelMeter::new(left_peak).rms(rms).id_source("left"));
+        ui.add(
+            egui::LevelMeter::new(right_peak)
+                .rms(rms * 0.8)
+                .id_source("right"),
+        );
+        ui.label("Turn up the gain to make it clip, and click a meter to reset it.");
+
+        ui.add(egui::Waveform::new(&self.samples).playhead(position));
+        ui.ctx().request_repaint();
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
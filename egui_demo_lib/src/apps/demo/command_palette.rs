@@ -0,0 +1,94 @@
+const COMMANDS: &[&str] = &[
+    "Counter: Increment",
+    "Counter: Decrement",
+    "Counter: Reset",
+    "Text: Make uppercase",
+    "Text: Make lowercase",
+    "Text: Clear",
+    "View: Toggle dark mode",
+];
+
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct CommandPaletteDemo {
+    counter: i64,
+    text: String,
+    last_command: Option<String>,
+}
+
+impl Default for CommandPaletteDemo {
+    fn default() -> Self {
+        Self {
+            counter: 0,
+            text: "Hello World!".to_owned(),
+            last_command: None,
+        }
+    }
+}
+
+impl super::Demo for CommandPaletteDemo {
+    fn name(&self) -> &'static str {
+        "🔍 Command Palette"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        if let Some(index) = egui::CommandPalette::new("demo_command_palette").show(ctx, COMMANDS) {
+            self.run_command(ctx, index);
+        }
+
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl CommandPaletteDemo {
+    fn run_command(&mut self, ctx: &egui::CtxRef, index: usize) {
+        match index {
+            0 => self.counter += 1,
+            1 => self.counter -= 1,
+            2 => self.counter = 0,
+            3 => self.text = self.text.to_uppercase(),
+            4 => self.text = self.text.to_lowercase(),
+            5 => self.text.clear(),
+            _ => {
+                let visuals = if ctx.style().visuals.dark_mode {
+                    egui::Visuals::light()
+                } else {
+                    egui::Visuals::dark()
+                };
+                ctx.set_visuals(visuals);
+            }
+        }
+        self.last_command = Some(COMMANDS[index].to_owned());
+    }
+}
+
+impl super::View for CommandPaletteDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Press ctrl/cmd + P to open the command palette, then start typing.");
+        ui.label("Use the arrow keys and enter to run a command, or escape to close the palette.");
+        if ui.button("Open command palette").clicked() {
+            egui::CommandPalette::set_open(ui.ctx(), "demo_command_palette", true);
+        }
+        ui.separator();
+
+        ui.label(format!("Counter: {}", self.counter));
+        ui.horizontal(|ui| {
+            ui.label("Text:");
+            ui.text_edit_singleline(&mut self.text);
+        });
+        if let Some(last_command) = &self.last_command {
+            ui.label(format!("Last command: {}", last_command));
+        }
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+
+struct Planet {
+    name: &'static str,
+    moons: u32,
+    /// In Earth masses.
+    mass: f32,
+    has_rings: bool,
+}
+
+const PLANETS: &[Planet] = &[
+    Planet {
+        name: "Mercury",
+        moons: 0,
+        mass: 0.055,
+        has_rings: false,
+    },
+    Planet {
+        name: "Venus",
+        moons: 0,
+        mass: 0.815,
+        has_rings: false,
+    },
+    Planet {
+        name: "Earth",
+        moons: 1,
+        mass: 1.0,
+        has_rings: false,
+    },
+    Planet {
+        name: "Mars",
+        moons: 2,
+        mass: 0.107,
+        has_rings: false,
+    },
+    Planet {
+        name: "Jupiter",
+        moons: 79,
+        mass: 317.8,
+        has_rings: true,
+    },
+    Planet {
+        name: "Saturn",
+        moons: 82,
+        mass: 95.2,
+        has_rings: true,
+    },
+    Planet {
+        name: "Uranus",
+        moons: 27,
+        mass: 14.5,
+        has_rings: true,
+    },
+    Planet {
+        name: "Neptune",
+        moons: 14,
+        mass: 17.1,
+        has_rings: true,
+    },
+];
+
+#[derive(Default)]
+pub struct DataGridDemo {}
+
+impl super::Demo for DataGridDemo {
+    fn name(&self) -> &'static str {
+        "🗐 Data Grid"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for DataGridDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label(
+            "Click a header to sort, drag it to move the column, and drag the gaps to resize.",
+        );
+        ui.add_space(4.0);
+
+        let response = egui::DataGrid::new("planets")
+            .column(
+                egui::DataColumn::new("Planet", |ui, planet: &Planet| {
+                    ui.label(planet.name);
+                })
+                .sortable(|a, b| a.name.cmp(b.name))
+                .filterable(|planet| planet.name.to_owned()),
+            )
+            .column(
+                egui::DataColumn::new("Moons", |ui, planet: &Planet| {
+                    ui.label(planet.moons.to_string());
+                })
+                .width(60.0)
+                .sortable(|a, b| a.moons.cmp(&b.moons)),
+            )
+            .column(
+                egui::DataColumn::new("Mass (Earths)", |ui, planet: &Planet| {
+                    ui.label(planet.mass.to_string());
+                })
+                .width(100.0)
+                .sortable(|a, b| a.mass.partial_cmp(&b.mass).unwrap_or(Ordering::Equal)),
+            )
+            .column(
+                egui::DataColumn::new("Rings", |ui, planet: &Planet| {
+                    ui.label(if planet.has_rings { "Yes" } else { "No" });
+                })
+                .width(60.0)
+                .sortable(|a, b| a.has_rings.cmp(&b.has_rings))
+                .filterable(|planet| if planet.has_rings { "Yes" } else { "No" }.to_owned()),
+            )
+            .show(ui, PLANETS);
+        ui.label(format!(
+            "Showing {} of {} planets",
+            response.inner.len(),
+            PLANETS.len()
+        ));
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
@@ -16,17 +16,29 @@ struct Demos {
 impl Default for Demos {
     fn default() -> Self {
         Self::from_demos(vec![
+            Box::new(super::audio_meters::AudioMeters::default()),
+            Box::new(super::command_palette::CommandPaletteDemo::default()),
             Box::new(super::dancing_strings::DancingStrings::default()),
+            Box::new(super::data_grid::DataGridDemo::default()),
+            Box::new(super::diff_view::DiffViewDemo::default()),
+            Box::new(super::dock::DockDemo::default()),
             Box::new(super::drag_and_drop::DragAndDropDemo::default()),
             Box::new(super::font_book::FontBook::default()),
+            Box::new(super::hex_view::HexViewDemo::default()),
+            Box::new(super::image_viewer::ImageViewerDemo::default()),
             Box::new(super::MiscDemoWindow::default()),
             Box::new(super::multi_touch::MultiTouch::default()),
             Box::new(super::painting::Painting::default()),
+            Box::new(super::palette::PaletteDemo::default()),
             Box::new(super::plot_demo::PlotDemo::default()),
             Box::new(super::scrolling::Scrolling::default()),
             Box::new(super::sliders::Sliders::default()),
+            Box::new(super::splitter::SplitterDemo::default()),
             Box::new(super::widget_gallery::WidgetGallery::default()),
             Box::new(super::window_options::WindowOptions::default()),
+            Box::new(super::tour::TourDemo::default()),
+            Box::new(super::value_tree::ValueTreeDemo::default()),
+            Box::new(super::wizard::WizardDemo::default()),
             Box::new(super::tests::WindowResizeTest::default()),
         ])
     }
@@ -148,7 +160,7 @@ impl DemoWindows {
         let Self { demos, tests } = self;
 
         egui::SidePanel::right("egui_demo_panel")
-            .min_width(150.0)
+            .width_range(150.0..=400.0)
             .default_width(190.0)
             .show(ctx, |ui| {
                 egui::trace!(ui);
@@ -206,6 +218,8 @@ impl DemoWindows {
         }
 
         self.windows(ctx);
+
+        egui::Magnifier::new("magnifier").show(ctx);
     }
 
     /// Show the open windows.
@@ -224,16 +238,48 @@ fn show_menu_bar(ui: &mut Ui) {
     use egui::*;
 
     menu::bar(ui, |ui| {
-        menu::menu(ui, "File", |ui| {
-            if ui.button("Organize windows").clicked() {
+        menu::menu(ui, "&File", |ui| {
+            if ui.add(menu::MenuItem::new("&Organize windows")).clicked() {
                 ui.ctx().memory().reset_areas();
             }
+            menu::submenu(ui, "&Clear egui memory", |ui| {
+                if ui
+                    .button("Everything")
+                    .on_hover_text("Forget scroll, positions, sizes etc")
+                    .clicked()
+                {
+                    *ui.ctx().memory() = Default::default();
+                }
+                if ui.button("Recent colors").clicked() {
+                    color_picker::clear_recent_colors(ui.ctx());
+                }
+            });
+        });
+        menu::menu(ui, "&View", |ui| {
+            let dark_mode = ui.visuals().dark_mode;
+            if ui
+                .add(menu::MenuItem::new("&Dark").radio(dark_mode))
+                .clicked()
+            {
+                ui.ctx().set_visuals(Visuals::dark());
+            }
+            if ui
+                .add(menu::MenuItem::new("&Light").radio(!dark_mode))
+                .clicked()
+            {
+                ui.ctx().set_visuals(Visuals::light());
+            }
+            ui.separator();
+            let magnifier_open = Magnifier::is_open(ui.ctx(), "magnifier");
             if ui
-                .button("Clear egui memory")
-                .on_hover_text("Forget scroll, positions, sizes etc")
+                .add(
+                    menu::MenuItem::new("&Magnifier")
+                        .checked(magnifier_open)
+                        .shortcut("Ctrl+Shift+M"),
+                )
                 .clicked()
             {
-                *ui.ctx().memory() = Default::default();
+                Magnifier::set_open(ui.ctx(), "magnifier", !magnifier_open);
             }
         });
     });
@@ -0,0 +1,69 @@
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct DiffViewDemo {
+    old: String,
+    new: String,
+    side_by_side: bool,
+    line_numbers: bool,
+}
+
+impl Default for DiffViewDemo {
+    fn default() -> Self {
+        Self {
+            old: "[window]\nwidth = 800\nheight = 600\ntitle = \"My app\"\n\n[theme]\ndark = true\n"
+                .to_owned(),
+            new: "[window]\nwidth = 1024\nheight = 600\ntitle = \"My great app\"\nresizable = true\n\n[theme]\ndark = false\n"
+                .to_owned(),
+            side_by_side: true,
+            line_numbers: true,
+        }
+    }
+}
+
+impl super::Demo for DiffViewDemo {
+    fn name(&self) -> &'static str {
+        "± Diff View"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .default_width(600.0)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for DiffViewDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let Self {
+            old,
+            new,
+            side_by_side,
+            line_numbers,
+        } = self;
+
+        ui.label("Edit the texts to see what changed:");
+        ui.columns(2, |columns| {
+            columns[0].add(egui::TextEdit::multiline(old).code_editor());
+            columns[1].add(egui::TextEdit::multiline(new).code_editor());
+        });
+        ui.horizontal(|ui| {
+            ui.checkbox(side_by_side, "Side by side");
+            ui.checkbox(line_numbers, "Line numbers");
+        });
+        ui.separator();
+
+        egui::DiffView::new(old, new)
+            .side_by_side(*side_by_side)
+            .line_numbers(*line_numbers)
+            .max_height(300.0)
+            .show(ui);
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct DockDemo {
+    tree: egui::DockTree,
+    notes: String,
+    num_tabs_added: usize,
+}
+
+impl Default for DockDemo {
+    fn default() -> Self {
+        Self {
+            tree: default_tree(),
+            notes: "Drag the tabs around!".to_owned(),
+            num_tabs_added: 0,
+        }
+    }
+}
+
+fn default_tree() -> egui::DockTree {
+    let mut tree = egui::DockTree::default();
+    tree.add_tab("Files");
+    tree.add_tab_at("Notes", "Files", egui::DockPosition::Right);
+    tree.add_tab("Preview");
+    tree.add_tab_at("Console", "Notes", egui::DockPosition::Bottom);
+    tree.focus_tab("Notes");
+    tree
+}
+
+impl super::Demo for DockDemo {
+    fn name(&self) -> &'static str {
+        "🗗 Dock"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .default_size([500.0, 350.0])
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for DockDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let Self {
+            tree,
+            notes,
+            num_tabs_added,
+        } = self;
+
+        ui.horizontal(|ui| {
+            if ui.button("Add tab").clicked() {
+                *num_tabs_added += 1;
+                tree.add_tab(format!("Tab {}", num_tabs_added));
+            }
+            if ui.button("Reset layout").clicked() {
+                *tree = default_tree();
+            }
+            if let Some(focused) = tree.focused_tab() {
+                ui.label(format!("Focused: {}", focused));
+            }
+        });
+        ui.add(crate::__egui_github_link_file!());
+        ui.separator();
+
+        egui::DockArea::new("dock_demo").show(ui, tree, |ui, tab| match tab {
+            "Files" => {
+                for file in &["main.rs", "lib.rs", "dock.rs"] {
+                    ui.label(*file);
+                }
+            }
+            "Notes" => {
+                ui.text_edit_multiline(notes);
+            }
+            "Preview" => {
+                ui.label(notes.as_str());
+            }
+            "Console" => {
+                ui.monospace("> cargo run");
+            }
+            _ => {
+                ui.label(format!("This is {}", tab));
+            }
+        });
+    }
+}
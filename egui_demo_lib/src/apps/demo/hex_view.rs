@@ -0,0 +1,76 @@
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct HexViewDemo {
+    data: Vec<u8>,
+    editable: bool,
+}
+
+impl Default for HexViewDemo {
+    fn default() -> Self {
+        // A tiny PNG header, followed by some text:
+        let mut data = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        data.extend_from_slice(
+            "egui is an easy-to-use immediate mode GUI in pure Rust. "
+                .repeat(8)
+                .as_bytes(),
+        );
+        Self {
+            data,
+            editable: false,
+        }
+    }
+}
+
+impl super::Demo for HexViewDemo {
+    fn name(&self) -> &'static str {
+        "🔢 Hex View"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for HexViewDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(&mut self.editable, "Editable")
+            .on_hover_text("Click a byte and type hex digits to overwrite it");
+        ui.horizontal(|ui| {
+            ui.colored_label(egui::Color32::from_rgb(140, 140, 255), "■ PNG signature");
+            ui.colored_label(
+                egui::Color32::from_rgb(90, 180, 90),
+                "■ Chunk length and type",
+            );
+        });
+
+        let hex_view = if self.editable {
+            egui::HexView::editable(&mut self.data)
+        } else {
+            egui::HexView::new(&self.data)
+        };
+        let response = hex_view
+            .highlight(0..8, egui::Color32::from_rgb(50, 50, 110))
+            .highlight(8..16, egui::Color32::from_rgb(30, 80, 30))
+            .max_height(200.0)
+            .show(ui);
+
+        match response.selection {
+            Some(selection) => ui.label(format!(
+                "Selected {} bytes at offset {}. Copy them as hex with the usual shortcut.",
+                selection.len(),
+                selection.start
+            )),
+            None => ui.label("Click and drag to select bytes."),
+        };
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
@@ -0,0 +1,71 @@
+pub struct ImageViewerDemo {
+    pixel_grid: bool,
+    eyedropper: bool,
+}
+
+impl Default for ImageViewerDemo {
+    fn default() -> Self {
+        Self {
+            pixel_grid: true,
+            eyedropper: false,
+        }
+    }
+}
+
+impl super::Demo for ImageViewerDemo {
+    fn name(&self) -> &'static str {
+        "🔍 Image Viewer"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .default_size([400.0, 400.0])
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for ImageViewerDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("This is the texture egui uses for the glyphs of the fonts.");
+        ui.label("Scroll to zoom, and drag to pan.");
+        ui.checkbox(&mut self.pixel_grid, "Pixel grid when zoomed in");
+
+        let [width, height] = ui.ctx().texture().size();
+        egui::ImageViewer::new(egui::TextureId::Egui, [width as f32, height as f32])
+            .desired_size([ui.available_width(), 300.0])
+            .pixel_grid(self.pixel_grid)
+            .show(ui);
+
+        ui.checkbox(&mut self.eyedropper, "Eyedropper")
+            .on_hover_text("Show the color on screen under the mouse, anywhere");
+        if self.eyedropper {
+            let color = ui.input().pointer.hover_pos().and_then(|pos| {
+                let rect = egui::Rect::from_center_size(pos, egui::Vec2::splat(1.0));
+                let pixels = ui.ctx().read_screen_pixels(rect)?;
+                pixels.pixels.get(pixels.pixels.len() / 2).copied()
+            });
+            ui.horizontal(|ui| match color {
+                Some(color) => {
+                    egui::widgets::color_picker::show_color(
+                        ui,
+                        color,
+                        egui::Vec2::splat(ui.spacing().interact_size.y),
+                    );
+                    let [r, g, b, _] = color.to_array();
+                    ui.monospace(format!("#{:02X}{:02X}{:02X}", r, g, b));
+                }
+                None => {
+                    ui.label("Move the mouse over something");
+                }
+            });
+        }
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
@@ -6,6 +6,11 @@ use egui::{color::*, *};
 #[cfg_attr(feature = "persistence", serde(default))]
 pub struct MiscDemoWindow {
     num_columns: usize,
+    num_cached_rows: usize,
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    cached_layout_runs: usize,
+    tabs: Vec<String>,
+    num_tabs_added: usize,
 
     widgets: Widgets,
     colors: ColorWidgets,
@@ -17,6 +22,14 @@ impl Default for MiscDemoWindow {
     fn default() -> MiscDemoWindow {
         MiscDemoWindow {
             num_columns: 2,
+            num_cached_rows: 100,
+            cached_layout_runs: 0,
+            tabs: vec![
+                "main.rs".to_owned(),
+                "lib.rs".to_owned(),
+                "README.md".to_owned(),
+            ],
+            num_tabs_added: 0,
 
             widgets: Default::default(),
             colors: Default::default(),
@@ -69,6 +82,39 @@ impl View for MiscDemoWindow {
             });
         });
 
+        ui.collapsing("Cached layout", |ui| {
+            ui.add(Slider::new(&mut self.num_cached_rows, 0..=1000).text("rows"));
+            ui.label(format!(
+                "The rows below have been laid out {} times. Hover them, or change the number of rows, to lay them out again.",
+                self.cached_layout_runs
+            ));
+            let num_rows = self.num_cached_rows;
+            let runs = &mut self.cached_layout_runs;
+            ui.cache_layout("cached_rows", num_rows, |ui| {
+                *runs += 1;
+                for row in 0..num_rows {
+                    ui.label(format!("Row {}", row));
+                }
+            });
+        });
+
+        ui.collapsing("Tabs", |ui| {
+            ui.label("Click a tab to show it, drag it to reorder. Add enough tabs and the tab bar scrolls.");
+            if ui.button("Add tab").clicked() {
+                self.num_tabs_added += 1;
+                self.tabs.push(format!("untitled_{}.rs", self.num_tabs_added));
+            }
+            let tabs = &self.tabs;
+            let response = Tabs::new("demo_tabs")
+                .tabs(tabs)
+                .closable(true)
+                .reorderable(true)
+                .show(ui, |ui, index| {
+                    ui.label(format!("This is the contents of {}.", tabs[index]));
+                });
+            response.apply(&mut self.tabs);
+        });
+
         CollapsingHeader::new("Test box rendering")
             .default_open(false)
             .show(ui, |ui| self.box_painting.ui(ui));
@@ -206,14 +252,86 @@ impl Widgets {
             );
         });
 
+        let mut layouter = |ui: &Ui, text: &str, wrap_width: f32| {
+            let galley =
+                ui.fonts()
+                    .layout_multiline(TextStyle::Monospace, text.to_owned(), wrap_width);
+            std::sync::Arc::new(galley.with_sections(highlight_rust(ui.visuals(), text)))
+        };
         ui.add(
             TextEdit::multiline(code_snippet)
                 .code_editor()
-                .lock_focus(*lock_focus),
+                .lock_focus(*lock_focus)
+                .layouter(&mut layouter),
         );
     }
 }
 
+/// A very simple syntax highlighter, for showing off [`TextEdit::layouter`].
+fn highlight_rust(visuals: &Visuals, text: &str) -> Vec<epaint::text::TextSection> {
+    use epaint::text::{TextFormat, TextSection};
+
+    const KEYWORDS: &[&str] = &[
+        "as", "else", "enum", "fn", "for", "if", "impl", "in", "let", "match", "mut", "pub",
+        "return", "struct", "use", "while",
+    ];
+
+    let (keyword_color, string_color, comment_color) = if visuals.dark_mode {
+        (
+            Color32::from_rgb(255, 160, 90),
+            Color32::from_rgb(140, 220, 110),
+            Color32::GRAY,
+        )
+    } else {
+        (
+            Color32::from_rgb(200, 80, 0),
+            Color32::from_rgb(40, 130, 20),
+            Color32::GRAY,
+        )
+    };
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut sections = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let color = if chars[i] == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            Some(comment_color)
+        } else if chars[i] == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            Some(string_color)
+        } else if chars[i].is_alphanumeric() || chars[i] == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            if KEYWORDS.contains(&word.as_str()) {
+                Some(keyword_color)
+            } else {
+                None
+            }
+        } else {
+            i += 1;
+            None
+        };
+
+        if let Some(color) = color {
+            sections.push(TextSection {
+                char_range: start..i,
+                format: TextFormat::color(color),
+            });
+        }
+    }
+    sections
+}
+
 // ----------------------------------------------------------------------------
 
 #[derive(PartialEq)]
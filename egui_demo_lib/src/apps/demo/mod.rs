@@ -5,22 +5,34 @@
 // ----------------------------------------------------------------------------
 
 mod app;
+pub mod audio_meters;
+pub mod command_palette;
 pub mod dancing_strings;
+pub mod data_grid;
 pub mod demo_app_windows;
+pub mod diff_view;
+pub mod dock;
 pub mod drag_and_drop;
 pub mod font_book;
+pub mod hex_view;
+pub mod image_viewer;
 pub mod layout_test;
 pub mod misc_demo_window;
 pub mod multi_touch;
 pub mod painting;
+pub mod palette;
 pub mod password;
 pub mod plot_demo;
 pub mod scrolling;
 pub mod sliders;
+pub mod splitter;
 pub mod tests;
 pub mod toggle_switch;
+pub mod tour;
+pub mod value_tree;
 pub mod widget_gallery;
 pub mod window_options;
+pub mod wizard;
 
 pub use {
     app::DemoApp, demo_app_windows::DemoWindows, misc_demo_window::MiscDemoWindow,
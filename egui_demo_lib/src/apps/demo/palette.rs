@@ -0,0 +1,69 @@
+use egui::Color32;
+
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct PaletteDemo {
+    colors: Vec<Color32>,
+    selected: Option<usize>,
+    new_color: Color32,
+}
+
+impl Default for PaletteDemo {
+    fn default() -> Self {
+        Self {
+            colors: vec![
+                Color32::from_rgb(230, 57, 70),
+                Color32::from_rgb(241, 250, 238),
+                Color32::from_rgb(168, 218, 220),
+                Color32::from_rgb(69, 123, 157),
+                Color32::from_rgb(29, 53, 87),
+            ],
+            selected: None,
+            new_color: Color32::from_rgb(255, 183, 3),
+        }
+    }
+}
+
+impl super::Demo for PaletteDemo {
+    fn name(&self) -> &'static str {
+        "🎨 Palette"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .default_width(280.0)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for PaletteDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label(
+            "Click a swatch to select it, drag it to move it, and double-click it to edit it.",
+        );
+        ui.horizontal(|ui| {
+            ui.label("Drag this color onto the palette to add it:");
+            ui.color_edit_button_srgba(&mut self.new_color);
+        });
+        ui.separator();
+
+        ui.add(egui::PaletteGrid::new(&mut self.colors).selected(&mut self.selected));
+
+        ui.separator();
+        match self.selected.and_then(|index| self.colors.get(index)) {
+            Some(color) => ui.label(format!("Selected: {:?}", color)),
+            None => ui.label("Nothing selected"),
+        };
+        if ui.button("Reset").clicked() {
+            *self = Default::default();
+        }
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
@@ -5,6 +5,7 @@ use egui::{color::*, *};
 enum ScrollDemo {
     ScrollTo,
     ManyLines,
+    ManyCells,
     LargeCanvas,
 }
 
@@ -47,6 +48,7 @@ impl super::View for Scrolling {
                 ScrollDemo::ManyLines,
                 "Scroll a lot of lines",
             );
+            ui.selectable_value(&mut self.demo, ScrollDemo::ManyCells, "Scroll a huge grid");
             ui.selectable_value(
                 &mut self.demo,
                 ScrollDemo::LargeCanvas,
@@ -61,6 +63,9 @@ impl super::View for Scrolling {
             ScrollDemo::ManyLines => {
                 huge_content_lines(ui);
             }
+            ScrollDemo::ManyCells => {
+                huge_content_cells(ui);
+            }
             ScrollDemo::LargeCanvas => {
                 huge_content_painter(ui);
             }
@@ -85,6 +90,33 @@ fn huge_content_lines(ui: &mut egui::Ui) {
     });
 }
 
+fn huge_content_cells(ui: &mut egui::Ui) {
+    ui.label(
+        "A huge grid, but only the visible cells are layed out, so performance is still good:",
+    );
+    ui.add_space(4.0);
+
+    let cell_size = vec2(64.0, ui.spacing().interact_size.y);
+    let num_rows = 10_000;
+    let num_cols = 100;
+    ScrollArea::auto_sized().show_cells(
+        ui,
+        cell_size,
+        num_rows,
+        num_cols,
+        |ui, row_range, col_range| {
+            for row in row_range {
+                ui.horizontal(|ui| {
+                    ui.skip_ahead_auto_ids(col_range.start); // Make sure we get consistent IDs.
+                    for col in col_range.clone() {
+                        ui.add_sized(cell_size, Label::new(format!("{}:{}", row, col)));
+                    }
+                });
+            }
+        },
+    );
+}
+
 fn huge_content_painter(ui: &mut egui::Ui) {
     // This is similar to the other demo, but is fully manual, for when you want to do custom painting.
     ui.label("A lot of rows, but only the visible ones are painted, so performance is still good:");
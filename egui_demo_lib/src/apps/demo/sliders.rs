@@ -12,7 +12,11 @@ pub struct Sliders {
     pub clamp_to_range: bool,
     pub smart_aim: bool,
     pub integer: bool,
+    pub vertical: bool,
     pub value: f64,
+    pub angle: f64,
+    pub snap_only_with_alt: bool,
+    pub distance: f64,
 }
 
 impl Default for Sliders {
@@ -24,7 +28,11 @@ impl Default for Sliders {
             clamp_to_range: false,
             smart_aim: true,
             integer: false,
+            vertical: false,
             value: 10.0,
+            angle: 45.0,
+            snap_only_with_alt: false,
+            distance: 1500.0,
         }
     }
 }
@@ -54,7 +62,11 @@ impl super::View for Sliders {
             clamp_to_range,
             smart_aim,
             integer,
+            vertical,
             value,
+            angle,
+            snap_only_with_alt,
+            distance,
         } = self;
 
         ui.label("You can click a slider value to edit it with the keyboard.");
@@ -70,6 +82,12 @@ impl super::View for Sliders {
         *min = min.clamp(type_min, type_max);
         *max = max.clamp(type_min, type_max);
 
+        let orientation = if *vertical {
+            SliderOrientation::Vertical
+        } else {
+            SliderOrientation::Horizontal
+        };
+
         if *integer {
             let mut value_i32 = *value as i32;
             ui.add(
@@ -77,6 +95,7 @@ impl super::View for Sliders {
                     .logarithmic(*logarithmic)
                     .clamp_to_range(*clamp_to_range)
                     .smart_aim(*smart_aim)
+                    .orientation(orientation)
                     .text("i32 demo slider"),
             );
             *value = value_i32 as f64;
@@ -86,6 +105,7 @@ impl super::View for Sliders {
                     .logarithmic(*logarithmic)
                     .clamp_to_range(*clamp_to_range)
                     .smart_aim(*smart_aim)
+                    .orientation(orientation)
                     .text("f64 demo slider"),
             );
 
@@ -124,6 +144,10 @@ impl super::View for Sliders {
         ui.label("(f32, usize etc are also possible)");
         ui.add_space(8.0);
 
+        ui.checkbox(vertical, "Vertical");
+        ui.label("Vertical sliders are handy for faders in audio mixers and the like.");
+        ui.add_space(8.0);
+
         ui.checkbox(logarithmic, "Logarithmic");
         ui.label("Logarithmic sliders are great for when you want to span a huge range, i.e. from zero to a million.");
         ui.label("Logarithmic sliders can include infinity and zero.");
@@ -138,9 +162,62 @@ impl super::View for Sliders {
         ui.label("Smart Aim will guide you towards round values when you drag the slider so you you are more likely to hit 250 than 247.23");
         ui.add_space(8.0);
 
+        ui.separator();
+        ui.label("Sliders can snap to steps and show tick marks:");
+        ui.add(
+            Slider::new(angle, 0.0..=360.0)
+                .step_by(15.0)
+                .snap_modifiers(Modifiers {
+                    alt: *snap_only_with_alt,
+                    ..Default::default()
+                })
+                .ticks(90.0)
+                .tick_labels(true)
+                .suffix("°")
+                .text("angle"),
+        );
+        ui.checkbox(snap_only_with_alt, "Only snap while holding Alt");
+        ui.add_space(8.0);
+
+        ui.separator();
+        ui.label("You can format and parse the value yourself:");
+        ui.add(
+            Slider::new(distance, 0.0..=10_000.0)
+                .custom_formatter(format_distance)
+                .custom_parser(parse_distance)
+                .text("distance"),
+        );
+        ui.horizontal(|ui| {
+            ui.label("Or give a DragValue a list of units:");
+            ui.add(
+                egui::DragValue::new(distance)
+                    .clamp_range(0.0..=10_000.0)
+                    .suffix_units(&[("cm", 0.01), ("m", 1.0), ("km", 1000.0)]),
+            );
+        });
+        ui.add_space(8.0);
+
         ui.vertical_centered(|ui| {
             egui::reset_button(ui, self);
             ui.add(crate::__egui_github_link_file!());
         });
     }
 }
+
+fn format_distance(meters: f64, _decimals: std::ops::RangeInclusive<usize>) -> String {
+    if meters.abs() < 1000.0 {
+        format!("{:.0} m", meters)
+    } else {
+        format!("{:.1} km", meters / 1000.0)
+    }
+}
+
+/// Parses e.g. "1.5 km", "200 m" and "200".
+fn parse_distance(text: &str) -> Option<f64> {
+    let text = text.trim();
+    if let Some(km) = text.strip_suffix("km") {
+        km.trim().parse::<f64>().ok().map(|km| km * 1000.0)
+    } else {
+        text.trim_end_matches('m').trim().parse().ok()
+    }
+}
@@ -0,0 +1,68 @@
+use egui::SplitSize;
+
+#[derive(Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct SplitterDemo {
+    code: String,
+}
+
+impl super::Demo for SplitterDemo {
+    fn name(&self) -> &'static str {
+        "⇔ Splitter"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .default_size([600.0, 400.0])
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for SplitterDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Drag the dividers to resize the regions, double-click them to reset.");
+        ui.label("The file list keeps its width when the window is resized, the other regions share the rest.");
+        ui.separator();
+
+        let code = &mut self.code;
+        egui::Splitter::horizontal("demo_splitter")
+            .region(SplitSize::Points(120.0))
+            .region(SplitSize::Proportional(2.0))
+            .region(SplitSize::Proportional(1.0))
+            .show(ui, |ui, region| match region {
+                0 => {
+                    ui.heading("Files");
+                    for file in &["main.rs", "lib.rs", "splitter.rs"] {
+                        ui.label(*file);
+                    }
+                }
+                1 => {
+                    egui::Splitter::vertical("demo_editor_splitter")
+                        .region(SplitSize::Proportional(3.0))
+                        .region(SplitSize::Proportional(1.0))
+                        .show(ui, |ui, region| {
+                            if region == 0 {
+                                ui.add(
+                                    egui::TextEdit::multiline(code)
+                                        .code_editor()
+                                        .hint_text("Editor")
+                                        .desired_width(f32::INFINITY),
+                                );
+                            } else {
+                                ui.heading("Terminal");
+                                ui.label("$ cargo run");
+                            }
+                        });
+                }
+                _ => {
+                    ui.heading("Outline");
+                    ui.label("fn main()");
+                }
+            });
+    }
+}
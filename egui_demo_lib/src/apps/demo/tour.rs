@@ -0,0 +1,108 @@
+const TOUR_ID: &str = "demo_tour";
+
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct TourDemo {
+    name: String,
+    volume: f32,
+    saved: bool,
+
+    /// The widgets the tour points at, from the last frame.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    name_id: Option<egui::Id>,
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    volume_id: Option<egui::Id>,
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    save_id: Option<egui::Id>,
+}
+
+impl Default for TourDemo {
+    fn default() -> Self {
+        Self {
+            name: "Ferris".to_owned(),
+            volume: 0.5,
+            saved: false,
+            name_id: None,
+            volume_id: None,
+            save_id: None,
+        }
+    }
+}
+
+impl super::Demo for TourDemo {
+    fn name(&self) -> &'static str {
+        "🎓 Guided Tour"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+
+        // After the window, so the widgets have been laid out:
+        let step = |title: &str, text: &str, target: Option<egui::Id>| {
+            let step = egui::TourStep::new(title, text);
+            match target {
+                Some(target) => step.target(target),
+                None => step,
+            }
+        };
+        egui::Tour::new(TOUR_ID)
+            .step(step(
+                "Welcome!",
+                "This tour shows you around the settings. Press escape to end it at any time.",
+                None,
+            ))
+            .step(step("Name", "Type your name here.", self.name_id))
+            .step(step("Volume", "Drag to change the volume.", self.volume_id))
+            .step(step(
+                "Save",
+                "Don't forget to save your settings!",
+                self.save_id,
+            ))
+            .show(ctx);
+    }
+}
+
+impl super::View for TourDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("Take the tour").clicked() {
+                egui::Tour::start(ui.ctx(), TOUR_ID);
+            }
+            if ui.button("Where is the volume?").clicked() {
+                if let Some(volume_id) = self.volume_id {
+                    ui.ctx().highlight_widget(volume_id);
+                }
+            }
+        });
+        ui.separator();
+
+        egui::Grid::new("tour_demo_grid").show(ui, |ui| {
+            ui.label("Name:");
+            self.name_id = Some(ui.text_edit_singleline(&mut self.name).id);
+            ui.end_row();
+
+            ui.label("Volume:");
+            self.volume_id = Some(ui.add(egui::Slider::new(&mut self.volume, 0.0..=1.0)).id);
+            ui.end_row();
+        });
+
+        let save = ui.button("Save");
+        self.save_id = Some(save.id);
+        if save.clicked() {
+            self.saved = true;
+        }
+        if self.saved {
+            ui.label("Saved!");
+        }
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
@@ -0,0 +1,95 @@
+use egui::TreeValue;
+
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct ValueTreeDemo {
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    value: TreeValue,
+    search: bool,
+}
+
+impl Default for ValueTreeDemo {
+    fn default() -> Self {
+        Self {
+            value: example_value(),
+            search: true,
+        }
+    }
+}
+
+fn example_value() -> TreeValue {
+    fn string(s: &str) -> TreeValue {
+        TreeValue::String(s.to_owned())
+    }
+    fn object(entries: Vec<(&str, TreeValue)>) -> TreeValue {
+        TreeValue::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    object(vec![
+        ("name", string("egui")),
+        ("version", string("0.13.1")),
+        ("immediate mode", TreeValue::Bool(true)),
+        (
+            "authors",
+            TreeValue::Array(vec![string("Emil Ernerfeldt <emil.ernerfeldt@gmail.com>")]),
+        ),
+        (
+            "backends",
+            TreeValue::Array(vec![
+                object(vec![
+                    ("name", string("egui_glium")),
+                    ("native", TreeValue::Bool(true)),
+                ]),
+                object(vec![
+                    ("name", string("egui_web")),
+                    ("native", TreeValue::Bool(false)),
+                    ("canvas", string("WebGL")),
+                ]),
+            ]),
+        ),
+        (
+            "window",
+            object(vec![
+                ("width", TreeValue::Number(1024.0)),
+                ("height", TreeValue::Number(768.0)),
+                ("pixels_per_point", TreeValue::Number(1.5)),
+                ("icon", TreeValue::Null),
+            ]),
+        ),
+    ])
+}
+
+impl super::Demo for ValueTreeDemo {
+    fn name(&self) -> &'static str {
+        "🌲 Value Tree"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .default_width(400.0)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for ValueTreeDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Click a row to expand or collapse it, right-click it to copy its path or value.");
+        ui.checkbox(&mut self.search, "Search");
+        ui.separator();
+
+        egui::ScrollArea::auto_sized().show(ui, |ui| {
+            egui::ValueTree::new(&self.value)
+                .search(self.search)
+                .show(ui);
+        });
+    }
+}
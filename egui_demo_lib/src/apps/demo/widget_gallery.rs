@@ -13,7 +13,15 @@ pub struct WidgetGallery {
     visible: bool,
     boolean: bool,
     radio: Enum,
+    country: usize,
+    toppings: [bool; 5],
     scalar: f32,
+    range: (f32, f32),
+    size: egui::Vec2,
+    keep_aspect_ratio: bool,
+    vector: [f32; 3],
+    time: u32,
+    duration: std::time::Duration,
     string: String,
     color: egui::Color32,
 }
@@ -25,7 +33,15 @@ impl Default for WidgetGallery {
             visible: true,
             boolean: false,
             radio: Enum::First,
+            country: 0,
+            toppings: [true, false, true, false, false],
             scalar: 42.0,
+            range: (90.0, 270.0),
+            size: egui::vec2(640.0, 480.0),
+            keep_aspect_ratio: true,
+            vector: [1.0, 2.0, 3.0],
+            time: 9 * 3600 + 30 * 60,
+            duration: std::time::Duration::from_secs(90),
             string: Default::default(),
             color: egui::Color32::LIGHT_BLUE.linear_multiply(0.5),
         }
@@ -92,7 +108,15 @@ impl WidgetGallery {
             visible: _,
             boolean,
             radio,
+            country,
+            toppings,
             scalar,
+            range,
+            size,
+            keep_aspect_ratio,
+            vector,
+            time,
+            duration,
             string,
             color,
         } = self;
@@ -101,6 +125,10 @@ impl WidgetGallery {
         ui.label("Welcome to the widget gallery!");
         ui.end_row();
 
+        ui.add(doc_link_label("Label::selectable", "Label::selectable"));
+        ui.add(egui::Label::new("You can select and copy this text").selectable(true));
+        ui.end_row();
+
         ui.add(doc_link_label("Hyperlink", "Hyperlink"));
         use egui::special_emojis::GITHUB;
         ui.hyperlink_to(
@@ -113,12 +141,44 @@ impl WidgetGallery {
         ui.add(egui::TextEdit::singleline(string).hint_text("Write something here"));
         ui.end_row();
 
+        ui.add(doc_link_label("SymbolPicker", "SymbolPicker"));
+        ui.horizontal(|ui| {
+            let _ = egui::SymbolPicker::new("gallery_symbols").show(ui);
+            ui.label("Insert a symbol in the text above");
+        });
+        ui.end_row();
+
         ui.add(doc_link_label("Button", "button"));
         if ui.button("Click me!").clicked() {
             *boolean = !*boolean;
         }
         ui.end_row();
 
+        ui.add(doc_link_label("Context menu", "context_menu"));
+        ui.button("Right-click me").context_menu(|ui| {
+            if ui
+                .add(egui::menu::MenuItem::new("Checkbox").checked(*boolean))
+                .clicked()
+            {
+                *boolean = !*boolean;
+            }
+            egui::menu::submenu(ui, "More", |ui| {
+                if ui.button("Reset").clicked() {
+                    *boolean = false;
+                }
+            });
+        });
+        ui.end_row();
+
+        ui.add(doc_link_label(
+            "Button with icon",
+            "Button::icon,Button::image",
+        ));
+        if ui.add(egui::Button::new("Click me!").icon("☀")).clicked() {
+            *boolean = !*boolean;
+        }
+        ui.end_row();
+
         ui.add(doc_link_label("Checkbox", "checkbox"));
         ui.checkbox(boolean, "Checkbox");
         ui.end_row();
@@ -142,6 +202,20 @@ impl WidgetGallery {
         });
         ui.end_row();
 
+        ui.add(doc_link_label("ListItem", "ListItem"));
+        if ui
+            .add(
+                egui::ListItem::new("List item")
+                    .icon("🗀")
+                    .subtitle("Click to select")
+                    .selected(*boolean),
+            )
+            .clicked()
+        {
+            *boolean = !*boolean;
+        }
+        ui.end_row();
+
         ui.add(doc_link_label("Combo box", "ComboBox"));
 
         egui::ComboBox::from_label("Take your pick")
@@ -153,14 +227,116 @@ impl WidgetGallery {
             });
         ui.end_row();
 
+        ui.add(doc_link_label("Searchable", "ComboBox::searchable"));
+        egui::ComboBox::from_id_source("searchable_combo_box")
+            .searchable(true)
+            .show_index(ui, country, COUNTRIES.len(), |i| COUNTRIES[i].to_owned());
+        ui.end_row();
+
+        ui.add(doc_link_label(
+            "Tri-state checkbox",
+            "Checkbox::indeterminate",
+        ));
+        let mut all_toppings = toppings.iter().all(|&topping| topping);
+        let some_toppings = !all_toppings && toppings.iter().any(|&topping| topping);
+        if ui
+            .add(
+                egui::Checkbox::new(&mut all_toppings, "All toppings").indeterminate(some_toppings),
+            )
+            .changed()
+        {
+            *toppings = [all_toppings; 5];
+        }
+        ui.end_row();
+
+        ui.add(doc_link_label("Multi-select", "ComboBox::show_multi"));
+        egui::ComboBox::from_id_source("multi_select_combo_box")
+            .show_multi(ui, toppings, |i| TOPPINGS[i].to_owned());
+        ui.end_row();
+
         ui.add(doc_link_label("Slider", "Slider"));
         ui.add(egui::Slider::new(scalar, 0.0..=360.0).suffix("°"));
         ui.end_row();
 
+        ui.add(doc_link_label("RangeSlider", "RangeSlider"));
+        ui.add(egui::RangeSlider::new(
+            &mut range.0,
+            &mut range.1,
+            0.0..=360.0,
+        ));
+        ui.end_row();
+
         ui.add(doc_link_label("DragValue", "DragValue"));
         ui.add(egui::DragValue::new(scalar).speed(1.0));
         ui.end_row();
 
+        ui.add(doc_link_label("Knob", "Knob"));
+        ui.add(egui::Knob::new(scalar, 0.0..=360.0).default_value(42.0))
+            .on_hover_text("Drag up or down to turn, double-click to reset");
+        ui.end_row();
+
+        ui.add(doc_link_label("Stepper", "Stepper"));
+        ui.add(
+            egui::Stepper::new(scalar)
+                .step(10.0)
+                .clamp_range(0.0..=360.0),
+        );
+        ui.end_row();
+
+        ui.add(doc_link_label("ProgressBar", "ProgressBar"));
+        let progress = *scalar / 360.0;
+        ui.add(
+            egui::ProgressBar::new(progress)
+                .desired_width(ui.spacing().slider_width)
+                .show_percentage(),
+        );
+        ui.end_row();
+
+        ui.add(doc_link_label("RadialProgress", "RadialProgress"));
+        ui.add(egui::RadialProgress::new(progress));
+        ui.end_row();
+
+        ui.add(doc_link_label("Gauge", "Gauge"));
+        ui.add(
+            egui::Gauge::new(*scalar, 0.0..=360.0)
+                .zone(270.0..=360.0, egui::Color32::from_rgb(220, 80, 60))
+                .suffix("°"),
+        );
+        ui.end_row();
+
+        ui.add(doc_link_label("Sparkline", "Sparkline"));
+        let phase = scalar.to_radians();
+        let values: Vec<f32> = (0..60)
+            .map(|i| (0.2 * i as f32 + phase).sin() + 0.3 * (0.7 * i as f32).cos())
+            .collect();
+        ui.add(egui::Sparkline::new(&values).width(ui.spacing().slider_width));
+        ui.end_row();
+
+        ui.add(doc_link_label("Spinner", "Spinner"));
+        ui.add(egui::Spinner::new());
+        ui.end_row();
+
+        ui.add(doc_link_label("DragVec2", "DragVec2"));
+        ui.add(
+            egui::DragVec2::new(size)
+                .clamp_range(1.0..=4096.0)
+                .prefixes("w: ", "h: ")
+                .lock_aspect_ratio(keep_aspect_ratio),
+        );
+        ui.end_row();
+
+        ui.add(doc_link_label("VectorEdit", "VectorEdit,MatrixEdit"));
+        ui.add(egui::VectorEdit::new(vector).reset_to(&[0.0; 3]));
+        ui.end_row();
+
+        ui.add(doc_link_label("TimePicker", "TimePicker"));
+        ui.add(egui::TimePicker::new(time));
+        ui.end_row();
+
+        ui.add(doc_link_label("DurationPicker", "DurationPicker"));
+        ui.add(egui::DurationPicker::new(duration));
+        ui.end_row();
+
         ui.add(doc_link_label("Color picker", "color_edit"));
         ui.color_edit_button_srgba(color);
         ui.end_row();
@@ -225,6 +401,35 @@ fn example_plot() -> egui::plot::Plot {
         .data_aspect(1.0)
 }
 
+const TOPPINGS: [&str; 5] = ["Cheese", "Ham", "Mushrooms", "Olives", "Pineapple"];
+
+const COUNTRIES: &[&str] = &[
+    "Argentina",
+    "Australia",
+    "Brazil",
+    "Canada",
+    "Chile",
+    "China",
+    "Denmark",
+    "Egypt",
+    "Finland",
+    "France",
+    "Germany",
+    "Iceland",
+    "India",
+    "Japan",
+    "Kenya",
+    "Mexico",
+    "New Zealand",
+    "Norway",
+    "Peru",
+    "South Africa",
+    "Spain",
+    "Sweden",
+    "United Kingdom",
+    "United States",
+];
+
 fn doc_link_label<'a>(title: &'a str, search_term: &'a str) -> impl egui::Widget + 'a {
     let label = format!("{}:", title);
     let url = format!("https://docs.rs/egui?search={}", search_term);
@@ -5,7 +5,10 @@ pub struct WindowOptions {
     title_bar: bool,
     closable: bool,
     collapsible: bool,
+    minimize_button: bool,
     resizable: bool,
+    constrain: bool,
+    always_on_top: bool,
     scroll: bool,
     disabled_time: f64,
 
@@ -21,7 +24,10 @@ impl Default for WindowOptions {
             title_bar: true,
             closable: true,
             collapsible: true,
+            minimize_button: true,
             resizable: true,
+            constrain: true,
+            always_on_top: false,
             scroll: false,
             disabled_time: f64::NEG_INFINITY,
             anchored: false,
@@ -42,7 +48,10 @@ impl super::Demo for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            minimize_button,
             resizable,
+            constrain,
+            always_on_top,
             scroll,
             disabled_time,
             anchored,
@@ -59,7 +68,10 @@ impl super::Demo for WindowOptions {
         let mut window = egui::Window::new(title)
             .id(egui::Id::new("demo_window_options")) // required since we change the title
             .resizable(resizable)
+            .constrain(constrain)
+            .always_on_top(always_on_top)
             .collapsible(collapsible)
+            .minimize_button(minimize_button)
             .title_bar(title_bar)
             .scroll(scroll)
             .enabled(enabled);
@@ -80,7 +92,10 @@ impl super::View for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            minimize_button,
             resizable,
+            constrain,
+            always_on_top,
             scroll,
             disabled_time,
             anchored,
@@ -95,7 +110,11 @@ impl super::View for WindowOptions {
         ui.checkbox(title_bar, "title_bar");
         ui.checkbox(closable, "closable");
         ui.checkbox(collapsible, "collapsible");
+        ui.checkbox(minimize_button, "minimize_button");
         ui.checkbox(resizable, "resizable");
+        ui.checkbox(constrain, "constrain")
+            .on_hover_text("Keep the window on the screen");
+        ui.checkbox(always_on_top, "always_on_top");
         ui.checkbox(scroll, "scroll");
 
         ui.group(|ui| {
@@ -120,9 +139,30 @@ impl super::View for WindowOptions {
             });
         });
 
+        let mut snap_distance = ui.style().interaction.window_snap_distance;
+        let response = ui.add(
+            egui::Slider::new(&mut snap_distance, 0.0..=20.0)
+                .text("window snap distance (all windows)"),
+        );
+        if response.changed() {
+            let mut style = (*ui.ctx().style()).clone();
+            style.interaction.window_snap_distance = snap_distance;
+            ui.ctx().set_style(style);
+        }
+
         if ui.button("Disable for 2 seconds").clicked() {
             *disabled_time = ui.input().time;
         }
+        ui.horizontal(|ui| {
+            if ui.button("Collapse").clicked() {
+                egui::Window::set_collapsed(ui.ctx(), egui::Id::new("demo_window_options"), true);
+            }
+            if ui.button("Send to back").clicked() {
+                let layer_id =
+                    egui::LayerId::new(egui::Order::Middle, egui::Id::new("demo_window_options"));
+                ui.ctx().move_to_bottom(layer_id);
+            }
+        });
 
         ui.vertical_centered(|ui| {
             egui::reset_button(ui, self);
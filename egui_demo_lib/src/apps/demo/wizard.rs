@@ -0,0 +1,76 @@
+#[derive(Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct WizardDemo {
+    name: String,
+    email: String,
+    accept_terms: bool,
+    signed_up: Option<String>,
+}
+
+impl super::Demo for WizardDemo {
+    fn name(&self) -> &'static str {
+        "🧙 Wizard"
+    }
+
+    fn show(&mut self, ctx: &egui::CtxRef, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                use super::View;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for WizardDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let Self {
+            name,
+            email,
+            accept_terms,
+            signed_up,
+        } = self;
+
+        let response = egui::Wizard::new("sign_up_wizard")
+            .page("Name")
+            .page("Email")
+            .page("Terms")
+            .finish_text("Sign up")
+            .show(ui, |ui, page| match page {
+                0 => {
+                    ui.label("What is your name?");
+                    ui.text_edit_singleline(name);
+                    !name.is_empty()
+                }
+                1 => {
+                    ui.label("What is your email address?");
+                    ui.text_edit_singleline(email);
+                    let valid = email.contains('@');
+                    if !email.is_empty() && !valid {
+                        ui.colored_label(
+                            egui::Color32::RED,
+                            "That doesn't look like an email address",
+                        );
+                    }
+                    valid
+                }
+                _ => {
+                    ui.checkbox(accept_terms, "I accept the terms and conditions");
+                    *accept_terms
+                }
+            });
+        if response.finished {
+            *signed_up = Some(format!("Signed up {} <{}>", name, email));
+            *accept_terms = false;
+        }
+        if let Some(signed_up) = signed_up {
+            ui.label(signed_up.as_str());
+        }
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::__egui_github_link_file!());
+        });
+    }
+}
@@ -6,7 +6,7 @@ struct Resource {
     response: Response,
 
     /// If set, the response was an image.
-    image: Option<Image>,
+    image: Option<image::DynamicImage>,
 
     /// If set, the response was text with some supported syntax highlighting (e.g. ".rs" or ".md").
     colored_text: Option<ColoredText>,
@@ -15,7 +15,7 @@ struct Resource {
 impl Resource {
     fn from_response(response: Response) -> Self {
         let image = if response.header_content_type.starts_with("image/") {
-            Image::decode(&response.bytes)
+            image::load_from_memory(&response.bytes).ok()
         } else {
             None
         };
@@ -41,7 +41,10 @@ pub struct HttpApp {
     result: Option<Result<Resource, String>>,
 
     #[cfg_attr(feature = "persistence", serde(skip))]
-    tex_mngr: TexMngr,
+    textures: epi::TextureCache<String>,
+
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    images: epi::HttpImages,
 }
 
 impl Default for HttpApp {
@@ -50,7 +53,8 @@ impl Default for HttpApp {
             url: "https://raw.githubusercontent.com/emilk/egui/master/README.md".to_owned(),
             in_progress: Default::default(),
             result: Default::default(),
-            tex_mngr: Default::default(),
+            textures: Default::default(),
+            images: Default::default(),
         }
     }
 }
@@ -89,6 +93,10 @@ impl epi::App for HttpApp {
                 });
             }
 
+            ui.collapsing("Image gallery", |ui| {
+                ui_gallery(ui, frame, &mut self.images);
+            });
+
             ui.separator();
 
             if self.in_progress.is_some() {
@@ -96,7 +104,7 @@ impl epi::App for HttpApp {
             } else if let Some(result) = &self.result {
                 match result {
                     Ok(resource) => {
-                        ui_resource(ui, frame, &mut self.tex_mngr, resource);
+                        ui_resource(ui, frame, &mut self.textures, resource);
                     }
                     Err(error) => {
                         // This should only happen if the fetch API isn't available or something similar.
@@ -145,10 +153,32 @@ fn ui_url(ui: &mut egui::Ui, frame: &mut epi::Frame<'_>, url: &mut String) -> Op
     }
 }
 
+fn ui_gallery(ui: &mut egui::Ui, frame: &mut epi::Frame<'_>, images: &mut epi::HttpImages) {
+    ui.label(
+        "Images are cached according to their HTTP headers, and failed downloads are retried.",
+    );
+    ui.horizontal_wrapped(|ui| {
+        let size = egui::vec2(96.0, 96.0);
+        for id in 10..16 {
+            let url = format!("https://picsum.photos/id/{}/96", id);
+            images.show(ui, frame, &url, Some(size));
+        }
+        images.show(
+            ui,
+            frame,
+            "https://picsum.photos/this-is-not-an-image",
+            Some(size),
+        );
+    });
+    if ui.button("Clear cache").clicked() {
+        images.clear(frame.tex_allocator());
+    }
+}
+
 fn ui_resource(
     ui: &mut egui::Ui,
     frame: &mut epi::Frame<'_>,
-    tex_mngr: &mut TexMngr,
+    textures: &mut epi::TextureCache<String>,
     resource: &Resource,
 ) {
     let Resource {
@@ -179,10 +209,11 @@ fn ui_resource(
 
     egui::ScrollArea::auto_sized().show(ui, |ui| {
         if let Some(image) = image {
-            if let Some(texture_id) = tex_mngr.texture(frame, &response.url, image) {
-                let size = egui::Vec2::new(image.size.0 as f32, image.size.1 as f32);
-                ui.image(texture_id, size);
-            }
+            let (texture_id, size) =
+                textures.get_or_load_image(ui.ctx(), frame.tex_allocator(), &response.url, || {
+                    image.clone()
+                });
+            ui.image(texture_id, size);
         } else if let Some(colored_text) = colored_text {
             colored_text.ui(ui);
         } else if let Some(text) = &response.text {
@@ -262,58 +293,3 @@ struct ColoredText();
 impl ColoredText {
     pub fn ui(&self, _ui: &mut egui::Ui) {}
 }
-
-// ----------------------------------------------------------------------------
-// Texture/image handling is very manual at the moment.
-
-/// Immediate mode texture manager that supports at most one texture at the time :)
-#[derive(Default)]
-struct TexMngr {
-    loaded_url: String,
-    texture_id: Option<egui::TextureId>,
-}
-
-impl TexMngr {
-    fn texture(
-        &mut self,
-        frame: &mut epi::Frame<'_>,
-        url: &str,
-        image: &Image,
-    ) -> Option<egui::TextureId> {
-        if self.loaded_url != url {
-            if let Some(texture_id) = self.texture_id.take() {
-                frame.tex_allocator().free(texture_id);
-            }
-
-            self.texture_id = Some(
-                frame
-                    .tex_allocator()
-                    .alloc_srgba_premultiplied(image.size, &image.pixels),
-            );
-            self.loaded_url = url.to_owned();
-        }
-        self.texture_id
-    }
-}
-
-struct Image {
-    size: (usize, usize),
-    pixels: Vec<egui::Color32>,
-}
-
-impl Image {
-    fn decode(bytes: &[u8]) -> Option<Image> {
-        use image::GenericImageView;
-        let image = image::load_from_memory(bytes).ok()?;
-        let image_buffer = image.to_rgba8();
-        let size = (image.width() as usize, image.height() as usize);
-        let pixels = image_buffer.into_vec();
-        assert_eq!(size.0 * size.1 * 4, pixels.len());
-        let pixels = pixels
-            .chunks(4)
-            .map(|p| egui::Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
-            .collect();
-
-        Some(Image { size, pixels })
-    }
-}
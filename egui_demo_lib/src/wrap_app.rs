@@ -88,11 +88,10 @@ impl epi::App for WrapApp {
 
         self.backend_panel.update(ctx, frame);
 
-        if self.backend_panel.open || ctx.memory().everything_is_visible() {
-            egui::SidePanel::left("backend_panel").show(ctx, |ui| {
-                self.backend_panel.ui(ui, frame);
-            });
-        }
+        let is_open = self.backend_panel.open || ctx.memory().everything_is_visible();
+        egui::SidePanel::left("backend_panel").show_animated(ctx, is_open, |ui| {
+            self.backend_panel.ui(ui, frame);
+        });
 
         for (anchor, app) in self.apps.iter_mut() {
             if anchor == self.selected_anchor || ctx.memory().everything_is_visible() {
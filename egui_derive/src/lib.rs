@@ -0,0 +1,265 @@
+//! Derive macros for [`egui`](https://github.com/emilk/egui).
+//!
+//! Don't depend on this crate directly, but enable the `derive` feature of `egui`.
+
+// Forbid warnings in release builds:
+#![cfg_attr(not(debug_assertions), deny(warnings))]
+#![forbid(unsafe_code)]
+#![warn(clippy::all, future_incompatible, nonstandard_style, rust_2018_idioms)]
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Data, DeriveInput, Expr, Fields, GenericParam, Ident, Token,
+};
+
+/// Implement `egui::Inspect` for a struct or an enum.
+///
+/// Structs are shown as a collapsing header with one row per field.
+/// All fields must implement `egui::Inspect` (or be skipped).
+///
+/// Enums must only have variants without fields, and are shown as a combo box.
+///
+/// Field attributes:
+/// * `#[inspect(skip)]`: don't show this field.
+/// * `#[inspect(label = "…")]`: show this instead of the field name.
+/// * `#[inspect(range = min..=max)]`: edit a number with a slider over this range.
+/// * `#[inspect(speed = …)]`: the drag speed when editing a number with a `DragValue`.
+///
+/// Enum variants can also have a `#[inspect(label = "…")]`.
+///
+/// ```
+/// use egui::Inspect;
+///
+/// #[derive(Default, egui::Inspect)]
+/// struct Settings {
+///     #[inspect(range = 0.0..=1.0)]
+///     volume: f32,
+///     #[inspect(label = "Player name")]
+///     name: String,
+///     quality: Quality,
+///     #[inspect(skip)]
+///     cache: Vec<u8>,
+/// }
+///
+/// #[derive(egui::Inspect)]
+/// enum Quality {
+///     Low,
+///     #[inspect(label = "Medium (recommended)")]
+///     Medium,
+///     High,
+/// }
+///
+/// impl Default for Quality {
+///     fn default() -> Self {
+///         Quality::Medium
+///     }
+/// }
+///
+/// # let ui = &mut egui::Ui::__test();
+/// let mut settings = Settings::default();
+/// settings.inspect(ui);
+/// ```
+#[proc_macro_derive(Inspect, attributes(inspect))]
+pub fn derive_inspect(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_inspect(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn expand_inspect(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    for param in &mut input.generics.params {
+        if let GenericParam::Type(type_param) = param {
+            type_param
+                .bounds
+                .push(syn::parse_quote!(::egui::inspect::Inspect));
+        }
+    }
+
+    let body = match &input.data {
+        Data::Struct(data) => struct_body(&input.ident, &data.fields)?,
+        Data::Enum(data) => enum_body(&input.ident, data.variants.iter())?,
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                input.span(),
+                "Inspect can not be derived for unions",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::egui::inspect::Inspect for #name #ty_generics #where_clause {
+            fn inspect_with(
+                &mut self,
+                ui: &mut ::egui::Ui,
+                _options: &::egui::inspect::InspectOptions,
+            ) -> ::egui::Response {
+                #body
+            }
+        }
+    })
+}
+
+fn struct_body(name: &Ident, fields: &Fields) -> syn::Result<TokenStream> {
+    let mut field_rows = vec![];
+    for (i, field) in fields.iter().enumerate() {
+        let attrs = InspectAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let (member, default_label) = match &field.ident {
+            Some(ident) => (quote!(#ident), field_label(ident)),
+            None => {
+                let index = syn::Index::from(i);
+                (quote!(#index), i.to_string())
+            }
+        };
+        let label = attrs
+            .label
+            .map_or_else(|| quote!(#default_label), |label| quote!(#label));
+
+        let mut options = quote!(::egui::inspect::InspectOptions::default());
+        if let Some(range) = attrs.range {
+            options = quote!(#options.range(#range));
+        }
+        if let Some(speed) = attrs.speed {
+            options = quote!(#options.speed(#speed));
+        }
+
+        field_rows.push(quote! {
+            ::egui::inspect::field_ui(ui, #label, &mut self.#member, &#options)
+        });
+    }
+
+    let name = name.to_string();
+    let add_fields = if field_rows.is_empty() {
+        quote!(|_ui| false)
+    } else {
+        quote!(|ui| false #(| #field_rows)*)
+    };
+    Ok(quote! {
+        ::egui::inspect::struct_ui(ui, #name, #add_fields)
+    })
+}
+
+fn enum_body<'a>(
+    name: &Ident,
+    variants: impl Iterator<Item = &'a syn::Variant>,
+) -> syn::Result<TokenStream> {
+    let mut idents = vec![];
+    let mut labels = vec![];
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.span(),
+                "Inspect can only be derived for enums whose variants have no fields",
+            ));
+        }
+        let attrs = InspectAttrs::parse(&variant.attrs)?;
+        if attrs.skip || attrs.range.is_some() || attrs.speed.is_some() {
+            return Err(syn::Error::new(
+                variant.span(),
+                "enum variants only support #[inspect(label = \"…\")]",
+            ));
+        }
+        let default_label = variant.ident.to_string();
+        labels.push(
+            attrs
+                .label
+                .map_or_else(|| quote!(#default_label), |label| quote!(#label)),
+        );
+        idents.push(&variant.ident);
+    }
+
+    if idents.is_empty() {
+        return Err(syn::Error::new(
+            name.span(),
+            "Inspect can not be derived for enums without variants",
+        ));
+    }
+
+    let indices = (0..idents.len()).map(Literal::usize_unsuffixed);
+    let indices2 = indices.clone();
+    Ok(quote! {
+        let mut selected = match self {
+            #(Self::#idents => #indices,)*
+        };
+        let response = ::egui::inspect::enum_ui(ui, &[#(#labels),*], &mut selected);
+        if response.changed() {
+            *self = match selected {
+                #(#indices2 => Self::#idents,)*
+                _ => unreachable!(),
+            };
+        }
+        response
+    })
+}
+
+/// `window_width` -> `"window width"`.
+fn field_label(ident: &Ident) -> String {
+    let name = ident.to_string();
+    name.trim_start_matches("r#").replace('_', " ")
+}
+
+// ----------------------------------------------------------------------------
+
+/// The contents of all `#[inspect(…)]` attributes on a field or a variant.
+#[derive(Default)]
+struct InspectAttrs {
+    skip: bool,
+    label: Option<Expr>,
+    range: Option<Expr>,
+    speed: Option<Expr>,
+}
+
+impl InspectAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("inspect")) {
+            let args =
+                attr.parse_args_with(Punctuated::<InspectArg, Token![,]>::parse_terminated)?;
+            for InspectArg { name, value } in args {
+                match (name.to_string().as_str(), value) {
+                    ("skip", None) => result.skip = true,
+                    ("label", Some(value)) => result.label = Some(value),
+                    ("range", Some(value)) => result.range = Some(value),
+                    ("speed", Some(value)) => result.speed = Some(value),
+                    _ => {
+                        return Err(syn::Error::new(
+                            name.span(),
+                            "expected one of: `skip`, `label = …`, `range = …`, `speed = …`",
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// `name` or `name = value`
+struct InspectArg {
+    name: Ident,
+    value: Option<Expr>,
+}
+
+impl Parse for InspectArg {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { name, value })
+    }
+}
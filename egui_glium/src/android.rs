@@ -0,0 +1,40 @@
+//! Android specifics, using [`ndk_glue`].
+//!
+//! There is no text input on Android: winit passes on the key events of the soft keyboard
+//! without their key codes or characters, and a `NativeActivity` gets no text from an IME,
+//! so we don't show the soft keyboard either.
+
+use glium::glutin;
+
+/// The GL context can't be created until Android has given us a window to draw in.
+///
+/// `ndk_glue` runs `main` on its own thread, so we can wait here
+/// while the activity starts up on the main thread.
+pub(crate) fn wait_for_native_window() {
+    while ndk_glue::native_window().is_none() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
+/// The parts of the window covered by the status bar, the navigation bar etc, in egui points.
+///
+/// Android tells us which part of the window is free to draw in (the "content rect"),
+/// and the rest is what egui should keep out of.
+pub(crate) fn safe_area_insets(
+    display: &glium::Display,
+    pixels_per_point: f32,
+) -> egui::SafeAreaInsets {
+    let content = ndk_glue::content_rect();
+    if content.right <= content.left || content.bottom <= content.top {
+        // Android hasn't told us yet.
+        return Default::default();
+    }
+    let glutin::dpi::PhysicalSize { width, height } = display.gl_window().window().inner_size();
+    let points = |pixels: u32| pixels as f32 / pixels_per_point;
+    egui::SafeAreaInsets {
+        left: points(content.left),
+        right: points(width.saturating_sub(content.right)),
+        top: points(content.top),
+        bottom: points(height.saturating_sub(content.bottom)),
+    }
+}
@@ -40,6 +40,16 @@ impl epi::TextureAllocator for Painter {
         id
     }
 
+    fn alloc_linear_premultiplied(
+        &mut self,
+        size: (usize, usize),
+        rgba_pixels: &[egui::Rgba],
+    ) -> egui::TextureId {
+        let id = self.alloc_user_texture();
+        self.set_user_texture_linear(id, size, rgba_pixels);
+        id
+    }
+
     fn free(&mut self, id: egui::TextureId) {
         self.free_user_texture(id)
     }
@@ -79,7 +89,7 @@ fn create_display(
     native_options: &epi::NativeOptions,
     window_settings: Option<WindowSettings>,
     window_icon: Option<glutin::window::Icon>,
-    event_loop: &glutin::event_loop::EventLoop<RequestRepaintEvent>,
+    event_loop: &glutin::event_loop::EventLoopWindowTarget<RequestRepaintEvent>,
 ) -> glium::Display {
     let mut window_builder = glutin::window::WindowBuilder::new()
         .with_always_on_top(native_options.always_on_top)
@@ -109,7 +119,25 @@ fn create_display(
         .with_stencil_buffer(0)
         .with_vsync(true);
 
-    let display = glium::Display::new(window_builder, context_builder, event_loop).unwrap();
+    let hdr_display = if native_options.hdr_output {
+        // A half float framebuffer. It is linear, so it doesn't need to be sRGB capable:
+        let mut hdr_context_builder = context_builder.clone().with_srgb(false);
+        hdr_context_builder.pf_reqs.float_color_buffer = true;
+        hdr_context_builder.pf_reqs.color_bits = Some(48);
+        hdr_context_builder.pf_reqs.alpha_bits = Some(16);
+        match glium::Display::new(window_builder.clone(), hdr_context_builder, event_loop) {
+            Ok(display) => Some(display),
+            Err(err) => {
+                eprintln!("No HDR framebuffer, falling back to sRGB: {}", err);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let display = hdr_display.unwrap_or_else(|| {
+        glium::Display::new(window_builder, context_builder, event_loop).unwrap()
+    });
 
     if let Some(window_settings) = &window_settings {
         window_settings.restore_positions(&display);
@@ -174,7 +202,15 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
     let window_settings = deserialize_window_settings(&storage);
     let event_loop = glutin::event_loop::EventLoop::with_user_event();
     let icon = nativve_options.icon_data.clone().and_then(load_icon);
-    let display = create_display(&*app, &nativve_options, window_settings, icon, &event_loop);
+    #[cfg(target_os = "android")]
+    crate::android::wait_for_native_window();
+    let mut display = create_display(
+        &*app,
+        &nativve_options,
+        window_settings,
+        icon.clone(),
+        &event_loop,
+    );
 
     let repaint_signal = std::sync::Arc::new(GliumRepaintSignal(std::sync::Mutex::new(
         event_loop.create_proxy(),
@@ -200,8 +236,18 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
 
     let mut previous_frame_time = None;
 
+    let mut frame_pacer = nativve_options
+        .late_input_sampling
+        .then(crate::frame_pacer::FramePacer::default);
+
     let mut is_focused = true;
 
+    // Mobile apps are suspended when they go to the background.
+    let mut is_suspended = false;
+
+    #[cfg(target_os = "ios")]
+    let mut soft_keyboard = crate::ios::SoftKeyboard::new(&display);
+
     #[cfg(feature = "persistence")]
     let mut last_auto_save = Instant::now();
 
@@ -233,8 +279,13 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
         // eprintln!("Warmed up in {} ms", warm_up_start.elapsed().as_millis())
     }
 
-    event_loop.run(move |event, _, control_flow| {
+    event_loop.run(move |event, event_loop_target, control_flow| {
         let mut redraw = || {
+            if is_suspended {
+                // There is nothing to draw on.
+                return;
+            }
+
             if !is_focused {
                 // On Mac, a minimized Window uses up all CPU: https://github.com/emilk/egui/issues/325
                 // We can't know if we are minimized: https://github.com/rust-windowing/winit/issues/208
@@ -244,8 +295,22 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
                 std::thread::sleep(std::time::Duration::from_millis(10));
             }
 
+            if let Some(next_frame_start) = frame_pacer
+                .as_ref()
+                .and_then(crate::frame_pacer::FramePacer::next_frame_start)
+            {
+                if Instant::now() < next_frame_start {
+                    // Keep collecting input until it is time to start the frame:
+                    *control_flow = glutin::event_loop::ControlFlow::WaitUntil(next_frame_start);
+                    return;
+                }
+            }
+
             let frame_start = std::time::Instant::now();
 
+            egui.input_state.raw.previous_frame_time = previous_frame_time;
+            #[cfg(target_os = "ios")]
+            egui.push_events(soft_keyboard.take_events());
             egui.begin_frame(&display);
             let (ctx, painter) = egui.ctx_and_painter_mut();
             let mut app_output = epi::backend::AppOutput::default();
@@ -261,6 +326,9 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
             app.update(ctx, &mut frame);
             let (needs_repaint, shapes) = egui.end_frame(&display);
 
+            #[cfg(target_os = "ios")]
+            soft_keyboard.set_visible(egui.ctx().wants_keyboard_input());
+
             let frame_time = (Instant::now() - frame_start).as_secs_f64() as f32;
             previous_frame_time = Some(frame_time);
 
@@ -275,7 +343,21 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
                     clear_color[3],
                 );
                 egui.paint(&display, &mut target, shapes);
-                target.finish().unwrap();
+                egui.read_pixels(&display, &target);
+                if let Some(frame_pacer) = &mut frame_pacer {
+                    frame_pacer.on_frame_submitted(frame_start, Instant::now());
+                    target.finish().unwrap();
+                    if frame_pacer.should_wait_for_present() {
+                        // Now and then, wait for the frame to be presented to learn when vsync is:
+                        display.finish();
+                        frame_pacer.on_presented(Instant::now());
+                    } else {
+                        frame_pacer.on_swapped(Instant::now());
+                    }
+                } else {
+                    // We don't wait for the GPU, so the next frame runs while this one is presented.
+                    target.finish().unwrap();
+                }
             }
 
             {
@@ -293,6 +375,10 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
 
                 *control_flow = if quit {
                     glutin::event_loop::ControlFlow::Exit
+                } else if let Some(repaint_after) = egui.repaint_after() {
+                    glutin::event_loop::ControlFlow::WaitUntil(
+                        Instant::now() + std::time::Duration::from_secs_f32(repaint_after),
+                    )
                 } else if needs_repaint {
                     display.gl_window().window().request_redraw();
                     glutin::event_loop::ControlFlow::Poll
@@ -353,6 +439,33 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
                 }
             }
 
+            glutin::event::Event::NewEvents(glutin::event::StartCause::ResumeTimeReached {
+                ..
+            }) => {
+                // The frame pacer says it is time for the next frame.
+                display.gl_window().window().request_redraw();
+            }
+
+            glutin::event::Event::Suspended => {
+                is_suspended = true;
+            }
+            glutin::event::Event::Resumed if is_suspended => {
+                is_suspended = false;
+                if cfg!(target_os = "android") {
+                    // Android destroys the window surface when suspending,
+                    // so we need a new GL context:
+                    display = create_display(
+                        &*app,
+                        &nativve_options,
+                        None,
+                        icon.clone(),
+                        event_loop_target,
+                    );
+                    egui.on_display_recreated(&display);
+                }
+                display.gl_window().window().request_redraw();
+            }
+
             glutin::event::Event::UserEvent(RequestRepaintEvent) => {
                 display.gl_window().window().request_redraw();
             }
@@ -0,0 +1,146 @@
+use std::time::{Duration, Instant};
+
+/// Frame time we leave unused, to absorb jitter in how long a frame takes.
+const SAFETY_MARGIN: Duration = Duration::from_millis(2);
+
+/// Gaps between presented frames longer than this are idle time, not vsync.
+const MAX_VSYNC_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Wait for the GPU to present one frame in this many, to keep track of when vsync is.
+/// Waiting stalls the CPU until the GPU is done, so we don't want to do it every frame.
+const MEASURE_PRESENT_EVERY: u32 = 30;
+
+/// Delays the start of each frame until just before it needs to be presented,
+/// so that input is sampled as late as possible.
+///
+/// Without this, a frame starts right after the previous one is presented (at vsync),
+/// so input arriving just after that has to wait almost a whole frame before it is even looked at.
+/// See [`epi::NativeOptions::late_input_sampling`].
+#[derive(Default)]
+pub(crate) struct FramePacer {
+    /// When the latest frame was presented, measured or predicted.
+    last_present: Option<Instant>,
+
+    /// Estimated time between two vsyncs.
+    vsync_interval: Option<Duration>,
+
+    /// How long it takes from sampling input to submitting the frame to the GPU.
+    /// Quick to grow, slow to shrink.
+    frame_duration: Duration,
+
+    /// Frames swapped since we last waited for one to be presented.
+    frames_since_measured: u32,
+}
+
+impl FramePacer {
+    /// When to start the next frame, if it should not start right away.
+    pub fn next_frame_start(&self) -> Option<Instant> {
+        let last_present = self.last_present?;
+        let vsync_interval = self.vsync_interval?;
+        let budget = self.frame_duration + SAFETY_MARGIN;
+        if budget < vsync_interval {
+            Some(last_present + vsync_interval - budget)
+        } else {
+            None
+        }
+    }
+
+    /// Call when a frame has been submitted, just before swapping buffers.
+    pub fn on_frame_submitted(&mut self, frame_start: Instant, now: Instant) {
+        let frame_duration = now.saturating_duration_since(frame_start);
+        self.frame_duration = frame_duration.max(self.frame_duration.mul_f32(0.95));
+    }
+
+    /// Should we wait for this frame to be presented (see [`Self::on_presented`]),
+    /// or just predict when it will be (see [`Self::on_swapped`])?
+    pub fn should_wait_for_present(&self) -> bool {
+        self.vsync_interval.is_none() || self.frames_since_measured + 1 >= MEASURE_PRESENT_EVERY
+    }
+
+    /// Call when a frame has been presented, i.e. after swapping buffers and waiting for the GPU.
+    pub fn on_presented(&mut self, now: Instant) {
+        if let Some(last_present) = self.last_present {
+            let interval = now.saturating_duration_since(last_present);
+            if interval < MAX_VSYNC_INTERVAL {
+                // A missed vsync gives a long interval, so trust the shortest recent one.
+                // Allow slow growth for when the window moves to a screen with a lower refresh rate.
+                self.vsync_interval = Some(match self.vsync_interval {
+                    Some(vsync_interval) => interval.min(vsync_interval.mul_f32(1.01)),
+                    None => interval,
+                });
+            }
+        }
+        self.last_present = Some(now);
+        self.frames_since_measured = 0;
+    }
+
+    /// Call after swapping buffers without waiting for the GPU.
+    ///
+    /// The frame will be presented at the first vsync after `now`.
+    pub fn on_swapped(&mut self, now: Instant) {
+        self.frames_since_measured += 1;
+        if let (Some(last_present), Some(vsync_interval)) = (self.last_present, self.vsync_interval)
+        {
+            let since_last_present = now.saturating_duration_since(last_present);
+            let vsyncs = (since_last_present.as_secs_f64() / vsync_interval.as_secs_f64()) as u32;
+            self.last_present = Some(last_present + vsync_interval * (vsyncs + 1));
+        }
+    }
+}
+
+#[test]
+fn test_frame_pacer() {
+    let ms = Duration::from_millis;
+    let t0 = Instant::now();
+    let mut pacer = FramePacer::default();
+
+    // Wait for the GPU until we know the vsync interval:
+    assert!(pacer.should_wait_for_present());
+    pacer.on_presented(t0);
+    assert_eq!(pacer.next_frame_start(), None);
+    assert!(pacer.should_wait_for_present());
+    pacer.on_frame_submitted(t0 + ms(10), t0 + ms(14));
+    pacer.on_presented(t0 + ms(16));
+    assert_eq!(pacer.vsync_interval, Some(ms(16)));
+
+    // Start late enough to just make the next vsync:
+    assert_eq!(pacer.next_frame_start(), Some(t0 + ms(32 - 4 - 2)));
+
+    // Then only now and then:
+    assert!(!pacer.should_wait_for_present());
+    pacer.on_frame_submitted(t0 + ms(26), t0 + ms(30));
+    pacer.on_swapped(t0 + ms(30));
+    assert_eq!(pacer.last_present, Some(t0 + ms(32)));
+    for _ in 2..MEASURE_PRESENT_EVERY {
+        assert!(!pacer.should_wait_for_present());
+        pacer.on_swapped(pacer.next_frame_start().unwrap() + ms(4));
+    }
+    assert!(pacer.should_wait_for_present());
+
+    // A frame swapped after a missed vsync is presented at the vsync after that:
+    let last_present = pacer.last_present.unwrap();
+    pacer.on_swapped(last_present + ms(20));
+    assert_eq!(pacer.last_present, Some(last_present + ms(32)));
+}
+
+#[test]
+fn test_frame_pacer_missed_vsync() {
+    let ms = Duration::from_millis;
+    let t0 = Instant::now();
+    let mut pacer = FramePacer::default();
+    pacer.on_presented(t0);
+    pacer.on_presented(t0 + ms(16));
+
+    // A missed vsync makes the estimate only a little longer:
+    pacer.on_presented(t0 + ms(48));
+    let vsync_interval = pacer.vsync_interval.unwrap();
+    assert!(ms(16) < vsync_interval && vsync_interval < ms(17));
+
+    // Idle time doesn't change it:
+    pacer.on_presented(t0 + ms(1000));
+    assert_eq!(pacer.vsync_interval, Some(vsync_interval));
+
+    // A slow frame makes us start earlier, or right away:
+    pacer.on_frame_submitted(t0 + ms(1000), t0 + ms(1020));
+    assert_eq!(pacer.next_frame_start(), None);
+}
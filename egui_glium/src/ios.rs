@@ -0,0 +1,946 @@
+//! iOS specifics, talking to UIKit through [`objc`].
+//!
+//! winit doesn't show the soft keyboard on iOS, nor tell us where the notch is,
+//! so we ask UIKit ourselves.
+//!
+//! Every Objective-C call is `unsafe`, since the compiler can't check that the object
+//! understands the message, nor the types of its arguments. They are kept in the small helpers
+//! at the end of this file, each saying why the types are right.
+
+use {
+    glium::glutin::platform::ios::WindowExtIOS as _,
+    objc::{
+        class,
+        declare::{ClassDecl, MethodImplementation},
+        msg_send,
+        runtime::{Class, Object, Protocol, Sel, BOOL, NO, YES},
+        sel, sel_impl, Encode, Encoding,
+    },
+    std::{cell::RefCell, ffi::CStr, os::raw::c_char},
+};
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct UIEdgeInsets {
+    top: f64,
+    left: f64,
+    bottom: f64,
+    right: f64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct CGPoint {
+    x: f64,
+    y: f64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct CGSize {
+    width: f64,
+    height: f64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct CGRect {
+    origin: CGPoint,
+    size: CGSize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct NSRange {
+    location: usize,
+    length: usize,
+}
+
+/// The parts of the window covered by the notch, the home indicator etc, in egui points.
+pub(crate) fn safe_area_insets(
+    display: &glium::Display,
+    pixels_per_point: f32,
+) -> egui::SafeAreaInsets {
+    let gl_window = display.gl_window();
+    let window = gl_window.window();
+    let insets = view_safe_area_insets(window.ui_view() as *mut Object);
+    // UIKit points are physical pixels divided by the scale factor:
+    let scale = window.scale_factor() as f32 / pixels_per_point;
+    egui::SafeAreaInsets {
+        left: insets.left as f32 * scale,
+        right: insets.right as f32 * scale,
+        top: insets.top as f32 * scale,
+        bottom: insets.bottom as f32 * scale,
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What the soft keyboard has done, shared with the UIKit callbacks below.
+///
+/// UIKit calls them on the main thread, which is also where the event loop runs.
+#[derive(Default)]
+struct KeyboardState {
+    /// What was typed since the last [`SoftKeyboard::take_events`].
+    events: Vec<egui::Event>,
+
+    /// The text being composed (e.g. pinyin before it is turned into Chinese characters), in UTF-16.
+    ///
+    /// This is all of the text UIKit gets to see: the text of the `TextEdit` stays in egui.
+    marked_text: Vec<u16>,
+
+    /// The selection within `marked_text`.
+    selection: (usize, usize),
+
+    /// Where to show the candidates of the input method, in UIKit points.
+    cursor_rect: CGRect,
+}
+
+thread_local! {
+    static KEYBOARD_STATE: RefCell<KeyboardState> = RefCell::new(KeyboardState::default());
+}
+
+fn with_state<R>(f: impl FnOnce(&mut KeyboardState) -> R) -> R {
+    KEYBOARD_STATE.with(|state| f(&mut *state.borrow_mut()))
+}
+
+/// Shows the soft keyboard while egui wants keyboard input, e.g. when a `TextEdit` has focus.
+///
+/// iOS only shows the keyboard for a view that takes text, which the winit view doesn't,
+/// so we add an invisible one of our own (implementing `UITextInput`) and make it the first responder.
+///
+/// Text being composed by an input method (e.g. for Chinese or Japanese) is sent to egui
+/// as [`egui::Event::CompositionUpdate`], so it is shown in the `TextEdit` until it is committed.
+pub(crate) struct SoftKeyboard {
+    view: *mut Object,
+    visible: bool,
+}
+
+impl SoftKeyboard {
+    pub fn new(display: &glium::Display) -> Self {
+        let winit_view = display.gl_window().window().ui_view() as *mut Object;
+        let view = new_object(text_input_view_class());
+        add_subview(winit_view, view);
+        Self {
+            view,
+            visible: false,
+        }
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        if visible != self.visible {
+            self.visible = visible;
+            set_first_responder(self.view, visible);
+        }
+    }
+
+    /// What was typed since last time.
+    pub fn take_events(&self) -> Vec<egui::Event> {
+        with_state(|state| std::mem::take(&mut state.events))
+    }
+}
+
+impl Drop for SoftKeyboard {
+    fn drop(&mut self) {
+        remove_from_superview_and_release(self.view);
+    }
+}
+
+/// Show the candidates of the input method next to this rectangle (in egui points),
+/// see [`egui::Output::text_cursor_rect`].
+///
+/// Like `set_ime_position` on other platforms, this assumes egui points are UIKit points.
+pub(crate) fn set_text_cursor_rect(rect: Option<egui::Rect>) {
+    if let Some(rect) = rect {
+        with_state(|state| {
+            state.cursor_rect = CGRect {
+                origin: CGPoint {
+                    x: rect.min.x as f64,
+                    y: rect.min.y as f64,
+                },
+                size: CGSize {
+                    width: rect.width() as f64,
+                    height: rect.height() as f64,
+                },
+            };
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+// The `UITextInput` view.
+//
+// Its "document" is only the text being composed, so positions and ranges are
+// UTF-16 offsets into `KeyboardState::marked_text`.
+
+/// A `UIView` subclass that takes text from the soft keyboard (`UITextInput`).
+fn text_input_view_class() -> &'static Class {
+    const NAME: &str = "EguiTextInputView";
+    static DECLARE: std::sync::Once = std::sync::Once::new();
+    DECLARE.call_once(|| {
+        let mut decl = ClassDecl::new(NAME, class!(UIView)).expect("Failed to declare a UIView");
+        if let Some(protocol) = Protocol::get("UITextInput") {
+            decl.add_protocol(protocol);
+        }
+        let decl = &mut decl;
+
+        // UIResponder:
+        add_method(
+            decl,
+            sel!(canBecomeFirstResponder),
+            yes as extern "C" fn(&Object, Sel) -> BOOL,
+        );
+
+        // UIKeyInput:
+        add_method(
+            decl,
+            sel!(hasText),
+            yes as extern "C" fn(&Object, Sel) -> BOOL,
+        );
+        add_method(
+            decl,
+            sel!(insertText:),
+            insert_text as extern "C" fn(&Object, Sel, *mut Object),
+        );
+        add_method(
+            decl,
+            sel!(deleteBackward),
+            delete_backward as extern "C" fn(&Object, Sel),
+        );
+
+        // UITextInputTraits: autocorrection would replace text that is only in egui.
+        add_method(
+            decl,
+            sel!(autocorrectionType),
+            autocorrection_type as extern "C" fn(&Object, Sel) -> isize,
+        );
+
+        // UITextInput, the marked (composed) text:
+        add_method(
+            decl,
+            sel!(setMarkedText:selectedRange:),
+            set_marked_text as extern "C" fn(&Object, Sel, *mut Object, NSRange),
+        );
+        add_method(
+            decl,
+            sel!(unmarkText),
+            unmark_text as extern "C" fn(&Object, Sel),
+        );
+        add_method(
+            decl,
+            sel!(markedTextRange),
+            marked_text_range as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(markedTextStyle),
+            nil as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(setMarkedTextStyle:),
+            ignore as extern "C" fn(&Object, Sel, *mut Object),
+        );
+
+        // UITextInput, the document:
+        add_method(
+            decl,
+            sel!(textInRange:),
+            text_in_range as extern "C" fn(&Object, Sel, *mut Object) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(replaceRange:withText:),
+            replace_range as extern "C" fn(&Object, Sel, *mut Object, *mut Object),
+        );
+        add_method(
+            decl,
+            sel!(selectedTextRange),
+            selected_text_range as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(setSelectedTextRange:),
+            set_selected_text_range as extern "C" fn(&Object, Sel, *mut Object),
+        );
+        add_method(
+            decl,
+            sel!(beginningOfDocument),
+            beginning_of_document as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(endOfDocument),
+            end_of_document as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+
+        // UITextInput, positions and ranges:
+        add_method(
+            decl,
+            sel!(textRangeFromPosition:toPosition:),
+            text_range_from_positions
+                as extern "C" fn(&Object, Sel, *mut Object, *mut Object) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(positionFromPosition:offset:),
+            position_from_position
+                as extern "C" fn(&Object, Sel, *mut Object, isize) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(positionFromPosition:inDirection:offset:),
+            position_in_direction
+                as extern "C" fn(&Object, Sel, *mut Object, isize, isize) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(comparePosition:toPosition:),
+            compare_positions as extern "C" fn(&Object, Sel, *mut Object, *mut Object) -> isize,
+        );
+        add_method(
+            decl,
+            sel!(offsetFromPosition:toPosition:),
+            offset_between_positions
+                as extern "C" fn(&Object, Sel, *mut Object, *mut Object) -> isize,
+        );
+        add_method(
+            decl,
+            sel!(positionWithinRange:farthestInDirection:),
+            farthest_position_in_range
+                as extern "C" fn(&Object, Sel, *mut Object, isize) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(characterRangeByExtendingPosition:inDirection:),
+            extend_position as extern "C" fn(&Object, Sel, *mut Object, isize) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(tokenizer),
+            tokenizer as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(inputDelegate),
+            nil as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(setInputDelegate:),
+            ignore as extern "C" fn(&Object, Sel, *mut Object),
+        );
+
+        // UITextInput, writing direction and geometry:
+        add_method(
+            decl,
+            sel!(baseWritingDirectionForPosition:inDirection:),
+            natural_writing_direction as extern "C" fn(&Object, Sel, *mut Object, isize) -> isize,
+        );
+        add_method(
+            decl,
+            sel!(setBaseWritingDirection:forRange:),
+            ignore_writing_direction as extern "C" fn(&Object, Sel, isize, *mut Object),
+        );
+        add_method(
+            decl,
+            sel!(firstRectForRange:),
+            cursor_rect as extern "C" fn(&Object, Sel, *mut Object) -> CGRect,
+        );
+        add_method(
+            decl,
+            sel!(caretRectForPosition:),
+            cursor_rect as extern "C" fn(&Object, Sel, *mut Object) -> CGRect,
+        );
+        add_method(
+            decl,
+            sel!(selectionRectsForRange:),
+            selection_rects as extern "C" fn(&Object, Sel, *mut Object) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(closestPositionToPoint:),
+            closest_position as extern "C" fn(&Object, Sel, CGPoint) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(closestPositionToPoint:withinRange:),
+            closest_position_within_range
+                as extern "C" fn(&Object, Sel, CGPoint, *mut Object) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(characterRangeAtPoint:),
+            character_range_at_point as extern "C" fn(&Object, Sel, CGPoint) -> *mut Object,
+        );
+
+        decl.register();
+    });
+    Class::get(NAME).unwrap()
+}
+
+/// A `UITextPosition`, which is an offset into the marked text.
+fn text_position_class() -> &'static Class {
+    const NAME: &str = "EguiTextPosition";
+    static DECLARE: std::sync::Once = std::sync::Once::new();
+    DECLARE.call_once(|| {
+        let mut decl = ClassDecl::new(NAME, class!(UITextPosition))
+            .expect("Failed to declare a UITextPosition");
+        decl.add_ivar::<usize>("index");
+        decl.register();
+    });
+    Class::get(NAME).unwrap()
+}
+
+/// A `UITextRange` between two offsets into the marked text.
+fn text_range_class() -> &'static Class {
+    const NAME: &str = "EguiTextRange";
+    static DECLARE: std::sync::Once = std::sync::Once::new();
+    DECLARE.call_once(|| {
+        let mut decl =
+            ClassDecl::new(NAME, class!(UITextRange)).expect("Failed to declare a UITextRange");
+        decl.add_ivar::<usize>("start");
+        decl.add_ivar::<usize>("end");
+        let decl = &mut decl;
+        add_method(
+            decl,
+            sel!(start),
+            range_start as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(end),
+            range_end as extern "C" fn(&Object, Sel) -> *mut Object,
+        );
+        add_method(
+            decl,
+            sel!(isEmpty),
+            range_is_empty as extern "C" fn(&Object, Sel) -> BOOL,
+        );
+        decl.register();
+    });
+    Class::get(NAME).unwrap()
+}
+
+extern "C" fn yes(_this: &Object, _sel: Sel) -> BOOL {
+    YES
+}
+
+extern "C" fn nil(_this: &Object, _sel: Sel) -> *mut Object {
+    std::ptr::null_mut()
+}
+
+extern "C" fn ignore(_this: &Object, _sel: Sel, _object: *mut Object) {}
+
+extern "C" fn autocorrection_type(_this: &Object, _sel: Sel) -> isize {
+    1 // UITextAutocorrectionTypeNo
+}
+
+/// Text is committed, either typed directly or chosen from the candidates of an input method.
+extern "C" fn insert_text(this: &Object, _sel: Sel, text: *mut Object) {
+    let text = string_from_ns(text);
+    with_state(|state| {
+        if !state.marked_text.is_empty() {
+            state.marked_text.clear();
+            state.selection = (0, 0);
+            state.events.push(egui::Event::CompositionEnd(text));
+        } else if text == "\n" {
+            // The return key:
+            state.events.extend(key_press(egui::Key::Enter));
+        } else {
+            state.events.push(egui::Event::Text(text));
+        }
+    });
+    wake_up(this);
+}
+
+extern "C" fn delete_backward(this: &Object, _sel: Sel) {
+    with_state(|state| {
+        if state.marked_text.pop().is_some() {
+            let marked_text = String::from_utf16_lossy(&state.marked_text);
+            state.selection = (state.marked_text.len(), state.marked_text.len());
+            state
+                .events
+                .push(egui::Event::CompositionUpdate(marked_text));
+        } else {
+            state.events.extend(key_press(egui::Key::Backspace));
+        }
+    });
+    wake_up(this);
+}
+
+extern "C" fn set_marked_text(
+    this: &Object,
+    _sel: Sel,
+    text: *mut Object,
+    selected_range: NSRange,
+) {
+    let text = string_from_ns(text);
+    with_state(|state| {
+        if state.marked_text.is_empty() {
+            state.events.push(egui::Event::CompositionStart);
+        }
+        state.marked_text = text.encode_utf16().collect();
+        let len = state.marked_text.len();
+        let start = selected_range.location.min(len);
+        state.selection = (start, (start + selected_range.length).min(len));
+        state
+            .events
+            .push(egui::Event::CompositionUpdate(text.clone()));
+        if text.is_empty() {
+            // The composition was cancelled:
+            state.events.push(egui::Event::CompositionEnd(text));
+        }
+    });
+    wake_up(this);
+}
+
+extern "C" fn unmark_text(this: &Object, _sel: Sel) {
+    with_state(|state| {
+        if !state.marked_text.is_empty() {
+            let marked_text = String::from_utf16_lossy(&state.marked_text);
+            state.marked_text.clear();
+            state.selection = (0, 0);
+            state.events.push(egui::Event::CompositionEnd(marked_text));
+        }
+    });
+    wake_up(this);
+}
+
+extern "C" fn marked_text_range(_this: &Object, _sel: Sel) -> *mut Object {
+    let len = with_state(|state| state.marked_text.len());
+    if len == 0 {
+        std::ptr::null_mut()
+    } else {
+        new_range(0, len)
+    }
+}
+
+fn document_len() -> usize {
+    with_state(|state| state.marked_text.len())
+}
+
+extern "C" fn text_in_range(_this: &Object, _sel: Sel, range: *mut Object) -> *mut Object {
+    let (start, end) = range_offsets(range);
+    let text = with_state(|state| {
+        let end = end.min(state.marked_text.len());
+        String::from_utf16_lossy(&state.marked_text[start.min(end)..end])
+    });
+    new_ns_string(&text)
+}
+
+extern "C" fn replace_range(this: &Object, _sel: Sel, range: *mut Object, text: *mut Object) {
+    let (start, end) = range_offsets(range);
+    let text = string_from_ns(text);
+    with_state(|state| {
+        if state.marked_text.is_empty() {
+            // There is nothing to replace in our document, which is only the marked text:
+            state.events.push(egui::Event::Text(text));
+        } else {
+            let end = end.min(state.marked_text.len());
+            let start = start.min(end);
+            let replacement: Vec<u16> = text.encode_utf16().collect();
+            let cursor = start + replacement.len();
+            state.marked_text.splice(start..end, replacement);
+            state.selection = (cursor, cursor);
+            let marked_text = String::from_utf16_lossy(&state.marked_text);
+            state
+                .events
+                .push(egui::Event::CompositionUpdate(marked_text));
+        }
+    });
+    wake_up(this);
+}
+
+extern "C" fn selected_text_range(_this: &Object, _sel: Sel) -> *mut Object {
+    let (start, end) = with_state(|state| state.selection);
+    new_range(start, end)
+}
+
+extern "C" fn set_selected_text_range(_this: &Object, _sel: Sel, range: *mut Object) {
+    let (start, end) = range_offsets(range);
+    with_state(|state| {
+        let len = state.marked_text.len();
+        state.selection = (start.min(len), end.min(len));
+    });
+}
+
+extern "C" fn beginning_of_document(_this: &Object, _sel: Sel) -> *mut Object {
+    new_position(0)
+}
+
+extern "C" fn end_of_document(_this: &Object, _sel: Sel) -> *mut Object {
+    new_position(document_len())
+}
+
+extern "C" fn text_range_from_positions(
+    _this: &Object,
+    _sel: Sel,
+    from: *mut Object,
+    to: *mut Object,
+) -> *mut Object {
+    let (from, to) = (position_offset(from), position_offset(to));
+    new_range(from.min(to), from.max(to))
+}
+
+/// `nil` if the offset is outside of the document, as UIKit expects.
+fn position_at(offset: isize) -> *mut Object {
+    if 0 <= offset && offset as usize <= document_len() {
+        new_position(offset as usize)
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+extern "C" fn position_from_position(
+    _this: &Object,
+    _sel: Sel,
+    position: *mut Object,
+    offset: isize,
+) -> *mut Object {
+    position_at(position_offset(position) as isize + offset)
+}
+
+/// Is this `UITextLayoutDirection` (or `UITextStorageDirection`) towards the start of the text?
+fn is_backward(direction: isize) -> bool {
+    // UITextStorageDirectionBackward, UITextLayoutDirectionLeft and UITextLayoutDirectionUp:
+    matches!(direction, 1 | 3 | 4)
+}
+
+extern "C" fn position_in_direction(
+    _this: &Object,
+    _sel: Sel,
+    position: *mut Object,
+    direction: isize,
+    offset: isize,
+) -> *mut Object {
+    let offset = if is_backward(direction) {
+        -offset
+    } else {
+        offset
+    };
+    position_at(position_offset(position) as isize + offset)
+}
+
+extern "C" fn compare_positions(
+    _this: &Object,
+    _sel: Sel,
+    position: *mut Object,
+    other: *mut Object,
+) -> isize {
+    // NSOrderedAscending, NSOrderedSame or NSOrderedDescending:
+    match position_offset(position).cmp(&position_offset(other)) {
+        std::cmp::Ordering::Less => -1,
+        std::cmp::Ordering::Equal => 0,
+        std::cmp::Ordering::Greater => 1,
+    }
+}
+
+extern "C" fn offset_between_positions(
+    _this: &Object,
+    _sel: Sel,
+    from: *mut Object,
+    to: *mut Object,
+) -> isize {
+    position_offset(to) as isize - position_offset(from) as isize
+}
+
+extern "C" fn farthest_position_in_range(
+    _this: &Object,
+    _sel: Sel,
+    range: *mut Object,
+    direction: isize,
+) -> *mut Object {
+    let (start, end) = range_offsets(range);
+    new_position(if is_backward(direction) { start } else { end })
+}
+
+extern "C" fn extend_position(
+    _this: &Object,
+    _sel: Sel,
+    position: *mut Object,
+    direction: isize,
+) -> *mut Object {
+    let offset = position_offset(position);
+    if is_backward(direction) {
+        new_range(0, offset)
+    } else {
+        new_range(offset, document_len().max(offset))
+    }
+}
+
+extern "C" fn tokenizer(this: &Object, _sel: Sel) -> *mut Object {
+    new_string_tokenizer(this)
+}
+
+extern "C" fn natural_writing_direction(
+    _this: &Object,
+    _sel: Sel,
+    _position: *mut Object,
+    _direction: isize,
+) -> isize {
+    -1 // NSWritingDirectionNatural
+}
+
+extern "C" fn ignore_writing_direction(
+    _this: &Object,
+    _sel: Sel,
+    _direction: isize,
+    _range: *mut Object,
+) {
+}
+
+extern "C" fn cursor_rect(_this: &Object, _sel: Sel, _range_or_position: *mut Object) -> CGRect {
+    with_state(|state| state.cursor_rect)
+}
+
+extern "C" fn selection_rects(_this: &Object, _sel: Sel, _range: *mut Object) -> *mut Object {
+    new_empty_ns_array()
+}
+
+extern "C" fn closest_position(_this: &Object, _sel: Sel, _point: CGPoint) -> *mut Object {
+    new_position(document_len())
+}
+
+extern "C" fn closest_position_within_range(
+    _this: &Object,
+    _sel: Sel,
+    _point: CGPoint,
+    range: *mut Object,
+) -> *mut Object {
+    new_position(range_offsets(range).1)
+}
+
+extern "C" fn character_range_at_point(_this: &Object, _sel: Sel, _point: CGPoint) -> *mut Object {
+    new_range(0, document_len())
+}
+
+extern "C" fn range_start(this: &Object, _sel: Sel) -> *mut Object {
+    new_position(ivar(this, "start"))
+}
+
+extern "C" fn range_end(this: &Object, _sel: Sel) -> *mut Object {
+    new_position(ivar(this, "end"))
+}
+
+extern "C" fn range_is_empty(this: &Object, _sel: Sel) -> BOOL {
+    if ivar(this, "start") == ivar(this, "end") {
+        YES
+    } else {
+        NO
+    }
+}
+
+fn key_press(key: egui::Key) -> Vec<egui::Event> {
+    [true, false]
+        .iter()
+        .map(|&pressed| egui::Event::Key {
+            key,
+            pressed,
+            modifiers: Default::default(),
+        })
+        .collect()
+}
+
+/// Wake up the event loop to paint a new frame with the new events.
+fn wake_up(this: &Object) {
+    // winit sends `RedrawRequested` when its view is drawn:
+    set_needs_display(superview(this));
+}
+
+fn new_position(offset: usize) -> *mut Object {
+    let position = new_object(text_position_class());
+    set_ivar(position, "index", offset);
+    autorelease(position)
+}
+
+fn new_range(start: usize, end: usize) -> *mut Object {
+    let range = new_object(text_range_class());
+    set_ivar(range, "start", start);
+    set_ivar(range, "end", end);
+    autorelease(range)
+}
+
+fn position_offset(position: *mut Object) -> usize {
+    object_ref(position).map_or(0, |position| ivar(position, "index"))
+}
+
+fn range_offsets(range: *mut Object) -> (usize, usize) {
+    object_ref(range).map_or((0, 0), |range| (ivar(range, "start"), ivar(range, "end")))
+}
+
+// ----------------------------------------------------------------------------
+// The unsafe Objective-C calls.
+
+#[allow(unsafe_code)]
+// SAFETY: `NSRange` is two `NSUInteger`s, which are 64-bit on all the devices iOS 11 runs on.
+unsafe impl Encode for NSRange {
+    fn encode() -> Encoding {
+        // SAFETY: this is the type encoding of `NSRange`, matching the struct above.
+        unsafe { Encoding::from_str("{_NSRange=QQ}") }
+    }
+}
+
+#[allow(unsafe_code)]
+// SAFETY: `CGPoint` is two `CGFloat`s, which are `f64` on 64-bit devices.
+unsafe impl Encode for CGPoint {
+    fn encode() -> Encoding {
+        // SAFETY: this is the type encoding of `CGPoint`, matching the struct above.
+        unsafe { Encoding::from_str("{CGPoint=dd}") }
+    }
+}
+
+#[allow(unsafe_code)]
+// SAFETY: `CGRect` is a `CGPoint` and a `CGSize`, both two `CGFloat`s.
+unsafe impl Encode for CGRect {
+    fn encode() -> Encoding {
+        // SAFETY: this is the type encoding of `CGRect`, matching the struct above.
+        unsafe { Encoding::from_str("{CGRect={CGPoint=dd}{CGSize=dd}}") }
+    }
+}
+
+#[allow(unsafe_code)]
+fn add_method<F: MethodImplementation<Callee = Object>>(decl: &mut ClassDecl, sel: Sel, method: F) {
+    // SAFETY: each selector is given a function with the argument and return types
+    // UIKit calls it with, see the docs of `UIKeyInput`, `UITextInput` and `UITextRange`.
+    unsafe { decl.add_method(sel, method) }
+}
+
+#[allow(unsafe_code)]
+fn view_safe_area_insets(view: *mut Object) -> UIEdgeInsets {
+    // SAFETY: `view` is the `UIView` of the winit window, and `safeAreaInsets` returns a `UIEdgeInsets`.
+    unsafe { msg_send![view, safeAreaInsets] }
+}
+
+/// `[class new]`, retained.
+#[allow(unsafe_code)]
+fn new_object(class: &Class) -> *mut Object {
+    // SAFETY: `new` takes no arguments and returns a new object of the class.
+    unsafe { msg_send![class, new] }
+}
+
+#[allow(unsafe_code)]
+fn autorelease(object: *mut Object) -> *mut Object {
+    // SAFETY: `object` is retained by us, and `autorelease` hands it to the caller's autorelease pool.
+    unsafe { msg_send![object, autorelease] }
+}
+
+#[allow(unsafe_code)]
+fn object_ref<'a>(object: *mut Object) -> Option<&'a Object> {
+    // SAFETY: UIKit passes either `nil` or an object it keeps alive for the duration of the call.
+    unsafe { object.as_ref() }
+}
+
+#[allow(unsafe_code)]
+fn ivar(object: &Object, name: &str) -> usize {
+    // SAFETY: our `UITextPosition` and `UITextRange` classes have `usize` ivars of these names.
+    // UIKit may also give us positions and ranges of its own, but only ones we gave it.
+    unsafe { *object.get_ivar::<usize>(name) }
+}
+
+#[allow(unsafe_code)]
+fn set_ivar(object: *mut Object, name: &str, value: usize) {
+    // SAFETY: `object` was just created by `new_object` from one of our classes,
+    // which have `usize` ivars of these names.
+    unsafe { (*object).set_ivar::<usize>(name, value) }
+}
+
+#[allow(unsafe_code)]
+fn add_subview(view: *mut Object, subview: *mut Object) {
+    // SAFETY: both are `UIView`s, and `addSubview:` takes a `UIView` and returns nothing.
+    unsafe {
+        let () = msg_send![view, addSubview: subview];
+    }
+}
+
+#[allow(unsafe_code)]
+fn remove_from_superview_and_release(view: *mut Object) {
+    // SAFETY: `view` is a `UIView` we created with `new_object`, and don't use after this.
+    unsafe {
+        let () = msg_send![view, removeFromSuperview];
+        let () = msg_send![view, release];
+    }
+}
+
+#[allow(unsafe_code)]
+fn set_first_responder(view: *mut Object, first_responder: bool) {
+    // SAFETY: `view` is a `UIResponder`, and both messages take no arguments and return a `BOOL`.
+    let _: BOOL = unsafe {
+        if first_responder {
+            msg_send![view, becomeFirstResponder]
+        } else {
+            msg_send![view, resignFirstResponder]
+        }
+    };
+}
+
+#[allow(unsafe_code)]
+fn superview(view: &Object) -> *mut Object {
+    // SAFETY: `view` is a `UIView`, and `superview` returns a `UIView` (or `nil`).
+    unsafe { msg_send![view, superview] }
+}
+
+#[allow(unsafe_code)]
+fn set_needs_display(view: *mut Object) {
+    // SAFETY: `view` is a `UIView` (sending to `nil` does nothing), and `setNeedsDisplay` returns nothing.
+    unsafe {
+        let () = msg_send![view, setNeedsDisplay];
+    }
+}
+
+/// The contents of an `NSString` or `NSAttributedString`, which `setMarkedText:` may give us.
+#[allow(unsafe_code)]
+fn string_from_ns(string: *mut Object) -> String {
+    if string.is_null() {
+        return String::new();
+    }
+    // SAFETY: `string` is an `NSString` or an `NSAttributedString`, whose `string` is an `NSString`.
+    // `UTF8String` returns a null-terminated string that lives as long as the autorelease pool.
+    unsafe {
+        let is_attributed: BOOL = msg_send![string, isKindOfClass: class!(NSAttributedString)];
+        let string: *mut Object = if is_attributed == YES {
+            msg_send![string, string]
+        } else {
+            string
+        };
+        let utf8: *const c_char = msg_send![string, UTF8String];
+        if utf8.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(utf8).to_string_lossy().into_owned()
+        }
+    }
+}
+
+/// A new, autoreleased, `NSString`.
+#[allow(unsafe_code)]
+fn new_ns_string(text: &str) -> *mut Object {
+    const NS_UTF8_STRING_ENCODING: usize = 4;
+    // SAFETY: `initWithBytes:length:encoding:` copies `length` bytes from `text`, which are UTF-8.
+    unsafe {
+        let string: *mut Object = msg_send![class!(NSString), alloc];
+        let string: *mut Object = msg_send![string,
+            initWithBytes: text.as_ptr()
+            length: text.len()
+            encoding: NS_UTF8_STRING_ENCODING];
+        msg_send![string, autorelease]
+    }
+}
+
+/// A new, autoreleased, empty `NSArray`.
+#[allow(unsafe_code)]
+fn new_empty_ns_array() -> *mut Object {
+    // SAFETY: `array` takes no arguments and returns an autoreleased `NSArray`.
+    unsafe { msg_send![class!(NSArray), array] }
+}
+
+/// A new, autoreleased, `UITextInputStringTokenizer` for our view.
+#[allow(unsafe_code)]
+fn new_string_tokenizer(view: &Object) -> *mut Object {
+    // SAFETY: `view` implements `UITextInput`, which `initWithTextInput:` takes.
+    unsafe {
+        let tokenizer: *mut Object = msg_send![class!(UITextInputStringTokenizer), alloc];
+        let tokenizer: *mut Object = msg_send![tokenizer, initWithTextInput: view];
+        msg_send![tokenizer, autorelease]
+    }
+}
@@ -14,13 +14,20 @@
 //     rustdoc::missing_crate_level_docs,
 //     rustdoc::private_intra_doc_links
 // )]
-#![forbid(unsafe_code)]
+// UIKit can only be reached through `unsafe` Objective-C calls, see `ios.rs`:
+#![cfg_attr(not(target_os = "ios"), forbid(unsafe_code))]
+#![cfg_attr(target_os = "ios", deny(unsafe_code))]
 #![warn(clippy::all, rust_2018_idioms)]
 #![allow(clippy::manual_range_contains, clippy::single_match)]
 
+#[cfg(target_os = "android")]
+mod android;
 mod backend;
+mod frame_pacer;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(target_os = "ios")]
+mod ios;
 mod painter;
 #[cfg(feature = "persistence")]
 pub mod persistence;
@@ -46,6 +53,8 @@ pub use copypasta::ClipboardContext; // TODO: remove
 
 pub struct GliumInputState {
     pub pointer_pos_in_points: Option<Pos2>,
+    /// The touch that is acting as the mouse, on platforms without a mouse (Android and iOS).
+    pub pointer_touch_id: Option<u64>,
     pub raw: egui::RawInput,
 }
 
@@ -53,6 +62,7 @@ impl GliumInputState {
     pub fn from_pixels_per_point(pixels_per_point: f32) -> Self {
         Self {
             pointer_pos_in_points: Default::default(),
+            pointer_touch_id: None,
             raw: egui::RawInput {
                 pixels_per_point: Some(pixels_per_point),
                 ..Default::default()
@@ -191,6 +201,13 @@ pub fn input_to_egui(
                         modifiers: input_state.raw.modifiers,
                     });
                 }
+            } else if is_android_back_button(input) {
+                // Close popups, stop editing text etc:
+                input_state.raw.events.push(Event::Key {
+                    key: Key::Escape,
+                    pressed: input.state == glutin::event::ElementState::Pressed,
+                    modifiers: input_state.raw.modifiers,
+                });
             }
         }
         WindowEvent::Focused(_) => {
@@ -231,6 +248,16 @@ pub fn input_to_egui(
         }
         WindowEvent::Touch(touch) => {
             let pixels_per_point_recip = 1. / pixels_per_point;
+            if emulate_pointer_with_touch() {
+                touch_to_pointer_events(
+                    input_state,
+                    touch,
+                    pos2(
+                        touch.location.x as f32 * pixels_per_point_recip,
+                        touch.location.y as f32 * pixels_per_point_recip,
+                    ),
+                );
+            }
             let mut hasher = std::collections::hash_map::DefaultHasher::new();
             touch.device_id.hash(&mut hasher);
             input_state.raw.events.push(Event::Touch {
@@ -261,6 +288,65 @@ pub fn input_to_egui(
     }
 }
 
+/// On Android and iOS there are no mouse events, only touch events.
+fn emulate_pointer_with_touch() -> bool {
+    cfg!(any(target_os = "android", target_os = "ios"))
+}
+
+/// Let the first finger down act as the mouse, until it is lifted.
+fn touch_to_pointer_events(
+    input_state: &mut GliumInputState,
+    touch: &glutin::event::Touch,
+    pos_in_points: Pos2,
+) {
+    use glutin::event::TouchPhase;
+    match touch.phase {
+        TouchPhase::Started if input_state.pointer_touch_id.is_none() => {
+            input_state.pointer_touch_id = Some(touch.id);
+            input_state.pointer_pos_in_points = Some(pos_in_points);
+            input_state
+                .raw
+                .events
+                .push(Event::PointerMoved(pos_in_points));
+            input_state.raw.events.push(Event::PointerButton {
+                pos: pos_in_points,
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: input_state.raw.modifiers,
+            });
+        }
+        TouchPhase::Moved if input_state.pointer_touch_id == Some(touch.id) => {
+            input_state.pointer_pos_in_points = Some(pos_in_points);
+            input_state
+                .raw
+                .events
+                .push(Event::PointerMoved(pos_in_points));
+        }
+        TouchPhase::Ended | TouchPhase::Cancelled
+            if input_state.pointer_touch_id == Some(touch.id) =>
+        {
+            input_state.pointer_touch_id = None;
+            input_state.pointer_pos_in_points = None;
+            input_state.raw.events.push(Event::PointerButton {
+                pos: pos_in_points,
+                button: PointerButton::Primary,
+                pressed: false,
+                modifiers: input_state.raw.modifiers,
+            });
+            // There is no hovering without a mouse:
+            input_state.raw.events.push(Event::PointerGone);
+        }
+        _ => {}
+    }
+}
+
+/// winit doesn't translate the Android key codes,
+/// so we recognize the back button by its (Linux) scan code.
+fn is_android_back_button(input: &glutin::event::KeyboardInput) -> bool {
+    const KEY_BACK: u32 = 158;
+    cfg!(target_os = "android") && input.virtual_keycode.is_none() && input.scancode == KEY_BACK
+}
+
 /// Glium sends special keys (backspace, delete, F1, ...) as characters.
 /// Ignore those.
 /// We also ignore '\r', '\n', '\t'.
@@ -422,12 +508,15 @@ pub fn handle_output(
         }
     }
 
-    if let Some(egui::Pos2 { x, y }) = output.text_cursor_pos {
+    if let Some(egui::Pos2 { x, y }) = output.text_cursor_rect.map(|rect| rect.left_bottom()) {
         display
             .gl_window()
             .window()
             .set_ime_position(glium::glutin::dpi::LogicalPosition { x, y })
     }
+
+    #[cfg(target_os = "ios")]
+    crate::ios::set_text_cursor_rect(output.text_cursor_rect);
 }
 
 pub fn init_clipboard() -> Option<ClipboardContext> {
@@ -461,6 +550,17 @@ pub fn screen_size_in_pixels(display: &glium::Display) -> Vec2 {
     vec2(width_in_pixels as f32, height_in_pixels as f32)
 }
 
+/// Identifies the monitors and how they are arranged, for [`egui::RawInput::monitor_setup`].
+pub fn monitor_setup(display: &glium::Display) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for monitor in display.gl_window().window().available_monitors() {
+        let (pos, size) = (monitor.position(), monitor.size());
+        (pos.x, pos.y, size.width, size.height).hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
 pub fn native_pixels_per_point(display: &glium::Display) -> f32 {
     display.gl_window().window().scale_factor() as f32
 }
@@ -476,20 +576,31 @@ pub struct EguiGlium {
     painter: crate::Painter,
     current_cursor_icon: egui::CursorIcon,
     screen_reader: crate::screen_reader::ScreenReader,
+    repaint_after: Option<f32>,
+    /// See [`egui::Output::read_pixels`].
+    read_pixels: Option<egui::Rect>,
+    /// The screen size when we last checked the monitor setup.
+    monitor_setup_screen_size: Vec2,
 }
 
 impl EguiGlium {
     pub fn new(display: &glium::Display) -> Self {
+        let egui_ctx = egui::CtxRef::default();
+        let painter = crate::Painter::new(display);
+        egui_ctx.set_texel_reader(painter.texel_reader());
         Self {
-            egui_ctx: Default::default(),
+            egui_ctx,
             start_time: std::time::Instant::now(),
             clipboard: crate::init_clipboard(),
             input_state: crate::GliumInputState::from_pixels_per_point(
                 crate::native_pixels_per_point(display),
             ),
-            painter: crate::Painter::new(display),
+            painter,
             current_cursor_icon: egui::CursorIcon::Default,
             screen_reader: crate::screen_reader::ScreenReader::default(),
+            repaint_after: None,
+            read_pixels: None,
+            monitor_setup_screen_size: Vec2::ZERO,
         }
     }
 
@@ -501,13 +612,40 @@ impl EguiGlium {
         (&self.egui_ctx, &mut self.painter)
     }
 
+    /// Call after replacing the [`glium::Display`],
+    /// e.g. when an Android app is resumed and has to create a new GL context.
+    ///
+    /// The user textures are lost, and need to be allocated again.
+    pub fn on_display_recreated(&mut self, display: &glium::Display) {
+        self.painter = crate::Painter::new(display);
+        self.egui_ctx.set_texel_reader(self.painter.texel_reader());
+    }
+
     pub fn on_event(&mut self, event: &glium::glutin::event::WindowEvent<'_>) {
+        let num_old_events = self.input_state.raw.events.len();
         crate::input_to_egui(
             self.egui_ctx.pixels_per_point(),
             &event,
             self.clipboard.as_mut(),
             &mut self.input_state,
         );
+
+        // winit events have no timestamp, so we use the time we got them:
+        let raw = &mut self.input_state.raw;
+        if raw.events.len() > num_old_events {
+            let time = self.start_time.elapsed().as_nanos() as f64 * 1e-9;
+            raw.event_times.resize(num_old_events, None);
+            raw.event_times.resize(raw.events.len(), Some(time));
+        }
+    }
+
+    /// Add events that don't come from winit, e.g. text typed on the iOS soft keyboard.
+    #[cfg(target_os = "ios")]
+    pub(crate) fn push_events(&mut self, events: Vec<egui::Event>) {
+        let time = self.start_time.elapsed().as_nanos() as f64 * 1e-9;
+        for event in events {
+            self.input_state.raw.push_event_at(event, time);
+        }
     }
 
     /// Is this a close event or a Cmd-Q/Alt-F4 keyboard command?
@@ -537,6 +675,24 @@ impl EguiGlium {
             None
         };
 
+        // The notch moves when the device is rotated, and the status bar comes and goes:
+        #[cfg(target_os = "android")]
+        {
+            self.input_state.raw.safe_area_insets =
+                crate::android::safe_area_insets(display, pixels_per_point);
+        }
+        #[cfg(target_os = "ios")]
+        {
+            self.input_state.raw.safe_area_insets =
+                crate::ios::safe_area_insets(display, pixels_per_point);
+        }
+
+        // Listing the monitors can be slow, so only do it when the window has likely moved to another monitor:
+        if screen_size != self.monitor_setup_screen_size {
+            self.monitor_setup_screen_size = screen_size;
+            self.input_state.raw.monitor_setup = Some(monitor_setup(display));
+        }
+
         self.egui_ctx.begin_frame(self.input_state.raw.take());
     }
 
@@ -558,12 +714,20 @@ impl EguiGlium {
         }
 
         let needs_repaint = egui_output.needs_repaint;
+        self.repaint_after = egui_output.repaint_after;
+        self.read_pixels = egui_output.read_pixels;
 
         handle_output(egui_output, self.clipboard.as_mut(), display);
 
         (needs_repaint, shapes)
     }
 
+    /// If set, the last frame only needs a repaint after this many seconds,
+    /// even though [`Self::end_frame`] said it needs a repaint. See [`egui::Output::repaint_after`].
+    pub fn repaint_after(&self) -> Option<f32> {
+        self.repaint_after
+    }
+
     pub fn paint(
         &mut self,
         display: &glium::Display,
@@ -579,4 +743,83 @@ impl EguiGlium {
             &self.egui_ctx.texture(),
         );
     }
+
+    /// Call this after [`Self::paint`], before `target.finish()`.
+    ///
+    /// Reads back the pixels egui asked for (see [`egui::Output::read_pixels`]),
+    /// e.g. for [`egui::Painter::read_pixels`].
+    pub fn read_pixels(&mut self, display: &glium::Display, target: &glium::Frame) {
+        use glium::Surface as _;
+
+        let rect = match self.read_pixels.take() {
+            Some(rect) => rect,
+            None => return,
+        };
+
+        let pixels_per_point = self.egui_ctx.pixels_per_point();
+        let (width, height) = target.get_dimensions();
+        let (width, height) = (width as usize, height as usize);
+        let to_pixel =
+            |points: f32, size: usize| ((points * pixels_per_point).round() as usize).min(size);
+        let (min_x, max_x) = (to_pixel(rect.min.x, width), to_pixel(rect.max.x, width));
+        let (min_y, max_y) = (to_pixel(rect.min.y, height), to_pixel(rect.max.y, height));
+        if min_x >= max_x || min_y >= max_y {
+            return;
+        }
+
+        // Copy just the rect out of the back buffer, so we don't wait for the frame to be shown:
+        let (rect_width, rect_height) = ((max_x - min_x) as u32, (max_y - min_y) as u32);
+        let texture = match glium::texture::Texture2d::empty_with_format(
+            display,
+            glium::texture::UncompressedFloatFormat::U8U8U8U8,
+            glium::texture::MipmapsOption::NoMipmap,
+            rect_width,
+            rect_height,
+        ) {
+            Ok(texture) => texture,
+            Err(err) => {
+                eprintln!("Failed to read back the screen: {:?}", err);
+                return;
+            }
+        };
+        target.blit_color(
+            &glium::Rect {
+                left: min_x as u32,
+                bottom: (height - max_y) as u32, // OpenGL counts from the bottom
+                width: rect_width,
+                height: rect_height,
+            },
+            &texture.as_surface(),
+            &glium::BlitTarget {
+                left: 0,
+                bottom: 0,
+                width: rect_width as i32,
+                height: rect_height as i32,
+            },
+            glium::uniforms::MagnifySamplerFilter::Nearest,
+        );
+        let pixels: glium::texture::RawImage2d<'_, u8> = texture.read();
+
+        // OpenGL has the bottom row first:
+        let rgba: Vec<u8> = pixels
+            .data
+            .chunks_exact(rect_width as usize * 4)
+            .rev()
+            .flatten()
+            .copied()
+            .collect();
+        let image =
+            egui::ColorImage::from_rgba_premultiplied([max_x - min_x, max_y - min_y], &rgba);
+        let rect = egui::Rect::from_min_max(
+            egui::pos2(
+                min_x as f32 / pixels_per_point,
+                min_y as f32 / pixels_per_point,
+            ),
+            egui::pos2(
+                max_x as f32 / pixels_per_point,
+                max_y as f32 / pixels_per_point,
+            ),
+        );
+        self.egui_ctx.set_screen_pixels(rect, image);
+    }
 }
@@ -3,26 +3,36 @@
 use {
     egui::{
         emath::Rect,
-        epaint::{Color32, Mesh},
+        epaint::{Color32, ColorImage, Mesh},
     },
     glium::{
         implement_vertex,
         index::PrimitiveType,
         program,
-        texture::{self, srgb_texture2d::SrgbTexture2d},
-        uniform,
-        uniforms::{MagnifySamplerFilter, SamplerWrapFunction},
+        texture::{self, srgb_texture2d::SrgbTexture2d, texture2d::Texture2d},
+        uniforms::{
+            MagnifySamplerFilter, SamplerBehavior, SamplerWrapFunction, UniformValue, Uniforms,
+        },
         Frame, Surface,
     },
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    },
 };
 
 pub struct Painter {
     program: glium::Program,
+    /// OpenGL ES, where the shaders only understand sRGB textures.
+    is_gles: bool,
     egui_texture: Option<SrgbTexture2d>,
     egui_texture_version: Option<u64>,
 
     /// `None` means unallocated (freed) slot.
     user_textures: Vec<Option<UserTexture>>,
+
+    /// The texels of the user textures from [`Painter::set_user_texture`], for [`Painter::texel_reader`].
+    user_texels: Arc<Mutex<HashMap<u64, ColorImage>>>,
 }
 
 #[derive(Default)]
@@ -31,8 +41,28 @@ struct UserTexture {
     /// This is the format glium likes.
     pixels: Vec<Vec<(u8, u8, u8, u8)>>,
 
+    /// Pending upload of a linear texture instead (will be emptied later),
+    /// see [`Painter::set_user_texture_linear`].
+    linear_pixels: Vec<Vec<(f32, f32, f32, f32)>>,
+
     /// Lazily uploaded
     gl_texture: Option<SrgbTexture2d>,
+
+    /// Lazily uploaded instead of `gl_texture` for a linear texture.
+    gl_linear_texture: Option<Texture2d>,
+}
+
+/// The uniforms of the egui shader, with either an sRGB or a linear texture.
+struct MeshUniforms<'t> {
+    screen_size: [f32; 2],
+    sampler: UniformValue<'t>,
+}
+
+impl Uniforms for MeshUniforms<'_> {
+    fn visit_values<'a, F: FnMut(&str, UniformValue<'a>)>(&'a self, mut visit: F) {
+        visit("u_screen_size", UniformValue::Vec2(self.screen_size));
+        visit("u_sampler", self.sampler);
+    }
 }
 
 impl Painter {
@@ -58,11 +88,15 @@ impl Painter {
         }
         .expect("Failed to compile shader");
 
+        let is_gles = facade.get_context().get_opengl_version().0 == glium::Api::GlEs;
+
         Painter {
             program,
+            is_gles,
             egui_texture: None,
             egui_texture_version: None,
             user_textures: Default::default(),
+            user_texels: Default::default(),
         }
     }
 
@@ -153,16 +187,26 @@ impl Painter {
         let width_in_points = width_in_pixels as f32 / pixels_per_point;
         let height_in_points = height_in_pixels as f32 / pixels_per_point;
 
-        if let Some(texture) = self.get_texture(mesh.texture_id) {
-            // The texture coordinates for text are so that both nearest and linear should work with the egui font texture.
-            // For user textures linear sampling is more likely to be the right choice.
-            let filter = MagnifySamplerFilter::Linear;
-
-            let uniforms = uniform! {
-                u_screen_size: [width_in_points, height_in_points],
-                u_sampler: texture.sampled().magnify_filter(filter).wrap_function(SamplerWrapFunction::Clamp),
-            };
+        // The texture coordinates for text are so that both nearest and linear should work with the egui font texture.
+        // For user textures linear sampling is more likely to be the right choice.
+        let sampler = SamplerBehavior {
+            magnify_filter: MagnifySamplerFilter::Linear,
+            wrap_function: (
+                SamplerWrapFunction::Clamp,
+                SamplerWrapFunction::Clamp,
+                SamplerWrapFunction::Clamp,
+            ),
+            ..Default::default()
+        };
+        // Both kinds of texture are sampled as linear colors, so they use the same shader:
+        let texture = if let Some(texture) = self.get_texture(mesh.texture_id) {
+            Some(UniformValue::SrgbTexture2d(texture, Some(sampler)))
+        } else {
+            self.get_linear_texture(mesh.texture_id)
+                .map(|texture| UniformValue::Texture2d(texture, Some(sampler)))
+        };
 
+        if let Some(texture) = texture {
             // egui outputs colors with premultiplied alpha:
             let color_blend_func = glium::BlendingFunction::Addition {
                 source: glium::LinearBlendingFactor::One,
@@ -214,6 +258,10 @@ impl Painter {
                 ..Default::default()
             };
 
+            let uniforms = MeshUniforms {
+                screen_size: [width_in_points, height_in_points],
+                sampler: texture,
+            };
             target
                 .draw(
                     &vertex_buffer,
@@ -241,6 +289,25 @@ impl Painter {
         self.user_textures.push(Some(Default::default()));
         id
     }
+
+    /// Looks up the texels of the user textures, for [`egui::Context::set_texel_reader`].
+    ///
+    /// Only textures from [`Self::set_user_texture`] are known,
+    /// since the texels of a [registered glium texture](Self::register_glium_texture) are only on the GPU.
+    pub fn texel_reader(
+        &self,
+    ) -> impl Fn(egui::TextureId, [usize; 2]) -> Option<Color32> + Send + Sync + 'static {
+        let user_texels = self.user_texels.clone();
+        move |texture_id, [x, y]| match texture_id {
+            egui::TextureId::User(id) => {
+                let user_texels = user_texels.lock().unwrap();
+                let image = user_texels.get(&id)?;
+                (x < image.width() && y < image.height()).then(|| image[(x, y)])
+            }
+            egui::TextureId::Egui => None,
+        }
+    }
+
     /// register glium texture as egui texture
     /// Usable for render to image rectangle
     pub fn register_glium_texture(
@@ -251,8 +318,8 @@ impl Painter {
         if let egui::TextureId::User(id) = id {
             if let Some(Some(user_texture)) = self.user_textures.get_mut(id as usize) {
                 *user_texture = UserTexture {
-                    pixels: vec![],
                     gl_texture: Some(texture),
+                    ..Default::default()
                 }
             }
         }
@@ -268,6 +335,13 @@ impl Painter {
 
         if let egui::TextureId::User(id) = id {
             if let Some(Some(user_texture)) = self.user_textures.get_mut(id as usize) {
+                self.user_texels.lock().unwrap().insert(
+                    id,
+                    ColorImage {
+                        size: [size.0, size.1],
+                        pixels: pixels.to_vec(),
+                    },
+                );
                 let pixels: Vec<Vec<(u8, u8, u8, u8)>> = pixels
                     .chunks(size.0 as usize)
                     .map(|row| row.iter().map(|srgba| srgba.to_tuple()).collect())
@@ -275,7 +349,47 @@ impl Painter {
 
                 *user_texture = UserTexture {
                     pixels,
-                    gl_texture: None,
+                    ..Default::default()
+                };
+            }
+        }
+    }
+
+    /// Like [`Self::set_user_texture`], but with linear colors (and premultiplied alpha),
+    /// which are kept as floats, so colors brighter than white aren't clamped.
+    ///
+    /// On OpenGL ES the colors are converted to 8-bit sRGB instead.
+    pub fn set_user_texture_linear(
+        &mut self,
+        id: egui::TextureId,
+        size: (usize, usize),
+        pixels: &[egui::Rgba],
+    ) {
+        assert_eq!(size.0 * size.1, pixels.len());
+
+        if self.is_gles {
+            let srgba_pixels: Vec<Color32> = pixels.iter().map(|&rgba| rgba.into()).collect();
+            self.set_user_texture(id, size, &srgba_pixels);
+            return;
+        }
+
+        if let egui::TextureId::User(id) = id {
+            if let Some(Some(user_texture)) = self.user_textures.get_mut(id as usize) {
+                self.user_texels.lock().unwrap().insert(
+                    id,
+                    ColorImage {
+                        size: [size.0, size.1],
+                        pixels: pixels.iter().map(|&rgba| rgba.into()).collect(),
+                    },
+                );
+                let linear_pixels: Vec<Vec<(f32, f32, f32, f32)>> = pixels
+                    .chunks(size.0 as usize)
+                    .map(|row| row.iter().map(|rgba| rgba.to_tuple()).collect())
+                    .collect();
+
+                *user_texture = UserTexture {
+                    linear_pixels,
+                    ..Default::default()
                 };
             }
         }
@@ -283,6 +397,7 @@ impl Painter {
 
     pub fn free_user_texture(&mut self, id: egui::TextureId) {
         if let egui::TextureId::User(id) = id {
+            self.user_texels.lock().unwrap().remove(&id);
             let index = id as usize;
             if index < self.user_textures.len() {
                 self.user_textures[index] = None;
@@ -302,9 +417,28 @@ impl Painter {
         }
     }
 
+    fn get_linear_texture(&self, texture_id: egui::TextureId) -> Option<&Texture2d> {
+        match texture_id {
+            egui::TextureId::Egui => None,
+            egui::TextureId::User(id) => self
+                .user_textures
+                .get(id as usize)?
+                .as_ref()?
+                .gl_linear_texture
+                .as_ref(),
+        }
+    }
+
     pub fn upload_pending_user_textures(&mut self, facade: &dyn glium::backend::Facade) {
         for user_texture in self.user_textures.iter_mut().flatten() {
-            if user_texture.gl_texture.is_none() {
+            if !user_texture.linear_pixels.is_empty() {
+                let pixels = std::mem::take(&mut user_texture.linear_pixels);
+                let format = texture::UncompressedFloatFormat::F16F16F16F16;
+                let mipmaps = texture::MipmapsOption::NoMipmap;
+                user_texture.gl_linear_texture =
+                    Some(Texture2d::with_format(facade, pixels, format, mipmaps).unwrap());
+            } else if user_texture.gl_texture.is_none() && user_texture.gl_linear_texture.is_none()
+            {
                 let pixels = std::mem::take(&mut user_texture.pixels);
                 let format = texture::SrgbFormat::U8U8U8U8;
                 let mipmaps = texture::MipmapsOption::NoMipmap;
@@ -71,8 +71,21 @@ impl WindowSettings {
         //     });
 
         if let Some(pos) = self.pos {
-            display
-                .gl_window()
+            // Don't put the window off the screen, e.g. if it was on a monitor that is now unplugged:
+            let gl_window = display.gl_window();
+            let on_a_monitor = gl_window.window().available_monitors().any(|monitor| {
+                let (min, size) = (monitor.position(), monitor.size());
+                let monitor_rect = egui::Rect::from_min_size(
+                    egui::pos2(min.x as f32, min.y as f32),
+                    egui::vec2(size.width as f32, size.height as f32),
+                );
+                // The window border can be a bit outside the monitor, e.g. when maximized:
+                monitor_rect.expand(16.0).contains(pos)
+            });
+            if !on_a_monitor {
+                return;
+            }
+            gl_window
                 .window()
                 .set_outer_position(glutin::dpi::PhysicalPosition::new(
                     pos.x as f64,
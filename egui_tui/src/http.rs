@@ -0,0 +1,16 @@
+pub use epi::http::{fetch_blocking, Request, Response};
+
+pub(crate) struct TuiHttp {}
+
+impl epi::backend::Http for TuiHttp {
+    fn fetch_dyn(
+        &self,
+        request: Request,
+        on_done: Box<dyn FnOnce(Result<Response, String>) + Send>,
+    ) {
+        std::thread::spawn(move || {
+            let result = crate::http::fetch_blocking(&request);
+            on_done(result)
+        });
+    }
+}
@@ -0,0 +1,202 @@
+use crossterm::event::{
+    Event as TermEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use egui::{pos2, Event, Key, Modifiers, PointerButton, Pos2, RawInput, Vec2};
+
+/// Collects terminal events into an [`egui::RawInput`].
+pub struct TermInputState {
+    pub raw: RawInput,
+    pointer_pos: Option<Pos2>,
+}
+
+impl Default for TermInputState {
+    fn default() -> Self {
+        Self {
+            raw: RawInput {
+                pixels_per_point: Some(1.0),
+                ..Default::default()
+            },
+            pointer_pos: None,
+        }
+    }
+}
+
+impl TermInputState {
+    /// `cell_size` is the size of a character cell, in points.
+    pub fn on_event(&mut self, cell_size: Vec2, event: TermEvent) {
+        match event {
+            TermEvent::Key(key_event) => self.on_key(key_event),
+            TermEvent::Mouse(mouse_event) => self.on_mouse(cell_size, mouse_event),
+            TermEvent::Resize(..) => {} // The screen size is set every frame
+        }
+    }
+
+    fn on_key(&mut self, KeyEvent { code, modifiers }: KeyEvent) {
+        let modifiers = egui_modifiers(modifiers);
+        self.raw.modifiers = modifiers;
+
+        if let KeyCode::Char(ch) = code {
+            if modifiers.command {
+                match ch {
+                    'c' => self.raw.events.push(Event::Copy),
+                    'x' => self.raw.events.push(Event::Cut),
+                    _ => {}
+                }
+            } else if !modifiers.alt {
+                self.raw.events.push(Event::Text(ch.to_string()));
+            }
+        }
+
+        let (key, shift) = match code {
+            // Shift+Tab:
+            KeyCode::BackTab => (Some(Key::Tab), true),
+            code => (egui_key(code), modifiers.shift),
+        };
+        if let Some(key) = key {
+            let modifiers = Modifiers { shift, ..modifiers };
+            // Terminals only tell us about key presses:
+            for &pressed in &[true, false] {
+                self.raw.events.push(Event::Key {
+                    key,
+                    pressed,
+                    modifiers,
+                });
+            }
+        }
+    }
+
+    fn on_mouse(&mut self, cell_size: Vec2, mouse_event: MouseEvent) {
+        let MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers,
+        } = mouse_event;
+        let modifiers = egui_modifiers(modifiers);
+        self.raw.modifiers = modifiers;
+
+        // The center of the cell:
+        let pos = pos2(
+            (column as f32 + 0.5) * cell_size.x,
+            (row as f32 + 0.5) * cell_size.y,
+        );
+        if self.pointer_pos != Some(pos) {
+            self.pointer_pos = Some(pos);
+            self.raw.events.push(Event::PointerMoved(pos));
+        }
+
+        let mut button_event = |button, pressed| {
+            if let Some(button) = egui_button(button) {
+                self.raw.events.push(Event::PointerButton {
+                    pos,
+                    button,
+                    pressed,
+                    modifiers,
+                });
+            }
+        };
+
+        match kind {
+            MouseEventKind::Down(button) => button_event(button, true),
+            MouseEventKind::Up(button) => button_event(button, false),
+            MouseEventKind::Drag(_) | MouseEventKind::Moved => {}
+            MouseEventKind::ScrollDown => self.raw.scroll_delta.y -= 3.0 * cell_size.y,
+            MouseEventKind::ScrollUp => self.raw.scroll_delta.y += 3.0 * cell_size.y,
+        }
+    }
+}
+
+fn egui_modifiers(modifiers: KeyModifiers) -> Modifiers {
+    let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+    Modifiers {
+        alt: modifiers.contains(KeyModifiers::ALT),
+        ctrl,
+        shift: modifiers.contains(KeyModifiers::SHIFT),
+        mac_cmd: false,
+        // Terminals send Ctrl, also on Mac:
+        command: ctrl,
+    }
+}
+
+fn egui_button(button: MouseButton) -> Option<PointerButton> {
+    match button {
+        MouseButton::Left => Some(PointerButton::Primary),
+        MouseButton::Right => Some(PointerButton::Secondary),
+        MouseButton::Middle => Some(PointerButton::Middle),
+    }
+}
+
+fn egui_key(code: KeyCode) -> Option<Key> {
+    Some(match code {
+        KeyCode::Down => Key::ArrowDown,
+        KeyCode::Left => Key::ArrowLeft,
+        KeyCode::Right => Key::ArrowRight,
+        KeyCode::Up => Key::ArrowUp,
+
+        KeyCode::Esc => Key::Escape,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Enter => Key::Enter,
+
+        KeyCode::Insert => Key::Insert,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+
+        KeyCode::Char(ch) => return egui_char_key(ch),
+
+        _ => {
+            return None;
+        }
+    })
+}
+
+fn egui_char_key(ch: char) -> Option<Key> {
+    Some(match ch.to_ascii_uppercase() {
+        ' ' => Key::Space,
+
+        '0' => Key::Num0,
+        '1' => Key::Num1,
+        '2' => Key::Num2,
+        '3' => Key::Num3,
+        '4' => Key::Num4,
+        '5' => Key::Num5,
+        '6' => Key::Num6,
+        '7' => Key::Num7,
+        '8' => Key::Num8,
+        '9' => Key::Num9,
+
+        'A' => Key::A,
+        'B' => Key::B,
+        'C' => Key::C,
+        'D' => Key::D,
+        'E' => Key::E,
+        'F' => Key::F,
+        'G' => Key::G,
+        'H' => Key::H,
+        'I' => Key::I,
+        'J' => Key::J,
+        'K' => Key::K,
+        'L' => Key::L,
+        'M' => Key::M,
+        'N' => Key::N,
+        'O' => Key::O,
+        'P' => Key::P,
+        'Q' => Key::Q,
+        'R' => Key::R,
+        'S' => Key::S,
+        'T' => Key::T,
+        'U' => Key::U,
+        'V' => Key::V,
+        'W' => Key::W,
+        'X' => Key::X,
+        'Y' => Key::Y,
+        'Z' => Key::Z,
+
+        _ => {
+            return None;
+        }
+    })
+}
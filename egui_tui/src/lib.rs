@@ -0,0 +1,384 @@
+//! [`egui`] bindings for terminals, using [`crossterm`](https://github.com/crossterm-rs/crossterm).
+//!
+//! This lets you run an egui app in a terminal, e.g. over SSH on a machine without a GPU.
+//! Each character cell of the terminal covers a rectangle of points,
+//! and the shapes egui paints are approximated with characters and colors:
+//!
+//! * Text is painted one character per cell. All text uses the same monospace font and size.
+//! * Filled rectangles set the background color of the cells they cover.
+//! * Windows and other big frames get a border of box drawing characters.
+//! * Lines, circles and arrows are drawn with box drawing characters and symbols.
+//! * Images and shadows are not painted.
+//!
+//! The spacing of the [`egui::Style`] is set up so that widgets line up with the cells,
+//! so labels, buttons, checkboxes, sliders, text edits, scroll areas and windows all work,
+//! with both the keyboard and the mouse.
+//! The terminal needs to support 24-bit colors.
+//!
+//! This library is an [`epi`] backend: call [`run`] with your app.
+//! Press <kbd>Ctrl</kbd>+<kbd>Q</kbd> to quit.
+//! Use [`EguiTui`] if you want to drive egui yourself.
+
+// Forbid warnings in release builds:
+#![cfg_attr(not(debug_assertions), deny(warnings))]
+#![forbid(unsafe_code)]
+#![warn(clippy::all, rust_2018_idioms)]
+
+#[cfg(feature = "http")]
+pub mod http;
+mod input;
+mod painter;
+
+pub use input::TermInputState;
+pub use painter::{Cell, Painter};
+
+use std::{
+    io::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    cursor, event,
+    event::{Event as TermEvent, KeyCode, KeyEvent, KeyModifiers},
+    execute, queue, style, terminal,
+};
+use egui::{emath::NumExt as _, epaint::ClippedShape, vec2, Color32, Pos2, Rect, TextStyle, Vec2};
+
+/// Use [`egui`] from a terminal.
+pub struct EguiTui {
+    egui_ctx: egui::CtxRef,
+    start_time: Instant,
+    input_state: TermInputState,
+    painter: Painter,
+    text_cursor_pos: Option<Pos2>,
+    repaint_after: Option<f32>,
+}
+
+impl EguiTui {
+    /// `columns` and `rows` is the size of the terminal, e.g. from [`crossterm::terminal::size`].
+    pub fn new(columns: u16, rows: u16) -> Self {
+        let mut egui_ctx = egui::CtxRef::default();
+        egui_ctx.set_fonts(monospace_fonts());
+
+        // We need to run a frame to get at the fonts:
+        egui_ctx.begin_frame(Default::default());
+        let cell_size = vec2(
+            egui_ctx
+                .fonts()
+                .glyph_width(TextStyle::Body, 'M')
+                .at_least(1.0),
+            egui_ctx.fonts().row_height(TextStyle::Body).at_least(1.0),
+        );
+        let _ = egui_ctx.end_frame();
+        egui_ctx.set_style(terminal_style(cell_size));
+
+        Self {
+            egui_ctx,
+            start_time: Instant::now(),
+            input_state: Default::default(),
+            painter: Painter::new(cell_size, columns as usize, rows as usize),
+            text_cursor_pos: None,
+            repaint_after: None,
+        }
+    }
+
+    pub fn ctx(&self) -> &egui::CtxRef {
+        &self.egui_ctx
+    }
+
+    pub fn painter_mut(&mut self) -> &mut Painter {
+        &mut self.painter
+    }
+
+    pub fn on_event(&mut self, event: TermEvent) {
+        if let TermEvent::Resize(columns, rows) = event {
+            self.painter.set_size(columns as usize, rows as usize);
+        }
+        let num_old_events = self.input_state.raw.events.len();
+        self.input_state.on_event(self.painter.cell_size(), event);
+
+        // Terminal events have no timestamp, so we use the time we got them:
+        let raw = &mut self.input_state.raw;
+        if raw.events.len() > num_old_events {
+            let time = self.start_time.elapsed().as_secs_f64();
+            raw.event_times.resize(num_old_events, None);
+            raw.event_times.resize(raw.events.len(), Some(time));
+        }
+    }
+
+    pub fn begin_frame(&mut self) {
+        let raw_input = &mut self.input_state.raw;
+        raw_input.time = Some(self.start_time.elapsed().as_secs_f64());
+        raw_input.screen_rect = Some(Rect::from_min_size(
+            Default::default(),
+            self.painter.screen_size(),
+        ));
+        self.egui_ctx.begin_frame(raw_input.take());
+    }
+
+    /// Returns `needs_repaint` and shapes to draw.
+    pub fn end_frame(&mut self) -> (bool, Vec<ClippedShape>) {
+        let (egui_output, shapes) = self.egui_ctx.end_frame();
+        // Clipboard, cursor icons and opening links are not supported.
+        self.text_cursor_pos = egui_output.text_cursor_pos;
+        self.repaint_after = egui_output.repaint_after;
+        (egui_output.needs_repaint, shapes)
+    }
+
+    /// If set, the last frame only needs a repaint after this many seconds,
+    /// even though [`Self::end_frame`] said it needs a repaint. See [`egui::Output::repaint_after`].
+    pub fn repaint_after(&self) -> Option<f32> {
+        self.repaint_after
+    }
+
+    /// Paint the shapes, and move the terminal cursor to the text cursor (if any).
+    pub fn paint(
+        &mut self,
+        out: &mut impl Write,
+        clear_color: Color32,
+        shapes: Vec<ClippedShape>,
+    ) -> std::io::Result<()> {
+        self.painter.paint_shapes(out, clear_color, &shapes)?;
+
+        if let Some(pos) = self.text_cursor_pos {
+            let cell_size = self.painter.cell_size();
+            let column = ((pos.x + 0.5 * cell_size.x) / cell_size.x).floor().max(0.0);
+            let row = ((pos.y + 0.5 * cell_size.y) / cell_size.y).floor().max(0.0);
+            queue!(out, cursor::MoveTo(column as u16, row as u16), cursor::Show)?;
+        } else {
+            queue!(out, cursor::Hide)?;
+        }
+        out.flush()
+    }
+}
+
+/// All text in the same monospace font and size, so that each character fills one cell.
+fn monospace_fonts() -> egui::FontDefinitions {
+    let mut fonts = egui::FontDefinitions::default();
+    // The default size for the monospace font gives a whole number of points per character,
+    // which keeps the characters lined up with the cells.
+    let size = fonts
+        .family_and_size
+        .get(&TextStyle::Monospace)
+        .map_or(13.0, |(_, size)| *size);
+    for family_and_size in fonts.family_and_size.values_mut() {
+        *family_and_size = (egui::FontFamily::Monospace, size);
+    }
+    fonts
+}
+
+/// Spacing in whole cells.
+fn terminal_style(cell_size: Vec2) -> egui::Style {
+    let mut style = egui::Style::default();
+    let spacing = &mut style.spacing;
+    spacing.item_spacing = vec2(cell_size.x, 0.0);
+    spacing.window_padding = cell_size;
+    spacing.button_padding = vec2(cell_size.x, 0.0);
+    spacing.indent = 2.0 * cell_size.x;
+    spacing.interact_size = vec2(4.0 * cell_size.x, cell_size.y);
+    spacing.slider_width = 16.0 * cell_size.x;
+    spacing.text_edit_width = 24.0 * cell_size.x;
+    spacing.icon_width = cell_size.x;
+    spacing.icon_spacing = cell_size.x;
+    spacing.tooltip_width = 40.0 * cell_size.x;
+    spacing.combo_height = 10.0 * cell_size.y;
+    spacing.scroll_bar_width = cell_size.x;
+    // Every frame may have to go over the network, so skip the animations:
+    style.animation_time = 0.0;
+    style
+}
+
+// ----------------------------------------------------------------------------
+
+/// How often to check for repaint requests from other threads while idle.
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Limit the frame rate of animations, to save bandwidth.
+const MIN_FRAME_TIME: Duration = Duration::from_millis(33);
+
+#[derive(Default)]
+struct TuiRepaintSignal(AtomicBool);
+
+impl TuiRepaintSignal {
+    fn take(&self) -> bool {
+        self.0.swap(false, Ordering::SeqCst)
+    }
+}
+
+impl epi::RepaintSignal for TuiRepaintSignal {
+    fn request_repaint(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// There are no images in a terminal, but we still hand out ids.
+#[derive(Default)]
+struct TextureAllocator {
+    next_id: u64,
+}
+
+impl epi::TextureAllocator for TextureAllocator {
+    fn alloc_srgba_premultiplied(
+        &mut self,
+        _size: (usize, usize),
+        _srgba_pixels: &[Color32],
+    ) -> egui::TextureId {
+        self.next_id += 1;
+        egui::TextureId::User(self.next_id)
+    }
+
+    fn free(&mut self, _id: egui::TextureId) {}
+}
+
+/// Puts the terminal in raw mode and on the alternate screen, and restores it when dropped.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new(out: &mut impl Write) -> std::io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(
+            out,
+            terminal::EnterAlternateScreen,
+            event::EnableMouseCapture,
+            cursor::Hide
+        )?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        execute!(
+            std::io::stdout(),
+            style::ResetColor,
+            cursor::Show,
+            event::DisableMouseCapture,
+            terminal::LeaveAlternateScreen
+        )
+        .ok();
+        terminal::disable_raw_mode().ok();
+    }
+}
+
+fn is_quit_shortcut(event: &TermEvent) -> bool {
+    matches!(
+        event,
+        TermEvent::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            modifiers,
+        }) if modifiers.contains(KeyModifiers::CONTROL)
+    )
+}
+
+fn integration_info(previous_frame_time: Option<f32>) -> epi::IntegrationInfo {
+    epi::IntegrationInfo {
+        web_info: None,
+        prefer_dark_mode: None,
+        cpu_usage: previous_frame_time,
+        seconds_since_midnight: None,
+        native_pixels_per_point: Some(1.0),
+    }
+}
+
+/// Run an egui app in the terminal, until it quits or the user presses <kbd>Ctrl</kbd>+<kbd>Q</kbd>.
+///
+/// # Errors
+/// If reading from or writing to the terminal fails.
+pub fn run(mut app: Box<dyn epi::App>) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout();
+    let _terminal_guard = TerminalGuard::new(&mut stdout)?;
+
+    let (columns, rows) = terminal::size()?;
+    let mut egui = EguiTui::new(columns, rows);
+    let repaint_signal = Arc::new(TuiRepaintSignal::default());
+    #[cfg(feature = "http")]
+    let http = Arc::new(crate::http::TuiHttp {});
+    let mut tex_allocator = TextureAllocator::default();
+
+    {
+        let mut app_output = epi::backend::AppOutput::default();
+        let mut frame = epi::backend::FrameBuilder {
+            info: integration_info(None),
+            tex_allocator: &mut tex_allocator,
+            #[cfg(feature = "http")]
+            http: http.clone(),
+            output: &mut app_output,
+            repaint_signal: repaint_signal.clone(),
+        }
+        .build();
+        app.setup(egui.ctx(), &mut frame, None);
+    }
+
+    let mut previous_frame_time = None;
+    let mut needs_repaint = true;
+    let mut repaint_at: Option<Instant> = None;
+
+    loop {
+        let timeout = if needs_repaint {
+            MIN_FRAME_TIME
+        } else if let Some(repaint_at) = repaint_at {
+            repaint_at
+                .saturating_duration_since(Instant::now())
+                .max(MIN_FRAME_TIME)
+                .min(IDLE_POLL_INTERVAL)
+        } else {
+            IDLE_POLL_INTERVAL
+        };
+        let mut quit = false;
+        if event::poll(timeout)? {
+            // Handle all the events that are waiting:
+            loop {
+                let event = event::read()?;
+                quit |= is_quit_shortcut(&event);
+                egui.on_event(event);
+                if !event::poll(Duration::from_secs(0))? {
+                    break;
+                }
+            }
+        } else if !needs_repaint
+            && !matches!(repaint_at, Some(repaint_at) if Instant::now() >= repaint_at)
+            && !repaint_signal.take()
+        {
+            continue;
+        }
+        if quit {
+            break;
+        }
+
+        let frame_start = Instant::now();
+
+        egui.input_state.raw.previous_frame_time = previous_frame_time;
+        egui.begin_frame();
+        let mut app_output = epi::backend::AppOutput::default();
+        let mut frame = epi::backend::FrameBuilder {
+            info: integration_info(previous_frame_time),
+            tex_allocator: &mut tex_allocator,
+            #[cfg(feature = "http")]
+            http: http.clone(),
+            output: &mut app_output,
+            repaint_signal: repaint_signal.clone(),
+        }
+        .build();
+        app.update(egui.ctx(), &mut frame);
+        let (egui_needs_repaint, shapes) = egui.end_frame();
+        repaint_at = egui
+            .repaint_after()
+            .map(|seconds| Instant::now() + Duration::from_secs_f32(seconds));
+        needs_repaint = egui_needs_repaint && repaint_at.is_none();
+
+        egui.paint(&mut stdout, app.clear_color().into(), shapes)?;
+
+        previous_frame_time = Some((Instant::now() - frame_start).as_secs_f32());
+
+        if app_output.quit {
+            break;
+        }
+    }
+
+    app.on_exit();
+    Ok(())
+}
@@ -0,0 +1,453 @@
+use std::io::Write;
+
+use crossterm::{cursor, queue, style};
+use egui::{
+    emath::{pos2, Pos2, Rect, Vec2},
+    epaint::{ClippedShape, Galley, Shape},
+    Color32, Stroke,
+};
+
+/// One character cell of the terminal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cell {
+    pub ch: char,
+    pub fg: Color32,
+    pub bg: Color32,
+}
+
+/// Paints egui shapes into a grid of terminal cells, and writes the cells to the terminal.
+///
+/// Each cell covers [`Self::cell_size`] points.
+/// A cell is painted by a shape if the center of the cell is inside the shape (and its clip rect).
+///
+/// Only the cells that changed since the previous call to [`Self::paint_shapes`] are written,
+/// to keep the bandwidth down when running over a network.
+pub struct Painter {
+    cell_size: Vec2,
+    columns: usize,
+    rows: usize,
+    cells: Vec<Cell>,
+    /// What is on the terminal right now.
+    /// Empty if unknown, e.g. after a resize.
+    on_screen: Vec<Cell>,
+}
+
+impl Painter {
+    pub fn new(cell_size: Vec2, columns: usize, rows: usize) -> Self {
+        Self {
+            cell_size,
+            columns,
+            rows,
+            cells: vec![],
+            on_screen: vec![],
+        }
+    }
+
+    /// The size of one character cell, in points.
+    pub fn cell_size(&self) -> Vec2 {
+        self.cell_size
+    }
+
+    /// The size of the terminal, in points.
+    pub fn screen_size(&self) -> Vec2 {
+        Vec2::new(
+            self.columns as f32 * self.cell_size.x,
+            self.rows as f32 * self.cell_size.y,
+        )
+    }
+
+    /// Call when the terminal has been resized.
+    pub fn set_size(&mut self, columns: usize, rows: usize) {
+        if (columns, rows) != (self.columns, self.rows) {
+            self.columns = columns;
+            self.rows = rows;
+            self.on_screen.clear();
+        }
+    }
+
+    /// The cells painted by the latest call to [`Self::paint_shapes`], row by row.
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    /// Paint the shapes on a background of `clear_color`, and write the changed cells to `out`.
+    pub fn paint_shapes(
+        &mut self,
+        out: &mut impl Write,
+        clear_color: Color32,
+        shapes: &[ClippedShape],
+    ) -> std::io::Result<()> {
+        self.cells.clear();
+        self.cells.resize(
+            self.columns * self.rows,
+            Cell {
+                ch: ' ',
+                fg: Color32::WHITE,
+                bg: clear_color,
+            },
+        );
+
+        for ClippedShape(clip_rect, shape) in shapes {
+            self.paint_shape(*clip_rect, shape);
+        }
+
+        self.write_changes(out)
+    }
+
+    /// Center of the cell, in points.
+    fn cell_center(&self, column: usize, row: usize) -> Pos2 {
+        pos2(
+            (column as f32 + 0.5) * self.cell_size.x,
+            (row as f32 + 0.5) * self.cell_size.y,
+        )
+    }
+
+    /// The cell containing `pos`, if it is on the screen and inside `clip_rect`.
+    fn cell_index_at(&self, clip_rect: Rect, pos: Pos2) -> Option<usize> {
+        let column = (pos.x / self.cell_size.x).floor();
+        let row = (pos.y / self.cell_size.y).floor();
+        if column < 0.0 || row < 0.0 {
+            return None;
+        }
+        let (column, row) = (column as usize, row as usize);
+        if column < self.columns
+            && row < self.rows
+            && clip_rect.contains(self.cell_center(column, row))
+        {
+            Some(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+
+    /// The cells whose centers are inside `rect`, as ranges of columns and rows.
+    fn cells_in_rect(&self, rect: Rect) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let first = |min: f32, cell: f32| ((min / cell - 0.5).ceil().max(0.0)) as usize;
+        let end = |max: f32, cell: f32, count: usize| {
+            (((max / cell - 0.5).floor() + 1.0).max(0.0) as usize).min(count)
+        };
+        (
+            first(rect.min.x, self.cell_size.x)..end(rect.max.x, self.cell_size.x, self.columns),
+            first(rect.min.y, self.cell_size.y)..end(rect.max.y, self.cell_size.y, self.rows),
+        )
+    }
+
+    fn paint_shape(&mut self, clip_rect: Rect, shape: &Shape) {
+        match shape {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    self.paint_shape(clip_rect, shape);
+                }
+            }
+            Shape::Circle {
+                center,
+                radius,
+                fill,
+                stroke,
+            } => {
+                // Radio buttons, slider handles, …
+                // A big circle is drawn hollow, so that a small one can be drawn inside it.
+                let (ch, color) = if *radius * 2.0 < self.cell_size.x {
+                    ('●', *fill)
+                } else if stroke.width > 0.0 && stroke.color != Color32::TRANSPARENT {
+                    ('○', stroke.color)
+                } else {
+                    ('○', *fill)
+                };
+                self.paint_char(clip_rect, *center, ch, color);
+            }
+            Shape::LineSegment { points, stroke } => {
+                self.paint_line(clip_rect, points[0], points[1], *stroke);
+            }
+            Shape::Path {
+                points,
+                closed,
+                fill,
+                stroke,
+            } => {
+                if *closed && points.len() == 3 {
+                    // A triangle, e.g. in a collapsing header.
+                    let color = if *fill == Color32::TRANSPARENT {
+                        stroke.color
+                    } else {
+                        *fill
+                    };
+                    self.paint_arrow(clip_rect, points, color);
+                } else {
+                    for segment in points.windows(2) {
+                        self.paint_line(clip_rect, segment[0], segment[1], *stroke);
+                    }
+                    if *closed && points.len() > 2 {
+                        self.paint_line(clip_rect, points[points.len() - 1], points[0], *stroke);
+                    }
+                }
+            }
+            Shape::Rect {
+                rect, fill, stroke, ..
+            } => {
+                self.paint_rect(clip_rect, *rect, *fill, *stroke);
+            }
+            Shape::Text {
+                pos, galley, color, ..
+            } => {
+                self.paint_galley(clip_rect, *pos, galley, *color);
+            }
+            Shape::Mesh(_) => {
+                // Shadows and images. Not much we can do with those.
+            }
+        }
+    }
+
+    fn paint_rect(&mut self, clip_rect: Rect, rect: Rect, fill: Color32, stroke: Stroke) {
+        let (columns, rows) = self.cells_in_rect(rect.intersect(clip_rect));
+        if columns.is_empty() || rows.is_empty() {
+            return;
+        }
+
+        if fill != Color32::TRANSPARENT {
+            for row in rows.clone() {
+                for column in columns.clone() {
+                    let cell = &mut self.cells[row * self.columns + column];
+                    cell.bg = blend(fill, cell.bg);
+                    if fill.a() == 255 {
+                        cell.ch = ' ';
+                    }
+                }
+            }
+        }
+
+        // Only draw frames around things that are big enough to have a border and some content,
+        // like windows. A frame around a button would take up all of the button.
+        let (full_columns, full_rows) = self.cells_in_rect(rect);
+        let has_border = full_columns.len() >= 3 && full_rows.len() >= 3;
+        if has_border && stroke.width > 0.0 && stroke.color != Color32::TRANSPARENT {
+            let (left, right) = (full_columns.start, full_columns.end - 1);
+            let (top, bottom) = (full_rows.start, full_rows.end - 1);
+            for row in rows {
+                for column in columns.clone() {
+                    let ch = match (column, row) {
+                        (c, r) if c == left && r == top => '┌',
+                        (c, r) if c == right && r == top => '┐',
+                        (c, r) if c == left && r == bottom => '└',
+                        (c, r) if c == right && r == bottom => '┘',
+                        (_, r) if r == top || r == bottom => '─',
+                        (c, _) if c == left || c == right => '│',
+                        _ => continue,
+                    };
+                    let cell = &mut self.cells[row * self.columns + column];
+                    cell.ch = ch;
+                    cell.fg = blend(stroke.color, cell.bg);
+                }
+            }
+        }
+    }
+
+    /// Lines are drawn with box drawing characters, but only over empty cells,
+    /// so that e.g. the text cursor or a line just below some text doesn't hide the text.
+    fn paint_line(&mut self, clip_rect: Rect, a: Pos2, b: Pos2, stroke: Stroke) {
+        if stroke.width <= 0.0 || stroke.color == Color32::TRANSPARENT {
+            return;
+        }
+        let delta = b - a;
+        let ch = if delta.y.abs() <= 0.5 * delta.x.abs() {
+            '─'
+        } else if delta.x.abs() <= 0.5 * delta.y.abs() {
+            '│'
+        } else if (delta.x > 0.0) == (delta.y > 0.0) {
+            '╲'
+        } else {
+            '╱'
+        };
+
+        // Sample a few times per cell:
+        let steps = (delta.x.abs() / self.cell_size.x)
+            .max(delta.y.abs() / self.cell_size.y)
+            .mul_add(2.0, 1.0)
+            .ceil() as usize;
+        let mut indices: Vec<usize> = (0..=steps)
+            .filter_map(|i| {
+                let pos = a + delta * (i as f32 / steps as f32);
+                self.cell_index_at(clip_rect, pos)
+            })
+            .collect();
+        indices.dedup();
+
+        if indices.iter().all(|&index| self.cells[index].ch == ' ') {
+            for index in indices {
+                let cell = &mut self.cells[index];
+                cell.ch = ch;
+                cell.fg = blend(stroke.color, cell.bg);
+            }
+        }
+    }
+
+    /// Draws a polygon as an arrow pointing towards the vertex furthest from the center.
+    fn paint_arrow(&mut self, clip_rect: Rect, points: &[Pos2], fill: Color32) {
+        if points.is_empty() {
+            return;
+        }
+        let center = Pos2::ZERO
+            + points.iter().fold(Vec2::ZERO, |sum, p| sum + p.to_vec2()) / points.len() as f32;
+        let tip = points.iter().fold(Vec2::ZERO, |tip, p| {
+            let v = *p - center;
+            if v.length_sq() > tip.length_sq() {
+                v
+            } else {
+                tip
+            }
+        });
+        let ch = if tip.x.abs() > tip.y.abs() {
+            if tip.x > 0.0 {
+                '▸'
+            } else {
+                '◂'
+            }
+        } else if tip.y > 0.0 {
+            '▾'
+        } else {
+            '▴'
+        };
+        self.paint_char(clip_rect, center, ch, fill);
+    }
+
+    fn paint_char(&mut self, clip_rect: Rect, pos: Pos2, ch: char, color: Color32) {
+        if let Some(index) = self.cell_index_at(clip_rect, pos) {
+            let cell = &mut self.cells[index];
+            cell.ch = ch;
+            cell.fg = blend(color, cell.bg);
+        }
+    }
+
+    fn paint_galley(&mut self, clip_rect: Rect, pos: Pos2, galley: &Galley, color: Color32) {
+        let mut chars = galley.text.chars().enumerate();
+        for row in &galley.rows {
+            let y = pos.y + 0.5 * (row.y_min + row.y_max);
+            for x_range in row.x_offsets.windows(2) {
+                let (char_index, ch) = match chars.next() {
+                    Some(next) => next,
+                    None => return,
+                };
+                let format = galley
+                    .sections
+                    .iter()
+                    .find(|section| section.char_range.contains(&char_index))
+                    .map(|section| section.format)
+                    .unwrap_or_default();
+
+                let x = pos.x + 0.5 * (x_range[0] + x_range[1]);
+                if let Some(index) = self.cell_index_at(clip_rect, pos2(x, y)) {
+                    let cell = &mut self.cells[index];
+                    cell.bg = blend(format.background, cell.bg);
+                    cell.ch = if ch.is_control() { ' ' } else { ch };
+                    cell.fg = blend(format.color.unwrap_or(color), cell.bg);
+                }
+            }
+            if row.ends_with_newline {
+                chars.next();
+            }
+        }
+    }
+
+    fn write_changes(&mut self, out: &mut impl Write) -> std::io::Result<()> {
+        let full_redraw = self.on_screen.len() != self.cells.len();
+        let mut cursor_index = None;
+        let mut colors = None;
+
+        for (index, cell) in self.cells.iter().enumerate() {
+            if !full_redraw && self.on_screen[index] == *cell {
+                continue;
+            }
+            if cursor_index != Some(index) {
+                let (column, row) = (index % self.columns, index / self.columns);
+                queue!(out, cursor::MoveTo(column as u16, row as u16))?;
+            }
+            if colors != Some((cell.fg, cell.bg)) {
+                colors = Some((cell.fg, cell.bg));
+                queue!(
+                    out,
+                    style::SetColors(style::Colors::new(
+                        terminal_color(cell.fg),
+                        terminal_color(cell.bg)
+                    ))
+                )?;
+            }
+            queue!(out, style::Print(cell.ch))?;
+            // Don't rely on the cursor wrapping at the end of a row:
+            cursor_index = if (index + 1) % self.columns == 0 {
+                None
+            } else {
+                Some(index + 1)
+            };
+        }
+
+        self.on_screen.clone_from(&self.cells);
+        out.flush()
+    }
+}
+
+/// Blend the premultiplied `color` over the opaque `background`.
+fn blend(color: Color32, background: Color32) -> Color32 {
+    let alpha = color.a() as u32;
+    let channel = |c: u8, b: u8| (c as u32 + b as u32 * (255 - alpha) / 255).min(255) as u8;
+    Color32::from_rgb(
+        channel(color.r(), background.r()),
+        channel(color.g(), background.g()),
+        channel(color.b(), background.b()),
+    )
+}
+
+fn terminal_color(color: Color32) -> style::Color {
+    style::Color::Rgb {
+        r: color.r(),
+        g: color.g(),
+        b: color.b(),
+    }
+}
+
+#[test]
+fn test_paint_shapes() {
+    use egui::epaint::emath::vec2;
+
+    let mut painter = Painter::new(vec2(8.0, 16.0), 10, 4);
+    let shapes = vec![
+        ClippedShape(
+            Rect::EVERYTHING,
+            Shape::rect_filled(
+                Rect::from_min_size(pos2(8.0, 16.0), vec2(24.0, 16.0)),
+                0.0,
+                Color32::RED,
+            ),
+        ),
+        ClippedShape(
+            Rect::EVERYTHING,
+            Shape::line_segment([pos2(0.0, 56.0), pos2(80.0, 56.0)], (1.0, Color32::WHITE)),
+        ),
+        // Clipped away:
+        ClippedShape(
+            Rect::from_min_size(Pos2::ZERO, vec2(80.0, 16.0)),
+            Shape::circle_filled(pos2(4.0, 24.0), 2.0, Color32::GREEN),
+        ),
+    ];
+
+    let mut out = vec![];
+    painter
+        .paint_shapes(&mut out, Color32::BLACK, &shapes)
+        .unwrap();
+    let cells = painter.cells();
+    let row = |r: usize| -> String { cells[r * 10..(r + 1) * 10].iter().map(|c| c.ch).collect() };
+    assert_eq!(row(0), "          ");
+    assert_eq!(row(1), "          ");
+    assert_eq!(row(3), "──────────");
+    assert_eq!(cells[10].bg, Color32::BLACK);
+    assert_eq!(cells[11].bg, Color32::RED);
+    assert_eq!(cells[13].bg, Color32::RED);
+    assert_eq!(cells[14].bg, Color32::BLACK);
+
+    // Nothing changed, so nothing is written:
+    out.clear();
+    painter
+        .paint_shapes(&mut out, Color32::BLACK, &shapes)
+        .unwrap();
+    assert!(out.is_empty());
+}
@@ -9,6 +9,8 @@ pub struct WebBackend {
     painter: Box<dyn Painter>,
     previous_frame_time: Option<f32>,
     frame_start: Option<f64>,
+    /// See [`egui::Output::read_pixels`].
+    read_pixels: Option<egui::Rect>,
 }
 
 impl WebBackend {
@@ -24,11 +26,22 @@ impl WebBackend {
                 Box::new(webgl1::WebGlPainter::new(canvas_id)?)
             };
 
+        let user_texels = painter.user_texels();
+        ctx.set_texel_reader(move |texture_id, [x, y]| match texture_id {
+            egui::TextureId::User(id) => {
+                let user_texels = user_texels.lock().unwrap();
+                let image = user_texels.get(&id)?;
+                (x < image.width() && y < image.height()).then(|| image[(x, y)])
+            }
+            egui::TextureId::Egui => None,
+        });
+
         Ok(Self {
             egui_ctx: ctx,
             painter,
             previous_frame_time: None,
             frame_start: None,
+            read_pixels: None,
         })
     }
 
@@ -37,8 +50,9 @@ impl WebBackend {
         self.painter.canvas_id()
     }
 
-    pub fn begin_frame(&mut self, raw_input: egui::RawInput) {
+    pub fn begin_frame(&mut self, mut raw_input: egui::RawInput) {
         self.frame_start = Some(now_sec());
+        raw_input.previous_frame_time = raw_input.previous_frame_time.or(self.previous_frame_time);
         self.egui_ctx.begin_frame(raw_input)
     }
 
@@ -49,6 +63,7 @@ impl WebBackend {
             .expect("unmatched calls to begin_frame/end_frame");
 
         let (output, shapes) = self.egui_ctx.end_frame();
+        self.read_pixels = output.read_pixels;
         let clipped_meshes = self.egui_ctx.tessellate(shapes);
 
         let now = now_sec();
@@ -65,7 +80,44 @@ impl WebBackend {
         self.painter.upload_egui_texture(&self.egui_ctx.texture());
         self.painter.clear(clear_color);
         self.painter
-            .paint_meshes(clipped_meshes, self.egui_ctx.pixels_per_point())
+            .paint_meshes(clipped_meshes, self.egui_ctx.pixels_per_point())?;
+        self.read_pixels()
+    }
+
+    /// Reads back the pixels egui asked for, see [`egui::Output::read_pixels`].
+    fn read_pixels(&mut self) -> Result<(), JsValue> {
+        let rect = match self.read_pixels.take() {
+            Some(rect) => rect,
+            None => return Ok(()),
+        };
+        let pixels_per_point = self.egui_ctx.pixels_per_point();
+        let canvas_size = (self.egui_ctx.input().screen_rect().size() * pixels_per_point).floor();
+        let to_pixel =
+            |points: f32, size: f32| (points * pixels_per_point).round().clamp(0.0, size);
+        let (min_x, max_x) = (
+            to_pixel(rect.min.x, canvas_size.x),
+            to_pixel(rect.max.x, canvas_size.x),
+        );
+        let (min_y, max_y) = (
+            to_pixel(rect.min.y, canvas_size.y),
+            to_pixel(rect.max.y, canvas_size.y),
+        );
+        if min_x >= max_x || min_y >= max_y {
+            return Ok(());
+        }
+        let (width, height) = ((max_x - min_x) as u32, (max_y - min_y) as u32);
+
+        let rgba = self
+            .painter
+            .read_pixels([min_x as u32, min_y as u32, width, height])?;
+        let image =
+            egui::ColorImage::from_rgba_premultiplied([width as usize, height as usize], &rgba);
+        let rect = egui::Rect::from_min_max(
+            egui::pos2(min_x / pixels_per_point, min_y / pixels_per_point),
+            egui::pos2(max_x / pixels_per_point, max_y / pixels_per_point),
+        );
+        self.egui_ctx.set_screen_pixels(rect, image);
+        Ok(())
     }
 
     pub fn painter_debug_info(&self) -> String {
@@ -87,13 +139,26 @@ pub struct WebInput {
     /// Required to maintain a stable touch position for multi-touch gestures.
     pub latest_touch_pos_id: Option<egui::TouchId>,
 
+    /// The safe area insets are only looked up again when the canvas changes size
+    /// (e.g. when the phone is rotated), since that needs a style recalculation.
+    safe_area_insets: Option<(egui::Vec2, egui::SafeAreaInsets)>,
+
     pub raw: egui::RawInput,
 }
 
 impl WebInput {
     pub fn new_frame(&mut self, canvas_size: egui::Vec2) -> egui::RawInput {
+        let safe_area_insets = match self.safe_area_insets {
+            Some((size, insets)) if size == canvas_size => insets,
+            _ => {
+                let insets = safe_area_insets().unwrap_or_default();
+                self.safe_area_insets = Some((canvas_size, insets));
+                insets
+            }
+        };
         egui::RawInput {
             screen_rect: Some(egui::Rect::from_min_size(Default::default(), canvas_size)),
+            safe_area_insets,
             pixels_per_point: Some(native_pixels_per_point()), // We ALWAYS use the native pixels-per-point
             time: Some(now_sec()),
             ..self.raw.take()
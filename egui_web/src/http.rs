@@ -13,7 +13,13 @@ pub async fn fetch_async(request: &Request) -> Result<Response, String> {
 /// NOTE: Ok(..) is returned on network error.
 /// Err is only for failure to use the fetch api.
 async fn fetch_jsvalue(request: &Request) -> Result<Response, JsValue> {
-    let Request { method, url, body } = request;
+    let Request {
+        method,
+        url,
+        body,
+        headers,
+        max_bytes,
+    } = request;
 
     // https://rustwasm.github.io/wasm-bindgen/examples/fetch.html
 
@@ -30,6 +36,9 @@ async fn fetch_jsvalue(request: &Request) -> Result<Response, JsValue> {
 
     let request = web_sys::Request::new_with_str_and_init(url, &opts)?;
     request.headers().set("Accept", "*/*")?;
+    for (name, value) in headers {
+        request.headers().set(name, value)?;
+    }
 
     let window = web_sys::window().unwrap();
     let response = JsFuture::from(window.fetch_with_request(&request)).await?;
@@ -41,9 +50,29 @@ async fn fetch_jsvalue(request: &Request) -> Result<Response, JsValue> {
     // let body = JsFuture::from(response.text()?).await?;
     // let body = body.as_string().unwrap_or_default();
 
-    let array_buffer = JsFuture::from(response.array_buffer()?).await?;
-    let uint8_array = js_sys::Uint8Array::new(&array_buffer);
-    let bytes = uint8_array.to_vec();
+    let content_length = response
+        .headers()
+        .get("Content-Length")
+        .ok()
+        .flatten()
+        .and_then(|length| length.trim().parse::<usize>().ok());
+    let too_large = match (max_bytes, content_length) {
+        (Some(max_bytes), Some(content_length)) => content_length > *max_bytes,
+        _ => false,
+    };
+    let bytes = if too_large {
+        vec![]
+    } else {
+        let array_buffer = JsFuture::from(response.array_buffer()?).await?;
+        let uint8_array = js_sys::Uint8Array::new(&array_buffer);
+        // The browser has read it all, but we only keep one byte more than allowed:
+        let len = max_bytes.map_or(uint8_array.length(), |max_bytes| {
+            uint8_array
+                .length()
+                .min((max_bytes as u32).saturating_add(1))
+        });
+        uint8_array.subarray(0, len).to_vec()
+    };
 
     let header_content_type = response
         .headers()
@@ -52,6 +81,18 @@ async fn fetch_jsvalue(request: &Request) -> Result<Response, JsValue> {
         .flatten()
         .unwrap_or_default();
 
+    // Only the headers the server exposes to scripts are visible here:
+    let mut headers = std::collections::BTreeMap::new();
+    if let Some(entries) = js_sys::try_iter(&response.headers())? {
+        for entry in entries {
+            let entry: js_sys::Array = entry?.into();
+            if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string())
+            {
+                headers.insert(name.to_lowercase(), value);
+            }
+        }
+    }
+
     let text = if header_content_type.starts_with("text")
         || header_content_type == "application/javascript"
     {
@@ -66,6 +107,7 @@ async fn fetch_jsvalue(request: &Request) -> Result<Response, JsValue> {
         ok: response.ok(),
         status: response.status(),
         header_content_type,
+        headers,
         bytes,
         text,
     })
@@ -35,7 +35,7 @@ use egui::mutex::Mutex;
 pub use wasm_bindgen;
 pub use web_sys;
 
-pub use painter::Painter;
+pub use painter::{Painter, UserTexels};
 use std::cell::Cell;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -94,6 +94,55 @@ pub fn native_pixels_per_point() -> f32 {
     }
 }
 
+/// The CSS `env(safe-area-inset-*)` values, e.g. for the notch of a phone.
+///
+/// These are only non-zero if the page has `viewport-fit=cover` in its viewport `<meta>` tag.
+pub fn safe_area_insets() -> Option<egui::SafeAreaInsets> {
+    use wasm_bindgen::JsCast;
+    const PROBE_ID: &str = "egui_safe_area_probe";
+
+    let window = web_sys::window()?;
+    let document = window.document()?;
+    // The `env()` values can only be read by applying them to an element:
+    let probe = if let Some(probe) = document.get_element_by_id(PROBE_ID) {
+        probe
+    } else {
+        let probe = document
+            .create_element("div")
+            .ok()?
+            .dyn_into::<web_sys::HtmlElement>()
+            .ok()?;
+        probe.set_id(PROBE_ID);
+        let style = probe.style();
+        style.set_property("position", "fixed").ok()?;
+        style.set_property("visibility", "hidden").ok()?;
+        style.set_property("pointer-events", "none").ok()?;
+        style
+            .set_property(
+                "padding",
+                "env(safe-area-inset-top) env(safe-area-inset-right) \
+                env(safe-area-inset-bottom) env(safe-area-inset-left)",
+            )
+            .ok()?;
+        document.body()?.append_child(&probe).ok()?;
+        probe.into()
+    };
+
+    let style = window.get_computed_style(&probe).ok()??;
+    let padding = |side: &str| -> Option<f32> {
+        let value = style
+            .get_property_value(&format!("padding-{}", side))
+            .ok()?;
+        value.trim().trim_end_matches("px").parse().ok()
+    };
+    Some(egui::SafeAreaInsets {
+        left: padding("left")?,
+        right: padding("right")?,
+        top: padding("top")?,
+        bottom: padding("bottom")?,
+    })
+}
+
 pub fn prefer_dark_mode() -> Option<bool> {
     Some(
         web_sys::window()?
@@ -180,6 +229,11 @@ fn canvas_origin(canvas_id: &str) -> egui::Pos2 {
     egui::Pos2::new(rect.left() as f32, rect.top() as f32)
 }
 
+/// When the event happened, on the same clock as [`now_sec`].
+fn event_time(event: &web_sys::Event) -> f64 {
+    event.time_stamp() / 1000.0
+}
+
 fn push_touches(runner: &mut AppRunner, phase: egui::TouchPhase, event: &web_sys::TouchEvent) {
     let canvas_origin = canvas_origin(runner.canvas_id());
     for touch_idx in 0..event.changed_touches().length() {
@@ -314,8 +368,12 @@ pub fn handle_output(output: &egui::Output, runner: &mut AppRunner) {
         open_url,
         copied_text,
         needs_repaint: _, // handled elsewhere
+        repaint_after: _, // we repaint right away instead
         events: _,        // we ignore these (TODO: accessibility screen reader)
+        speech: _,        // the screen reader speaks `events_description` instead
         text_cursor_pos,
+        text_cursor_rect: _, // we position the text agent at `text_cursor_pos` instead
+        read_pixels: _,      // handled by `WebBackend::paint`
     } = output;
 
     set_cursor_icon(*cursor_icon);
@@ -856,16 +914,15 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
                 if let Some(button) = button_from_mouse_event(&event) {
                     let pos = pos_from_mouse_event(runner_lock.canvas_id(), &event);
                     let modifiers = runner_lock.input.raw.modifiers;
-                    runner_lock
-                        .input
-                        .raw
-                        .events
-                        .push(egui::Event::PointerButton {
+                    runner_lock.input.raw.push_event_at(
+                        egui::Event::PointerButton {
                             pos,
                             button,
                             pressed: true,
                             modifiers,
-                        });
+                        },
+                        event_time(&event),
+                    );
                     runner_lock.needs_repaint.set_true();
                     event.stop_propagation();
                     event.prevent_default();
@@ -886,8 +943,7 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
                 runner_lock
                     .input
                     .raw
-                    .events
-                    .push(egui::Event::PointerMoved(pos));
+                    .push_event_at(egui::Event::PointerMoved(pos), event_time(&event));
                 runner_lock.needs_repaint.set_true();
                 event.stop_propagation();
                 event.prevent_default();
@@ -906,16 +962,15 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
                 if let Some(button) = button_from_mouse_event(&event) {
                     let pos = pos_from_mouse_event(runner_lock.canvas_id(), &event);
                     let modifiers = runner_lock.input.raw.modifiers;
-                    runner_lock
-                        .input
-                        .raw
-                        .events
-                        .push(egui::Event::PointerButton {
+                    runner_lock.input.raw.push_event_at(
+                        egui::Event::PointerButton {
                             pos,
                             button,
                             pressed: false,
                             modifiers,
-                        });
+                        },
+                        event_time(&event),
+                    );
                     runner_lock.needs_repaint.set_true();
                     event.stop_propagation();
                     event.prevent_default();
@@ -943,6 +998,76 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         closure.forget();
     }
 
+    {
+        // Text from another application is being dragged over the canvas.
+        let event_name = "dragover";
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move |event: web_sys::DragEvent| {
+            let mut runner_lock = runner_ref.0.lock();
+            let has_text = event.data_transfer().map_or(false, |data_transfer| {
+                data_transfer.types().includes(&"text/plain".into(), 0)
+            });
+            if has_text {
+                let pos = pos_from_mouse_event(runner_lock.canvas_id(), &event);
+                runner_lock.input.raw.hovered_text = true;
+                runner_lock
+                    .input
+                    .raw
+                    .events
+                    .push(egui::Event::PointerMoved(pos));
+                runner_lock.needs_repaint.set_true();
+                event.stop_propagation();
+                event.prevent_default(); // Allow dropping
+            }
+        }) as Box<dyn FnMut(_)>);
+        canvas.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    {
+        let event_name = "dragleave";
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move |_event: web_sys::DragEvent| {
+            let mut runner_lock = runner_ref.0.lock();
+            runner_lock.input.raw.hovered_text = false;
+            runner_lock.input.raw.events.push(egui::Event::PointerGone);
+            runner_lock.needs_repaint.set_true();
+        }) as Box<dyn FnMut(_)>);
+        canvas.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    {
+        let event_name = "drop";
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move |event: web_sys::DragEvent| {
+            let mut runner_lock = runner_ref.0.lock();
+            runner_lock.input.raw.hovered_text = false;
+            if let Some(data_transfer) = event.data_transfer() {
+                if let Ok(text) = data_transfer.get_data("text/plain") {
+                    if !text.is_empty() {
+                        let pos = pos_from_mouse_event(runner_lock.canvas_id(), &event);
+                        runner_lock
+                            .input
+                            .raw
+                            .events
+                            .push(egui::Event::PointerMoved(pos));
+                        runner_lock
+                            .input
+                            .raw
+                            .events
+                            .push(egui::Event::DroppedText(text));
+                    }
+                }
+            }
+            runner_lock.needs_repaint.set_true();
+            event.stop_propagation();
+            event.prevent_default();
+        }) as Box<dyn FnMut(_)>);
+        canvas.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
     {
         let event_name = "touchstart";
         let runner_ref = runner_ref.clone();
@@ -955,16 +1080,15 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
             runner_lock.input.latest_touch_pos = Some(pos);
             runner_lock.input.is_touch = true;
             let modifiers = runner_lock.input.raw.modifiers;
-            runner_lock
-                .input
-                .raw
-                .events
-                .push(egui::Event::PointerButton {
+            runner_lock.input.raw.push_event_at(
+                egui::Event::PointerButton {
                     pos,
                     button: egui::PointerButton::Primary,
                     pressed: true,
                     modifiers,
-                });
+                },
+                event_time(&event),
+            );
 
             push_touches(&mut *runner_lock, egui::TouchPhase::Start, &event);
             runner_lock.needs_repaint.set_true();
@@ -989,8 +1113,7 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
             runner_lock
                 .input
                 .raw
-                .events
-                .push(egui::Event::PointerMoved(pos));
+                .push_event_at(egui::Event::PointerMoved(pos), event_time(&event));
 
             push_touches(&mut *runner_lock, egui::TouchPhase::Move, &event);
             runner_lock.needs_repaint.set_true();
@@ -1010,16 +1133,15 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
             if let Some(pos) = runner_lock.input.latest_touch_pos {
                 let modifiers = runner_lock.input.raw.modifiers;
                 // First release mouse to click:
-                runner_lock
-                    .input
-                    .raw
-                    .events
-                    .push(egui::Event::PointerButton {
+                runner_lock.input.raw.push_event_at(
+                    egui::Event::PointerButton {
                         pos,
                         button: egui::PointerButton::Primary,
                         pressed: false,
                         modifiers,
-                    });
+                    },
+                    event_time(&event),
+                );
                 // Then remove hover effect:
                 runner_lock.input.raw.events.push(egui::Event::PointerGone);
 
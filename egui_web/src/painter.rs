@@ -1,5 +1,12 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 use wasm_bindgen::prelude::JsValue;
 
+/// The texels of user textures by id, see [`Painter::user_texels`].
+pub type UserTexels = Arc<Mutex<HashMap<u64, egui::ColorImage>>>;
+
 pub trait Painter {
     fn as_tex_allocator(&mut self) -> &mut dyn epi::TextureAllocator;
 
@@ -8,6 +15,9 @@ pub trait Painter {
     /// id of the canvas html element containing the rendering
     fn canvas_id(&self) -> &str;
 
+    /// The texels of the user textures allocated with pixels, for [`egui::Context::set_texel_reader`].
+    fn user_texels(&self) -> UserTexels;
+
     fn upload_egui_texture(&mut self, texture: &egui::Texture);
 
     fn clear(&mut self, clear_color: egui::Rgba);
@@ -17,4 +27,8 @@ pub trait Painter {
         clipped_meshes: Vec<egui::ClippedMesh>,
         pixels_per_point: f32,
     ) -> Result<(), JsValue>;
+
+    /// The sRGBA bytes (premultiplied) of what was just painted within `[x, y, width, height]`,
+    /// in pixels from the top left, row by row from the top.
+    fn read_pixels(&self, rect: [u32; 4]) -> Result<Vec<u8>, JsValue>;
 }
@@ -26,6 +26,9 @@ pub struct WebGlPainter {
 
     /// `None` means unallocated (freed) slot.
     user_textures: Vec<Option<UserTexture>>,
+
+    /// The texels of the user textures with pixels, for [`crate::Painter::user_texels`].
+    user_texels: crate::UserTexels,
 }
 
 #[derive(Default)]
@@ -86,6 +89,7 @@ impl WebGlPainter {
             egui_texture,
             egui_texture_version: None,
             user_textures: Default::default(),
+            user_texels: Default::default(),
         })
     }
 
@@ -110,6 +114,13 @@ impl WebGlPainter {
         assert_eq!(size.0 * size.1, srgba_pixels.len());
 
         if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
+            self.user_texels.lock().unwrap().insert(
+                index as u64,
+                egui::ColorImage {
+                    size: [size.0, size.1],
+                    pixels: srgba_pixels.to_vec(),
+                },
+            );
             let mut pixels: Vec<u8> = Vec::with_capacity(srgba_pixels.len() * 4);
             for srgba in srgba_pixels {
                 pixels.push(srgba.r());
@@ -130,6 +141,7 @@ impl WebGlPainter {
 
     fn free_user_texture(&mut self, id: egui::TextureId) {
         if let egui::TextureId::User(id) = id {
+            self.user_texels.lock().unwrap().remove(&id);
             let index = id as usize;
             if index < self.user_textures.len() {
                 self.user_textures[index] = None;
@@ -356,6 +368,31 @@ impl crate::Painter for WebGlPainter {
         &self.canvas_id
     }
 
+    fn user_texels(&self) -> crate::UserTexels {
+        self.user_texels.clone()
+    }
+
+    fn read_pixels(&self, [x, y, width, height]: [u32; 4]) -> Result<Vec<u8>, JsValue> {
+        let mut rgba = vec![0_u8; (width * height * 4) as usize];
+        // WebGL counts from the bottom:
+        let bottom = self.canvas.height() as i32 - (y + height) as i32;
+        self.gl.read_pixels_with_opt_u8_array(
+            x as i32,
+            bottom,
+            width as i32,
+            height as i32,
+            Gl::RGBA,
+            Gl::UNSIGNED_BYTE,
+            Some(&mut rgba),
+        )?;
+        Ok(rgba
+            .chunks_exact(4 * width as usize)
+            .rev()
+            .flatten()
+            .copied()
+            .collect())
+    }
+
     fn upload_egui_texture(&mut self, texture: &Texture) {
         if self.egui_texture_version == Some(texture.version) {
             return; // No change
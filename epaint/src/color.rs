@@ -3,6 +3,10 @@
 //! If you want a compact color representation, use [`Color32`].
 //! If you want to manipulate RGBA colors use [`Rgba`].
 //! If you want to manipulate colors in a way closer to how humans think about colors, use [`HsvaGamma`].
+//!
+//! [`Rgba`] and [`Hsva`] can also hold HDR (high dynamic range) colors, with values above `1.0`.
+//! Use [`Rgba::to_color32_tone_mapped`] to turn them into a displayable [`Color32`]
+//! without just clamping them.
 
 #![allow(clippy::wrong_self_convention)]
 
@@ -174,6 +178,8 @@ impl Color32 {
 // ----------------------------------------------------------------------------
 
 /// 0-1 linear space `RGBA` color with premultiplied alpha.
+///
+/// The color channels may go above 1 for HDR colors, see [`ToneMapping`].
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct Rgba(pub(crate) [f32; 4]);
@@ -281,6 +287,44 @@ impl Rgba {
         0.3 * self.r() + 0.59 * self.g() + 0.11 * self.b()
     }
 
+    /// Is any color channel brighter than `1.0`, i.e. can this not be shown without clamping?
+    pub fn is_hdr(&self) -> bool {
+        self.r() > 1.0 || self.g() > 1.0 || self.b() > 1.0
+    }
+
+    /// Scale the color channels (but not alpha) by `2^stops`.
+    ///
+    /// Use this to preview a part of an HDR image that is too bright (negative `stops`) or too dark.
+    pub fn exposure(self, stops: f32) -> Self {
+        let factor = 2_f32.powf(stops);
+        let [r, g, b, a] = self.0;
+        Self([factor * r, factor * g, factor * b, a])
+    }
+
+    /// Map this (possibly HDR) color into the displayable `[0, 1]` range using the given [`ToneMapping`].
+    pub fn tone_mapped(self, tone_mapping: ToneMapping) -> Self {
+        if tone_mapping == ToneMapping::Clamp {
+            return self; // Clamping is done when converting to `Color32`
+        }
+        let [r, g, b, _] = self.to_opaque().0;
+        let (r, g, b) = (
+            tone_mapping.map(r),
+            tone_mapping.map(g),
+            tone_mapping.map(b),
+        );
+        let a = self.a();
+        if a == 0.0 {
+            Self([r, g, b, 0.0]) // additive
+        } else {
+            Self([a * r, a * g, a * b, a])
+        }
+    }
+
+    /// Convert a (possibly HDR) color to a [`Color32`] using the given [`ToneMapping`].
+    pub fn to_color32_tone_mapped(self, tone_mapping: ToneMapping) -> Color32 {
+        self.tone_mapped(tone_mapping).into()
+    }
+
     /// Returns an opaque version of self
     pub fn to_opaque(&self) -> Self {
         if self.a() == 0.0 {
@@ -391,6 +435,71 @@ impl From<Rgba> for Color32 {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// How to map HDR colors (with values above `1.0`) into the displayable `[0, 1]` range.
+///
+/// See [`Rgba::tone_mapped`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum ToneMapping {
+    /// Clamp each channel to `[0, 1]`. Colors that are already in range are unchanged.
+    Clamp,
+
+    /// `x / (1 + x)`: simple, and never clips, but darkens everything.
+    Reinhard,
+
+    /// An approximation of the ACES filmic curve, popular in games.
+    AcesFilmic,
+}
+
+impl Default for ToneMapping {
+    fn default() -> Self {
+        Self::Clamp
+    }
+}
+
+impl ToneMapping {
+    /// Map one linear color channel into `[0, 1]`.
+    pub fn map(self, linear: f32) -> f32 {
+        let x = linear.max(0.0);
+        match self {
+            Self::Clamp => x.min(1.0),
+            Self::Reinhard => x / (1.0 + x),
+            Self::AcesFilmic => {
+                // By Krzysztof Narkowicz, https://knarkowicz.wordpress.com/2016/01/06/aces-filmic-tone-mapping-curve/
+                let (a, b, c, d, e) = (2.51, 0.03, 2.43, 0.59, 0.14);
+                ((x * (a * x + b)) / (x * (c * x + d) + e)).clamp(0.0, 1.0)
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tone_mapping() {
+    for &tone_mapping in &[
+        ToneMapping::Clamp,
+        ToneMapping::Reinhard,
+        ToneMapping::AcesFilmic,
+    ] {
+        assert_eq!(tone_mapping.map(0.0), 0.0);
+        assert_eq!(tone_mapping.map(-1.0), 0.0);
+        let mut previous = 0.0;
+        for i in 1..100 {
+            let mapped = tone_mapping.map(i as f32 * 0.1);
+            assert!(previous <= mapped && mapped <= 1.0);
+            previous = mapped;
+        }
+    }
+
+    let hdr = Rgba::from_rgb(4.0, 1.0, 0.25);
+    assert!(hdr.is_hdr());
+    assert!(!hdr.tone_mapped(ToneMapping::Reinhard).is_hdr());
+    assert_eq!(hdr.exposure(-2.0), Rgba::from_rgb(1.0, 0.25, 0.0625));
+}
+
+// ----------------------------------------------------------------------------
+
 /// gamma [0, 255] -> linear [0, 1].
 pub fn linear_f32_from_gamma_u8(s: u8) -> f32 {
     if s <= 10 {
@@ -463,7 +572,8 @@ pub fn gamma_from_linear(linear: f32) -> f32 {
 
 // ----------------------------------------------------------------------------
 
-/// Hue, saturation, value, alpha. All in the range [0, 1].
+/// Hue, saturation, value, alpha. All in the range [0, 1],
+/// except for HDR colors where the value can be above 1.
 /// No premultiplied alpha.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Hsva {
@@ -471,7 +581,7 @@ pub struct Hsva {
     pub h: f32,
     /// saturation 0-1
     pub s: f32,
-    /// value 0-1
+    /// value 0-1, or above 1 for HDR colors
     pub v: f32,
     /// alpha 0-1. A negative value signifies an additive color (and alpha is ignored).
     pub a: f32,
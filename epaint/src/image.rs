@@ -0,0 +1,119 @@
+//! [`ColorImage`]: an image of colors, e.g. pixels read back from the screen.
+
+use crate::{Color32, Rgba};
+
+/// An image of sRGBA pixels with premultiplied alpha, row by row, top to bottom.
+#[derive(Clone, Default, PartialEq)]
+pub struct ColorImage {
+    /// Width and height, in pixels.
+    pub size: [usize; 2],
+    pub pixels: Vec<Color32>,
+}
+
+impl ColorImage {
+    /// An image of the given size, filled with `color`.
+    pub fn new(size: [usize; 2], color: Color32) -> Self {
+        Self {
+            size,
+            pixels: vec![color; size[0] * size[1]],
+        }
+    }
+
+    /// An image from sRGBA bytes with premultiplied alpha, four per pixel, row by row, top to bottom.
+    ///
+    /// This is what you get when reading back what egui painted.
+    pub fn from_rgba_premultiplied(size: [usize; 2], rgba: &[u8]) -> Self {
+        assert_eq!(size[0] * size[1] * 4, rgba.len());
+        let pixels = rgba
+            .chunks_exact(4)
+            .map(|p| Color32::from_rgba_premultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+        Self { size, pixels }
+    }
+
+    /// The pixels within `size` from `min`, cut off at the edges of the image.
+    pub fn region(&self, [x, y]: [usize; 2], [width, height]: [usize; 2]) -> Self {
+        let x = x.min(self.width());
+        let y = y.min(self.height());
+        let width = width.min(self.width() - x);
+        let height = height.min(self.height() - y);
+        let pixels = (y..y + height)
+            .flat_map(|row| {
+                let start = row * self.width() + x;
+                self.pixels[start..start + width].iter().copied()
+            })
+            .collect();
+        Self {
+            size: [width, height],
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.size[0]
+    }
+
+    pub fn height(&self) -> usize {
+        self.size[1]
+    }
+
+    /// The pixels as sRGBA with unmultiplied alpha, four bytes per pixel, e.g. for saving as a PNG.
+    pub fn to_srgba_unmultiplied(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&color| {
+                let Rgba([r, g, b, a]) = Rgba::from(color);
+                if a > 0.0 {
+                    let unmultiplied = Color32::from(Rgba([r / a, g / a, b / a, a]));
+                    unmultiplied.to_array()
+                } else {
+                    [0, 0, 0, 0]
+                }
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Debug for ColorImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColorImage")
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for ColorImage {
+    type Output = Color32;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Color32 {
+        assert!(x < self.width());
+        assert!(y < self.height());
+        &self.pixels[y * self.width() + x]
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for ColorImage {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Color32 {
+        assert!(x < self.width());
+        assert!(y < self.height());
+        let width = self.width();
+        &mut self.pixels[y * width + x]
+    }
+}
+
+#[test]
+fn test_color_image_region() {
+    let rgba: Vec<u8> = (0..4 * 3 * 4).map(|i| i as u8).collect();
+    let image = ColorImage::from_rgba_premultiplied([4, 3], &rgba);
+    assert_eq!(
+        image[(1, 2)],
+        Color32::from_rgba_premultiplied(36, 37, 38, 39)
+    );
+
+    let region = image.region([1, 1], [2, 5]);
+    assert_eq!(region.size, [2, 2], "cut off at the bottom");
+    assert_eq!(region[(0, 0)], image[(1, 1)]);
+    assert_eq!(region[(1, 1)], image[(2, 2)]);
+}
@@ -80,11 +80,15 @@
 #![allow(clippy::manual_range_contains)]
 
 pub mod color;
+mod image;
 mod mesh;
 pub mod mutex;
+pub mod remote;
 mod shadow;
 mod shape;
 pub mod shape_transform;
+#[cfg(feature = "software_rasterizer")]
+pub mod software_rasterizer;
 pub mod stats;
 mod stroke;
 pub mod tessellator;
@@ -92,7 +96,8 @@ pub mod text;
 mod texture_atlas;
 
 pub use {
-    color::{Color32, Rgba},
+    color::{Color32, Rgba, ToneMapping},
+    image::ColorImage,
     mesh::{Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shape::Shape,
@@ -114,6 +119,7 @@ pub const WHITE_UV: emath::Pos2 = emath::pos2(0.0, 0.0);
 
 /// What texture to use in a [`Mesh`] mesh.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub enum TextureId {
     /// The egui font texture.
     /// If you don't want to use a texture, pick this and the [`WHITE_UV`] for uv-coord.
@@ -153,7 +159,8 @@ pub struct ClippedShape(
 /// A [`Mesh`] within a clip rectangle.
 ///
 /// Everything is using logical points.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedMesh(
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Mesh`] that falls within this.
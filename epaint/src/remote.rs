@@ -0,0 +1,337 @@
+//! Send the painted output of egui to another machine, see [`FrameEncoder`] and [`FrameDecoder`].
+//!
+//! This lets you run an egui app on one machine and show it on another,
+//! e.g. for remote debugging or for thin clients.
+//! Each frame is encoded relative to the previous one:
+//! meshes that did not change are sent as a reference to last frame,
+//! and only the rows of the font texture that changed are sent.
+//!
+//! With the `persistence` feature, [`FrameDelta`] can be serialized with `serde`.
+//!
+//! ``` ignore
+//! // On the machine running the app:
+//! let (output, shapes) = ctx.end_frame();
+//! let meshes = ctx.tessellate(shapes);
+//! let delta = encoder.encode(&ctx.texture(), &meshes);
+//! send(&delta);
+//!
+//! // On the machine showing the app:
+//! let delta = receive();
+//! if decoder.decode(delta).is_err() {
+//!     request_full_frame(); // the sender should call `encoder.reset()`
+//! }
+//! painter.paint_meshes(decoder.meshes(), decoder.font_texture());
+//! ```
+//!
+//! Meshes using [`TextureId::User`] are sent as is,
+//! so the receiver must know about those textures by other means.
+
+use std::collections::HashMap;
+
+use crate::{ClippedMesh, Texture};
+
+/// One frame of output, encoded relative to the previous frame. Created by [`FrameEncoder::encode`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct FrameDelta {
+    /// Set if the font texture changed since the previous frame.
+    pub font_texture: Option<TextureDelta>,
+
+    /// The meshes of this frame, in paint order.
+    pub meshes: Vec<MeshDelta>,
+}
+
+/// A change to the font texture.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextureDelta {
+    /// The new [`Texture::version`].
+    pub version: u64,
+    pub width: usize,
+    pub height: usize,
+    /// The first row of `pixels`.
+    pub first_row: usize,
+    /// Replaces the texture pixels from `first_row` and down.
+    /// Always a whole number of rows.
+    pub pixels: Vec<u8>,
+}
+
+/// One mesh of a [`FrameDelta`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum MeshDelta {
+    /// Same as the mesh with this index in the previous frame.
+    Unchanged(usize),
+    /// A mesh that was not in the previous frame.
+    New(ClippedMesh),
+}
+
+// ----------------------------------------------------------------------------
+
+/// Encodes each frame relative to the previous one. Lives on the machine running the app.
+#[derive(Clone, Default)]
+pub struct FrameEncoder {
+    font_texture: Option<Texture>,
+    meshes: Vec<ClippedMesh>,
+    /// Mesh hash to index in `meshes`.
+    mesh_indices: HashMap<u64, usize>,
+}
+
+impl FrameEncoder {
+    /// Encode a frame, and remember it for the next one.
+    ///
+    /// The resulting [`FrameDelta`] must be decoded by a [`FrameDecoder`]
+    /// that has decoded all the previous deltas from this encoder.
+    pub fn encode(&mut self, font_texture: &Texture, meshes: &[ClippedMesh]) -> FrameDelta {
+        let font_texture_delta = texture_delta(self.font_texture.as_ref(), font_texture);
+        if font_texture_delta.is_some() {
+            self.font_texture = Some(font_texture.clone());
+        }
+
+        let mut mesh_indices = HashMap::with_capacity(meshes.len());
+        let mesh_deltas = meshes
+            .iter()
+            .enumerate()
+            .map(|(i, clipped_mesh)| {
+                let hash = hash_clipped_mesh(clipped_mesh);
+                mesh_indices.entry(hash).or_insert(i);
+                match self.mesh_indices.get(&hash) {
+                    Some(&prev_index) if self.meshes[prev_index] == *clipped_mesh => {
+                        MeshDelta::Unchanged(prev_index)
+                    }
+                    _ => MeshDelta::New(clipped_mesh.clone()),
+                }
+            })
+            .collect();
+
+        self.meshes = meshes.to_vec();
+        self.mesh_indices = mesh_indices;
+
+        FrameDelta {
+            font_texture: font_texture_delta,
+            meshes: mesh_deltas,
+        }
+    }
+
+    /// Forget the previous frame, so that the next frame is encoded in full.
+    ///
+    /// Call this when a new receiver connects, or when a receiver fails to decode a frame.
+    pub fn reset(&mut self) {
+        *self = Default::default();
+    }
+}
+
+/// Decodes the frames from a [`FrameEncoder`]. Lives on the machine showing the app.
+#[derive(Clone, Default)]
+pub struct FrameDecoder {
+    font_texture: Texture,
+    meshes: Vec<ClippedMesh>,
+}
+
+/// Why a [`FrameDelta`] could not be decoded.
+///
+/// This means the [`FrameDecoder`] is out of sync with the [`FrameEncoder`],
+/// which should then be [reset](FrameEncoder::reset).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Refers to a mesh the previous frame did not have.
+    UnknownMesh(usize),
+    /// The texture delta does not fit the texture.
+    BadTexture,
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownMesh(index) => write!(f, "unknown mesh {} in frame delta", index),
+            Self::BadTexture => write!(f, "texture delta does not fit the font texture"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl FrameDecoder {
+    /// Apply the next frame.
+    ///
+    /// # Errors
+    /// If `delta` does not follow the previously decoded frame.
+    /// The previous frame is then kept.
+    pub fn decode(&mut self, delta: FrameDelta) -> Result<(), DecodeError> {
+        let FrameDelta {
+            font_texture,
+            meshes,
+        } = delta;
+
+        let meshes = meshes
+            .into_iter()
+            .map(|mesh_delta| match mesh_delta {
+                MeshDelta::Unchanged(index) => self
+                    .meshes
+                    .get(index)
+                    .cloned()
+                    .ok_or(DecodeError::UnknownMesh(index)),
+                MeshDelta::New(clipped_mesh) => Ok(clipped_mesh),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(texture_delta) = font_texture {
+            apply_texture_delta(&mut self.font_texture, texture_delta)?;
+        }
+        self.meshes = meshes;
+        Ok(())
+    }
+
+    /// The font texture, as of the latest decoded frame.
+    pub fn font_texture(&self) -> &Texture {
+        &self.font_texture
+    }
+
+    /// The meshes of the latest decoded frame, to be painted in order.
+    pub fn meshes(&self) -> &[ClippedMesh] {
+        &self.meshes
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn texture_delta(prev: Option<&Texture>, texture: &Texture) -> Option<TextureDelta> {
+    let prev = match prev {
+        Some(prev) if prev.size() == texture.size() => prev,
+        _ => {
+            return Some(TextureDelta {
+                version: texture.version,
+                width: texture.width,
+                height: texture.height,
+                first_row: 0,
+                pixels: texture.pixels.clone(),
+            });
+        }
+    };
+
+    if prev.version == texture.version {
+        return None;
+    }
+
+    let width = texture.width.max(1);
+    let row_changed = |(prev_row, row): (&[u8], &[u8])| prev_row != row;
+    let rows = || prev.pixels.chunks(width).zip(texture.pixels.chunks(width));
+    let first_row = rows().position(row_changed).unwrap_or(0);
+    let end_row = rows()
+        .rposition(row_changed)
+        .map_or(first_row, |last| last + 1);
+
+    Some(TextureDelta {
+        version: texture.version,
+        width: texture.width,
+        height: texture.height,
+        first_row,
+        pixels: texture.pixels[first_row * texture.width..end_row * texture.width].to_vec(),
+    })
+}
+
+fn apply_texture_delta(texture: &mut Texture, delta: TextureDelta) -> Result<(), DecodeError> {
+    let TextureDelta {
+        version,
+        width,
+        height,
+        first_row,
+        pixels,
+    } = delta;
+
+    let start = first_row * width;
+    let fits_new_size = first_row == 0 && pixels.len() == width * height;
+    let fits_old_size = texture.size() == [width, height]
+        && (width == 0 || pixels.len() % width == 0)
+        && start + pixels.len() <= texture.pixels.len();
+
+    if fits_new_size {
+        *texture = Texture {
+            version,
+            width,
+            height,
+            pixels,
+        };
+        Ok(())
+    } else if fits_old_size {
+        texture.version = version;
+        texture.pixels[start..start + pixels.len()].copy_from_slice(&pixels);
+        Ok(())
+    } else {
+        Err(DecodeError::BadTexture)
+    }
+}
+
+fn hash_clipped_mesh(ClippedMesh(clip_rect, mesh): &ClippedMesh) -> u64 {
+    use std::hash::{Hash as _, Hasher as _};
+    let mut hasher = ahash::AHasher::default();
+    for &value in &[
+        clip_rect.min.x,
+        clip_rect.min.y,
+        clip_rect.max.x,
+        clip_rect.max.y,
+    ] {
+        hasher.write_u32(value.to_bits());
+    }
+    mesh.texture_id.hash(&mut hasher);
+    mesh.indices.hash(&mut hasher);
+    for vertex in &mesh.vertices {
+        for &value in &[vertex.pos.x, vertex.pos.y, vertex.uv.x, vertex.uv.y] {
+            hasher.write_u32(value.to_bits());
+        }
+        hasher.write(&vertex.color.to_array());
+    }
+    hasher.finish()
+}
+
+#[test]
+fn test_frame_delta() {
+    use crate::{emath::*, Color32, Mesh};
+
+    let mut texture = Texture {
+        version: 1,
+        width: 4,
+        height: 4,
+        pixels: vec![0; 16],
+    };
+    let mesh = |x: f32| {
+        let mut mesh = Mesh::default();
+        mesh.add_colored_rect(
+            Rect::from_min_size(pos2(x, 0.0), vec2(1.0, 1.0)),
+            Color32::RED,
+        );
+        ClippedMesh(Rect::EVERYTHING, mesh)
+    };
+
+    let mut encoder = FrameEncoder::default();
+    let mut decoder = FrameDecoder::default();
+
+    let frame_1 = vec![mesh(0.0), mesh(1.0)];
+    let delta = encoder.encode(&texture, &frame_1);
+    assert_eq!(delta.font_texture.as_ref().unwrap().pixels.len(), 16);
+    decoder.decode(delta).unwrap();
+    assert_eq!(decoder.meshes(), &frame_1[..]);
+
+    // One new mesh, and one row of the texture changed:
+    texture.version = 2;
+    texture.pixels[9] = 255;
+    let frame_2 = vec![mesh(1.0), mesh(2.0)];
+    let delta = encoder.encode(&texture, &frame_2);
+    assert_eq!(delta.meshes[0], MeshDelta::Unchanged(1));
+    assert!(matches!(delta.meshes[1], MeshDelta::New(_)));
+    let texture_delta = delta.font_texture.as_ref().unwrap();
+    assert_eq!(texture_delta.first_row, 2);
+    assert_eq!(texture_delta.pixels.len(), 4);
+    decoder.decode(delta).unwrap();
+    assert_eq!(decoder.meshes(), &frame_2[..]);
+    assert_eq!(decoder.font_texture().pixels, texture.pixels);
+    assert_eq!(decoder.font_texture().version, 2);
+
+    // Nothing changed:
+    let delta = encoder.encode(&texture, &frame_2);
+    assert!(delta.font_texture.is_none());
+
+    // A decoder that missed the first frame is out of sync:
+    let mut late_decoder = FrameDecoder::default();
+    assert_eq!(late_decoder.decode(delta), Err(DecodeError::UnknownMesh(0)));
+}
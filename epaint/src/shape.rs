@@ -86,6 +86,29 @@ impl Shape {
         }
     }
 
+    /// An arc of the circle around `center`, e.g. for a gauge or a radial progress indicator.
+    ///
+    /// The angles are in radians, clockwise from straight up (like the hands of a clock),
+    /// and the arc goes from `start_angle` to `end_angle`.
+    pub fn arc(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        let angle = (end_angle - start_angle).abs();
+        // Short enough segments that the arc looks round, whatever its size:
+        let num_segments = (angle * 10.0).max(angle * radius / 4.0).ceil().max(1.0) as usize;
+        let points = (0..=num_segments)
+            .map(|i| {
+                let angle = lerp(start_angle..=end_angle, i as f32 / num_segments as f32);
+                center + radius * vec2(angle.sin(), -angle.cos())
+            })
+            .collect();
+        Self::line(points, stroke)
+    }
+
     /// A convex polygon with a fill and optional stroke.
     pub fn convex_polygon(
         points: Vec<Pos2>,
@@ -182,6 +205,58 @@ impl Shape {
         }
     }
 
+    /// The smallest rectangle containing everything this shape paints (including strokes).
+    ///
+    /// Returns [`Rect::NOTHING`] for shapes that paint nothing.
+    /// Used to skip tessellating shapes that are outside their clip rectangle.
+    pub fn visual_bounding_rect(&self) -> Rect {
+        match self {
+            Shape::Noop => Rect::NOTHING,
+            Shape::Vec(shapes) => shapes
+                .iter()
+                .map(Shape::visual_bounding_rect)
+                .fold(Rect::NOTHING, Rect::union),
+            Shape::Circle {
+                center,
+                radius,
+                stroke,
+                ..
+            } => Rect::from_center_size(*center, Vec2::splat(2.0 * radius + stroke.width)),
+            Shape::LineSegment { points, stroke } => {
+                Rect::from_two_pos(points[0], points[1]).expand(stroke.width / 2.0)
+            }
+            Shape::Path { points, stroke, .. } => {
+                let mut rect = Rect::NOTHING;
+                for &p in points {
+                    rect.extend_with(p);
+                }
+                rect.expand(stroke.width / 2.0)
+            }
+            Shape::Rect { rect, stroke, .. } => rect.expand(stroke.width / 2.0),
+            Shape::Text {
+                pos,
+                galley,
+                fake_italics,
+                ..
+            } => {
+                let rect = Rect::from_min_size(*pos, galley.size);
+                if *fake_italics {
+                    // The letters lean to the right by a quarter of their height:
+                    let row_height = galley
+                        .rows
+                        .iter()
+                        .map(|row| row.height())
+                        .fold(0.0, f32::max);
+                    let lean = 0.25 * row_height;
+                    Rect::from_min_max(rect.min, rect.max + vec2(lean, 0.0))
+                } else {
+                    rect
+                }
+            }
+            Shape::Mesh(mesh) => mesh.calc_bounds(),
+        }
+    }
+
     /// Translate location by this much, in-place
     pub fn translate(&mut self, delta: Vec2) {
         match self {
@@ -215,4 +290,43 @@ impl Shape {
             }
         }
     }
+
+    /// Make the shape more transparent, e.g. for fading it out.
+    ///
+    /// `opacity` is in the range `0.0..=1.0`, where `0.0` makes the shape invisible.
+    pub fn multiply_with_opacity(&mut self, opacity: f32) {
+        match self {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    shape.multiply_with_opacity(opacity);
+                }
+            }
+            Shape::Circle { fill, stroke, .. }
+            | Shape::Path { fill, stroke, .. }
+            | Shape::Rect { fill, stroke, .. } => {
+                *fill = fill.linear_multiply(opacity);
+                stroke.color = stroke.color.linear_multiply(opacity);
+            }
+            Shape::LineSegment { stroke, .. } => {
+                stroke.color = stroke.color.linear_multiply(opacity);
+            }
+            Shape::Text { galley, color, .. } => {
+                *color = color.linear_multiply(opacity);
+                if !galley.sections.is_empty() {
+                    for section in &mut std::sync::Arc::make_mut(galley).sections {
+                        let format = &mut section.format;
+                        format.color = format.color.map(|color| color.linear_multiply(opacity));
+                        format.background = format.background.linear_multiply(opacity);
+                        format.underline.color = format.underline.color.linear_multiply(opacity);
+                    }
+                }
+            }
+            Shape::Mesh(mesh) => {
+                for vertex in &mut mesh.vertices {
+                    vertex.color = vertex.color.linear_multiply(opacity);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,293 @@
+//! Paint egui meshes on the CPU, see [`SoftwareRasterizer`].
+//!
+//! This is for when there is no GPU to paint with:
+//! framebuffer-only embedded devices, headless golden-image tests and the like.
+//! It is much slower than a GPU, but gives the same result as the GPU backends:
+//! colors are blended in linear space, with premultiplied alpha.
+
+pub use crate::ColorImage;
+use crate::{emath::*, ClippedMesh, Color32, Mesh, Rgba, Texture, TextureId, Vertex};
+
+// ----------------------------------------------------------------------------
+
+/// Paints [`ClippedMesh`]es into a [`ColorImage`] on the CPU.
+///
+/// ```
+/// # use epaint::{software_rasterizer::*, *};
+/// # let mut fonts = text::Fonts::from_definitions(1.0, Default::default());
+/// # let clipped_meshes = vec![];
+/// let mut rasterizer = SoftwareRasterizer::default();
+/// let mut image = ColorImage::new([640, 480], Color32::BLACK);
+/// rasterizer.paint_meshes(&mut image, 1.0, &clipped_meshes, &fonts.texture());
+/// ```
+///
+/// Meshes using a [`TextureId::User`] are only painted if you have given the rasterizer
+/// that texture with [`Self::set_user_texture`].
+#[derive(Clone, Default)]
+pub struct SoftwareRasterizer {
+    font_texture_version: Option<u64>,
+    font_texture: LinearImage,
+    user_textures: ahash::AHashMap<u64, LinearImage>,
+}
+
+impl SoftwareRasterizer {
+    /// Set the image to use for meshes with [`TextureId::User`] `id`.
+    pub fn set_user_texture(&mut self, id: u64, image: &ColorImage) {
+        let pixels = image.pixels.iter().map(|&color| linear(color)).collect();
+        self.user_textures.insert(
+            id,
+            LinearImage {
+                size: image.size,
+                pixels,
+            },
+        );
+    }
+
+    pub fn free_user_texture(&mut self, id: u64) {
+        self.user_textures.remove(&id);
+    }
+
+    /// Paint the meshes on top of what is already in `target`, in order.
+    ///
+    /// The meshes are in points, and `pixels_per_point` is how many pixels of `target` there are per point.
+    pub fn paint_meshes(
+        &mut self,
+        target: &mut ColorImage,
+        pixels_per_point: f32,
+        clipped_meshes: &[ClippedMesh],
+        font_texture: &Texture,
+    ) {
+        self.update_font_texture(font_texture);
+
+        let mut linear_target = LinearImage {
+            size: target.size,
+            pixels: target.pixels.iter().map(|&color| linear(color)).collect(),
+        };
+
+        for ClippedMesh(clip_rect, mesh) in clipped_meshes {
+            let texture = match mesh.texture_id {
+                TextureId::Egui => Some(&self.font_texture),
+                TextureId::User(id) => self.user_textures.get(&id),
+            };
+            if let Some(texture) = texture {
+                let clip_rect = pixel_clip_rect(*clip_rect, pixels_per_point, target.size);
+                paint_mesh(
+                    &mut linear_target,
+                    clip_rect,
+                    pixels_per_point,
+                    mesh,
+                    texture,
+                );
+            }
+        }
+
+        for (color, rgba) in target.pixels.iter_mut().zip(linear_target.pixels) {
+            *color = Color32::from(rgba);
+        }
+    }
+
+    fn update_font_texture(&mut self, font_texture: &Texture) {
+        if self.font_texture_version != Some(font_texture.version) {
+            self.font_texture = LinearImage {
+                size: font_texture.size(),
+                pixels: font_texture
+                    .pixels
+                    .iter()
+                    .map(|&alpha| Rgba::from_white_alpha(alpha as f32 / 255.0))
+                    .collect(),
+            };
+            self.font_texture_version = Some(font_texture.version);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Premultiplied linear colors, for sampling and blending.
+#[derive(Clone, Default)]
+struct LinearImage {
+    size: [usize; 2],
+    pixels: Vec<Rgba>,
+}
+
+impl LinearImage {
+    /// Bilinear sampling with clamp-to-edge, like the GPU backends.
+    fn sample(&self, uv: Pos2) -> Rgba {
+        let [width, height] = self.size;
+        if width == 0 || height == 0 {
+            return Rgba::WHITE;
+        }
+
+        let x = uv.x * width as f32 - 0.5;
+        let y = uv.y * height as f32 - 0.5;
+        let (x0, y0) = (x.floor(), y.floor());
+        let (tx, ty) = (x - x0, y - y0);
+
+        let texel = |x: f32, y: f32| {
+            let x = (x.max(0.0) as usize).min(width - 1);
+            let y = (y.max(0.0) as usize).min(height - 1);
+            self.pixels[y * width + x]
+        };
+        let top = texel(x0, y0) * (1.0 - tx) + texel(x0 + 1.0, y0) * tx;
+        let bottom = texel(x0, y0 + 1.0) * (1.0 - tx) + texel(x0 + 1.0, y0 + 1.0) * tx;
+        top * (1.0 - ty) + bottom * ty
+    }
+}
+
+/// Paint the triangles of `mesh` that fall within `clip_rect` (in pixels).
+fn paint_mesh(
+    target: &mut LinearImage,
+    clip_rect: [usize; 4],
+    pixels_per_point: f32,
+    mesh: &Mesh,
+    texture: &LinearImage,
+) {
+    for triangle in mesh.indices.chunks_exact(3) {
+        let vertex = |i: usize| mesh.vertices.get(triangle[i] as usize);
+        if let (Some(a), Some(b), Some(c)) = (vertex(0), vertex(1), vertex(2)) {
+            paint_triangle(target, clip_rect, pixels_per_point, [a, b, c], texture);
+        }
+    }
+}
+
+fn paint_triangle(
+    target: &mut LinearImage,
+    [clip_min_x, clip_min_y, clip_max_x, clip_max_y]: [usize; 4],
+    pixels_per_point: f32,
+    mut vertices: [&Vertex; 3],
+    texture: &LinearImage,
+) {
+    let pos = |vertex: &Vertex| {
+        pos2(
+            vertex.pos.x * pixels_per_point,
+            vertex.pos.y * pixels_per_point,
+        )
+    };
+    let mut area = edge(pos(vertices[0]), pos(vertices[1]), pos(vertices[2]));
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+    if area < 0.0 {
+        // egui doesn't use a consistent winding order.
+        vertices.swap(1, 2);
+        area = -area;
+    }
+    let [p0, p1, p2] = [pos(vertices[0]), pos(vertices[1]), pos(vertices[2])];
+    let colors = [
+        linear(vertices[0].color),
+        linear(vertices[1].color),
+        linear(vertices[2].color),
+    ];
+
+    let min_x = (p0.x.min(p1.x).min(p2.x).floor().max(0.0) as usize).max(clip_min_x);
+    let min_y = (p0.y.min(p1.y).min(p2.y).floor().max(0.0) as usize).max(clip_min_y);
+    let max_x = (p0.x.max(p1.x).max(p2.x).ceil().max(0.0) as usize).min(clip_max_x);
+    let max_y = (p0.y.max(p1.y).max(p2.y).ceil().max(0.0) as usize).min(clip_max_y);
+
+    let width = target.size[0];
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let center = pos2(x as f32 + 0.5, y as f32 + 0.5);
+            let w0 = edge(p1, p2, center);
+            let w1 = edge(p2, p0, center);
+            let w2 = edge(p0, p1, center);
+            if !(covers(w0, p1, p2) && covers(w1, p2, p0) && covers(w2, p0, p1)) {
+                continue;
+            }
+
+            let (b0, b1, b2) = (w0 / area, w1 / area, w2 / area);
+            let uv = pos2(
+                vertices[0].uv.x * b0 + vertices[1].uv.x * b1 + vertices[2].uv.x * b2,
+                vertices[0].uv.y * b0 + vertices[1].uv.y * b1 + vertices[2].uv.y * b2,
+            );
+            let color = colors[0] * b0 + colors[1] * b1 + colors[2] * b2;
+            let src = color * texture.sample(uv);
+
+            // Premultiplied alpha blending:
+            let dst = &mut target.pixels[y * width + x];
+            *dst = src + *dst * (1.0 - src.a());
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `c`.
+fn edge(a: Pos2, b: Pos2, c: Pos2) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Is a pixel center at distance `w` from the edge `a -> b` inside the triangle?
+///
+/// Pixel centers exactly on an edge belong to only one of the two triangles sharing that edge,
+/// so that they are not blended twice.
+fn covers(w: f32, a: Pos2, b: Pos2) -> bool {
+    w > 0.0 || (w == 0.0 && (a.y < b.y || (a.y == b.y && a.x > b.x)))
+}
+
+/// The clip rectangle in pixels, as `[min_x, min_y, max_x, max_y]`, rounded like the GPU backends do it.
+fn pixel_clip_rect(
+    clip_rect: Rect,
+    pixels_per_point: f32,
+    [width, height]: [usize; 2],
+) -> [usize; 4] {
+    let to_pixel =
+        |points: f32, max: usize| ((points * pixels_per_point).round().max(0.0) as usize).min(max);
+    [
+        to_pixel(clip_rect.min.x, width),
+        to_pixel(clip_rect.min.y, height),
+        to_pixel(clip_rect.max.x, width),
+        to_pixel(clip_rect.max.y, height),
+    ]
+}
+
+/// `Color32 -> Rgba`, i.e. premultiplied sRGBA to premultiplied linear.
+fn linear(color: Color32) -> Rgba {
+    Rgba::from(color)
+}
+
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_software_rasterizer() {
+    let mut rasterizer = SoftwareRasterizer::default();
+    let font_texture = Texture {
+        version: 0,
+        width: 1,
+        height: 1,
+        pixels: vec![255],
+    };
+    let paint = |rasterizer: &mut SoftwareRasterizer, clip_rect: Rect, rect: Rect, color| {
+        let mut image = ColorImage::new([4, 4], Color32::BLACK);
+        let mut mesh = Mesh::default();
+        mesh.add_colored_rect(rect, color);
+        let clipped_meshes = [ClippedMesh(clip_rect, mesh)];
+        rasterizer.paint_meshes(&mut image, 1.0, &clipped_meshes, &font_texture);
+        image
+    };
+
+    let image = paint(
+        &mut rasterizer,
+        Rect::EVERYTHING,
+        Rect::from_min_max(pos2(1.0, 1.0), pos2(3.0, 3.0)),
+        Color32::RED,
+    );
+    assert_eq!(image[(0, 0)], Color32::BLACK);
+    assert_eq!(image[(1, 1)], Color32::RED);
+    assert_eq!(image[(2, 2)], Color32::RED);
+    assert_eq!(image[(3, 3)], Color32::BLACK);
+
+    // Half-transparent white is blended in linear space,
+    // and the diagonal shared by the two triangles of the rectangle is not painted twice:
+    let image = paint(
+        &mut rasterizer,
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(4.0, 2.0)),
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(4.0, 4.0)),
+        Color32::from(Rgba::from_white_alpha(0.5)),
+    );
+    let half_gray = Color32::from(Rgba::from_gray(0.5));
+    for y in 0..2 {
+        for x in 0..4 {
+            assert_eq!(image[(x, y)], half_gray);
+        }
+    }
+    assert_eq!(image[(0, 2)], Color32::BLACK, "clipped");
+}
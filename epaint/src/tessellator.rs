@@ -499,6 +499,9 @@ impl Tessellator {
             Shape::Noop => {}
             Shape::Vec(vec) => {
                 for shape in vec {
+                    if options.coarse_tessellation_culling && !self.is_visible(&shape) {
+                        continue;
+                    }
                     self.tessellate_shape(tex_size, shape, out)
                 }
             }
@@ -599,6 +602,15 @@ impl Tessellator {
         }
     }
 
+    /// Does the [`Shape::visual_bounding_rect`] of the shape intersect the current clip rectangle?
+    fn is_visible(&self, shape: &Shape) -> bool {
+        let bounds = shape.visual_bounding_rect();
+        // Anti-aliasing feathers the edges a bit outside of the shape:
+        bounds
+            .expand(self.options.aa_size)
+            .intersects(self.clip_rect)
+    }
+
     pub(crate) fn tessellate_rect(&mut self, rect: &PaintRect, out: &mut Mesh) {
         let PaintRect {
             mut rect,
@@ -638,7 +650,7 @@ impl Tessellator {
         fake_italics: bool,
         out: &mut Mesh,
     ) {
-        if color == Color32::TRANSPARENT || galley.is_empty() {
+        if (color == Color32::TRANSPARENT && galley.sections.is_empty()) || galley.is_empty() {
             return;
         }
         if cfg!(any(
@@ -666,18 +678,60 @@ impl Tessellator {
         let clip_rect_min_y = self.clip_rect.min.y - clip_slack;
         let clip_rect_max_y = self.clip_rect.max.y + clip_slack;
 
+        let mut row_start_char = 0; // char index of the first char in the current row
+
         for row in &galley.rows {
             let row_min_y = pos.y + row.y_min;
             let row_max_y = pos.y + row.y_max;
             let is_line_visible = clip_rect_min_y <= row_max_y && row_min_y <= clip_rect_max_y;
 
+            let row_chars = row_start_char..row_start_char + row.char_count_excluding_newline();
+            row_start_char += row.char_count_including_newline();
+
             if self.options.coarse_tessellation_culling && !is_line_visible {
                 // culling individual lines of text is important, since a single `Shape::Text`
                 // can span hundreds of lines.
                 continue;
             }
 
-            for (x_offset, uv_rect) in row.x_offsets.iter().zip(&row.uv_rects) {
+            let first_section = galley.first_section_after(row_chars.start);
+            for section in &galley.sections[first_section..] {
+                if row_chars.end <= section.char_range.start {
+                    break;
+                }
+                let start = section.char_range.start.max(row_chars.start) - row_chars.start;
+                let end = section.char_range.end.min(row_chars.end) - row_chars.start;
+                let x_range = pos.x + row.x_offset(start)..=pos.x + row.x_offset(end);
+
+                let format = &section.format;
+                if format.background != Color32::TRANSPARENT {
+                    let rect = Rect::from_x_y_ranges(x_range.clone(), row_min_y..=row_max_y);
+                    out.add_colored_rect(rect, format.background);
+                }
+                if format.underline.width > 0.0 && format.underline.color != Color32::TRANSPARENT {
+                    let y = self.options.round_to_pixel(row_max_y);
+                    let rect = Rect::from_x_y_ranges(x_range, y - format.underline.width..=y);
+                    out.add_colored_rect(rect, format.underline.color);
+                }
+            }
+
+            let mut section_index = first_section;
+            for (i, (x_offset, uv_rect)) in row.x_offsets.iter().zip(&row.uv_rects).enumerate() {
+                let char_index = row_chars.start + i;
+                while galley
+                    .sections
+                    .get(section_index)
+                    .map_or(false, |section| section.char_range.end <= char_index)
+                {
+                    section_index += 1;
+                }
+                let color = match galley.sections.get(section_index) {
+                    Some(section) if section.char_range.start <= char_index => {
+                        section.format.color.unwrap_or(color)
+                    }
+                    _ => color,
+                };
+
                 if let Some(glyph) = uv_rect {
                     let mut left_top = pos + glyph.offset + vec2(*x_offset, row.y_min);
                     left_top.x = self.options.round_to_pixel(left_top.x); // Pixel-perfection.
@@ -736,6 +790,8 @@ impl Tessellator {
 /// The given shapes will be painted back-to-front (painters algorithm).
 /// They will be batched together by clip rectangle.
 ///
+/// With the `parallel_tessellation` feature, each batch is tessellated on the rayon thread pool.
+///
 /// * `shapes`: the shape to tessellate
 /// * `options`: tessellation quality
 /// * `tex_size`: size of the font texture (required to normalize glyph uv rectangles)
@@ -746,29 +802,78 @@ pub fn tessellate_shapes(
     shapes: Vec<ClippedShape>,
     options: TessellationOptions,
     tex_size: [usize; 2],
+) -> Vec<ClippedMesh> {
+    tessellate_shapes_on_threads(
+        shapes,
+        options,
+        tex_size,
+        cfg!(feature = "parallel_tessellation"),
+    )
+}
+
+/// [`tessellate_shapes`], with a choice of using rayon or not, so the tests can compare the two.
+fn tessellate_shapes_on_threads(
+    shapes: Vec<ClippedShape>,
+    options: TessellationOptions,
+    tex_size: [usize; 2],
+    parallel: bool,
 ) -> Vec<ClippedMesh> {
     let mut tessellator = Tessellator::from_options(options);
 
-    let mut clipped_meshes: Vec<ClippedMesh> = Vec::default();
+    // Consecutive shapes with the same clip rectangle and texture end up in the same mesh.
+    // Each such run can be tessellated independently of the others.
+    let mut runs: Vec<(Rect, Vec<Shape>)> = Vec::default();
 
     for ClippedShape(clip_rect, shape) in shapes {
         if !clip_rect.is_positive() {
             continue; // skip empty clip rectangles
         }
 
-        let start_new_mesh = match clipped_meshes.last() {
+        tessellator.clip_rect = clip_rect;
+        if options.coarse_tessellation_culling && !tessellator.is_visible(&shape) {
+            continue; // e.g. the part of a large `ScrollArea` that is scrolled out of view
+        }
+
+        let start_new_run = match runs.last() {
             None => true,
-            Some(cm) => cm.0 != clip_rect || cm.1.texture_id != shape.texture_id(),
+            Some((run_clip_rect, run_shapes)) => {
+                *run_clip_rect != clip_rect || run_shapes[0].texture_id() != shape.texture_id()
+            }
         };
 
-        if start_new_mesh {
-            clipped_meshes.push(ClippedMesh(clip_rect, Mesh::default()));
+        if start_new_run {
+            runs.push((clip_rect, vec![shape]));
+        } else {
+            runs.last_mut().unwrap().1.push(shape);
         }
+    }
 
-        let out = &mut clipped_meshes.last_mut().unwrap().1;
+    let tessellate_run = |(clip_rect, shapes): (Rect, Vec<Shape>)| {
+        let mut tessellator = Tessellator::from_options(options);
         tessellator.clip_rect = clip_rect;
-        tessellator.tessellate_shape(tex_size, shape, out);
-    }
+        let mut mesh = Mesh::default();
+        for shape in shapes {
+            tessellator.tessellate_shape(tex_size, shape, &mut mesh);
+        }
+        ClippedMesh(clip_rect, mesh)
+    };
+
+    #[cfg(feature = "parallel_tessellation")]
+    let mut clipped_meshes: Vec<ClippedMesh> = if parallel {
+        use rayon::prelude::*;
+        runs.into_par_iter().map(tessellate_run).collect() // keeps the order
+    } else {
+        runs.into_iter().map(tessellate_run).collect()
+    };
+
+    #[cfg(not(feature = "parallel_tessellation"))]
+    let mut clipped_meshes: Vec<ClippedMesh> = {
+        debug_assert!(
+            !parallel,
+            "tessellating in parallel needs the parallel_tessellation feature"
+        );
+        runs.into_iter().map(tessellate_run).collect()
+    };
 
     if options.debug_paint_clip_rects {
         for ClippedMesh(clip_rect, mesh) in &mut clipped_meshes {
@@ -798,3 +903,97 @@ pub fn tessellate_shapes(
 
     clipped_meshes
 }
+
+#[test]
+fn test_cull_invisible_shapes() {
+    let clip_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+    let rect_at = |x: f32| Shape::Rect {
+        rect: Rect::from_min_size(pos2(x, 10.0), vec2(10.0, 10.0)),
+        corner_radius: 0.0,
+        fill: Color32::WHITE,
+        stroke: Stroke::none(),
+    };
+    let num_vertices = |shape: Shape| -> usize {
+        tessellate_shapes(
+            vec![ClippedShape(clip_rect, shape)],
+            Default::default(),
+            [1, 1],
+        )
+        .iter()
+        .map(|ClippedMesh(_, mesh)| mesh.vertices.len())
+        .sum()
+    };
+
+    let visible = num_vertices(rect_at(10.0));
+    assert!(visible > 0);
+    assert_eq!(num_vertices(rect_at(500.0)), 0);
+    assert_eq!(
+        num_vertices(Shape::Vec(vec![rect_at(10.0), rect_at(500.0)])),
+        visible
+    );
+    assert_eq!(
+        num_vertices(Shape::line_segment(
+            [pos2(-50.0, -5.0), pos2(-10.0, -5.0)],
+            (1.0, Color32::WHITE)
+        )),
+        0
+    );
+}
+
+#[cfg(feature = "parallel_tessellation")]
+#[test]
+fn test_parallel_tessellation_matches_serial() {
+    let clip_a = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+    let clip_b = Rect::from_min_size(pos2(50.0, 50.0), vec2(100.0, 100.0));
+    let rect_at = |x: f32| {
+        Shape::rect_filled(
+            Rect::from_min_size(pos2(x, 60.0), vec2(10.0, 10.0)),
+            2.0,
+            Color32::RED,
+        )
+    };
+    let mut user_mesh = Mesh::with_texture(TextureId::User(1));
+    user_mesh.add_rect_with_uv(
+        Rect::from_min_size(pos2(20.0, 20.0), vec2(30.0, 30.0)),
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+        Color32::WHITE,
+    );
+
+    let shapes = vec![
+        ClippedShape(clip_a, rect_at(10.0)),
+        ClippedShape(
+            clip_a,
+            Shape::circle_filled(pos2(40.0, 40.0), 5.0, Color32::GREEN),
+        ),
+        ClippedShape(
+            clip_a,
+            Shape::line_segment([pos2(0.0, 0.0), pos2(90.0, 90.0)], (2.0, Color32::WHITE)),
+        ),
+        ClippedShape(clip_a, Shape::Mesh(user_mesh)),
+        ClippedShape(clip_a, rect_at(30.0)),
+        ClippedShape(clip_b, rect_at(60.0)),
+        ClippedShape(clip_b, rect_at(80.0)),
+        ClippedShape(clip_a, rect_at(50.0)),
+    ];
+    let options = TessellationOptions::default();
+
+    let serial = tessellate_shapes_on_threads(shapes.clone(), options, [64, 64], false);
+    let parallel = tessellate_shapes_on_threads(shapes, options, [64, 64], true);
+    assert_eq!(serial, parallel);
+
+    // A new mesh for every change of clip rectangle or texture, in the order painted:
+    let runs: Vec<(Rect, TextureId)> = parallel
+        .iter()
+        .map(|ClippedMesh(clip_rect, mesh)| (*clip_rect, mesh.texture_id))
+        .collect();
+    assert_eq!(
+        runs,
+        vec![
+            (clip_a, TextureId::Egui),
+            (clip_a, TextureId::User(1)),
+            (clip_a, TextureId::Egui),
+            (clip_b, TextureId::Egui),
+            (clip_a, TextureId::Egui),
+        ]
+    );
+}
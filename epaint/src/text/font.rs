@@ -287,7 +287,11 @@ impl Font {
 
     /// Width of this character in points.
     pub fn glyph_width(&self, c: char) -> f32 {
-        self.glyph_info(c).1.advance_width
+        if c == super::INLINE_IMAGE_CHAR {
+            self.row_height()
+        } else {
+            self.glyph_info(c).1.advance_width
+        }
     }
 
     /// `\n` will (intentionally) show up as the replacement character.
@@ -329,7 +333,12 @@ impl Font {
         let mut last_glyph_id = None;
 
         for c in text.chars() {
-            if !self.fonts.is_empty() {
+            if c == super::INLINE_IMAGE_CHAR {
+                // Reserve a square for an image painted by the user of the galley:
+                cursor_x_in_points += self.row_height();
+                cursor_x_in_points = self.round_to_pixel(cursor_x_in_points);
+                last_glyph_id = None;
+            } else if !self.fonts.is_empty() {
                 let (font_index, glyph_info) = self.glyph_info(c);
 
                 let font_impl = &self.fonts[font_index];
@@ -370,6 +379,7 @@ impl Font {
             text,
             rows: vec![row],
             size,
+            sections: vec![],
         };
         self.finalize_galley(galley)
     }
@@ -468,6 +478,7 @@ impl Font {
             text,
             rows,
             size,
+            sections: vec![],
         };
         self.finalize_galley(galley)
     }
@@ -10,7 +10,7 @@ use crate::{
     mutex::Mutex,
     text::{
         font::{Font, FontImpl},
-        Galley,
+        Galley, Row, TextSection,
     },
     Texture, TextureAtlas,
 };
@@ -221,6 +221,9 @@ pub struct Fonts {
 }
 
 impl Fonts {
+    /// See [`Self::set_galley_cache_max_bytes`].
+    pub const DEFAULT_GALLEY_CACHE_MAX_BYTES: usize = 16 * 1024 * 1024;
+
     pub fn from_definitions(pixels_per_point: f32, definitions: FontDefinitions) -> Self {
         assert!(
             0.0 < pixels_per_point && pixels_per_point < 100.0,
@@ -384,7 +387,27 @@ impl Fonts {
         self.galley_cache.lock().num_galleys_in_cache()
     }
 
-    /// Must be called once per frame to clear the [`Galley`] cache.
+    /// How many layouts were found in the [`Galley`] cache during the last frame, and how many had to be done.
+    ///
+    /// Use this to check that your text-heavy UI isn't laying out the same text over and over.
+    pub fn galley_cache_stats(&self) -> GalleyCacheStats {
+        self.galley_cache.lock().last_stats
+    }
+
+    /// Keep up to about this many bytes of [`Galley`]s in the cache across frames.
+    /// Default: [`Self::DEFAULT_GALLEY_CACHE_MAX_BYTES`].
+    ///
+    /// Galleys not used in the current frame are evicted at the end of the frame,
+    /// least recently used first, when the cache is bigger than this.
+    /// Galleys used in the current frame are never evicted.
+    ///
+    /// Note that the cache starts over when the fonts are recreated,
+    /// e.g. when the pixels per point or the font definitions change.
+    pub fn set_galley_cache_max_bytes(&self, max_bytes: usize) {
+        self.galley_cache.lock().max_bytes = max_bytes;
+    }
+
+    /// Must be called once per frame to evict old galleys from the [`Galley`] cache.
     pub fn end_frame(&self) {
         self.galley_cache.lock().end_frame()
     }
@@ -419,23 +442,103 @@ struct LayoutJob {
 
 struct CachedGalley {
     /// When it was last used
-    last_used: u32,
+    last_used: u64,
+    /// See [`galley_size_in_bytes`].
+    num_bytes: usize,
     galley: Arc<Galley>,
 }
 
-#[derive(Default)]
+/// Roughly how much memory a cached galley takes, including its key.
+fn galley_size_in_bytes(galley: &Galley) -> usize {
+    use std::mem::size_of;
+    let rows: usize = galley
+        .rows
+        .iter()
+        .map(|row| {
+            size_of::<Row>()
+                + row.x_offsets.len() * size_of::<f32>()
+                + row.uv_rects.len() * size_of::<Option<super::font::UvRect>>()
+        })
+        .sum();
+    size_of::<LayoutJob>()
+        + size_of::<CachedGalley>()
+        + size_of::<Galley>()
+        + 2 * galley.text.len() // in the galley and in the key
+        + rows
+        + galley.sections.len() * size_of::<TextSection>()
+}
+
+/// How well the [`Galley`] cache of [`Fonts`] is doing, see [`Fonts::galley_cache_stats`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct GalleyCacheStats {
+    /// Number of layouts found in the cache during the last frame.
+    pub hits: usize,
+
+    /// Number of layouts that had to be done during the last frame.
+    pub misses: usize,
+
+    /// Number of galleys dropped from the cache at the end of the last frame.
+    pub evictions: usize,
+
+    /// Number of galleys in the cache.
+    pub num_galleys: usize,
+
+    /// Roughly how many bytes the galleys in the cache take.
+    pub num_bytes: usize,
+}
+
+impl GalleyCacheStats {
+    /// The fraction of layouts found in the cache during the last frame, in `[0, 1]`.
+    pub fn hit_rate(&self) -> f32 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            1.0
+        } else {
+            self.hits as f32 / total as f32
+        }
+    }
+}
+
 struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
-    generation: u32,
+    generation: u64,
     cache: AHashMap<LayoutJob, CachedGalley>,
+
+    /// The sum of [`CachedGalley::num_bytes`].
+    num_bytes: usize,
+
+    /// Galleys not used in the current frame are evicted, least recently used first,
+    /// when [`Self::num_bytes`] is more than this.
+    max_bytes: usize,
+
+    /// Hits and misses of the current frame.
+    current_stats: GalleyCacheStats,
+
+    /// Stats of the last finished frame.
+    last_stats: GalleyCacheStats,
+}
+
+impl Default for GalleyCache {
+    fn default() -> Self {
+        Self {
+            generation: 0,
+            cache: Default::default(),
+            num_bytes: 0,
+            max_bytes: Fonts::DEFAULT_GALLEY_CACHE_MAX_BYTES,
+            current_stats: Default::default(),
+            last_stats: Default::default(),
+        }
+    }
 }
 
 impl GalleyCache {
     fn layout(&mut self, fonts: &BTreeMap<TextStyle, Font>, job: LayoutJob) -> Arc<Galley> {
         if let Some(cached) = self.cache.get_mut(&job) {
+            self.current_stats.hits += 1;
             cached.last_used = self.generation;
             cached.galley.clone()
         } else {
+            self.current_stats.misses += 1;
             let LayoutJob {
                 text_style,
                 layout_params,
@@ -454,10 +557,13 @@ impl GalleyCache {
                 ),
             };
             let galley = Arc::new(galley);
+            let num_bytes = galley_size_in_bytes(&galley);
+            self.num_bytes += num_bytes;
             self.cache.insert(
                 job,
                 CachedGalley {
                     last_used: self.generation,
+                    num_bytes,
                     galley: galley.clone(),
                 },
             );
@@ -469,13 +575,41 @@ impl GalleyCache {
         self.cache.len()
     }
 
-    /// Must be called once per frame to clear the [`Galley`] cache.
+    /// Must be called once per frame to evict old galleys from the cache.
     pub fn end_frame(&mut self) {
-        let current_generation = self.generation;
-        self.cache.retain(|_key, cached| {
-            cached.last_used == current_generation // only keep those that were used this frame
-        });
-        self.generation = self.generation.wrapping_add(1);
+        let num_galleys_before = self.cache.len();
+        if self.num_bytes > self.max_bytes {
+            // Galleys used this frame are always kept, so we don't lay them out again next frame.
+            let current_generation = self.generation;
+            let mut unused: Vec<(u64, usize, &LayoutJob)> = self
+                .cache
+                .iter()
+                .filter(|(_, cached)| cached.last_used != current_generation)
+                .map(|(job, cached)| (cached.last_used, cached.num_bytes, job))
+                .collect();
+            unused.sort_unstable_by_key(|(last_used, _, _)| *last_used); // least recently used first
+            let mut num_bytes = self.num_bytes;
+            let evicted: Vec<LayoutJob> = unused
+                .iter()
+                .take_while(|(_, galley_bytes, _)| {
+                    let evict = num_bytes > self.max_bytes;
+                    num_bytes -= galley_bytes;
+                    evict
+                })
+                .map(|(_, _, job)| (*job).clone())
+                .collect();
+            for job in &evicted {
+                if let Some(cached) = self.cache.remove(job) {
+                    self.num_bytes -= cached.num_bytes;
+                }
+            }
+        }
+
+        self.current_stats.evictions = num_galleys_before - self.cache.len();
+        self.current_stats.num_galleys = self.cache.len();
+        self.current_stats.num_bytes = self.num_bytes;
+        self.last_stats = std::mem::take(&mut self.current_stats);
+        self.generation += 1;
     }
 }
 
@@ -550,3 +684,37 @@ impl FontImplCache {
         font_impl
     }
 }
+
+#[test]
+fn test_galley_cache() {
+    let fonts = Fonts::from_definitions(1.0, Default::default());
+    let layout = |text: &str| fonts.layout_single_line(TextStyle::Body, text.to_owned());
+    // Room for three galleys of one character:
+    let one_char = fonts[TextStyle::Body].layout_single_line("x".to_owned());
+    fonts.set_galley_cache_max_bytes(3 * galley_size_in_bytes(&one_char));
+
+    layout("a");
+    layout("b");
+    fonts.end_frame();
+    let stats = fonts.galley_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.num_galleys), (0, 2, 2));
+
+    // Kept across frames:
+    layout("c");
+    layout("a");
+    fonts.end_frame();
+    let stats = fonts.galley_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.num_galleys), (1, 1, 3));
+
+    // Over capacity, so the least recently used is evicted:
+    layout("d");
+    fonts.end_frame();
+    let stats = fonts.galley_cache_stats();
+    assert_eq!((stats.evictions, stats.num_galleys), (1, 3));
+    assert_eq!(stats.num_bytes, 3 * galley_size_in_bytes(&one_char));
+    layout("a");
+    layout("b");
+    fonts.end_frame();
+    let stats = fonts.galley_cache_stats();
+    assert_eq!((stats.hits, stats.misses), (1, 1));
+}
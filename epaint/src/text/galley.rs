@@ -20,7 +20,9 @@
 //! [`CCursor::prefer_next_row`] etc selects which.
 
 use super::{cursor::*, font::UvRect};
+use crate::{Color32, Stroke};
 use emath::{pos2, NumExt, Rect, Vec2};
+use std::ops::Range;
 
 /// A collection of text locked into place.
 #[derive(Clone, Debug, PartialEq)]
@@ -39,6 +41,40 @@ pub struct Galley {
 
     // Optimization: calculated once and reused.
     pub size: Vec2,
+
+    /// Parts of the text to paint differently, e.g. for syntax highlighting.
+    /// Sorted by [`TextSection::char_range`], and never overlapping.
+    ///
+    /// Usually empty. See [`Self::with_sections`].
+    pub sections: Vec<TextSection>,
+}
+
+/// How to paint a part of a [`Galley`], see [`Galley::sections`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSection {
+    /// Char indices (not byte indices!) into [`Galley::text`].
+    pub char_range: Range<usize>,
+    pub format: TextFormat,
+}
+
+/// The look of a [`TextSection`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextFormat {
+    /// Text color. `None` means the color the [`Galley`] is painted with.
+    pub color: Option<Color32>,
+    /// Fill behind the text. [`Color32::TRANSPARENT`] for none.
+    pub background: Color32,
+    /// Line under the text, e.g. for marking errors.
+    pub underline: Stroke,
+}
+
+impl TextFormat {
+    pub fn color(color: Color32) -> Self {
+        Self {
+            color: Some(color),
+            ..Default::default()
+        }
+    }
 }
 
 /// A typeset piece of text on a single row.
@@ -152,6 +188,63 @@ impl Galley {
         char_count
     }
 
+    /// The room reserved by each [`super::INLINE_IMAGE_CHAR`] in the text, in order.
+    ///
+    /// Each rectangle is relative to the galley position and is as high as its row.
+    pub fn inline_image_rects(&self) -> Vec<Rect> {
+        let mut rects = vec![];
+        let mut chars = self.text.chars();
+        for row in &self.rows {
+            for i in 0..row.char_count_excluding_newline() {
+                if chars.next() == Some(super::INLINE_IMAGE_CHAR) {
+                    rects.push(Rect::from_min_max(
+                        pos2(row.x_offsets[i], row.y_min),
+                        pos2(row.x_offsets[i + 1], row.y_max),
+                    ));
+                }
+            }
+            if row.ends_with_newline {
+                chars.next();
+            }
+        }
+        rects
+    }
+
+    /// A copy of this galley with some parts of the text painted differently.
+    ///
+    /// The sections must be sorted by [`TextSection::char_range`], and not overlap.
+    ///
+    /// ```
+    /// # use epaint::{text::{Galley, TextFormat, TextSection}, Color32};
+    /// # fn highlight(galley: &Galley) -> Galley {
+    /// // Paint the first word red:
+    /// let end = galley.text.find(' ').unwrap_or(galley.text.len());
+    /// galley.with_sections(vec![TextSection {
+    ///     char_range: 0..galley.text[..end].chars().count(),
+    ///     format: TextFormat::color(Color32::RED),
+    /// }])
+    /// # }
+    /// ```
+    pub fn with_sections(&self, sections: Vec<TextSection>) -> Galley {
+        Galley {
+            sections,
+            ..self.clone()
+        }
+    }
+
+    /// The index of the first section that ends after the given char index.
+    pub(crate) fn first_section_after(&self, char_index: usize) -> usize {
+        match self.sections.binary_search_by(|section| {
+            if section.char_range.end <= char_index {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Greater
+            }
+        }) {
+            Ok(index) | Err(index) => index,
+        }
+    }
+
     pub fn sanity_check(&self) {
         let mut char_count = 0;
         for row in &self.rows {
@@ -819,3 +912,28 @@ fn test_text_layout() {
         );
     }
 }
+
+#[test]
+fn test_inline_image_layout() {
+    use crate::*;
+
+    let pixels_per_point = 1.0;
+    let fonts = text::Fonts::from_definitions(pixels_per_point, text::FontDefinitions::default());
+    let font = &fonts[TextStyle::Body];
+    let row_height = font.row_height();
+
+    let text = format!("a{}b\n{}", text::INLINE_IMAGE_CHAR, text::INLINE_IMAGE_CHAR);
+    let galley = font.layout_no_wrap(text);
+    let rects = galley.inline_image_rects();
+    assert_eq!(rects.len(), 2);
+    assert_eq!(rects[0].width(), row_height);
+    assert_eq!(rects[0].height(), row_height);
+    assert_eq!(rects[0].min.x, galley.rows[0].x_offsets[1]);
+    assert_eq!(rects[1].min, pos2(0.0, galley.rows[1].y_min));
+
+    // Images wrap like any other character:
+    let text = text::INLINE_IMAGE_CHAR.to_string().repeat(10);
+    let galley = font.layout_multiline(text, 4.5 * row_height);
+    assert_eq!(galley.rows.len(), 3);
+    assert_eq!(galley.inline_image_rects().len(), 10);
+}
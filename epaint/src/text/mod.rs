@@ -9,9 +9,16 @@ mod galley;
 pub const TAB_SIZE: usize = 4;
 
 pub use {
-    fonts::{FontDefinitions, FontFamily, Fonts, TextStyle},
-    galley::{Galley, Row},
+    fonts::{FontDefinitions, FontFamily, Fonts, GalleyCacheStats, TextStyle},
+    galley::{Galley, Row, TextFormat, TextSection},
 };
 
 /// Suggested character to use to replace those in password text fields.
 pub const PASSWORD_REPLACEMENT_CHAR: char = '•';
+
+/// Reserves room for an inline image in a text.
+///
+/// Each occurrence is laid out as an invisible square with the side of the row height,
+/// and wraps just like any other character.
+/// Use [`Galley::inline_image_rects`] to find where to paint the images.
+pub const INLINE_IMAGE_CHAR: char = '\u{FFFC}'; // OBJECT REPLACEMENT CHARACTER
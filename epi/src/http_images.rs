@@ -0,0 +1,658 @@
+//! Loading images over HTTP, see [`HttpImages`].
+
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use crate::{http, Frame, TextureAllocator, TextureCache};
+
+/// How long to keep using an image when the server doesn't say, in seconds.
+const DEFAULT_FRESHNESS: f64 = f64::INFINITY;
+
+/// Limits and retry behavior of [`HttpImages`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HttpImageOptions {
+    /// Images larger than this many bytes are not loaded. Default: 16 MB.
+    pub max_bytes: usize,
+
+    /// How many times to try again after a network error or a server error
+    /// (5xx, `429 Too Many Requests` or `408 Request Timeout`). Default: 3.
+    pub max_retries: u32,
+
+    /// Seconds to wait before the first retry. Every following retry waits twice as long. Default: 1.
+    pub retry_delay: f64,
+
+    /// Never wait longer than this many seconds before a retry,
+    /// unless the server asks for it with `Retry-After`. Default: 30.
+    pub max_retry_delay: f64,
+}
+
+impl Default for HttpImageOptions {
+    fn default() -> Self {
+        Self {
+            max_bytes: 16 * 1024 * 1024,
+            max_retries: 3,
+            retry_delay: 1.0,
+            max_retry_delay: 30.0,
+        }
+    }
+}
+
+/// How far an image of [`HttpImages`] has come.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HttpImageState {
+    /// Downloading, or waiting to try again.
+    Loading,
+    /// Ready to be shown.
+    Ready,
+    /// We gave up. Call [`HttpImages::retry`] to try again.
+    Failed(String),
+}
+
+/// Downloads images and keeps them around, respecting the HTTP cache headers.
+///
+/// * An image is kept for as long as `Cache-Control: max-age` (or `Expires`) says.
+///   When it is shown again after that, it is revalidated with `If-None-Match` (`ETag`)
+///   or `If-Modified-Since` (`Last-Modified`), while the old image is still shown.
+/// * Network and server errors are retried with an exponential backoff, honoring `Retry-After`.
+/// * Images larger than [`HttpImageOptions::max_bytes`] are refused.
+///
+/// On the web the browser has its own cache, and only shows the headers the server exposes to scripts.
+///
+/// ```
+/// struct MyApp {
+///     images: epi::HttpImages,
+/// }
+///
+/// impl MyApp {
+///     fn ui(&mut self, ui: &mut egui::Ui, frame: &mut epi::Frame<'_>) {
+///         // Shows a spinner while loading, and a retry button if it fails:
+///         self.images.show(ui, frame, "https://picsum.photos/200", None);
+///     }
+/// }
+/// ```
+pub struct HttpImages {
+    options: HttpImageOptions,
+    entries: HashMap<String, Entry>,
+    textures: TextureCache<String>,
+    sender: Sender<(String, Result<http::Response, http::Error>)>,
+    receiver: Receiver<(String, Result<http::Response, http::Error>)>,
+}
+
+impl Default for HttpImages {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+struct Entry {
+    /// The last image we got. Kept while revalidating, and when revalidation fails.
+    image: Option<CachedImage>,
+    fetch: Fetch,
+    /// Failed tries since the last success.
+    failures: u32,
+    last_shown_frame_nr: Option<u64>,
+}
+
+enum Fetch {
+    Idle,
+    InFlight,
+    RetryAt(f64),
+    Failed(String),
+}
+
+struct CachedImage {
+    bytes: Vec<u8>,
+    /// Until when (in [`egui::InputState::time`]) we may show this without asking the server again.
+    fresh_until: f64,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl HttpImages {
+    /// Use `HttpImages::default()` for the default [`HttpImageOptions`].
+    pub fn new(options: HttpImageOptions) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            options,
+            entries: Default::default(),
+            textures: Default::default(),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Show the image at `url`, at its own size or at `size` (in points).
+    ///
+    /// While it is loading a placeholder with a spinner is shown,
+    /// and if it fails an error icon (with the error on hover) and a retry button.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        frame: &mut Frame<'_>,
+        url: &str,
+        size: Option<egui::Vec2>,
+    ) -> egui::Response {
+        let placeholder_size = size.unwrap_or_else(|| egui::Vec2::splat(64.0));
+        match self.load(ui.ctx(), frame, url) {
+            Ok(Some((texture_id, image_size))) => ui.image(texture_id, size.unwrap_or(image_size)),
+            Ok(None) => placeholder(ui, placeholder_size, |ui| {
+                ui.add(egui::Spinner::new());
+            }),
+            Err(error) => {
+                let mut retry = false;
+                let response = placeholder(ui, placeholder_size, |ui| {
+                    let error_color = ui.visuals().error_fg_color;
+                    ui.add(egui::Label::new("⚠").heading().text_color(error_color))
+                        .on_hover_text(&error);
+                    retry = ui.small_button("Retry").clicked();
+                });
+                if retry {
+                    self.retry(url);
+                }
+                response.on_hover_text(error)
+            }
+        }
+    }
+
+    /// Start loading the image at `url` if needed, and return its texture and size (in pixels) once it is ready.
+    ///
+    /// Call this every frame you show the image.
+    ///
+    /// # Errors
+    /// If we gave up on loading the image. Call [`Self::retry`] to try again.
+    pub fn load(
+        &mut self,
+        ctx: &egui::Context,
+        frame: &mut Frame<'_>,
+        url: &str,
+    ) -> Result<Option<(egui::TextureId, egui::Vec2)>, String> {
+        let now = ctx.input().time;
+        let frame_nr = ctx.input().frame_nr;
+        self.receive(now, frame.tex_allocator());
+
+        let entry = self.entries.entry(url.to_owned()).or_insert_with(|| Entry {
+            image: None,
+            fetch: Fetch::Idle,
+            failures: 0,
+            last_shown_frame_nr: None,
+        });
+        let shown_again = entry
+            .last_shown_frame_nr
+            .map_or(true, |last| last + 1 < frame_nr);
+        entry.last_shown_frame_nr = Some(frame_nr);
+
+        match &entry.fetch {
+            Fetch::Idle => {
+                let is_stale = entry
+                    .image
+                    .as_ref()
+                    .map_or(true, |image| image.fresh_until <= now);
+                if entry.image.is_none() || (shown_again && is_stale) {
+                    start_fetch(&self.sender, frame, url, entry, &self.options);
+                }
+            }
+            Fetch::InFlight => {}
+            Fetch::RetryAt(time) => {
+                if *time <= now {
+                    start_fetch(&self.sender, frame, url, entry, &self.options);
+                } else {
+                    ctx.request_repaint();
+                }
+            }
+            Fetch::Failed(error) => {
+                if entry.image.is_none() {
+                    return Err(error.clone());
+                }
+            }
+        }
+
+        let bytes = match &entry.image {
+            Some(image) => &image.bytes,
+            None => return Ok(None),
+        };
+        match self
+            .textures
+            .get_or_load_image_bytes(ctx, frame.tex_allocator(), url, bytes)
+        {
+            Ok(texture) => Ok(Some(texture)),
+            Err(error) => {
+                let error = format!("Failed to decode image: {}", error);
+                entry.image = None;
+                entry.fetch = Fetch::Failed(error.clone());
+                Err(error)
+            }
+        }
+    }
+
+    /// How far the image at `url` has come, or `None` if it hasn't been asked for.
+    pub fn state(&self, url: &str) -> Option<HttpImageState> {
+        let entry = self.entries.get(url)?;
+        Some(match (&entry.fetch, &entry.image) {
+            (_, Some(_)) => HttpImageState::Ready,
+            (Fetch::Failed(error), None) => HttpImageState::Failed(error.clone()),
+            _ => HttpImageState::Loading,
+        })
+    }
+
+    /// Try loading the image at `url` again (on the next [`Self::load`]), e.g. after it failed.
+    pub fn retry(&mut self, url: &str) {
+        if let Some(entry) = self.entries.get_mut(url) {
+            if !matches!(entry.fetch, Fetch::InFlight) {
+                entry.fetch = Fetch::RetryAt(f64::NEG_INFINITY);
+                entry.failures = 0;
+            }
+        }
+    }
+
+    /// Forget all the images, and free their textures.
+    pub fn clear(&mut self, tex_allocator: &mut dyn TextureAllocator) {
+        self.entries.clear();
+        self.textures.clear(tex_allocator);
+    }
+
+    /// Handle the responses that have arrived.
+    fn receive(&mut self, now: f64, tex_allocator: &mut dyn TextureAllocator) {
+        while let Ok((url, result)) = self.receiver.try_recv() {
+            if let Some(entry) = self.entries.get_mut(&url) {
+                if entry.on_response(result, now, &self.options) {
+                    // Upload the new image the next time it is shown:
+                    self.textures.remove(tex_allocator, &url);
+                }
+            }
+        }
+    }
+}
+
+fn start_fetch(
+    sender: &Sender<(String, Result<http::Response, http::Error>)>,
+    frame: &Frame<'_>,
+    url: &str,
+    entry: &mut Entry,
+    options: &HttpImageOptions,
+) {
+    let mut request = http::Request::get(url).with_max_bytes(options.max_bytes);
+    if let Some(image) = &entry.image {
+        if let Some(etag) = &image.etag {
+            request = request.with_header("If-None-Match", etag);
+        }
+        if let Some(last_modified) = &image.last_modified {
+            request = request.with_header("If-Modified-Since", last_modified);
+        }
+    }
+    entry.fetch = Fetch::InFlight;
+
+    let sender = sender.clone();
+    let repaint_signal = frame.repaint_signal();
+    let url = url.to_owned();
+    frame.http_fetch(request, move |result| {
+        sender.send((url, result)).ok();
+        repaint_signal.request_repaint();
+    });
+}
+
+impl Entry {
+    /// Returns `true` if we got a new image.
+    fn on_response(
+        &mut self,
+        result: Result<http::Response, http::Error>,
+        now: f64,
+        options: &HttpImageOptions,
+    ) -> bool {
+        let response = match result {
+            Ok(response) => response,
+            Err(error) => {
+                self.retry_or_fail(error, None, now, options);
+                return false;
+            }
+        };
+        let status = response.status;
+        let error = || format!("{} {}", status, response.status_text);
+
+        if status == 304 {
+            if let Some(image) = &mut self.image {
+                // Not modified, so we can keep using what we have:
+                let revalidated = CachedImage::new(vec![], &response, now);
+                image.fresh_until = revalidated.fresh_until;
+                image.etag = revalidated.etag.or_else(|| image.etag.take());
+                image.last_modified = revalidated
+                    .last_modified
+                    .or_else(|| image.last_modified.take());
+                self.failures = 0;
+                self.fetch = Fetch::Idle;
+                return false;
+            }
+        }
+
+        if status == 408 || status == 429 || (500..600).contains(&status) {
+            let retry_after = response
+                .header("retry-after")
+                .and_then(|seconds| seconds.trim().parse::<f64>().ok());
+            self.retry_or_fail(error(), retry_after, now, options);
+            false
+        } else if !response.ok {
+            // The image is gone (or never was), so don't keep showing an old version:
+            self.image = None;
+            self.fetch = Fetch::Failed(error());
+            false
+        } else if too_large(&response, options.max_bytes) {
+            self.image = None;
+            self.fetch = Fetch::Failed(format!(
+                "The image is too large (more than {} bytes)",
+                options.max_bytes
+            ));
+            false
+        } else {
+            self.image = Some(CachedImage::new(response.bytes.clone(), &response, now));
+            self.failures = 0;
+            self.fetch = Fetch::Idle;
+            true
+        }
+    }
+
+    fn retry_or_fail(
+        &mut self,
+        error: String,
+        retry_after: Option<f64>,
+        now: f64,
+        options: &HttpImageOptions,
+    ) {
+        self.failures += 1;
+        if self.failures > options.max_retries {
+            self.fetch = Fetch::Failed(error);
+        } else {
+            let backoff = options.retry_delay * 2.0_f64.powi(self.failures as i32 - 1);
+            let delay = retry_after.unwrap_or_else(|| backoff.min(options.max_retry_delay));
+            self.fetch = Fetch::RetryAt(now + delay);
+        }
+    }
+}
+
+/// Is the response body more than `max_bytes`?
+///
+/// The backend stops reading the body after `max_bytes + 1` bytes (see [`http::Request::with_max_bytes`]),
+/// or doesn't read it at all if the `Content-Length` header is too large.
+fn too_large(response: &http::Response, max_bytes: usize) -> bool {
+    let content_length = response
+        .header("content-length")
+        .and_then(|length| length.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    content_length.max(response.bytes.len()) > max_bytes
+}
+
+impl CachedImage {
+    fn new(bytes: Vec<u8>, response: &http::Response, now: f64) -> Self {
+        let cache_control = response
+            .header("cache-control")
+            .unwrap_or_default()
+            .to_lowercase();
+        let mut no_store = false;
+        let mut no_cache = false;
+        let mut max_age = None;
+        for directive in cache_control.split(',') {
+            let directive = directive.trim();
+            if directive == "no-store" {
+                no_store = true;
+            } else if directive == "no-cache" {
+                no_cache = true;
+            } else if let Some(seconds) = directive.strip_prefix("max-age=") {
+                max_age = seconds.trim_matches('"').parse::<f64>().ok();
+            }
+        }
+
+        let lifetime = if no_store || no_cache {
+            0.0
+        } else if let Some(max_age) = max_age {
+            let age = response
+                .header("age")
+                .and_then(|age| age.trim().parse::<f64>().ok())
+                .unwrap_or(0.0);
+            max_age - age
+        } else if let Some(expires) = response.header("expires") {
+            let date = response.header("date").and_then(parse_http_date);
+            match (parse_http_date(expires), date) {
+                (Some(expires), Some(date)) => expires - date,
+                _ => 0.0, // e.g. `Expires: 0`, which means "already expired"
+            }
+        } else {
+            DEFAULT_FRESHNESS
+        };
+
+        let validator = |name: &str| {
+            if no_store {
+                None
+            } else {
+                response.header(name).map(ToOwned::to_owned)
+            }
+        };
+        Self {
+            bytes,
+            fresh_until: now + lifetime.max(0.0),
+            etag: validator("etag"),
+            last_modified: validator("last-modified"),
+        }
+    }
+}
+
+/// Parse a date like `Sun, 06 Nov 1994 08:49:37 GMT` into seconds since 1970.
+fn parse_http_date(date: &str) -> Option<f64> {
+    let parts: Vec<&str> = date.split_whitespace().collect();
+    if parts.len() != 6 || parts[5] != "GMT" {
+        return None;
+    }
+    let day: i64 = parts[1].parse().ok()?;
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let month = MONTHS.iter().position(|month| *month == parts[2])? as i64 + 1;
+    let year: i64 = parts[3].parse().ok()?;
+    let time: Vec<i64> = parts[4]
+        .split(':')
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    if time.len() != 3 {
+        return None;
+    }
+
+    // Days since 1970-01-01, from http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    Some((days * 86_400 + time[0] * 3600 + time[1] * 60 + time[2]) as f64)
+}
+
+/// A frame of the given size, with `add_contents` on a row in the middle of it.
+fn placeholder(
+    ui: &mut egui::Ui,
+    size: egui::Vec2,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    ui.painter().rect(
+        rect,
+        2.0,
+        ui.visuals().faint_bg_color,
+        ui.visuals().widgets.noninteractive.bg_stroke,
+    );
+    let layout = egui::Layout::left_to_right().with_cross_align(egui::Align::Center);
+    ui.allocate_ui_at_rect(rect.shrink(4.0), |ui| {
+        ui.with_layout(layout, add_contents);
+    });
+    response
+}
+
+#[test]
+fn test_parse_http_date() {
+    assert_eq!(
+        parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+        Some(784_111_777.0)
+    );
+    assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0.0));
+    assert_eq!(
+        parse_http_date("Tue, 29 Feb 2000 12:00:00 GMT"),
+        Some(951_825_600.0)
+    );
+    assert_eq!(parse_http_date("0"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 PST"), None);
+}
+
+#[test]
+fn test_http_images() {
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct FakeHttp {
+        responses: Mutex<VecDeque<Result<http::Response, http::Error>>>,
+        requests: Mutex<Vec<http::Request>>,
+    }
+    impl crate::backend::Http for FakeHttp {
+        fn fetch_dyn(
+            &self,
+            request: http::Request,
+            on_done: Box<dyn FnOnce(Result<http::Response, http::Error>) + Send>,
+        ) {
+            self.requests.lock().unwrap().push(request);
+            on_done(self.responses.lock().unwrap().pop_front().unwrap());
+        }
+    }
+    struct FakeTextures;
+    impl TextureAllocator for FakeTextures {
+        fn alloc_srgba_premultiplied(
+            &mut self,
+            _size: (usize, usize),
+            _pixels: &[egui::Color32],
+        ) -> egui::TextureId {
+            egui::TextureId::User(0)
+        }
+        fn free(&mut self, _id: egui::TextureId) {}
+    }
+    struct NoRepaint;
+    impl crate::RepaintSignal for NoRepaint {
+        fn request_repaint(&self) {}
+    }
+
+    let response = |status: u16, headers: &[(&str, &str)], bytes: Vec<u8>| {
+        Ok(http::Response {
+            url: "https://example.com/cat.png".to_owned(),
+            ok: (200..300).contains(&status),
+            status,
+            status_text: String::new(),
+            header_content_type: "image/png".to_owned(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            bytes,
+            text: None,
+        })
+    };
+    let mut png = vec![];
+    image::DynamicImage::ImageRgba8(image::RgbaImage::new(3, 2))
+        .write_to(&mut png, image::ImageOutputFormat::Png)
+        .unwrap();
+
+    let http = Arc::new(FakeHttp::default());
+    let mut images = HttpImages::default();
+    let mut ctx = egui::CtxRef::default();
+    let mut textures = FakeTextures;
+    let mut load = |images: &mut HttpImages, time: f64| {
+        // A frame without the image first, so it is shown again every time:
+        ctx.begin_frame(Default::default());
+        let _ = ctx.end_frame();
+        ctx.begin_frame(egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        });
+        let mut output = Default::default();
+        let mut frame = crate::backend::FrameBuilder {
+            info: crate::IntegrationInfo {
+                web_info: None,
+                prefer_dark_mode: None,
+                cpu_usage: None,
+                seconds_since_midnight: None,
+                native_pixels_per_point: None,
+            },
+            tex_allocator: &mut textures,
+            http: http.clone(),
+            output: &mut output,
+            repaint_signal: Arc::new(NoRepaint),
+        }
+        .build();
+        let url = "https://example.com/cat.png";
+        let result = images.load(&ctx, &mut frame, url);
+        let _ = ctx.end_frame();
+        (
+            result.map(|texture| texture.map(|(_, size)| size)),
+            images.state(url),
+        )
+    };
+    let push = |result| http.responses.lock().unwrap().push_back(result);
+
+    push(Err("Network is down".to_owned()));
+    assert_eq!(
+        load(&mut images, 0.0),
+        (Ok(None), Some(HttpImageState::Loading))
+    );
+    assert_eq!(
+        load(&mut images, 0.5).1,
+        Some(HttpImageState::Loading),
+        "waiting to retry"
+    );
+    assert_eq!(http.requests.lock().unwrap().len(), 1);
+
+    let headers = [("cache-control", "max-age=60"), ("etag", "\"v1\"")];
+    push(response(200, &headers, png.clone()));
+    let _ = load(&mut images, 1.5);
+    assert_eq!(http.requests.lock().unwrap().len(), 2, "retried");
+    let size = Some(egui::vec2(3.0, 2.0));
+    assert_eq!(
+        load(&mut images, 1.6),
+        (Ok(size), Some(HttpImageState::Ready))
+    );
+    assert_eq!(load(&mut images, 30.0).0, Ok(size));
+    assert_eq!(http.requests.lock().unwrap().len(), 2, "still fresh");
+
+    // Once stale, it is revalidated, and the old image is shown meanwhile:
+    push(response(304, &[("cache-control", "max-age=60")], vec![]));
+    assert_eq!(load(&mut images, 100.0).0, Ok(size));
+    assert_eq!(load(&mut images, 100.1).0, Ok(size));
+    assert_eq!(load(&mut images, 150.0).0, Ok(size));
+    {
+        let requests = http.requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].headers["If-None-Match"], "\"v1\"");
+    }
+
+    // A missing image is not retried:
+    push(response(404, &[], vec![]));
+    assert_eq!(load(&mut images, 300.0).0, Ok(size));
+    assert!(load(&mut images, 300.1).0.is_err());
+    let _ = load(&mut images, 400.0);
+    assert_eq!(http.requests.lock().unwrap().len(), 4);
+
+    images.retry("https://example.com/cat.png");
+    push(response(200, &[("content-length", "100000000")], vec![]));
+    let _ = load(&mut images, 500.0);
+    assert!(
+        matches!(load(&mut images, 500.1).1, Some(HttpImageState::Failed(_))),
+        "too large"
+    );
+    assert_eq!(
+        http.requests.lock().unwrap()[4].max_bytes,
+        Some(HttpImageOptions::default().max_bytes),
+        "the backend doesn't read more than that"
+    );
+
+    // Without a Content-Length, the backend stops reading after one byte too many:
+    images.retry("https://example.com/cat.png");
+    push(response(200, &[], vec![0; 16 * 1024 * 1024 + 1]));
+    let _ = load(&mut images, 600.0);
+    assert!(matches!(
+        load(&mut images, 600.1).1,
+        Some(HttpImageState::Failed(_))
+    ));
+}
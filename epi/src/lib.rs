@@ -87,6 +87,11 @@
 
 pub use egui; // Re-export for user convenience
 
+#[cfg(all(feature = "http", feature = "image"))]
+mod http_images;
+#[cfg(all(feature = "http", feature = "image"))]
+pub use http_images::{HttpImageOptions, HttpImageState, HttpImages};
+
 // ----------------------------------------------------------------------------
 
 /// Implement this trait to write apps that can be compiled both natively using the [`egui_glium`](https://crates.io/crates/egui_glium) crate,
@@ -174,12 +179,33 @@ pub struct NativeOptions {
     /// uses that use multi-threaded COM API <https://github.com/rust-windowing/winit/pull/1524>
     pub drag_and_drop_support: bool,
 
+    /// Paint into a floating point framebuffer, so colors brighter than white
+    /// (see [`TextureAllocator::alloc_linear_premultiplied`]) are not clamped.
+    ///
+    /// egui paints in linear colors, so on an HDR display this is scRGB,
+    /// where `1.0` is the brightness of standard white.
+    /// Falls back to a normal framebuffer if there is no floating point one.
+    /// Only supported natively.
+    pub hdr_output: bool,
+
     /// The application icon, e.g. in the Windows task bar etc.
     pub icon_data: Option<IconData>,
 
     /// The initial size of the native window in points (logical pixels).
     pub initial_window_size: Option<egui::Vec2>,
 
+    /// Start each frame as late as possible before the next vsync, instead of right after the previous one,
+    /// so that the latest input (e.g. the mouse position while dragging) makes it into the frame.
+    ///
+    /// When repainting continuously this cuts the latency from input to screen by up to a frame,
+    /// at the risk of missing a vsync when a frame suddenly takes much longer than the previous ones.
+    /// To keep track of when vsync is, egui waits for the GPU to present one frame in 30.
+    ///
+    /// Without this, egui never waits for the GPU,
+    /// so the next frame runs while the previous one is being presented.
+    /// Only supported natively.
+    pub late_input_sampling: bool,
+
     /// Should the app window be resizable?
     pub resizable: bool,
 
@@ -195,8 +221,10 @@ impl Default for NativeOptions {
             always_on_top: false,
             decorated: true,
             drag_and_drop_support: true,
+            hdr_output: false,
             icon_data: None,
             initial_window_size: None,
+            late_input_sampling: false,
             resizable: true,
             transparent: false,
         }
@@ -311,6 +339,22 @@ pub trait TextureAllocator {
         srgba_pixels: &[egui::Color32],
     ) -> egui::TextureId;
 
+    /// Allocate a new user texture from linear colors (with premultiplied alpha),
+    /// e.g. for an HDR image with colors brighter than white.
+    ///
+    /// Integrations that support it (`egui_glium`) keep the colors as floats.
+    /// The others convert them to 8-bit sRGB, clamping everything above `1.0`.
+    /// To see colors above `1.0` you also need an HDR framebuffer, see [`NativeOptions::hdr_output`].
+    fn alloc_linear_premultiplied(
+        &mut self,
+        size: (usize, usize),
+        rgba_pixels: &[egui::Rgba],
+    ) -> egui::TextureId {
+        let srgba_pixels: Vec<egui::Color32> =
+            rgba_pixels.iter().map(|&rgba| rgba.into()).collect();
+        self.alloc_srgba_premultiplied(size, &srgba_pixels)
+    }
+
     /// Free the given texture.
     fn free(&mut self, id: egui::TextureId);
 }
@@ -324,6 +368,457 @@ pub trait RepaintSignal: Send + Sync {
 
 // ----------------------------------------------------------------------------
 
+/// Keeps the textures of your images around for as long as you show them.
+///
+/// Every frame you show an image, look up its texture with a key of your choice, e.g. a path or url.
+/// The image is only decoded and uploaded the first time.
+/// Textures that were not looked up during the previous frame are freed,
+/// so to get rid of an image you simply stop showing it.
+///
+/// With the `image` feature you can also load PNG and JPEG files, or any [`image::DynamicImage`],
+/// as well as animated GIF and PNG files for showing with [`egui::AnimatedImage`].
+/// With the `svg` feature you can load SVG files, which stay crisp at any scale.
+///
+/// ```
+/// # fn load_pixels(path: &str) -> ((usize, usize), Vec<egui::Color32>) { ((1, 1), vec![egui::Color32::WHITE]) }
+/// struct MyApp {
+///     textures: epi::TextureCache<String>,
+/// }
+///
+/// impl MyApp {
+///     fn ui(&mut self, ui: &mut egui::Ui, frame: &mut epi::Frame<'_>) {
+///         let path = "cat.png";
+///         let (texture_id, size) =
+///             self.textures
+///                 .get_or_load(ui.ctx(), frame.tex_allocator(), path, || load_pixels(path));
+///         ui.image(texture_id, size);
+///     }
+/// }
+/// ```
+pub struct TextureCache<Key> {
+    textures: std::collections::HashMap<Key, CachedTexture>,
+    frame_nr: u64,
+}
+
+struct CachedTexture {
+    /// One frame, with a zero duration, unless this is an animation.
+    frames: Vec<egui::AnimationFrame>,
+    size: egui::Vec2,
+    last_used_frame_nr: u64,
+    /// For SVG:s, which are rasterized for the current [`egui::Context::pixels_per_point`].
+    #[cfg(feature = "svg")]
+    pixels_per_point: Option<f32>,
+}
+
+impl<Key> Default for TextureCache<Key> {
+    fn default() -> Self {
+        Self {
+            textures: Default::default(),
+            frame_nr: 0,
+        }
+    }
+}
+
+impl<Key: std::hash::Hash + Eq> TextureCache<Key> {
+    /// Look up the texture for `key`, calling `load` to get the size and premultiplied sRGBA pixels of the image if it's not loaded yet.
+    ///
+    /// Returns the texture and its size in pixels, ready for [`egui::Ui::image`].
+    pub fn get_or_load<Q>(
+        &mut self,
+        ctx: &egui::Context,
+        tex_allocator: &mut dyn TextureAllocator,
+        key: &Q,
+        load: impl FnOnce() -> ((usize, usize), Vec<egui::Color32>),
+    ) -> (egui::TextureId, egui::Vec2)
+    where
+        Key: std::borrow::Borrow<Q>,
+        Q: ?Sized + std::hash::Hash + Eq + ToOwned<Owned = Key>,
+    {
+        if let Some(texture) = self.get(ctx, tex_allocator, key) {
+            return texture;
+        }
+        let (size, pixels) = load();
+        self.insert(tex_allocator, key.to_owned(), size, &pixels)
+    }
+
+    /// Like [`Self::get_or_load_image_bytes`], but also decodes all the frames of an animated GIF or PNG file.
+    ///
+    /// Returns the frames and their size in pixels, ready for [`egui::AnimatedImage`].
+    /// Any other image gives a single frame.
+    ///
+    /// # Errors
+    /// If the image could not be decoded.
+    /// Only images that decode fine are cached, so don't keep asking for a broken image every frame.
+    #[cfg(feature = "image")]
+    pub fn get_or_load_animation_bytes<Q>(
+        &mut self,
+        ctx: &egui::Context,
+        tex_allocator: &mut dyn TextureAllocator,
+        key: &Q,
+        bytes: &[u8],
+    ) -> Result<(Vec<egui::AnimationFrame>, egui::Vec2), String>
+    where
+        Key: std::borrow::Borrow<Q>,
+        Q: ?Sized + std::hash::Hash + Eq + ToOwned<Owned = Key>,
+    {
+        if self.get(ctx, tex_allocator, key).is_none() {
+            let (size, frames) = decode_animation(bytes)?;
+            self.insert_frames(tex_allocator, key.to_owned(), size, frames);
+        }
+        let texture = &self.textures[key];
+        Ok((texture.frames.clone(), texture.size))
+    }
+
+    /// Like [`Self::get_or_load`], for an [`image::DynamicImage`].
+    #[cfg(feature = "image")]
+    pub fn get_or_load_image<Q>(
+        &mut self,
+        ctx: &egui::Context,
+        tex_allocator: &mut dyn TextureAllocator,
+        key: &Q,
+        load: impl FnOnce() -> image::DynamicImage,
+    ) -> (egui::TextureId, egui::Vec2)
+    where
+        Key: std::borrow::Borrow<Q>,
+        Q: ?Sized + std::hash::Hash + Eq + ToOwned<Owned = Key>,
+    {
+        self.get_or_load(ctx, tex_allocator, key, || image_pixels(&load()))
+    }
+
+    /// Like [`Self::get_or_load`], for the bytes of an image file, e.g. a PNG or JPEG.
+    ///
+    /// # Errors
+    /// If the image could not be decoded.
+    /// Only images that decode fine are cached, so don't keep asking for a broken image every frame.
+    #[cfg(feature = "image")]
+    pub fn get_or_load_image_bytes<Q>(
+        &mut self,
+        ctx: &egui::Context,
+        tex_allocator: &mut dyn TextureAllocator,
+        key: &Q,
+        bytes: &[u8],
+    ) -> Result<(egui::TextureId, egui::Vec2), String>
+    where
+        Key: std::borrow::Borrow<Q>,
+        Q: ?Sized + std::hash::Hash + Eq + ToOwned<Owned = Key>,
+    {
+        if let Some(texture) = self.get(ctx, tex_allocator, key) {
+            return Ok(texture);
+        }
+        let image = image::load_from_memory(bytes).map_err(|err| err.to_string())?;
+        let (size, pixels) = image_pixels(&image);
+        Ok(self.insert(tex_allocator, key.to_owned(), size, &pixels))
+    }
+
+    /// Like [`Self::get_or_load`], for the bytes of an SVG file.
+    ///
+    /// The SVG is rasterized to be crisp at the current [`egui::Context::pixels_per_point`],
+    /// and rasterized again if that changes.
+    /// `size` is the size to show it at, in points.
+    /// If `None`, the size given in the SVG file is used.
+    ///
+    /// Unlike the other functions, this returns the size in points.
+    ///
+    /// # Errors
+    /// If the SVG could not be parsed or rendered.
+    /// Only SVG:s that render fine are cached, so don't keep asking for a broken one every frame.
+    #[cfg(feature = "svg")]
+    pub fn get_or_load_svg_bytes<Q>(
+        &mut self,
+        ctx: &egui::Context,
+        tex_allocator: &mut dyn TextureAllocator,
+        key: &Q,
+        bytes: &[u8],
+        size: Option<egui::Vec2>,
+    ) -> Result<(egui::TextureId, egui::Vec2), String>
+    where
+        Key: std::borrow::Borrow<Q>,
+        Q: ?Sized + std::hash::Hash + Eq + ToOwned<Owned = Key>,
+    {
+        let pixels_per_point = ctx.pixels_per_point();
+        if let Some(texture) = self.get(ctx, tex_allocator, key) {
+            let is_crisp =
+                self.textures.get(key).and_then(|t| t.pixels_per_point) == Some(pixels_per_point);
+            if is_crisp && (size.is_none() || size == Some(texture.1)) {
+                return Ok(texture);
+            }
+            self.remove(tex_allocator, key);
+        }
+
+        let (size, pixel_size, pixels) = rasterize_svg(bytes, size, pixels_per_point)?;
+        let (id, _) = self.insert(tex_allocator, key.to_owned(), pixel_size, &pixels);
+        if let Some(texture) = self.textures.get_mut(key) {
+            texture.size = size;
+            texture.pixels_per_point = Some(pixels_per_point);
+        }
+        Ok((id, size))
+    }
+
+    /// Free the texture for `key` now, e.g. because the image has changed.
+    pub fn remove<Q>(&mut self, tex_allocator: &mut dyn TextureAllocator, key: &Q)
+    where
+        Key: std::borrow::Borrow<Q>,
+        Q: ?Sized + std::hash::Hash + Eq,
+    {
+        if let Some(texture) = self.textures.remove(key) {
+            texture.free(tex_allocator);
+        }
+    }
+
+    /// Free all the textures.
+    pub fn clear(&mut self, tex_allocator: &mut dyn TextureAllocator) {
+        for (_, texture) in self.textures.drain() {
+            texture.free(tex_allocator);
+        }
+    }
+
+    fn get<Q>(
+        &mut self,
+        ctx: &egui::Context,
+        tex_allocator: &mut dyn TextureAllocator,
+        key: &Q,
+    ) -> Option<(egui::TextureId, egui::Vec2)>
+    where
+        Key: std::borrow::Borrow<Q>,
+        Q: ?Sized + std::hash::Hash + Eq,
+    {
+        self.free_unused(ctx, tex_allocator);
+        let texture = self.textures.get_mut(key)?;
+        texture.last_used_frame_nr = self.frame_nr;
+        Some((texture.frames[0].texture_id, texture.size))
+    }
+
+    fn insert(
+        &mut self,
+        tex_allocator: &mut dyn TextureAllocator,
+        key: Key,
+        size: (usize, usize),
+        pixels: &[egui::Color32],
+    ) -> (egui::TextureId, egui::Vec2) {
+        let id = tex_allocator.alloc_srgba_premultiplied(size, pixels);
+        let frame = egui::AnimationFrame {
+            texture_id: id,
+            duration: 0.0,
+        };
+        let size = self.insert_texture(key, size, vec![frame]);
+        (id, size)
+    }
+
+    /// Upload the premultiplied pixels and duration of each frame.
+    #[cfg(feature = "image")]
+    fn insert_frames(
+        &mut self,
+        tex_allocator: &mut dyn TextureAllocator,
+        key: Key,
+        size: (usize, usize),
+        frames: Vec<(Vec<egui::Color32>, f32)>,
+    ) {
+        let frames = frames
+            .into_iter()
+            .map(|(pixels, duration)| egui::AnimationFrame {
+                texture_id: tex_allocator.alloc_srgba_premultiplied(size, &pixels),
+                duration,
+            })
+            .collect();
+        self.insert_texture(key, size, frames);
+    }
+
+    fn insert_texture(
+        &mut self,
+        key: Key,
+        size: (usize, usize),
+        frames: Vec<egui::AnimationFrame>,
+    ) -> egui::Vec2 {
+        let size = egui::vec2(size.0 as f32, size.1 as f32);
+        let last_used_frame_nr = self.frame_nr;
+        self.textures.insert(
+            key,
+            CachedTexture {
+                frames,
+                size,
+                last_used_frame_nr,
+                #[cfg(feature = "svg")]
+                pixels_per_point: None,
+            },
+        );
+        size
+    }
+
+    /// On the first lookup of a new frame, free the textures that were not used during the previous frame.
+    fn free_unused(&mut self, ctx: &egui::Context, tex_allocator: &mut dyn TextureAllocator) {
+        let frame_nr = ctx.input().frame_nr;
+        if frame_nr == self.frame_nr {
+            return;
+        }
+        self.frame_nr = frame_nr;
+        self.textures.retain(|_, texture| {
+            let keep = texture.last_used_frame_nr + 1 >= frame_nr;
+            if !keep {
+                texture.free(tex_allocator);
+            }
+            keep
+        });
+    }
+}
+
+impl CachedTexture {
+    fn free(&self, tex_allocator: &mut dyn TextureAllocator) {
+        for frame in &self.frames {
+            tex_allocator.free(frame.texture_id);
+        }
+    }
+}
+
+/// The size in points, and the size and premultiplied pixels of a rasterized SVG.
+#[cfg(feature = "svg")]
+type RasterizedSvg = (egui::Vec2, (usize, usize), Vec<egui::Color32>);
+
+#[cfg(feature = "svg")]
+fn rasterize_svg(
+    bytes: &[u8],
+    size: Option<egui::Vec2>,
+    pixels_per_point: f32,
+) -> Result<RasterizedSvg, String> {
+    let options = usvg::Options::default();
+    let tree = usvg::Tree::from_data(bytes, &options.to_ref()).map_err(|err| err.to_string())?;
+    let size = size.unwrap_or_else(|| {
+        let svg_size = tree.svg_node().size;
+        egui::vec2(svg_size.width() as f32, svg_size.height() as f32)
+    });
+
+    let width = (size.x * pixels_per_point).round().max(1.0) as u32;
+    let height = (size.y * pixels_per_point).round().max(1.0) as u32;
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| format!("Can't rasterize an SVG of {}x{} pixels", width, height))?;
+    resvg::render(
+        &tree,
+        usvg::FitTo::Size(width, height),
+        tiny_skia::Transform::default(),
+        pixmap.as_mut(),
+    )
+    .ok_or_else(|| "Failed to render SVG".to_owned())?;
+
+    let pixels = pixmap
+        .data()
+        .chunks_exact(4)
+        .map(|p| egui::Color32::from_rgba_premultiplied(p[0], p[1], p[2], p[3]))
+        .collect();
+    Ok((size, (width as usize, height as usize), pixels))
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn test_rasterize_svg() {
+    let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10">
+        <rect width="10" height="10" fill="red"/>
+    </svg>"#;
+    let (size, pixel_size, pixels) = rasterize_svg(svg, None, 2.0).unwrap();
+    assert_eq!(size, egui::vec2(10.0, 10.0));
+    assert_eq!(pixel_size, (20, 20));
+    assert_eq!(pixels[10 * 20 + 10], egui::Color32::RED);
+
+    assert!(rasterize_svg(b"not an svg", None, 1.0).is_err());
+}
+
+/// The size and premultiplied pixels of an image.
+#[cfg(feature = "image")]
+fn image_pixels(image: &image::DynamicImage) -> ((usize, usize), Vec<egui::Color32>) {
+    rgba_pixels(&image.to_rgba8())
+}
+
+#[cfg(feature = "image")]
+fn rgba_pixels(image: &image::RgbaImage) -> ((usize, usize), Vec<egui::Color32>) {
+    let size = (image.width() as usize, image.height() as usize);
+    let pixels = image
+        .pixels()
+        .map(|p| egui::Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+        .collect();
+    (size, pixels)
+}
+
+/// The size of an image, and the premultiplied pixels and duration in seconds of each of its frames.
+#[cfg(feature = "image")]
+type DecodedAnimation = ((usize, usize), Vec<(Vec<egui::Color32>, f32)>);
+
+/// Decode all the frames of an animated GIF or PNG file, or the single frame of any other image.
+#[cfg(feature = "image")]
+fn decode_animation(bytes: &[u8]) -> Result<DecodedAnimation, String> {
+    use image::{AnimationDecoder as _, ImageFormat};
+
+    let frames = match image::guess_format(bytes) {
+        Ok(ImageFormat::Gif) => image::codecs::gif::GifDecoder::new(bytes)
+            .and_then(|decoder| decoder.into_frames().collect_frames()),
+        Ok(ImageFormat::Png) => {
+            let decoder =
+                image::codecs::png::PngDecoder::new(bytes).map_err(|err| err.to_string())?;
+            if decoder.is_apng() {
+                decoder.apng().into_frames().collect_frames()
+            } else {
+                Ok(vec![])
+            }
+        }
+        _ => Ok(vec![]),
+    }
+    .map_err(|err| err.to_string())?;
+
+    if frames.is_empty() {
+        let image = image::load_from_memory(bytes).map_err(|err| err.to_string())?;
+        let (size, pixels) = image_pixels(&image);
+        return Ok((size, vec![(pixels, 0.0)]));
+    }
+
+    let size = (
+        frames[0].buffer().width() as usize,
+        frames[0].buffer().height() as usize,
+    );
+    let frames = frames
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let mut duration = numer as f32 / denom.max(1) as f32 / 1000.0;
+            if duration <= 0.01 {
+                duration = 0.1; // like web browsers do, for animations that ask for "as fast as possible"
+            }
+            (rgba_pixels(frame.buffer()).1, duration)
+        })
+        .collect();
+    Ok((size, frames))
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_decode_animation() {
+    use image::{codecs::gif::GifEncoder, Delay, Frame, Rgba, RgbaImage};
+
+    let frame = |color: [u8; 4], delay_ms: u32| {
+        let buffer = RgbaImage::from_pixel(2, 2, Rgba(color));
+        Frame::from_parts(buffer, 0, 0, Delay::from_numer_denom_ms(delay_ms, 1))
+    };
+    let mut gif = vec![];
+    {
+        // The encoder finishes the file when dropped.
+        let mut encoder = GifEncoder::new(&mut gif);
+        encoder
+            .encode_frames(vec![
+                frame([255, 0, 0, 255], 200),
+                frame([0, 0, 255, 255], 0),
+            ])
+            .unwrap();
+    }
+
+    let (size, frames) = decode_animation(&gif).unwrap();
+    assert_eq!(size, (2, 2));
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0].0[0], egui::Color32::RED);
+    assert_eq!(frames[1].0[0], egui::Color32::BLUE);
+    assert!((frames[0].1 - 0.2).abs() < 1e-6);
+    assert!((frames[1].1 - 0.1).abs() < 1e-6);
+
+    assert!(decode_animation(b"not an image").is_err());
+}
+
+// ----------------------------------------------------------------------------
+
 /// A place where you can store custom data in a way that persists when you restart the app.
 ///
 /// On the web this is backed by [local storage](https://developer.mozilla.org/en-US/docs/Web/API/Window/localStorage).
@@ -377,6 +872,8 @@ pub const APP_KEY: &str = "app";
 ///
 /// You must enable the "http" feature for this.
 pub mod http {
+    use std::collections::BTreeMap;
+
     /// A simple http requests.
     pub struct Request {
         /// "GET", …
@@ -385,6 +882,10 @@ pub mod http {
         pub url: String,
         /// x-www-form-urlencoded body
         pub body: String,
+        /// Extra headers to send, e.g. `If-None-Match`.
+        pub headers: BTreeMap<String, String>,
+        /// Don't read more than this many bytes of the response body, see [`Self::with_max_bytes`].
+        pub max_bytes: Option<usize>,
     }
 
     impl Request {
@@ -395,6 +896,8 @@ pub mod http {
                 method: "GET".to_owned(),
                 url: url.to_string(),
                 body: "".to_string(),
+                headers: Default::default(),
+                max_bytes: None,
             }
         }
 
@@ -405,8 +908,28 @@ pub mod http {
                 method: "POST".to_owned(),
                 url: url.to_string(),
                 body: body.to_string(),
+                headers: Default::default(),
+                max_bytes: None,
             }
         }
+
+        /// Send this header along with the request.
+        #[allow(clippy::needless_pass_by_value)]
+        pub fn with_header(mut self, name: impl ToString, value: impl ToString) -> Self {
+            self.headers.insert(name.to_string(), value.to_string());
+            self
+        }
+
+        /// Stop reading the response body after `max_bytes + 1` bytes,
+        /// so a huge response isn't read into memory just to be thrown away.
+        ///
+        /// [`Response::bytes`] is then longer than `max_bytes` when the body was too large.
+        /// If the `Content-Length` header already says it is too large, the body isn't read at all
+        /// and [`Response::bytes`] is empty.
+        pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+            self.max_bytes = Some(max_bytes);
+            self
+        }
     }
 
     /// Response from a completed HTTP request.
@@ -423,6 +946,12 @@ pub mod http {
         /// Content-Type header, or empty string if missing.
         pub header_content_type: String,
 
+        /// All the headers, with lowercase names.
+        ///
+        /// On the web, only the headers the server exposes to scripts are here
+        /// (see `Access-Control-Expose-Headers`).
+        pub headers: BTreeMap<String, String>,
+
         /// The raw bytes.
         pub bytes: Vec<u8>,
 
@@ -431,8 +960,105 @@ pub mod http {
         pub text: Option<String>,
     }
 
+    impl Response {
+        /// The value of the header with this name (in any case), if there is one.
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .get(&name.to_lowercase())
+                .map(|value| value.as_str())
+        }
+    }
+
     /// Possible errors does NOT include e.g. 404, which is NOT considered an error.
     pub type Error = String;
+
+    /// Do a HTTP request and wait for the response, using [`ureq`](https://docs.rs/ureq).
+    ///
+    /// The native integrations call this on a background thread to implement [`crate::backend::Http`].
+    /// It is not available on the web, where requests can't block.
+    ///
+    /// # Errors
+    /// When there is no response at all, e.g. because the server can't be reached.
+    /// A response with an error status, e.g. 404, is still `Ok`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn fetch_blocking(request: &Request) -> Result<Response, Error> {
+        let Request {
+            method,
+            url,
+            body,
+            headers,
+            max_bytes,
+        } = request;
+
+        let mut req = ureq::request(method, url).set("Accept", "*/*");
+        for (name, value) in headers {
+            req = req.set(name, value);
+        }
+        let resp = if body.is_empty() {
+            req.call()
+        } else {
+            req.set("Content-Type", "text/plain; charset=utf-8")
+                .send_string(body)
+        };
+
+        let (ok, resp) = match resp {
+            Ok(resp) => (true, resp),
+            Err(ureq::Error::Status(_, resp)) => (false, resp), // Still read the body on e.g. 404
+            Err(ureq::Error::Transport(error)) => return Err(error.to_string()),
+        };
+
+        let url = resp.get_url().to_owned();
+        let status = resp.status();
+        let status_text = resp.status_text().to_owned();
+        let header_content_type = resp.header("Content-Type").unwrap_or_default().to_owned();
+        let headers = resp
+            .headers_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = resp.header(&name)?.to_owned();
+                Some((name.to_lowercase(), value))
+            })
+            .collect();
+
+        let content_length = resp
+            .header("Content-Length")
+            .and_then(|length| length.trim().parse::<usize>().ok());
+        let mut bytes = vec![];
+        let too_large = match (max_bytes, content_length) {
+            (Some(max_bytes), Some(content_length)) => content_length > *max_bytes,
+            _ => false,
+        };
+        if !too_large {
+            // Reading one byte more than allowed tells the caller the body was too large:
+            let limit =
+                max_bytes.map_or(u64::MAX, |max_bytes| (max_bytes as u64).saturating_add(1));
+            use std::io::Read;
+            resp.into_reader()
+                .take(limit)
+                .read_to_end(&mut bytes)
+                .map_err(|err| err.to_string())?;
+        }
+
+        let text = if header_content_type.starts_with("text")
+            || header_content_type == "application/javascript"
+        {
+            String::from_utf8(bytes.clone()).ok()
+        } else {
+            None
+        };
+
+        let response = Response {
+            url,
+            ok,
+            status,
+            status_text,
+            header_content_type,
+            headers,
+            bytes,
+            text,
+        };
+        Ok(response)
+    }
 }
 
 // ----------------------------------------------------------------------------